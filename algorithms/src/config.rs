@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_utilities::execute_with_threads;
+#[cfg(not(feature = "serial"))]
+use snarkvm_utilities::max_available_threads;
+
+use anyhow::{ensure, Result};
+use std::sync::RwLock;
+
+/// The global configuration of the algorithms.
+static CONFIG: RwLock<AlgorithmsConfig> = RwLock::new(AlgorithmsConfig::new());
+
+/// The configuration of the parallelism and memory usage of the FFT and MSM algorithms.
+///
+/// By default, FFTs and MSMs use all available threads and choose their own memory usage. Provers on
+/// constrained machines can lower the thread counts, bound the FFT scratch space, and compute MSMs in
+/// chunks, to trade time for memory. The configuration is set for the process with `set_global`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AlgorithmsConfig {
+    /// The number of threads for FFTs, or `None` to use all available threads.
+    fft_threads: Option<usize>,
+    /// The number of threads for MSMs, or `None` to use all available threads.
+    msm_threads: Option<usize>,
+    /// The maximum number of bytes of scratch space for an FFT, or `None` for no limit.
+    /// Note: This bounds the memory used to compact the roots of unity, not the roots or the input.
+    max_fft_scratch_bytes: Option<usize>,
+    /// The window size of an MSM, or `None` to choose it from the number of bases.
+    msm_window_size: Option<usize>,
+    /// The maximum number of bases in one chunk of an MSM, or `None` to compute an MSM in one chunk.
+    msm_chunk_size: Option<usize>,
+}
+
+impl AlgorithmsConfig {
+    /// The maximum window size of an MSM.
+    pub const MAX_MSM_WINDOW_SIZE: usize = 24;
+
+    /// Returns the default configuration.
+    pub const fn new() -> Self {
+        Self {
+            fft_threads: None,
+            msm_threads: None,
+            max_fft_scratch_bytes: None,
+            msm_window_size: None,
+            msm_chunk_size: None,
+        }
+    }
+
+    /// Returns the global configuration.
+    pub fn global() -> Self {
+        *CONFIG.read().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Sets the global configuration.
+    pub fn set_global(self) -> Result<()> {
+        // Ensure the configuration is valid.
+        ensure!(self.fft_threads != Some(0), "The number of FFT threads must be nonzero");
+        ensure!(self.msm_threads != Some(0), "The number of MSM threads must be nonzero");
+        if let Some(window_size) = self.msm_window_size {
+            ensure!(
+                (1..=Self::MAX_MSM_WINDOW_SIZE).contains(&window_size),
+                "The MSM window size must be between 1 and {}",
+                Self::MAX_MSM_WINDOW_SIZE
+            );
+        }
+        ensure!(self.msm_chunk_size != Some(0), "The MSM chunk size must be nonzero");
+        // Set the configuration.
+        *CONFIG.write().unwrap_or_else(|error| error.into_inner()) = self;
+        Ok(())
+    }
+
+    /// Sets the number of threads for FFTs.
+    pub const fn with_fft_threads(mut self, num_threads: usize) -> Self {
+        self.fft_threads = Some(num_threads);
+        self
+    }
+
+    /// Sets the number of threads for MSMs.
+    pub const fn with_msm_threads(mut self, num_threads: usize) -> Self {
+        self.msm_threads = Some(num_threads);
+        self
+    }
+
+    /// Sets the maximum number of bytes of scratch space for an FFT.
+    pub const fn with_max_fft_scratch_bytes(mut self, num_bytes: usize) -> Self {
+        self.max_fft_scratch_bytes = Some(num_bytes);
+        self
+    }
+
+    /// Sets the window size of an MSM.
+    pub const fn with_msm_window_size(mut self, window_size: usize) -> Self {
+        self.msm_window_size = Some(window_size);
+        self
+    }
+
+    /// Sets the maximum number of bases in one chunk of an MSM.
+    pub const fn with_msm_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.msm_chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Returns the number of threads for FFTs, if set.
+    pub const fn fft_threads(&self) -> Option<usize> {
+        self.fft_threads
+    }
+
+    /// Returns the number of threads for MSMs, if set.
+    pub const fn msm_threads(&self) -> Option<usize> {
+        self.msm_threads
+    }
+
+    /// Returns the maximum number of bytes of scratch space for an FFT, if set.
+    pub const fn max_fft_scratch_bytes(&self) -> Option<usize> {
+        self.max_fft_scratch_bytes
+    }
+
+    /// Returns the window size of an MSM, if set.
+    pub const fn msm_window_size(&self) -> Option<usize> {
+        self.msm_window_size
+    }
+
+    /// Returns the maximum number of bases in one chunk of an MSM, if set.
+    pub const fn msm_chunk_size(&self) -> Option<usize> {
+        self.msm_chunk_size
+    }
+}
+
+/// Executes the given function with the configured number of threads for FFTs.
+#[cfg(all(feature = "fft", not(feature = "serial")))]
+pub(crate) fn execute_with_fft_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send) -> T {
+    execute_with_num_threads(f, AlgorithmsConfig::global().fft_threads.unwrap_or_else(max_available_threads))
+}
+
+/// Executes the given function with the configured number of threads for FFTs.
+#[cfg(all(feature = "fft", feature = "serial"))]
+pub(crate) fn execute_with_fft_threads<T>(f: impl FnOnce() -> T + Send) -> T {
+    execute_with_threads(f, 1)
+}
+
+/// Executes the given function with the configured number of threads for MSMs.
+#[cfg(all(feature = "msm", not(feature = "serial")))]
+pub(crate) fn execute_with_msm_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send) -> T {
+    execute_with_num_threads(f, AlgorithmsConfig::global().msm_threads.unwrap_or_else(max_available_threads))
+}
+
+/// Executes the given function with the configured number of threads for MSMs.
+#[cfg(all(feature = "msm", feature = "serial"))]
+pub(crate) fn execute_with_msm_threads<T>(f: impl FnOnce() -> T + Send) -> T {
+    execute_with_threads(f, 1)
+}
+
+/// Executes the given function with the given number of threads,
+/// reusing the current thread pool if it is already of that size.
+#[cfg(not(feature = "serial"))]
+fn execute_with_num_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send, num_threads: usize) -> T {
+    match rayon::current_num_threads() == num_threads {
+        true => f(),
+        false => execute_with_threads(f, num_threads),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_global() {
+        // Ensure invalid configurations are rejected.
+        assert!(AlgorithmsConfig::new().with_fft_threads(0).set_global().is_err());
+        assert!(AlgorithmsConfig::new().with_msm_threads(0).set_global().is_err());
+        assert!(AlgorithmsConfig::new().with_msm_window_size(0).set_global().is_err());
+        assert!(AlgorithmsConfig::new().with_msm_window_size(25).set_global().is_err());
+        assert!(AlgorithmsConfig::new().with_msm_chunk_size(0).set_global().is_err());
+
+        // Ensure a valid configuration is set.
+        let config = AlgorithmsConfig::new()
+            .with_fft_threads(2)
+            .with_msm_threads(2)
+            .with_max_fft_scratch_bytes(0)
+            .with_msm_window_size(3)
+            .with_msm_chunk_size(100);
+        config.set_global().unwrap();
+        assert_eq!(AlgorithmsConfig::global(), config);
+
+        // Ensure the algorithms are correct under the configuration.
+        #[cfg(all(feature = "fft", feature = "msm"))]
+        {
+            use crate::{fft::EvaluationDomain, msm::VariableBase};
+            use snarkvm_curves::{
+                bls12_377::{Fr, G1Affine, G1Projective},
+                AffineCurve,
+                ProjectiveCurve,
+            };
+            use snarkvm_fields::{PrimeField, Zero};
+            use snarkvm_utilities::{BitIteratorBE, TestRng, Uniform};
+
+            let mut rng = TestRng::default();
+
+            // Check an FFT, without compacting the roots of unity.
+            let domain = EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+            let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let evals = domain.fft(&coeffs);
+            for (i, element) in domain.elements().enumerate().step_by(100) {
+                let expected = coeffs.iter().rev().fold(Fr::zero(), |sum, coeff| sum * element + coeff);
+                assert_eq!(evals[i], expected);
+            }
+            assert_eq!(domain.ifft(&evals), coeffs);
+
+            // Check an MSM, in chunks.
+            let bases = (0..250).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+            let scalars = (0..250).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+            let expected = bases
+                .iter()
+                .zip(&scalars)
+                .map(|(base, scalar)| base.mul_bits(BitIteratorBE::new(*scalar)))
+                .sum::<G1Projective>();
+            assert_eq!(VariableBase::msm(&bases, &scalars).to_affine(), expected.to_affine());
+        }
+
+        // Reset the configuration.
+        AlgorithmsConfig::new().set_global().unwrap();
+    }
+}
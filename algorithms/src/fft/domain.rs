@@ -29,12 +29,14 @@ use crate::{
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
+    config::execute_with_fft_threads,
     fft::{DomainCoeff, SparsePolynomial},
+    AlgorithmsConfig,
 };
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(not(feature = "serial"))]
 use snarkvm_utilities::max_available_threads;
-use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
+use snarkvm_utilities::serialize::*;
 
 use rand::Rng;
 use std::{borrow::Cow, fmt};
@@ -166,7 +168,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an FFT, modifying the vector in place.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_with_fft_threads(|| {
             coeffs.resize(self.size(), T::zero());
             self.in_order_fft_in_place(&mut *coeffs);
         });
@@ -182,7 +184,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an IFFT, modifying the vector in place.
     #[inline]
     pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_with_fft_threads(|| {
             evals.resize(self.size(), T::zero());
             self.in_order_ifft_in_place(&mut *evals);
         });
@@ -198,7 +200,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an FFT over a coset of the domain, modifying the input vector
     /// in place.
     pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_with_fft_threads(|| {
             Self::distribute_powers(coeffs, F::multiplicative_generator());
             self.fft_in_place(coeffs);
         });
@@ -213,7 +215,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
     pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_with_fft_threads(|| {
             evals.resize(self.size(), T::zero());
             self.in_order_coset_ifft_in_place(&mut *evals);
         });
@@ -357,14 +359,11 @@ impl<F: FftField> EvaluationDomain<F> {
 
 impl<F: FftField> EvaluationDomain<F> {
     pub fn precompute_fft(&self) -> FFTPrecomputation<F> {
-        execute_with_max_available_threads(|| FFTPrecomputation {
-            roots: self.roots_of_unity(self.group_gen),
-            domain: *self,
-        })
+        execute_with_fft_threads(|| FFTPrecomputation { roots: self.roots_of_unity(self.group_gen), domain: *self })
     }
 
     pub fn precompute_ifft(&self) -> IFFTPrecomputation<F> {
-        execute_with_max_available_threads(|| IFFTPrecomputation {
+        execute_with_fft_threads(|| IFFTPrecomputation {
             inverse_roots: self.roots_of_unity(self.group_gen_inv),
             domain: *self,
         })
@@ -544,11 +543,13 @@ impl<F: FftField> EvaluationDomain<F> {
 
         let log_len = log2(x_s.len());
 
-        if ord == OI {
-            self.oi_helper_with_roots(x_s, &pc.roots);
-        } else {
-            self.io_helper_with_roots(x_s, &pc.roots);
-        }
+        execute_with_fft_threads(|| {
+            if ord == OI {
+                self.oi_helper_with_roots(x_s, &pc.roots);
+            } else {
+                self.io_helper_with_roots(x_s, &pc.roots);
+            }
+        });
 
         if ord == II {
             derange_helper(x_s, log_len);
@@ -573,11 +574,13 @@ impl<F: FftField> EvaluationDomain<F> {
             derange_helper(x_s, log_len);
         }
 
-        if ord == IO {
-            self.io_helper_with_roots(x_s, &pc.inverse_roots);
-        } else {
-            self.oi_helper_with_roots(x_s, &pc.inverse_roots);
-        }
+        execute_with_fft_threads(|| {
+            if ord == IO {
+                self.io_helper_with_roots(x_s, &pc.inverse_roots);
+            } else {
+                self.oi_helper_with_roots(x_s, &pc.inverse_roots);
+            }
+        });
     }
 
     /// Computes the first `self.size / 2` roots of unity for the entire domain.
@@ -689,6 +692,8 @@ impl<F: FftField> EvaluationDomain<F> {
     #[allow(clippy::unnecessary_to_owned)]
     fn io_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F]) {
         let mut roots = std::borrow::Cow::Borrowed(roots);
+        // Only compact roots if the copy of the roots fits in the scratch space.
+        let can_compact = roots.len() <= max_fft_scratch_len::<F>();
 
         let mut step = 1;
         let mut first = true;
@@ -707,7 +712,7 @@ impl<F: FftField> EvaluationDomain<F> {
             // Only compact roots to achieve cache locality/compactness if
             // the roots lookup is done a significant amount of times
             // Which also implies a large lookup stride.
-            if num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION {
+            if can_compact && num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION {
                 if !first {
                     roots = Cow::Owned(cfg_into_iter!(roots.into_owned()).step_by(step * 2).collect());
                 }
@@ -740,7 +745,9 @@ impl<F: FftField> EvaluationDomain<F> {
 
         let compaction_max_size =
             core::cmp::min(roots_cache.len() / 2, roots_cache.len() / MIN_NUM_CHUNKS_FOR_COMPACTION);
-        let mut compacted_roots = vec![F::default(); compaction_max_size];
+        // Only compact roots if the compacted roots fit in the scratch space.
+        let can_compact = compaction_max_size <= max_fft_scratch_len::<F>();
+        let mut compacted_roots = vec![F::default(); if can_compact { compaction_max_size } else { 0 }];
 
         #[cfg(not(feature = "serial"))]
         let max_threads = snarkvm_utilities::parallel::max_available_threads();
@@ -756,7 +763,7 @@ impl<F: FftField> EvaluationDomain<F> {
             // Only compact roots to achieve cache locality/compactness if
             // the roots lookup is done a significant amount of times
             // Which also implies a large lookup stride.
-            let (roots, step) = if num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION && gap < xi.len() / 2 {
+            let (roots, step) = if can_compact && num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION && gap < xi.len() / 2 {
                 cfg_iter_mut!(compacted_roots[..gap])
                     .zip(cfg_iter!(roots_cache[..(gap * num_chunks)]).step_by(num_chunks))
                     .for_each(|(a, b)| *a = *b);
@@ -772,6 +779,13 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 }
 
+/// Returns the maximum number of field elements in the scratch space of an FFT.
+fn max_fft_scratch_len<F>() -> usize {
+    AlgorithmsConfig::global()
+        .max_fft_scratch_bytes()
+        .map_or(usize::MAX, |num_bytes| num_bytes / core::mem::size_of::<F>().max(1))
+}
+
 /// The minimum number of chunks at which root compaction
 /// is beneficial.
 const MIN_NUM_CHUNKS_FOR_COMPACTION: usize = 1 << 7;
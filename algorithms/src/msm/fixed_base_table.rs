@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_iter, BitIteratorLE};
+
+use anyhow::{ensure, Result};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A precomputed window table of multiples of a fixed base, for fast fixed-base scalar multiplication.
+///
+/// The table stores `i * 2^(j * window) * base` for every window `j` and every `i < 2^window`,
+/// so that a scalar multiplication costs one mixed addition per window, and no doublings.
+pub struct FixedBaseTable<G: AffineCurve> {
+    /// The fixed base.
+    base: G,
+    /// The number of bits in each window.
+    window: usize,
+    /// The number of windows.
+    num_windows: usize,
+    /// The multiples of the base, indexed by `j * 2^window + i`.
+    entries: Vec<G>,
+}
+
+impl<G: AffineCurve> FixedBaseTable<G> {
+    /// The maximum number of bits in each window.
+    pub const MAX_WINDOW: usize = 16;
+
+    /// Computes the window table for the given base and window size.
+    pub fn new(base: G, window: usize) -> Result<Self> {
+        ensure!((1..=Self::MAX_WINDOW).contains(&window), "The window must be between 1 and {}", Self::MAX_WINDOW);
+        let num_windows = Self::num_windows(window);
+
+        // Compute `2^(j * window) * base` for each window `j`.
+        let mut window_bases = Vec::with_capacity(num_windows);
+        let mut window_base = base.to_projective();
+        for _ in 0..num_windows {
+            window_bases.push(window_base);
+            for _ in 0..window {
+                window_base.double_in_place();
+            }
+        }
+
+        // Compute `i * 2^(j * window) * base` for each window `j` and each `i < 2^window`.
+        let entries = cfg_iter!(window_bases)
+            .flat_map(|window_base| {
+                let mut multiples = Vec::with_capacity(1 << window);
+                let mut multiple = G::Projective::zero();
+                for _ in 0..(1 << window) {
+                    multiples.push(multiple);
+                    multiple += window_base;
+                }
+                multiples
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Self { base, window, num_windows, entries: G::Projective::batch_normalization_into_affine(entries) })
+    }
+
+    /// Returns the fixed base.
+    pub const fn base(&self) -> G {
+        self.base
+    }
+
+    /// Returns the number of bits in each window.
+    pub const fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns `scalar * base`.
+    pub fn mul(&self, scalar: &G::ScalarField) -> G::Projective {
+        let mut bits = BitIteratorLE::new(scalar.to_bigint());
+
+        let mut result = G::Projective::zero();
+        for j in 0..self.num_windows {
+            // Read the next window of the scalar, in little-endian order.
+            let i = (0..self.window).fold(0usize, |i, k| i | (usize::from(bits.next().unwrap_or(false)) << k));
+            // Add the precomputed multiple for this window.
+            if i != 0 {
+                result.add_assign_mixed(&self.entries[(j << self.window) + i]);
+            }
+        }
+        result
+    }
+
+    /// Returns `scalar * base` for each of the given scalars.
+    pub fn batch_mul(&self, scalars: &[G::ScalarField]) -> Vec<G::Projective> {
+        cfg_iter!(scalars).map(|scalar| self.mul(scalar)).collect()
+    }
+
+    /// Returns the number of windows needed to cover a scalar, for the given window size.
+    fn num_windows(window: usize) -> usize {
+        let scalar_size = <G::ScalarField as PrimeField>::size_in_bits();
+        (scalar_size + window - 1) / window
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::{
+        bls12_377::{Fr, G1Affine},
+        edwards_bls12::{EdwardsAffine, Fr as EdwardsFr},
+    };
+    use snarkvm_fields::One;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_mul() {
+        let rng = &mut TestRng::default();
+
+        for window in [1, 3, 4, 8] {
+            let base = G1Affine::rand(rng);
+            let table = FixedBaseTable::new(base, window).unwrap();
+
+            // Ensure the edge cases are correct.
+            assert_eq!(table.mul(&Fr::zero()), G1Affine::zero().to_projective());
+            assert_eq!(table.mul(&Fr::one()), base.to_projective());
+            assert_eq!(table.mul(&-Fr::one()), base * -Fr::one());
+
+            // Ensure random scalars are correct.
+            let scalars = (0..ITERATIONS).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let expected = scalars.iter().map(|scalar| base * *scalar).collect::<Vec<_>>();
+            assert_eq!(table.batch_mul(&scalars), expected);
+        }
+
+        // Ensure invalid windows are rejected.
+        assert!(FixedBaseTable::new(G1Affine::rand(rng), 0).is_err());
+        assert!(FixedBaseTable::new(G1Affine::rand(rng), FixedBaseTable::<G1Affine>::MAX_WINDOW + 1).is_err());
+    }
+
+    #[test]
+    fn test_mul_edwards() {
+        let rng = &mut TestRng::default();
+
+        let base = EdwardsAffine::rand(rng);
+        let table = FixedBaseTable::new(base, 8).unwrap();
+
+        // Ensure random scalars are correct.
+        for _ in 0..ITERATIONS {
+            let scalar = EdwardsFr::rand(rng);
+            assert_eq!(table.mul(&scalar), base * scalar);
+        }
+    }
+}
@@ -15,6 +15,9 @@
 pub mod fixed_base;
 pub use fixed_base::*;
 
+pub mod fixed_base_table;
+pub use fixed_base_table::*;
+
 #[cfg(test)]
 pub mod tests;
 
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::PrimeField;
+
+/// The number of scalars above which the MSM is offloaded to the GPU.
+/// Up to this size, the cost of transferring the bases and scalars to the device exceeds the speedup.
+pub const GPU_MSM_THRESHOLD: usize = 1 << 10;
+
+/// Computes the MSM on the GPU, returning `None` if the caller should fall back to the CPU.
+///
+/// The GPU is skipped for MSMs of at most `GPU_MSM_THRESHOLD` scalars. A device error or an invalid
+/// result falls back to the CPU for this MSM only, so a transient failure does not disable the GPU.
+/// In debug builds, every result is additionally cross-checked against the CPU implementation.
+pub(super) fn msm<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> Option<G::Projective> {
+    // Ensure the MSM is large enough to benefit from the GPU.
+    if scalars.len() <= GPU_MSM_THRESHOLD {
+        return None;
+    }
+
+    // Compute the MSM on the device.
+    let result = match snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
+        bases, scalars,
+    ) {
+        Ok(result) => result,
+        Err(error) => return fallback(&format!("device error (code {})", error.code)),
+    };
+
+    // Ensure the result is a valid group element, which guards against corrupted device output.
+    let affine = result.to_affine();
+    if !affine.is_on_curve() || !affine.is_in_correct_subgroup_assuming_on_curve() {
+        return fallback("the result is not a valid group element");
+    }
+
+    // Ensure the result matches the CPU implementation.
+    #[cfg(debug_assertions)]
+    if affine != super::batched::msm(bases, scalars).to_affine() {
+        return fallback("the result does not match the CPU implementation");
+    }
+
+    Some(result)
+}
+
+/// Logs the reason the GPU MSM failed, and returns `None` to fall back to the CPU.
+fn fallback<T>(reason: &str) -> Option<T> {
+    tracing::warn!("The GPU MSM failed, falling back to the CPU - {reason}");
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msm::{variable_base::standard, VariableBase};
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    fn sample_msm(size: usize, rng: &mut TestRng) -> (Vec<G1Affine>, Vec<<Fr as PrimeField>::BigInteger>) {
+        let bases = (0..size).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..size).map(|_| Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+        (bases, scalars)
+    }
+
+    #[test]
+    fn test_gpu_msm_threshold() {
+        let mut rng = TestRng::default();
+
+        // Ensure the GPU is skipped up to the threshold, independently of the device.
+        for size in [1, GPU_MSM_THRESHOLD - 1, GPU_MSM_THRESHOLD] {
+            let (bases, scalars) = sample_msm(size, &mut rng);
+            assert!(msm(bases.as_slice(), scalars.as_slice()).is_none(), "MSM size: {size}");
+        }
+    }
+
+    #[test]
+    fn test_gpu_msm_fallback() {
+        let mut rng = TestRng::default();
+
+        let (bases, scalars) = sample_msm(GPU_MSM_THRESHOLD + 1, &mut rng);
+        let expected = standard::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+
+        // Ensure every attempt either matches the CPU result, or falls back to the CPU.
+        for _ in 0..2 {
+            if let Some(result) = msm(bases.as_slice(), scalars.as_slice()) {
+                assert_eq!(expected, result.to_affine());
+            }
+            // Ensure the MSM is correct with or without a device.
+            assert_eq!(expected, VariableBase::msm(bases.as_slice(), scalars.as_slice()).to_affine());
+        }
+    }
+}
@@ -15,9 +15,13 @@
 pub mod batched;
 pub mod standard;
 
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+pub mod gpu;
+
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
+use crate::{config::execute_with_msm_threads, AlgorithmsConfig};
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::PrimeField;
 
@@ -27,17 +31,27 @@ pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        execute_with_msm_threads(|| match AlgorithmsConfig::global().msm_chunk_size() {
+            // If the MSM is larger than the chunk size, compute it in chunks, to bound the memory of each chunk.
+            Some(chunk_size) if bases.len() > chunk_size => bases
+                .chunks(chunk_size)
+                .zip(scalars.chunks(chunk_size))
+                .map(|(bases, scalars)| Self::msm_in_one_chunk(bases, scalars))
+                .sum(),
+            _ => Self::msm_in_one_chunk(bases, scalars),
+        })
+    }
+
+    fn msm_in_one_chunk<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> G::Projective {
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+            // If the GPU is enabled, attempt to offload the MSM, and otherwise fall back to the CPU.
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-            // TODO SNP: where to set the threshold
-            if scalars.len() > 1024 {
-                let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
-                    bases, scalars,
-                );
-                if let Ok(result) = result {
-                    return result;
-                }
+            if let Some(result) = gpu::msm(bases, scalars) {
+                return result;
             }
             batched::msm(bases, scalars)
         }
@@ -67,6 +81,18 @@ impl VariableBase {
     }
 }
 
+/// Returns the window size of an MSM of the given number of scalars,
+/// which is either configured or chosen empirically.
+fn window_size(num_scalars: usize) -> usize {
+    match AlgorithmsConfig::global().msm_window_size() {
+        Some(window_size) => window_size,
+        None => match num_scalars < 32 {
+            true => 1,
+            false => crate::msm::ln_without_floats(num_scalars) + 2,
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,6 +131,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_around_gpu_threshold() {
+        use snarkvm_curves::ProjectiveCurve;
+        let mut rng = TestRng::default();
+        // Ensure the MSM is correct on either side of the GPU threshold (i.e. 1024 scalars), with or without a GPU.
+        for msm_size in [1024, 1025] {
+            let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            let expected = standard::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+            let candidate = VariableBase::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+            assert_eq!(expected, candidate, "MSM size: {msm_size}");
+        }
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
@@ -76,11 +76,8 @@ fn standard_window<G: AffineCurve>(
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-    // Determine the bucket size `c` (chosen empirically).
-    let c = match scalars.len() < 32 {
-        true => 1,
-        false => crate::msm::ln_without_floats(scalars.len()) + 2,
-    };
+    // Determine the bucket size `c`.
+    let c = super::window_size(scalars.len());
 
     let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
 
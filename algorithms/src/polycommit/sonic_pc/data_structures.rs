@@ -395,6 +395,20 @@ impl<E: PairingEngine> BatchProof<E> {
     }
 }
 
+/// The inputs to check a single batch proof, as part of `SonicKZG10::batch_check_many`.
+pub struct BatchCheckInstance<'a, E: PairingEngine, S> {
+    /// The commitments to the opened polynomials.
+    pub commitments: &'a [LabeledCommitment<Commitment<E>>],
+    /// The query set of the openings.
+    pub query_set: &'a QuerySet<E::Fr>,
+    /// The claimed evaluations at the query set.
+    pub evaluations: &'a Evaluations<E::Fr>,
+    /// The batch proof of the openings.
+    pub proof: &'a BatchProof<E>,
+    /// The Fiat-Shamir sponge, in the same state as the prover's sponge when opening.
+    pub fs_rng: &'a mut S,
+}
+
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
 pub type PolynomialLabel = String;
 
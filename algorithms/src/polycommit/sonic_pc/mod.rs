@@ -23,6 +23,7 @@ use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::rand::Uniform;
 
 use anyhow::{bail, ensure, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
@@ -340,6 +341,35 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         batch_proof
     }
 
+    /// Opens the given labeled polynomials at the points in the query set, and returns the evaluations
+    /// along with a single batch proof of all of the openings.
+    pub fn open_query_set<'a>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        query_set: &QuerySet<E::Fr>,
+        rands: impl IntoIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+    ) -> Result<(Evaluations<E::Fr>, BatchProof<E>)>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        let labeled_polynomials = labeled_polynomials.into_iter().collect::<Vec<_>>();
+        let rands = rands.into_iter().collect::<Vec<_>>();
+
+        let evaluations = evaluate_query_set(labeled_polynomials.iter().copied(), query_set);
+        let proof = Self::batch_open(
+            universal_prover,
+            ck,
+            labeled_polynomials.into_iter(),
+            query_set,
+            rands.into_iter(),
+            fs_rng,
+        )?;
+        Ok((evaluations, proof))
+    }
+
     pub fn batch_check<'a>(
         vk: &UniversalVerifier<E>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
@@ -351,62 +381,63 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     where
         Commitment<E>: 'a,
     {
-        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
-        let batch_check_time = start_timer!(|| format!(
-            "Checking {} commitments at query set of size {}",
-            commitments.len(),
-            query_set.len(),
-        ));
-        let mut query_to_labels_map = BTreeMap::new();
-
-        for (label, (point_name, point)) in query_set.iter() {
-            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
-            labels.1.insert(label);
-        }
-
-        assert_eq!(proof.0.len(), query_to_labels_map.len());
-
-        let mut randomizer = E::Fr::one();
-
         let mut combined_comms = BTreeMap::new();
         let mut combined_witness = E::G1Projective::zero();
         let mut combined_adjusted_witness = E::G1Projective::zero();
 
-        ensure!(query_to_labels_map.len() == proof.0.len());
-        for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
-            let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
-            let mut values_to_combine = Vec::new();
-            for label in labels.into_iter() {
-                let commitment =
-                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
-
-                let v_i = values
-                    .get(&(label.clone(), *query))
-                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+        Self::accumulate_batch(
+            &mut combined_comms,
+            &mut combined_witness,
+            &mut combined_adjusted_witness,
+            vk,
+            commitments,
+            query_set,
+            values,
+            proof,
+            E::Fr::one(),
+            fs_rng,
+        )?;
+
+        Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness)
+    }
 
-                comms_to_combine.push(commitment);
-                values_to_combine.push(*v_i);
-            }
+    /// Checks many independent batch proofs with a single product of pairings.
+    ///
+    /// Each instance is checked as in `batch_check`, with its own Fiat-Shamir sponge.
+    /// The instances are combined with random scalars sampled from `rng`,
+    /// which must not be known to the prover.
+    pub fn batch_check_many<'a, R: RngCore>(
+        vk: &UniversalVerifier<E>,
+        instances: impl IntoIterator<Item = BatchCheckInstance<'a, E, S>>,
+        rng: &mut R,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
 
-            Self::accumulate_elems(
+        let mut instance_randomizer = E::Fr::one();
+        for BatchCheckInstance { commitments, query_set, evaluations, proof, fs_rng } in instances {
+            Self::accumulate_batch(
                 &mut combined_comms,
                 &mut combined_witness,
                 &mut combined_adjusted_witness,
                 vk,
-                comms_to_combine.into_iter(),
-                *query,
-                values_to_combine.into_iter(),
-                p,
-                Some(randomizer),
+                commitments,
+                query_set,
+                evaluations,
+                proof,
+                instance_randomizer,
                 fs_rng,
             )?;
-
-            randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+            // We don't need to sample randomizers from the full field,
+            // only from 128-bit strings.
+            instance_randomizer = u128::rand(rng).into();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
-        end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness)
     }
 
     pub fn open_combinations<'a>(
@@ -577,6 +608,74 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 }
 
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    /// Accumulates the pairing elements of a batch proof, scaled by the given `instance_randomizer`.
+    #[allow(clippy::too_many_arguments)]
+    fn accumulate_batch<'a>(
+        combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
+        combined_witness: &mut E::G1Projective,
+        combined_adjusted_witness: &mut E::G1Projective,
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        instance_randomizer: E::Fr,
+        fs_rng: &mut S,
+    ) -> Result<()>
+    where
+        Commitment<E>: 'a,
+    {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let batch_check_time = start_timer!(|| format!(
+            "Checking {} commitments at query set of size {}",
+            commitments.len(),
+            query_set.len(),
+        ));
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        let mut randomizer = E::Fr::one();
+
+        ensure!(query_to_labels_map.len() == proof.0.len());
+        for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
+            let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
+            let mut values_to_combine = Vec::new();
+            for label in labels.into_iter() {
+                let commitment =
+                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                let v_i = values
+                    .get(&(label.clone(), *query))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                comms_to_combine.push(commitment);
+                values_to_combine.push(*v_i);
+            }
+
+            Self::accumulate_elems(
+                combined_comms,
+                combined_witness,
+                combined_adjusted_witness,
+                vk,
+                comms_to_combine.into_iter(),
+                *query,
+                values_to_combine.into_iter(),
+                p,
+                Some(instance_randomizer * randomizer),
+                fs_rng,
+            )?;
+
+            randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        }
+
+        end_timer!(batch_check_time);
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn accumulate_elems<'a>(
         combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
@@ -770,6 +869,12 @@ mod tests {
         println!("Finished bls12-377");
     }
 
+    #[test]
+    fn test_batch_check_many() {
+        batch_check_many_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        println!("Finished bls12-377");
+    }
+
     #[test]
     #[should_panic]
     fn test_bad_degree_bound() {
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use super::sonic_pc::{
+    evaluate_query_set,
+    BatchCheckInstance,
     BatchLCProof,
     BatchProof,
     Commitment,
@@ -128,6 +130,72 @@ pub fn bad_degree_bound_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -
     Ok(())
 }
 
+pub fn batch_check_many_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -> Result<(), PCError> {
+    let rng = &mut TestRng::default();
+    let max_degree = 64;
+    let pp = SonicKZG10::<E, S>::load_srs(max_degree)?;
+    let universal_prover = &pp.to_universal_prover().unwrap();
+    let (ck, vk) = SonicKZG10::<E, S>::trim(&pp, max_degree, None, 1, None).unwrap();
+    let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+    // Open several independent sets of polynomials, each at several points.
+    let mut instances = Vec::new();
+    for _ in 0..4 {
+        let polynomials = (0..3)
+            .map(|i| {
+                let degree = distributions::Uniform::from(1..=max_degree).sample(rng);
+                LabeledPolynomial::new(format!("Test{i}"), DensePolynomial::rand(degree, rng), None, Some(1))
+            })
+            .collect::<Vec<_>>();
+        let (comms, rands) =
+            SonicKZG10::<E, S>::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng))?;
+
+        let mut query_set = QuerySet::new();
+        for point_id in 0..2 {
+            let point = E::Fr::rand(rng);
+            for polynomial in &polynomials {
+                query_set.insert((polynomial.label().to_string(), (format!("rand_{point_id}"), point)));
+            }
+        }
+
+        let (evaluations, proof) = SonicKZG10::open_query_set(
+            universal_prover,
+            &ck,
+            polynomials.iter(),
+            &query_set,
+            rands.iter(),
+            &mut S::new(),
+        )?;
+        assert_eq!(evaluations, evaluate_query_set(polynomials.iter(), &query_set));
+        instances.push((comms, query_set, evaluations, proof));
+    }
+
+    // Checks all instances at once, with the given evaluations.
+    let check_many = |evaluations: &[Evaluations<E::Fr>], rng: &mut TestRng| {
+        let mut sponges = (0..instances.len()).map(|_| S::new()).collect::<Vec<_>>();
+        let batch = instances.iter().zip_eq(evaluations).zip_eq(sponges.iter_mut()).map(
+            |(((commitments, query_set, _, proof), evaluations), fs_rng)| BatchCheckInstance {
+                commitments,
+                query_set,
+                evaluations,
+                proof,
+                fs_rng,
+            },
+        );
+        SonicKZG10::batch_check_many(&vk, batch, rng)
+    };
+
+    // Ensure the valid openings are accepted.
+    let mut evaluations = instances.iter().map(|(_, _, evaluations, _)| evaluations.clone()).collect::<Vec<_>>();
+    assert!(check_many(&evaluations, rng)?);
+
+    // Ensure a single invalid evaluation is rejected.
+    let (_, value) = evaluations[2].iter_mut().next().unwrap();
+    *value += E::Fr::one();
+    assert!(!check_many(&evaluations, rng)?);
+    Ok(())
+}
+
 pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>()
 -> Result<Vec<TestComponents<E, S>>, PCError> {
     let num_iters = 10usize;
@@ -13,22 +13,26 @@
 // limitations under the License.
 
 use crate::{
-    polycommit::sonic_pc,
+    polycommit::{kzg10::KZGProof, sonic_pc},
     snark::varuna::{ahp, CircuitId},
     SNARKError,
 };
 
-use ahp::prover::{FourthMessage, ThirdMessage};
+use ahp::prover::{FourthMessage, MatrixSums, ThirdMessage};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
     serialize::*,
+    FromBits,
     FromBytes,
+    ToBits,
     ToBytes,
 };
 
+use anyhow::{anyhow, bail, ensure, Result};
+
 use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
@@ -370,6 +374,216 @@ impl<E: PairingEngine> FromBytes for Proof<E> {
     }
 }
 
+/// The version of the compressed proof encoding.
+const COMPRESSED_PROOF_VERSION: u8 = 1;
+
+impl<E: PairingEngine> Proof<E> {
+    /// Returns the compressed encoding of the proof.
+    ///
+    /// On top of point compression, the encoding writes the batch sizes as varints, packs the presence flags
+    /// of the optional elements into a bitmap, writes all commitments and evaluation proofs as one contiguous
+    /// block of compressed points, and packs all field elements into `MODULUS_BITS` bits each.
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>> {
+        // Ensure the proof is well-formed.
+        self.check_batch_sizes()?;
+        let kzg_proofs = &self.pc_proof.proof.0;
+
+        // Write the version and the batch sizes.
+        let mut bytes = vec![COMPRESSED_PROOF_VERSION];
+        write_varint(&mut bytes, self.batch_sizes.len() as u64);
+        for batch_size in &self.batch_sizes {
+            write_varint(&mut bytes, *batch_size as u64);
+        }
+        write_varint(&mut bytes, kzg_proofs.len() as u64);
+
+        // Write the presence flags of the optional elements.
+        let flags = std::iter::once(self.commitments.mask_poly.is_some())
+            .chain(kzg_proofs.iter().map(|proof| proof.random_v.is_some()));
+        bytes.extend(pack_bits(flags));
+
+        // Write the group elements.
+        let commitments = &self.commitments;
+        let points = commitments
+            .witness_commitments
+            .iter()
+            .map(|commitment| &commitment.w.0)
+            .chain(commitments.mask_poly.iter().map(|commitment| &commitment.0))
+            .chain([&commitments.h_0.0, &commitments.g_1.0, &commitments.h_1.0])
+            .chain(commitments.g_a_commitments.iter().map(|commitment| &commitment.0))
+            .chain(commitments.g_b_commitments.iter().map(|commitment| &commitment.0))
+            .chain(commitments.g_c_commitments.iter().map(|commitment| &commitment.0))
+            .chain([&commitments.h_2.0])
+            .chain(kzg_proofs.iter().map(|proof| &proof.w));
+        for point in points {
+            point.serialize_compressed(&mut bytes)?;
+        }
+
+        // Write the field elements.
+        let mut scalars = self.evaluations.to_field_elements();
+        for sums in self.third_msg.sums.iter().flatten().chain(&self.fourth_msg.sums) {
+            scalars.extend(sums.iter());
+        }
+        scalars.extend(kzg_proofs.iter().filter_map(|proof| proof.random_v));
+        let num_bits = E::Fr::size_in_bits();
+        bytes.extend(pack_bits(
+            scalars.iter().flat_map(|scalar| scalar.to_bigint().to_bits_le().into_iter().take(num_bits)),
+        ));
+
+        Ok(bytes)
+    }
+
+    /// Returns the proof from its compressed encoding, as produced by `Proof::to_compressed_bytes`.
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+
+        // Read the version and the batch sizes.
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == COMPRESSED_PROOF_VERSION, "Unsupported compressed proof version '{version}'");
+        let num_circuits = read_varint(&mut reader)?;
+        let batch_sizes = (0..num_circuits).map(|_| read_varint(&mut reader)).collect::<Result<Vec<_>>>()?;
+        let num_kzg_proofs = read_varint(&mut reader)?;
+        let total_instances = batch_sizes
+            .iter()
+            .try_fold(0usize, |sum, batch_size| sum.checked_add(*batch_size))
+            .ok_or_else(|| anyhow!("Overflowed while summing the batch sizes"))?;
+
+        // Read the presence flags of the optional elements.
+        let num_flags = num_kzg_proofs.checked_add(1).ok_or_else(|| anyhow!("Too many evaluation proofs"))?;
+        let flags = unpack_bits(&mut reader, num_flags)?;
+        let (has_mask_poly, has_random_v) = (flags[0], &flags[1..]);
+
+        // Read the group elements.
+        let mut read_commitment = || sonic_pc::Commitment::<E>::deserialize_compressed(&mut reader);
+        let witness_commitments = (0..total_instances)
+            .map(|_| Ok(WitnessCommitments { w: read_commitment()? }))
+            .collect::<Result<Vec<_>, SerializationError>>()?;
+        let mask_poly = if has_mask_poly { Some(read_commitment()?) } else { None };
+        let (h_0, g_1, h_1) = (read_commitment()?, read_commitment()?, read_commitment()?);
+        let g_a_commitments = (0..num_circuits).map(|_| read_commitment()).collect::<Result<Vec<_>, _>>()?;
+        let g_b_commitments = (0..num_circuits).map(|_| read_commitment()).collect::<Result<Vec<_>, _>>()?;
+        let g_c_commitments = (0..num_circuits).map(|_| read_commitment()).collect::<Result<Vec<_>, _>>()?;
+        let h_2 = read_commitment()?;
+        let witnesses = (0..num_kzg_proofs)
+            .map(|_| E::G1Affine::deserialize_compressed(&mut reader))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Read the field elements.
+        let num_random_v = has_random_v.iter().filter(|has_random_v| **has_random_v).count();
+        let num_sums = total_instances.checked_add(num_circuits).ok_or_else(|| anyhow!("Too many matrix sums"))?;
+        let num_scalars = num_sums
+            .checked_add(num_circuits)
+            .and_then(|num| num.checked_mul(3))
+            .and_then(|num| num.checked_add(1 + num_random_v))
+            .ok_or_else(|| anyhow!("Too many field elements"))?;
+        let num_bits = E::Fr::size_in_bits();
+        let bits =
+            unpack_bits(&mut reader, num_scalars.checked_mul(num_bits).ok_or_else(|| anyhow!("Too many bits"))?)?;
+        let scalars = bits
+            .chunks(num_bits)
+            .map(|bits| {
+                let bigint = <E::Fr as PrimeField>::BigInteger::from_bits_le(bits)?;
+                E::Fr::from_bigint(bigint).ok_or_else(|| anyhow!("Invalid field element in the compressed proof"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut scalars = scalars.into_iter();
+
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found trailing bytes after the compressed proof");
+
+        // Note: The number of field elements was checked above, so the iterator is never exhausted early.
+        let mut next = |num: usize| scalars.by_ref().take(num).collect::<Vec<_>>();
+        let g_1_eval = next(1)[0];
+        let (g_a_evals, g_b_evals, g_c_evals) = (next(num_circuits), next(num_circuits), next(num_circuits));
+        let mut next_sums = |num: usize| -> Vec<MatrixSums<E::Fr>> {
+            next(3 * num).chunks(3).map(|sums| MatrixSums { sum_a: sums[0], sum_b: sums[1], sum_c: sums[2] }).collect()
+        };
+        let third_sums = batch_sizes.iter().map(|batch_size| next_sums(*batch_size)).collect();
+        let fourth_sums = next_sums(num_circuits);
+        let kzg_proofs = witnesses
+            .into_iter()
+            .zip(has_random_v)
+            .map(|(w, has_random_v)| KZGProof { w, random_v: has_random_v.then(|| scalars.next()).flatten() })
+            .collect();
+
+        let proof = Self {
+            batch_sizes,
+            commitments: Commitments {
+                witness_commitments,
+                mask_poly,
+                h_0,
+                g_1,
+                h_1,
+                g_a_commitments,
+                g_b_commitments,
+                g_c_commitments,
+                h_2,
+            },
+            evaluations: Evaluations { g_1_eval, g_a_evals, g_b_evals, g_c_evals },
+            third_msg: ThirdMessage { sums: third_sums },
+            fourth_msg: FourthMessage { sums: fourth_sums },
+            pc_proof: sonic_pc::BatchLCProof { proof: sonic_pc::BatchProof(kzg_proofs) },
+        };
+        // Ensure the proof is well-formed.
+        proof.check_batch_sizes()?;
+        Ok(proof)
+    }
+}
+
+/// Writes the given integer as an unsigned LEB128 varint.
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 varint.
+fn read_varint(reader: &mut &[u8]) -> Result<usize> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = u8::read_le(&mut *reader)?;
+        // Ensure the varint fits in a `u64`.
+        ensure!(shift < 63 || byte <= 1, "The varint overflows a u64");
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            // Ensure the varint is minimally encoded.
+            ensure!(byte != 0 || shift == 0, "The varint is not minimally encoded");
+            return Ok(usize::try_from(value)?);
+        }
+    }
+    bail!("The varint is too long")
+}
+
+/// Packs the given bits into bytes, in little-endian order, with the last byte padded with zeros.
+fn pack_bits(bits: impl IntoIterator<Item = bool>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for (i, bit) in bits.into_iter().enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0u8);
+        }
+        if bit {
+            let last = bytes.len() - 1;
+            bytes[last] |= 1 << (i % 8);
+        }
+    }
+    bytes
+}
+
+/// Reads `num_bits` bits that were packed with `pack_bits`.
+fn unpack_bits(reader: &mut &[u8], num_bits: usize) -> Result<Vec<bool>> {
+    let num_bytes = num_bits / 8 + usize::from(num_bits % 8 != 0);
+    ensure!(reader.len() >= num_bytes, "Expected {num_bytes} more bytes, found {}", reader.len());
+    let (packed, rest) = reader.split_at(num_bytes);
+    *reader = rest;
+
+    // Ensure the padding bits are zero.
+    if num_bits % 8 != 0 {
+        ensure!(packed[num_bytes - 1] >> (num_bits % 8) == 0, "Found non-zero padding bits");
+    }
+    Ok((0..num_bits).map(|i| (packed[i / 8] >> (i % 8)) & 1 == 1).collect())
+}
+
 #[cfg(test)]
 mod test {
     #![allow(non_camel_case_types)]
@@ -506,4 +720,34 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_compressed_proof() {
+        let rng = &mut TestRng::default();
+
+        for i in 1..6 {
+            for j in 1..6 {
+                let test_with_none = i * j % 2 == 0;
+                let batch_sizes = vec![j; i];
+                let commitments = rand_commitments(j, i, test_with_none);
+                let evaluations: Evaluations<Fr> = rand_evaluations(rng, i);
+                let third_msg = ThirdMessage::<Fr> { sums: vec![vec![rand_sums(rng); j]; i] };
+                let fourth_msg = FourthMessage::<Fr> { sums: vec![rand_sums(rng); i] };
+                let pc_proof =
+                    sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, test_with_none); j]) };
+                let proof = Proof { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof };
+
+                // Ensure the compressed encoding round-trips, and is smaller than the compressed serialization.
+                let compressed = proof.to_compressed_bytes().unwrap();
+                assert_eq!(proof, Proof::from_compressed_bytes(&compressed).unwrap());
+                assert!(compressed.len() < proof.serialized_size(Compress::Yes));
+
+                // Ensure truncated and extended encodings are rejected.
+                assert!(Proof::<Bls12_377>::from_compressed_bytes(&compressed[..compressed.len() - 1]).is_err());
+                let mut extended = compressed.clone();
+                extended.push(0);
+                assert!(Proof::<Bls12_377>::from_compressed_bytes(&extended).is_err());
+            }
+        }
+    }
 }
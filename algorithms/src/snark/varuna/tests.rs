@@ -359,6 +359,35 @@ mod varuna_hiding {
         test_circuit_n_times(num_constraints, num_variables, 100)
     }
 
+    #[cfg(feature = "deterministic_proving")]
+    #[test]
+    fn test_prove_deterministic() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let prove = |seed| {
+            let proof =
+                VarunaInst::prove_deterministic(universal_prover, &fs_parameters, &index_pk, &circuit, seed).unwrap();
+            assert!(
+                VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs.as_slice(), &proof)
+                    .unwrap()
+            );
+            proof.to_bytes_le().unwrap()
+        };
+
+        // Ensure the proofs are identical for the same seed, and differ across seeds.
+        assert_eq!(prove([7u8; 32]), prove([7u8; 32]));
+        assert_ne!(prove([7u8; 32]), prove([8u8; 32]));
+    }
+
     fn test_serde_json(num_constraints: usize, num_variables: usize) {
         let rng = &mut TestRng::default();
 
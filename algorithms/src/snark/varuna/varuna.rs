@@ -194,6 +194,52 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
     }
 }
 
+#[cfg(feature = "deterministic_proving")]
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Returns a proof for the given circuit, where the zero-knowledge randomness is derived from `seed`.
+    ///
+    /// **Warning:** This is for reproducible tests only. Proofs with the same seed share their randomness,
+    /// which breaks zero-knowledge, so this must never be used to prove private inputs. As a safeguard,
+    /// it returns an error in release builds, outside of tests.
+    pub fn prove_deterministic<C: ConstraintSynthesizer<E::Fr>>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        proving_key: &CircuitProvingKey<E, SM>,
+        constraints: &C,
+        seed: [u8; 32],
+    ) -> Result<Proof<E>> {
+        let mut keys_to_constraints = BTreeMap::new();
+        keys_to_constraints.insert(proving_key, std::slice::from_ref(constraints));
+        Self::prove_batch_deterministic(universal_prover, fs_parameters, &keys_to_constraints, seed)
+    }
+
+    /// Returns a batch proof for the given circuits, where the zero-knowledge randomness is derived from `seed`.
+    ///
+    /// **Warning:** This is for reproducible tests only. Proofs with the same seed share their randomness,
+    /// which breaks zero-knowledge, so this must never be used to prove private inputs. As a safeguard,
+    /// it returns an error in release builds, outside of tests.
+    pub fn prove_batch_deterministic<C: ConstraintSynthesizer<E::Fr>>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        seed: [u8; 32],
+    ) -> Result<Proof<E>> {
+        use rand::SeedableRng;
+
+        // Deterministic proving reuses the zero-knowledge randomness across proofs, so it is restricted to tests.
+        ensure!(cfg!(any(test, debug_assertions)), "Deterministic proving is only available in tests and debug builds");
+
+        let zk_rng = &mut rand_chacha::ChaChaRng::from_seed(seed);
+        <Self as SNARK>::prove_batch(universal_prover, fs_parameters, keys_to_constraints, zk_rng)
+    }
+}
+
 impl<E: PairingEngine, FS, SM> SNARK for VarunaSNARK<E, FS, SM>
 where
     E::Fr: PrimeField,
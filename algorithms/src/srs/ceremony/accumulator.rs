@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The state of a powers-of-tau ceremony for the universal SRS.
+///
+/// The accumulator holds the same powers as the universal SRS, for secrets `beta` and `gamma`
+/// that are the products of the secrets of every participant so far.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Accumulator<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub(super) powers_of_beta_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `num_gamma_powers - 1`.
+    pub(super) powers_of_beta_times_gamma_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^{-(max_degree - d)} H }`, for each supported degree bound `d`.
+    pub(super) negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+    /// \beta times the generator of G2.
+    pub(super) beta_h: E::G2Affine,
+}
+
+impl<E: PairingEngine> Accumulator<E> {
+    /// Initializes the accumulator for a new ceremony, where `beta` and `gamma` are both one.
+    pub fn new(max_degree: usize, num_gamma_powers: usize, supported_degree_bounds: &[usize]) -> Result<Self> {
+        // Ensure the dimensions are valid.
+        check_dimensions(max_degree, num_gamma_powers, supported_degree_bounds)?;
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        Ok(Self {
+            powers_of_beta_g: vec![g; max_degree + 1],
+            powers_of_beta_times_gamma_g: vec![g; num_gamma_powers],
+            negative_powers_of_beta_h: supported_degree_bounds.iter().map(|d| (*d, h)).collect(),
+            beta_h: h,
+        })
+    }
+
+    /// Returns the maximum degree supported by the accumulator.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_beta_g.len() - 1
+    }
+
+    /// Returns the group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub fn powers_of_beta_g(&self) -> &[E::G1Affine] {
+        &self.powers_of_beta_g
+    }
+
+    /// Returns the group elements of the form `{ \beta^i \gamma G }`.
+    pub fn powers_of_beta_times_gamma_g(&self) -> &[E::G1Affine] {
+        &self.powers_of_beta_times_gamma_g
+    }
+
+    /// Returns the group elements of the form `{ \beta^{-(max_degree - d)} H }`, for each supported degree bound `d`.
+    pub fn negative_powers_of_beta_h(&self) -> &BTreeMap<usize, E::G2Affine> {
+        &self.negative_powers_of_beta_h
+    }
+
+    /// Returns the generator of G2.
+    pub fn h(&self) -> E::G2Affine {
+        E::G2Affine::prime_subgroup_generator()
+    }
+
+    /// Returns \beta times the generator of G2.
+    pub fn beta_h(&self) -> E::G2Affine {
+        self.beta_h
+    }
+
+    /// Updates the accumulator with fresh secrets sampled from the given RNG,
+    /// and returns the contribution that proves knowledge of them.
+    ///
+    /// The caller must not retain the RNG state, as anyone who learns the secrets can forge proofs.
+    pub fn contribute<R: Rng + CryptoRng>(&mut self, rng: &mut R) -> Result<Contribution<E>> {
+        // Sample the secrets.
+        let sample_nonzero = |rng: &mut R| loop {
+            let secret = E::Fr::rand(rng);
+            if !secret.is_zero() {
+                break secret;
+            }
+        };
+        let beta_secret = sample_nonzero(rng);
+        let gamma_secret = sample_nonzero(rng);
+
+        // Record the elements before the update.
+        let (previous_beta_g, previous_gamma_g) = (self.beta_g(), self.gamma_g());
+        // Update the accumulator.
+        self.apply(beta_secret, gamma_secret);
+
+        // Prove knowledge of the secrets.
+        let beta_proof = ProofOfKnowledge::prove(previous_beta_g, self.beta_g(), beta_secret, rng)?;
+        let gamma_proof = ProofOfKnowledge::prove(previous_gamma_g, self.gamma_g(), gamma_secret, rng)?;
+
+        Ok(Contribution::new(self.beta_g(), self.gamma_g(), beta_proof, gamma_proof))
+    }
+
+    /// Updates the accumulator with secrets derived from the given public randomness beacon.
+    ///
+    /// The beacon should be a value that no participant can predict or influence, which is fixed
+    /// before the ceremony ends (for example, a future block hash).
+    pub fn apply_beacon(&mut self, beacon: &[u8]) -> Result<()> {
+        // Derive the secrets from the beacon.
+        let (beta_secret, gamma_secret) = beacon_secrets::<E>(self.beta_g(), self.gamma_g(), beacon)?;
+        // Update the accumulator.
+        self.apply(beta_secret, gamma_secret);
+        Ok(())
+    }
+
+    /// Checks that `next` is the result of applying the given contribution to this accumulator.
+    pub fn verify_contribution<R: Rng>(&self, next: &Self, contribution: &Contribution<E>, rng: &mut R) -> Result<()> {
+        // Ensure the dimensions are unchanged.
+        ensure!(self.has_same_dimensions(next), "The contribution changed the dimensions of the accumulator");
+        // Ensure the next accumulator is well-formed.
+        next.verify(rng)?;
+        // Ensure the contribution matches the next accumulator.
+        ensure!(
+            contribution.beta_g() == next.beta_g() && contribution.gamma_g() == next.gamma_g(),
+            "The contribution does not match the next accumulator"
+        );
+        // Ensure the contribution proves knowledge of the secrets.
+        contribution.verify(self.beta_g(), self.gamma_g())
+    }
+
+    /// Checks that the accumulator is well-formed, i.e. that every element is a power of the same `beta` and `gamma`.
+    pub fn verify<R: Rng>(&self, rng: &mut R) -> Result<()> {
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = self.h();
+
+        // Ensure the dimensions are valid.
+        ensure!(!self.powers_of_beta_g.is_empty(), "The accumulator must have at least one power of beta");
+        check_dimensions(
+            self.max_degree(),
+            self.powers_of_beta_times_gamma_g.len(),
+            &self.negative_powers_of_beta_h.keys().copied().collect::<Vec<_>>(),
+        )?;
+        // Ensure the first power is the generator.
+        ensure!(self.powers_of_beta_g[0] == g, "The first power of beta must be the generator of G1");
+        // Ensure the secrets are not zero.
+        ensure!(!self.beta_h.is_zero(), "The accumulator must not have a zero beta");
+        ensure!(!self.gamma_g().is_zero(), "The accumulator must not have a zero gamma");
+
+        let mut batch = BatchPairingCheck::<E>::new();
+
+        // Check that each consecutive pair of powers differs by a factor of `beta`, using a random linear combination:
+        // e(\sum_i r_i \beta^{i+1} G, H) == e(\sum_i r_i \beta^i G, \beta H).
+        for powers in [&self.powers_of_beta_g, &self.powers_of_beta_times_gamma_g] {
+            if powers.len() > 1 {
+                let coefficients =
+                    (1..powers.len()).map(|_| E::Fr::from(rng.gen::<u128>()).to_bigint()).collect::<Vec<_>>();
+                let shifted = VariableBase::msm(&powers[1..], &coefficients).to_affine();
+                let unshifted = VariableBase::msm(&powers[..powers.len() - 1], &coefficients).to_affine();
+                batch.add_equation([(shifted, h), (-unshifted, self.beta_h)], rng);
+            }
+        }
+
+        // Check that each negative power of beta cancels the corresponding power of beta:
+        // e(\beta^{max_degree - d} G, \beta^{-(max_degree - d)} H) == e(G, H).
+        for (degree_bound, negative_power) in &self.negative_powers_of_beta_h {
+            let power = self.powers_of_beta_g[self.max_degree() - degree_bound];
+            batch.add_equation([(power, *negative_power), (-g, h)], rng);
+        }
+
+        ensure!(batch.verify(), "The accumulator is not well-formed");
+        Ok(())
+    }
+
+    /// Returns \beta times the generator of G1.
+    pub(super) fn beta_g(&self) -> E::G1Affine {
+        self.powers_of_beta_g[1]
+    }
+
+    /// Returns \gamma times the generator of G1.
+    pub(super) fn gamma_g(&self) -> E::G1Affine {
+        self.powers_of_beta_times_gamma_g[0]
+    }
+
+    /// Returns `true` if the given accumulator has the same dimensions as this accumulator.
+    pub(super) fn has_same_dimensions(&self, other: &Self) -> bool {
+        self.powers_of_beta_g.len() == other.powers_of_beta_g.len()
+            && self.powers_of_beta_times_gamma_g.len() == other.powers_of_beta_times_gamma_g.len()
+            && self.negative_powers_of_beta_h.keys().eq(other.negative_powers_of_beta_h.keys())
+    }
+
+    /// Multiplies `beta` by `beta_secret`, and `gamma` by `gamma_secret`.
+    pub(super) fn apply(&mut self, beta_secret: E::Fr, gamma_secret: E::Fr) {
+        let max_degree = self.max_degree();
+
+        // Compute the powers of the beta secret.
+        let mut powers_of_secret = Vec::with_capacity(max_degree + 1);
+        let mut power = E::Fr::one();
+        for _ in 0..=max_degree {
+            powers_of_secret.push(power);
+            power *= beta_secret;
+        }
+
+        // Update the powers of beta, and the powers of beta times gamma.
+        scale(&mut self.powers_of_beta_g, &powers_of_secret);
+        let gamma_scalars =
+            powers_of_secret.iter().take(self.powers_of_beta_times_gamma_g.len()).map(|p| *p * gamma_secret);
+        scale(&mut self.powers_of_beta_times_gamma_g, &gamma_scalars.collect::<Vec<_>>());
+
+        // Update the negative powers of beta.
+        // Note: The inverse exists, as the beta secret is not zero.
+        if let Some(inverse) = beta_secret.inverse() {
+            for (degree_bound, negative_power) in self.negative_powers_of_beta_h.iter_mut() {
+                let exponent = [(max_degree - degree_bound) as u64];
+                *negative_power = (*negative_power * inverse.pow(exponent)).into();
+            }
+        }
+
+        // Update beta times the generator of G2.
+        self.beta_h = (self.beta_h * beta_secret).into();
+    }
+}
+
+/// Ensures the given dimensions describe a valid accumulator.
+pub(super) fn check_dimensions(
+    max_degree: usize,
+    num_gamma_powers: usize,
+    supported_degree_bounds: &[usize],
+) -> Result<()> {
+    ensure!(max_degree > 0, "The maximum degree must be greater than 0");
+    ensure!(num_gamma_powers > 0, "The number of powers of beta times gamma must be greater than 0");
+    ensure!(num_gamma_powers <= max_degree + 1, "The number of powers of beta times gamma exceeds the maximum degree");
+    for degree_bound in supported_degree_bounds {
+        ensure!(*degree_bound <= max_degree, "The degree bound {degree_bound} exceeds the maximum degree {max_degree}");
+    }
+    Ok(())
+}
+
+/// Returns the secrets derived from the given randomness beacon, for an accumulator with the given elements.
+pub(super) fn beacon_secrets<E: PairingEngine>(
+    beta_g: E::G1Affine,
+    gamma_g: E::G1Affine,
+    beacon: &[u8],
+) -> Result<(E::Fr, E::Fr)> {
+    let input = [beta_g.to_bytes_le()?, gamma_g.to_bytes_le()?, beacon.to_vec()].concat();
+    let beta_secret = hash_to_scalar::<E::Fr>(BEACON_DOMAIN, &[&[0u8][..], &input[..]].concat());
+    let gamma_secret = hash_to_scalar::<E::Fr>(BEACON_DOMAIN, &[&[1u8][..], &input[..]].concat());
+    // Ensure the secrets are not zero.
+    if beta_secret.is_zero() || gamma_secret.is_zero() {
+        bail!("The randomness beacon produced a zero secret");
+    }
+    Ok((beta_secret, gamma_secret))
+}
+
+/// Multiplies each element by the corresponding scalar.
+fn scale<G: AffineCurve>(elements: &mut [G], scalars: &[G::ScalarField]) {
+    let mut projective = vec![G::Projective::zero(); elements.len()];
+    cfg_iter_mut!(projective).zip(cfg_iter!(elements)).zip(cfg_iter!(scalars)).for_each(|((p, e), s)| *p = *e * *s);
+    elements.copy_from_slice(&G::Projective::batch_normalization_into_affine(projective));
+}
+
+impl<E: PairingEngine> ToBytes for Accumulator<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("Failed to serialize the ceremony accumulator"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Accumulator<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("Failed to deserialize the ceremony accumulator"))
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Schnorr proof of knowledge of the secret `x` such that `element = x * base`.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ProofOfKnowledge<E: PairingEngine> {
+    /// The commitment to the nonce, i.e. `r * base`.
+    commitment: E::G1Affine,
+    /// The response, i.e. `r + challenge * x`.
+    response: E::Fr,
+}
+
+impl<E: PairingEngine> ProofOfKnowledge<E> {
+    /// Returns a proof of knowledge of the given secret, where `element = secret * base`.
+    pub fn prove<R: Rng + CryptoRng>(
+        base: E::G1Affine,
+        element: E::G1Affine,
+        secret: E::Fr,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample a random nonce, and commit to it.
+        let nonce = E::Fr::rand(rng);
+        let commitment = (base * nonce).to_affine();
+        // Compute the challenge.
+        let challenge = Self::challenge(base, element, commitment)?;
+        // Compute the response.
+        Ok(Self { commitment, response: nonce + challenge * secret })
+    }
+
+    /// Checks that the proof shows knowledge of the secret `x` such that `element = x * base`.
+    pub fn verify(&self, base: E::G1Affine, element: E::G1Affine) -> Result<()> {
+        // Ensure the elements are not zero.
+        ensure!(!base.is_zero() && !element.is_zero(), "The proof of knowledge is for a zero element");
+        // Compute the challenge.
+        let challenge = Self::challenge(base, element, self.commitment)?;
+        // Ensure `response * base == commitment + challenge * element`.
+        ensure!(
+            base * self.response == self.commitment.to_projective() + element * challenge,
+            "The proof of knowledge is invalid"
+        );
+        Ok(())
+    }
+
+    /// Returns the challenge for the given base, element, and commitment.
+    fn challenge(base: E::G1Affine, element: E::G1Affine, commitment: E::G1Affine) -> Result<E::Fr> {
+        let input = [base.to_bytes_le()?, element.to_bytes_le()?, commitment.to_bytes_le()?].concat();
+        Ok(hash_to_scalar(PROOF_OF_KNOWLEDGE_DOMAIN, &input))
+    }
+}
+
+/// The contribution of one participant to the ceremony.
+///
+/// A contribution records `beta G` and `gamma G` after the update, along with proofs that the
+/// participant knows the secrets that they multiplied the previous `beta` and `gamma` by.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Contribution<E: PairingEngine> {
+    /// \beta times the generator of G1, after the contribution.
+    beta_g: E::G1Affine,
+    /// \gamma times the generator of G1, after the contribution.
+    gamma_g: E::G1Affine,
+    /// The proof of knowledge of the secret that \beta was multiplied by.
+    beta_proof: ProofOfKnowledge<E>,
+    /// The proof of knowledge of the secret that \gamma was multiplied by.
+    gamma_proof: ProofOfKnowledge<E>,
+}
+
+impl<E: PairingEngine> Contribution<E> {
+    /// Initializes a new contribution.
+    pub(super) fn new(
+        beta_g: E::G1Affine,
+        gamma_g: E::G1Affine,
+        beta_proof: ProofOfKnowledge<E>,
+        gamma_proof: ProofOfKnowledge<E>,
+    ) -> Self {
+        Self { beta_g, gamma_g, beta_proof, gamma_proof }
+    }
+
+    /// Returns \beta times the generator of G1, after the contribution.
+    pub fn beta_g(&self) -> E::G1Affine {
+        self.beta_g
+    }
+
+    /// Returns \gamma times the generator of G1, after the contribution.
+    pub fn gamma_g(&self) -> E::G1Affine {
+        self.gamma_g
+    }
+
+    /// Checks that the contribution proves knowledge of the secrets that take
+    /// the given previous elements to the elements of this contribution.
+    pub fn verify(&self, previous_beta_g: E::G1Affine, previous_gamma_g: E::G1Affine) -> Result<()> {
+        self.beta_proof.verify(previous_beta_g, self.beta_g)?;
+        self.gamma_proof.verify(previous_gamma_g, self.gamma_g)
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Contribution<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("Failed to serialize the ceremony contribution"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Contribution<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("Failed to deserialize the ceremony contribution"))
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A powers-of-tau ceremony for the universal SRS.
+//!
+//! Each participant updates the [`Accumulator`] with fresh secrets, and publishes a [`Contribution`]
+//! that proves knowledge of those secrets. After the last participant, a public randomness beacon may
+//! be applied, so that the final parameters are not chosen by the last participant alone.
+//! The [`Transcript`] of contributions can then be verified against the final accumulator by anyone.
+
+mod accumulator;
+pub use accumulator::*;
+
+mod contribution;
+pub use contribution::*;
+
+mod transcript;
+pub use transcript::*;
+
+use crate::msm::VariableBase;
+use snarkvm_curves::{AffineCurve, BatchPairingCheck, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    cfg_iter,
+    cfg_iter_mut,
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+    Uniform,
+};
+
+use anyhow::{bail, ensure, Result};
+use blake2::{Blake2b512, Digest};
+use rand::{CryptoRng, Rng};
+use std::collections::BTreeMap;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The domain separator for the proofs of knowledge of the contributed secrets.
+const PROOF_OF_KNOWLEDGE_DOMAIN: &[u8] = b"AleoSetupCeremonyProofOfKnowledge0";
+/// The domain separator for the secrets derived from a randomness beacon.
+const BEACON_DOMAIN: &[u8] = b"AleoSetupCeremonyBeacon0";
+
+/// Returns a scalar derived from the hash of the given domain separator and input.
+fn hash_to_scalar<F: PrimeField>(domain: &[u8], input: &[u8]) -> F {
+    let mut hasher = Blake2b512::new();
+    hasher.update((domain.len() as u64).to_le_bytes());
+    hasher.update(domain);
+    hasher.update(input);
+    F::from_bytes_le_mod_order(&hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr, G1Affine};
+    use snarkvm_utilities::rand::TestRng;
+
+    type E = Bls12_377;
+
+    const MAX_DEGREE: usize = 32;
+    const NUM_GAMMA_POWERS: usize = 4;
+    const DEGREE_BOUNDS: [usize; 3] = [1, 15, 31];
+
+    /// Runs a ceremony with the given number of participants, and returns the final accumulator and transcript.
+    fn run_ceremony(
+        num_participants: usize,
+        beacon: Option<&[u8]>,
+        rng: &mut TestRng,
+    ) -> (Accumulator<E>, Transcript<E>) {
+        let mut accumulator = Accumulator::<E>::new(MAX_DEGREE, NUM_GAMMA_POWERS, &DEGREE_BOUNDS).unwrap();
+        let mut transcript = Transcript::<E>::new(MAX_DEGREE, NUM_GAMMA_POWERS, &DEGREE_BOUNDS).unwrap();
+        for _ in 0..num_participants {
+            let previous = accumulator.clone();
+            let contribution = accumulator.contribute(rng).unwrap();
+            // Ensure the coordinator accepts the contribution.
+            previous.verify_contribution(&accumulator, &contribution, rng).unwrap();
+            transcript.add_contribution(contribution).unwrap();
+        }
+        if let Some(beacon) = beacon {
+            accumulator.apply_beacon(beacon).unwrap();
+            transcript.set_beacon(beacon.to_vec()).unwrap();
+        }
+        (accumulator, transcript)
+    }
+
+    #[test]
+    fn test_ceremony() {
+        let mut rng = TestRng::default();
+
+        for num_participants in 0..4 {
+            for beacon in [None, Some(&b"beacon"[..])] {
+                let (accumulator, transcript) = run_ceremony(num_participants, beacon, &mut rng);
+                assert_eq!(num_participants, transcript.contributions().len());
+                // Ensure the transcript is valid for the final accumulator.
+                transcript.verify(&accumulator, &mut rng).unwrap();
+                // Ensure the powers are consistent with the pairings.
+                let powers = accumulator.powers_of_beta_g();
+                assert_eq!(MAX_DEGREE + 1, powers.len());
+                assert_eq!(G1Affine::prime_subgroup_generator(), powers[0]);
+                assert_eq!(E::pairing(powers[1], accumulator.h()), E::pairing(powers[0], accumulator.beta_h()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ceremony_rejects_tampering() {
+        let mut rng = TestRng::default();
+
+        let (accumulator, transcript) = run_ceremony(2, Some(b"beacon"), &mut rng);
+
+        // Ensure a tampered power of beta is rejected.
+        let mut tampered = accumulator.clone();
+        tampered.powers_of_beta_g[MAX_DEGREE / 2] = (tampered.powers_of_beta_g[MAX_DEGREE / 2] * Fr::from(2u64)).into();
+        assert!(tampered.verify(&mut rng).is_err());
+        assert!(transcript.verify(&tampered, &mut rng).is_err());
+
+        // Ensure a tampered power of beta times gamma is rejected.
+        let mut tampered = accumulator.clone();
+        tampered.powers_of_beta_times_gamma_g[1] = G1Affine::rand(&mut rng);
+        assert!(transcript.verify(&tampered, &mut rng).is_err());
+
+        // Ensure a tampered negative power of beta is rejected.
+        let mut tampered = accumulator.clone();
+        tampered.negative_powers_of_beta_h.values_mut().for_each(|h| *h = (*h * Fr::from(2u64)).into());
+        assert!(transcript.verify(&tampered, &mut rng).is_err());
+
+        // Ensure an accumulator that was rescaled outside of the transcript is rejected.
+        let mut rescaled = accumulator.clone();
+        rescaled.apply(Fr::rand(&mut rng), Fr::rand(&mut rng));
+        assert!(rescaled.verify(&mut rng).is_ok());
+        assert!(transcript.verify(&rescaled, &mut rng).is_err());
+
+        // Ensure a different beacon is rejected.
+        let mut wrong_beacon = Transcript::<E>::new(MAX_DEGREE, NUM_GAMMA_POWERS, &DEGREE_BOUNDS).unwrap();
+        transcript.contributions().iter().for_each(|c| wrong_beacon.add_contribution(c.clone()).unwrap());
+        wrong_beacon.set_beacon(b"different".to_vec()).unwrap();
+        assert!(wrong_beacon.verify(&accumulator, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_contribution_rejects_wrong_accumulator() {
+        let mut rng = TestRng::default();
+
+        let initial = Accumulator::<E>::new(MAX_DEGREE, NUM_GAMMA_POWERS, &DEGREE_BOUNDS).unwrap();
+
+        // Ensure a contribution does not verify for an accumulator that it did not produce.
+        let mut first = initial.clone();
+        let first_contribution = first.contribute(&mut rng).unwrap();
+        let mut second = initial.clone();
+        let _ = second.contribute(&mut rng).unwrap();
+        assert!(initial.verify_contribution(&first, &first_contribution, &mut rng).is_ok());
+        assert!(initial.verify_contribution(&second, &first_contribution, &mut rng).is_err());
+
+        // Ensure a contribution does not verify against the wrong previous accumulator.
+        assert!(second.verify_contribution(&first, &first_contribution, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        let (accumulator, transcript) = run_ceremony(2, Some(b"beacon"), &mut rng);
+
+        let bytes = accumulator.to_bytes_le().unwrap();
+        assert_eq!(accumulator, Accumulator::read_le(&bytes[..]).unwrap());
+
+        let bytes = transcript.to_bytes_le().unwrap();
+        assert_eq!(transcript, Transcript::read_le(&bytes[..]).unwrap());
+
+        for contribution in transcript.contributions() {
+            let bytes = contribution.to_bytes_le().unwrap();
+            assert_eq!(*contribution, Contribution::read_le(&bytes[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The public transcript of a ceremony, which allows anyone to verify the final accumulator.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Transcript<E: PairingEngine> {
+    /// The maximum degree supported by the ceremony.
+    max_degree: usize,
+    /// The number of powers of beta times gamma in the ceremony.
+    num_gamma_powers: usize,
+    /// The degree bounds supported by the ceremony.
+    supported_degree_bounds: Vec<usize>,
+    /// The contributions, in the order in which they were applied.
+    contributions: Vec<Contribution<E>>,
+    /// The randomness beacon that was applied after the last contribution, if any.
+    beacon: Option<Vec<u8>>,
+}
+
+impl<E: PairingEngine> Transcript<E> {
+    /// Initializes the transcript for a new ceremony with the given dimensions.
+    pub fn new(max_degree: usize, num_gamma_powers: usize, supported_degree_bounds: &[usize]) -> Result<Self> {
+        // Ensure the dimensions are valid.
+        check_dimensions(max_degree, num_gamma_powers, supported_degree_bounds)?;
+        // Sort and deduplicate the degree bounds, to match the accumulator.
+        let mut supported_degree_bounds = supported_degree_bounds.to_vec();
+        supported_degree_bounds.sort_unstable();
+        supported_degree_bounds.dedup();
+
+        Ok(Self { max_degree, num_gamma_powers, supported_degree_bounds, contributions: vec![], beacon: None })
+    }
+
+    /// Returns the contributions, in the order in which they were applied.
+    pub fn contributions(&self) -> &[Contribution<E>] {
+        &self.contributions
+    }
+
+    /// Returns the randomness beacon that was applied after the last contribution, if any.
+    pub fn beacon(&self) -> Option<&[u8]> {
+        self.beacon.as_deref()
+    }
+
+    /// Appends the given contribution to the transcript.
+    pub fn add_contribution(&mut self, contribution: Contribution<E>) -> Result<()> {
+        // Ensure the ceremony has not been finalized.
+        ensure!(self.beacon.is_none(), "Cannot add a contribution after the randomness beacon");
+        self.contributions.push(contribution);
+        Ok(())
+    }
+
+    /// Records the randomness beacon that was applied after the last contribution, which finalizes the transcript.
+    pub fn set_beacon(&mut self, beacon: Vec<u8>) -> Result<()> {
+        // Ensure the ceremony has not been finalized.
+        ensure!(self.beacon.is_none(), "The randomness beacon has already been applied");
+        self.beacon = Some(beacon);
+        Ok(())
+    }
+
+    /// Checks that the given accumulator is the result of applying every contribution in the transcript,
+    /// followed by the randomness beacon (if any), to the initial accumulator.
+    pub fn verify<R: Rng>(&self, accumulator: &Accumulator<E>, rng: &mut R) -> Result<()> {
+        // Ensure the accumulator is well-formed, and has the dimensions of the ceremony.
+        accumulator.verify(rng)?;
+        ensure!(
+            accumulator.max_degree() == self.max_degree
+                && accumulator.powers_of_beta_times_gamma_g().len() == self.num_gamma_powers
+                && accumulator.negative_powers_of_beta_h().keys().eq(&self.supported_degree_bounds),
+            "The accumulator does not have the dimensions of the ceremony"
+        );
+
+        // Initialize the elements of the initial accumulator, where `beta` and `gamma` are both one.
+        let mut beta_g = E::G1Affine::prime_subgroup_generator();
+        let mut gamma_g = E::G1Affine::prime_subgroup_generator();
+
+        // Ensure each contribution proves knowledge of the secrets that take the previous elements to the next.
+        for (i, contribution) in self.contributions.iter().enumerate() {
+            if let Err(error) = contribution.verify(beta_g, gamma_g) {
+                bail!("Contribution {i} is invalid - {error}");
+            }
+            beta_g = contribution.beta_g();
+            gamma_g = contribution.gamma_g();
+        }
+
+        // Apply the randomness beacon, if any.
+        if let Some(beacon) = &self.beacon {
+            let (beta_secret, gamma_secret) = beacon_secrets::<E>(beta_g, gamma_g, beacon)?;
+            beta_g = (beta_g * beta_secret).to_affine();
+            gamma_g = (gamma_g * gamma_secret).to_affine();
+        }
+
+        // Ensure the accumulator matches the final elements.
+        ensure!(
+            accumulator.beta_g() == beta_g && accumulator.gamma_g() == gamma_g,
+            "The accumulator does not match the transcript"
+        );
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Transcript<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("Failed to serialize the ceremony transcript"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Transcript<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("Failed to deserialize the ceremony transcript"))
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod ceremony;
+pub use ceremony::*;
+
 pub mod universal_prover;
 pub use universal_prover::*;
 
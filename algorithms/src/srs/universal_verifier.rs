@@ -13,7 +13,8 @@
 // limitations under the License.
 
 use crate::polycommit::kzg10;
-use snarkvm_curves::{PairingCurve, PairingEngine};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine};
+use snarkvm_parameters::testnet3::PowersOfG;
 
 use std::{collections::BTreeMap, sync::Arc};
 
@@ -27,3 +28,36 @@ pub struct UniversalVerifier<E: PairingEngine> {
     /// and `i` is of the form `2^k - 1` for `k` in `1` to `log_2(max_degree)`.
     pub prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>>,
 }
+
+impl<E: PairingEngine> UniversalVerifier<E> {
+    /// Loads the universal verifier, without loading the powers of beta G that are only needed for proving.
+    pub fn load() -> anyhow::Result<Self> {
+        let powers = PowersOfG::<E>::load_verifier()?;
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        Ok(Self {
+            vk: kzg10::VerifierKey::<E> {
+                g: powers.g,
+                gamma_g: powers.gamma_g,
+                h,
+                beta_h: powers.beta_h,
+                prepared_h: h.prepare(),
+                prepared_beta_h: powers.beta_h.prepare(),
+            },
+            prepared_negative_powers_of_beta_h: powers.prepared_negative_powers_of_beta_h,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    #[test]
+    fn test_load_matches_universal_params() {
+        let expected = kzg10::UniversalParams::<Bls12_377>::load().unwrap().to_universal_verifier().unwrap();
+        let candidate = UniversalVerifier::<Bls12_377>::load().unwrap();
+        assert_eq!(candidate, expected);
+    }
+}
@@ -85,4 +85,35 @@ mod tests {
     fn test_hash_private() -> Result<()> {
         check_hash::<32, 48>(Mode::Private, 470, 0, 8774, 8776)
     }
+
+    #[test]
+    fn test_hash_custom() -> Result<()> {
+        use console::Hash as H;
+
+        // Initialize a BHP with 32 windows of size 48 at runtime.
+        let native = console::CustomBHP::<<Circuit as Environment>::Network>::setup_custom(DOMAIN, 32, 48)?;
+        let circuit = CustomBHP::<Circuit>::new(Mode::Constant, native.clone());
+        // Ensure it matches the const-generic BHP with the same parameters.
+        let preset = console::BHP::<<Circuit as Environment>::Network, 32, 48>::setup(DOMAIN)?;
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..32 * 48 * BHP_CHUNK_SIZE).map(|_| bool::rand(&mut rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input)?;
+            assert_eq!(expected, preset.hash(&input)?);
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(Mode::Private, input);
+
+            Circuit::scope(format!("BHP {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
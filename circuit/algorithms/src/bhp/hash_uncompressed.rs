@@ -26,7 +26,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
         // The number of hasher bits to fit.
-        let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+        let num_hasher_bits = self.hasher.num_windows() as usize * self.hasher.window_size() as usize * BHP_CHUNK_SIZE;
         // The number of data bits in the output.
         let num_data_bits = E::BaseField::size_in_data_bits();
         // The maximum number of input bits per iteration.
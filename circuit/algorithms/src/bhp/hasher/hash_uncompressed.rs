@@ -28,12 +28,12 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
         // Ensure the input size is at least the window size.
-        if input.len() <= Self::MIN_BITS {
-            E::halt(format!("Inputs to this BHP must be greater than {} bits", Self::MIN_BITS))
+        if input.len() <= self.min_bits() {
+            E::halt(format!("Inputs to this BHP must be greater than {} bits", self.min_bits()))
         }
 
         // Ensure the input size is within the parameter size.
-        let input = match input.len() <= Self::MAX_BITS {
+        let input = match input.len() <= self.max_bits() {
             true => {
                 // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
                 if input.len() % BHP_CHUNK_SIZE != 0 {
@@ -52,7 +52,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
                     Cow::Borrowed(input)
                 }
             }
-            false => E::halt(format!("Inputs to this BHP cannot exceed {} bits", Self::MAX_BITS)),
+            false => E::halt(format!("Inputs to this BHP cannot exceed {} bits", self.max_bits())),
         };
 
         // Declare the 1 constant field element.
@@ -101,7 +101,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
         // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
         // `NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE` in length, which is the parameter size here.
         input
-            .chunks(WINDOW_SIZE as usize * BHP_CHUNK_SIZE)
+            .chunks(self.window_size as usize * BHP_CHUNK_SIZE)
             .zip(self.bases.iter())
             .map(|(bits, bases)| {
                 // Initialize accumulating sum variables for the x- and y-coordinates.
@@ -29,6 +29,10 @@ type BaseLookups<E> = (Vec<Field<E>>, Vec<Field<E>>);
 /// BHP is a collision-resistant hash function that takes a variable-length input.
 /// The BHP hasher is used to process one internal iteration of the BHP hash function.
 pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+    /// The number of windows.
+    num_windows: u8,
+    /// The window size.
+    window_size: u8,
     /// The bases for the BHP hash.
     bases: Vec<Vec<BaseLookups<E>>>,
     /// The random base for the BHP commitment.
@@ -38,10 +42,26 @@ pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
     /// The BHP lookup size per iteration.
     const BHP_LOOKUP_SIZE: usize = 4;
-    /// The maximum number of input bits.
-    const MAX_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
-    /// The minimum number of input bits (at least one window).
-    const MIN_BITS: usize = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+
+    /// Returns the number of windows.
+    pub fn num_windows(&self) -> u8 {
+        self.num_windows
+    }
+
+    /// Returns the window size.
+    pub fn window_size(&self) -> u8 {
+        self.window_size
+    }
+
+    /// Returns the maximum number of input bits.
+    fn max_bits(&self) -> usize {
+        self.num_windows as usize * self.window_size as usize * BHP_CHUNK_SIZE
+    }
+
+    /// Returns the minimum number of input bits (at least one window).
+    fn min_bits(&self) -> usize {
+        self.window_size as usize * BHP_CHUNK_SIZE
+    }
 
     #[cfg(test)]
     /// Returns the bases.
@@ -61,15 +81,18 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Inject for BH
 
     /// Initializes a new instance of a BHP circuit with the given BHP variant.
     fn new(_mode: Mode, bhp: Self::Primitive) -> Self {
+        // Retrieve the number of windows and the window size.
+        let (num_windows, window_size) = (bhp.num_windows(), bhp.window_size());
+
         // Compute the bases.
         let bases = bhp
             .bases()
             .iter()
-            .take(NUM_WINDOWS as usize)
+            .take(num_windows as usize)
             .map(|window| {
                 // Construct the window with the base.
-                let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
-                for base in window.iter().take(WINDOW_SIZE as usize).map(|base| Group::constant(*base)) {
+                let mut powers = Vec::with_capacity(window_size as usize);
+                for base in window.iter().take(window_size as usize).map(|base| Group::constant(*base)) {
                     let mut x_bases = Vec::with_capacity(Self::BHP_LOOKUP_SIZE);
                     let mut y_bases = Vec::with_capacity(Self::BHP_LOOKUP_SIZE);
                     let mut accumulator = base.clone();
@@ -88,14 +111,14 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Inject for BH
                 powers
             })
             .collect::<Vec<Vec<BaseLookups<E>>>>();
-        assert_eq!(bases.len(), NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
-        bases.iter().for_each(|window| assert_eq!(window.len(), WINDOW_SIZE as usize));
+        assert_eq!(bases.len(), num_windows as usize, "Incorrect number of BHP windows ({})", bases.len());
+        bases.iter().for_each(|window| assert_eq!(window.len(), window_size as usize));
 
         // Initialize the random base.
         let random_base = Vec::constant(bhp.random_base().iter().copied().collect());
         assert_eq!(random_base.len(), console::Scalar::<E::Network>::size_in_bits());
 
-        Self { bases, random_base }
+        Self { num_windows, window_size, bases, random_base }
     }
 }
 
@@ -34,6 +34,8 @@ pub type BHP512<E> = BHP<E, 6, 43>; // Supports inputs up to 522 bits (2 u8 + 2
 pub type BHP768<E> = BHP<E, 15, 23>; // Supports inputs up to 783 bits (3 u8 + 3 Fq).
 /// BHP1024 is a collision-resistant hash function that processes inputs in 1024-bit chunks.
 pub type BHP1024<E> = BHP<E, 8, 54>; // Supports inputs up to 1044 bits (4 u8 + 4 Fq).
+/// CustomBHP is a collision-resistant hash function whose number of windows and window size are chosen at runtime.
+pub type CustomBHP<E> = BHP<E, 0, 0>;
 
 /// The BHP chunk size (this implementation is for a 3-bit BHP).
 const BHP_CHUNK_SIZE: usize = 3;
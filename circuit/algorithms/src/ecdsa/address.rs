@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Hash, Keccak256};
+
+impl<E: Environment> Secp256k1<E> {
+    /// Returns the 20-byte Ethereum address of the given public key `x || y` in big-endian bytes,
+    /// where every byte is given as 8 little-endian bits (as in [`Secp256k1::verify`]).
+    ///
+    /// The address is the last 20 bytes of the Keccak-256 hash of the uncompressed public key
+    /// (without its `0x04` prefix), and is returned in the same byte and bit order.
+    ///
+    /// Note: The public key is not checked to be on the curve.
+    pub fn ethereum_address(public_key: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the public key is of the expected size.
+        if public_key.len() != 512 {
+            E::halt("Invalid public key size: expected a 512-bit secp256k1 public key")
+        }
+        // Hash the public key, and truncate the digest to its last 20 bytes.
+        Keccak256::<E>::new().hash(public_key)[96..].to_vec()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    /// Returns the given bytes as bits, with 8 little-endian bits per byte.
+    fn to_bits(mode: Mode, bytes: &[u8]) -> Vec<Boolean<Circuit>> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| Boolean::new(mode, (byte >> i) & 1 == 1))).collect()
+    }
+
+    fn check_ethereum_address(mode: Mode) {
+        let mut rng = TestRng::default();
+        let curve = console::Secp256k1::new();
+        let n = curve.scalar_modulus();
+
+        // Sample a public key.
+        let secret_key = BigUint::from_bytes_be(&<[u8; 32]>::rand(&mut rng)) % (n - 1u32) + 1u32;
+        let public_key = curve.public_key(&secret_key).unwrap();
+        let address = console::Secp256k1::ethereum_address(&public_key);
+        let expected = address.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect::<Vec<_>>();
+
+        Circuit::scope(format!("Secp256k1::ethereum_address {mode}"), || {
+            let candidate = Secp256k1::<Circuit>::ethereum_address(&to_bits(mode, &public_key));
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_ethereum_address_constant() {
+        check_ethereum_address(Mode::Constant);
+    }
+
+    #[test]
+    fn test_ethereum_address_private() {
+        check_ethereum_address(Mode::Private);
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An affine point on the secp256k1 curve, which is never the point at infinity.
+///
+/// The addition formulas are incomplete: adding two points with the same x-coordinate
+/// makes the circuit unsatisfiable, which is negligibly likely for the points in ECDSA verification.
+#[derive(Clone)]
+pub struct Secp256k1Point<E: Environment> {
+    /// The x-coordinate of the point.
+    x: NonNativeField<E>,
+    /// The y-coordinate of the point.
+    y: NonNativeField<E>,
+}
+
+impl<E: Environment> Secp256k1Point<E> {
+    /// Initializes a point from the given coordinates, which are not checked to be on the curve.
+    pub fn from_xy_coordinates_unchecked(x: NonNativeField<E>, y: NonNativeField<E>) -> Self {
+        Self { x, y }
+    }
+
+    /// Initializes a constant point from the given affine coordinates.
+    pub fn constant((x, y): &(BigUint, BigUint), modulus: &BigUint) -> Self {
+        Self { x: NonNativeField::constant(x, modulus), y: NonNativeField::constant(y, modulus) }
+    }
+
+    /// Returns the x-coordinate of the point.
+    pub const fn x(&self) -> &NonNativeField<E> {
+        &self.x
+    }
+
+    /// Returns the y-coordinate of the point.
+    pub const fn y(&self) -> &NonNativeField<E> {
+        &self.y
+    }
+
+    /// Returns `true` if the point is a constant.
+    pub fn is_constant(&self) -> bool {
+        self.x.is_constant() && self.y.is_constant()
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: NonNativeField::ternary(condition, &first.x, &second.x),
+            y: NonNativeField::ternary(condition, &first.y, &second.y),
+        }
+    }
+
+    /// Returns `true` if the point is on the curve `y^2 = x^3 + 7`.
+    pub fn is_on_curve(&self) -> Boolean<E> {
+        let (x, y, p) = (&self.x, &self.y, self.x.modulus());
+        let x2 = NonNativeField::sum_of_products(p, &[(1, x, x)], &[], 0);
+        let lhs = NonNativeField::sum_of_products(p, &[(1, y, y)], &[], 0);
+        let rhs = NonNativeField::sum_of_products(p, &[(1, x, &x2)], &[], i64::from(console::Secp256k1::B));
+        lhs.is_equal(&rhs)
+    }
+
+    /// Returns `self + other`, where the points must have distinct x-coordinates.
+    pub fn add(&self, other: &Self) -> Self {
+        let p = self.x.modulus();
+        let mode = if self.is_constant() && other.is_constant() { Mode::Constant } else { Mode::Private };
+
+        // Witness `inverse = 1 / (x2 - x1)` and `lambda = (y2 - y1) / (x2 - x1)`.
+        let (x1, y1) = (self.x.value() % p, self.y.value() % p);
+        let (x2, y2) = (other.x.value() % p, other.y.value() % p);
+        let inverse = console::Secp256k1::inverse(&((x2 + p - x1) % p), p);
+        let lambda = ((y2 + p - y1) * &inverse) % p;
+        let inverse = NonNativeField::new(mode, &inverse, p);
+        let lambda = NonNativeField::new(mode, &lambda, p);
+
+        // Ensure `inverse * (x2 - x1) == 1`, which implies the x-coordinates are distinct.
+        NonNativeField::assert_zero(p, &[(1, &inverse, &other.x), (-1, &inverse, &self.x)], &[], -1);
+        // Ensure `lambda * (x2 - x1) == y2 - y1`.
+        NonNativeField::assert_zero(
+            p,
+            &[(1, &lambda, &other.x), (-1, &lambda, &self.x)],
+            &[(-1, &other.y), (1, &self.y)],
+            0,
+        );
+
+        // Compute `x3 = lambda^2 - x1 - x2` and `y3 = lambda * (x1 - x3) - y1`.
+        let x = NonNativeField::sum_of_products(p, &[(1, &lambda, &lambda)], &[(-1, &self.x), (-1, &other.x)], 0);
+        let y = NonNativeField::sum_of_products(p, &[(1, &lambda, &self.x), (-1, &lambda, &x)], &[(-1, &self.y)], 0);
+        Self { x, y }
+    }
+
+    /// Returns `2 * self`.
+    pub fn double(&self) -> Self {
+        let p = self.x.modulus();
+        let mode = if self.is_constant() { Mode::Constant } else { Mode::Private };
+
+        // Witness `lambda = 3 * x^2 / (2 * y)`.
+        // Note: As secp256k1 has no points of order 2, `y` is nonzero for points on the curve.
+        let (x, y) = (self.x.value() % p, self.y.value() % p);
+        let lambda = (&x * &x * 3u32 * console::Secp256k1::inverse(&((y * 2u32) % p), p)) % p;
+        let lambda = NonNativeField::new(mode, &lambda, p);
+
+        // Ensure `2 * lambda * y == 3 * x^2`.
+        NonNativeField::assert_zero(p, &[(2, &lambda, &self.y), (-3, &self.x, &self.x)], &[], 0);
+
+        // Compute `x3 = lambda^2 - 2 * x` and `y3 = lambda * (x - x3) - y`.
+        let x = NonNativeField::sum_of_products(p, &[(1, &lambda, &lambda)], &[(-2, &self.x)], 0);
+        let y = NonNativeField::sum_of_products(p, &[(1, &lambda, &self.x), (-1, &lambda, &x)], &[(-1, &self.y)], 0);
+        Self { x, y }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    #[test]
+    fn test_add_and_double() {
+        let curve = console::Secp256k1::new();
+        let p = curve.base_modulus();
+        let generator = Some(curve.generator().clone());
+        let offset = Some(curve.offset().clone());
+
+        for mode in [Mode::Constant, Mode::Private] {
+            let (x, y) = curve.generator();
+            let a = Secp256k1Point::<Circuit>::from_xy_coordinates_unchecked(
+                NonNativeField::new(mode, x, p),
+                NonNativeField::new(mode, y, p),
+            );
+            let b = Secp256k1Point::<Circuit>::constant(curve.offset(), p);
+
+            // Ensure the points are on the curve.
+            assert!(a.is_on_curve().eject_value());
+            assert!(b.is_on_curve().eject_value());
+
+            // Ensure addition and doubling match the native implementation.
+            let sum = a.add(&b);
+            let expected = curve.add(&generator, &offset).unwrap();
+            assert_eq!(expected, (sum.x().to_canonical().value(), sum.y().to_canonical().value()));
+            let double = a.double();
+            let expected = curve.double(&generator).unwrap();
+            assert_eq!(expected, (double.x().to_canonical().value(), double.y().to_canonical().value()));
+            assert!(Circuit::is_satisfied());
+
+            // Ensure a point that is not on the curve is detected.
+            let c = Secp256k1Point::<Circuit>::from_xy_coordinates_unchecked(
+                NonNativeField::new(mode, x, p),
+                NonNativeField::new(mode, &(y + 1u32), p),
+            );
+            assert!(!c.is_on_curve().eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
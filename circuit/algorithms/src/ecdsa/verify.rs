@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Secp256k1<E> {
+    /// Returns `true` if `signature` is a valid ECDSA signature on the 32-byte `digest` under `public_key`.
+    ///
+    /// The signature is encoded as `r || s`, the public key as `x || y`, and the digest as a 256-bit integer,
+    /// each in big-endian bytes (as in Ethereum), where every byte is given as 8 little-endian bits.
+    /// Both `r` and `s` must be in `[1, n - 1]`, and the public key must be on the curve,
+    /// otherwise the output is `false`.
+    ///
+    /// Note: The scalar multiplications use incomplete addition formulas, so inputs that are crafted to hit
+    /// an exceptional case (e.g. a public key that is a small multiple of the generator) make the circuit
+    /// unsatisfiable, instead of returning `false`.
+    pub fn verify(signature: &[Boolean<E>], public_key: &[Boolean<E>], digest: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the expected sizes.
+        if signature.len() != 512 || public_key.len() != 512 || digest.len() != 256 {
+            E::halt("Invalid ECDSA input sizes: expected a 512-bit signature and public key, and a 256-bit digest")
+        }
+
+        let curve = console::Secp256k1::new();
+        let (p, n) = (curve.base_modulus(), curve.scalar_modulus());
+
+        // Convert the big-endian bytes into the little-endian bits of each integer.
+        let to_bits_le = |bytes: &[Boolean<E>]| bytes.chunks(8).rev().flatten().cloned().collect::<Vec<_>>();
+        let (r_bits, s_bits) = (to_bits_le(&signature[..256]), to_bits_le(&signature[256..]));
+        let (x_bits, y_bits) = (to_bits_le(&public_key[..256]), to_bits_le(&public_key[256..]));
+        let e_bits = to_bits_le(digest);
+
+        // Check that `r` and `s` are in `[1, n - 1]`.
+        let is_scalar = |bits: &[Boolean<E>]| {
+            bits.iter().fold(Boolean::constant(false), |is_nonzero, bit| is_nonzero | bit) & is_less_than(bits, n)
+        };
+        let (is_r_valid, is_s_valid) = (is_scalar(&r_bits), is_scalar(&s_bits));
+
+        // Check that the public key is on the curve.
+        let public_key = Secp256k1Point::from_xy_coordinates_unchecked(
+            NonNativeField::from_bits_le(&x_bits, p),
+            NonNativeField::from_bits_le(&y_bits, p),
+        );
+        let is_public_key_valid = is_less_than(&x_bits, p) & is_less_than(&y_bits, p) & public_key.is_on_curve();
+
+        // Replace invalid inputs with valid ones, so that the circuit remains satisfiable.
+        let one = NonNativeField::constant(&BigUint::from(1u32), n);
+        let r = NonNativeField::ternary(&is_r_valid, &NonNativeField::from_bits_le(&r_bits, n), &one);
+        let s = NonNativeField::ternary(&is_s_valid, &NonNativeField::from_bits_le(&s_bits, n), &one);
+        let generator = Secp256k1Point::constant(curve.generator(), p);
+        let public_key = Secp256k1Point::ternary(&is_public_key_valid, &public_key, &generator);
+
+        // Compute `u1 = e / s` and `u2 = r / s`.
+        // Note: The scalars are not reduced, as `u * G` only depends on `u` modulo `n`.
+        let e = NonNativeField::from_bits_le(&e_bits, n);
+        let w = s.inverse();
+        let u1 = NonNativeField::sum_of_products(n, &[(1, &e, &w)], &[], 0).to_bits_le();
+        let u2 = NonNativeField::sum_of_products(n, &[(1, &r, &w)], &[], 0).to_bits_le();
+
+        // Compute `2^256 * T + u2 * Q`, where the offset `T` avoids the point at infinity.
+        let offset = curve.offset();
+        let mut output = Secp256k1Point::constant(offset, p);
+        for bit in u2.iter().rev() {
+            output = output.double();
+            output = Secp256k1Point::ternary(bit, &output.add(&public_key), &output);
+        }
+        // Add `u1 * G`, using the constant multiples `2^i * G`.
+        let mut base = Some(curve.generator().clone());
+        for bit in &u1 {
+            if let Some(point) = &base {
+                output = Secp256k1Point::ternary(bit, &output.add(&Secp256k1Point::constant(point, p)), &output);
+            }
+            base = curve.double(&base);
+        }
+        // Subtract `2^256 * T`, to obtain `R = u1 * G + u2 * Q`.
+        match curve.mul(&Some(offset.clone()), &(BigUint::from(1u32) << 256)) {
+            Some((x, y)) => output = output.add(&Secp256k1Point::constant(&(x, p - y), p)),
+            None => E::halt("The offset point has an invalid order"),
+        }
+
+        // Check that `x(R) mod n == r`.
+        let x = output.x().to_canonical().with_modulus(n);
+        is_r_valid & is_s_valid & is_public_key_valid & x.is_equal(&r)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    /// Returns the given bytes as bits, with 8 little-endian bits per byte.
+    fn to_bits(mode: Mode, bytes: &[u8]) -> Vec<Boolean<Circuit>> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| Boolean::new(mode, (byte >> i) & 1 == 1))).collect()
+    }
+
+    fn check_verify(mode: Mode) {
+        let mut rng = TestRng::default();
+        let curve = console::Secp256k1::new();
+        let n = curve.scalar_modulus();
+
+        // Sample a key pair, a digest, and a signature.
+        let sample = |rng: &mut TestRng| BigUint::from_bytes_be(&<[u8; 32]>::rand(rng)) % (n - 1u32) + 1u32;
+        let secret_key = sample(&mut rng);
+        let public_key = curve.public_key(&secret_key).unwrap();
+        let digest = <[u8; 32]>::rand(&mut rng);
+        let signature = curve.sign(&secret_key, &digest, &sample(&mut rng)).unwrap();
+
+        let mut other_digest = digest;
+        other_digest[0] ^= 1;
+        let mut invalid_public_key = public_key;
+        invalid_public_key[63] ^= 1;
+
+        for (public_key, digest, expected) in
+            [(public_key, digest, true), (public_key, other_digest, false), (invalid_public_key, digest, false)]
+        {
+            assert_eq!(expected, curve.verify(&signature, &public_key, &digest));
+
+            Circuit::scope(format!("Secp256k1::verify {mode}"), || {
+                let candidate = Secp256k1::<Circuit>::verify(
+                    &to_bits(mode, &signature),
+                    &to_bits(mode, &public_key),
+                    &to_bits(mode, &digest),
+                );
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        check_verify(Mode::Constant);
+    }
+
+    #[test]
+    fn test_verify_private() {
+        check_verify(Mode::Private);
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod point;
+pub use point::Ed25519Point;
+
+mod verify;
+
+use crate::{non_native::is_less_than, Hash, NonNativeField, Sha512};
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+use num_bigint::BigUint;
+
+/// The Ed25519 signature scheme (RFC 8032), which is used in Solana, Cosmos, and hardware tokens.
+///
+/// As Ed25519 is defined over a different field than the circuit, its field elements are
+/// emulated with non-native field arithmetic (see [`NonNativeField`]).
+pub struct Ed25519<E: Environment>(PhantomData<E>);
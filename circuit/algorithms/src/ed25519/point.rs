@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An affine point on the Ed25519 curve `-x^2 + y^2 = 1 + d * x^2 * y^2`.
+///
+/// The addition formulas are complete, as `d` is not a square, so any two points on the curve can be added.
+#[derive(Clone)]
+pub struct Ed25519Point<E: Environment> {
+    /// The x-coordinate of the point.
+    x: NonNativeField<E>,
+    /// The y-coordinate of the point.
+    y: NonNativeField<E>,
+}
+
+impl<E: Environment> Ed25519Point<E> {
+    /// Initializes a point from the given coordinates, which are not checked to be on the curve.
+    pub fn from_xy_coordinates_unchecked(x: NonNativeField<E>, y: NonNativeField<E>) -> Self {
+        Self { x, y }
+    }
+
+    /// Initializes a constant point from the given affine coordinates.
+    pub fn constant((x, y): &(BigUint, BigUint), modulus: &BigUint) -> Self {
+        Self { x: NonNativeField::constant(x, modulus), y: NonNativeField::constant(y, modulus) }
+    }
+
+    /// Returns the identity `(0, 1)`.
+    pub fn identity(modulus: &BigUint) -> Self {
+        Self::constant(&(BigUint::default(), BigUint::from(1u32)), modulus)
+    }
+
+    /// Returns the x-coordinate of the point.
+    pub const fn x(&self) -> &NonNativeField<E> {
+        &self.x
+    }
+
+    /// Returns the y-coordinate of the point.
+    pub const fn y(&self) -> &NonNativeField<E> {
+        &self.y
+    }
+
+    /// Returns `true` if the point is a constant.
+    pub fn is_constant(&self) -> bool {
+        self.x.is_constant() && self.y.is_constant()
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: NonNativeField::ternary(condition, &first.x, &second.x),
+            y: NonNativeField::ternary(condition, &first.y, &second.y),
+        }
+    }
+
+    /// Returns `true` if the points are equal.
+    pub fn is_equal(&self, other: &Self) -> Boolean<E> {
+        self.x.is_equal(&other.x) & self.y.is_equal(&other.y)
+    }
+
+    /// Returns `self + other`, for the curve coefficient `d`.
+    pub fn add(&self, other: &Self, d: &NonNativeField<E>) -> Self {
+        let p = self.x.modulus();
+        let mode = if self.is_constant() && other.is_constant() { Mode::Constant } else { Mode::Private };
+
+        // Compute `a = x1 * x2`, `b = y1 * y2`, `c = x1 * y2 + y1 * x2`, and `k = d * a * b`.
+        let a = NonNativeField::sum_of_products(p, &[(1, &self.x, &other.x)], &[], 0);
+        let b = NonNativeField::sum_of_products(p, &[(1, &self.y, &other.y)], &[], 0);
+        let c = NonNativeField::sum_of_products(p, &[(1, &self.x, &other.y), (1, &self.y, &other.x)], &[], 0);
+        let da = NonNativeField::sum_of_products(p, &[(1, d, &a)], &[], 0);
+        let k = NonNativeField::sum_of_products(p, &[(1, &da, &b)], &[], 0);
+
+        // Witness `x3 = c / (1 + k)` and `y3 = (a + b) / (1 - k)`.
+        let (a_value, b_value, c_value, k_value) = (a.value() % p, b.value() % p, c.value() % p, k.value() % p);
+        let x3 = c_value * console::Ed25519::inverse(&((&k_value + 1u32) % p), p) % p;
+        let y3 = (a_value + b_value) * console::Ed25519::inverse(&((p + 1u32 - k_value) % p), p) % p;
+        let x3 = NonNativeField::new(mode, &x3, p);
+        let y3 = NonNativeField::new(mode, &y3, p);
+
+        // Ensure `x3 * (1 + k) == c` and `y3 * (1 - k) == a + b`.
+        NonNativeField::assert_zero(p, &[(1, &x3, &k)], &[(1, &x3), (-1, &c)], 0);
+        NonNativeField::assert_zero(p, &[(-1, &y3, &k)], &[(1, &y3), (-1, &a), (-1, &b)], 0);
+        Self { x: x3, y: y3 }
+    }
+
+    /// Returns `2 * self`, for the curve coefficient `d`.
+    pub fn double(&self, d: &NonNativeField<E>) -> Self {
+        self.add(self, d)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    #[test]
+    fn test_add_and_double() {
+        let curve = console::Ed25519::new();
+        let p = curve.base_modulus();
+        let other = curve.mul(curve.base(), &BigUint::from(7u32));
+
+        for mode in [Mode::Constant, Mode::Private] {
+            let d = NonNativeField::<Circuit>::constant(curve.d(), p);
+            let (x, y) = curve.base();
+            let a = Ed25519Point::<Circuit>::from_xy_coordinates_unchecked(
+                NonNativeField::new(mode, x, p),
+                NonNativeField::new(mode, y, p),
+            );
+            let b = Ed25519Point::<Circuit>::constant(&other, p);
+
+            // Ensure addition and doubling match the native implementation.
+            let sum = a.add(&b, &d);
+            let expected = curve.add(curve.base(), &other);
+            assert_eq!(expected, (sum.x().to_canonical().value(), sum.y().to_canonical().value()));
+            let double = a.double(&d);
+            let expected = curve.add(curve.base(), curve.base());
+            assert_eq!(expected, (double.x().to_canonical().value(), double.y().to_canonical().value()));
+
+            // Ensure the identity is neutral.
+            let sum = a.add(&Ed25519Point::identity(p), &d);
+            assert!(sum.is_equal(&a).eject_value());
+            assert!(!sum.is_equal(&b).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
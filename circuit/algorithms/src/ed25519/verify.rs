@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Ed25519<E> {
+    /// Returns `true` if `signature` is a valid Ed25519 signature on `message` under `public_key`.
+    ///
+    /// The signature is encoded as `R || S`, the public key as a 32-byte point encoding, and the message
+    /// as bytes, as in RFC 8032, where every byte is given as 8 little-endian bits.
+    /// This checks the cofactorless equation `S * B == R + SHA-512(R || A || M) * A`, and outputs `false`
+    /// if `S` is not reduced, or if `R` or the public key is not a valid point encoding.
+    pub fn verify(signature: &[Boolean<E>], public_key: &[Boolean<E>], message: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the expected sizes.
+        if signature.len() != 512 || public_key.len() != 256 || message.len() % 8 != 0 {
+            E::halt("Invalid Ed25519 input sizes: expected a 512-bit signature, a 256-bit public key, and bytes")
+        }
+
+        let curve = console::Ed25519::new();
+        let (p, l) = (curve.base_modulus(), curve.scalar_modulus());
+        let d = NonNativeField::constant(curve.d(), p);
+
+        // Decompress `R` and the public key `A`.
+        let (r, is_r_valid) = Self::decompress(&signature[..256], &curve, &d);
+        let (a, is_a_valid) = Self::decompress(public_key, &curve, &d);
+
+        // Check that `S` is reduced.
+        // Note: As every byte is given in little-endian bits, the bits of `S` are in little-endian order.
+        let s_bits = &signature[256..];
+        let is_s_valid = is_less_than(s_bits, l);
+
+        // Compute `h = SHA-512(R || A || M) mod l`.
+        let input = signature[..256].iter().chain(public_key).chain(message).cloned().collect::<Vec<_>>();
+        let hash = Sha512::<E>::new().hash(&input);
+        let (low, high) =
+            (NonNativeField::from_bits_le(&hash[..256], l), NonNativeField::from_bits_le(&hash[256..], l));
+        let two_to_256 = NonNativeField::constant(&(BigUint::from(1u32) << 256), l);
+        // Note: `h` is reduced, as `h * A` depends on `h` modulo the order of `A`, which may be a multiple of `l`.
+        let h = NonNativeField::sum_of_products(l, &[(1, &high, &two_to_256)], &[(1, &low)], 0).to_canonical();
+
+        // Compute `S * B`, using the constant multiples `2^i * B`.
+        let mut lhs = Ed25519Point::identity(p);
+        let mut base = curve.base().clone();
+        for bit in s_bits {
+            lhs = Ed25519Point::ternary(bit, &lhs.add(&Ed25519Point::constant(&base, p), &d), &lhs);
+            base = curve.add(&base, &base);
+        }
+
+        // Compute `R + h * A`.
+        let mut rhs = Ed25519Point::identity(p);
+        for bit in h.to_bits_le().iter().rev() {
+            rhs = rhs.double(&d);
+            rhs = Ed25519Point::ternary(bit, &rhs.add(&a, &d), &rhs);
+        }
+        let rhs = rhs.add(&r, &d);
+
+        // Check that `S * B == R + h * A`.
+        is_r_valid & is_a_valid & is_s_valid & lhs.is_equal(&rhs)
+    }
+
+    /// Returns the point with the given 256-bit encoding, and `true` if the encoding is valid.
+    /// If the encoding is invalid, the base point is returned, so that the circuit remains satisfiable.
+    fn decompress(
+        bits: &[Boolean<E>],
+        curve: &console::Ed25519,
+        d: &NonNativeField<E>,
+    ) -> (Ed25519Point<E>, Boolean<E>) {
+        let p = curve.base_modulus();
+
+        // Parse `y`, and check that it is reduced.
+        let (y_bits, sign) = (&bits[..255], &bits[255]);
+        let is_canonical = is_less_than(y_bits, p);
+        let y = NonNativeField::from_bits_le(y_bits, p);
+
+        // Compute `u = y^2 - 1` and `v = d * y^2 + 1`, so that `x^2 = u / v`.
+        // Note: `v` is nonzero, as `-1 / d` is not a square.
+        let y2 = NonNativeField::sum_of_products(p, &[(1, &y, &y)], &[], 0);
+        let u = NonNativeField::sum_of_products(p, &[], &[(1, &y2)], -1);
+        let v = NonNativeField::sum_of_products(p, &[(1, d, &y2)], &[], 1);
+
+        // Witness whether `u / v` is a square, and a square root of `u / v` if so, or of `2 * u / v` otherwise.
+        // Note: As `p = 5 mod 8`, `2` is not a square, so if `u / v` is not a square, then `2 * u / v` is a square.
+        let mode = if y.is_constant() { Mode::Constant } else { Mode::Private };
+        let ratio = u.value() * console::Ed25519::inverse(&(v.value() % p), p) % p;
+        let (is_square, root) = match console::Ed25519::sqrt(&ratio, p) {
+            Some(root) => (true, root),
+            None => (false, console::Ed25519::sqrt(&(ratio * 2u32 % p), p).unwrap_or_default()),
+        };
+        let is_square = Boolean::new(mode, is_square);
+        let root = NonNativeField::new(mode, &root, p);
+
+        // Ensure `root^2 * v == u` if `is_square`, or `root^2 * v == 2 * u` otherwise.
+        let one = NonNativeField::constant(&BigUint::from(1u32), p);
+        let two = NonNativeField::constant(&BigUint::from(2u32), p);
+        let factor = NonNativeField::ternary(&is_square, &one, &two);
+        let root2 = NonNativeField::sum_of_products(p, &[(1, &root, &root)], &[], 0);
+        NonNativeField::assert_zero(p, &[(1, &root2, &v), (-1, &factor, &u)], &[], 0);
+        // Note: If `u == 0`, both cases are satisfied by `root == 0`, which is the valid square root.
+        let zero = NonNativeField::constant(&BigUint::default(), p);
+        let is_square = is_square | u.is_equal(&zero);
+
+        // Select the square root with the given parity, and reject `x = 0` with a set sign bit.
+        let root = root.to_canonical();
+        let is_root_zero = root.is_equal(&zero);
+        let negated_root = NonNativeField::sum_of_products(p, &[], &[(-1, &root)], 0);
+        let is_parity_equal = root.to_bits_le()[0].is_equal(sign);
+        let x = NonNativeField::ternary(&is_parity_equal, &root, &negated_root);
+        let is_valid = is_canonical & is_square & !(is_root_zero & sign);
+
+        // Replace an invalid point with the base point.
+        let point = Ed25519Point::from_xy_coordinates_unchecked(x, y);
+        let point = Ed25519Point::ternary(&is_valid, &point, &Ed25519Point::constant(curve.base(), p));
+        (point, is_valid)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    /// Returns the given bytes as bits, with 8 little-endian bits per byte.
+    fn to_bits(mode: Mode, bytes: &[u8]) -> Vec<Boolean<Circuit>> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| Boolean::new(mode, (byte >> i) & 1 == 1))).collect()
+    }
+
+    fn check_verify(mode: Mode) {
+        let mut rng = TestRng::default();
+        let curve = console::Ed25519::new();
+
+        // Sample a key pair, a message, and a signature.
+        let secret_key = <[u8; 32]>::rand(&mut rng);
+        let public_key = curve.public_key(&secret_key);
+        let message = (0..40).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+        let signature = curve.sign(&secret_key, &message);
+
+        let mut other_message = message.clone();
+        other_message[0] ^= 1;
+        let mut invalid_signature = signature;
+        invalid_signature[63] |= 0xf0;
+        // Note: A `y`-coordinate of 2 is not a valid encoding, as `(4 - 1) / (4 * d + 1)` is not a square.
+        let mut invalid_public_key = [0u8; 32];
+        invalid_public_key[0] = 2;
+        assert!(curve.decompress(&invalid_public_key).is_none());
+
+        for (signature, public_key, message, expected) in [
+            (signature, public_key, &message, true),
+            (signature, public_key, &other_message, false),
+            (invalid_signature, public_key, &message, false),
+            (signature, invalid_public_key, &message, false),
+        ] {
+            assert_eq!(expected, curve.verify(&signature, &public_key, message));
+
+            Circuit::scope(format!("Ed25519::verify {mode}"), || {
+                let candidate = Ed25519::<Circuit>::verify(
+                    &to_bits(mode, &signature),
+                    &to_bits(mode, &public_key),
+                    &to_bits(mode, message),
+                );
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        check_verify(Mode::Constant);
+    }
+
+    #[test]
+    fn test_verify_private() {
+        check_verify(Mode::Private);
+    }
+}
@@ -21,9 +21,34 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> Hash for Keccak<E, TY
     /// Returns the Keccak hash of the given input as bits.
     #[inline]
     fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // The digest length `d`.
+        // For SHAKE, the digest length is twice the security level (i.e. twice the variant).
+        let digest_length = match TYPE {
+            0 | 1 => VARIANT,
+            2 => 2 * VARIANT,
+            3.. => unreachable!("Invalid Keccak type"),
+        };
+        self.sponge(input, digest_length)
+    }
+}
+
+impl<E: Environment, const VARIANT: usize> HashMany for Keccak<E, { KeccakType::Shake as u8 }, VARIANT> {
+    type Input = Boolean<E>;
+    type Output = Boolean<E>;
+
+    /// Returns the first `num_outputs` bits of the SHAKE output for the given input.
+    #[inline]
+    fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
+        self.sponge(input, num_outputs as usize)
+    }
+}
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Absorbs the given input into the sponge, and squeezes out `digest_length` bits.
+    fn sponge(&self, input: &[Boolean<E>], digest_length: usize) -> Vec<Boolean<E>> {
         // The bitrate `r`.
-        // The capacity is twice the digest length (i.e. twice the variant, where the variant is in {224, 256, 384, 512}),
-        // and the bit rate is the width (1600 in our case) minus the capacity.
+        // The capacity is twice the variant, where the variant is in {224, 256, 384, 512} for Keccak and SHA-3,
+        // and in {128, 256} for SHAKE. The bit rate is the width (1600 in our case) minus the capacity.
         let bitrate = PERMUTATION_WIDTH - 2 * VARIANT;
         debug_assert!(bitrate < PERMUTATION_WIDTH, "The bitrate must be less than the permutation width");
         debug_assert!(bitrate % 8 == 0, "The bitrate must be a multiple of 8");
@@ -40,7 +65,8 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> Hash for Keccak<E, TY
         let padded_blocks = match TYPE {
             0 => Self::pad_keccak(input, bitrate),
             1 => Self::pad_sha3(input, bitrate),
-            2.. => unreachable!("Invalid Keccak type"),
+            2 => Self::pad_shake(input, bitrate),
+            3.. => unreachable!("Invalid Keccak type"),
         };
 
         /* The first part of the sponge construction (the absorbing phase):
@@ -70,20 +96,18 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> Hash for Keccak<E, TY
          */
         // Z = s[0..r-1]
         let mut z = s[..bitrate].to_vec();
-        // while |Z| < l do
-        while z.len() < VARIANT {
+        // while |Z| < d do
+        while z.len() < digest_length {
             // s = f(s)
             s = Self::permutation_f::<PERMUTATION_WIDTH, NUM_ROUNDS>(s, &self.round_constants, &self.rotl);
             // Z = Z || s[0..r-1]
             z.extend(s.iter().take(bitrate).cloned());
         }
         // return Z[0..d-1]
-        z.truncate(VARIANT);
+        z.truncate(digest_length);
         z
     }
-}
 
-impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
     /// In Keccak, `pad` is a multi-rate padding, defined as `pad(M) = M || 0x01 || 0x00…0x00 || 0x80`,
     /// where `M` is the input data, and `0x01 || 0x00…0x00 || 0x80` is the padding.
     /// The padding extends the input data to a multiple of the bitrate `r`, defined as `r = b - c`,
@@ -147,6 +171,36 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIA
         result
     }
 
+    /// In SHAKE, `pad` is defined as `pad(M) = M || 0x1F || 0x00…0x00 || 0x80`,
+    /// where `M` is the input data, and `0x1F || 0x00…0x00 || 0x80` is the padding.
+    /// The padding extends the input data to a multiple of the bitrate `r`, defined as `r = b - c`,
+    /// where `b` is the width of the permutation, and `c` is the capacity.
+    fn pad_shake(input: &[Boolean<E>], bitrate: usize) -> Vec<Vec<Boolean<E>>> {
+        debug_assert!(bitrate > 1, "The bitrate must be greater than 1");
+
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+
+        // Step 1: Append the "0x1F" byte to the message (without its trailing zeros).
+        padded_input.extend((0..5).map(|_| Boolean::constant(true)));
+
+        // Step 2: Append "0" bits until the length of the message is congruent to r-1 mod r.
+        while (padded_input.len() % bitrate) != (bitrate - 1) {
+            padded_input.push(Boolean::constant(false));
+        }
+
+        // Step 3: Append the bit "1" to the message.
+        padded_input.push(Boolean::constant(true));
+
+        // Construct the padded blocks.
+        let mut result = Vec::new();
+        for block in padded_input.chunks(bitrate) {
+            result.push(block.to_vec());
+        }
+        result
+    }
+
     /// The permutation `f` is a function that takes a fixed-length input and produces a fixed-length output,
     /// defined as `f = Keccak-f[b]`, where `b := 25 * 2^l` is the width of the permutation,
     /// and `l` is the log width of the permutation.
@@ -455,4 +509,41 @@ mod tests {
     fn test_sha3_512_equivalence() {
         check_equivalence!(console::Sha3_512::default(), Sha3_512::<Circuit>::new());
     }
+
+    #[test]
+    fn test_shake_128_equivalence() {
+        check_equivalence!(console::Shake128::default(), Shake128::<Circuit>::new());
+    }
+
+    #[test]
+    fn test_shake_256_equivalence() {
+        check_equivalence!(console::Shake256::default(), Shake256::<Circuit>::new());
+    }
+
+    #[test]
+    fn test_shake_hash_many_equivalence() {
+        use console::HashMany as H;
+
+        let rng = &mut TestRng::default();
+
+        for num_inputs in [1, 8, 1343, 1344, 1345] {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+
+            for num_outputs in [1u16, 7, 8, 256, 1088, 1089, 2048] {
+                let input = native_input.iter().map(|v| Boolean::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+
+                // Compute the console outputs.
+                let expected_128 = console::Shake128::default().hash_many(&native_input, num_outputs);
+                let expected_256 = console::Shake256::default().hash_many(&native_input, num_outputs);
+
+                // Compute the circuit outputs.
+                let candidate_128 = Shake128::<Circuit>::new().hash_many(&input, num_outputs);
+                let candidate_256 = Shake256::<Circuit>::new().hash_many(&input, num_outputs);
+                assert_eq!(expected_128, candidate_128.eject_value());
+                assert_eq!(expected_256, candidate_256.eject_value());
+                Circuit::reset();
+            }
+        }
+    }
 }
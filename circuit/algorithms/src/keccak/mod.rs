@@ -19,7 +19,7 @@ use snarkvm_circuit_types::environment::assert_scope;
 #[cfg(test)]
 use snarkvm_utilities::{TestRng, Uniform};
 
-use crate::Hash;
+use crate::{Hash, HashMany};
 use snarkvm_circuit_types::{environment::prelude::*, Boolean, U64};
 
 /// The Keccak-224 hash function.
@@ -40,10 +40,16 @@ pub type Sha3_384<E> = Keccak<E, { KeccakType::Sha3 as u8 }, 384>;
 /// The SHA3-512 hash function.
 pub type Sha3_512<E> = Keccak<E, { KeccakType::Sha3 as u8 }, 512>;
 
+/// The SHAKE-128 extendable-output function, which outputs 256 bits when used as a `Hash`.
+pub type Shake128<E> = Keccak<E, { KeccakType::Shake as u8 }, 128>;
+/// The SHAKE-256 extendable-output function, which outputs 512 bits when used as a `Hash`.
+pub type Shake256<E> = Keccak<E, { KeccakType::Shake as u8 }, 256>;
+
 /// A helper to specify the hash type.
 enum KeccakType {
     Keccak,
     Sha3,
+    Shake,
 }
 
 /// The rows and columns are 5-bit lanes.
@@ -68,6 +74,8 @@ const PERMUTATION_WIDTH: usize = 1600;
 /// where `M` is the input data, and `0x01 || 0x00…0x00 || 0x80` is the padding.
 /// In SHA-3, `pad` is a SHAKE, defined as `pad(M) = M || 0x06 || 0x00…0x00 || 0x80`,
 /// where `M` is the input data, and `0x06 || 0x00…0x00 || 0x80` is the padding.
+/// In SHAKE, `pad` is defined as `pad(M) = M || 0x1F || 0x00…0x00 || 0x80`, and the output
+/// may be squeezed to any length (see `HashMany`).
 ///
 /// The bitrate `r` is the number of bits that are absorbed into the sponge state in each iteration
 /// of the absorbing phase.
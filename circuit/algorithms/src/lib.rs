@@ -18,17 +18,32 @@
 pub mod bhp;
 pub use bhp::*;
 
+pub mod ecdsa;
+pub use ecdsa::*;
+
+pub mod ed25519;
+pub use ed25519::*;
+
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
 pub mod keccak;
 pub use keccak::*;
 
+pub mod non_native;
+pub use non_native::NonNativeField;
+
 pub mod pedersen;
 pub use pedersen::*;
 
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod sha512;
+pub use sha512::*;
+
 pub mod traits;
 pub use traits::*;
+
+pub mod vrf;
+pub use vrf::*;
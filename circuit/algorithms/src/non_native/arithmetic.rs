@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> NonNativeField<E> {
+    /// Returns the square of the element, which is not necessarily reduced.
+    pub fn square(&self) -> Self {
+        Self::sum_of_products(&self.modulus, &[(1, self, self)], &[], 0)
+    }
+
+    /// Returns the inverse of the element, which must be invertible modulo the modulus.
+    pub fn inverse(&self) -> Self {
+        let mode = if self.is_constant() { Mode::Constant } else { Mode::Private };
+        // Witness the inverse.
+        // Note: If the element is not invertible, the witness is zero and the constraint below is unsatisfied.
+        let inverse = mod_inverse(&self.value(), &self.modulus).unwrap_or_default();
+        let inverse = Self::new(mode, &inverse, &self.modulus);
+        // Ensure `self * inverse - 1 == 0`.
+        Self::assert_zero(&self.modulus, &[(1, self, &inverse)], &[], -1);
+        inverse
+    }
+
+    /// Returns the element `sum(c * a * b) + sum(c * x) + constant` for the given products `(c, a, b)`
+    /// and linear terms `(c, x)`, which is not necessarily reduced.
+    pub fn sum_of_products(
+        modulus: &BigUint,
+        products: &[(i64, &Self, &Self)],
+        linear: &[(i64, &Self)],
+        constant: i64,
+    ) -> Self {
+        Self::reduce(modulus, products, linear, constant, false)
+    }
+
+    /// Enforces that `sum(c * a * b) + sum(c * x) + constant` is zero in the field, for the given
+    /// products `(c, a, b)` and linear terms `(c, x)`.
+    pub fn assert_zero(modulus: &BigUint, products: &[(i64, &Self, &Self)], linear: &[(i64, &Self)], constant: i64) {
+        Self::reduce(modulus, products, linear, constant, true);
+    }
+}
+
+impl<E: Environment> Add<&NonNativeField<E>> for &NonNativeField<E> {
+    type Output = NonNativeField<E>;
+
+    /// Returns `self + other`, which is not necessarily reduced.
+    fn add(self, other: &NonNativeField<E>) -> Self::Output {
+        NonNativeField::sum_of_products(&self.modulus, &[], &[(1, self), (1, other)], 0)
+    }
+}
+
+impl<E: Environment> Sub<&NonNativeField<E>> for &NonNativeField<E> {
+    type Output = NonNativeField<E>;
+
+    /// Returns `self - other`, which is not necessarily reduced.
+    fn sub(self, other: &NonNativeField<E>) -> Self::Output {
+        NonNativeField::sum_of_products(&self.modulus, &[], &[(1, self), (-1, other)], 0)
+    }
+}
+
+impl<E: Environment> Mul<&NonNativeField<E>> for &NonNativeField<E> {
+    type Output = NonNativeField<E>;
+
+    /// Returns `self * other`, which is not necessarily reduced.
+    fn mul(self, other: &NonNativeField<E>) -> Self::Output {
+        NonNativeField::sum_of_products(&self.modulus, &[(1, self, other)], &[], 0)
+    }
+}
+
+impl<E: Environment> Neg for &NonNativeField<E> {
+    type Output = NonNativeField<E>;
+
+    /// Returns `-self`, which is not necessarily reduced.
+    fn neg(self) -> Self::Output {
+        NonNativeField::sum_of_products(&self.modulus, &[], &[(-1, self)], 0)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 3;
+
+    /// Returns the secp256k1 base field, BLS12-381 base field, and an RSA-2048 modulus.
+    fn moduli(rng: &mut TestRng) -> Vec<BigUint> {
+        let secp256k1 = console::Secp256k1::new().base_modulus().clone();
+        let bls12_381 = BigUint::parse_bytes(
+            b"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+            16,
+        )
+        .unwrap();
+        // Note: An RSA modulus is composite, so a random odd 2048-bit integer exercises the same code paths.
+        let rsa = sample(rng, 32) | (BigUint::from(1u32) << 2047) | BigUint::from(1u32);
+        vec![secp256k1, bls12_381, rsa]
+    }
+
+    /// Samples a random integer in `[0, 2^(64 * num_limbs))`.
+    fn sample(rng: &mut TestRng, num_limbs: usize) -> BigUint {
+        BigUint::from_bytes_le(&(0..num_limbs * 8).map(|_| u8::rand(rng)).collect::<Vec<_>>())
+    }
+
+    fn check_arithmetic(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for p in moduli(&mut rng) {
+            let n = num_limbs(&p);
+            for i in 0..ITERATIONS {
+                let (a, b, c) = (sample(&mut rng, n), sample(&mut rng, n), sample(&mut rng, n));
+                Circuit::scope(format!("NonNativeField {mode} {n} {i}"), || {
+                    let (x, y, z) = (
+                        NonNativeField::<Circuit>::new(mode, &a, &p),
+                        NonNativeField::new(mode, &b, &p),
+                        NonNativeField::new(mode, &c, &p),
+                    );
+                    // Ensure the basic operations are computed correctly.
+                    assert_eq!((&a + &b) % &p, (&x + &y).to_canonical().value());
+                    assert_eq!((&a + &p - &b % &p) % &p, (&x - &y).to_canonical().value());
+                    assert_eq!((&a * &b) % &p, (&x * &y).to_canonical().value());
+                    assert_eq!((&a * &a) % &p, x.square().to_canonical().value());
+                    assert_eq!((&p - &a % &p) % &p, (-&x).to_canonical().value());
+                    // Ensure `x * y - 3 * z - 7` is computed correctly.
+                    let expected = (&a * &b + &p * 4u32 - (&c * 3u32) % &p - 7u32) % &p;
+                    let candidate = NonNativeField::sum_of_products(&p, &[(1, &x, &y)], &[(-3, &z)], -7).to_canonical();
+                    assert_eq!(expected, candidate.value());
+                    // Ensure the inverse is correct, if it exists.
+                    if let Some(expected) = mod_inverse(&a, &p) {
+                        assert_eq!(expected, x.inverse().to_canonical().value());
+                    }
+                    // Ensure equality holds up to the modulus.
+                    assert!(x.is_equal(&NonNativeField::new(mode, &(&a % &p), &p)).eject_value());
+                    assert!(!x.is_equal(&y).eject_value());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_constant() {
+        check_arithmetic(Mode::Constant);
+    }
+
+    #[test]
+    fn test_arithmetic_private() {
+        check_arithmetic(Mode::Private);
+    }
+
+    #[test]
+    fn test_inverse_of_zero_fails() {
+        let p = console::Secp256k1::new().base_modulus().clone();
+        let x = NonNativeField::<Circuit>::new(Mode::Private, &p, &p);
+        x.inverse();
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod arithmetic;
+mod reduce;
+
+use snarkvm_circuit_types::prelude::*;
+
+use num_bigint::{BigInt, BigUint};
+
+/// The number of bits in a limb of a non-native field element.
+const LIMB_BITS: usize = 64;
+
+/// An element of the integers modulo an arbitrary modulus, which is emulated with 64-bit limbs.
+///
+/// The number of limbs is the number of 64-bit words in the modulus, so the same gadget serves
+/// secp256k1 and ed25519 (4 limbs), BLS12-381 (6 limbs), and RSA-2048 (32 limbs).
+///
+/// The limbs are range-checked, however the element is not necessarily reduced,
+/// i.e. it is an integer in `[0, 2^(64 * num_limbs))` that is congruent to the value of the element.
+/// Use [`NonNativeField::to_canonical`] to obtain the reduced representation.
+#[derive(Clone)]
+pub struct NonNativeField<E: Environment> {
+    /// The little-endian limbs of the element.
+    limbs: Vec<Field<E>>,
+    /// The modulus of the field.
+    modulus: BigUint,
+}
+
+impl<E: Environment> NonNativeField<E> {
+    /// Initializes a constant element from the given integer, which is reduced modulo `modulus`.
+    pub fn constant(value: &BigUint, modulus: &BigUint) -> Self {
+        let limbs = to_limbs(&(value % modulus), num_limbs(modulus))
+            .into_iter()
+            .map(|limb| Field::constant(console::Field::from_u64(limb)))
+            .collect();
+        Self { limbs, modulus: modulus.clone() }
+    }
+
+    /// Initializes a new element from the given integer, which must fit in the limbs of the modulus.
+    pub fn new(mode: Mode, value: &BigUint, modulus: &BigUint) -> Self {
+        // Ensure the value fits in the limbs.
+        if num_limbs(value) > num_limbs(modulus) {
+            E::halt("Attempted to initialize a non-native field element that exceeds its limbs")
+        }
+        Self { limbs: witness_limbs(mode, value, num_limbs(modulus)), modulus: modulus.clone() }
+    }
+
+    /// Initializes an element from at most `64 * num_limbs` little-endian bits.
+    pub fn from_bits_le(bits_le: &[Boolean<E>], modulus: &BigUint) -> Self {
+        // Ensure the number of bits is within the allowed size.
+        if bits_le.len() > LIMB_BITS * num_limbs(modulus) {
+            E::halt(format!("Attempted to initialize a non-native field element from {} bits", bits_le.len()))
+        }
+        let mut limbs = bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect::<Vec<_>>();
+        limbs.resize(num_limbs(modulus), Field::zero());
+        Self { limbs, modulus: modulus.clone() }
+    }
+
+    /// Returns the modulus of the field.
+    pub const fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// Returns the number of 64-bit limbs in the element.
+    pub fn num_limbs(&self) -> usize {
+        self.limbs.len()
+    }
+
+    /// Returns the same integer as an element of the field with the given modulus,
+    /// which must have the same number of limbs.
+    pub fn with_modulus(&self, modulus: &BigUint) -> Self {
+        // Ensure the number of limbs match.
+        if num_limbs(modulus) != self.limbs.len() {
+            E::halt("Attempted to change the modulus of a non-native field element to a different size")
+        }
+        Self { limbs: self.limbs.clone(), modulus: modulus.clone() }
+    }
+
+    /// Returns `true` if the element is a constant.
+    pub fn is_constant(&self) -> bool {
+        self.limbs.iter().all(|limb| limb.is_constant())
+    }
+
+    /// Returns the integer value of the element, which is not necessarily reduced.
+    pub fn value(&self) -> BigUint {
+        self.limbs.iter().rev().fold(BigUint::default(), |value, limb| (value << LIMB_BITS) + to_biguint(limb))
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        // Ensure the moduli match.
+        if first.modulus != second.modulus {
+            E::halt("Attempted to select between elements of different non-native fields")
+        }
+        let limbs = first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b)).collect();
+        Self { limbs, modulus: first.modulus.clone() }
+    }
+
+    /// Returns the `64 * num_limbs` little-endian bits of the integer value of the element, which is not necessarily reduced.
+    pub fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_BITS)).collect()
+    }
+}
+
+/// Returns `true` if the integer with the given little-endian bits is less than the constant `value`.
+pub(crate) fn is_less_than<E: Environment>(bits_le: &[Boolean<E>], value: &BigUint) -> Boolean<E> {
+    bits_le.iter().enumerate().fold(Boolean::constant(false), |is_less_than, (i, bit)| {
+        // Note: If the bits are equal, the output is determined by the less significant bits.
+        match value.bit(i as u64) {
+            true => !bit | is_less_than,
+            false => !bit & is_less_than,
+        }
+    })
+}
+
+/// Returns the inverse of `value` modulo `modulus`, or `None` if they are not coprime.
+fn mod_inverse(value: &BigUint, modulus: &BigUint) -> Option<BigUint> {
+    // Note: This is the extended Euclidean algorithm, which also supports composite moduli.
+    let modulus = BigInt::from(modulus.clone());
+    let (mut r0, mut r1) = (modulus.clone(), BigInt::from(value.clone()) % &modulus);
+    let (mut t0, mut t1) = (BigInt::default(), BigInt::from(1u32));
+    while r1 != BigInt::default() {
+        let quotient = &r0 / &r1;
+        (r0, r1) = (r1.clone(), r0 - &quotient * r1);
+        (t0, t1) = (t1.clone(), t0 - &quotient * t1);
+    }
+    match r0 == BigInt::from(1u32) {
+        true => (((t0 % &modulus) + &modulus) % &modulus).to_biguint(),
+        false => None,
+    }
+}
+
+/// Returns `2^64` as a console field element.
+fn two_to_limb_bits<N: console::Network>() -> console::Field<N> {
+    console::Field::from_u128(1u128 << LIMB_BITS)
+}
+
+/// Returns the given signed integer as a constant field element.
+fn signed_constant<E: Environment>(value: i64) -> Field<E> {
+    let magnitude = Field::constant(console::Field::from_u64(value.unsigned_abs()));
+    match value.is_negative() {
+        true => -magnitude,
+        false => magnitude,
+    }
+}
+
+/// Returns the number of 64-bit limbs in the given integer.
+fn num_limbs(value: &BigUint) -> usize {
+    value.iter_u64_digits().len()
+}
+
+/// Returns the given integer as `num_limbs` little-endian 64-bit limbs.
+fn to_limbs(value: &BigUint, num_limbs: usize) -> Vec<u64> {
+    let mut limbs = value.to_u64_digits();
+    limbs.resize(num_limbs, 0);
+    limbs
+}
+
+/// Returns `num_limbs` range-checked limbs, witnessed from the given integer.
+fn witness_limbs<E: Environment>(mode: Mode, value: &BigUint, num_limbs: usize) -> Vec<Field<E>> {
+    to_limbs(value, num_limbs)
+        .into_iter()
+        .map(|limb| {
+            let limb = Field::new(mode, console::Field::from_u64(limb));
+            // Ensure the limb is within `[0, 2^64)`.
+            limb.to_lower_bits_le(LIMB_BITS);
+            limb
+        })
+        .collect()
+}
+
+/// Returns the integer value of the given field element.
+fn to_biguint<E: Environment>(field: &Field<E>) -> BigUint {
+    match console::ToBytes::to_bytes_le(&field.eject_value()) {
+        Ok(bytes) => BigUint::from_bytes_le(&bytes),
+        Err(error) => E::halt(format!("Failed to convert a field element into bytes: {error}")),
+    }
+}
+
+/// Returns the lower 128 bits of the given integer.
+fn to_u128(value: &BigUint) -> u128 {
+    value.iter_u64_digits().take(2).rev().fold(0, |output, digit| (output << 64) | u128::from(digit))
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    /// The base field modulus of BLS12-381.
+    const BLS12_381_BASE_MODULUS: &str =
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+    #[test]
+    fn test_num_limbs() {
+        let secp256k1 = console::Secp256k1::new();
+        let bls12_381 = BigUint::parse_bytes(BLS12_381_BASE_MODULUS.as_bytes(), 16).unwrap();
+        let rsa = (BigUint::from(1u32) << 2048) - 1u32;
+
+        assert_eq!(NonNativeField::<Circuit>::constant(&BigUint::default(), secp256k1.base_modulus()).num_limbs(), 4);
+        assert_eq!(NonNativeField::<Circuit>::constant(&BigUint::default(), &bls12_381).num_limbs(), 6);
+        assert_eq!(NonNativeField::<Circuit>::constant(&BigUint::default(), &rsa).num_limbs(), 32);
+        assert_eq!(NonNativeField::<Circuit>::new(Mode::Private, &rsa, &rsa).to_bits_le().len(), 2048);
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        // Ensure composite moduli are supported.
+        let modulus = BigUint::from(3u32 * 5 * 7 * 11);
+        for value in 1u32..1155 {
+            let value = BigUint::from(value);
+            match mod_inverse(&value, &modulus) {
+                Some(inverse) => assert_eq!(BigUint::from(1u32), (&value * inverse) % &modulus),
+                None => assert_ne!(BigUint::from(1u32), gcd(&value, &modulus)),
+            }
+        }
+        assert!(mod_inverse(&BigUint::default(), &modulus).is_none());
+    }
+
+    /// Returns the greatest common divisor of the given integers.
+    fn gcd(a: &BigUint, b: &BigUint) -> BigUint {
+        match b == &BigUint::default() {
+            true => a.clone(),
+            false => gcd(b, &(a % b)),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> NonNativeField<E> {
+    /// Returns the reduced representation of the element, which is less than the modulus.
+    pub fn to_canonical(&self) -> Self {
+        // Compute an element that is congruent to `self`.
+        let result = Self::sum_of_products(&self.modulus, &[], &[(1, self)], 0);
+        let mode = if result.is_constant() { Mode::Constant } else { Mode::Private };
+
+        // Witness `difference := (modulus - 1) - result`.
+        let (value, max) = (result.value(), &self.modulus - 1u32);
+        let difference = Self::new(mode, &if value <= max { &max - &value } else { BigUint::default() }, &self.modulus);
+
+        // Ensure `result + difference == modulus - 1`, which implies `result < modulus`.
+        let mut carry = Boolean::constant(false);
+        let mut carry_value = BigUint::default();
+        for ((a, b), max) in result.limbs.iter().zip_eq(&difference.limbs).zip_eq(to_limbs(&max, result.num_limbs())) {
+            // Witness the carry of `a + b + carry`.
+            carry_value = (to_biguint(a) + to_biguint(b) + carry_value) >> LIMB_BITS;
+            let carry_out = Boolean::new(mode, carry_value.bits() != 0);
+            // Ensure `a + b + carry == max + carry_out * 2^64`.
+            E::assert_eq(
+                a + b + Field::from_boolean(&carry),
+                Field::constant(console::Field::from_u64(max))
+                    + Field::from_boolean(&carry_out) * Field::constant(two_to_limb_bits()),
+            );
+            carry = carry_out;
+        }
+        E::assert(!carry);
+
+        result
+    }
+
+    /// Returns `true` if the elements are congruent modulo the modulus.
+    pub fn is_equal(&self, other: &Self) -> Boolean<E> {
+        // Ensure the moduli match.
+        if self.modulus != other.modulus {
+            E::halt("Attempted to compare elements of different non-native fields")
+        }
+        let (a, b) = (self.to_canonical(), other.to_canonical());
+        a.limbs.iter().zip_eq(&b.limbs).fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b))
+    }
+}
+
+impl<E: Environment> NonNativeField<E> {
+    /// Enforces `sum(c * a * b) + sum(c * x) + constant == result (mod modulus)`, and returns `result`,
+    /// or enforces `sum(c * a * b) + sum(c * x) + constant == 0 (mod modulus)` if `is_zero` is `true`.
+    ///
+    /// This is done by witnessing the quotient `q` and checking the integer identity
+    /// `sum(c * a * b) + sum(c * x) + constant + offset * modulus - result - q * modulus == 0`
+    /// limb-wise with carries, where `offset` ensures `q` is non-negative.
+    pub(super) fn reduce(
+        modulus: &BigUint,
+        products: &[(i64, &Self, &Self)],
+        linear: &[(i64, &Self)],
+        constant: i64,
+        is_zero: bool,
+    ) -> Self {
+        // Ensure the moduli match.
+        if products.iter().any(|(_, a, b)| &a.modulus != modulus || &b.modulus != modulus)
+            || linear.iter().any(|(_, x)| &x.modulus != modulus)
+        {
+            E::halt("Attempted to combine elements of different non-native fields")
+        }
+
+        // Determine the mode of the witnesses.
+        let is_constant = products.iter().all(|(_, a, b)| a.is_constant() && b.is_constant())
+            && linear.iter().all(|(_, x)| x.is_constant());
+        let mode = if is_constant { Mode::Constant } else { Mode::Private };
+
+        // Compute upper bounds on the positive and negative parts of the expression.
+        let num_modulus_limbs = num_limbs(modulus);
+        let bound = BigUint::from(1u32) << (LIMB_BITS * num_modulus_limbs);
+        let (mut positive, mut negative) = (BigUint::default(), BigUint::default());
+        let terms = products.iter().map(|(c, _, _)| (*c, &bound * &bound));
+        for (coefficient, magnitude) in terms.chain(linear.iter().map(|(c, _)| (*c, bound.clone()))) {
+            match coefficient.is_negative() {
+                true => negative += magnitude * coefficient.unsigned_abs(),
+                false => positive += magnitude * coefficient.unsigned_abs(),
+            }
+        }
+        match constant.is_negative() {
+            true => negative += constant.unsigned_abs(),
+            false => positive += constant.unsigned_abs(),
+        }
+
+        // Offset the constant by a multiple of the modulus, so that the expression is non-negative.
+        let offset = &negative / modulus + 1u32;
+        let constant = match constant.is_negative() {
+            true => &offset * modulus - constant.unsigned_abs(),
+            false => &offset * modulus + constant.unsigned_abs(),
+        };
+        // Compute the number of limbs in the quotient.
+        let max_quotient = (positive + &offset * modulus) / modulus + 1u32;
+        let num_quotient_limbs = num_limbs(&max_quotient);
+
+        // Compute the value of the expression.
+        let mut value = BigInt::from(constant.clone());
+        for (coefficient, a, b) in products {
+            value += BigInt::from(*coefficient) * BigInt::from(a.value() * b.value());
+        }
+        for (coefficient, x) in linear {
+            value += BigInt::from(*coefficient) * BigInt::from(x.value());
+        }
+        // Note: The expression is non-negative, as the offset exceeds the negative part.
+        let value = value.to_biguint().unwrap_or_default();
+
+        // Witness the result and the quotient.
+        let result = match is_zero {
+            true => Self::constant(&BigUint::default(), modulus),
+            false => Self::new(mode, &(&value % modulus), modulus),
+        };
+        let quotient = witness_limbs(mode, &(&value / modulus), num_quotient_limbs);
+
+        // Compute each limb of the integer identity, and its value.
+        let constant_limbs = to_limbs(&constant, num_limbs(&constant));
+        let modulus_limbs = to_limbs(modulus, num_modulus_limbs);
+        let num_positions =
+            (2 * num_modulus_limbs - 1).max(num_quotient_limbs + num_modulus_limbs - 1).max(constant_limbs.len());
+        let mut positions = vec![(Field::<E>::zero(), BigInt::default()); num_positions];
+        for (coefficient, a, b) in products {
+            for (i, a) in a.limbs.iter().enumerate() {
+                for (j, b) in b.limbs.iter().enumerate() {
+                    positions[i + j].0 += signed_constant::<E>(*coefficient) * (a * b);
+                    positions[i + j].1 += BigInt::from(*coefficient) * BigInt::from(to_biguint(a) * to_biguint(b));
+                }
+            }
+        }
+        for (coefficient, x) in linear {
+            for (i, x) in x.limbs.iter().enumerate() {
+                positions[i].0 += signed_constant::<E>(*coefficient) * x;
+                positions[i].1 += BigInt::from(*coefficient) * BigInt::from(to_biguint(x));
+            }
+        }
+        for (i, limb) in constant_limbs.into_iter().enumerate() {
+            positions[i].0 += Field::constant(console::Field::from_u64(limb));
+            positions[i].1 += BigInt::from(limb);
+        }
+        for (i, r) in result.limbs.iter().enumerate() {
+            positions[i].0 -= r;
+            positions[i].1 -= BigInt::from(to_biguint(r));
+        }
+        for (i, q) in quotient.iter().enumerate() {
+            for (j, p) in modulus_limbs.iter().enumerate() {
+                positions[i + j].0 -= q * Field::constant(console::Field::from_u64(*p));
+                positions[i + j].1 -= BigInt::from(to_biguint(q) * *p);
+            }
+        }
+
+        // Compute the number of bits in a carry, which grows with the number of limbs and the coefficients.
+        let carry_bits = Self::num_carry_bits(products, linear, num_modulus_limbs, num_quotient_limbs);
+
+        // Ensure the integer identity holds, by propagating the carry of each limb to the next limb.
+        let shift = BigInt::from(1u32) << (carry_bits - 1);
+        let shift_field = Field::<E>::constant(console::Field::from_u128(1u128 << (carry_bits - 1)));
+        let mut carry = (Field::<E>::zero(), BigInt::default());
+        for (index, (limb, limb_value)) in positions.into_iter().enumerate() {
+            let (limb, limb_value) = (limb + &carry.0, limb_value + &carry.1);
+            // Ensure the most significant limb is zero.
+            if index + 1 == num_positions {
+                E::assert_eq(limb, Field::<E>::zero());
+                break;
+            }
+            // Witness the carry, shifted by `2^(carry_bits - 1)` to be non-negative.
+            let carry_value = &limb_value >> LIMB_BITS;
+            let shifted = (&carry_value + &shift).to_biguint().unwrap_or_default();
+            let shifted = Field::new(mode, console::Field::from_u128(to_u128(&shifted)));
+            // Ensure the carry is within `[-2^(carry_bits - 1), 2^(carry_bits - 1))`.
+            shifted.to_lower_bits_le(carry_bits);
+            let carry_field = shifted - &shift_field;
+            // Ensure `limb + carry_in == carry_out * 2^64`.
+            E::assert_eq(limb, &carry_field * Field::constant(two_to_limb_bits()));
+            carry = (carry_field, carry_value);
+        }
+
+        result
+    }
+
+    /// Returns the number of bits in a signed carry of the limb-wise reduction.
+    ///
+    /// Each position of the integer identity is bounded in magnitude by
+    /// `(sum(|c|) * num_limbs + num_limbs) * 2^128 + (sum(|c|) + 2) * 2^64`, for the products,
+    /// the quotient, and the linear, constant, and result terms, respectively.
+    /// As the incoming carry is at most twice this bound divided by `2^64`, so is every carry.
+    fn num_carry_bits(
+        products: &[(i64, &Self, &Self)],
+        linear: &[(i64, &Self)],
+        num_modulus_limbs: usize,
+        num_quotient_limbs: usize,
+    ) -> usize {
+        let product_weight: u64 = products.iter().map(|(c, _, _)| c.unsigned_abs()).sum();
+        let linear_weight: u64 = linear.iter().map(|(c, _)| c.unsigned_abs()).sum();
+        let num_limbs = num_modulus_limbs as u64;
+        let bound = (BigUint::from(product_weight * num_limbs + num_quotient_limbs.min(num_modulus_limbs) as u64)
+            << (2 * LIMB_BITS))
+            + (BigUint::from(linear_weight + 2) << LIMB_BITS);
+        // Note: The additional bit is for the sign of the carry.
+        let carry_bits = ((bound >> LIMB_BITS) * 2u32).bits() as usize + 1;
+        // Ensure the carry fits in 128 bits, which ensures no position overflows the base field.
+        if carry_bits > 128 {
+            E::halt("Attempted to reduce a non-native expression with too many terms")
+        }
+        carry_bits
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    #[test]
+    fn test_to_canonical_unreduced() {
+        let curve = console::Secp256k1::new();
+        let p = curve.base_modulus();
+
+        // Initialize an element that is congruent to 1, but is not reduced.
+        let x = NonNativeField::<Circuit>::new(Mode::Private, &(p + 1u32), p);
+        assert_eq!(BigUint::from(1u32), x.to_canonical().value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_reduce_wrong_result_fails() {
+        let p = console::Secp256k1::new().base_modulus().clone();
+        let (a, b) = (&p - 2u32, &p - 3u32);
+
+        // Ensure the assertion fails for a nonzero expression, i.e. `a * b - 5 == 1 (mod p)`.
+        let (x, y) =
+            (NonNativeField::<Circuit>::new(Mode::Private, &a, &p), NonNativeField::new(Mode::Private, &b, &p));
+        NonNativeField::assert_zero(&p, &[(1, &x, &y)], &[], -5);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure the assertion holds for a zero expression, i.e. `a * b - 6 == 0 (mod p)`.
+        let (x, y) =
+            (NonNativeField::<Circuit>::new(Mode::Private, &a, &p), NonNativeField::new(Mode::Private, &b, &p));
+        NonNativeField::assert_zero(&p, &[(1, &x, &y)], &[], -6);
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
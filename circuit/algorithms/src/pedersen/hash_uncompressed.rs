@@ -23,12 +23,13 @@ impl<E: Environment, const NUM_BITS: u8> HashUncompressed for Pedersen<E, NUM_BI
     /// Returns the Pedersen hash of the given input as an affine group element.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Self::Output {
         // Ensure the input is within the size bounds.
+        let num_bits = self.base_window.len();
         let mut input = Cow::Borrowed(input);
-        match input.len() <= NUM_BITS as usize {
+        match input.len() <= num_bits {
             // Pad the input if it is under the required parameter size.
-            true => input.to_mut().resize(NUM_BITS as usize, Boolean::constant(false)),
+            true => input.to_mut().resize(num_bits, Boolean::constant(false)),
             // Ensure the input size is within the parameter size.
-            false => E::halt(format!("The Pedersen hash input cannot exceed {NUM_BITS} bits.")),
+            false => E::halt(format!("The Pedersen hash input cannot exceed {num_bits} bits.")),
         }
 
         // Compute the sum of base_i^{input_i} for all i.
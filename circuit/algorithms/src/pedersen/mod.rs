@@ -27,6 +27,8 @@ use snarkvm_circuit_types::prelude::*;
 pub type Pedersen64<E> = Pedersen<E, 64>;
 /// Pedersen128 is an *additively-homomorphic* collision-resistant hash function that takes up to a 128-bit input.
 pub type Pedersen128<E> = Pedersen<E, 128>;
+/// CustomPedersen is an *additively-homomorphic* collision-resistant hash function whose input size is chosen at runtime.
+pub type CustomPedersen<E> = Pedersen<E, 0>;
 
 /// Pedersen is a collision-resistant hash function that takes a variable-length input.
 /// The Pedersen hash function does *not* behave like a random oracle, see Poseidon for one.
@@ -45,7 +47,7 @@ impl<E: Environment, const NUM_BITS: u8> Inject for Pedersen<E, NUM_BITS> {
     fn new(_mode: Mode, pedersen: Self::Primitive) -> Self {
         // Initialize the base window.
         let base_window = Vec::constant(pedersen.base_window().iter().copied().collect());
-        assert_eq!(base_window.len(), NUM_BITS as usize);
+        assert_eq!(base_window.len(), pedersen.num_bits());
 
         // Initialize the random base.
         let random_base = Vec::constant(pedersen.random_base_window().iter().copied().collect());
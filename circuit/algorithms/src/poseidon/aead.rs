@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_circuit_types::Boolean;
+
+/// The domain separator for authenticated encryption.
+const AEAD_DOMAIN: &str = "AleoPoseidonAEAD0";
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Encrypts the given plaintext under the given key and nonce, authenticating the associated data,
+    /// and returns the ciphertext and the authentication tag.
+    ///
+    /// Note: A nonce must never be reused with the same key.
+    pub fn encrypt(
+        &self,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        associated_data: &[Field<E>],
+        plaintext: &[Field<E>],
+    ) -> (Vec<Field<E>>, Field<E>) {
+        // Initialize the sponge.
+        let (mut state, mut mode) = self.initialize_aead(key, nonce, associated_data, plaintext.len());
+
+        // Encrypt the plaintext, one block at a time.
+        let mut ciphertext = Vec::with_capacity(plaintext.len());
+        for block in plaintext.chunks(RATE) {
+            // Mask the plaintext block with the squeezed elements.
+            let mask = self.squeeze(&mut state, &mut mode, block.len() as u16);
+            let ciphertext_block = block.iter().zip_eq(&mask).map(|(p, m)| p + m).collect::<Vec<_>>();
+            // Absorb the ciphertext block.
+            self.absorb(&mut state, &mut mode, &ciphertext_block);
+            ciphertext.extend(ciphertext_block);
+        }
+
+        // Squeeze the authentication tag.
+        let tag = self.squeeze(&mut state, &mut mode, 1)[0].clone();
+        (ciphertext, tag)
+    }
+
+    /// Decrypts the given ciphertext under the given key and nonce, and returns the plaintext,
+    /// along with a boolean indicating whether the tag authenticates both the ciphertext and the associated data.
+    pub fn decrypt(
+        &self,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        associated_data: &[Field<E>],
+        ciphertext: &[Field<E>],
+        tag: &Field<E>,
+    ) -> (Vec<Field<E>>, Boolean<E>) {
+        // Initialize the sponge.
+        let (mut state, mut mode) = self.initialize_aead(key, nonce, associated_data, ciphertext.len());
+
+        // Decrypt the ciphertext, one block at a time.
+        let mut plaintext = Vec::with_capacity(ciphertext.len());
+        for block in ciphertext.chunks(RATE) {
+            // Unmask the ciphertext block with the squeezed elements.
+            let mask = self.squeeze(&mut state, &mut mode, block.len() as u16);
+            plaintext.extend(block.iter().zip_eq(&mask).map(|(c, m)| c - m));
+            // Absorb the ciphertext block.
+            self.absorb(&mut state, &mut mode, block);
+        }
+
+        // Check the authentication tag.
+        let is_valid = self.squeeze(&mut state, &mut mode, 1)[0].is_equal(tag);
+        (plaintext, is_valid)
+    }
+
+    /// Returns a sponge state that has absorbed the key, nonce, lengths, and associated data.
+    fn initialize_aead(
+        &self,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        associated_data: &[Field<E>],
+        message_length: usize,
+    ) -> (Vec<Field<E>>, DuplexSpongeMode) {
+        // Construct the preimage: [ DOMAIN || AEAD_DOMAIN || KEY || NONCE || LENGTH(AD) || LENGTH(MESSAGE) || AD ].
+        let mut preimage = Vec::with_capacity(6 + associated_data.len());
+        preimage.push(self.domain.clone());
+        preimage.push(Field::constant(console::Field::new_domain_separator(AEAD_DOMAIN)));
+        preimage.push(key.clone());
+        preimage.push(nonce.clone());
+        preimage.push(Field::constant(console::Field::from_u128(associated_data.len() as u128)));
+        preimage.push(Field::constant(console::Field::from_u128(message_length as u128)));
+        preimage.extend_from_slice(associated_data);
+
+        // Initialize a new sponge.
+        let mut state = vec![Field::zero(); RATE + CAPACITY];
+        let mut mode = DuplexSpongeMode::Absorbing { next_absorb_index: 0 };
+
+        // Absorb the preimage.
+        self.absorb(&mut state, &mut mode, &preimage);
+        (state, mode)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const ITERATIONS: usize = 3;
+
+    fn check_aead<const RATE: usize>(mode: Mode, rng: &mut TestRng) -> Result<()> {
+        let native = console::Poseidon::<CurrentNetwork, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for num_elements in 0..=(2 * RATE + 1) {
+            for i in 0..ITERATIONS {
+                // Sample the key, nonce, associated data, and plaintext.
+                let native_key = console::Field::<CurrentNetwork>::rand(rng);
+                let native_nonce = console::Field::<CurrentNetwork>::rand(rng);
+                let native_associated_data = (0..i).map(|_| console::Field::rand(rng)).collect::<Vec<_>>();
+                let native_plaintext = (0..num_elements).map(|_| console::Field::rand(rng)).collect::<Vec<_>>();
+
+                // Compute the native ciphertext.
+                let (expected_ciphertext, expected_tag) =
+                    native.encrypt(&native_key, &native_nonce, &native_associated_data, &native_plaintext);
+
+                // Inject the inputs.
+                let key = Field::<Circuit>::new(mode, native_key);
+                let nonce = Field::<Circuit>::new(mode, native_nonce);
+                let associated_data: Vec<Field<Circuit>> = Inject::new(mode, native_associated_data.clone());
+                let plaintext: Vec<Field<Circuit>> = Inject::new(mode, native_plaintext.clone());
+
+                Circuit::scope(format!("Poseidon AEAD {mode} {num_elements} {i}"), || {
+                    // Ensure the circuit ciphertext matches the native ciphertext.
+                    let (ciphertext, tag) = poseidon.encrypt(&key, &nonce, &associated_data, &plaintext);
+                    assert_eq!(expected_ciphertext, ciphertext.eject_value());
+                    assert_eq!(expected_tag, tag.eject_value());
+
+                    // Ensure the ciphertext decrypts to the plaintext.
+                    let (candidate, is_valid) = poseidon.decrypt(&key, &nonce, &associated_data, &ciphertext, &tag);
+                    assert_eq!(native_plaintext, candidate.eject_value());
+                    assert!(is_valid.eject_value());
+
+                    // Ensure decryption fails with a modified tag.
+                    let modified_tag = &tag + &Field::one();
+                    let (_, is_valid) = poseidon.decrypt(&key, &nonce, &associated_data, &ciphertext, &modified_tag);
+                    assert!(!is_valid.eject_value());
+                });
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_aead_constant() -> Result<()> {
+        check_aead::<2>(Mode::Constant, &mut TestRng::default())?;
+        check_aead::<4>(Mode::Constant, &mut TestRng::default())
+    }
+
+    #[test]
+    fn test_aead_public() -> Result<()> {
+        check_aead::<2>(Mode::Public, &mut TestRng::default())?;
+        check_aead::<4>(Mode::Public, &mut TestRng::default())
+    }
+
+    #[test]
+    fn test_aead_private() -> Result<()> {
+        check_aead::<2>(Mode::Private, &mut TestRng::default())?;
+        check_aead::<4>(Mode::Private, &mut TestRng::default())
+    }
+}
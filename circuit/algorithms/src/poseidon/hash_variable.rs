@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns the hash of the first `length` elements of the given input, which is equivalent to
+    /// `hash(&input[..length])`. The number of constraints only depends on the maximum length, `input.len()`.
+    ///
+    /// Note: The circuit is not satisfied if `length` is greater than `input.len()`.
+    pub fn hash_variable(&self, input: &[Field<E>], length: &Field<E>) -> Field<E> {
+        // Compute the indicators for `length == i`, for each candidate length `i` in `[0, input.len()]`.
+        let is_length = (0..=input.len())
+            .map(|i| length.is_equal(&Field::constant(console::Field::from_u128(i as u128))))
+            .collect::<Vec<_>>();
+        // Ensure the length is at most `input.len()`, by ensuring exactly one of the indicators is set.
+        let num_lengths = is_length.iter().fold(Field::zero(), |sum, is_length| sum + Field::from_boolean(is_length));
+        E::assert_eq(&num_lengths, Field::<E>::one());
+
+        // Zero out the input elements at or beyond the length, as the native hash does not absorb them.
+        // Note: The element at index `i` is absorbed if `length` is not in `[0, i]`.
+        let mut is_absorbed = Field::<E>::one();
+        let input = input
+            .iter()
+            .zip(&is_length)
+            .map(|(element, is_length)| {
+                is_absorbed -= Field::from_boolean(is_length);
+                element * &is_absorbed
+            })
+            .collect::<Vec<_>>();
+
+        // Initialize a new sponge, and absorb the first block: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] ].
+        let mut state = vec![Field::zero(); RATE + CAPACITY];
+        state[CAPACITY] = self.domain.clone();
+        state[CAPACITY + 1] = length.clone();
+
+        // Absorb the input one block at a time, recording the output of squeezing after each block.
+        // Note: The `k`-th output is the hash of an input with `k` blocks.
+        let mut outputs = Vec::with_capacity(1 + (input.len() + RATE - 1) / RATE);
+        for block in input.chunks(RATE) {
+            self.permute(&mut state);
+            outputs.push(state[CAPACITY].clone());
+            block.iter().enumerate().for_each(|(i, element)| state[CAPACITY + i] += element);
+        }
+        self.permute(&mut state);
+        outputs.push(state[CAPACITY].clone());
+
+        // Select the output for the number of blocks in the input, which is `ceil(length / RATE)`.
+        outputs.iter().enumerate().fold(Field::zero(), |hash, (num_blocks, output)| {
+            let is_num_blocks = is_length
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (i + RATE - 1) / RATE == num_blocks)
+                .fold(Field::zero(), |sum, (_, is_length)| sum + Field::from_boolean(is_length));
+            hash + is_num_blocks * output
+        })
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const RATE: usize = 4;
+
+    fn check_hash_variable(mode: Mode, max_length: usize, rng: &mut TestRng) -> Result<()> {
+        use console::Hash as H;
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        // Prepare the preimage.
+        let native_input =
+            (0..max_length).map(|_| console::Field::<<Circuit as Environment>::Network>::rand(rng)).collect::<Vec<_>>();
+        let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+        let mut expected_count = None;
+        for length in 0..=max_length {
+            // Compute the native hash.
+            let expected = native.hash(&native_input[..length])?;
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Poseidon {mode} {length}"), || {
+                let length = Field::<Circuit>::new(mode, console::Field::from_u128(length as u128));
+                let candidate = poseidon.hash_variable(&input, &length);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+
+                // Ensure the number of constraints does not depend on the length.
+                let count = Circuit::count_in_scope();
+                assert_eq!(*expected_count.get_or_insert(count), count);
+                if mode.is_constant() {
+                    assert_eq!(0, Circuit::num_constraints_in_scope());
+                }
+            });
+        }
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_variable_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+        for max_length in 0..=2 * RATE + 1 {
+            check_hash_variable(Mode::Constant, max_length, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_variable_public() -> Result<()> {
+        let mut rng = TestRng::default();
+        for max_length in 0..=2 * RATE + 1 {
+            check_hash_variable(Mode::Public, max_length, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_variable_private() -> Result<()> {
+        let mut rng = TestRng::default();
+        for max_length in 0..=2 * RATE + 1 {
+            check_hash_variable(Mode::Private, max_length, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_variable_exceeds_max_length() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native);
+
+        let input =
+            (0..RATE).map(|_| Field::<Circuit>::new(Mode::Private, console::Field::rand(&mut rng))).collect::<Vec<_>>();
+        let length = Field::<Circuit>::new(Mode::Private, console::Field::from_u128(RATE as u128 + 1));
+        let _candidate = poseidon.hash_variable(&input, &length);
+        assert!(!Circuit::is_satisfied());
+
+        Circuit::reset();
+        Ok(())
+    }
+}
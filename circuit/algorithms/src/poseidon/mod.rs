@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aead;
 mod hash;
 mod hash_many;
 mod hash_to_group;
 mod hash_to_scalar;
+mod hash_variable;
 mod prf;
 
 #[cfg(all(test, console))]
@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A 64-bit word, represented as little-endian bits.
+type Word<E> = Vec<Boolean<E>>;
+
+impl<E: Environment> Hash for Sha512<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-512 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // Initialize the state.
+        let mut state = INITIAL_STATE.iter().map(|value| Self::constant_word(*value)).collect::<Vec<_>>();
+
+        // Process each padded block.
+        for block in Self::pad(input).chunks(BLOCK_BITS) {
+            state = Self::compress(&state, block);
+        }
+
+        // Output the state as big-endian words.
+        state.iter().flat_map(Self::word_to_bytes_be).collect()
+    }
+}
+
+impl<E: Environment> Sha512<E> {
+    /// Pads the input, which is resized to a multiple of 8 bits, as `M || 0x80 || 0x00…0x00 || LENGTH(M)`,
+    /// where the length is a 128-bit big-endian integer, and the padded input is a multiple of 1024 bits.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+        let length = padded_input.len() as u128;
+
+        // Step 1: Append the "0x80" byte to the message.
+        padded_input.extend(Self::constant_bytes(&[0x80]));
+
+        // Step 2: Append "0x00" bytes until the length of the message is congruent to 896 mod 1024.
+        while padded_input.len() % BLOCK_BITS != BLOCK_BITS - 128 {
+            padded_input.extend(Self::constant_bytes(&[0x00]));
+        }
+
+        // Step 3: Append the length of the message in bits.
+        padded_input.extend(Self::constant_bytes(&length.to_be_bytes()));
+        padded_input
+    }
+
+    /// Returns the state after compressing the given 1024-bit block.
+    fn compress(state: &[Word<E>], block: &[Boolean<E>]) -> Vec<Word<E>> {
+        // Prepare the message schedule.
+        let mut schedule = block.chunks(WORD_BITS).map(Self::bytes_be_to_word).collect::<Vec<_>>();
+        for t in 16..NUM_ROUNDS {
+            let s0 = Self::xor(&[
+                &Self::rotr(&schedule[t - 15], 1),
+                &Self::rotr(&schedule[t - 15], 8),
+                &Self::shr(&schedule[t - 15], 7),
+            ]);
+            let s1 = Self::xor(&[
+                &Self::rotr(&schedule[t - 2], 19),
+                &Self::rotr(&schedule[t - 2], 61),
+                &Self::shr(&schedule[t - 2], 6),
+            ]);
+            schedule.push(Self::add(&[&schedule[t - 16], &s0, &schedule[t - 7], &s1]));
+        }
+
+        // Apply the rounds.
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] =
+            [0, 1, 2, 3, 4, 5, 6, 7].map(|i| state[i].clone());
+        for (t, word) in schedule.iter().enumerate() {
+            // Compute `T1 = h + Σ1(e) + Ch(e, f, g) + K[t] + W[t]`.
+            let sigma1 = Self::xor(&[&Self::rotr(&e, 14), &Self::rotr(&e, 18), &Self::rotr(&e, 41)]);
+            // Note: `Ch(e, f, g) = (e ∧ f) ⊕ (¬e ∧ g) = g ⊕ (e ∧ (f ⊕ g))`.
+            let choose = e.iter().zip_eq(&f).zip_eq(&g).map(|((e, f), g)| g ^ &(e & &(f ^ g))).collect::<Vec<_>>();
+            let t1 = Self::add(&[&h, &sigma1, &choose, &Self::constant_word(ROUND_CONSTANTS[t]), word]);
+            // Compute `T2 = Σ0(a) + Maj(a, b, c)`.
+            let sigma0 = Self::xor(&[&Self::rotr(&a, 28), &Self::rotr(&a, 34), &Self::rotr(&a, 39)]);
+            // Note: `Maj(a, b, c) = (a ∧ b) ⊕ (a ∧ c) ⊕ (b ∧ c) = (a ∧ b) ⊕ (c ∧ (a ⊕ b))`.
+            let majority =
+                a.iter().zip_eq(&b).zip_eq(&c).map(|((a, b), c)| &(a & b) ^ &(c & &(a ^ b))).collect::<Vec<_>>();
+            let t2 = Self::add(&[&sigma0, &majority]);
+            // Update the working variables.
+            h = g;
+            g = f;
+            f = e;
+            e = Self::add(&[&d, &t1]);
+            d = c;
+            c = b;
+            b = a;
+            a = Self::add(&[&t1, &t2]);
+        }
+
+        // Add the working variables to the state.
+        state.iter().zip_eq([a, b, c, d, e, f, g, h]).map(|(word, variable)| Self::add(&[word, &variable])).collect()
+    }
+
+    /// Returns the sum of the given words modulo `2^64`.
+    fn add(words: &[&Word<E>]) -> Word<E> {
+        // Compute the sum over the field, which does not overflow as there are few words.
+        let sum = words.iter().fold(Field::zero(), |sum, word| sum + Field::from_bits_le(word));
+        // Decompose the sum into bits, and truncate the carry bits.
+        let num_carry_bits = (usize::BITS - (words.len() - 1).leading_zeros()) as usize;
+        let mut bits = sum.to_lower_bits_le(WORD_BITS + num_carry_bits);
+        bits.truncate(WORD_BITS);
+        bits
+    }
+
+    /// Returns the XOR of the given words.
+    fn xor(words: &[&Word<E>]) -> Word<E> {
+        (0..WORD_BITS).map(|i| words[1..].iter().fold(words[0][i].clone(), |output, word| output ^ &word[i])).collect()
+    }
+
+    /// Returns the word rotated right by `n` bits.
+    fn rotr(word: &Word<E>, n: usize) -> Word<E> {
+        (0..WORD_BITS).map(|i| word[(i + n) % WORD_BITS].clone()).collect()
+    }
+
+    /// Returns the word shifted right by `n` bits.
+    fn shr(word: &Word<E>, n: usize) -> Word<E> {
+        (0..WORD_BITS).map(|i| word.get(i + n).cloned().unwrap_or_else(|| Boolean::constant(false))).collect()
+    }
+
+    /// Returns the given value as a constant word.
+    fn constant_word(value: u64) -> Word<E> {
+        (0..WORD_BITS).map(|i| Boolean::constant((value >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the given bytes as constant bits, with 8 little-endian bits per byte.
+    fn constant_bytes(bytes: &[u8]) -> Vec<Boolean<E>> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| Boolean::constant((byte >> i) & 1 == 1))).collect()
+    }
+
+    /// Returns the word with the given 8 big-endian bytes.
+    fn bytes_be_to_word(bytes: &[Boolean<E>]) -> Word<E> {
+        bytes.chunks(8).rev().flatten().cloned().collect()
+    }
+
+    /// Returns the 8 big-endian bytes of the given word.
+    fn word_to_bytes_be(word: &Word<E>) -> Vec<Boolean<E>> {
+        word.chunks(8).rev().flatten().cloned().collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use sha2::Digest;
+
+    fn check_hash(mode: Mode, num_bytes: usize, rng: &mut TestRng) {
+        // Prepare the preimage.
+        let native_input = (0..num_bytes).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+        let input = native_input
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| Boolean::<Circuit>::new(mode, (byte >> i) & 1 == 1)))
+            .collect::<Vec<_>>();
+
+        // Compute the native hash.
+        let expected = sha2::Sha512::digest(&native_input)
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect::<Vec<_>>();
+
+        // Compute the circuit hash.
+        Circuit::scope(format!("SHA-512 {mode} {num_bytes}"), || {
+            let candidate = Sha512::<Circuit>::new().hash(&input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_sha512_hash() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bytes in [0, 1, 32, 111, 112, 128, 200] {
+                check_hash(mode, num_bytes, &mut rng);
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use crate::{Hash, HashToGroup, HashToScalar, Poseidon4};
+use snarkvm_circuit_types::prelude::*;
+
+/// An elliptic curve verifiable random function (ECVRF), with Poseidon as the hash function.
+/// See the console implementation for the construction.
+pub struct ECVRF<E: Environment> {
+    /// The hash function for the input, the challenge, and the output.
+    hasher: Poseidon4<E>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for ECVRF<E> {
+    type Primitive = console::ECVRF<E::Network>;
+
+    /// Initializes a new instance of the VRF with the given native VRF.
+    fn new(_mode: Mode, vrf: Self::Primitive) -> Self {
+        Self { hasher: Poseidon4::constant(vrf.hasher().clone()) }
+    }
+}
+
+/// A proof that a VRF output was computed with the secret key of a public key.
+#[derive(Clone)]
+pub struct VRFProof<E: Environment> {
+    /// The group element `gamma := H^sk`, from which the output is derived.
+    gamma: Group<E>,
+    /// The verifier challenge to check against.
+    challenge: Scalar<E>,
+    /// The prover response to the challenge.
+    response: Scalar<E>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for VRFProof<E> {
+    type Primitive = console::VRFProof<E::Network>;
+
+    /// Initializes a VRF proof from the given mode and native VRF proof.
+    fn new(mode: Mode, proof: Self::Primitive) -> Self {
+        Self {
+            gamma: Group::new(mode, proof.gamma()),
+            challenge: Scalar::new(mode, proof.challenge()),
+            response: Scalar::new(mode, proof.response()),
+        }
+    }
+}
+
+impl<E: Environment> VRFProof<E> {
+    /// Returns the group element `gamma`.
+    pub const fn gamma(&self) -> &Group<E> {
+        &self.gamma
+    }
+
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> &Scalar<E> {
+        &self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> &Scalar<E> {
+        &self.response
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for VRFProof<E> {
+    type Primitive = console::VRFProof<E::Network>;
+
+    /// Ejects the mode of the VRF proof.
+    fn eject_mode(&self) -> Mode {
+        (&self.gamma, &self.challenge, &self.response).eject_mode()
+    }
+
+    /// Ejects the VRF proof.
+    fn eject_value(&self) -> Self::Primitive {
+        Self::Primitive::from((&self.gamma, &self.challenge, &self.response).eject_value())
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ECVRF<E> {
+    /// Returns `true` and the VRF output, if the proof is valid for the given public key and input.
+    pub fn verify(&self, public_key: &Group<E>, input: &[Field<E>], proof: &VRFProof<E>) -> (Boolean<E>, Field<E>) {
+        // Hash the input to a group element `H`.
+        let h = self.hasher.hash_to_group(input);
+
+        // Compute `G^k` := (response * G) + (challenge * pk).
+        let g_k = Group::generator() * &proof.response + public_key * &proof.challenge;
+        // Compute `H^k` := (response * H) + (challenge * gamma).
+        let h_k = &h * &proof.response + &proof.gamma * &proof.challenge;
+
+        // Compute the candidate verifier challenge as `Hash(pk, H, gamma, G^k, H^k)`.
+        let preimage = [public_key, &h, &proof.gamma, &g_k, &h_k].map(|point| point.to_x_coordinate());
+        let candidate_challenge = self.hasher.hash_to_scalar(&preimage);
+
+        // Compute the output as `Hash(gamma)`.
+        let output = self.hasher.hash(&[proof.gamma.to_x_coordinate()]);
+
+        // Return `true` if the public key is not the identity, and the challenge is valid.
+        let is_valid = !public_key.is_equal(&Group::zero()) & proof.challenge.is_equal(&candidate_challenge);
+        (is_valid, output)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_verify(mode: Mode) -> Result<()> {
+        let native = console::ECVRF::<<Circuit as Environment>::Network>::setup("VRFCircuit0")?;
+        let vrf = ECVRF::<Circuit>::constant(native.clone());
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a key pair and an input.
+            let secret_key = console::Scalar::rand(rng);
+            let public_key = console::Group::generator() * secret_key;
+            let input = [console::Field::rand(rng), console::Field::rand(rng)];
+            let (expected, proof) = native.prove(&secret_key, &input, rng)?;
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let input = Vec::<Field<Circuit>>::new(mode, input.to_vec());
+                let proof = VRFProof::new(mode, proof);
+
+                // Ensure a valid proof is accepted, and returns the output.
+                let (is_valid, output) = vrf.verify(&Group::new(mode, public_key), &input, &proof);
+                assert!(is_valid.eject_value());
+                assert_eq!(expected, output.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+
+                // Ensure the proof is rejected for a different public key.
+                let (is_valid, _) = vrf.verify(&Group::new(mode, console::Group::rand(rng)), &input, &proof);
+                assert!(!is_valid.eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private)
+    }
+}
@@ -100,6 +100,80 @@ impl<E: Environment, PH: PathHash<E>, const DEPTH: u8, const ARITY: u8> KaryMerk
         // Ensure the final hash matches the given root.
         root.is_equal(&current_hash)
     }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf hash.
+    ///
+    /// Unlike [`Self::verify`], the leaf is given by its hash, so custom circuits can verify inclusion in external
+    /// trees whose leaves are hashed differently. The arity must be a power of two (e.g. 2, 4, or 8), so that the
+    /// position on each level is read directly from the bits of the leaf index, and the leaf index is constrained
+    /// to be within the tree.
+    pub fn verify_leaf_hash(&self, path_hasher: &PH, root: &PH::Hash, leaf_hash: &PH::Hash) -> Boolean<E> {
+        // Ensure the arity is a power of two, greater than one.
+        if ARITY < 2 || !ARITY.is_power_of_two() {
+            E::halt("Found a Merkle path arity that is not a power of two")
+        }
+        // Compute the number of leaf index bits on each level.
+        let num_level_bits = ARITY.trailing_zeros() as usize;
+
+        // Ensure the leaf index bits for every level fit in the leaf index.
+        if num_level_bits * DEPTH as usize > 64 {
+            E::halt("Found a Merkle tree with more than 2^64 leaves")
+        }
+        // Ensure the path length matches the expected depth.
+        else if self.siblings.len() != DEPTH as usize {
+            E::halt("Found an incorrect Merkle path length")
+        }
+        // Ensure the Merkle path has the correct arity.
+        for sibling in &self.siblings {
+            if sibling.len() != ARITY as usize - 1 {
+                return E::halt("Merkle path is not the correct arity");
+            }
+        }
+
+        // Split the leaf index into the bits for each level, and the excess bits.
+        let leaf_index_bits = self.leaf_index.to_bits_le();
+        let (level_bits, excess_bits) = leaf_index_bits.split_at(num_level_bits * DEPTH as usize);
+        // Ensure the leaf index is within the tree, i.e. the excess bits are zero.
+        let is_in_bounds = excess_bits.iter().fold(Boolean::constant(true), |is_in_bounds, bit| is_in_bounds & !bit);
+
+        // Initialize a tracker for the current hash, starting from the leaf hash.
+        let mut current_hash = leaf_hash.clone();
+
+        // Check levels between leaf level and root.
+        for (position_bits, sibling_hashes) in level_bits.chunks(num_level_bits).zip_eq(&self.siblings) {
+            // Compute the indicator of each child, which is `true` if the current hash is the child.
+            let is_current = (0..ARITY as usize)
+                .map(|child| {
+                    position_bits.iter().enumerate().fold(Boolean::constant(true), |is_current, (i, bit)| {
+                        match (child >> i) & 1 == 1 {
+                            true => is_current & bit,
+                            false => is_current & !bit,
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            // Assemble the children, where the siblings before the current hash keep their index,
+            // and the siblings after the current hash are shifted by one.
+            let mut is_after_current = Boolean::constant(false);
+            let mut children = Vec::with_capacity(ARITY as usize);
+            for (child, is_current) in is_current.iter().enumerate() {
+                let sibling = match child {
+                    0 => sibling_hashes[0].clone(),
+                    _ if child == sibling_hashes.len() => sibling_hashes[child - 1].clone(),
+                    _ => PH::Hash::ternary(&is_after_current, &sibling_hashes[child - 1], &sibling_hashes[child]),
+                };
+                children.push(PH::Hash::ternary(is_current, &current_hash, &sibling));
+                is_after_current |= is_current;
+            }
+
+            // Update the current hash for the next level.
+            current_hash = path_hasher.hash_children(&children);
+        }
+
+        // Ensure the leaf index is in bounds, and the final hash matches the given root.
+        is_in_bounds & root.is_equal(&current_hash)
+    }
 }
 
 #[cfg(all(test, console))]
@@ -271,6 +345,79 @@ mod tests {
         }};
     }
 
+    macro_rules! check_verify_leaf_hash {
+        ($lh:ident, $ph:ident, $mode:ident, $depth:expr, $arity:expr, $num_inputs:expr) => {{
+            // Initialize the leaf hasher.
+            let native_leaf_hasher =
+                snarkvm_console_algorithms::$lh::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_leaf_hasher = $lh::<Circuit>::constant(native_leaf_hasher.clone());
+
+            let mut rng = TestRng::default();
+
+            // Initialize the path hasher.
+            let native_path_hasher =
+                snarkvm_console_algorithms::$ph::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+            let circuit_path_hasher = $ph::<Circuit>::constant(native_path_hasher.clone());
+
+            // Compute the leaves.
+            let num_leaves = core::cmp::min(($arity as u128).pow($depth as u32), 5);
+            let leaves = (0..num_leaves)
+                .map(|_| (0..$num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            // Compute the Merkle tree.
+            let merkle_tree = console::kary_merkle_tree::KaryMerkleTree::<_, _, $depth, $arity>::new(
+                &native_leaf_hasher,
+                &native_path_hasher,
+                &leaves,
+            )?;
+
+            for (index, merkle_leaf) in leaves.iter().enumerate() {
+                // Compute the Merkle path.
+                let merkle_path = merkle_tree.prove(index, merkle_leaf)?;
+
+                // Initialize the Merkle path, root, and leaf.
+                let path =
+                    KaryMerklePath::<Circuit, $ph<Circuit>, $depth, $arity>::new(Mode::$mode, merkle_path.clone());
+                let root = Field::new(Mode::$mode, *merkle_tree.root());
+                let leaf: Vec<_> = Inject::new(Mode::$mode, merkle_leaf.clone());
+
+                Circuit::scope(format!("Verify Leaf Hash {}", Mode::$mode), || {
+                    let leaf_hash = circuit_leaf_hasher.hash_leaf(&leaf);
+                    let candidate = path.verify_leaf_hash(&circuit_path_hasher, &root, &leaf_hash);
+                    assert!(candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+
+                    // Ensure an incorrect root fails.
+                    let incorrect_root = root.clone() + Field::one();
+                    let candidate = path.verify_leaf_hash(&circuit_path_hasher, &incorrect_root, &leaf_hash);
+                    assert!(!candidate.eject_value());
+
+                    // Ensure an incorrect leaf hash fails.
+                    let incorrect_leaf_hash = leaf_hash.clone() + Field::one();
+                    let candidate = path.verify_leaf_hash(&circuit_path_hasher, &root, &incorrect_leaf_hash);
+                    assert!(!candidate.eject_value());
+                });
+                Circuit::reset();
+
+                // Ensure a leaf index that is out of bounds fails, even though its lower bits match the path.
+                let out_of_bounds = KaryMerklePath::<Circuit, $ph<Circuit>, $depth, $arity> {
+                    leaf_index: U64::new(
+                        Mode::$mode,
+                        console::U64::new(index as u64 + ($arity as u64).pow($depth as u32)),
+                    ),
+                    siblings: path.siblings.clone(),
+                };
+                Circuit::scope(format!("Verify Leaf Hash (Out of Bounds) {}", Mode::$mode), || {
+                    let leaf_hash = circuit_leaf_hasher.hash_leaf(&leaf);
+                    let candidate = out_of_bounds.verify_leaf_hash(&circuit_path_hasher, &root, &leaf_hash);
+                    assert!(!candidate.eject_value());
+                });
+                Circuit::reset();
+            }
+            Ok(())
+        }};
+    }
+
     #[test]
     fn test_verify_bhp512_constant() -> Result<()> {
         check_verify!(BHP1024, BHP512, Constant, 10, 4, 1024, (39234, 0, 0, 0))
@@ -330,4 +477,34 @@ mod tests {
     fn test_verify_sha3_256_private() -> Result<()> {
         check_verify_keccak!(Sha3_256, Sha3_256, Private, 10, 4, 256, (7648, 0, 1696439, 1696519))
     }
+
+    #[test]
+    fn test_verify_leaf_hash_bhp512_arity_2() -> Result<()> {
+        check_verify_leaf_hash!(BHP1024, BHP512, Private, 4, 2, 1024)
+    }
+
+    #[test]
+    fn test_verify_leaf_hash_bhp512_arity_4() -> Result<()> {
+        check_verify_leaf_hash!(BHP1024, BHP512, Private, 3, 4, 1024)
+    }
+
+    #[test]
+    fn test_verify_leaf_hash_bhp512_arity_8() -> Result<()> {
+        check_verify_leaf_hash!(BHP1024, BHP512, Private, 2, 8, 1024)
+    }
+
+    #[test]
+    fn test_verify_leaf_hash_poseidon2_arity_2_constant() -> Result<()> {
+        check_verify_leaf_hash!(Poseidon4, Poseidon2, Constant, 4, 2, 4)
+    }
+
+    #[test]
+    fn test_verify_leaf_hash_poseidon2_arity_4() -> Result<()> {
+        check_verify_leaf_hash!(Poseidon4, Poseidon2, Public, 3, 4, 4)
+    }
+
+    #[test]
+    fn test_verify_leaf_hash_poseidon2_arity_8() -> Result<()> {
+        check_verify_leaf_hash!(Poseidon4, Poseidon2, Private, 2, 8, 4)
+    }
 }
@@ -24,6 +24,8 @@ type Field = <console::Testnet3 as console::Environment>::Field;
 thread_local! {
     pub(super) static CIRCUIT: RefCell<R1CS<Field>> = RefCell::new(R1CS::new());
     pub(super) static IN_WITNESS: Cell<bool> = Cell::new(false);
+    pub(super) static EVALUATION_ONLY: Cell<bool> = Cell::new(false);
+    pub(super) static PROFILER: RefCell<Profiler> = RefCell::new(Profiler::default());
     pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
     pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
 }
@@ -54,8 +56,14 @@ impl Environment for Circuit {
             if !in_witness.get() {
                 CIRCUIT.with(|circuit| match mode {
                     Mode::Constant => circuit.borrow_mut().new_constant(value),
-                    Mode::Public => circuit.borrow_mut().new_public(value),
-                    Mode::Private => circuit.borrow_mut().new_private(value),
+                    Mode::Public => {
+                        PROFILER.with(|profiler| profiler.borrow_mut().record_public());
+                        circuit.borrow_mut().new_public(value)
+                    }
+                    Mode::Private => {
+                        PROFILER.with(|profiler| profiler.borrow_mut().record_private());
+                        circuit.borrow_mut().new_private(value)
+                    }
                 })
             } else {
                 Self::halt("Tried to initialize a new variable in witness mode")
@@ -134,6 +142,106 @@ impl Environment for Circuit {
         })
     }
 
+    /// Synthesizes the given logic in a new scope, and then discards the variables and constraints it allocated.
+    fn speculate<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> (Output, (u64, u64, u64, u64, (u64, u64, u64)))
+    where
+        Fn: FnOnce() -> Output,
+    {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                CIRCUIT.with(|circuit| {
+                    // Save the sizes of the circuit.
+                    let checkpoint = circuit.borrow().checkpoint();
+                    let (constants, public, private, constraints, nonzeros) = Self::count();
+                    // Suspend the profiler, so the speculative synthesis is not attributed to any scope.
+                    let profiler = PROFILER.with(|profiler| profiler.take());
+
+                    // Set the entire environment to the new scope.
+                    let name = name.into();
+                    if let Err(error) = circuit.borrow_mut().push_scope(&name) {
+                        Self::halt(error)
+                    }
+
+                    // Run the logic, and count what it allocated, including in any nested scopes.
+                    let output = logic();
+                    let count = {
+                        let (num_constants, num_public, num_private, num_constraints, num_nonzeros) = Self::count();
+                        (
+                            num_constants - constants,
+                            num_public - public,
+                            num_private - private,
+                            num_constraints - constraints,
+                            (num_nonzeros.0 - nonzeros.0, num_nonzeros.1 - nonzeros.1, num_nonzeros.2 - nonzeros.2),
+                        )
+                    };
+
+                    // Return the entire environment to the previous scope, and discard the speculative synthesis.
+                    if let Err(error) = circuit.borrow_mut().pop_scope(name) {
+                        Self::halt(error)
+                    }
+                    circuit.borrow_mut().rollback(checkpoint);
+                    PROFILER.with(|previous| previous.replace(profiler));
+
+                    (output, count)
+                })
+            } else {
+                Self::halt("Tried to initialize a new scope in witness mode")
+            }
+        })
+    }
+
+    /// Executes the given logic in evaluation-only mode, where the values of the variables are computed,
+    /// but no constraints are enforced.
+    fn evaluation_only<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        /// Restores the previous mode when dropped, including if the logic panics.
+        struct Restore(bool);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                EVALUATION_ONLY.with(|evaluation_only| evaluation_only.set(self.0));
+            }
+        }
+
+        let _restore = Restore(EVALUATION_ONLY.with(|evaluation_only| evaluation_only.replace(true)));
+        logic()
+    }
+
+    /// Returns `true` if the environment is in evaluation-only mode.
+    fn is_evaluation_only() -> bool {
+        EVALUATION_ONLY.with(|evaluation_only| evaluation_only.get())
+    }
+
+    /// Enables or disables profiling, which attributes constraints and variables to the open profiling scopes.
+    fn set_profiling(is_enabled: bool) {
+        PROFILER.with(|profiler| profiler.borrow_mut().set_enabled(is_enabled))
+    }
+
+    /// Returns `true` if profiling is enabled.
+    fn is_profiling() -> bool {
+        PROFILER.with(|profiler| profiler.borrow().is_enabled())
+    }
+
+    /// Opens a profiling scope with the given label, if profiling is enabled.
+    fn push_scope<S: Into<String>>(label: S) {
+        PROFILER.with(|profiler| profiler.borrow_mut().push(label.into()))
+    }
+
+    /// Closes the innermost profiling scope, which must have the given label, if profiling is enabled.
+    fn pop_scope<S: AsRef<str>>(label: S) {
+        if let Err(error) = PROFILER.with(|profiler| profiler.borrow_mut().pop(label.as_ref())) {
+            Self::halt(error)
+        }
+    }
+
+    /// Returns the profiles of the profiling scopes, in the order they were opened, and clears them.
+    fn take_profile() -> Vec<ScopeProfile> {
+        PROFILER.with(|profiler| profiler.borrow_mut().take())
+    }
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -145,6 +253,10 @@ impl Environment for Circuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !in_witness.get() {
+                // If the environment is in evaluation-only mode, skip the constraint.
+                if EVALUATION_ONLY.with(|evaluation_only| evaluation_only.get()) {
+                    return;
+                }
                 CIRCUIT.with(|circuit| {
                     let (a, b, c) = constraint();
                     let (a, b, c) = (a.into(), b.into(), c.into());
@@ -168,6 +280,8 @@ impl Environment for Circuit {
                             // }
                         }
                         false => {
+                            // Attribute the constraint to the current profiling scope.
+                            PROFILER.with(|profiler| profiler.borrow_mut().record_constraint());
                             // Construct the constraint object.
                             let constraint = Constraint(circuit.borrow().scope(), a, b, c);
                             // Append the constraint.
@@ -181,6 +295,22 @@ impl Environment for Circuit {
         })
     }
 
+    /// Executes the given logic, attaching the given label to the constraints that it adds.
+    fn labeled<S: Into<String>, Fn, Output>(label: S, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // In evaluation-only mode, no constraints are added, so there is nothing to label.
+        if Self::is_evaluation_only() {
+            return logic();
+        }
+        let start = Self::num_constraints();
+        let output = logic();
+        let end = Self::num_constraints();
+        CIRCUIT.with(|circuit| circuit.borrow_mut().label(start..end, label.into()));
+        output
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied())
@@ -191,6 +321,16 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied_in_scope())
     }
 
+    /// Returns the diagnostics of the first unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>> {
+        CIRCUIT.with(|circuit| circuit.borrow().first_unsatisfied_constraint())
+    }
+
+    /// Returns a counterexample to the environment, if one exists.
+    fn counterexample() -> Option<Counterexample<Self::BaseField>> {
+        CIRCUIT.with(|circuit| circuit.borrow().counterexample())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         CIRCUIT.with(|circuit| circuit.borrow().num_constants())
@@ -374,4 +514,139 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_circuit_speculate() {
+        let two = snarkvm_console_types::Field::one() + snarkvm_console_types::Field::one();
+
+        let a = Field::<Circuit>::new(Mode::Private, two);
+        let b = Field::<Circuit>::new(Mode::Private, two);
+        let _c = &a * &b;
+        let before = Circuit::count();
+
+        Circuit::scope("outer", || {
+            // Speculatively synthesize an unsatisfied sub-circuit, within nested scopes.
+            let (value, count) = Circuit::speculate("speculative", || {
+                let product = Circuit::scope("inner", || &a * &Field::new(Mode::Private, two));
+                Circuit::assert_eq(&product, &a);
+                assert!(!Circuit::is_satisfied());
+                product.eject_value()
+            });
+            assert_eq!(value, two + two + two + two);
+            assert_eq!((0, 0, 2, 2), (count.0, count.1, count.2, count.3));
+
+            // Ensure the speculative synthesis is not counted in the enclosing scope.
+            assert_eq!((0, 0, 0, 0), {
+                let (constants, public, private, constraints, _) = Circuit::count_in_scope();
+                (constants, public, private, constraints)
+            });
+        });
+
+        // Ensure the circuit is restored.
+        assert_eq!(before, Circuit::count());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure new variables continue from the restored circuit.
+        let d = Field::<Circuit>::new(Mode::Private, two);
+        let _e = &a * &d;
+        assert_eq!(before.2 + 2, Circuit::num_private());
+        assert_eq!(before.3 + 1, Circuit::num_constraints());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_circuit_labeled() {
+        let two = snarkvm_console_types::Field::one() + snarkvm_console_types::Field::one();
+
+        let a = Field::<Circuit>::new(Mode::Private, two);
+        let b = Field::<Circuit>::new(Mode::Private, two + two);
+        assert!(Circuit::first_unsatisfied_constraint().is_none());
+
+        Circuit::labeled("outer", || {
+            Circuit::assert_eq(&a, &a);
+            Circuit::assert_eq_labeled("a == b", &a, &b);
+            Circuit::assert_eq(&b, &a);
+        });
+
+        // Ensure the first unsatisfied constraint is reported with its innermost label.
+        let unsatisfied = Circuit::first_unsatisfied_constraint().unwrap();
+        assert_eq!(1, unsatisfied.index);
+        assert_eq!(Some("a == b".to_string()), unsatisfied.label);
+        assert_eq!((*two, *(two + two)), (unsatisfied.a, unsatisfied.c));
+
+        // Ensure unlabeled constraints inherit the enclosing label.
+        let r1cs = Circuit::eject_r1cs_and_reset();
+        assert_eq!(Some("outer"), r1cs.label_of(0));
+        assert_eq!(Some("a == b"), r1cs.label_of(1));
+        assert_eq!(Some("outer"), r1cs.label_of(2));
+    }
+
+    #[test]
+    fn test_circuit_profiling() {
+        use snarkvm_circuit::environment::ScopeProfile;
+
+        // Ensure scopes are ignored when profiling is disabled.
+        Circuit::push_scope("ignored");
+        let _a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one());
+        Circuit::pop_scope("ignored");
+        assert!(Circuit::take_profile().is_empty());
+
+        Circuit::set_profiling(true);
+        assert!(Circuit::is_profiling());
+
+        // Allocate variables and constraints in nested scopes, where labels may contain periods.
+        Circuit::push_scope("mul.w r0 r1 into r2;");
+        let a = Field::<Circuit>::new(Mode::Public, snarkvm_console_types::Field::one());
+        let b = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one());
+        let _c = &a * &b;
+        Circuit::push_scope("inner");
+        let _d = &a * &b;
+        let _e = &a * &b;
+        Circuit::pop_scope("inner");
+        Circuit::pop_scope("mul.w r0 r1 into r2;");
+        // Ensure constraints outside of a scope are not attributed.
+        let _f = &a * &b;
+
+        let profile = Circuit::take_profile();
+        assert_eq!(profile.len(), 2);
+        assert_eq!(profile[0], ScopeProfile {
+            label: "mul.w r0 r1 into r2;".to_string(),
+            num_public: 1,
+            num_private: 2,
+            num_constraints: 1
+        });
+        assert_eq!(profile[1], ScopeProfile {
+            label: "inner".to_string(),
+            num_public: 0,
+            num_private: 2,
+            num_constraints: 2
+        });
+        assert!(Circuit::take_profile().is_empty());
+
+        Circuit::set_profiling(false);
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_circuit_evaluation_only() {
+        let two = snarkvm_console_types::Field::one() + snarkvm_console_types::Field::one();
+
+        let product = Circuit::evaluation_only(|| {
+            assert!(Circuit::is_evaluation_only());
+            let a = Field::<Circuit>::new(Mode::Private, two);
+            let b = Field::<Circuit>::new(Mode::Private, two);
+            // Ensure the values are computed, without any constraints.
+            let product = &a * &b;
+            Circuit::assert_eq(&product, &a);
+            assert_eq!(0, Circuit::num_constraints());
+            assert_eq!(3, Circuit::num_private());
+            product.eject_value()
+        });
+        assert_eq!(product, two + two);
+
+        // Ensure the mode is restored.
+        assert!(!Circuit::is_evaluation_only());
+        Circuit::reset();
+    }
 }
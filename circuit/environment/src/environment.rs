@@ -12,7 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{
+    witness_mode,
+    Assignment,
+    Counterexample,
+    Inject,
+    LinearCombination,
+    Mode,
+    ScopeProfile,
+    UnsatisfiedConstraint,
+    Variable,
+    R1CS,
+};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -59,6 +70,40 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     where
         Fn: FnOnce() -> Output;
 
+    /// Synthesizes the given logic in a new scope, and then discards the variables and constraints it allocated.
+    /// Returns the output of the logic, and the number of constants, public variables, private variables,
+    /// constraints, and nonzeros that the logic allocated. The output must not contain circuit values,
+    /// as their variables no longer exist in the environment.
+    fn speculate<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> (Output, (u64, u64, u64, u64, (u64, u64, u64)))
+    where
+        Fn: FnOnce() -> Output;
+
+    /// Executes the given logic in evaluation-only mode, where the values of the variables are computed,
+    /// but no constraints are enforced. The resulting circuit must only be used for its values.
+    fn evaluation_only<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
+    /// Returns `true` if the environment is in evaluation-only mode.
+    fn is_evaluation_only() -> bool;
+
+    /// Enables or disables profiling, which attributes constraints and variables to the open profiling scopes.
+    fn set_profiling(is_enabled: bool);
+
+    /// Returns `true` if profiling is enabled.
+    fn is_profiling() -> bool;
+
+    /// Opens a profiling scope with the given label, if profiling is enabled.
+    /// Unlike `scope`, the label may be any string, such as an Aleo instruction.
+    fn push_scope<S: Into<String>>(label: S);
+
+    /// Closes the innermost profiling scope, which must have the given label, if profiling is enabled.
+    fn pop_scope<S: AsRef<str>>(label: S);
+
+    /// Returns the profiles of the profiling scopes, in the order they were opened, and clears them.
+    /// Note: The profiles are retained when the circuit is reset, so they may span multiple circuits.
+    fn take_profile() -> Vec<ScopeProfile>;
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -103,12 +148,48 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         Self::enforce(|| (a_minus_b, multiplier, Self::one()));
     }
 
+    /// Executes the given logic, attaching the given label to the constraints that it adds.
+    /// If one of these constraints is not satisfied, the innermost label is reported with it.
+    fn labeled<S: Into<String>, Fn, Output>(label: S, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output;
+
+    /// Adds one constraint enforcing that the given boolean is `true`, labeled with the given message.
+    fn assert_labeled<S: Into<String>, Boolean: Into<LinearCombination<Self::BaseField>>>(label: S, boolean: Boolean) {
+        Self::labeled(label, || Self::assert(boolean))
+    }
+
+    /// Adds one constraint enforcing that the `A == B`, labeled with the given message.
+    fn assert_eq_labeled<S: Into<String>, A, B>(label: S, a: A, b: B)
+    where
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+    {
+        Self::labeled(label, || Self::assert_eq(a, b))
+    }
+
+    /// Adds one constraint enforcing that the `A != B`, labeled with the given message.
+    fn assert_neq_labeled<S: Into<String>, A, B>(label: S, a: A, b: B)
+    where
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+    {
+        Self::labeled(label, || Self::assert_neq(a, b))
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Returns the diagnostics of the first unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>>;
+
+    /// Returns a counterexample to the environment, which is its first unsatisfied constraint
+    /// with its evaluated linear combinations and contributing variable assignments, if one exists.
+    fn counterexample() -> Option<Counterexample<Self::BaseField>>;
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> u64;
 
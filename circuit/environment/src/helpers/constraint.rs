@@ -52,6 +52,33 @@ impl<F: PrimeField> Constraint<F> {
     }
 }
 
+/// The diagnostics of a constraint that is not satisfied by the assigned values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint<F: PrimeField> {
+    /// The index of the constraint in the constraint system.
+    pub index: u64,
+    /// The scope in which the constraint was enforced.
+    pub scope: Scope,
+    /// The innermost label attached to the constraint, if one exists.
+    pub label: Option<String>,
+    /// The value of the linear combination `A`.
+    pub a: F,
+    /// The value of the linear combination `B`.
+    pub b: F,
+    /// The value of the linear combination `C`.
+    pub c: F,
+}
+
+impl<F: PrimeField> Display for UnsatisfiedConstraint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (index, scope, a, b, c) = (self.index, &self.scope, self.a, self.b, self.c);
+        match &self.label {
+            Some(label) => write!(f, "Constraint {index} ('{label}') at {scope}: ({a} * {b}) != {c}"),
+            None => write!(f, "Constraint {index} at {scope}: ({a} * {b}) != {c}"),
+        }
+    }
+}
+
 impl<F: PrimeField> Display for Constraint<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let (scope, a, b, c) = (&self.0, &self.1, &self.2, &self.3);
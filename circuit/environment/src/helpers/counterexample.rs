@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{prelude::*, *};
+use snarkvm_fields::PrimeField;
+
+/// A counterexample to a constraint system, which is its first unsatisfied constraint,
+/// along with the evaluated linear combinations of the constraint.
+#[derive(Clone, Debug)]
+pub struct Counterexample<F: PrimeField> {
+    /// The diagnostics of the unsatisfied constraint.
+    pub constraint: UnsatisfiedConstraint<F>,
+    /// The linear combination `A` of the constraint.
+    pub a: LinearCombination<F>,
+    /// The linear combination `B` of the constraint.
+    pub b: LinearCombination<F>,
+    /// The linear combination `C` of the constraint.
+    pub c: LinearCombination<F>,
+}
+
+impl<F: PrimeField> Counterexample<F> {
+    /// Returns the constant term of each linear combination `(A, B, C)`.
+    pub fn constants(&self) -> (F, F, F) {
+        (self.a.to_constant(), self.b.to_constant(), self.c.to_constant())
+    }
+
+    /// Returns the variables that contribute to the constraint, as `(mode, index, value)`,
+    /// in the order they first appear in `A`, `B`, and `C`.
+    pub fn assignments(&self) -> Vec<(Mode, u64, F)> {
+        let mut assignments = IndexMap::new();
+        for (variable, _) in [&self.a, &self.b, &self.c].into_iter().flat_map(|lc| lc.to_terms()) {
+            assignments.entry((variable.mode(), variable.index())).or_insert_with(|| variable.value());
+        }
+        assignments.into_iter().map(|((mode, index), value)| (mode, index, value)).collect()
+    }
+}
+
+impl<F: PrimeField> Display for Counterexample<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.constraint)?;
+        for (name, lc) in [("A", &self.a), ("B", &self.b), ("C", &self.c)] {
+            writeln!(f, "\t{name} = {lc:?} = {}", lc.value())?;
+        }
+        for (mode, index, value) in self.assignments() {
+            writeln!(f, "\t{mode}({index}) = {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use snarkvm_fields::{One as O, Zero as Z};
+
+    type F = <Circuit as Environment>::BaseField;
+
+    #[test]
+    fn test_counterexample() {
+        let two = F::one() + F::one();
+
+        let a = Circuit::new_variable(Mode::Public, two);
+        let b = Circuit::new_variable(Mode::Private, two);
+        let c = Circuit::new_variable(Mode::Private, two + two);
+        assert!(Circuit::counterexample().is_none());
+
+        // Enforce `(a + 1) * b == c`, which is not satisfied as `3 * 2 != 4`.
+        Circuit::enforce(|| (LinearCombination::from(&a) + Circuit::one(), &b, &c));
+
+        let counterexample = Circuit::counterexample().unwrap();
+        assert_eq!(0, counterexample.constraint.index);
+        assert_eq!(
+            (two + F::one(), two, two + two),
+            (counterexample.a.value(), counterexample.b.value(), counterexample.c.value())
+        );
+        assert_eq!((F::one(), F::zero(), F::zero()), counterexample.constants());
+        assert_eq!(
+            vec![(Mode::Public, 1, two), (Mode::Private, 0, two), (Mode::Private, 1, two + two)],
+            counterexample.assignments()
+        );
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Assignment, AssignmentLC, AssignmentVariable};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::ToBytes;
+
+use std::io::{Error, ErrorKind, Result, Write};
+
+/// The magic bytes of an `.r1cs` file.
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+/// The version of the `.r1cs` file format.
+const R1CS_VERSION: u32 = 1;
+/// The magic bytes of a `.wtns` file.
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+/// The version of the `.wtns` file format.
+const WTNS_VERSION: u32 = 2;
+
+/// Exports the assignment into the binary `.r1cs` and `.wtns` formats of iden3,
+/// which are read by circom, snarkjs, and arkworks (via `ark-circom`).
+///
+/// The wires are ordered as the constant `1`, followed by the public variables, and then the private variables.
+/// Note that the first public variable of an assignment is itself the constant `1`, so wire `1` is always `1`.
+impl<F: PrimeField> Assignment<F> {
+    /// Writes the constraint system of the assignment in the `.r1cs` format.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> Result<()> {
+        let modulus = F::modulus().to_bytes_le()?;
+        let num_wires = 1 + self.num_public() + self.num_private();
+
+        // Construct the header section.
+        let mut header = Vec::new();
+        to_u32(modulus.len())?.write_le(&mut header)?;
+        header.extend_from_slice(&modulus);
+        to_u32(num_wires)?.write_le(&mut header)?;
+        // Write the number of public outputs, public inputs, and private inputs.
+        0u32.write_le(&mut header)?;
+        to_u32(self.num_public())?.write_le(&mut header)?;
+        to_u32(self.num_private())?.write_le(&mut header)?;
+        // Write the number of labels, which is one per wire.
+        num_wires.write_le(&mut header)?;
+        to_u32(self.num_constraints())?.write_le(&mut header)?;
+
+        // Construct the constraints section.
+        let mut constraints = Vec::new();
+        for (a, b, c) in self.constraints().iter() {
+            for lc in [a, b, c] {
+                self.write_linear_combination(lc, &mut constraints)?;
+            }
+        }
+
+        // Construct the wire-to-label section, where the label of each wire is its index.
+        let mut labels = Vec::new();
+        for wire in 0..num_wires {
+            wire.write_le(&mut labels)?;
+        }
+
+        writer.write_all(R1CS_MAGIC)?;
+        R1CS_VERSION.write_le(&mut writer)?;
+        3u32.write_le(&mut writer)?;
+        for (section_type, section) in [(1u32, header), (2u32, constraints), (3u32, labels)] {
+            write_section(section_type, &section, &mut writer)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the values of the wires of the assignment in the `.wtns` format.
+    pub fn write_witness<W: Write>(&self, mut writer: W) -> Result<()> {
+        let modulus = F::modulus().to_bytes_le()?;
+        let num_wires = 1 + self.num_public() + self.num_private();
+
+        // Construct the header section.
+        let mut header = Vec::new();
+        to_u32(modulus.len())?.write_le(&mut header)?;
+        header.extend_from_slice(&modulus);
+        to_u32(num_wires)?.write_le(&mut header)?;
+
+        // Construct the witness section.
+        let mut witness = Vec::new();
+        F::one().to_bigint().write_le(&mut witness)?;
+        for (_, value) in self.public_inputs().iter().chain(self.private_inputs().iter()) {
+            value.to_bigint().write_le(&mut witness)?;
+        }
+
+        writer.write_all(WTNS_MAGIC)?;
+        WTNS_VERSION.write_le(&mut writer)?;
+        2u32.write_le(&mut writer)?;
+        write_section(1, &header, &mut writer)?;
+        write_section(2, &witness, &mut writer)
+    }
+
+    /// Writes the given linear combination, as the number of terms followed by the `(wire, coefficient)` terms.
+    fn write_linear_combination<W: Write>(&self, lc: &AssignmentLC<F>, mut writer: W) -> Result<()> {
+        let num_terms = lc.terms().len() + usize::from(!lc.constant().is_zero());
+        to_u32(num_terms)?.write_le(&mut writer)?;
+        // Write the constant term, which is a multiple of wire `0`.
+        if !lc.constant().is_zero() {
+            0u32.write_le(&mut writer)?;
+            lc.constant().to_bigint().write_le(&mut writer)?;
+        }
+        for (variable, coefficient) in lc.terms() {
+            let wire = match variable {
+                AssignmentVariable::Constant(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "Linear combinations cannot contain constant terms",
+                    ));
+                }
+                AssignmentVariable::Public(index) => 1 + index,
+                AssignmentVariable::Private(index) => 1 + self.num_public() + index,
+            };
+            to_u32(wire)?.write_le(&mut writer)?;
+            coefficient.to_bigint().write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a section, as its type and size followed by its contents.
+fn write_section<W: Write>(section_type: u32, section: &[u8], mut writer: W) -> Result<()> {
+    section_type.write_le(&mut writer)?;
+    (section.len() as u64).write_le(&mut writer)?;
+    writer.write_all(section)
+}
+
+/// Returns the given count as a `u32`, which is the size of the counts in the file formats.
+fn to_u32<T: TryInto<u32>>(count: T) -> Result<u32> {
+    count.try_into().map_err(|_| Error::new(ErrorKind::InvalidData, "The assignment is too large to export"))
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::PrimeField;
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    type ConsoleField = snarkvm_console_types::Field<<Circuit as Environment>::Network>;
+
+    /// Reads a little-endian `u32` at the given offset, and advances the offset.
+    fn read_u32(bytes: &[u8], offset: &mut usize) -> u32 {
+        *offset += 4;
+        u32::read_le(&bytes[*offset - 4..*offset]).unwrap()
+    }
+
+    /// Reads a little-endian `u64` at the given offset, and advances the offset.
+    fn read_u64(bytes: &[u8], offset: &mut usize) -> u64 {
+        *offset += 8;
+        u64::read_le(&bytes[*offset - 8..*offset]).unwrap()
+    }
+
+    /// Reads a field element at the given offset, and advances the offset.
+    fn read_field(bytes: &[u8], offset: &mut usize) -> Fr {
+        *offset += 32;
+        Fr::from_bigint(FromBytes::read_le(&bytes[*offset - 32..*offset]).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_export_is_satisfied() {
+        // Construct a circuit that checks `(a + 1) * b == c`.
+        let a = Field::<Circuit>::new(Mode::Public, ConsoleField::from_u64(3));
+        let b = Field::<Circuit>::new(Mode::Private, ConsoleField::from_u64(5));
+        let c = (a + Field::one()) * b;
+        assert_eq!(c.eject_value(), ConsoleField::from_u64(20));
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        let mut r1cs = Vec::new();
+        assignment.write_r1cs(&mut r1cs).unwrap();
+        let mut wtns = Vec::new();
+        assignment.write_witness(&mut wtns).unwrap();
+
+        // Parse the witness.
+        let offset = &mut 0;
+        assert_eq!(&wtns[..4], b"wtns");
+        *offset += 4;
+        assert_eq!(read_u32(&wtns, offset), 2);
+        assert_eq!(read_u32(&wtns, offset), 2);
+        assert_eq!((read_u32(&wtns, offset), read_u64(&wtns, offset)), (1, 4 + 32 + 4));
+        assert_eq!(read_u32(&wtns, offset), 32);
+        assert_eq!(&wtns[*offset..*offset + 32], &Fr::modulus().to_bytes_le().unwrap()[..]);
+        *offset += 32;
+        let num_wires = read_u32(&wtns, offset) as usize;
+        assert_eq!(num_wires as u64, 1 + assignment.num_public() + assignment.num_private());
+        assert_eq!((read_u32(&wtns, offset), read_u64(&wtns, offset)), (2, 32 * num_wires as u64));
+        let wires = (0..num_wires).map(|_| read_field(&wtns, offset)).collect::<Vec<_>>();
+        assert_eq!(*offset, wtns.len());
+        assert_eq!((wires[0], wires[1], wires[2]), (Fr::from(1u64), Fr::from(1u64), Fr::from(3u64)));
+
+        // Parse the constraint system, and ensure every constraint is satisfied by the witness.
+        let offset = &mut 0;
+        assert_eq!(&r1cs[..4], b"r1cs");
+        *offset += 4;
+        assert_eq!(read_u32(&r1cs, offset), 1);
+        assert_eq!(read_u32(&r1cs, offset), 3);
+        assert_eq!(read_u32(&r1cs, offset), 1);
+        read_u64(&r1cs, offset);
+        assert_eq!(read_u32(&r1cs, offset), 32);
+        *offset += 32;
+        assert_eq!(read_u32(&r1cs, offset) as usize, num_wires);
+        assert_eq!(read_u32(&r1cs, offset), 0);
+        assert_eq!(read_u32(&r1cs, offset) as u64, assignment.num_public());
+        assert_eq!(read_u32(&r1cs, offset) as u64, assignment.num_private());
+        assert_eq!(read_u64(&r1cs, offset) as usize, num_wires);
+        let num_constraints = read_u32(&r1cs, offset);
+        assert_eq!(num_constraints as u64, assignment.num_constraints());
+        assert!(num_constraints > 0);
+
+        assert_eq!(read_u32(&r1cs, offset), 2);
+        read_u64(&r1cs, offset);
+        for _ in 0..num_constraints {
+            let mut evaluate = || {
+                (0..read_u32(&r1cs, offset)).fold(Fr::from(0u64), |sum, _| {
+                    let wire = read_u32(&r1cs, offset) as usize;
+                    sum + wires[wire] * read_field(&r1cs, offset)
+                })
+            };
+            let (a, b, c) = (evaluate(), evaluate(), evaluate());
+            assert_eq!(a * b, c);
+        }
+
+        assert_eq!((read_u32(&r1cs, offset), read_u64(&r1cs, offset)), (3, 8 * num_wires as u64));
+        for wire in 0..num_wires {
+            assert_eq!(read_u64(&r1cs, offset), wire as u64);
+        }
+        assert_eq!(*offset, r1cs.len());
+    }
+}
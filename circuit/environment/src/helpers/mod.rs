@@ -23,9 +23,14 @@ pub use constraint::*;
 
 pub(super) mod converter;
 
+mod export;
+
 pub mod count;
 pub use count::*;
 
+pub mod counterexample;
+pub use counterexample::*;
+
 pub(super) mod counter;
 pub(super) use counter::*;
 
@@ -35,6 +40,10 @@ pub use linear_combination::*;
 mod mode;
 pub use mode::*;
 
+pub(super) mod profiler;
+pub use profiler::ScopeProfile;
+pub(super) use profiler::Profiler;
+
 pub mod variable;
 pub use variable::*;
 
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The constraints and variables that were allocated directly within a profiling scope,
+/// excluding those of any nested profiling scopes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ScopeProfile {
+    /// The label of the scope, such as the Aleo instruction that was synthesized.
+    pub label: String,
+    /// The number of public variables allocated in the scope.
+    pub num_public: u64,
+    /// The number of private variables allocated in the scope.
+    pub num_private: u64,
+    /// The number of constraints enforced in the scope.
+    pub num_constraints: u64,
+}
+
+/// A profiler that attributes constraints and variables to the innermost open profiling scope.
+#[derive(Debug, Default)]
+pub(crate) struct Profiler {
+    /// A flag indicating if profiling is enabled.
+    is_enabled: bool,
+    /// The indices (into `profiles`) of the open scopes, ordered from outermost to innermost.
+    open: Vec<usize>,
+    /// The profiles of the scopes, in the order they were opened.
+    profiles: Vec<ScopeProfile>,
+}
+
+impl Profiler {
+    /// Returns `true` if profiling is enabled.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Enables or disables profiling. Disabling profiling discards any open scopes.
+    pub(crate) fn set_enabled(&mut self, is_enabled: bool) {
+        self.is_enabled = is_enabled;
+        if !is_enabled {
+            self.open.clear();
+        }
+    }
+
+    /// Opens a new scope with the given label, if profiling is enabled.
+    pub(crate) fn push(&mut self, label: String) {
+        if self.is_enabled {
+            self.open.push(self.profiles.len());
+            self.profiles.push(ScopeProfile { label, ..Default::default() });
+        }
+    }
+
+    /// Closes the innermost scope, which must have the given label, if profiling is enabled.
+    pub(crate) fn pop(&mut self, label: &str) -> Result<(), String> {
+        if !self.is_enabled {
+            return Ok(());
+        }
+        match self.open.pop() {
+            Some(index) if self.profiles[index].label == label => Ok(()),
+            Some(index) => {
+                Err(format!("Mismatching profiling scope: expected '{}', found '{label}'", self.profiles[index].label))
+            }
+            None => Err(format!("Tried to close the profiling scope '{label}', but no scope is open")),
+        }
+    }
+
+    /// Returns the profiles of the scopes, and clears them. Any open scopes are closed.
+    pub(crate) fn take(&mut self) -> Vec<ScopeProfile> {
+        self.open.clear();
+        std::mem::take(&mut self.profiles)
+    }
+
+    /// Attributes a new public variable to the innermost open scope.
+    pub(crate) fn record_public(&mut self) {
+        if let Some(index) = self.open.last() {
+            self.profiles[*index].num_public += 1;
+        }
+    }
+
+    /// Attributes a new private variable to the innermost open scope.
+    pub(crate) fn record_private(&mut self) {
+        if let Some(index) = self.open.last() {
+            self.profiles[*index].num_private += 1;
+        }
+    }
+
+    /// Attributes a new constraint to the innermost open scope.
+    pub(crate) fn record_constraint(&mut self) {
+        if let Some(index) = self.open.last() {
+            self.profiles[*index].num_constraints += 1;
+        }
+    }
+}
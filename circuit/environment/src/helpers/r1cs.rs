@@ -13,21 +13,32 @@
 // limitations under the License.
 
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{Constraint, Counter, Counterexample, UnsatisfiedConstraint},
     prelude::*,
 };
 use snarkvm_fields::PrimeField;
 
-use std::rc::Rc;
+use std::{ops::Range, rc::Rc};
 
 pub type Scope = String;
 
+/// The sizes of a constraint system at a point in time, used to discard any later synthesis.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Checkpoint {
+    num_constants: usize,
+    num_public: usize,
+    num_private: usize,
+    num_constraints: usize,
+    nonzeros: (u64, u64, u64),
+}
+
 #[derive(Debug)]
 pub struct R1CS<F: PrimeField> {
     constants: Vec<Variable<F>>,
     public: Vec<Variable<F>>,
     private: Vec<Variable<F>>,
     constraints: Vec<Rc<Constraint<F>>>,
+    labels: Vec<(Range<u64>, String)>,
     counter: Counter<F>,
     nonzeros: (u64, u64, u64),
 }
@@ -40,6 +51,7 @@ impl<F: PrimeField> R1CS<F> {
             public: vec![Variable::Public(Rc::new((0u64, F::one())))],
             private: Default::default(),
             constraints: Default::default(),
+            labels: Default::default(),
             counter: Default::default(),
             nonzeros: (0, 0, 0),
         }
@@ -55,6 +67,30 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.pop(name)
     }
 
+    /// Returns a checkpoint of the current sizes of the constraint system.
+    pub(crate) fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            num_constants: self.constants.len(),
+            num_public: self.public.len(),
+            num_private: self.private.len(),
+            num_constraints: self.constraints.len(),
+            nonzeros: self.nonzeros,
+        }
+    }
+
+    /// Discards the variables and constraints that were added after the given checkpoint.
+    ///
+    /// Note: The scope counter is not modified, as it is restored by popping the scope
+    /// that was pushed after the checkpoint was taken.
+    pub(crate) fn rollback(&mut self, checkpoint: Checkpoint) {
+        self.constants.truncate(checkpoint.num_constants);
+        self.public.truncate(checkpoint.num_public);
+        self.private.truncate(checkpoint.num_private);
+        self.constraints.truncate(checkpoint.num_constraints);
+        self.labels.retain(|(range, _)| range.end <= checkpoint.num_constraints as u64);
+        self.nonzeros = checkpoint.nonzeros;
+    }
+
     /// Returns a new constant with the given value and scope.
     pub(crate) fn new_constant(&mut self, value: F) -> Variable<F> {
         let variable = Variable::Constant(Rc::new(value));
@@ -124,6 +160,49 @@ impl<F: PrimeField> R1CS<F> {
         })
     }
 
+    /// Attaches the given label to the given range of constraints, if the range is not empty.
+    pub(crate) fn label(&mut self, constraints: Range<u64>, label: String) {
+        if !constraints.is_empty() {
+            self.labels.push((constraints, label));
+        }
+    }
+
+    /// Returns the innermost label attached to the constraint at the given index, if one exists.
+    pub fn label_of(&self, index: u64) -> Option<&str> {
+        self.labels
+            .iter()
+            .filter(|(range, _)| range.contains(&index))
+            .min_by_key(|(range, _)| range.end - range.start)
+            .map(|(_, label)| label.as_str())
+    }
+
+    /// Returns the diagnostics of the first unsatisfied constraint, if one exists.
+    pub fn first_unsatisfied_constraint(&self) -> Option<UnsatisfiedConstraint<F>> {
+        self.constraints.iter().enumerate().find_map(|(index, constraint)| {
+            let (a, b, c) = constraint.to_terms();
+            let (a, b, c) = (a.value(), b.value(), c.value());
+            match a * b == c {
+                true => None,
+                false => Some(UnsatisfiedConstraint {
+                    index: index as u64,
+                    scope: constraint.0.clone(),
+                    label: self.label_of(index as u64).map(|label| label.to_string()),
+                    a,
+                    b,
+                    c,
+                }),
+            }
+        })
+    }
+
+    /// Returns a counterexample to the constraint system, which is its first unsatisfied constraint
+    /// with its evaluated linear combinations, if one exists.
+    pub fn counterexample(&self) -> Option<Counterexample<F>> {
+        let constraint = self.first_unsatisfied_constraint()?;
+        let (a, b, c) = self.constraints[constraint.index as usize].to_terms();
+        Some(Counterexample { constraint, a: a.clone(), b: b.clone(), c: c.clone() })
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
         self.counter.is_satisfied_in_scope()
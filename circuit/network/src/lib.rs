@@ -24,6 +24,12 @@ use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Sca
 pub trait Aleo: Environment {
     /// The maximum number of field elements in data (must not exceed u16::MAX).
     const MAX_DATA_SIZE_IN_FIELDS: u32 = <Self::Network as console::Network>::MAX_DATA_SIZE_IN_FIELDS;
+    /// The maximum number of entries in a struct.
+    const MAX_STRUCT_ENTRIES: usize = <Self::Network as console::Network>::MAX_STRUCT_ENTRIES;
+    /// The maximum number of elements in an array.
+    const MAX_ARRAY_ELEMENTS: usize = <Self::Network as console::Network>::MAX_ARRAY_ELEMENTS;
+    /// The maximum number of entries in a map.
+    const MAX_MAP_ENTRIES: usize = <Self::Network as console::Network>::MAX_MAP_ENTRIES;
 
     /// Returns the encryption domain as a constant field element.
     fn encryption_domain() -> Field<Self>;
@@ -118,6 +124,12 @@ pub trait Aleo: Environment {
     /// Returns the SHA-3 hash with a 512-bit output.
     fn hash_sha3_512(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
 
+    /// Returns the SHAKE-128 output with the given number of bits.
+    fn hash_shake128(input: &[Boolean<Self>], num_outputs: u16) -> Vec<Boolean<Self>>;
+
+    /// Returns the SHAKE-256 output with the given number of bits.
+    fn hash_shake256(input: &[Boolean<Self>], num_outputs: u16) -> Vec<Boolean<Self>>;
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
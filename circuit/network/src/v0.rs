@@ -32,6 +32,8 @@ use snarkvm_circuit_algorithms::{
     Sha3_256,
     Sha3_384,
     Sha3_512,
+    Shake128,
+    Shake256,
     BHP1024,
     BHP256,
     BHP512,
@@ -39,7 +41,7 @@ use snarkvm_circuit_algorithms::{
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
-    environment::{prelude::*, Assignment, Circuit, R1CS},
+    environment::{prelude::*, Assignment, Circuit, Counterexample, ScopeProfile, UnsatisfiedConstraint, R1CS},
     Boolean,
     Field,
     Group,
@@ -95,6 +97,11 @@ thread_local! {
     static SHA3_384: Sha3_384<AleoV0> = Sha3_384::<AleoV0>::new();
     /// The SHA-3 hash function, which outputs 512 bits.
     static SHA3_512: Sha3_512<AleoV0> = Sha3_512::<AleoV0>::new();
+
+    /// The SHAKE-128 extendable-output function.
+    static SHAKE_128: Shake128<AleoV0> = Shake128::<AleoV0>::new();
+    /// The SHAKE-256 extendable-output function.
+    static SHAKE_256: Shake256<AleoV0> = Shake256::<AleoV0>::new();
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -262,6 +269,16 @@ impl Aleo for AleoV0 {
         SHA3_512.with(|sha3| sha3.hash(input))
     }
 
+    /// Returns the SHAKE-128 output with the given number of bits.
+    fn hash_shake128(input: &[Boolean<Self>], num_outputs: u16) -> Vec<Boolean<Self>> {
+        SHAKE_128.with(|shake| shake.hash_many(input, num_outputs))
+    }
+
+    /// Returns the SHAKE-256 output with the given number of bits.
+    fn hash_shake256(input: &[Boolean<Self>], num_outputs: u16) -> Vec<Boolean<Self>> {
+        SHAKE_256.with(|shake| shake.hash_many(input, num_outputs))
+    }
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
         POSEIDON_2.with(|poseidon| poseidon.hash_many(input, num_outputs))
@@ -390,6 +407,53 @@ impl Environment for AleoV0 {
         E::scope(name, logic)
     }
 
+    /// Synthesizes the given logic in a new scope, and then discards the variables and constraints it allocated.
+    fn speculate<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> (Output, (u64, u64, u64, u64, (u64, u64, u64)))
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::speculate(name, logic)
+    }
+
+    /// Executes the given logic in evaluation-only mode, where the values of the variables are computed,
+    /// but no constraints are enforced.
+    fn evaluation_only<Fn, Output>(logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::evaluation_only(logic)
+    }
+
+    /// Returns `true` if the environment is in evaluation-only mode.
+    fn is_evaluation_only() -> bool {
+        E::is_evaluation_only()
+    }
+
+    /// Enables or disables profiling, which attributes constraints and variables to the open profiling scopes.
+    fn set_profiling(is_enabled: bool) {
+        E::set_profiling(is_enabled)
+    }
+
+    /// Returns `true` if profiling is enabled.
+    fn is_profiling() -> bool {
+        E::is_profiling()
+    }
+
+    /// Opens a profiling scope with the given label, if profiling is enabled.
+    fn push_scope<S: Into<String>>(label: S) {
+        E::push_scope(label)
+    }
+
+    /// Closes the innermost profiling scope, which must have the given label, if profiling is enabled.
+    fn pop_scope<S: AsRef<str>>(label: S) {
+        E::pop_scope(label)
+    }
+
+    /// Returns the profiles of the profiling scopes, in the order they were opened, and clears them.
+    fn take_profile() -> Vec<ScopeProfile> {
+        E::take_profile()
+    }
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -401,6 +465,14 @@ impl Environment for AleoV0 {
         E::enforce(constraint)
     }
 
+    /// Executes the given logic, attaching the given label to the constraints that it adds.
+    fn labeled<S: Into<String>, Fn, Output>(label: S, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        E::labeled(label, logic)
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         E::is_satisfied()
@@ -411,6 +483,16 @@ impl Environment for AleoV0 {
         E::is_satisfied_in_scope()
     }
 
+    /// Returns the diagnostics of the first unsatisfied constraint in the environment, if one exists.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint<Self::BaseField>> {
+        E::first_unsatisfied_constraint()
+    }
+
+    /// Returns a counterexample to the environment, if one exists.
+    fn counterexample() -> Option<Counterexample<Self::BaseField>> {
+        E::counterexample()
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         E::num_constants()
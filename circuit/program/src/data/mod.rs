@@ -28,7 +28,7 @@ mod literal;
 pub use literal::{Cast, CastLossy, Literal};
 
 mod plaintext;
-pub use plaintext::Plaintext;
+pub use plaintext::{Plaintext, PlaintextHasher};
 
 mod record;
 pub use record::{Entry, Owner, Record};
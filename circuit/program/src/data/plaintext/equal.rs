@@ -43,7 +43,21 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::constant(false),
+            (Self::Map(a, _), Self::Map(b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each entry for equality.
+                    // Note: This relies on the entries being in their canonical order (see `Plaintext::new_map`).
+                    let mut equal = Boolean::constant(true);
+                    for ((key_a, plaintext_a), (key_b, plaintext_b)) in a.iter().zip_eq(b.iter()) {
+                        equal = equal & key_a.is_equal(key_b) & plaintext_a.is_equal(plaintext_b);
+                    }
+                    equal
+                }
+                false => Boolean::constant(false),
+            },
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Map(..), _) => {
+                Boolean::constant(false)
+            }
         }
     }
 
@@ -73,7 +87,21 @@ impl<A: Aleo> Equal<Self> for Plaintext<A> {
                 }
                 false => Boolean::constant(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::constant(true),
+            (Self::Map(a, _), Self::Map(b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each entry for inequality.
+                    // Note: This relies on the entries being in their canonical order (see `Plaintext::new_map`).
+                    let mut not_equal = Boolean::constant(false);
+                    for ((key_a, plaintext_a), (key_b, plaintext_b)) in a.iter().zip_eq(b.iter()) {
+                        not_equal = not_equal | key_a.is_not_equal(key_b) | plaintext_a.is_not_equal(plaintext_b);
+                    }
+                    not_equal
+                }
+                false => Boolean::constant(true),
+            },
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Map(..), _) => {
+                Boolean::constant(true)
+            }
         }
     }
 }
@@ -25,6 +25,8 @@ impl<A: Aleo> Plaintext<A> {
         match self {
             // Halts if the value is not a struct or an array.
             Self::Literal(..) => A::halt("A literal is not a struct or an array"),
+            // Halts if the value is a map, as its entries are not accessible by path.
+            Self::Map(..) => A::halt("A map does not support member access"),
             // Retrieve the value of the member (from the value).
             Self::Struct(..) | Self::Array(..) => {
                 // Initialize the plaintext starting from the top-level.
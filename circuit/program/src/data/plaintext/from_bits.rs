@@ -56,6 +56,9 @@ impl<A: Aleo> FromBits for Plaintext<A> {
         // Struct
         else if variant == [false, true] {
             let num_members = U8::from_bits_le(next_bits(8)).eject_value();
+            if *num_members as usize > A::MAX_STRUCT_ENTRIES {
+                A::halt("Struct exceeds maximum of entries.")
+            }
 
             let mut members = IndexMap::with_capacity(*num_members as usize);
             for _ in 0..*num_members {
@@ -74,6 +77,9 @@ impl<A: Aleo> FromBits for Plaintext<A> {
         // Array
         else if variant == [true, false] {
             let num_elements = U32::from_bits_le(next_bits(32)).eject_value();
+            if *num_elements as usize > A::MAX_ARRAY_ELEMENTS {
+                A::halt("Array exceeds maximum of elements.")
+            }
 
             let mut elements = Vec::with_capacity(*num_elements as usize);
             for _ in 0..*num_elements {
@@ -86,6 +92,31 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             // Cache the plaintext bits, and return the array.
             Self::Array(elements, OnceCell::with_value(bits_le.to_vec()))
         }
+        // Map
+        else if variant == [true, true] {
+            let num_entries = U32::from_bits_le(next_bits(32)).eject_value();
+            if *num_entries as usize > A::MAX_MAP_ENTRIES {
+                A::halt("Map exceeds maximum of entries.")
+            }
+
+            let mut entries = Vec::with_capacity(*num_entries as usize);
+            for _ in 0..*num_entries {
+                let key_variant = U8::from_bits_le(next_bits(8));
+                let key_size = U16::from_bits_le(next_bits(16)).eject_value();
+                let key = Literal::from_bits_le(&key_variant, next_bits(*key_size as usize));
+
+                let value_size = U16::from_bits_le(next_bits(16)).eject_value();
+                let value = Plaintext::from_bits_le(next_bits(*value_size as usize));
+
+                entries.push((key, value));
+            }
+
+            // Ensure the entries are in their canonical order.
+            Self::enforce_map_entries(&entries);
+
+            // Cache the plaintext bits, and return the map.
+            Self::Map(entries, OnceCell::with_value(bits_le.to_vec()))
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
@@ -131,6 +162,9 @@ impl<A: Aleo> FromBits for Plaintext<A> {
         // Struct
         else if variant == [false, true] {
             let num_members = U8::from_bits_be(next_bits(8)).eject_value();
+            if *num_members as usize > A::MAX_STRUCT_ENTRIES {
+                A::halt("Struct exceeds maximum of entries.")
+            }
 
             let mut members = IndexMap::with_capacity(*num_members as usize);
             for _ in 0..*num_members {
@@ -149,6 +183,9 @@ impl<A: Aleo> FromBits for Plaintext<A> {
         // Array
         else if variant == [true, false] {
             let num_elements = U32::from_bits_be(next_bits(32)).eject_value();
+            if *num_elements as usize > A::MAX_ARRAY_ELEMENTS {
+                A::halt("Array exceeds maximum of elements.")
+            }
 
             let mut elements = Vec::with_capacity(*num_elements as usize);
             for _ in 0..*num_elements {
@@ -161,6 +198,31 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             // Cache the plaintext bits, and return the array.
             Self::Array(elements, OnceCell::with_value(bits_be.to_vec()))
         }
+        // Map
+        else if variant == [true, true] {
+            let num_entries = U32::from_bits_be(next_bits(32)).eject_value();
+            if *num_entries as usize > A::MAX_MAP_ENTRIES {
+                A::halt("Map exceeds maximum of entries.")
+            }
+
+            let mut entries = Vec::with_capacity(*num_entries as usize);
+            for _ in 0..*num_entries {
+                let key_variant = U8::from_bits_be(next_bits(8));
+                let key_size = U16::from_bits_be(next_bits(16)).eject_value();
+                let key = Literal::from_bits_be(&key_variant, next_bits(*key_size as usize));
+
+                let value_size = U16::from_bits_be(next_bits(16)).eject_value();
+                let value = Plaintext::from_bits_be(next_bits(*value_size as usize));
+
+                entries.push((key, value));
+            }
+
+            // Ensure the entries are in their canonical order.
+            Self::enforce_map_entries(&entries);
+
+            // Cache the plaintext bits, and return the map.
+            Self::Map(entries, OnceCell::with_value(bits_be.to_vec()))
+        }
         // Unknown variant.
         else {
             A::halt("Unknown plaintext variant.")
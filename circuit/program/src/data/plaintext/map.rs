@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Plaintext<A> {
+    /// Initializes a new map from the given entries, and enforces that they are in their canonical order.
+    ///
+    /// The canonical order sorts the keys by their literal variant, and then by their big-endian bits,
+    /// so that `is_equal` and `is_not_equal` may compare the entries of two maps pairwise.
+    pub fn new_map(entries: Vec<(Literal<A>, Plaintext<A>)>) -> Self {
+        Self::enforce_map_entries(&entries);
+        Self::Map(entries, Default::default())
+    }
+
+    /// Enforces that the given map entries are in their canonical order, with unique keys.
+    pub(super) fn enforce_map_entries(entries: &[(Literal<A>, Plaintext<A>)]) {
+        for window in entries.windows(2) {
+            let (key_a, key_b) = (&window[0].0, &window[1].0);
+            // Note: The variant of a literal is fixed by its type, and is therefore a constant.
+            let (variant_a, variant_b) = (*key_a.variant().eject_value(), *key_b.variant().eject_value());
+            match variant_a.cmp(&variant_b) {
+                core::cmp::Ordering::Less => (),
+                core::cmp::Ordering::Equal => A::assert(is_less_than_bits_be(&key_a.to_bits_be(), &key_b.to_bits_be())),
+                core::cmp::Ordering::Greater => A::halt("Map keys are not in canonical order"),
+            }
+        }
+    }
+}
+
+/// Returns `true` if the first list of big-endian bits is lexicographically less than the second.
+/// Note: If one list is a prefix of the other, the shorter list is less, as for the console map keys.
+fn is_less_than_bits_be<A: Aleo>(a: &[Boolean<A>], b: &[Boolean<A>]) -> Boolean<A> {
+    // Compare the common prefix from its least significant bit, starting from the order of the lengths.
+    a.iter().zip(b).rev().fold(Boolean::constant(a.len() < b.len()), |is_less_than, (this, that)| {
+        Boolean::ternary(&this.bitxor(that), that, &is_less_than)
+    })
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    fn sample_map(keys: &[&str]) -> console::Plaintext<CurrentNetwork> {
+        // Note: The entries are constructed directly, to bypass the canonical ordering of the console map.
+        let entries = keys
+            .iter()
+            .map(|key| (console::Literal::from_str(key).unwrap(), console::Plaintext::from_str("true").unwrap()))
+            .collect();
+        console::Plaintext::Map(entries, Default::default())
+    }
+
+    fn check_map(keys: &[&str], expected: bool) {
+        // Ensure the injected map enforces the canonical order.
+        let map = Plaintext::<Circuit>::new(Mode::Private, sample_map(keys));
+        assert_eq!(expected, Circuit::is_satisfied(), "{keys:?}");
+        let (value, bits) = (map.eject_value(), map.to_bits_le().eject_value());
+        Circuit::reset();
+
+        // Ensure a map from bits enforces the canonical order.
+        let bits = bits.into_iter().map(|bit| Boolean::new(Mode::Private, bit)).collect::<Vec<_>>();
+        let candidate = Plaintext::<Circuit>::from_bits_le(&bits);
+        assert_eq!(expected, Circuit::is_satisfied(), "{keys:?}");
+        assert_eq!(value, candidate.eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_map_order() {
+        check_map(&[], true);
+        check_map(&["1u8"], true);
+        check_map(&["1u8", "2u8", "255u8"], true);
+        check_map(&["1field", "2field"], true);
+        check_map(&["\"a\"", "\"ab\"", "\"b\""], true);
+
+        // Ensure out-of-order and duplicate keys are rejected.
+        check_map(&["2u8", "1u8"], false);
+        check_map(&["1u8", "1u8"], false);
+        check_map(&["1u8", "255u8", "2u8"], false);
+        check_map(&["2field", "1field"], false);
+        check_map(&["\"ab\"", "\"a\""], false);
+    }
+}
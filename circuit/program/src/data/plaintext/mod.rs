@@ -15,11 +15,15 @@
 #[cfg(test)]
 use snarkvm_circuit_types::environment::assert_scope;
 
+mod to_hash;
+pub use to_hash::PlaintextHasher;
+
 mod encrypt;
 mod equal;
 mod find;
 mod from_bits;
 mod from_fields;
+mod map;
 mod num_randomizers;
 mod size_in_fields;
 mod to_bits;
@@ -37,6 +41,9 @@ pub enum Plaintext<A: Aleo> {
     Struct(IndexMap<Identifier<A>, Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
     /// A plaintext array.
     Array(Vec<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext map, with its entries sorted by key in canonical order.
+    /// Note: Use `Plaintext::new_map` to construct a map, which enforces the canonical order.
+    Map(Vec<(Literal<A>, Plaintext<A>)>, OnceCell<Vec<Boolean<A>>>),
 }
 
 #[cfg(console)]
@@ -49,6 +56,12 @@ impl<A: Aleo> Inject for Plaintext<A> {
             Self::Primitive::Literal(literal, _) => Self::Literal(Literal::new(mode, literal), Default::default()),
             Self::Primitive::Struct(struct_, _) => Self::Struct(Inject::new(mode, struct_), Default::default()),
             Self::Primitive::Array(array, _) => Self::Array(Inject::new(mode, array), Default::default()),
+            Self::Primitive::Map(entries, _) => Self::new_map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (Literal::new(mode, key), Plaintext::new(mode, value)))
+                    .collect(),
+            ),
         }
     }
 }
@@ -67,6 +80,13 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 .collect::<Vec<_>>()
                 .eject_mode(),
             Self::Array(array, _) => array.iter().map(Eject::eject_mode).collect::<Vec<_>>().eject_mode(),
+            // Note: An empty map has no circuit variables, and is therefore a constant.
+            Self::Map(entries, _) if entries.is_empty() => Mode::Constant,
+            Self::Map(entries, _) => entries
+                .iter()
+                .flat_map(|(key, value)| [key.eject_mode(), value.eject_mode()])
+                .collect::<Vec<_>>()
+                .eject_mode(),
         }
     }
 
@@ -80,6 +100,10 @@ impl<A: Aleo> Eject for Plaintext<A> {
             Self::Array(array, _) => {
                 console::Plaintext::Array(array.iter().map(Eject::eject_value).collect(), Default::default())
             }
+            Self::Map(entries, _) => console::Plaintext::Map(
+                entries.iter().map(|(key, value)| (key.eject_value(), value.eject_value())).collect(),
+                Default::default(),
+            ),
         }
     }
 }
@@ -407,6 +431,24 @@ mod tests {
             OnceCell::new(),
         ));
 
+        // Test a map.
+        run_test(Plaintext::<Circuit>::Map(
+            vec![
+                (
+                    Literal::U8(U8::new(Mode::Private, console::U8::new(1))),
+                    Plaintext::<Circuit>::Literal(
+                        Literal::Field(Field::new(Mode::Private, Uniform::rand(&mut rng))),
+                        OnceCell::new(),
+                    ),
+                ),
+                (
+                    Literal::U8(U8::new(Mode::Private, console::U8::new(2))),
+                    Plaintext::<Circuit>::Literal(Literal::Boolean(Boolean::new(Mode::Private, true)), OnceCell::new()),
+                ),
+            ],
+            OnceCell::new(),
+        ));
+
         Ok(())
     }
 }
@@ -64,6 +64,24 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 // Extend the vector with the bits of the array.
                 vec.extend_from_slice(bits);
             }
+            Self::Map(entries, bits_le) => {
+                // Compute the bits of the map.
+                let bits = bits_le.get_or_init(|| {
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(true)]; // Variant bit.
+                    U32::constant(console::U32::new(entries.len() as u32)).write_bits_le(&mut bits_le);
+                    for (key, value) in entries {
+                        key.variant().write_bits_le(&mut bits_le);
+                        key.size_in_bits().write_bits_le(&mut bits_le);
+                        key.write_bits_le(&mut bits_le);
+                        let value_bits = value.to_bits_le();
+                        U16::constant(console::U16::new(value_bits.len() as u16)).write_bits_le(&mut bits_le);
+                        bits_le.extend(value_bits);
+                    }
+                    bits_le
+                });
+                // Extend the vector with the bits of the map.
+                vec.extend_from_slice(bits);
+            }
         }
     }
 
@@ -114,6 +132,24 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 // Extend the vector with the bits of the array.
                 vec.extend_from_slice(bits)
             }
+            Self::Map(entries, bits_be) => {
+                // Compute the bits of the map.
+                let bits = bits_be.get_or_init(|| {
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(true)]; // Variant bit.
+                    U32::constant(console::U32::new(entries.len() as u32)).write_bits_be(&mut bits_be);
+                    for (key, value) in entries {
+                        key.variant().write_bits_be(&mut bits_be);
+                        key.size_in_bits().write_bits_be(&mut bits_be);
+                        key.write_bits_be(&mut bits_be);
+                        let value_bits = value.to_bits_be();
+                        U16::constant(console::U16::new(value_bits.len() as u16)).write_bits_be(&mut bits_be);
+                        bits_be.extend(value_bits);
+                    }
+                    bits_be
+                });
+                // Extend the vector with the bits of the map.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 }
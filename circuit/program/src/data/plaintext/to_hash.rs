@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_circuit_algorithms::{Poseidon2, Poseidon4, Poseidon8};
+
+/// The domain separator for the canonical hash of a plaintext.
+const PLAINTEXT_HASH_DOMAIN: &str = "AleoPlaintextHash0";
+
+/// A Poseidon hash function, as instantiated by the network, that may be used to hash a plaintext.
+pub trait PlaintextHasher<A: Aleo> {
+    /// Returns the hash of the given field elements.
+    fn hash_fields(input: &[Field<A>]) -> Field<A>;
+}
+
+impl<A: Aleo> PlaintextHasher<A> for Poseidon2<A> {
+    /// Returns the hash of the given field elements, using Poseidon with a rate of 2.
+    fn hash_fields(input: &[Field<A>]) -> Field<A> {
+        A::hash_psd2(input)
+    }
+}
+
+impl<A: Aleo> PlaintextHasher<A> for Poseidon4<A> {
+    /// Returns the hash of the given field elements, using Poseidon with a rate of 4.
+    fn hash_fields(input: &[Field<A>]) -> Field<A> {
+        A::hash_psd4(input)
+    }
+}
+
+impl<A: Aleo> PlaintextHasher<A> for Poseidon8<A> {
+    /// Returns the hash of the given field elements, using Poseidon with a rate of 8.
+    fn hash_fields(input: &[Field<A>]) -> Field<A> {
+        A::hash_psd8(input)
+    }
+}
+
+impl<A: Aleo> Plaintext<A> {
+    /// Returns the canonical hash of the plaintext under the Poseidon hash function `H`,
+    /// e.g. `plaintext.to_hash::<Poseidon2<A>>()`.
+    pub fn to_hash<H: PlaintextHasher<A>>(&self) -> Field<A> {
+        // Construct the preimage as `[ PLAINTEXT_HASH_DOMAIN || FIELDS(plaintext) ]`.
+        let mut preimage = vec![Field::constant(console::Field::new_domain_separator(PLAINTEXT_HASH_DOMAIN))];
+        preimage.extend(self.to_fields());
+        // Hash the preimage.
+        H::hash_fields(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console_root::algorithms;
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    #[test]
+    fn test_to_hash() -> Result<()> {
+        let expected = console::Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: [2u8, 3u8], c: { d: 4field } }")?;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Ensure the circuit hash matches the native hash.
+            let plaintext = Plaintext::<Circuit>::new(mode, expected.clone());
+            assert_eq!(
+                expected.to_hash::<algorithms::Poseidon2<CurrentNetwork>>()?,
+                plaintext.to_hash::<Poseidon2<Circuit>>().eject_value()
+            );
+            assert_eq!(
+                expected.to_hash::<algorithms::Poseidon4<CurrentNetwork>>()?,
+                plaintext.to_hash::<Poseidon4<Circuit>>().eject_value()
+            );
+            assert_eq!(
+                expected.to_hash::<algorithms::Poseidon8<CurrentNetwork>>()?,
+                plaintext.to_hash::<Poseidon8<Circuit>>().eject_value()
+            );
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -23,12 +23,14 @@ use crate::Identifier;
 use snarkvm_circuit_network::Aleo;
 use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field};
 
-/// A program ID is of the form `{name}.{network}`.
+/// A program ID is of the form `{name}.{network}`, or `{name}.{namespace}.{network}` for a namespaced program.
 /// If no `network`-level domain is specified, the default network is used.
 #[derive(Clone)]
 pub struct ProgramID<A: Aleo> {
     /// The program name.
     name: Identifier<A>,
+    /// The namespace, if the program is deployed under a parent program.
+    namespace: Option<Identifier<A>>,
     /// The network-level domain (NLD).
     network: Identifier<A>,
 }
@@ -41,6 +43,7 @@ impl<A: Aleo> Inject for ProgramID<A> {
     fn new(_: Mode, id: Self::Primitive) -> Self {
         Self {
             name: Identifier::new(Mode::Constant, *id.name()),
+            namespace: id.namespace().map(|namespace| Identifier::new(Mode::Constant, *namespace)),
             network: Identifier::new(Mode::Constant, *id.network()),
         }
     }
@@ -53,6 +56,12 @@ impl<A: Aleo> ProgramID<A> {
         &self.name
     }
 
+    /// Returns the namespace, if the program is deployed under a parent program.
+    #[inline]
+    pub const fn namespace(&self) -> Option<&Identifier<A>> {
+        self.namespace.as_ref()
+    }
+
     /// Returns the network-level domain (NLD).
     #[inline]
     pub const fn network(&self) -> &Identifier<A> {
@@ -71,7 +80,15 @@ impl<A: Aleo> Eject for ProgramID<A> {
 
     /// Ejects a program ID into a primitive.
     fn eject_value(&self) -> Self::Primitive {
-        match console::ProgramID::try_from((self.name.eject_value(), self.network.eject_value())) {
+        let program_id = match &self.namespace {
+            Some(namespace) => console::ProgramID::try_from((
+                self.name.eject_value(),
+                namespace.eject_value(),
+                self.network.eject_value(),
+            )),
+            None => console::ProgramID::try_from((self.name.eject_value(), self.network.eject_value())),
+        };
+        match program_id {
             Ok(id) => id,
             Err(error) => A::halt(format!("Failed to eject program ID: {error}")),
         }
@@ -83,11 +100,21 @@ impl<A: Aleo> Equal<Self> for ProgramID<A> {
 
     /// Returns `true` if `self` and `other` are equal.
     fn is_equal(&self, other: &Self) -> Self::Output {
-        self.name.is_equal(&other.name) & (self.network.is_equal(&other.network))
+        let namespace_is_equal = match (&self.namespace, &other.namespace) {
+            (Some(a), Some(b)) => a.is_equal(b),
+            (None, None) => Boolean::constant(true),
+            _ => Boolean::constant(false),
+        };
+        self.name.is_equal(&other.name) & namespace_is_equal & (self.network.is_equal(&other.network))
     }
 
     /// Returns `true` if `self` and `other` are **not** equal.
     fn is_not_equal(&self, other: &Self) -> Self::Output {
-        self.name.is_not_equal(&other.name) | (self.network.is_not_equal(&other.network))
+        let namespace_is_not_equal = match (&self.namespace, &other.namespace) {
+            (Some(a), Some(b)) => a.is_not_equal(b),
+            (None, None) => Boolean::constant(false),
+            _ => Boolean::constant(true),
+        };
+        self.name.is_not_equal(&other.name) | namespace_is_not_equal | (self.network.is_not_equal(&other.network))
     }
 }
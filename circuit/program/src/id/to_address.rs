@@ -18,7 +18,7 @@ impl<A: Aleo> ProgramID<A> {
     /// Returns the program address for this program ID.
     pub fn to_address(&self) -> Address<A> {
         // Compute the program address as `HashToGroup(program_id)`.
-        let group = A::hash_to_group_psd4(&[self.name().to_field(), self.network().to_field()]);
+        let group = A::hash_to_group_psd4(&self.to_fields());
         // Return the program address.
         Address::from_group(group)
     }
@@ -19,6 +19,11 @@ impl<A: Aleo> ToFields for ProgramID<A> {
 
     /// Returns this program ID as a list of field elements.
     fn to_fields(&self) -> Vec<Self::Field> {
-        vec![self.name.to_field(), self.network.to_field()]
+        let mut fields = vec![self.name.to_field()];
+        if let Some(namespace) = &self.namespace {
+            fields.push(namespace.to_field());
+        }
+        fields.push(self.network.to_field());
+        fields
     }
 }
@@ -22,9 +22,7 @@ impl<A: Aleo> Request<A> {
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
     pub fn verify(&self, input_types: &[console::ValueType<A::Network>], tpk: &Group<A>) -> Boolean<A> {
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id = A::hash_bhp1024(
-            &(&self.network_id, self.program_id.name(), self.program_id.network(), &self.function_name).to_bits_le(),
-        );
+        let function_id = A::hash_bhp1024(&(&self.network_id, &self.program_id, &self.function_name).to_bits_le());
 
         // Construct the signature message as `[tvk, tcm, function ID, input IDs]`.
         let mut message = Vec::with_capacity(3 + 4 * self.input_ids.len());
@@ -112,8 +110,7 @@ impl<A: Aleo> Request<A> {
         }
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id =
-            A::hash_bhp1024(&(network_id, program_id.name(), program_id.network(), function_name).to_bits_le());
+        let function_id = A::hash_bhp1024(&(network_id, program_id, function_name).to_bits_le());
 
         // Initialize a vector for a message.
         let mut message = Vec::new();
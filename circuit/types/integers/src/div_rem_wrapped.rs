@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns the quotient and remainder of `self` divided by `other`, for unsigned integers.
+    ///
+    /// For 128-bit integers, the Euclidean division is checked over 64-bit halves of the quotient and divisor,
+    /// which costs fewer constraints than `div_wrapped` and `rem_wrapped`. Note that this gadget is not used by the
+    /// `div` and `rem` instructions, as changing their constraints would invalidate existing verifying keys.
+    pub fn div_rem_wrapped(&self, other: &Self) -> (Self, Self) {
+        // Ensure the integers are unsigned.
+        if I::is_signed() {
+            E::halt("Division with remainder is only supported for unsigned integers.")
+        }
+
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            // If `self` and `other` are constants, and other is not zero, then directly compute the division.
+            (true, true) => (
+                witness!(|self, other| console::Integer::new(self.wrapping_div(&other))),
+                witness!(|self, other| console::Integer::new(self.wrapping_rem(&other))),
+            ),
+            // If the product of two integers fits in the base field, then divide in the base field.
+            _ if 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 => self.unsigned_division_via_witness(other),
+            // Otherwise, divide over the halves of the quotient and divisor.
+            _ => self.unsigned_division_via_halves(other),
+        }
+    }
+
+    /// Divides `self` by `other`, via witnesses, returning the quotient and remainder.
+    /// This method does not check that `other` is non-zero.
+    /// This method assumes the `self` and `other` are unsigned integers.
+    fn unsigned_division_via_halves(&self, other: &Self) -> (Self, Self) {
+        // Eject the dividend and divisor, to compute the quotient as a witness.
+        let dividend_value = self.eject_value();
+        // Note: This prevents a panic when the divisor is 0, which is caught by the remainder check below.
+        let divisor_value = match other.eject_value().is_zero() {
+            true => console::Integer::one(),
+            false => other.eject_value(),
+        };
+
+        // Overflow is not possible for unsigned integers so we use wrapping operations.
+        let quotient = Integer::new(Mode::Private, console::Integer::new(dividend_value.wrapping_div(&divisor_value)));
+        let remainder = Integer::new(Mode::Private, console::Integer::new(dividend_value.wrapping_rem(&divisor_value)));
+
+        // Split the quotient and divisor into halves, so that every partial product fits in the base field.
+        let half = I::BITS as usize / 2;
+        let (quotient_lo, quotient_hi) =
+            (Field::from_bits_le(&quotient.bits_le[..half]), Field::from_bits_le(&quotient.bits_le[half..]));
+        let (divisor_lo, divisor_hi) =
+            (Field::from_bits_le(&other.bits_le[..half]), Field::from_bits_le(&other.bits_le[half..]));
+
+        // Ensure that `quotient_hi * divisor_hi == 0`, so that `quotient * divisor` is less than 2^I::BITS.
+        E::enforce(|| (quotient_hi, divisor_hi, E::zero()));
+
+        // Ensure that `self == quotient_lo * divisor + (quotient - quotient_lo) * divisor_lo + remainder`.
+        // Note that `(quotient - quotient_lo) * divisor_lo == quotient_hi * divisor_lo * 2^half`, and the
+        // right-hand side is less than 2^(3 * I::BITS / 2 + 2), which does not wrap around the field modulus.
+        let partial = &quotient_lo * other.to_field();
+        E::enforce(|| {
+            (quotient.to_field() - &quotient_lo, divisor_lo, self.to_field() - partial - remainder.to_field())
+        });
+
+        // Ensure that the remainder is less than the divisor.
+        // Note that if this check is satisfied, then `other` is not zero.
+        E::assert(remainder.is_less_than(other));
+
+        // Return the quotient and remainder of `self` and `other`.
+        (quotient, remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_div_rem<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        if second == console::Integer::zero() {
+            match mode_b {
+                Mode::Constant => check_operation_halts(&a, &b, Integer::div_rem_wrapped),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.div_rem_wrapped(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            }
+        } else {
+            Circuit::scope(name, || {
+                let (quotient, remainder) = a.div_rem_wrapped(&b);
+                assert_eq!(first.wrapping_div(&second), *quotient.eject_value());
+                assert_eq!(first.wrapping_rem(&second), *remainder.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            })
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("DivRem: {first} / {second}");
+            check_div_rem::<I>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("DivRem by One: {first} / 1");
+            check_div_rem::<I>(&name, first, console::Integer::one(), mode_a, mode_b);
+
+            let name = format!("DivRem by Self: {first} / {first}");
+            check_div_rem::<I>(&name, first, first, mode_a, mode_b);
+
+            let name = format!("DivRem by Zero: {first} / 0");
+            check_div_rem::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        // Check corner cases.
+        check_div_rem::<I>("MAX / 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_div_rem::<I>("MAX / MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_div_rem::<I>("1 / MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_div_rem::<I>("0 / 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+        check_div_rem::<I>("MAX / 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+    }
+
+    test_integer_binary!(run_test, u8, div_rem);
+    test_integer_binary!(run_test, u16, div_rem);
+    test_integer_binary!(run_test, u32, div_rem);
+    test_integer_binary!(run_test, u64, div_rem);
+    test_integer_binary!(run_test, u128, div_rem);
+
+    #[test]
+    fn test_u128_div_rem_is_cheaper() {
+        let mut rng = TestRng::default();
+
+        let first = Uniform::rand(&mut rng);
+        let second = Uniform::rand(&mut rng);
+
+        // Count the constraints of the division instruction.
+        let a = U128::<Circuit>::new(Mode::Private, first);
+        let b = U128::<Circuit>::new(Mode::Private, second);
+        let expected = Circuit::scope("DivWrapped", || {
+            let _candidate = a.div_wrapped(&b);
+            Circuit::num_constraints_in_scope()
+        });
+        Circuit::reset();
+
+        // Ensure the gadget, which also returns the remainder, costs fewer constraints than the division alone.
+        let a = U128::<Circuit>::new(Mode::Private, first);
+        let b = U128::<Circuit>::new(Mode::Private, second);
+        let candidate = Circuit::scope("DivRemWrapped", || {
+            let _candidate = a.div_rem_wrapped(&b);
+            Circuit::num_constraints_in_scope()
+        });
+        assert!(candidate < expected, "{candidate} constraints, expected fewer than {expected}");
+        Circuit::reset();
+    }
+}
@@ -25,6 +25,7 @@ pub mod add_wrapped;
 pub mod and;
 pub mod compare;
 pub mod div_checked;
+pub mod div_rem_wrapped;
 pub mod div_wrapped;
 pub mod equal;
 pub mod modulo;
@@ -37,6 +38,8 @@ pub mod pow_checked;
 pub mod pow_wrapped;
 pub mod rem_checked;
 pub mod rem_wrapped;
+pub mod rotate_left;
+pub mod rotate_right;
 pub mod shl_checked;
 pub mod shl_wrapped;
 pub mod shr_checked;
@@ -44,6 +47,7 @@ pub mod shr_wrapped;
 pub mod sub_checked;
 pub mod sub_wrapped;
 pub mod ternary;
+pub mod u256;
 pub mod xor;
 
 pub type I8<E> = Integer<E, i8>;
@@ -58,6 +62,8 @@ pub type U32<E> = Integer<E, u32>;
 pub type U64<E> = Integer<E, u64>;
 pub type U128<E> = Integer<E, u128>;
 
+pub use u256::U256;
+
 #[cfg(test)]
 use snarkvm_circuit_environment::{
     assert_count,
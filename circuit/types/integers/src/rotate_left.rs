@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateLeft<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates the bits of `self` to the left by `rhs` bits, wrapping the truncated bits to the end.
+    #[inline]
+    fn rotate_left(&self, rhs: &Integer<E, M>) -> Self::Output {
+        // Since `I::BITS` is a power of two, rotating by `rhs` is equivalent to rotating by its lower `log2(I::BITS)` bits.
+        let num_stages = I::BITS.trailing_zeros() as usize;
+
+        // Rotate the bits with a barrel shifter, where the `i`-th stage rotates by `2^i` bits if the `i`-th bit of `rhs` is set.
+        // Note: If a bit of `rhs` is constant, its stage selects the bits directly, without any constraints.
+        let mut bits_le = self.bits_le.clone();
+        for (i, bit) in rhs.bits_le[..num_stages].iter().enumerate() {
+            // Rotating to the left moves each bit to a more significant position in the little-endian bits.
+            let mut rotated = bits_le.clone();
+            rotated.rotate_right(1 << i);
+            bits_le = rotated
+                .iter()
+                .zip_eq(&bits_le)
+                .map(|(rotated, bit_le)| Boolean::ternary(bit, rotated, bit_le))
+                .collect();
+        }
+
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn RotateLeft<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, bool);
+
+    fn count(case: &Self::Case) -> Count {
+        // Each stage of the barrel shifter selects every bit of the integer.
+        let num_selections = I::BITS * I::BITS.trailing_zeros() as u64;
+
+        match (case.0, case.1) {
+            (_, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (Mode::Constant, _) => Count::less_than(num_selections, 0, num_selections, num_selections),
+            (_, _) => Count::is(0, 0, num_selections, num_selections),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn RotateLeft<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, bool);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1, case.2) {
+            (mode_a, Mode::Constant, _) => mode_a,
+            // If the bits of `self` are all equal, every rotation of `self` is the same constant.
+            (Mode::Constant, _, true) => Mode::Constant,
+            (_, _, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rotate_left<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let expected = first.rotate_left(&second);
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        let a_is_uniform = first.is_zero() || (!first).is_zero();
+        Circuit::scope(name, || {
+            let candidate = a.rotate_left(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(RotateLeft(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b, a_is_uniform));
+            assert_output_mode!(RotateLeft(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b, a_is_uniform), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("RotateLeft: {mode_a} {mode_b} {i}");
+            check_rotate_left::<I, M>(&name, first, second, mode_a, mode_b);
+
+            // Check that rotating by zero is the identity.
+            let name = format!("Identity: {mode_a} {mode_b} {i}");
+            check_rotate_left::<I, M>(&name, first, console::Integer::zero(), mode_a, mode_b);
+
+            // Check that rotating a uniform integer is the identity.
+            let name = format!("Uniform: {mode_a} {mode_b} {i}");
+            check_rotate_left::<I, M>(&name, console::Integer::zero(), second, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_rotate_left_matches_primitive() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first: u32 = Uniform::rand(&mut rng);
+            let second: u8 = Uniform::rand(&mut rng);
+
+            let a = Integer::<Circuit, u32>::new(Mode::Private, console::Integer::new(first));
+            let b = Integer::<Circuit, u8>::new(Mode::Private, console::Integer::new(second));
+            assert_eq!(first.rotate_left(second as u32), *a.rotate_left(&b).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    test_integer_binary!(run_test, i8, u8, rotate_left);
+    test_integer_binary!(run_test, i16, u8, rotate_left);
+    test_integer_binary!(run_test, i32, u8, rotate_left);
+    test_integer_binary!(run_test, i64, u8, rotate_left);
+    test_integer_binary!(run_test, i128, u8, rotate_left);
+
+    test_integer_binary!(run_test, u8, u8, rotate_left);
+    test_integer_binary!(run_test, u16, u8, rotate_left);
+    test_integer_binary!(run_test, u32, u8, rotate_left);
+    test_integer_binary!(run_test, u32, u16, rotate_left);
+    test_integer_binary!(run_test, u32, u32, rotate_left);
+    test_integer_binary!(run_test, u64, u8, rotate_left);
+    test_integer_binary!(run_test, u128, u8, rotate_left);
+}
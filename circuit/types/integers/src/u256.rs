@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bits in a limb of the division identity.
+const LIMB_BITS: usize = 64;
+/// The number of limbs in a 256-bit integer.
+const NUM_LIMBS: usize = 4;
+/// The number of bits in a carry of the division identity, which is less than `2^66 + 2`.
+const CARRY_BITS: usize = 67;
+
+/// A 256-bit unsigned integer, represented by its lower and upper 128 bits.
+///
+/// Note: This type supports division and remainder for custom circuits that need to match 256-bit
+/// semantics (e.g. EVM arithmetic), and is not a literal type in Aleo programs.
+#[derive(Clone)]
+pub struct U256<E: Environment> {
+    /// The lower 128 bits.
+    lo: U128<E>,
+    /// The upper 128 bits.
+    hi: U128<E>,
+}
+
+impl<E: Environment> U256<E> {
+    /// Initializes a 256-bit integer from its lower and upper 128 bits.
+    pub const fn from_u128s(lo: U128<E>, hi: U128<E>) -> Self {
+        Self { lo, hi }
+    }
+
+    /// Returns the lower 128 bits.
+    pub const fn lo(&self) -> &U128<E> {
+        &self.lo
+    }
+
+    /// Returns the upper 128 bits.
+    pub const fn hi(&self) -> &U128<E> {
+        &self.hi
+    }
+
+    /// Returns `true` if `self` is less than `other`.
+    pub fn is_less_than(&self, other: &Self) -> Boolean<E> {
+        self.hi.is_less_than(&other.hi) | (self.hi.is_equal(&other.hi) & self.lo.is_less_than(&other.lo))
+    }
+
+    /// Returns the four little-endian 64-bit limbs of `self`, as field elements.
+    fn to_limbs(&self) -> Vec<Field<E>> {
+        let bits_le = self.lo.bits_le.iter().chain(&self.hi.bits_le).cloned().collect::<Vec<_>>();
+        bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for U256<E> {
+    type Primitive = (console::Integer<E::Network, u128>, console::Integer<E::Network, u128>);
+
+    /// Initializes a new 256-bit integer from its lower and upper 128 bits.
+    fn new(mode: Mode, (lo, hi): Self::Primitive) -> Self {
+        Self { lo: U128::new(mode, lo), hi: U128::new(mode, hi) }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for U256<E> {
+    type Primitive = (console::Integer<E::Network, u128>, console::Integer<E::Network, u128>);
+
+    /// Ejects the mode of the 256-bit integer.
+    fn eject_mode(&self) -> Mode {
+        (&self.lo, &self.hi).eject_mode()
+    }
+
+    /// Ejects the 256-bit integer as its lower and upper 128 bits.
+    fn eject_value(&self) -> Self::Primitive {
+        (self.lo.eject_value(), self.hi.eject_value())
+    }
+}
+
+impl<E: Environment> DivWrapped<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns the quotient of `self` and `other`, which halts if `other` is a constant zero.
+    fn div_wrapped(&self, other: &Self) -> Self::Output {
+        self.unsigned_division(other).0
+    }
+}
+
+impl<E: Environment> RemWrapped<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns the remainder of `self` and `other`, which halts if `other` is a constant zero.
+    fn rem_wrapped(&self, other: &Self) -> Self::Output {
+        self.unsigned_division(other).1
+    }
+}
+
+impl<E: Environment> Modulo<Self> for U256<E> {
+    type Output = Self;
+
+    /// Returns `self` modulo `other`, which is the remainder for unsigned integers.
+    fn modulo(&self, other: &Self) -> Self::Output {
+        self.rem_wrapped(other)
+    }
+}
+
+impl<E: Environment> U256<E> {
+    /// Divides `self` by `other`, via witnesses, returning the quotient and remainder.
+    ///
+    /// The quotient `q` and remainder `r` are computed natively, and the circuit checks the integer identity
+    /// `q * d + r == n` over 64-bit limbs with carries, along with `r < d`, which also ensures `d` is non-zero.
+    fn unsigned_division(&self, other: &Self) -> (Self, Self) {
+        // Eject the dividend and divisor, to compute the quotient and remainder natively.
+        let dividend = (*self.lo.eject_value(), *self.hi.eject_value());
+        let divisor = (*other.lo.eject_value(), *other.hi.eject_value());
+
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if divisor == (0, 0) => E::halt("Attempted to divide by zero."),
+            // If `self` and `other` are constants, then directly return the quotient and remainder.
+            (true, true) => {
+                let (quotient, remainder) = div_rem(dividend, divisor);
+                (Self::from_primitive(Mode::Constant, quotient), Self::from_primitive(Mode::Constant, remainder))
+            }
+            _ => {
+                // Note: The divisor is replaced with one when it is zero, to prevent a panic. The circuit is unsatisfied.
+                let (quotient_value, remainder_value) =
+                    div_rem(dividend, if divisor == (0, 0) { (1, 0) } else { divisor });
+                let quotient = Self::from_primitive(Mode::Private, quotient_value);
+                let remainder = Self::from_primitive(Mode::Private, remainder_value);
+
+                // Split each integer into limbs, and their values.
+                let (n, q, d, r) = (self.to_limbs(), quotient.to_limbs(), other.to_limbs(), remainder.to_limbs());
+                let (n_value, q_value, d_value, r_value) = (
+                    to_u64_limbs(dividend),
+                    to_u64_limbs(quotient_value),
+                    to_u64_limbs(divisor),
+                    to_u64_limbs(remainder_value),
+                );
+
+                // Ensure the partial products above 2^256 are zero, so that `q * d` is less than 2^256.
+                for i in 1..NUM_LIMBS {
+                    for j in (NUM_LIMBS - i)..NUM_LIMBS {
+                        E::enforce(|| (&q[i], &d[j], E::zero()));
+                    }
+                }
+
+                // Ensure `q * d + r == n`, by checking each limb and propagating the carry to the next limb.
+                // Note that each limb is less than 2^131, which does not wrap around the field modulus.
+                let two_to_limb_bits = Field::constant(console::Field::from_u128(1u128 << LIMB_BITS));
+                let (mut carry, mut carry_value) = (Field::<E>::zero(), 0u128);
+                for k in 0..NUM_LIMBS {
+                    // Compute `sum(q_i * d_j) + r_k + carry` for `i + j == k`.
+                    let mut limb = &r[k] + &carry;
+                    let (mut upper, mut lower) = (0u128, u128::from(r_value[k]) + carry_value);
+                    for i in 0..=k {
+                        limb += &q[i] * &d[k - i];
+                        let product = u128::from(q_value[i]) * u128::from(d_value[k - i]);
+                        upper += product >> LIMB_BITS;
+                        lower += product & u128::from(u64::MAX);
+                    }
+                    match k + 1 == NUM_LIMBS {
+                        // Ensure the most significant limb has no carry.
+                        true => E::assert_eq(limb, &n[k]),
+                        false => {
+                            // Witness the carry, which is `(limb - n_k) / 2^64`.
+                            carry_value = upper + (lower.wrapping_sub(u128::from(n_value[k])) >> LIMB_BITS);
+                            let carry_out = Field::new(Mode::Private, console::Field::from_u128(carry_value));
+                            // Ensure the carry is within `[0, 2^CARRY_BITS)`.
+                            carry_out.to_lower_bits_le(CARRY_BITS);
+                            // Ensure `limb == n_k + carry * 2^64`.
+                            E::assert_eq(limb, &n[k] + &carry_out * &two_to_limb_bits);
+                            carry = carry_out;
+                        }
+                    }
+                }
+
+                // Ensure that the remainder is less than the divisor.
+                // Note that if this check is satisfied, then `other` is not zero.
+                E::assert(remainder.is_less_than(other));
+
+                (quotient, remainder)
+            }
+        }
+    }
+
+    /// Initializes a 256-bit integer of the given mode from its lower and upper 128 bits.
+    fn from_primitive(mode: Mode, (lo, hi): (u128, u128)) -> Self {
+        Self { lo: U128::new(mode, console::Integer::new(lo)), hi: U128::new(mode, console::Integer::new(hi)) }
+    }
+}
+
+/// Returns the quotient and remainder of the 256-bit integers `(lo, hi)`, where the divisor is non-zero.
+fn div_rem(dividend: (u128, u128), divisor: (u128, u128)) -> ((u128, u128), (u128, u128)) {
+    // Compare the integers by their upper bits, then by their lower bits.
+    let is_greater_than_or_equal =
+        |(a_lo, a_hi): (u128, u128), (b_lo, b_hi): (u128, u128)| (a_hi, a_lo) >= (b_hi, b_lo);
+
+    // Compute the quotient and remainder via binary long division.
+    let (mut quotient, mut remainder) = ((0u128, 0u128), (0u128, 0u128));
+    for i in (0..256).rev() {
+        // Shift the next bit of the dividend into the remainder, tracking the bit that is shifted out.
+        let overflow = remainder.1 >> 127 == 1;
+        let bit = match i < 128 {
+            true => (dividend.0 >> i) & 1,
+            false => (dividend.1 >> (i - 128)) & 1,
+        };
+        remainder = ((remainder.0 << 1) | bit, (remainder.1 << 1) | (remainder.0 >> 127));
+        // If the remainder is at least the divisor, subtract the divisor and set the quotient bit.
+        if overflow || is_greater_than_or_equal(remainder, divisor) {
+            let (lo, borrow) = remainder.0.overflowing_sub(divisor.0);
+            remainder = (lo, remainder.1.wrapping_sub(divisor.1).wrapping_sub(u128::from(borrow)));
+            match i < 128 {
+                true => quotient.0 |= 1 << i,
+                false => quotient.1 |= 1 << (i - 128),
+            }
+        }
+    }
+    (quotient, remainder)
+}
+
+/// Returns the four little-endian 64-bit limbs of the 256-bit integer `(lo, hi)`.
+fn to_u64_limbs((lo, hi): (u128, u128)) -> [u64; NUM_LIMBS] {
+    let mask = u128::from(u64::MAX);
+    [(lo & mask) as u64, (lo >> 64) as u64, (hi & mask) as u64, (hi >> 64) as u64]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Returns `q * d + r` as 64-bit limbs, or `None` if it overflows 256 bits.
+    fn mul_add(q: (u128, u128), d: (u128, u128), r: (u128, u128)) -> Option<[u64; NUM_LIMBS]> {
+        let (q, d, r) = (to_u64_limbs(q), to_u64_limbs(d), to_u64_limbs(r));
+        let mut output = [0u128; 2 * NUM_LIMBS];
+        for (i, q) in q.iter().enumerate() {
+            for (j, d) in d.iter().enumerate() {
+                let product = u128::from(*q) * u128::from(*d);
+                output[i + j] += product & u128::from(u64::MAX);
+                output[i + j + 1] += product >> 64;
+            }
+        }
+        for (k, r) in r.iter().enumerate() {
+            output[k] += u128::from(*r);
+        }
+        for k in 0..2 * NUM_LIMBS - 1 {
+            output[k + 1] += output[k] >> 64;
+            output[k] &= u128::from(u64::MAX);
+        }
+        match output[NUM_LIMBS..].iter().all(|limb| *limb == 0) {
+            true => Some([output[0] as u64, output[1] as u64, output[2] as u64, output[3] as u64]),
+            false => None,
+        }
+    }
+
+    /// Samples a random 256-bit integer, with a random number of leading zeros.
+    fn sample(rng: &mut TestRng) -> (u128, u128) {
+        let (lo, hi) = (u128::rand(rng), u128::rand(rng));
+        match u8::rand(rng) % 4 {
+            0 => (lo >> (u8::rand(rng) % 128), 0),
+            1 => (lo, hi >> (u8::rand(rng) % 128)),
+            _ => (lo, hi),
+        }
+    }
+
+    fn check_div_rem(name: &str, first: (u128, u128), second: (u128, u128), mode_a: Mode, mode_b: Mode) {
+        let a = U256::<Circuit>::new(mode_a, (console::Integer::new(first.0), console::Integer::new(first.1)));
+        let b = U256::<Circuit>::new(mode_b, (console::Integer::new(second.0), console::Integer::new(second.1)));
+        if second == (0, 0) {
+            match mode_b {
+                Mode::Constant => check_operation_halts(&a, &b, U256::div_wrapped),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.div_wrapped(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            }
+        } else {
+            let (quotient, remainder) = div_rem(first, second);
+            Circuit::scope(name, || {
+                let candidate = a.div_wrapped(&b);
+                assert_eq!(quotient, (*candidate.lo().eject_value(), *candidate.hi().eject_value()));
+                let candidate = a.rem_wrapped(&b);
+                assert_eq!(remainder, (*candidate.lo().eject_value(), *candidate.hi().eject_value()));
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let (first, second) = (sample(&mut rng), sample(&mut rng));
+            check_div_rem(&format!("Div: {i}"), first, second, mode_a, mode_b);
+            check_div_rem(&format!("Div by One: {i}"), first, (1, 0), mode_a, mode_b);
+            check_div_rem(&format!("Div by Self: {i}"), first, first, mode_a, mode_b);
+            check_div_rem(&format!("Div by Zero: {i}"), first, (0, 0), mode_a, mode_b);
+        }
+
+        // Check some corner cases.
+        check_div_rem("MAX / 1", (u128::MAX, u128::MAX), (1, 0), mode_a, mode_b);
+        check_div_rem("MAX / MAX", (u128::MAX, u128::MAX), (u128::MAX, u128::MAX), mode_a, mode_b);
+        check_div_rem("MAX / 2^128", (u128::MAX, u128::MAX), (0, 1), mode_a, mode_b);
+        check_div_rem("0 / MAX", (0, 0), (u128::MAX, u128::MAX), mode_a, mode_b);
+        check_div_rem("MAX / 0", (u128::MAX, u128::MAX), (0, 0), mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_div_rem_native() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..1000 {
+            let (dividend, divisor) = (sample(&mut rng), sample(&mut rng));
+            if divisor == (0, 0) {
+                continue;
+            }
+            let (quotient, remainder) = div_rem(dividend, divisor);
+            // Ensure the remainder is less than the divisor.
+            assert!((remainder.1, remainder.0) < (divisor.1, divisor.0));
+            // Ensure `quotient * divisor + remainder == dividend`.
+            assert_eq!(Some(to_u64_limbs(dividend)), mul_add(quotient, divisor, remainder));
+            // Ensure the result matches 128-bit division, when the integers fit in 128 bits.
+            if dividend.1 == 0 && divisor.1 == 0 {
+                assert_eq!(quotient, (dividend.0 / divisor.0, 0));
+                assert_eq!(remainder, (dividend.0 % divisor.0, 0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_u256_div_rem_constant_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_u256_div_rem_constant_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_u256_div_rem_private_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_u256_div_rem_private_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
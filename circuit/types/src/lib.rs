@@ -32,7 +32,7 @@ pub mod modules {
     pub use snarkvm_circuit_types_group::Group;
 
     pub use snarkvm_circuit_types_integers as integers;
-    pub use snarkvm_circuit_types_integers::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+    pub use snarkvm_circuit_types_integers::{I128, I16, I32, I64, I8, U128, U16, U256, U32, U64, U8};
 
     pub use snarkvm_circuit_types_scalar as scalar;
     pub use snarkvm_circuit_types_scalar::Scalar;
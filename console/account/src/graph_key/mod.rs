@@ -23,7 +23,15 @@ use crate::ViewKey;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::Field;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+use zeroize::Zeroize;
+
+/// The account graph key, which derives the tags of the records of an account.
+///
+/// A graph key determines whether a known record is spent, as `tag := Hash(sk_tag, commitment)`, but it cannot
+/// determine whether a record ciphertext belongs to the account: the owner of a record is encrypted under the
+/// record view key `(nonce * view_key).x`, which a graph key cannot compute. To test ownership without decrypting
+/// a record, use `Record::is_owner` with the view key, which decrypts only the owner.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
 pub struct GraphKey<N: Network> {
     /// The graph key `sk_tag` := Hash(view_key || ctr).
     sk_tag: Field<N>,
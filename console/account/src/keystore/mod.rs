@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serialize;
+mod string;
+
+use crate::PrivateKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Address;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+use zeroize::Zeroize;
+
+/// The version of the keystore format.
+const KEYSTORE_VERSION: u8 = 1;
+/// The number of bytes in the salt of the key derivation function.
+const SALT_SIZE: usize = 16;
+/// The number of bytes in the nonce of the cipher.
+const NONCE_SIZE: usize = 12;
+/// The number of bytes in the encryption key.
+const KEY_SIZE: usize = 32;
+/// The maximum memory cost of the key derivation function, in KiB (i.e. 1 GiB).
+const MAX_MEMORY_COST: u32 = 1024 * 1024;
+/// The maximum number of iterations of the key derivation function.
+const MAX_TIME_COST: u32 = 16;
+/// The maximum degree of parallelism of the key derivation function.
+const MAX_PARALLELISM: u32 = 16;
+
+/// The Argon2id parameters that derive the encryption key of a keystore from its password.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KdfParams {
+    /// The memory cost, in KiB.
+    pub memory_cost: u32,
+    /// The number of iterations.
+    pub time_cost: u32,
+    /// The degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl KdfParams {
+    /// Ensures the parameters are within the supported bounds, so an untrusted keystore
+    /// cannot force an arbitrarily expensive key derivation.
+    pub fn check(&self) -> Result<()> {
+        ensure!(self.memory_cost <= MAX_MEMORY_COST, "The keystore memory cost exceeds {MAX_MEMORY_COST} KiB");
+        ensure!(self.time_cost <= MAX_TIME_COST, "The keystore time cost exceeds {MAX_TIME_COST}");
+        ensure!(self.parallelism <= MAX_PARALLELISM, "The keystore parallelism exceeds {MAX_PARALLELISM}");
+        Ok(())
+    }
+}
+
+impl Default for KdfParams {
+    /// Returns the recommended Argon2id parameters (RFC 9106, second recommended option).
+    fn default() -> Self {
+        Self { memory_cost: 64 * 1024, time_cost: 3, parallelism: 4 }
+    }
+}
+
+/// A private key, encrypted under a password.
+///
+/// The encryption key is derived from the password with Argon2id, and the private key is encrypted
+/// with ChaCha20-Poly1305, with the address of the account as associated data.
+/// A keystore is stored as a versioned JSON file, which is read and written with `FromStr` and `Display`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Keystore<N: Network> {
+    /// The address of the account.
+    address: Address<N>,
+    /// The parameters of the key derivation function.
+    kdf_params: KdfParams,
+    /// The salt of the key derivation function.
+    salt: [u8; SALT_SIZE],
+    /// The nonce of the cipher.
+    nonce: [u8; NONCE_SIZE],
+    /// The encrypted private key, with its authentication tag.
+    ciphertext: Vec<u8>,
+}
+
+impl<N: Network> Keystore<N> {
+    /// Encrypts the given private key under the given password, with the default key derivation parameters.
+    pub fn encrypt<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, password: &str, rng: &mut R) -> Result<Self> {
+        Self::encrypt_with_params(private_key, password, KdfParams::default(), rng)
+    }
+
+    /// Encrypts the given private key under the given password, with the given key derivation parameters.
+    pub fn encrypt_with_params<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        password: &str,
+        kdf_params: KdfParams,
+        rng: &mut R,
+    ) -> Result<Self> {
+        let address = Address::try_from(private_key)?;
+        // Sample the salt and the nonce.
+        let salt = rng.gen::<[u8; SALT_SIZE]>();
+        let nonce = rng.gen::<[u8; NONCE_SIZE]>();
+        // Derive the encryption key.
+        let mut key = derive_key(password, &kdf_params, &salt)?;
+        // Encrypt the private key.
+        let mut plaintext = private_key.to_bytes_le()?;
+        let aad = address.to_string();
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: &plaintext, aad: aad.as_bytes() });
+        key.zeroize();
+        plaintext.zeroize();
+        let ciphertext = ciphertext.map_err(|_| anyhow!("Failed to encrypt the private key"))?;
+        Ok(Self { address, kdf_params, salt, nonce, ciphertext })
+    }
+
+    /// Decrypts the private key with the given password.
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey<N>> {
+        // Derive the encryption key.
+        let mut key = derive_key(password, &self.kdf_params, &self.salt)?;
+        // Decrypt the private key.
+        let aad = self.address.to_string();
+        let plaintext = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .decrypt(Nonce::from_slice(&self.nonce), Payload { msg: &self.ciphertext, aad: aad.as_bytes() });
+        key.zeroize();
+        let mut plaintext =
+            plaintext.map_err(|_| anyhow!("Failed to decrypt the keystore, the password is incorrect"))?;
+        let private_key = PrivateKey::from_bytes_le(&plaintext);
+        plaintext.zeroize();
+        let private_key = private_key?;
+        // Ensure the private key corresponds to the address.
+        ensure!(
+            Address::try_from(&private_key)? == self.address,
+            "The keystore private key does not match its address"
+        );
+        Ok(private_key)
+    }
+
+    /// Returns the address of the account.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the parameters of the key derivation function.
+    pub const fn kdf_params(&self) -> &KdfParams {
+        &self.kdf_params
+    }
+}
+
+/// Returns the encryption key for the given password, with Argon2id.
+fn derive_key(password: &str, kdf_params: &KdfParams, salt: &[u8]) -> Result<[u8; KEY_SIZE]> {
+    // Ensure the parameters are within bounds, before allocating the memory of the key derivation.
+    kdf_params.check()?;
+    let params = Params::new(kdf_params.memory_cost, kdf_params.time_cost, kdf_params.parallelism, Some(KEY_SIZE))
+        .map_err(|error| anyhow!("Invalid keystore KDF parameters: {error}"))?;
+    let mut key = [0u8; KEY_SIZE];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|error| anyhow!("Failed to derive the keystore key: {error}"))?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Inexpensive key derivation parameters, for testing.
+    pub(super) const TEST_KDF_PARAMS: KdfParams = KdfParams { memory_cost: 64, time_cost: 1, parallelism: 1 };
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let keystore = Keystore::encrypt_with_params(&private_key, "password", TEST_KDF_PARAMS, &mut rng)?;
+            assert_eq!(Address::try_from(&private_key)?, keystore.address());
+            assert_eq!(private_key, keystore.decrypt("password")?);
+
+            // Ensure an incorrect password fails.
+            assert!(keystore.decrypt("Password").is_err());
+            assert!(keystore.decrypt("").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampered_keystore_fails() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let keystore = Keystore::encrypt_with_params(&private_key, "password", TEST_KDF_PARAMS, &mut rng)?;
+
+        // Ensure a different address fails authentication.
+        let mut tampered = keystore.clone();
+        tampered.address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        assert!(tampered.decrypt("password").is_err());
+
+        // Ensure a modified ciphertext fails authentication.
+        let mut tampered = keystore.clone();
+        tampered.ciphertext[0] ^= 1;
+        assert!(tampered.decrypt("password").is_err());
+
+        // Ensure modified parameters fail authentication.
+        let mut tampered = keystore;
+        tampered.kdf_params.time_cost += 1;
+        assert!(tampered.decrypt("password").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_kdf_params_bounds() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure the default parameters are within bounds.
+        KdfParams::default().check()?;
+
+        // Ensure excessive parameters are rejected, without deriving a key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        for kdf_params in [
+            KdfParams { memory_cost: MAX_MEMORY_COST + 1, ..TEST_KDF_PARAMS },
+            KdfParams { time_cost: MAX_TIME_COST + 1, ..TEST_KDF_PARAMS },
+            KdfParams { parallelism: MAX_PARALLELISM + 1, ..TEST_KDF_PARAMS },
+        ] {
+            assert!(kdf_params.check().is_err());
+            assert!(Keystore::encrypt_with_params(&private_key, "password", kdf_params, &mut rng).is_err());
+        }
+        Ok(())
+    }
+}
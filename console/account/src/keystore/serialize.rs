@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The name of the key derivation function.
+const KDF: &str = "argon2id";
+/// The name of the cipher.
+const CIPHER: &str = "chacha20poly1305";
+
+impl<N: Network> Serialize for Keystore<N> {
+    /// Serializes the keystore to a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_json::json!({
+            "version": KEYSTORE_VERSION,
+            "address": self.address.to_string(),
+            "crypto": {
+                "kdf": KDF,
+                "kdfparams": {
+                    "memory_cost": self.kdf_params.memory_cost,
+                    "time_cost": self.kdf_params.time_cost,
+                    "parallelism": self.kdf_params.parallelism,
+                    "salt": hex::encode(self.salt),
+                },
+                "cipher": CIPHER,
+                "nonce": hex::encode(self.nonce),
+                "ciphertext": hex::encode(&self.ciphertext),
+            },
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Keystore<N> {
+    /// Deserializes the keystore from a JSON-string.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Deserialize the keystore into a JSON value.
+        let keystore = serde_json::Value::deserialize(deserializer)?;
+        Self::from_json(&keystore).map_err(de::Error::custom)
+    }
+}
+
+impl<N: Network> Keystore<N> {
+    /// Initializes the keystore from a JSON value.
+    fn from_json(keystore: &serde_json::Value) -> Result<Self> {
+        // Ensure the version is supported.
+        let version = keystore["version"].as_u64().ok_or_else(|| anyhow!("The keystore \"version\" is missing"))?;
+        ensure!(version == KEYSTORE_VERSION as u64, "Unsupported keystore version {version}");
+        // Retrieve the address.
+        let address = Address::from_str(get_str(keystore, "address")?)?;
+
+        // Ensure the key derivation function and the cipher are supported.
+        let crypto = &keystore["crypto"];
+        ensure!(get_str(crypto, "kdf")? == KDF, "Unsupported keystore KDF, expected '{KDF}'");
+        ensure!(get_str(crypto, "cipher")? == CIPHER, "Unsupported keystore cipher, expected '{CIPHER}'");
+
+        // Retrieve the parameters of the key derivation function.
+        let params = &crypto["kdfparams"];
+        let get_u32 = |key: &str| -> Result<u32> {
+            let value = params[key].as_u64().ok_or_else(|| anyhow!("The keystore \"{key}\" is missing"))?;
+            Ok(u32::try_from(value)?)
+        };
+        let kdf_params = KdfParams {
+            memory_cost: get_u32("memory_cost")?,
+            time_cost: get_u32("time_cost")?,
+            parallelism: get_u32("parallelism")?,
+        };
+        // Ensure the parameters are within bounds, as the keystore may be untrusted.
+        kdf_params.check()?;
+        let salt =
+            get_hex(params, "salt")?.try_into().map_err(|_| anyhow!("The keystore salt must be {SALT_SIZE} bytes"))?;
+
+        // Retrieve the nonce and the ciphertext.
+        let nonce = get_hex(crypto, "nonce")?
+            .try_into()
+            .map_err(|_| anyhow!("The keystore nonce must be {NONCE_SIZE} bytes"))?;
+        let ciphertext = get_hex(crypto, "ciphertext")?;
+
+        Ok(Self { address, kdf_params, salt, nonce, ciphertext })
+    }
+}
+
+/// Returns the string at the given key of the JSON object.
+fn get_str<'a>(object: &'a serde_json::Value, key: &str) -> Result<&'a str> {
+    object[key].as_str().ok_or_else(|| anyhow!("The keystore \"{key}\" is missing"))
+}
+
+/// Returns the bytes of the hex string at the given key of the JSON object.
+fn get_hex(object: &serde_json::Value, key: &str) -> Result<Vec<u8>> {
+    hex::decode(get_str(object, key)?).map_err(|error| anyhow!("The keystore \"{key}\" is not valid hex: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keystore::tests::TEST_KDF_PARAMS;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let expected = Keystore::encrypt_with_params(&private_key, "password", TEST_KDF_PARAMS, &mut rng)?;
+
+        // Serialize
+        let expected_string = expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected_string, candidate_string);
+        let json = serde_json::Value::from_str(&candidate_string)?;
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["crypto"]["kdf"], "argon2id");
+
+        // Deserialize
+        let candidate = Keystore::<CurrentNetwork>::from_str(&expected_string)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        assert_eq!(private_key, candidate.decrypt("password")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_keystore_fails() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let keystore = Keystore::encrypt_with_params(&private_key, "password", TEST_KDF_PARAMS, &mut rng)?;
+        let json = serde_json::to_value(&keystore)?;
+
+        // Ensure an unsupported version, KDF, or cipher fails.
+        for (pointer, value) in [
+            ("/version", serde_json::json!(2)),
+            ("/crypto/kdf", "scrypt".into()),
+            ("/crypto/cipher", "aes-128-ctr".into()),
+        ] {
+            let mut candidate = json.clone();
+            *candidate.pointer_mut(pointer).unwrap() = value;
+            assert!(serde_json::from_value::<Keystore<CurrentNetwork>>(candidate).is_err());
+        }
+
+        // Ensure an excessive memory cost fails.
+        let mut candidate = json.clone();
+        *candidate.pointer_mut("/crypto/kdfparams/memory_cost").unwrap() = u32::MAX.into();
+        assert!(serde_json::from_value::<Keystore<CurrentNetwork>>(candidate).is_err());
+
+        // Ensure a malformed salt fails.
+        let mut candidate = json;
+        *candidate.pointer_mut("/crypto/kdfparams/salt").unwrap() = "00".into();
+        assert!(serde_json::from_value::<Keystore<CurrentNetwork>>(candidate).is_err());
+        Ok(())
+    }
+}
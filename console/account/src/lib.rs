@@ -17,8 +17,16 @@
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+#[cfg(feature = "async")]
+#[macro_use]
+extern crate async_trait;
+
 pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group, Scalar};
 
+/// The secret keys of an account implement `Zeroize`. As they are `Copy`, a long-lived key
+/// should be held in `Zeroizing`, which erases it from memory when it is dropped.
+pub use zeroize::{Zeroize, Zeroizing};
+
 mod address;
 
 #[cfg(feature = "compute_key")]
@@ -31,6 +39,11 @@ pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "keystore")]
+pub mod keystore;
+#[cfg(feature = "keystore")]
+pub use keystore::*;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
@@ -41,14 +54,28 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "signer")]
+pub mod signer;
+#[cfg(feature = "signer")]
+pub use signer::*;
+
+#[cfg(feature = "threshold")]
+pub mod threshold;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
 pub use view_key::*;
 
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 #[cfg(test)]
 mod tests {
-    use crate::{Address, ComputeKey, PrivateKey, Signature, ViewKey};
+    use crate::{Address, ComputeKey, PrivateKey, Signature, ViewKey, Zeroize};
     use snarkvm_console_network::{prelude::*, Testnet3};
 
     type CurrentNetwork = Testnet3;
@@ -144,6 +171,18 @@ mod tests {
         assert!(Address::<CurrentNetwork>::from_str("").is_err());
     }
 
+    #[test]
+    fn test_zeroize() {
+        let mut private_key = PrivateKey::<CurrentNetwork>::from_str(ALEO_PRIVATE_KEY).unwrap();
+        let mut view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Ensure the secret keys are erased.
+        private_key.zeroize();
+        view_key.zeroize();
+        assert!(private_key.seed().is_zero() && private_key.sk_sig().is_zero() && private_key.r_sig().is_zero());
+        assert!(view_key.is_zero());
+    }
+
     #[test]
     fn test_sign_bits() {
         let private_key = PrivateKey::<CurrentNetwork>::from_str(ALEO_PRIVATE_KEY).unwrap();
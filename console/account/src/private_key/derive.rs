@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static ACCOUNT_HD_MASTER_DOMAIN: &str = "AleoAccountHDMasterChainCode0";
+static ACCOUNT_HD_CHILD_DOMAIN: &str = "AleoAccountHDChild0";
+
+/// The offset of hardened child indices, as in BIP-32.
+const HARDENED_OFFSET: u32 = 1 << 31;
+/// The maximum number of components in a derivation path, as in BIP-32.
+const MAX_DERIVATION_DEPTH: usize = u8::MAX as usize;
+/// The derivation path of the diversified accounts of a private key, as `m/44'/683'/0'/{index}'`.
+const DIVERSIFIED_PATH: &str = "m/44'/683'/0'";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the private key derived from this private key at the given derivation path, such as `m/44'/683'/0'`.
+    ///
+    /// This follows the SLIP-0010 scheme for hardened keys, with HMAC-SHA512 replaced by a Poseidon hash over
+    /// the account seed and chain code. As the account keys are hashed from the seed, only hardened indices
+    /// (written with `'` or `h`) are supported. The path is always derived from this private key as the master key,
+    /// so `derive("m/0'/1'")` is not equal to `derive("m/0'")?.derive("m/1'")`.
+    pub fn derive(&self, path: &str) -> Result<Self> {
+        // Compute the master chain code.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_HD_MASTER_DOMAIN);
+        let mut chain_code = Zeroizing::new(N::hash_psd2(&[domain, self.seed])?);
+        // Derive the account seed for each index in the path.
+        let mut seed = Zeroizing::new(self.seed);
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_HD_CHILD_DOMAIN);
+        for index in parse_derivation_path(path)? {
+            let children = Zeroizing::new(N::hash_many_psd4(&[domain, *seed, *chain_code, Field::from_u32(index)], 2));
+            match children.as_slice() {
+                [child_seed, child_chain_code] => (*seed, *chain_code) = (*child_seed, *child_chain_code),
+                _ => bail!("Failed to derive the child key at index {index}"),
+            }
+        }
+        // Derive the private key.
+        Self::try_from(*seed)
+    }
+
+    /// Returns the diversified account of this private key at the given index, in `0..2^31`.
+    ///
+    /// Each index derives an unlinkable account, so a user can give every counterparty a distinct address.
+    /// As the owner of a record must be the address that spends it, every diversified address is a full account,
+    /// which is derived from this private key at the path `m/44'/683'/0'/{index}'`. To find the records of all
+    /// diversified addresses, scan with the view key of each diversified account.
+    pub fn diversify(&self, index: u32) -> Result<Self> {
+        self.derive(&format!("{DIVERSIFIED_PATH}/{index}'"))
+    }
+}
+
+/// Returns the hardened child indices in the given derivation path, which is of the form `m/{index}'/...`.
+fn parse_derivation_path(path: &str) -> Result<Vec<u32>> {
+    // Ensure the path starts at the master key.
+    let mut components = path.split('/');
+    ensure!(components.next() == Some("m"), "Derivation path '{path}' must start with 'm'");
+    // Parse the child indices.
+    let indices = components
+        .map(|component| {
+            // Ensure the index is hardened.
+            let Some(index) = component.strip_suffix('\'').or_else(|| component.strip_suffix('h')) else {
+                bail!("Derivation path '{path}' contains a non-hardened index '{component}'")
+            };
+            // Ensure the index is a decimal number below the hardened offset.
+            ensure!(!index.is_empty() && index.chars().all(|c| c.is_ascii_digit()), "Invalid index '{component}'");
+            match index.parse::<u32>() {
+                Ok(index) if index < HARDENED_OFFSET => Ok(index + HARDENED_OFFSET),
+                _ => bail!("Derivation path '{path}' contains an out-of-range index '{component}'"),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // Ensure the path is not too deep.
+    ensure!(indices.len() <= MAX_DERIVATION_DEPTH, "Derivation path '{path}' exceeds the maximum depth");
+    Ok(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+            // Ensure the master path returns the private key.
+            assert_eq!(private_key, private_key.derive("m")?);
+
+            // Ensure derivation is deterministic, and both hardened notations are equivalent.
+            let child = private_key.derive("m/44'/683'/0'")?;
+            assert_eq!(child, private_key.derive("m/44'/683'/0'")?);
+            assert_eq!(child, private_key.derive("m/44h/683h/0h")?);
+
+            // Ensure distinct paths derive distinct private keys.
+            assert_ne!(child, private_key);
+            assert_ne!(child, private_key.derive("m/44'/683'/1'")?);
+            assert_ne!(child, private_key.derive("m/44'/683'")?);
+            assert_ne!(child, private_key.derive("m/44'/683'/0'/0'")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_diversify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+            // Ensure a diversified account is derived at its path, and has a distinct address.
+            let diversified = private_key.diversify(7)?;
+            assert_eq!(diversified, private_key.derive("m/44'/683'/0'/7'")?);
+            assert_eq!(diversified, private_key.diversify(7)?);
+            assert_ne!(Address::try_from(&diversified)?, Address::try_from(&private_key)?);
+            assert_ne!(Address::try_from(&diversified)?, Address::try_from(&private_key.diversify(8)?)?);
+        }
+        // Ensure the index must be below the hardened offset.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        assert!(private_key.diversify(HARDENED_OFFSET - 1).is_ok());
+        assert!(private_key.diversify(HARDENED_OFFSET).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_derivation_path() -> Result<()> {
+        assert_eq!(parse_derivation_path("m")?, Vec::<u32>::new());
+        assert_eq!(parse_derivation_path("m/0'/1h")?, vec![HARDENED_OFFSET, HARDENED_OFFSET + 1]);
+        assert_eq!(parse_derivation_path("m/2147483647'")?, vec![u32::MAX]);
+
+        // Ensure invalid paths fail.
+        assert!(parse_derivation_path("").is_err());
+        assert!(parse_derivation_path("0'/1'").is_err());
+        assert!(parse_derivation_path("m/").is_err());
+        assert!(parse_derivation_path("m/0").is_err());
+        assert!(parse_derivation_path("m/'").is_err());
+        assert!(parse_derivation_path("m/+1'").is_err());
+        assert!(parse_derivation_path("m/2147483648'").is_err());
+        assert!(parse_derivation_path(&format!("m{}", "/0'".repeat(MAX_DERIVATION_DEPTH))).is_ok());
+        assert!(parse_derivation_path(&format!("m{}", "/0'".repeat(MAX_DERIVATION_DEPTH + 1))).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use bip39::{Language, Mnemonic};
+
+/// The supported numbers of words in a mnemonic phrase.
+const MNEMONIC_WORD_COUNTS: [usize; 2] = [12, 24];
+/// The number of words in a mnemonic phrase that encodes the account seed directly.
+const SEED_WORD_COUNT: usize = 24;
+
+impl<N: Network> PrivateKey<N> {
+    /// Samples a new random private key, and returns it with its BIP-39 mnemonic phrase.
+    /// The phrase must contain 12 or 24 words, and is recovered with `PrivateKey::from_mnemonic`.
+    /// Note: A 24-word phrase encodes the account seed itself (see `PrivateKey::to_mnemonic`), and takes no passphrase.
+    pub fn new_with_mnemonic<R: Rng + CryptoRng>(
+        num_words: usize,
+        passphrase: &str,
+        rng: &mut R,
+    ) -> Result<(Self, String)> {
+        // Ensure the number of words is supported.
+        ensure!(MNEMONIC_WORD_COUNTS.contains(&num_words), "A mnemonic must have 12 or 24 words, found {num_words}");
+        // Encode the seed of a new private key, if the phrase has 24 words.
+        if num_words == SEED_WORD_COUNT {
+            ensure!(passphrase.is_empty(), "A {SEED_WORD_COUNT}-word mnemonic does not take a passphrase");
+            let private_key = Self::new(rng)?;
+            let phrase = private_key.to_mnemonic()?;
+            return Ok((private_key, phrase));
+        }
+        // Sample the entropy, as 4 bytes per 3 words.
+        let mut entropy = vec![0u8; num_words / 3 * 4];
+        rng.fill_bytes(&mut entropy);
+        // Construct the mnemonic phrase.
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy);
+        entropy.zeroize();
+        let phrase = mnemonic.map_err(|error| anyhow!("Failed to construct the mnemonic: {error}"))?.to_string();
+        // Derive the private key from the mnemonic phrase.
+        Ok((Self::from_mnemonic(&phrase, passphrase)?, phrase))
+    }
+
+    /// Returns the 24-word BIP-39 mnemonic phrase, whose entropy is the 32-byte account seed.
+    /// The phrase is recovered with `PrivateKey::from_mnemonic`, without a passphrase.
+    pub fn to_mnemonic(&self) -> Result<String> {
+        let mut entropy = self.seed.to_bytes_le()?;
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy);
+        entropy.zeroize();
+        Ok(mnemonic.map_err(|error| anyhow!("Failed to construct the mnemonic: {error}"))?.to_string())
+    }
+
+    /// Returns the private key for the given BIP-39 mnemonic phrase and passphrase.
+    /// The phrase is validated against the English word list and its checksum.
+    ///
+    /// A 12-word phrase derives the account seed from its BIP-39 seed and the passphrase.
+    /// A 24-word phrase encodes the account seed as its entropy, and requires an empty passphrase.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self> {
+        // Parse the phrase, which checks the words and the checksum.
+        let mnemonic = parse_mnemonic(phrase)?;
+        // Decode the account seed from the entropy, if the phrase has 24 words.
+        if mnemonic.word_count() == SEED_WORD_COUNT {
+            ensure!(passphrase.is_empty(), "A {SEED_WORD_COUNT}-word mnemonic does not take a passphrase");
+            let mut entropy = mnemonic.to_entropy();
+            let account_seed = Field::from_bytes_le(&entropy);
+            entropy.zeroize();
+            let account_seed =
+                account_seed.map_err(|_| anyhow!("The mnemonic does not encode a valid account seed"))?;
+            return Self::try_from(account_seed);
+        }
+        // Compute the BIP-39 seed.
+        let mut seed = mnemonic_to_seed(phrase, passphrase)?;
+        // Reduce the seed into an account seed.
+        let account_seed = Field::new(<N as Environment>::Field::from_bytes_le_mod_order(&seed));
+        seed.zeroize();
+        // Derive the private key.
+        Self::try_from(account_seed)
+    }
+}
+
+/// Returns the parsed mnemonic, after checking its words, its checksum, and its number of words.
+fn parse_mnemonic(phrase: &str) -> Result<Mnemonic> {
+    let mnemonic =
+        Mnemonic::parse_in(Language::English, phrase).map_err(|error| anyhow!("Invalid mnemonic: {error}"))?;
+    // Ensure the number of words is supported.
+    let num_words = mnemonic.word_count();
+    ensure!(MNEMONIC_WORD_COUNTS.contains(&num_words), "A mnemonic must have 12 or 24 words, found {num_words}");
+    Ok(mnemonic)
+}
+
+/// Returns the 64-byte BIP-39 seed for the given mnemonic phrase and passphrase.
+fn mnemonic_to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64]> {
+    // Compute the seed as `PBKDF2-HMAC-SHA512(phrase, "mnemonic" || passphrase)`.
+    Ok(parse_mnemonic(phrase)?.to_seed(passphrase))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_mnemonic_to_seed() -> Result<()> {
+        // Check the test vector from the BIP-39 reference implementation.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let expected = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+        let seed = mnemonic_to_seed(phrase, "TREZOR")?;
+        assert_eq!(expected, seed.iter().map(|byte| format!("{byte:02x}")).collect::<String>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_mnemonic() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for (num_words, passphrase) in [(12, "passphrase"), (24, "")] {
+            for _ in 0..ITERATIONS {
+                // Sample a private key with its mnemonic.
+                let (private_key, phrase) =
+                    PrivateKey::<CurrentNetwork>::new_with_mnemonic(num_words, passphrase, rng)?;
+                assert_eq!(num_words, phrase.split_whitespace().count());
+
+                // Ensure the mnemonic recovers the same account.
+                let candidate = PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, passphrase)?;
+                assert_eq!(private_key, candidate);
+                assert_eq!(Address::try_from(&private_key)?, Address::try_from(&candidate)?);
+
+                // Ensure a different passphrase derives a different account, or is rejected.
+                match num_words {
+                    12 => assert_ne!(private_key, PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, "")?),
+                    _ => assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, "passphrase").is_err()),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_mnemonic() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure the mnemonic of a private key recovers the same private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let phrase = private_key.to_mnemonic()?;
+            assert_eq!(SEED_WORD_COUNT, phrase.split_whitespace().count());
+            assert_eq!(private_key, PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, "")?);
+        }
+
+        // Ensure a 24-word phrase whose entropy is not a valid account seed fails.
+        let phrase = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_mnemonic_fails() {
+        // Ensure an invalid checksum fails.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "").is_err());
+        // Ensure an unknown word fails.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon aleo";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "").is_err());
+        // Ensure an unsupported number of words fails.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "").is_err());
+        // Ensure an unsupported number of words fails to sample.
+        assert!(PrivateKey::<CurrentNetwork>::new_with_mnemonic(18, "", &mut TestRng::default()).is_err());
+    }
+}
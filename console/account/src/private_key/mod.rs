@@ -13,17 +13,21 @@
 // limitations under the License.
 
 mod bytes;
+mod derive;
 mod serialize;
 mod string;
 mod try_from;
 
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
+
 #[cfg(feature = "signature")]
 mod sign;
 
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Field, Scalar};
 
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
 pub struct PrivateKey<N: Network> {
@@ -22,7 +22,7 @@ impl<N: Network> FromStr for PrivateKey<N> {
     /// Reads in an account private key from a base58 string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // Encode the string into base58.
-        let data = bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?;
+        let data = Zeroizing::new(bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?);
         if data.len() != 43 {
             bail!("Invalid account private key length: found {}, expected 43", data.len())
         } else if data[0..11] != PRIVATE_KEY_PREFIX {
@@ -37,11 +37,11 @@ impl<N: Network> fmt::Display for PrivateKey<N> {
     /// Writes the account private key as a base58 string.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Write the private key bytes.
-        let mut private_key = [0u8; 43];
+        let mut private_key = Zeroizing::new([0u8; 43]);
         private_key[0..11].copy_from_slice(&PRIVATE_KEY_PREFIX);
         self.seed.write_le(&mut private_key[11..43]).map_err(|_| fmt::Error)?;
         // Encode the private key into base58.
-        write!(f, "{}", bs58::encode(private_key).into_string())
+        write!(f, "{}", Zeroizing::new(bs58::encode(&*private_key).into_string()).as_str())
     }
 }
 
@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The account API for Python, with `pyo3` types for the current network.
+//!
+//! Errors are raised as Python `ValueError` exceptions, and strings use the same encodings as the Rust types.
+
+use snarkvm_console_network::{prelude::*, Testnet3};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use rand::rngs::OsRng;
+use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+
+/// The network of the account API.
+pub type CurrentNetwork = Testnet3;
+
+/// Returns the given error as a Python `ValueError`.
+pub fn to_py_error(error: impl Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// An account private key.
+#[pyclass(frozen)]
+#[derive(Copy, Clone)]
+pub struct PrivateKey(crate::PrivateKey<CurrentNetwork>);
+
+#[pymethods]
+impl PrivateKey {
+    /// Samples a new random private key.
+    #[new]
+    pub fn new() -> PyResult<Self> {
+        crate::PrivateKey::new(&mut OsRng).map(Self).map_err(to_py_error)
+    }
+
+    /// Returns the private key for the given string.
+    #[staticmethod]
+    pub fn from_string(private_key: &str) -> PyResult<Self> {
+        crate::PrivateKey::from_str(private_key).map(Self).map_err(to_py_error)
+    }
+
+    /// Returns the private key as a string.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the view key of the private key.
+    pub fn to_view_key(&self) -> PyResult<ViewKey> {
+        crate::ViewKey::try_from(&self.0).map(ViewKey).map_err(to_py_error)
+    }
+
+    /// Returns the address of the private key.
+    pub fn to_address(&self) -> PyResult<Address> {
+        crate::Address::try_from(&self.0).map(Address).map_err(to_py_error)
+    }
+
+    /// Returns a signature for the given message (as bytes).
+    pub fn sign(&self, message: &[u8]) -> PyResult<Signature> {
+        self.0.sign_bytes(message, &mut OsRng).map(Signature).map_err(to_py_error)
+    }
+}
+
+impl Deref for PrivateKey {
+    type Target = crate::PrivateKey<CurrentNetwork>;
+
+    /// Returns the private key.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An account view key.
+#[pyclass(frozen)]
+#[derive(Copy, Clone)]
+pub struct ViewKey(crate::ViewKey<CurrentNetwork>);
+
+#[pymethods]
+impl ViewKey {
+    /// Returns the view key for the given string.
+    #[staticmethod]
+    pub fn from_string(view_key: &str) -> PyResult<Self> {
+        crate::ViewKey::from_str(view_key).map(Self).map_err(to_py_error)
+    }
+
+    /// Returns the view key as a string.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the address of the view key.
+    pub fn to_address(&self) -> Address {
+        Address(self.0.to_address())
+    }
+}
+
+impl Deref for ViewKey {
+    type Target = crate::ViewKey<CurrentNetwork>;
+
+    /// Returns the view key.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An account address.
+#[pyclass(frozen)]
+#[derive(Copy, Clone)]
+pub struct Address(crate::Address<CurrentNetwork>);
+
+#[pymethods]
+impl Address {
+    /// Returns the address for the given string.
+    #[staticmethod]
+    pub fn from_string(address: &str) -> PyResult<Self> {
+        crate::Address::from_str(address).map(Self).map_err(to_py_error)
+    }
+
+    /// Returns the address as a string.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the addresses are equal.
+    pub fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    /// Returns the hash of the address.
+    pub fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Deref for Address {
+    type Target = crate::Address<CurrentNetwork>;
+
+    /// Returns the address.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An account signature.
+#[pyclass(frozen)]
+#[derive(Copy, Clone)]
+pub struct Signature(crate::Signature<CurrentNetwork>);
+
+#[pymethods]
+impl Signature {
+    /// Returns the signature for the given string.
+    #[staticmethod]
+    pub fn from_string(signature: &str) -> PyResult<Self> {
+        crate::Signature::from_str(signature).map(Self).map_err(to_py_error)
+    }
+
+    /// Returns the signature as a string.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the signature is valid for the given address and message (as bytes).
+    pub fn verify(&self, address: &Address, message: &[u8]) -> bool {
+        self.0.verify_bytes(address, message)
+    }
+}
+
+impl Deref for Signature {
+    type Target = crate::Signature<CurrentNetwork>;
+
+    /// Returns the signature.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Adds the account classes to the given Python module.
+pub fn register(module: &PyModule) -> PyResult<()> {
+    module.add_class::<PrivateKey>()?;
+    module.add_class::<ViewKey>()?;
+    module.add_class::<Address>()?;
+    module.add_class::<Signature>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    #[test]
+    fn test_account() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        assert_eq!(ALEO_PRIVATE_KEY, private_key.__str__());
+        let view_key = private_key.to_view_key().unwrap();
+        assert_eq!(ALEO_VIEW_KEY, view_key.__str__());
+        assert_eq!(ALEO_ADDRESS, view_key.to_address().__str__());
+        assert!(private_key.to_address().unwrap().__eq__(&view_key.to_address()));
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let private_key = PrivateKey::new().unwrap();
+        let address = private_key.to_address().unwrap();
+
+        let signature = private_key.sign(b"hello world!").unwrap();
+        let signature = Signature::from_string(&signature.__str__()).unwrap();
+        assert!(signature.verify(&address, b"hello world!"));
+        assert!(!signature.verify(&address, b"hello world"));
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The domain separator of an off-chain message signature.
+const MESSAGE_DOMAIN: &str = "AleoSignedMessage0";
+
+impl<N: Network> Signature<N> {
+    /// Returns a signature for the given off-chain message (as bytes), under the given application domain.
+    ///
+    /// The signed message is the three field elements `(MESSAGE_DOMAIN, Hash(domain), Hash(message))`, where each
+    /// hash is `HashPSD8(length, bytes)` with the bytes packed into field elements. A transition signature signs
+    /// `(tvk, tcm, function_id, inputs...)`, whose first element is never the `MESSAGE_DOMAIN` constant, so an
+    /// off-chain message signature can never be replayed as a transition signature, nor across domains.
+    #[cfg(feature = "private_key")]
+    pub fn sign_message<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        domain: &str,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign(private_key, &Self::message_to_fields(domain, message)?, rng)
+    }
+
+    /// Verifies a signature for the given address and off-chain message (as bytes), under the given application domain.
+    pub fn verify_message(&self, address: &Address<N>, domain: &str, message: &[u8]) -> bool {
+        match Self::message_to_fields(domain, message) {
+            Ok(fields) => self.verify(address, &fields),
+            Err(error) => {
+                eprintln!("Failed to verify signature: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns the field elements that are signed for the given off-chain message, under the given application domain.
+    fn message_to_fields(domain: &str, message: &[u8]) -> Result<[Field<N>; 3]> {
+        // Ensure the domain is not empty.
+        ensure!(!domain.is_empty(), "The domain of a signed message must not be empty");
+        Ok([
+            Field::new_domain_separator(MESSAGE_DOMAIN),
+            hash_bytes::<N>(domain.as_bytes())?,
+            hash_bytes::<N>(message)?,
+        ])
+    }
+}
+
+/// Returns `HashPSD8(length, bytes)`, with the bytes packed into field elements.
+fn hash_bytes<N: Network>(bytes: &[u8]) -> Result<Field<N>> {
+    // Prefix the length, as packing discards trailing zero bits.
+    let mut preimage = vec![Field::from_u64(bytes.len() as u64)];
+    for chunk in bytes.to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+        preimage.push(Field::from_bits_le(chunk)?);
+    }
+    N::hash_psd8(&preimage)
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_and_verify_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the message and domain.
+            let message: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = private_key.sign_message("example.com", &message, rng)?;
+            assert!(signature.verify_message(&address, "example.com", &message));
+
+            // Check that the signature is invalid for another domain, message, or address.
+            assert!(!signature.verify_message(&address, "example.org", &message));
+            assert!(!signature.verify_message(&address, "example.com", &[message.as_slice(), &[0]].concat()));
+            let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!signature.verify_message(&other_address, "example.com", &message));
+
+            // Check that the signature is not valid as a signature of the raw message.
+            assert!(!signature.verify_bytes(&address, &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_domain_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        assert!(private_key.sign_message("", b"message", rng).is_err());
+        let signature = private_key.sign_message("example.com", b"message", rng)?;
+        assert!(!signature.verify_message(&Address::try_from(&private_key)?, "", b"message"));
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 mod bitwise;
 mod bytes;
 mod from_bits;
+mod message;
 mod parse;
 mod serialize;
 mod size_in_bits;
@@ -14,6 +14,8 @@
 
 use super::*;
 
+use zeroize::Zeroizing;
+
 impl<N: Network> Signature<N> {
     /// Returns a signature `(challenge, response, compute_key)` for a given message and RNG, where:
     ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
@@ -25,7 +27,7 @@ impl<N: Network> Signature<N> {
         }
 
         // Sample a random nonce from the scalar field.
-        let nonce = Scalar::rand(rng);
+        let nonce = Zeroizing::new(Scalar::rand(rng));
         // Compute `g_r` as `nonce * G`.
         let g_r = N::g_scalar_multiply(&nonce);
 
@@ -47,7 +49,7 @@ impl<N: Network> Signature<N> {
         // Compute the verifier challenge.
         let challenge = N::hash_to_scalar_psd8(&preimage)?;
         // Compute the prover response.
-        let response = nonce - (challenge * private_key.sk_sig());
+        let response = *nonce - (challenge * private_key.sk_sig());
 
         // Output the signature.
         Ok(Self { challenge, response, compute_key })
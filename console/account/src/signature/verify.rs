@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use super::*;
+use snarkvm_algorithms::msm::VariableBase;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
 impl<N: Network> Signature<N> {
     /// Verifies (challenge == challenge') && (address == address') where:
@@ -57,6 +61,76 @@ impl<N: Network> Signature<N> {
         self.challenge == candidate_challenge && *address == candidate_address
     }
 
+    /// Verifies a batch of signatures, returning `true` if every signature is valid for its address and message.
+    ///
+    /// The address checks `address == pk_sig + pr_sig + G^sk_prf` of all signatures are combined into one
+    /// random linear combination with 128-bit coefficients, which is checked with one multi-scalar multiplication.
+    /// As the challenge is a hash of `g_r`, which is not part of the signature, `g_r` is still computed for each
+    /// signature to check its challenge.
+    pub fn verify_batch<R: Rng + CryptoRng>(batch: &[(Address<N>, &[Field<N>], Signature<N>)], rng: &mut R) -> bool {
+        // Ensure the number of field elements in each message does not exceed the maximum allowed size.
+        if batch.iter().any(|(_, message, _)| message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize) {
+            eprintln!("Cannot verify the signatures: a signed message exceeds maximum allowed size");
+            return false;
+        }
+
+        // Compute `g_r` := (response * G) + (challenge * pk_sig) for every signature.
+        let g_rs = cfg_iter!(batch)
+            .map(|(_, _, signature)| {
+                *(N::g_scalar_multiply(&signature.response) + (signature.compute_key.pk_sig() * signature.challenge))
+            })
+            .collect::<Vec<_>>();
+        // Convert the `g_r` values to affine coordinates, with one inversion.
+        let g_rs = N::Projective::batch_normalization_into_affine(g_rs);
+
+        // Ensure the challenge of every signature is correct.
+        let is_challenge_valid = cfg_iter!(batch).zip_eq(&g_rs).all(|((address, message, signature), g_r)| {
+            // Retrieve pk_sig and pr_sig.
+            let (pk_sig, pr_sig) = (signature.compute_key.pk_sig(), signature.compute_key.pr_sig());
+
+            // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+            let mut preimage = Vec::with_capacity(4 + message.len());
+            preimage.push(Field::new(g_r.to_x_coordinate()));
+            preimage.extend([pk_sig, pr_sig, **address].map(|point| point.to_x_coordinate()));
+            preimage.extend(message.iter());
+
+            // Hash to derive the verifier challenge, and return `false` if this operation fails.
+            match N::hash_to_scalar_psd8(&preimage) {
+                Ok(candidate_challenge) => signature.challenge == candidate_challenge,
+                Err(_) => false,
+            }
+        });
+        if !is_challenge_valid {
+            return false;
+        }
+
+        // Sample a random 128-bit coefficient for every signature.
+        let coefficients =
+            (0..batch.len()).map(|_| Scalar::<N>::new(N::Scalar::from(rng.gen::<u128>()))).collect::<Vec<_>>();
+
+        // Compute `address - pk_sig - pr_sig` for every signature, followed by `G`, in affine coordinates.
+        let mut bases = cfg_iter!(batch)
+            .map(|(address, _, signature)| {
+                *(**address - signature.compute_key.pk_sig() - signature.compute_key.pr_sig())
+            })
+            .collect::<Vec<_>>();
+        bases.push(*N::g_powers()[0]);
+        let bases = N::Projective::batch_normalization_into_affine(bases);
+
+        // Compute `-sum(coefficient * sk_prf)`, as the scalar for the generator `G`.
+        let sk_prf: Scalar<N> = batch
+            .iter()
+            .zip_eq(&coefficients)
+            .map(|((_, _, signature), coefficient)| signature.compute_key.sk_prf() * coefficient)
+            .sum();
+        let scalars = coefficients.iter().chain([-sk_prf].iter()).map(|scalar| scalar.to_bigint()).collect::<Vec<_>>();
+
+        // Ensure `sum(coefficient * (address - pk_sig - pr_sig)) - G^sum(coefficient * sk_prf)` is zero.
+        // Note: As addresses and compute keys are in the prime-order subgroup, this check passes with probability
+        // at most 2^-128 if any address does not correspond to its compute key.
+        VariableBase::msm(&bases, &scalars).is_zero()
+    }
+
     /// Verifies a signature for the given address and message (as bytes).
     pub fn verify_bytes(&self, address: &Address<N>, message: &[u8]) -> bool {
         // Convert the message into bits, and verify the signature.
@@ -109,6 +183,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_verify_batch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sign a batch of messages.
+        let mut batch = Vec::new();
+        for i in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+            let message: Vec<Field<CurrentNetwork>> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, rng)?;
+            batch.push((address, message, signature));
+        }
+        let to_batch =
+            |batch: &[(Address<CurrentNetwork>, Vec<Field<CurrentNetwork>>, Signature<CurrentNetwork>)]| {
+                batch
+                    .iter()
+                    .map(|(address, message, signature)| (*address, message.as_slice(), *signature))
+                    .collect_vec()
+            };
+
+        // Check that the batch is valid.
+        assert!(Signature::verify_batch(&to_batch(&batch), rng));
+        assert!(Signature::<CurrentNetwork>::verify_batch(&[], rng));
+
+        // Check that the batch is invalid for an incorrect message.
+        let mut failure_batch = batch.clone();
+        failure_batch[3].1.push(Uniform::rand(rng));
+        assert!(!Signature::verify_batch(&to_batch(&failure_batch), rng));
+
+        // Check that the batch is invalid for an incorrect address.
+        let mut failure_batch = batch.clone();
+        failure_batch[5].0 = failure_batch[6].0;
+        assert!(!Signature::verify_batch(&to_batch(&failure_batch), rng));
+
+        // Check that the batch is invalid for a signature with a mismatched compute key.
+        let mut failure_batch = batch;
+        let (challenge, response) = (failure_batch[7].2.challenge(), failure_batch[7].2.response());
+        failure_batch[7].2 = Signature::from((challenge, response, failure_batch[8].2.compute_key()));
+        assert!(!Signature::verify_batch(&to_batch(&failure_batch), rng));
+        Ok(())
+    }
+
     #[test]
     fn test_sign_and_verify_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
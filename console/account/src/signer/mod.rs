@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod private_key;
+
+use crate::{ComputeKey, GraphKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// A signer holds the signature secret key `sk_sig` of an account, and signs requests without exporting it.
+///
+/// A request is signed in two rounds. First, the signer commits to a transition secret key `r` for a nonce,
+/// and computes the record values that depend on `r` and `sk_sig`. Then, the signer responds to the request
+/// message with `r - HashToScalar(message) * sk_sig`. An external signer, such as a hardware wallet,
+/// must only respond once for each commitment, as two responses for the same `r` reveal `sk_sig`.
+#[cfg_attr(feature = "async", async_trait(?Send))]
+pub trait Signer<N: Network> {
+    /// Returns the compute key of the signer.
+    fn compute_key(&self) -> Result<ComputeKey<N>>;
+
+    /// Returns the compute key of the signer.
+    #[cfg(feature = "async")]
+    async fn compute_key_async(&self) -> Result<ComputeKey<N>>;
+
+    /// Returns the graph key of the signer.
+    fn graph_key(&self) -> Result<GraphKey<N>>;
+
+    /// Returns the graph key of the signer.
+    #[cfg(feature = "async")]
+    async fn graph_key_async(&self) -> Result<GraphKey<N>>;
+
+    /// Returns the commitment to the transition secret key for the given nonce and record generators `H`.
+    fn commit(&self, nonce: Field<N>, generators: &[Group<N>]) -> Result<SignerCommitment<N>>;
+
+    /// Returns the commitment to the transition secret key for the given nonce and record generators `H`.
+    #[cfg(feature = "async")]
+    async fn commit_async(&self, nonce: Field<N>, generators: &[Group<N>]) -> Result<SignerCommitment<N>>;
+
+    /// Returns the signature response `r - HashToScalar(message) * sk_sig`, for the given commitment and message.
+    fn respond(&self, commitment: &SignerCommitment<N>, message: &[Field<N>]) -> Result<Scalar<N>>;
+
+    /// Returns the signature response `r - HashToScalar(message) * sk_sig`, for the given commitment and message.
+    #[cfg(feature = "async")]
+    async fn respond_async(&self, commitment: &SignerCommitment<N>, message: &[Field<N>]) -> Result<Scalar<N>>;
+}
+
+/// The commitment of a signer to the transition secret key `r` of a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignerCommitment<N: Network> {
+    /// The nonce, from which the signer derives `r`.
+    nonce: Field<N>,
+    /// The transition public key `tpk` := r * G.
+    tpk: Group<N>,
+    /// The transition view key `tvk` := (r * signer).x.
+    tvk: Field<N>,
+    /// The pairs `(r * H, gamma)` for each record generator `H`, where `gamma` := sk_sig * H.
+    records: Vec<(Group<N>, Group<N>)>,
+}
+
+impl<N: Network> SignerCommitment<N> {
+    /// Initializes a new signer commitment.
+    pub const fn new(nonce: Field<N>, tpk: Group<N>, tvk: Field<N>, records: Vec<(Group<N>, Group<N>)>) -> Self {
+        Self { nonce, tpk, tvk, records }
+    }
+
+    /// Returns the nonce.
+    pub const fn nonce(&self) -> Field<N> {
+        self.nonce
+    }
+
+    /// Returns the transition public key `tpk`.
+    pub const fn tpk(&self) -> Group<N> {
+        self.tpk
+    }
+
+    /// Returns the transition view key `tvk`.
+    pub const fn tvk(&self) -> Field<N> {
+        self.tvk
+    }
+
+    /// Returns the pairs `(r * H, gamma)` for each record generator `H`.
+    pub fn records(&self) -> &[(Group<N>, Group<N>)] {
+        &self.records
+    }
+}
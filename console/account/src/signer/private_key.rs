@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Address, PrivateKey, ViewKey};
+
+use zeroize::Zeroizing;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the transition secret key `r` := HashToScalar(sk_sig || nonce).
+    fn transition_secret_key(&self, nonce: Field<N>) -> Result<Zeroizing<Scalar<N>>> {
+        Ok(Zeroizing::new(N::hash_to_scalar_psd4(&[N::serial_number_domain(), self.sk_sig().to_field()?, nonce])?))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> Signer<N> for PrivateKey<N> {
+    /// Returns the compute key of the signer.
+    fn compute_key(&self) -> Result<ComputeKey<N>> {
+        ComputeKey::try_from(self)
+    }
+
+    /// Returns the compute key of the signer.
+    #[cfg(feature = "async")]
+    async fn compute_key_async(&self) -> Result<ComputeKey<N>> {
+        self.compute_key()
+    }
+
+    /// Returns the graph key of the signer.
+    fn graph_key(&self) -> Result<GraphKey<N>> {
+        GraphKey::try_from(ViewKey::try_from(self)?)
+    }
+
+    /// Returns the graph key of the signer.
+    #[cfg(feature = "async")]
+    async fn graph_key_async(&self) -> Result<GraphKey<N>> {
+        self.graph_key()
+    }
+
+    /// Returns the commitment to the transition secret key for the given nonce and record generators `H`.
+    fn commit(&self, nonce: Field<N>, generators: &[Group<N>]) -> Result<SignerCommitment<N>> {
+        // Compute the transition secret key `r`.
+        let r = self.transition_secret_key(nonce)?;
+        // Compute the transition public key `tpk` as `r * G`.
+        let tpk = N::g_scalar_multiply(&r);
+        // Compute the transition view key `tvk` as `(r * signer).x`.
+        let signer = Address::try_from(self)?;
+        let tvk = (*signer * *r).to_x_coordinate();
+        // Compute `(r * H, sk_sig * H)` for each record generator `H`.
+        let records = generators.iter().map(|h| (*h * *r, *h * self.sk_sig())).collect();
+        Ok(SignerCommitment::new(nonce, tpk, tvk, records))
+    }
+
+    /// Returns the commitment to the transition secret key for the given nonce and record generators `H`.
+    #[cfg(feature = "async")]
+    async fn commit_async(&self, nonce: Field<N>, generators: &[Group<N>]) -> Result<SignerCommitment<N>> {
+        self.commit(nonce, generators)
+    }
+
+    /// Returns the signature response `r - HashToScalar(message) * sk_sig`, for the given commitment and message.
+    fn respond(&self, commitment: &SignerCommitment<N>, message: &[Field<N>]) -> Result<Scalar<N>> {
+        // Compute the transition secret key `r`.
+        let r = self.transition_secret_key(commitment.nonce())?;
+        // Compute the challenge.
+        let challenge = N::hash_to_scalar_psd8(message)?;
+        // Compute the response.
+        Ok(*r - challenge * self.sk_sig())
+    }
+
+    /// Returns the signature response `r - HashToScalar(message) * sk_sig`, for the given commitment and message.
+    #[cfg(feature = "async")]
+    async fn respond_async(&self, commitment: &SignerCommitment<N>, message: &[Field<N>]) -> Result<Scalar<N>> {
+        self.respond(commitment, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_commit_and_respond() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = private_key.compute_key()?;
+            let view_key = ViewKey::try_from(&private_key)?;
+
+            // Commit to a transition secret key, with one record generator.
+            let nonce = Uniform::rand(rng);
+            let h = Uniform::rand(rng);
+            let commitment = private_key.commit(nonce, &[h])?;
+            assert_eq!(commitment, private_key.commit(nonce, &[h])?);
+            let [(h_r, gamma)] = commitment.records() else { panic!("Expected one record commitment") };
+            assert_eq!(*gamma, h * private_key.sk_sig());
+
+            // Ensure the response satisfies the Schnorr verification equations.
+            let message: Vec<_> = (0..4).map(|_| Uniform::rand(rng)).collect();
+            let response = private_key.respond(&commitment, &message)?;
+            let challenge = CurrentNetwork::hash_to_scalar_psd8(&message)?;
+            assert_eq!(
+                commitment.tpk(),
+                CurrentNetwork::g_scalar_multiply(&response) + compute_key.pk_sig() * challenge
+            );
+            assert_eq!(*h_r, h * response + *gamma * challenge);
+            assert_eq!(commitment.tvk(), (commitment.tpk() * *view_key).to_x_coordinate());
+        }
+        Ok(())
+    }
+}
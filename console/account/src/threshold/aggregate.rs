@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::threshold::sign::SigningPackage;
+
+impl<N: Network> ThresholdPublicKey<N> {
+    /// Ensures the signature share is valid for the given signing commitments and message, where:
+    ///     response_i * G == D_i + (binding_factor_i * E_i) - (challenge * lagrange_coefficient_i * verifying_share_i)
+    pub fn verify_share(
+        &self,
+        share: &SignatureShare<N>,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+    ) -> Result<()> {
+        let package = SigningPackage::new(self, commitments, message)?;
+        self.verify_share_internal(&package, share, commitments)
+    }
+
+    /// Returns the account signature for the given message, from the signature shares of every signer.
+    pub fn aggregate(
+        &self,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+        shares: &[SignatureShare<N>],
+    ) -> Result<Signature<N>> {
+        let package = SigningPackage::new(self, commitments, message)?;
+
+        // Ensure there is exactly one share from every signer.
+        ensure!(
+            shares.len() == commitments.len(),
+            "Expected {} signature shares, found {}",
+            commitments.len(),
+            shares.len()
+        );
+        let signers = shares.iter().map(|share| share.identifier()).collect::<BTreeSet<_>>();
+        ensure!(
+            signers.len() == shares.len() && signers.iter().all(|signer| package.binding_factors.contains_key(signer)),
+            "The signature shares do not match the signers"
+        );
+
+        // Verify every share, so that an invalid share identifies the signer who sent it.
+        for share in shares {
+            self.verify_share_internal(&package, share, commitments)?;
+        }
+
+        // Aggregate the response shares into the signature.
+        let response: Scalar<N> = shares.iter().map(|share| share.response).sum();
+        let signature = Signature::from((package.challenge, response, self.compute_key));
+        ensure!(signature.verify(&self.to_address(), message), "The aggregated signature is invalid");
+        Ok(signature)
+    }
+
+    /// Ensures the signature share is valid for the given signing session.
+    fn verify_share_internal(
+        &self,
+        package: &SigningPackage<N>,
+        share: &SignatureShare<N>,
+        commitments: &[SigningCommitments<N>],
+    ) -> Result<()> {
+        let identifier = share.identifier();
+        let (Some(verifying_share), Some(commitments)) = (
+            self.verifying_share(identifier),
+            commitments.iter().find(|commitments| commitments.identifier() == identifier),
+        ) else {
+            bail!("Participant {identifier} is not a signer")
+        };
+
+        // Compute the expected commitment of the signer.
+        let binding_factor = package.binding_factor(identifier)?;
+        let lagrange_coefficient = package.lagrange_coefficient(identifier)?;
+        let expected = commitments.hiding + commitments.binding * binding_factor
+            - verifying_share * (package.challenge * lagrange_coefficient);
+
+        ensure!(
+            N::g_scalar_multiply(&share.response) == expected,
+            "Participant {identifier} sent an invalid signature share"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::test_helpers::sample_key_shares;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_sign_and_aggregate() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (2, 3), (3, 5)] {
+            let key_shares = sample_key_shares(threshold, num_participants, &mut rng)?;
+            let public_key = key_shares[0].public_key();
+            let address = public_key.to_address();
+
+            for i in 0..ITERATIONS {
+                let message = (0..i).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+                // Select a rotating set of `threshold` signers.
+                let signers = (0..threshold as usize)
+                    .map(|j| &key_shares[(i + j) % num_participants as usize])
+                    .collect::<Vec<_>>();
+
+                // Round 1: Every signer commits to its nonces.
+                let (nonces, commitments): (Vec<_>, Vec<_>) =
+                    signers.iter().map(|signer| signer.commit(&mut rng)).unzip();
+
+                // Round 2: Every signer computes its signature share.
+                let shares = signers
+                    .iter()
+                    .zip_eq(nonces)
+                    .map(|(signer, nonces)| signer.sign(nonces, &commitments, &message))
+                    .collect::<Result<Vec<_>>>()?;
+                for share in &shares {
+                    public_key.verify_share(share, &commitments, &message)?;
+                }
+
+                // Ensure the aggregated signature is a valid account signature.
+                let signature = public_key.aggregate(&commitments, &message, &shares)?;
+                assert!(signature.verify(&address, &message));
+                assert_eq!(signature.to_address(), address);
+
+                // Ensure an invalid share is rejected.
+                let mut invalid_shares = shares.clone();
+                invalid_shares[0].response += Scalar::one();
+                assert!(public_key.verify_share(&invalid_shares[0], &commitments, &message).is_err());
+                assert!(public_key.aggregate(&commitments, &message, &invalid_shares).is_err());
+
+                // Ensure a share is rejected for a different message.
+                let mut other_message = message.clone();
+                other_message.push(Uniform::rand(&mut rng));
+                assert!(public_key.verify_share(&shares[0], &commitments, &other_message).is_err());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_with_shuffled_commitments() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let key_shares = sample_key_shares(3, 5, &mut rng)?;
+        let public_key = key_shares[0].public_key();
+        let message = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+        // Round 1: Every signer commits to its nonces.
+        let signers = &key_shares[1..4];
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|signer| signer.commit(&mut rng)).unzip();
+
+        // Round 2: Every signer receives the commitments in a different order.
+        let shares = signers
+            .iter()
+            .zip_eq(nonces)
+            .enumerate()
+            .map(|(i, (signer, nonces))| {
+                let mut shuffled = commitments.clone();
+                shuffled.rotate_left(i);
+                signer.sign(nonces, &shuffled, &message)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Ensure the shares aggregate into a valid signature, with the commitments in yet another order.
+        let mut reversed = commitments;
+        reversed.reverse();
+        for share in &shares {
+            public_key.verify_share(share, &reversed, &message)?;
+        }
+        let signature = public_key.aggregate(&reversed, &message, &shares)?;
+        assert!(signature.verify(&public_key.to_address(), &message));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_requires_threshold() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let key_shares = sample_key_shares(3, 4, &mut rng)?;
+        let message = [Uniform::rand(&mut rng)];
+
+        // Ensure signing fails with fewer than `threshold` signers.
+        let (nonces, commitments): (Vec<_>, Vec<_>) =
+            key_shares[..2].iter().map(|signer| signer.commit(&mut rng)).unzip();
+        let nonces = nonces.into_iter().next().unwrap();
+        assert!(key_shares[0].sign(nonces, &commitments, &message).is_err());
+
+        // Ensure signing fails if the nonces do not match the commitments.
+        let (_, commitments): (Vec<_>, Vec<_>) = key_shares[..3].iter().map(|signer| signer.commit(&mut rng)).unzip();
+        let (nonces, _) = key_shares[0].commit(&mut rng);
+        assert!(key_shares[0].sign(nonces, &commitments, &message).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for SigningCommitments<N> {
+    /// Reads the signing commitments from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let identifier = u16::read_le(&mut reader)?;
+        let hiding = Group::read_le(&mut reader)?;
+        let binding = Group::read_le(&mut reader)?;
+        Ok(Self { identifier, hiding, binding })
+    }
+}
+
+impl<N: Network> ToBytes for SigningCommitments<N> {
+    /// Writes the signing commitments to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.identifier.write_le(&mut writer)?;
+        self.hiding.write_le(&mut writer)?;
+        self.binding.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for SignatureShare<N> {
+    /// Reads the signature share from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let identifier = u16::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { identifier, response })
+    }
+}
+
+impl<N: Network> ToBytes for SignatureShare<N> {
+    /// Writes the signature share to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.identifier.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::test_helpers::sample_key_shares;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let key_shares = sample_key_shares(2, 3, &mut rng)?;
+        let message = [Uniform::rand(&mut rng)];
+
+        // Check the byte representation of the signing commitments.
+        let (nonces, commitments): (Vec<_>, Vec<_>) =
+            key_shares[..2].iter().map(|signer| signer.commit(&mut rng)).unzip();
+        for expected in &commitments {
+            let bytes = expected.to_bytes_le()?;
+            assert_eq!(*expected, SigningCommitments::read_le(&bytes[..])?);
+            assert!(SigningCommitments::<CurrentNetwork>::read_le(&bytes[1..]).is_err());
+        }
+
+        // Check the byte representation of the signature shares.
+        for (signer, nonces) in key_shares.iter().zip(nonces) {
+            let expected = signer.sign(nonces, &commitments, &message)?;
+            let bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, SignatureShare::read_le(&bytes[..])?);
+            assert!(SignatureShare::<CurrentNetwork>::read_le(&bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,359 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The domain of the proof of knowledge of the constant coefficient of the signing polynomial.
+const SIGNING_DOMAIN: &str = "AleoThresholdKeyGen0";
+/// The domain of the proof of knowledge of the constant coefficient of the randomizer polynomial.
+const RANDOMIZER_DOMAIN: &str = "AleoThresholdKeyGen1";
+
+/// The message that a participant broadcasts in the first round of key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyGenRound1<N: Network> {
+    /// The identifier of the participant.
+    identifier: u16,
+    /// The commitments `G^a_j` to the coefficients of the polynomial of the participant.
+    commitments: Vec<Group<N>>,
+    /// The commitments `G^b_j` to the coefficients of the randomizer polynomial of the participant.
+    randomizer_commitments: Vec<Group<N>>,
+    /// The proof of knowledge `(challenge, response)` of the constant coefficient of the polynomial.
+    proof: (Scalar<N>, Scalar<N>),
+    /// The proof of knowledge `(challenge, response)` of the constant coefficient of the randomizer polynomial.
+    randomizer_proof: (Scalar<N>, Scalar<N>),
+}
+
+impl<N: Network> KeyGenRound1<N> {
+    /// Returns the identifier of the participant.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the challenge of the proof of knowledge, for the given domain.
+    fn challenge(domain: &str, identifier: u16, commitment: &Group<N>, g_r: &Group<N>) -> Result<Scalar<N>> {
+        N::hash_to_scalar_psd4(&[
+            Field::new_domain_separator(domain),
+            Field::from_u16(identifier),
+            commitment.to_x_coordinate(),
+            g_r.to_x_coordinate(),
+        ])
+    }
+
+    /// Returns a proof of knowledge of the given secret, for the given domain.
+    fn prove<R: Rng + CryptoRng>(
+        domain: &str,
+        identifier: u16,
+        secret: &Scalar<N>,
+        rng: &mut R,
+    ) -> Result<(Scalar<N>, Scalar<N>)> {
+        let nonce = Zeroizing::new(Scalar::rand(rng));
+        let commitment = N::g_scalar_multiply(secret);
+        let challenge = Self::challenge(domain, identifier, &commitment, &N::g_scalar_multiply(&nonce))?;
+        Ok((challenge, *nonce + challenge * *secret))
+    }
+
+    /// Returns `true` if the given proof of knowledge of the discrete logarithm of the commitment is valid.
+    fn verify_proof(
+        domain: &str,
+        identifier: u16,
+        commitment: &Group<N>,
+        (challenge, response): (Scalar<N>, Scalar<N>),
+    ) -> Result<bool> {
+        let g_r = N::g_scalar_multiply(&response) - *commitment * challenge;
+        Ok(challenge == Self::challenge(domain, identifier, commitment, &g_r)?)
+    }
+
+    /// Ensures the message is well-formed for the given threshold and number of participants.
+    fn check(&self, threshold: u16, num_participants: u16) -> Result<()> {
+        ensure!((1..=num_participants).contains(&self.identifier), "Invalid participant {}", self.identifier);
+        ensure!(
+            self.commitments.len() == threshold as usize && self.randomizer_commitments.len() == threshold as usize,
+            "Participant {} committed to the wrong number of coefficients, expected {threshold}",
+            self.identifier,
+        );
+        // Verify the proofs of knowledge of the constant coefficients, which prevent rogue-key attacks.
+        ensure!(
+            Self::verify_proof(SIGNING_DOMAIN, self.identifier, &self.commitments[0], self.proof)?
+                && Self::verify_proof(
+                    RANDOMIZER_DOMAIN,
+                    self.identifier,
+                    &self.randomizer_commitments[0],
+                    self.randomizer_proof
+                )?,
+            "Participant {} has an invalid proof of knowledge",
+            self.identifier
+        );
+        Ok(())
+    }
+}
+
+/// The secret share that a participant privately sends to another participant in the second round of key generation.
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct KeyGenRound2<N: Network> {
+    /// The identifier of the sender.
+    #[zeroize(skip)]
+    sender: u16,
+    /// The identifier of the receiver.
+    #[zeroize(skip)]
+    receiver: u16,
+    /// The evaluation of the polynomial of the sender at the identifier of the receiver.
+    share: Scalar<N>,
+    /// The evaluation of the randomizer polynomial of the sender at the identifier of the receiver.
+    randomizer_share: Scalar<N>,
+}
+
+impl<N: Network> KeyGenRound2<N> {
+    /// Returns the identifier of the sender.
+    pub const fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    /// Returns the identifier of the receiver.
+    pub const fn receiver(&self) -> u16 {
+        self.receiver
+    }
+}
+
+/// The secret state of a participant during the distributed key generation (Pedersen DKG, as in FROST).
+///
+/// Every participant samples two random polynomials of degree `t - 1`, whose constant coefficients sum to `sk_sig`
+/// and `r_sig` respectively, so the participants also hold shares of the view key
+/// (see [`KeyShare::to_view_key_share`]).
+/// The protocol runs as follows:
+///   1. Every participant calls [`KeyGenSecret::new`], and broadcasts the [`KeyGenRound1`] message.
+///   2. Every participant calls [`KeyGenSecret::round2`] on all round-1 messages, and privately sends
+///      each [`KeyGenRound2`] message to its receiver.
+///   3. Every participant calls [`KeyGenSecret::finish`] to verify the received shares, and derive its key share.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct KeyGenSecret<N: Network> {
+    /// The identifier of the participant.
+    identifier: u16,
+    /// The number of participants that are required to sign.
+    threshold: u16,
+    /// The number of participants.
+    num_participants: u16,
+    /// The coefficients of the polynomial of the participant.
+    coefficients: Vec<Scalar<N>>,
+    /// The coefficients of the randomizer polynomial of the participant.
+    randomizer_coefficients: Vec<Scalar<N>>,
+}
+
+impl<N: Network> KeyGenSecret<N> {
+    /// Samples the polynomial of the participant, and returns the secret state and the round-1 message.
+    pub fn new<R: Rng + CryptoRng>(
+        identifier: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(Self, KeyGenRound1<N>)> {
+        ensure!(threshold > 0, "The threshold must be at least 1");
+        ensure!(threshold <= num_participants, "The threshold cannot exceed the number of participants");
+        ensure!((1..=num_participants).contains(&identifier), "The identifier must be in 1..={num_participants}");
+
+        // Sample the coefficients of the polynomials, and commit to them.
+        let coefficients = (0..threshold).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+        let commitments = coefficients.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+        let randomizer_coefficients = (0..threshold).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+        let randomizer_commitments = randomizer_coefficients.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+
+        // Prove knowledge of the constant coefficients.
+        let proof = KeyGenRound1::prove(SIGNING_DOMAIN, identifier, &coefficients[0], rng)?;
+        let randomizer_proof = KeyGenRound1::prove(RANDOMIZER_DOMAIN, identifier, &randomizer_coefficients[0], rng)?;
+
+        let round1 = KeyGenRound1 { identifier, commitments, randomizer_commitments, proof, randomizer_proof };
+        Ok((Self { identifier, threshold, num_participants, coefficients, randomizer_coefficients }, round1))
+    }
+
+    /// Verifies the round-1 messages of all participants, and returns the secret shares for the other participants.
+    pub fn round2(&self, round1: &[KeyGenRound1<N>]) -> Result<Vec<KeyGenRound2<N>>> {
+        self.check_round1(round1)?;
+        Ok((1..=self.num_participants)
+            .filter(|receiver| *receiver != self.identifier)
+            .map(|receiver| KeyGenRound2 {
+                sender: self.identifier,
+                receiver,
+                share: evaluate_polynomial(&self.coefficients, receiver),
+                randomizer_share: evaluate_polynomial(&self.randomizer_coefficients, receiver),
+            })
+            .collect())
+    }
+
+    /// Verifies the secret shares received from the other participants, and returns the key share of the participant.
+    pub fn finish(self, round1: &[KeyGenRound1<N>], round2: &[KeyGenRound2<N>]) -> Result<KeyShare<N>> {
+        self.check_round1(round1)?;
+
+        // Sum the shares from every participant, including the share from the participant itself.
+        let mut signing_share = evaluate_polynomial(&self.coefficients, self.identifier);
+        let mut randomizer_share = evaluate_polynomial(&self.randomizer_coefficients, self.identifier);
+        for message in round1.iter().filter(|message| message.identifier != self.identifier) {
+            // Retrieve the share that the sender sent to this participant.
+            let mut shares =
+                round2.iter().filter(|share| share.sender == message.identifier && share.receiver == self.identifier);
+            let (Some(share), None) = (shares.next(), shares.next()) else {
+                bail!("Expected exactly one share from participant {}", message.identifier)
+            };
+            // Ensure the shares are consistent with the commitments of the sender.
+            ensure!(
+                N::g_scalar_multiply(&share.share) == evaluate_commitments(&message.commitments, self.identifier)
+                    && N::g_scalar_multiply(&share.randomizer_share)
+                        == evaluate_commitments(&message.randomizer_commitments, self.identifier),
+                "Participant {} sent an invalid share",
+                message.identifier
+            );
+            signing_share += share.share;
+            randomizer_share += share.randomizer_share;
+        }
+
+        // Derive the compute key, as `pk_sig` and `pr_sig` are the sums of the constant commitments.
+        let pk_sig: Group<N> = round1.iter().map(|message| message.commitments[0]).sum();
+        let pr_sig: Group<N> = round1.iter().map(|message| message.randomizer_commitments[0]).sum();
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+
+        // Commit to the sharing of the view key `sk_sig + r_sig + sk_prf`, as the sum of the polynomials.
+        let mut view_key_commitments = (0..self.threshold as usize)
+            .map(|j| {
+                round1
+                    .iter()
+                    .map(|message| message.commitments[j] + message.randomizer_commitments[j])
+                    .sum::<Group<N>>()
+            })
+            .collect::<Vec<_>>();
+        view_key_commitments[0] += N::g_scalar_multiply(&compute_key.sk_prf());
+        let view_key_commitments = ViewKeyCommitments::new(view_key_commitments);
+        ensure!(view_key_commitments.to_address() == compute_key.to_address(), "The view key commitments are invalid");
+
+        // Compute the verifying share of every participant.
+        let verifying_shares = (1..=self.num_participants)
+            .map(|identifier| {
+                let share: Group<N> =
+                    round1.iter().map(|message| evaluate_commitments(&message.commitments, identifier)).sum();
+                (identifier, share)
+            })
+            .collect();
+
+        let public_key = ThresholdPublicKey {
+            compute_key,
+            threshold: self.threshold,
+            num_participants: self.num_participants,
+            verifying_shares,
+            view_key_commitments,
+        };
+        Ok(KeyShare { identifier: self.identifier, signing_share, randomizer_share, public_key })
+    }
+
+    /// Ensures there is exactly one valid round-1 message from every participant.
+    fn check_round1(&self, round1: &[KeyGenRound1<N>]) -> Result<()> {
+        ensure!(
+            round1.len() == self.num_participants as usize,
+            "Expected {} round-1 messages, found {}",
+            self.num_participants,
+            round1.len()
+        );
+        for message in round1 {
+            message.check(self.threshold, self.num_participants)?;
+        }
+        // Ensure the identifiers are distinct, and that the message of this participant is unchanged.
+        let identifiers = round1.iter().map(|message| message.identifier).collect::<BTreeSet<_>>();
+        ensure!(identifiers.len() == round1.len(), "Found duplicate participants in the round-1 messages");
+        ensure!(
+            round1.iter().any(|message| message.identifier == self.identifier
+                && message.commitments.first() == Some(&N::g_scalar_multiply(&self.coefficients[0]))),
+            "The round-1 messages do not include the message of participant {}",
+            self.identifier
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::test_helpers::sample_key_shares;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_keygen() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (1, 3), (2, 3), (3, 5), (5, 5)] {
+            let key_shares = sample_key_shares(threshold, num_participants, &mut rng)?;
+
+            // Ensure every participant derived the same public key.
+            let public_key = key_shares[0].public_key();
+            assert!(key_shares.iter().all(|key_share| key_share.public_key() == public_key));
+            assert_eq!(public_key.threshold(), threshold);
+            assert_eq!(public_key.num_participants(), num_participants);
+
+            // Ensure the signing shares match the verifying shares.
+            for key_share in &key_shares {
+                let verifying_share = public_key.verifying_share(key_share.identifier()).unwrap();
+                assert_eq!(CurrentNetwork::g_scalar_multiply(&key_share.signing_share), verifying_share);
+            }
+
+            // Ensure any `threshold` shares interpolate to `sk_sig`.
+            let signers = &key_shares[num_participants as usize - threshold as usize..];
+            let sk_sig: Scalar<CurrentNetwork> = signers
+                .iter()
+                .map(|key_share| {
+                    let signers = signers.iter().map(|key_share| key_share.identifier());
+                    key_share.signing_share * lagrange_coefficient(key_share.identifier(), signers).unwrap()
+                })
+                .sum();
+            assert_eq!(CurrentNetwork::g_scalar_multiply(&sk_sig), public_key.compute_key().pk_sig());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_keygen_rejects_invalid_messages() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (secrets, round1): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|identifier| KeyGenSecret::<CurrentNetwork>::new(identifier, 2, 3, &mut rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        // Ensure an invalid proof of knowledge is rejected.
+        let mut invalid_round1 = round1.clone();
+        invalid_round1[1].commitments[0] = Group::rand(&mut rng);
+        assert!(secrets[0].round2(&invalid_round1).is_err());
+        let mut invalid_round1 = round1.clone();
+        invalid_round1[1].randomizer_commitments[0] = Group::rand(&mut rng);
+        assert!(secrets[0].round2(&invalid_round1).is_err());
+
+        // Ensure a proof of knowledge is not accepted for the other polynomial.
+        let mut invalid_round1 = round1.clone();
+        invalid_round1[1].randomizer_proof = invalid_round1[1].proof;
+        invalid_round1[1].randomizer_commitments[0] = invalid_round1[1].commitments[0];
+        assert!(secrets[0].round2(&invalid_round1).is_err());
+
+        // Ensure a missing participant is rejected.
+        assert!(secrets[0].round2(&round1[1..]).is_err());
+
+        // Ensure an invalid share is rejected.
+        let mut round2 = secrets.iter().map(|secret| secret.round2(&round1)).collect::<Result<Vec<_>>>()?.concat();
+        let index = round2.iter().position(|share| share.sender == 2 && share.receiver == 1).unwrap();
+        round2[index].share += Scalar::one();
+        let index = round2.iter().position(|share| share.sender == 2 && share.receiver == 3).unwrap();
+        round2[index].randomizer_share += Scalar::one();
+        let mut secrets = secrets.into_iter();
+        assert!(secrets.next().unwrap().finish(&round1, &round2).is_err());
+        assert!(secrets.next().unwrap().finish(&round1, &round2).is_ok());
+        assert!(secrets.next().unwrap().finish(&round1, &round2).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod aggregate;
+mod bytes;
+mod keygen;
+mod session;
+mod sign;
+mod view_key;
+
+pub use keygen::{KeyGenRound1, KeyGenRound2, KeyGenSecret};
+pub use session::SigningSession;
+pub use sign::{SignatureShare, SigningCommitments, SigningNonces};
+pub use view_key::{DecryptionShare, ViewKeyCommitments, ViewKeyShare};
+
+use crate::{ComputeKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+use std::collections::{BTreeMap, BTreeSet};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// The secret share of a participant in a threshold account.
+///
+/// A threshold account is an account whose `sk_sig` is shared among `n` participants with Shamir secret sharing,
+/// such that any `t` of them can produce an account signature with the FROST protocol, which verifies with
+/// [`Signature::verify`]. The randomizer `r_sig` is shared in the same way, so no participant learns the view key
+/// of a threshold account, and any `t` of them can decrypt with their shares of it (see [`ViewKeyShare`]).
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct KeyShare<N: Network> {
+    /// The identifier of the participant, in `1..=n`.
+    identifier: u16,
+    /// The share of `sk_sig` of the participant.
+    signing_share: Scalar<N>,
+    /// The share of `r_sig` of the participant.
+    randomizer_share: Scalar<N>,
+    /// The public key of the threshold account.
+    #[zeroize(skip)]
+    public_key: ThresholdPublicKey<N>,
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the identifier of the participant.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the public key of the threshold account.
+    pub const fn public_key(&self) -> &ThresholdPublicKey<N> {
+        &self.public_key
+    }
+}
+
+/// The public key of a threshold account, and the verifying share `G^share` of every participant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdPublicKey<N: Network> {
+    /// The compute key of the account.
+    compute_key: ComputeKey<N>,
+    /// The number of participants that are required to sign.
+    threshold: u16,
+    /// The number of participants.
+    num_participants: u16,
+    /// The verifying share of each participant.
+    verifying_shares: BTreeMap<u16, Group<N>>,
+    /// The commitments to the sharing of the view key.
+    view_key_commitments: ViewKeyCommitments<N>,
+}
+
+impl<N: Network> ThresholdPublicKey<N> {
+    /// Returns the compute key of the account.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the address of the account.
+    pub fn to_address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+
+    /// Returns the number of participants that are required to sign.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the number of participants.
+    pub const fn num_participants(&self) -> u16 {
+        self.num_participants
+    }
+
+    /// Returns the verifying share of the given participant, if it exists.
+    pub fn verifying_share(&self, identifier: u16) -> Option<Group<N>> {
+        self.verifying_shares.get(&identifier).copied()
+    }
+
+    /// Returns the commitments to the sharing of the view key, which verify the decryption shares of the participants.
+    pub const fn view_key_commitments(&self) -> &ViewKeyCommitments<N> {
+        &self.view_key_commitments
+    }
+}
+
+/// Returns the evaluation of the polynomial with the given coefficients at the given identifier.
+fn evaluate_polynomial<N: Network>(coefficients: &[Scalar<N>], identifier: u16) -> Scalar<N> {
+    let x = Scalar::new(N::Scalar::from(identifier as u64));
+    coefficients.iter().rev().fold(Scalar::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+/// Returns the evaluation "in the exponent" of the polynomial with the given coefficient commitments,
+/// at the given identifier.
+fn evaluate_commitments<N: Network>(commitments: &[Group<N>], identifier: u16) -> Group<N> {
+    let x = Scalar::new(N::Scalar::from(identifier as u64));
+    commitments.iter().rev().fold(Group::zero(), |acc, commitment| acc * x + commitment)
+}
+
+/// Returns the Lagrange coefficient at zero of the given identifier, for the given set of signers.
+fn lagrange_coefficient<N: Network>(identifier: u16, signers: impl Iterator<Item = u16>) -> Result<Scalar<N>> {
+    let x_i = Scalar::<N>::new(N::Scalar::from(identifier as u64));
+    let (mut numerator, mut denominator) = (Scalar::<N>::one(), Scalar::<N>::one());
+    for signer in signers.filter(|signer| *signer != identifier) {
+        let x_j = Scalar::new(N::Scalar::from(signer as u64));
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+    Ok(numerator * denominator.inverse()?)
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Runs the distributed key generation, and returns the key share of every participant.
+    pub(crate) fn sample_key_shares(
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut TestRng,
+    ) -> Result<Vec<KeyShare<CurrentNetwork>>> {
+        // Round 1: Every participant broadcasts the commitments to their polynomial.
+        let (secrets, round1): (Vec<_>, Vec<_>) = (1..=num_participants)
+            .map(|identifier| KeyGenSecret::new(identifier, threshold, num_participants, rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        // Round 2: Every participant sends a secret share to every other participant.
+        let round2 = secrets.iter().map(|secret| secret.round2(&round1)).collect::<Result<Vec<_>>>()?.concat();
+        // Every participant derives their key share.
+        secrets.into_iter().map(|secret| secret.finish(&round1, &round2)).collect()
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The state of the coordinator of one signing session for a threshold account.
+///
+/// The coordinator collects the signing commitments of at least `threshold` signers, and sends them with
+/// the message to each of those signers. It then collects and verifies their signature shares, and
+/// aggregates them into the account signature. The coordinator does not hold any secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningSession<N: Network> {
+    /// The public key of the threshold account.
+    public_key: ThresholdPublicKey<N>,
+    /// The message to sign.
+    message: Vec<Field<N>>,
+    /// The signing commitments of each signer.
+    commitments: BTreeMap<u16, SigningCommitments<N>>,
+    /// The signature shares received so far.
+    shares: BTreeMap<u16, SignatureShare<N>>,
+}
+
+impl<N: Network> SigningSession<N> {
+    /// Initializes a new signing session for the given threshold account and message.
+    pub fn new(public_key: ThresholdPublicKey<N>, message: Vec<Field<N>>) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        Ok(Self { public_key, message, commitments: Default::default(), shares: Default::default() })
+    }
+
+    /// Returns the public key of the threshold account.
+    pub const fn public_key(&self) -> &ThresholdPublicKey<N> {
+        &self.public_key
+    }
+
+    /// Returns the message to sign.
+    pub fn message(&self) -> &[Field<N>] {
+        &self.message
+    }
+
+    /// Returns the signing commitments of every signer, in the order that every signer must use.
+    pub fn commitments(&self) -> Vec<SigningCommitments<N>> {
+        self.commitments.values().copied().collect()
+    }
+
+    /// Returns `true` if enough signers have committed for the signers to compute their signature shares.
+    pub fn is_ready_to_sign(&self) -> bool {
+        self.commitments.len() >= self.public_key.threshold() as usize
+    }
+
+    /// Returns the signers that have not yet sent their signature share.
+    pub fn missing_shares(&self) -> Vec<u16> {
+        self.commitments.keys().filter(|signer| !self.shares.contains_key(signer)).copied().collect()
+    }
+
+    /// Adds the signing commitments of a signer to the session.
+    /// The set of signers is fixed once the first signature share is received.
+    pub fn add_commitments(&mut self, commitments: SigningCommitments<N>) -> Result<()> {
+        let identifier = commitments.identifier();
+        ensure!(self.shares.is_empty(), "Cannot add signer {identifier}, as the signers have started signing");
+        ensure!(
+            self.public_key.verifying_share(identifier).is_some(),
+            "Signer {identifier} is not a participant of the threshold account"
+        );
+        ensure!(!self.commitments.contains_key(&identifier), "Signer {identifier} has already committed");
+        self.commitments.insert(identifier, commitments);
+        Ok(())
+    }
+
+    /// Verifies and adds the signature share of a signer to the session.
+    pub fn add_share(&mut self, share: SignatureShare<N>) -> Result<()> {
+        let identifier = share.identifier();
+        ensure!(self.is_ready_to_sign(), "Cannot add a signature share before enough signers have committed");
+        ensure!(self.commitments.contains_key(&identifier), "Signer {identifier} has not committed");
+        ensure!(!self.shares.contains_key(&identifier), "Signer {identifier} has already sent a signature share");
+        // Verify the share, so that an invalid share identifies the signer who sent it.
+        self.public_key.verify_share(&share, &self.commitments(), &self.message)?;
+        self.shares.insert(identifier, share);
+        Ok(())
+    }
+
+    /// Returns the account signature, once every signer has sent a valid signature share.
+    pub fn finish(&self) -> Result<Signature<N>> {
+        let missing_shares = self.missing_shares();
+        ensure!(self.is_ready_to_sign(), "Expected at least {} signers", self.public_key.threshold());
+        ensure!(missing_shares.is_empty(), "Missing the signature shares of signers {missing_shares:?}");
+        let shares = self.shares.values().copied().collect::<Vec<_>>();
+        self.public_key.aggregate(&self.commitments(), &self.message, &shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::test_helpers::sample_key_shares;
+
+    #[test]
+    fn test_signing_session() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let key_shares = sample_key_shares(2, 3, &mut rng)?;
+        let public_key = key_shares[0].public_key().clone();
+        let message = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+        let mut session = SigningSession::new(public_key.clone(), message.clone())?;
+
+        // Round 1: Signers 3 and 1 commit to their nonces.
+        let (nonces3, commitments3) = key_shares[2].commit(&mut rng);
+        let (nonces1, commitments1) = key_shares[0].commit(&mut rng);
+        session.add_commitments(commitments3)?;
+        assert!(!session.is_ready_to_sign());
+        assert!(session.add_commitments(commitments3).is_err());
+        session.add_commitments(commitments1)?;
+        assert!(session.is_ready_to_sign());
+        assert_eq!(session.commitments(), vec![commitments1, commitments3]);
+
+        // Round 2: The signers sign the message with the commitments from the session.
+        let share3 = key_shares[2].sign(nonces3, &session.commitments(), session.message())?;
+        let mut invalid_share = key_shares[0].sign(nonces1, &session.commitments(), session.message())?;
+        let share1 = invalid_share;
+        invalid_share.response += Scalar::one();
+        session.add_share(share3)?;
+        assert!(session.add_share(share3).is_err());
+        assert_eq!(session.missing_shares(), vec![1]);
+        assert!(session.finish().is_err());
+
+        // Ensure the set of signers is fixed, and an invalid share is rejected.
+        let (_, commitments2) = key_shares[1].commit(&mut rng);
+        assert!(session.add_commitments(commitments2).is_err());
+        assert!(session.add_share(invalid_share).is_err());
+
+        // Ensure the session produces a valid account signature.
+        session.add_share(share1)?;
+        let signature = session.finish()?;
+        assert!(signature.verify(&public_key.to_address(), &message));
+        Ok(())
+    }
+}
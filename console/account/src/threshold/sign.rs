@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The secret nonces of a participant for one signing session.
+///
+/// The nonces are consumed when signing, as reusing them for two signature shares reveals the signing share.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SigningNonces<N: Network> {
+    /// The identifier of the participant.
+    identifier: u16,
+    /// The hiding nonce `d`.
+    hiding: Scalar<N>,
+    /// The binding nonce `e`.
+    binding: Scalar<N>,
+}
+
+/// The commitments `(G^d, G^e)` of a participant to its signing nonces, which are sent to the other signers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SigningCommitments<N: Network> {
+    /// The identifier of the participant.
+    pub(super) identifier: u16,
+    /// The commitment to the hiding nonce.
+    pub(super) hiding: Group<N>,
+    /// The commitment to the binding nonce.
+    pub(super) binding: Group<N>,
+}
+
+impl<N: Network> SigningCommitments<N> {
+    /// Returns the identifier of the participant.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+}
+
+/// The signature share of a participant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignatureShare<N: Network> {
+    /// The identifier of the participant.
+    pub(super) identifier: u16,
+    /// The response share of the participant.
+    pub(super) response: Scalar<N>,
+}
+
+impl<N: Network> SignatureShare<N> {
+    /// Returns the identifier of the participant.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Samples the signing nonces of the participant for one signing session,
+    /// and returns them with the commitments to send to the other signers.
+    pub fn commit<R: Rng + CryptoRng>(&self, rng: &mut R) -> (SigningNonces<N>, SigningCommitments<N>) {
+        let (hiding, binding) = (Scalar::rand(rng), Scalar::rand(rng));
+        let commitments = SigningCommitments {
+            identifier: self.identifier,
+            hiding: N::g_scalar_multiply(&hiding),
+            binding: N::g_scalar_multiply(&binding),
+        };
+        (SigningNonces { identifier: self.identifier, hiding, binding }, commitments)
+    }
+
+    /// Returns the signature share of the participant for the given message, where:
+    ///     response_i := d_i + (e_i * binding_factor_i) - (challenge * lagrange_coefficient_i * share_i)
+    /// and `commitments` contains the commitments of every signer in the session.
+    pub fn sign(
+        &self,
+        nonces: SigningNonces<N>,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+    ) -> Result<SignatureShare<N>> {
+        ensure!(nonces.identifier == self.identifier, "The signing nonces belong to another participant");
+
+        // Ensure the commitments of this participant match its nonces.
+        let Some(own_commitments) = commitments.iter().find(|commitments| commitments.identifier == self.identifier)
+        else {
+            bail!("The signing commitments do not include participant {}", self.identifier)
+        };
+        ensure!(
+            own_commitments.hiding == N::g_scalar_multiply(&nonces.hiding)
+                && own_commitments.binding == N::g_scalar_multiply(&nonces.binding),
+            "The signing commitments of participant {} do not match its nonces",
+            self.identifier
+        );
+
+        // Compute the binding factor, challenge, and Lagrange coefficient of this participant.
+        let package = SigningPackage::new(&self.public_key, commitments, message)?;
+        let binding_factor = package.binding_factor(self.identifier)?;
+        let lagrange_coefficient = package.lagrange_coefficient(self.identifier)?;
+
+        // Compute the response share.
+        let response = nonces.hiding + (nonces.binding * binding_factor)
+            - (package.challenge * lagrange_coefficient * self.signing_share);
+        Ok(SignatureShare { identifier: self.identifier, response })
+    }
+}
+
+/// The public state of a signing session, which is derived by every signer and by the aggregator.
+pub(super) struct SigningPackage<N: Network> {
+    /// The binding factor of every signer.
+    pub(super) binding_factors: BTreeMap<u16, Scalar<N>>,
+    /// The verifier challenge of the signature.
+    pub(super) challenge: Scalar<N>,
+}
+
+impl<N: Network> SigningPackage<N> {
+    /// Computes the binding factors and challenge of the signing session.
+    pub(super) fn new(
+        public_key: &ThresholdPublicKey<N>,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+
+        // Order the commitments by signer, so that the signing session does not depend on the order they are given in.
+        let num_commitments = commitments.len();
+        let commitments =
+            commitments.iter().map(|commitments| (commitments.identifier, commitments)).collect::<BTreeMap<_, _>>();
+        let signers = commitments.keys().copied().collect::<BTreeSet<_>>();
+
+        // Ensure there are at least `threshold` distinct signers, and that each is a participant.
+        ensure!(signers.len() == num_commitments, "Found duplicate signers in the signing commitments");
+        ensure!(
+            signers.len() >= public_key.threshold as usize,
+            "Expected at least {} signers, found {}",
+            public_key.threshold,
+            signers.len()
+        );
+        if let Some(signer) = signers.iter().find(|signer| public_key.verifying_share(**signer).is_none()) {
+            bail!("Signer {signer} is not a participant of the threshold account")
+        }
+
+        // Compute the binding factor of every signer, which binds its nonces to the message and to the other signers.
+        let domain = Field::new_domain_separator("AleoThresholdSign0");
+        let message_hash = N::hash_psd8(message)?;
+        let commitments_hash = N::hash_psd8(
+            &commitments
+                .values()
+                .flat_map(|commitments| {
+                    let (hiding, binding) =
+                        (commitments.hiding.to_x_coordinate(), commitments.binding.to_x_coordinate());
+                    [Field::from_u16(commitments.identifier), hiding, binding]
+                })
+                .collect::<Vec<_>>(),
+        )?;
+        let binding_factors = signers
+            .iter()
+            .map(|signer| {
+                let preimage = [domain, message_hash, commitments_hash, Field::from_u16(*signer)];
+                Ok((*signer, N::hash_to_scalar_psd4(&preimage)?))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        // Compute the group commitment `g_r` := sum(D_i + (binding_factor_i * E_i)).
+        let g_r: Group<N> = commitments
+            .values()
+            .map(|commitments| commitments.hiding + commitments.binding * binding_factors[&commitments.identifier])
+            .sum();
+
+        // Compute the verifier challenge, as in `Signature::sign`.
+        let compute_key = public_key.compute_key();
+        let address = compute_key.to_address();
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage
+            .extend([g_r, compute_key.pk_sig(), compute_key.pr_sig(), *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+
+        Ok(Self { binding_factors, challenge })
+    }
+
+    /// Returns the binding factor of the given signer.
+    pub(super) fn binding_factor(&self, identifier: u16) -> Result<Scalar<N>> {
+        match self.binding_factors.get(&identifier) {
+            Some(binding_factor) => Ok(*binding_factor),
+            None => bail!("Participant {identifier} is not a signer"),
+        }
+    }
+
+    /// Returns the Lagrange coefficient of the given signer.
+    pub(super) fn lagrange_coefficient(&self, identifier: u16) -> Result<Scalar<N>> {
+        lagrange_coefficient(identifier, self.binding_factors.keys().copied())
+    }
+}
@@ -0,0 +1,359 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::ViewKey;
+
+/// A Shamir share of an account view key.
+///
+/// A view key is split into `n` shares, such that any `t` of them can jointly compute the decryption key
+/// `(view_key * nonce).x` of a record or ciphertext, without any party reconstructing the view key itself.
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct ViewKeyShare<N: Network> {
+    /// The identifier of the participant, in `1..=n`.
+    identifier: u16,
+    /// The number of shares that are required to decrypt.
+    threshold: u16,
+    /// The share of the view key.
+    share: Scalar<N>,
+    /// The address of the account.
+    #[zeroize(skip)]
+    address: Address<N>,
+}
+
+/// The public commitments `G^a_j` to the coefficients of the polynomial that shares a view key,
+/// from which the verifying key `G^share` of every participant is derived.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViewKeyCommitments<N: Network> {
+    /// The commitments to the coefficients, where the first commitment is the address of the account.
+    commitments: Vec<Group<N>>,
+}
+
+/// The contribution `share * nonce` of a participant to the decryption key for a nonce,
+/// with a proof that it was computed with the share of the participant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DecryptionShare<N: Network> {
+    /// The identifier of the participant.
+    identifier: u16,
+    /// The number of shares that are required to decrypt.
+    threshold: u16,
+    /// The decryption share `share * nonce`.
+    value: Group<N>,
+    /// The proof `(challenge, response)` that `log_G(verifying_key) == log_nonce(value)`.
+    proof: (Scalar<N>, Scalar<N>),
+}
+
+impl<N: Network> ViewKey<N> {
+    /// Splits the view key into `num_participants` shares, such that any `threshold` of them can decrypt,
+    /// and returns the shares with the public commitments that verify them.
+    pub fn split<R: Rng + CryptoRng>(
+        &self,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(Vec<ViewKeyShare<N>>, ViewKeyCommitments<N>)> {
+        ensure!(threshold >= 1, "The threshold must be at least 1");
+        ensure!(threshold <= num_participants, "The threshold {threshold} exceeds {num_participants} participants");
+        // Sample a random polynomial whose constant coefficient is the view key.
+        let coefficients = std::iter::once(**self).chain((1..threshold).map(|_| Scalar::rand(rng))).collect::<Vec<_>>();
+        // Evaluate the polynomial at the identifier of every participant.
+        let address = self.to_address();
+        let shares = (1..=num_participants)
+            .map(|identifier| ViewKeyShare {
+                identifier,
+                threshold,
+                share: evaluate_polynomial(&coefficients, identifier),
+                address,
+            })
+            .collect();
+        // Commit to the coefficients of the polynomial.
+        let commitments = ViewKeyCommitments { commitments: coefficients.iter().map(N::g_scalar_multiply).collect() };
+        Ok((shares, commitments))
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the share of the view key `sk_sig + r_sig + sk_prf` of the participant.
+    pub fn to_view_key_share(&self) -> ViewKeyShare<N> {
+        let compute_key = self.public_key.compute_key();
+        ViewKeyShare {
+            identifier: self.identifier,
+            threshold: self.public_key.threshold(),
+            share: self.signing_share + self.randomizer_share + compute_key.sk_prf(),
+            address: compute_key.to_address(),
+        }
+    }
+}
+
+impl<N: Network> ViewKeyCommitments<N> {
+    /// Initializes the commitments to the coefficients of a sharing polynomial.
+    pub(super) fn new(commitments: Vec<Group<N>>) -> Self {
+        Self { commitments }
+    }
+
+    /// Returns the number of shares that are required to decrypt.
+    pub fn threshold(&self) -> u16 {
+        self.commitments.len() as u16
+    }
+
+    /// Returns the address of the account.
+    pub fn to_address(&self) -> Address<N> {
+        Address::new(self.commitments[0])
+    }
+
+    /// Returns the verifying key `G^share` of the given participant.
+    pub fn verifying_key(&self, identifier: u16) -> Group<N> {
+        evaluate_commitments(&self.commitments, identifier)
+    }
+
+    /// Ensures the given view key share is consistent with the commitments.
+    pub fn check_share(&self, share: &ViewKeyShare<N>) -> Result<()> {
+        ensure!(share.identifier > 0, "The view key share has an invalid identifier");
+        ensure!(
+            share.threshold == self.threshold() && share.address == self.to_address(),
+            "The view key share belongs to a different account"
+        );
+        ensure!(
+            share.verifying_key() == self.verifying_key(share.identifier),
+            "The view key share of participant {} is invalid",
+            share.identifier
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> ViewKeyShare<N> {
+    /// Returns the identifier of the participant.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the number of shares that are required to decrypt.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the address of the account.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the verifying key `G^share` of the participant.
+    fn verifying_key(&self) -> Group<N> {
+        N::g_scalar_multiply(&self.share)
+    }
+
+    /// Returns the decryption share of the participant for the given nonce, with a proof of its correctness.
+    pub fn decrypt<R: Rng + CryptoRng>(&self, nonce: Group<N>, rng: &mut R) -> Result<DecryptionShare<N>> {
+        let value = nonce * self.share;
+        // Prove that the decryption share and the verifying key use the same share.
+        let randomizer = Zeroizing::new(Scalar::rand(rng));
+        let challenge = DecryptionShare::challenge(
+            &self.verifying_key(),
+            &nonce,
+            &value,
+            &N::g_scalar_multiply(&randomizer),
+            &(nonce * *randomizer),
+        )?;
+        let response = *randomizer - challenge * self.share;
+        Ok(DecryptionShare {
+            identifier: self.identifier,
+            threshold: self.threshold,
+            value,
+            proof: (challenge, response),
+        })
+    }
+
+    /// Returns the view key, recombined from at least `threshold` shares.
+    pub fn recombine(shares: &[Self]) -> Result<ViewKey<N>> {
+        let Some(first) = shares.first() else { bail!("Expected at least one view key share") };
+        ensure!(
+            shares.iter().all(|share| share.threshold == first.threshold && share.address == first.address),
+            "The view key shares belong to different accounts"
+        );
+        let view_key = interpolate(shares.iter().map(|share| (share.identifier, share.share)), first.threshold)?;
+        let view_key = ViewKey::from_scalar(view_key);
+        ensure!(view_key.to_address() == first.address, "The view key shares do not recombine to the view key");
+        Ok(view_key)
+    }
+}
+
+impl<N: Network> DecryptionShare<N> {
+    /// Returns the identifier of the participant.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns `true` if the decryption share was computed for the given nonce, with the share of the participant
+    /// that the given commitments attest to.
+    pub fn verify(&self, commitments: &ViewKeyCommitments<N>, nonce: &Group<N>) -> bool {
+        if self.identifier == 0 || self.threshold != commitments.threshold() {
+            return false;
+        }
+        // Derive the verifying key of the participant from the commitments.
+        let verifying_key = commitments.verifying_key(self.identifier);
+        let (challenge, response) = self.proof;
+        let g_r = N::g_scalar_multiply(&response) + verifying_key * challenge;
+        let h_r = *nonce * response + self.value * challenge;
+        match Self::challenge(&verifying_key, nonce, &self.value, &g_r, &h_r) {
+            Ok(candidate) => candidate == challenge,
+            Err(error) => {
+                eprintln!("Failed to verify the decryption share: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns the decryption key `(view_key * nonce).x`, from the decryption shares of at least `threshold` participants.
+    /// Note: This method does not verify the decryption shares. Use `DecryptionShare::verify` to identify invalid shares.
+    pub fn combine(shares: &[Self]) -> Result<Field<N>> {
+        let Some(first) = shares.first() else { bail!("Expected at least one decryption share") };
+        ensure!(
+            shares.iter().all(|share| share.threshold == first.threshold),
+            "The decryption shares have different thresholds"
+        );
+        let value = interpolate(shares.iter().map(|share| (share.identifier, share.value)), first.threshold)?;
+        Ok(value.to_x_coordinate())
+    }
+
+    /// Returns the challenge of the proof of equality of discrete logarithms.
+    fn challenge(
+        verifying_key: &Group<N>,
+        nonce: &Group<N>,
+        value: &Group<N>,
+        g_r: &Group<N>,
+        h_r: &Group<N>,
+    ) -> Result<Scalar<N>> {
+        let domain = Field::new_domain_separator("AleoThresholdDecrypt0");
+        let mut preimage = vec![domain];
+        preimage.extend([verifying_key, nonce, value, g_r, h_r].map(|point| point.to_x_coordinate()));
+        N::hash_to_scalar_psd8(&preimage)
+    }
+}
+
+/// Returns the interpolation at zero of the given `(identifier, value)` points,
+/// from at least `threshold` distinct identifiers.
+fn interpolate<N: Network, T: Copy + Mul<Scalar<N>, Output = T> + Sum>(
+    points: impl Iterator<Item = (u16, T)>,
+    threshold: u16,
+) -> Result<T> {
+    let points = points.collect::<BTreeMap<_, _>>();
+    ensure!(
+        points.len() >= threshold as usize,
+        "Expected at least {threshold} distinct shares, found {}",
+        points.len()
+    );
+    points
+        .iter()
+        .map(|(identifier, value)| Ok(*value * lagrange_coefficient::<N>(*identifier, points.keys().copied())?))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_split_and_recombine() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (2, 3), (3, 5)] {
+            let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            let (shares, commitments) = view_key.split(threshold, num_participants, &mut rng)?;
+            assert_eq!(view_key.to_address(), commitments.to_address());
+
+            // Ensure every share is consistent with the commitments.
+            for share in &shares {
+                commitments.check_share(share)?;
+            }
+
+            // Ensure any `threshold` shares recombine to the view key.
+            for start in 0..num_participants as usize {
+                let subset = (0..threshold as usize)
+                    .map(|i| shares[(start + i) % num_participants as usize].clone())
+                    .collect::<Vec<_>>();
+                assert_eq!(view_key, ViewKeyShare::recombine(&subset)?);
+            }
+            // Ensure fewer than `threshold` shares fail to recombine.
+            assert!(ViewKeyShare::recombine(&shares[..threshold as usize - 1]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_key_share_to_view_key_share() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let key_shares = crate::threshold::test_helpers::sample_key_shares(2, 3, &mut rng)?;
+        let public_key = key_shares[0].public_key();
+        let commitments = public_key.view_key_commitments();
+        assert_eq!(public_key.to_address(), commitments.to_address());
+
+        // Ensure the view key shares of the key generation are consistent with the commitments.
+        let shares = key_shares.iter().map(KeyShare::to_view_key_share).collect::<Vec<_>>();
+        for share in &shares {
+            commitments.check_share(share)?;
+        }
+
+        // Ensure any 2 shares decrypt to the decryption key of the account.
+        let view_key = ViewKeyShare::recombine(&shares[1..])?;
+        assert_eq!(public_key.to_address(), view_key.to_address());
+        let nonce: Group<CurrentNetwork> = Uniform::rand(&mut rng);
+        let decryption_shares =
+            shares[..2].iter().map(|share| share.decrypt(nonce, &mut rng)).collect::<Result<Vec<_>>>()?;
+        assert!(decryption_shares.iter().all(|decryption_share| decryption_share.verify(commitments, &nonce)));
+        assert_eq!((nonce * *view_key).to_x_coordinate(), DecryptionShare::combine(&decryption_shares)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_and_combine() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let (shares, commitments) = view_key.split(2, 3, &mut rng)?;
+        let nonce: Group<CurrentNetwork> = Uniform::rand(&mut rng);
+        let expected = (nonce * *view_key).to_x_coordinate();
+
+        // Compute and verify the decryption shares.
+        let decryption_shares =
+            shares.iter().map(|share| share.decrypt(nonce, &mut rng)).collect::<Result<Vec<_>>>()?;
+        for decryption_share in &decryption_shares {
+            assert!(decryption_share.verify(&commitments, &nonce));
+            assert!(!decryption_share.verify(&commitments, &Uniform::rand(&mut rng)));
+        }
+
+        // Ensure a decryption share under another identifier is rejected.
+        let mut forged = decryption_shares[0];
+        forged.identifier = 2;
+        assert!(!forged.verify(&commitments, &nonce));
+
+        // Ensure a decryption share from a share that is inconsistent with the commitments is rejected.
+        let mut invalid_share = shares[0].clone();
+        invalid_share.share += Scalar::one();
+        assert!(commitments.check_share(&invalid_share).is_err());
+        assert!(!invalid_share.decrypt(nonce, &mut rng)?.verify(&commitments, &nonce));
+
+        // Ensure any 2 decryption shares combine to the decryption key.
+        assert_eq!(expected, DecryptionShare::combine(&decryption_shares[..2])?);
+        assert_eq!(expected, DecryptionShare::combine(&decryption_shares[1..])?);
+        assert_eq!(expected, DecryptionShare::combine(&decryption_shares)?);
+        assert!(DecryptionShare::combine(&decryption_shares[..1]).is_err());
+        Ok(())
+    }
+}
@@ -26,7 +26,7 @@ use crate::PrivateKey;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Address, Scalar};
 
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 /// The account view key used to decrypt records and ciphertext.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
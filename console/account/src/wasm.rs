@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The account API for browser wallets, with `wasm-bindgen` types for the current network.
+//!
+//! Errors are returned as JavaScript `Error` objects, and strings use the same encodings as the Rust types.
+
+use snarkvm_console_network::{prelude::*, Testnet3};
+
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// The network of the account API.
+pub type CurrentNetwork = Testnet3;
+
+/// Returns the given error as a JavaScript error.
+pub fn to_js_error(error: impl Display) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// An account private key.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct PrivateKey(crate::PrivateKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl PrivateKey {
+    /// Samples a new random private key.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<PrivateKey, JsError> {
+        crate::PrivateKey::new(&mut OsRng).map(Self).map_err(to_js_error)
+    }
+
+    /// Returns the private key for the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, JsError> {
+        crate::PrivateKey::from_str(private_key).map(Self).map_err(to_js_error)
+    }
+
+    /// Returns the private key as a string.
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the view key of the private key.
+    #[wasm_bindgen(js_name = toViewKey)]
+    pub fn to_view_key(&self) -> Result<ViewKey, JsError> {
+        crate::ViewKey::try_from(&self.0).map(ViewKey).map_err(to_js_error)
+    }
+
+    /// Returns the address of the private key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, JsError> {
+        crate::Address::try_from(&self.0).map(Address).map_err(to_js_error)
+    }
+
+    /// Returns a signature for the given message (as bytes).
+    pub fn sign(&self, message: &[u8]) -> Result<Signature, JsError> {
+        self.0.sign_bytes(message, &mut OsRng).map(Signature).map_err(to_js_error)
+    }
+
+    /// Returns a signature for the given off-chain message (as bytes), under the given application domain.
+    #[wasm_bindgen(js_name = signMessage)]
+    pub fn sign_message(&self, domain: &str, message: &[u8]) -> Result<Signature, JsError> {
+        self.0.sign_message(domain, message, &mut OsRng).map(Signature).map_err(to_js_error)
+    }
+}
+
+impl Deref for PrivateKey {
+    type Target = crate::PrivateKey<CurrentNetwork>;
+
+    /// Returns the private key.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An account view key.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct ViewKey(crate::ViewKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl ViewKey {
+    /// Returns the view key for the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(view_key: &str) -> Result<ViewKey, JsError> {
+        crate::ViewKey::from_str(view_key).map(Self).map_err(to_js_error)
+    }
+
+    /// Returns the view key as a string.
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the address of the view key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Address {
+        Address(self.0.to_address())
+    }
+}
+
+impl Deref for ViewKey {
+    type Target = crate::ViewKey<CurrentNetwork>;
+
+    /// Returns the view key.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An account address.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct Address(crate::Address<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Address {
+    /// Returns the address for the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(address: &str) -> Result<Address, JsError> {
+        crate::Address::from_str(address).map(Self).map_err(to_js_error)
+    }
+
+    /// Returns the address as a string.
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl Deref for Address {
+    type Target = crate::Address<CurrentNetwork>;
+
+    /// Returns the address.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An account signature.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct Signature(crate::Signature<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Signature {
+    /// Returns the signature for the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(signature: &str) -> Result<Signature, JsError> {
+        crate::Signature::from_str(signature).map(Self).map_err(to_js_error)
+    }
+
+    /// Returns the signature as a string.
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the signature is valid for the given address and message (as bytes).
+    pub fn verify(&self, address: &Address, message: &[u8]) -> bool {
+        self.0.verify_bytes(address, message)
+    }
+
+    /// Returns `true` if the signature is valid for the given address and off-chain message (as bytes),
+    /// under the given application domain.
+    #[wasm_bindgen(js_name = verifyMessage)]
+    pub fn verify_message(&self, address: &Address, domain: &str, message: &[u8]) -> bool {
+        self.0.verify_message(address, domain, message)
+    }
+}
+
+impl Deref for Signature {
+    type Target = crate::Signature<CurrentNetwork>;
+
+    /// Returns the signature.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    #[test]
+    fn test_account() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        assert_eq!(ALEO_PRIVATE_KEY, private_key.to_string());
+        let view_key = private_key.to_view_key().unwrap();
+        assert_eq!(ALEO_VIEW_KEY, view_key.to_string());
+        assert_eq!(ALEO_ADDRESS, view_key.to_address().to_string());
+        assert_eq!(ALEO_ADDRESS, private_key.to_address().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let private_key = PrivateKey::new().unwrap();
+        let address = private_key.to_address().unwrap();
+
+        let signature = private_key.sign(b"hello world!").unwrap();
+        let signature = Signature::from_string(&signature.to_string()).unwrap();
+        assert!(signature.verify(&address, b"hello world!"));
+        assert!(!signature.verify(&address, b"hello world"));
+
+        let signature = private_key.sign_message("example.com", b"hello world!").unwrap();
+        assert!(signature.verify_message(&address, "example.com", b"hello world!"));
+        assert!(!signature.verify(&address, b"hello world!"));
+    }
+}
@@ -28,12 +28,12 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
         // Ensure the input size is at least the window size.
-        ensure!(input.len() > Self::MIN_BITS, "Inputs to this BHP must be greater than {} bits", Self::MIN_BITS);
+        ensure!(input.len() > self.min_bits(), "Inputs to this BHP must be greater than {} bits", self.min_bits());
         // Ensure the input size is within the parameter size,
         ensure!(
-            input.len() <= Self::MAX_BITS,
+            input.len() <= self.max_bits(),
             "Inputs to this BHP cannot exceed {} bits, found {}",
-            Self::MAX_BITS,
+            self.max_bits(),
             input.len()
         );
 
@@ -54,7 +54,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
         // Note: `.zip()` is used here (as opposed to `.zip_eq()`) as the input can be less than
         // `NUM_WINDOWS * WINDOW_SIZE * BHP_CHUNK_SIZE` in length, which is the parameter size here.
         Ok(input
-            .chunks(WINDOW_SIZE as usize * BHP_CHUNK_SIZE)
+            .chunks(self.window_size as usize * BHP_CHUNK_SIZE)
             .zip(&*self.bases_lookup)
             .flat_map(|(bits, bases)| {
                 bits.chunks(BHP_CHUNK_SIZE).zip(bases).map(|(chunk_bits, base)| {
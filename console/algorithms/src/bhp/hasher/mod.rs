@@ -28,6 +28,10 @@ pub(super) const BHP_LOOKUP_SIZE: usize = 1 << BHP_CHUNK_SIZE;
 /// The BHP hasher is used to process one internal iteration of the BHP hash function.
 #[derive(Clone, Debug, PartialEq)]
 pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+    /// The number of windows.
+    num_windows: u8,
+    /// The window size.
+    window_size: u8,
     /// The bases for the BHP hash.
     bases: Arc<Vec<Vec<Group<E>>>>,
     /// The bases lookup table for the BHP hash.
@@ -37,13 +41,16 @@ pub struct BHPHasher<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u
 }
 
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE> {
-    /// The maximum number of input bits.
-    const MAX_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
-    /// The minimum number of input bits (at least one window).
-    const MIN_BITS: usize = WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
-
     /// Initializes a new instance of BHP with the given domain.
     pub fn setup(domain: &str) -> Result<Self> {
+        Self::setup_with_windows(domain, NUM_WINDOWS, WINDOW_SIZE)
+    }
+
+    /// Initializes a new instance of BHP with the given domain, number of windows, and window size.
+    pub(super) fn setup_with_windows(domain: &str, num_windows: u8, window_size: u8) -> Result<Self> {
+        ensure!(num_windows > 0, "The number of BHP windows must be nonzero");
+        ensure!(window_size > 0, "The BHP window size must be nonzero");
+
         // Calculate the maximum window size.
         let mut maximum_window_size = 0;
         let mut range = E::BigInteger::from(2_u64);
@@ -52,19 +59,19 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
             range.muln(4); // range * 2^4
             maximum_window_size += 1;
         }
-        ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
+        ensure!(window_size <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
 
         // Compute the bases.
-        let bases = (0..NUM_WINDOWS)
+        let bases = (0..num_windows)
             .map(|index| {
                 // Construct an indexed message to attempt to sample a base.
                 let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!(
-                    "Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.{index}"
+                    "Aleo.BHP.{num_windows}.{window_size}.{domain}.{index}"
                 ));
                 let mut base = Group::<E>::new(generator);
                 // Compute the generators for the sampled base.
-                let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
-                for _ in 0..WINDOW_SIZE {
+                let mut powers = Vec::with_capacity(window_size as usize);
+                for _ in 0..window_size {
                     powers.push(base);
                     for _ in 0..4 {
                         base = base.double();
@@ -73,9 +80,9 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
                 powers
             })
             .collect::<Vec<Vec<Group<E>>>>();
-        ensure!(bases.len() == NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
+        ensure!(bases.len() == num_windows as usize, "Incorrect number of BHP windows ({})", bases.len());
         for window in &bases {
-            ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP window size ({})", window.len());
+            ensure!(window.len() == window_size as usize, "Incorrect BHP window size ({})", window.len());
         }
 
         // Compute the bases lookup.
@@ -102,14 +109,14 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
                     .collect()
             })
             .collect::<Vec<Vec<[Group<E>; BHP_LOOKUP_SIZE]>>>();
-        ensure!(bases_lookup.len() == NUM_WINDOWS as usize, "Incorrect number of BHP lookups ({})", bases_lookup.len());
+        ensure!(bases_lookup.len() == num_windows as usize, "Incorrect number of BHP lookups ({})", bases_lookup.len());
         for window in &bases_lookup {
-            ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP lookup window size ({})", window.len());
+            ensure!(window.len() == window_size as usize, "Incorrect BHP lookup window size ({})", window.len());
         }
 
         // Next, compute the random base.
         let (generator, _, _) =
-            Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.Randomizer"));
+            Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{num_windows}.{window_size}.{domain}.Randomizer"));
         let mut base_power = Group::<E>::new(generator);
         let mut random_base = Vec::with_capacity(Scalar::<E>::size_in_bits());
         for _ in 0..Scalar::<E>::size_in_bits() {
@@ -122,7 +129,33 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
             random_base.len()
         );
 
-        Ok(Self { bases: Arc::new(bases), bases_lookup: Arc::new(bases_lookup), random_base: Arc::new(random_base) })
+        Ok(Self {
+            num_windows,
+            window_size,
+            bases: Arc::new(bases),
+            bases_lookup: Arc::new(bases_lookup),
+            random_base: Arc::new(random_base),
+        })
+    }
+
+    /// Returns the number of windows.
+    pub fn num_windows(&self) -> u8 {
+        self.num_windows
+    }
+
+    /// Returns the window size.
+    pub fn window_size(&self) -> u8 {
+        self.window_size
+    }
+
+    /// Returns the maximum number of input bits.
+    fn max_bits(&self) -> usize {
+        self.num_windows as usize * self.window_size as usize * BHP_CHUNK_SIZE
+    }
+
+    /// Returns the minimum number of input bits (at least one window).
+    fn min_bits(&self) -> usize {
+        self.window_size as usize * BHP_CHUNK_SIZE
     }
 
     /// Returns the bases.
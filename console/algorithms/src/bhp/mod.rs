@@ -34,6 +34,8 @@ pub type BHP512<E> = BHP<E, 6, 43>; // Supports inputs up to 522 bits (2 u8 + 2
 pub type BHP768<E> = BHP<E, 15, 23>; // Supports inputs up to 783 bits (3 u8 + 3 Fq).
 /// BHP1024 is a collision-resistant hash function that processes inputs in 1024-bit chunks.
 pub type BHP1024<E> = BHP<E, 8, 54>; // Supports inputs up to 1044 bits (4 u8 + 4 Fq).
+/// CustomBHP is a collision-resistant hash function whose number of windows and window size are chosen at runtime.
+pub type CustomBHP<E> = BHP<E, 0, 0>;
 
 /// BHP is a collision-resistant hash function that takes a variable-length input.
 /// The BHP hash function does *not* behave like a random oracle, see Poseidon for one.
@@ -60,13 +62,25 @@ pub struct BHP<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
     /// Initializes a new instance of BHP with the given domain.
     pub fn setup(domain: &str) -> Result<Self> {
+        Self::setup_with_windows(domain, NUM_WINDOWS, WINDOW_SIZE)
+    }
+
+    /// Initializes a new instance of BHP with the given domain, number of windows, and window size.
+    fn setup_with_windows(domain: &str, num_windows: u8, window_size: u8) -> Result<Self> {
         // Ensure the given domain is within the allowed size in bits.
         let num_bits = domain.len().saturating_mul(8);
         let max_bits = Field::<E>::size_in_data_bits() - 64; // 64 bits encode the length.
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
         // Initialize the BHP hasher.
-        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
+        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::setup_with_windows(domain, num_windows, window_size)?;
+        // Ensure each iteration can absorb input bits, in addition to the previous digest.
+        let num_hasher_bits = num_windows as usize * window_size as usize * BHP_CHUNK_SIZE;
+        ensure!(
+            num_hasher_bits > Field::<E>::size_in_data_bits(),
+            "The BHP parameters must support more than {} bits, found {num_hasher_bits} bits",
+            Field::<E>::size_in_data_bits()
+        );
 
         // Convert the domain into a boolean vector.
         let mut domain = domain.as_bytes().to_bits_le();
@@ -96,11 +110,56 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WI
 
     /// Returns the number of windows.
     pub fn num_windows(&self) -> u8 {
-        NUM_WINDOWS
+        self.hasher.num_windows()
     }
 
     /// Returns the window size.
     pub fn window_size(&self) -> u8 {
-        WINDOW_SIZE
+        self.hasher.window_size()
+    }
+}
+
+impl<E: Environment> CustomBHP<E> {
+    /// Initializes a new instance of BHP with the given domain, number of windows, and window size.
+    pub fn setup_custom(domain: &str, num_windows: u8, window_size: u8) -> Result<Self> {
+        Self::setup_with_windows(domain, num_windows, window_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_setup_custom() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure a custom BHP with the parameters of a preset matches the preset.
+        let bhp256 = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+        let custom = CustomBHP::<CurrentEnvironment>::setup_custom("BHPTest", 3, 57)?;
+        assert_eq!((custom.num_windows(), custom.window_size()), (3, 57));
+        for i in 0..ITERATIONS {
+            let input = (0..i * 8).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            assert_eq!(bhp256.hash(&input)?, custom.hash(&input)?);
+        }
+
+        // Ensure a custom BHP with new parameters hashes and commits to inputs.
+        let custom = CustomBHP::<CurrentEnvironment>::setup_custom("BHPTest", 2, 60)?;
+        let input = (0..1000).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        let randomizer = Uniform::rand(&mut rng);
+        assert_ne!(bhp256.hash(&input)?, custom.hash(&input)?);
+        assert_ne!(bhp256.commit(&input, &randomizer)?, custom.commit(&input, &randomizer)?);
+
+        // Ensure invalid parameters are rejected.
+        assert!(CustomBHP::<CurrentEnvironment>::setup("BHPTest").is_err());
+        assert!(CustomBHP::<CurrentEnvironment>::setup_custom("BHPTest", 0, 57).is_err());
+        assert!(CustomBHP::<CurrentEnvironment>::setup_custom("BHPTest", 1, 57).is_err());
+        assert!(CustomBHP::<CurrentEnvironment>::setup_custom("BHPTest", 8, u8::MAX).is_err());
+        Ok(())
     }
 }
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Secp256k1 {
+    /// Returns the 20-byte Ethereum address of the given public key `x || y` in big-endian bytes,
+    /// which is the last 20 bytes of the Keccak-256 hash of the uncompressed public key (without its `0x04` prefix).
+    ///
+    /// Note: The public key is not checked to be on the curve.
+    pub fn ethereum_address(public_key: &[u8; 64]) -> [u8; 20] {
+        let mut keccak = TinyKeccak::v256();
+        keccak.update(public_key);
+        let mut hash = [0u8; 32];
+        keccak.finalize(&mut hash);
+
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ethereum_address() {
+        let curve = Secp256k1::new();
+
+        // Ensure the address of the secret key `1` (i.e. of the generator) matches the known Ethereum address.
+        let public_key = curve.public_key(&BigUint::from(1u32)).unwrap();
+        let address = Secp256k1::ethereum_address(&public_key);
+        assert_eq!(hex::encode(address), "7e5f4552091a69125d5dfcb7b8c2659029395bdf");
+
+        // Ensure a different public key has a different address.
+        let public_key = curve.public_key(&BigUint::from(2u32)).unwrap();
+        assert_ne!(Secp256k1::ethereum_address(&public_key), address);
+    }
+}
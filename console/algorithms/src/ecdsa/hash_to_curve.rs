@@ -0,0 +1,282 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_types::prelude::{ensure, Itertools, Result};
+
+use sha2::{Digest, Sha256};
+
+/// The coefficient `A'` of the curve `E': y^2 = x^3 + A' * x + B'`, which is 3-isogenous to secp256k1.
+const ISO_A: &str = "3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533";
+/// The coefficient `B'` of the curve `E': y^2 = x^3 + A' * x + B'`, which is 3-isogenous to secp256k1.
+const ISO_B: u32 = 1771;
+/// The negation of the non-square `Z = -11` of the simplified SWU map.
+const NEG_Z: u32 = 11;
+/// The coefficients of the numerator of the x-coordinate of the 3-isogeny, in increasing degree.
+const ISO_X_NUM: [&str; 4] = [
+    "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7",
+    "07d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581",
+    "534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262",
+    "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c",
+];
+/// The coefficients of the denominator of the x-coordinate of the 3-isogeny, in increasing degree.
+const ISO_X_DEN: [&str; 3] = [
+    "d35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b",
+    "edadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14",
+    "0000000000000000000000000000000000000000000000000000000000000001",
+];
+/// The coefficients of the numerator of the y-coordinate of the 3-isogeny, in increasing degree.
+const ISO_Y_NUM: [&str; 4] = [
+    "4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c",
+    "c75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3",
+    "29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931",
+    "2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84",
+];
+/// The coefficients of the denominator of the y-coordinate of the 3-isogeny, in increasing degree.
+const ISO_Y_DEN: [&str; 4] = [
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b",
+    "7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573",
+    "6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f",
+    "0000000000000000000000000000000000000000000000000000000000000001",
+];
+
+/// The number of bytes `L` that are reduced into each field element, for 128 bits of security.
+const FIELD_ELEMENT_SIZE: usize = 48;
+
+/// The method used to hash a message to a point on the curve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HashToCurveMethod {
+    /// Hashes the domain and message with Keccak-256 and a counter, until the digest is the x-coordinate of a point.
+    /// This is the method used to derive the offset point, and is not interoperable with other libraries.
+    TryAndIncrement,
+    /// The `secp256k1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380, with the domain as the domain separation tag.
+    Rfc9380,
+}
+
+/// Returns `length` uniformly random bytes for the given message and domain separation tag,
+/// using `expand_message_xmd` with SHA-256, as in RFC 9380 (Section 5.3.1).
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], length: usize) -> Result<Vec<u8>> {
+    // The output size `b_in_bytes` and block size `s_in_bytes` of SHA-256.
+    const B_IN_BYTES: usize = 32;
+    const S_IN_BYTES: usize = 64;
+
+    let ell = (length + B_IN_BYTES - 1) / B_IN_BYTES;
+    ensure!(ell <= 255, "Cannot expand a message to more than {} bytes", 255 * B_IN_BYTES);
+    ensure!(dst.len() <= 255, "The domain separation tag must be at most 255 bytes");
+
+    // Compute `DST_prime = DST || I2OSP(len(DST), 1)`.
+    let dst_prime = [dst, &[u8::try_from(dst.len())?]].concat();
+
+    // Compute `b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)`.
+    let b_0 = Sha256::new()
+        .chain_update([0u8; S_IN_BYTES])
+        .chain_update(message)
+        .chain_update(u16::try_from(length)?.to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    // Compute `b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)`, where `b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)`.
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    let mut b_i = Sha256::new().chain_update(b_0).chain_update([1u8]).chain_update(&dst_prime).finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xor = b_0.iter().zip_eq(b_i.iter()).map(|(a, b)| a ^ b).collect::<Vec<_>>();
+        b_i = Sha256::new().chain_update(xor).chain_update([u8::try_from(i)?]).chain_update(&dst_prime).finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(length);
+    Ok(uniform_bytes)
+}
+
+impl Secp256k1 {
+    /// Returns a point on the curve for the given domain and message, using the given method.
+    pub fn hash_to_point(&self, method: HashToCurveMethod, domain: &[u8], message: &[u8]) -> Result<Secp256k1Point> {
+        match method {
+            HashToCurveMethod::TryAndIncrement => {
+                // Prefix the domain with its length, so that the domain and message are unambiguous.
+                let input = [&u32::try_from(domain.len())?.to_le_bytes(), domain, message].concat();
+                Ok(Some(Self::hash_to_curve(&self.base_modulus, &input)))
+            }
+            HashToCurveMethod::Rfc9380 => self.hash_to_curve_rfc9380(domain, message),
+        }
+    }
+
+    /// Returns the point for the given message and domain separation tag,
+    /// using the `secp256k1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380.
+    ///
+    /// Note: This is not constant-time, and should not be used on secret messages.
+    pub fn hash_to_curve_rfc9380(&self, dst: &[u8], message: &[u8]) -> Result<Secp256k1Point> {
+        let u = self.hash_to_field(message, dst, 2)?;
+        let q_0 = self.iso_map(&self.map_to_curve_simple_swu(&u[0]));
+        let q_1 = self.iso_map(&self.map_to_curve_simple_swu(&u[1]));
+        // Note: The cofactor of secp256k1 is 1, so the cofactor does not need to be cleared.
+        Ok(self.add(&q_0, &q_1))
+    }
+
+    /// Returns the point for the given message and domain separation tag,
+    /// using the nonuniform `secp256k1_XMD:SHA-256_SSWU_NU_` suite of RFC 9380.
+    ///
+    /// Note: This is not constant-time, and should not be used on secret messages.
+    pub fn encode_to_curve_rfc9380(&self, dst: &[u8], message: &[u8]) -> Result<Secp256k1Point> {
+        let u = self.hash_to_field(message, dst, 1)?;
+        Ok(self.iso_map(&self.map_to_curve_simple_swu(&u[0])))
+    }
+
+    /// Returns `count` field elements for the given message and domain separation tag, as in RFC 9380 (Section 5.2).
+    fn hash_to_field(&self, message: &[u8], dst: &[u8], count: usize) -> Result<Vec<BigUint>> {
+        let uniform_bytes = expand_message_xmd(message, dst, count * FIELD_ELEMENT_SIZE)?;
+        Ok(uniform_bytes
+            .chunks(FIELD_ELEMENT_SIZE)
+            .map(|bytes| BigUint::from_bytes_be(bytes) % &self.base_modulus)
+            .collect())
+    }
+
+    /// Returns the point on `E'` for the given field element, using the simplified SWU map of RFC 9380 (Section 6.6.2).
+    fn map_to_curve_simple_swu(&self, u: &BigUint) -> (BigUint, BigUint) {
+        let p = &self.base_modulus;
+        let (a, b) = (parse_hex(ISO_A), BigUint::from(ISO_B));
+        let z = p - NEG_Z;
+        // Note: As `p = 3 mod 4`, the square root of a quadratic residue `a` is `a^((p + 1) / 4)`.
+        let sqrt_exponent: BigUint = (p + 1u32) >> 2;
+        let g = |x: &BigUint| (x * x * x + &a * x + &b) % p;
+        let sgn0 = |x: &BigUint| x.bit(0);
+
+        // Compute `tv1 = inv0(Z^2 * u^4 + Z * u^2)`.
+        let z_u2 = (&z * u * u) % p;
+        let tv1 = Self::inverse(&((&z_u2 * &z_u2 + &z_u2) % p), p);
+        // Compute `x1 = (-B / A) * (1 + tv1)`, or `x1 = B / (Z * A)` if `tv1 == 0`.
+        let x1 = match tv1.bits() == 0 {
+            true => (&b * Self::inverse(&((&z * &a) % p), p)) % p,
+            false => ((p - &b) * Self::inverse(&a, p) % p * (tv1 + 1u32)) % p,
+        };
+        // Compute `x2 = Z * u^2 * x1`.
+        let x2 = (&z_u2 * &x1) % p;
+
+        // Set `x = x1` if `g(x1)` is square, and `x = x2` otherwise.
+        let gx1 = g(&x1);
+        let y1 = gx1.modpow(&sqrt_exponent, p);
+        let (x, y) = match (&y1 * &y1) % p == gx1 {
+            true => (x1, y1),
+            false => (x2.clone(), g(&x2).modpow(&sqrt_exponent, p)),
+        };
+        // Ensure the sign of `y` matches the sign of `u`.
+        match sgn0(u) == sgn0(&y) {
+            true => (x, y),
+            false => (x, (p - &y) % p),
+        }
+    }
+
+    /// Returns the point on secp256k1 for the given point on `E'`, using the 3-isogeny of RFC 9380 (Appendix E.1).
+    fn iso_map(&self, (x, y): &(BigUint, BigUint)) -> Secp256k1Point {
+        let p = &self.base_modulus;
+        // Evaluates the polynomial with the given coefficients at `x`.
+        let evaluate = |coefficients: &[&str]| {
+            coefficients
+                .iter()
+                .rev()
+                .fold(BigUint::from(0u32), |acc, coefficient| (acc * x + parse_hex(coefficient)) % p)
+        };
+        let (x_den, y_den) = (evaluate(&ISO_X_DEN), evaluate(&ISO_Y_DEN));
+        // If a denominator is zero, the point is in the kernel of the isogeny.
+        if x_den.bits() == 0 || y_den.bits() == 0 {
+            return None;
+        }
+        let x_out = (evaluate(&ISO_X_NUM) * Self::inverse(&x_den, p)) % p;
+        let y_out = (y * evaluate(&ISO_Y_NUM) % p * Self::inverse(&y_den, p)) % p;
+        Some((x_out, y_out))
+    }
+}
+
+/// Returns the integer for the given hex string.
+fn parse_hex(hex: &str) -> BigUint {
+    // Note: The unwrap is guaranteed to succeed, as the constants are valid hex strings.
+    BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the point with the given hex coordinates.
+    fn point(x: &str, y: &str) -> Secp256k1Point {
+        Some((parse_hex(x), parse_hex(y)))
+    }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // The test vectors for `expand_message_xmd` with SHA-256, from RFC 9380 (Appendix K.1).
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"", dst, 0x20).unwrap()),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"abc", dst, 0x20).unwrap()),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+
+        // Ensure the output length is respected, and that the parameters are checked.
+        assert_eq!(expand_message_xmd(b"abc", dst, 0x80).unwrap().len(), 0x80);
+        assert!(expand_message_xmd(b"abc", dst, 255 * 32 + 1).is_err());
+        assert!(expand_message_xmd(b"abc", &[0u8; 256], 0x20).is_err());
+    }
+
+    #[test]
+    fn test_hash_to_curve_rfc9380() {
+        let curve = Secp256k1::new();
+
+        // The test vectors for `secp256k1_XMD:SHA-256_SSWU_RO_`, from RFC 9380 (Appendix J.8.1).
+        let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+        assert_eq!(
+            curve.hash_to_curve_rfc9380(dst, b"").unwrap(),
+            point(
+                "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067"
+            )
+        );
+        assert_eq!(
+            curve.hash_to_curve_rfc9380(dst, b"abc").unwrap(),
+            point(
+                "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6"
+            )
+        );
+    }
+
+    #[test]
+    fn test_hash_to_point() {
+        let curve = Secp256k1::new();
+
+        for method in [HashToCurveMethod::TryAndIncrement, HashToCurveMethod::Rfc9380] {
+            // Ensure the points are on the curve, and are distinct across domains and messages.
+            let a = curve.hash_to_point(method, b"domain", b"message").unwrap().unwrap();
+            let b = curve.hash_to_point(method, b"domain", b"message!").unwrap().unwrap();
+            let c = curve.hash_to_point(method, b"domain!", b"message").unwrap().unwrap();
+            for (x, y) in [&a, &b, &c] {
+                assert!(curve.is_on_curve(x, y));
+            }
+            assert_ne!(a, b);
+            assert_ne!(a, c);
+            assert_ne!(b, c);
+            // Ensure the points are deterministic.
+            assert_eq!(curve.hash_to_point(method, b"domain", b"message").unwrap().unwrap(), a);
+        }
+
+        // Ensure the encoding is on the curve.
+        let (x, y) = curve.encode_to_curve_rfc9380(b"domain", b"message").unwrap().unwrap();
+        assert!(curve.is_on_curve(&x, &y));
+    }
+}
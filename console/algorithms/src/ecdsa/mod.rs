@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod address;
+mod hash_to_curve;
+pub use hash_to_curve::{expand_message_xmd, HashToCurveMethod};
+
+mod verify;
+
+use tiny_keccak::{Hasher, Keccak as TinyKeccak};
+
+use num_bigint::BigUint;
+
+/// An affine point on the secp256k1 curve, where `None` is the point at infinity.
+pub type Secp256k1Point = Option<(BigUint, BigUint)>;
+
+/// The base field modulus `p` of secp256k1.
+const BASE_MODULUS: &str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+/// The scalar field modulus (i.e. the order of the group) `n` of secp256k1.
+const SCALAR_MODULUS: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+/// The x-coordinate of the generator of secp256k1.
+const GENERATOR_X: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// The y-coordinate of the generator of secp256k1.
+const GENERATOR_Y: &str = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+/// The domain separator for the offset point, which is used to avoid the point at infinity in circuits.
+const OFFSET_DOMAIN: &[u8] = b"AleoSecp256k1Offset0";
+
+/// The secp256k1 elliptic curve `y^2 = x^3 + 7`, which is used for ECDSA signatures in Bitcoin and Ethereum.
+///
+/// The arithmetic is implemented with arbitrary-precision integers,
+/// and is intended for signature verification and for computing circuit witnesses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1 {
+    /// The base field modulus `p`.
+    base_modulus: BigUint,
+    /// The scalar field modulus `n`.
+    scalar_modulus: BigUint,
+    /// The generator `G`.
+    generator: (BigUint, BigUint),
+    /// A point with an unknown discrete logarithm, which is derived by hashing to the curve.
+    offset: (BigUint, BigUint),
+}
+
+impl Default for Secp256k1 {
+    /// Initializes the secp256k1 curve.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Secp256k1 {
+    /// The coefficient `b` of the curve equation `y^2 = x^3 + b`.
+    pub const B: u32 = 7;
+
+    /// Initializes the secp256k1 curve.
+    pub fn new() -> Self {
+        // Note: The unwraps are guaranteed to succeed, as the constants are valid hex strings.
+        let parse = |hex: &str| BigUint::parse_bytes(hex.as_bytes(), 16).unwrap();
+        let base_modulus = parse(BASE_MODULUS);
+        let scalar_modulus = parse(SCALAR_MODULUS);
+        let generator = (parse(GENERATOR_X), parse(GENERATOR_Y));
+        let offset = Self::hash_to_curve(&base_modulus, OFFSET_DOMAIN);
+        Self { base_modulus, scalar_modulus, generator, offset }
+    }
+
+    /// Returns the base field modulus `p`.
+    pub const fn base_modulus(&self) -> &BigUint {
+        &self.base_modulus
+    }
+
+    /// Returns the scalar field modulus `n`.
+    pub const fn scalar_modulus(&self) -> &BigUint {
+        &self.scalar_modulus
+    }
+
+    /// Returns the generator `G`.
+    pub const fn generator(&self) -> &(BigUint, BigUint) {
+        &self.generator
+    }
+
+    /// Returns a point with an unknown discrete logarithm with respect to `G`.
+    pub const fn offset(&self) -> &(BigUint, BigUint) {
+        &self.offset
+    }
+
+    /// Returns `true` if `(x, y)` is a point on the curve, with `x` and `y` reduced modulo `p`.
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        let p = &self.base_modulus;
+        x < p && y < p && (y * y) % p == (x * x * x + Self::B) % p
+    }
+
+    /// Returns the inverse of `value` modulo the prime `modulus`.
+    pub fn inverse(value: &BigUint, modulus: &BigUint) -> BigUint {
+        // Note: By Fermat's little theorem, `value^(modulus - 2)` is the inverse of `value`.
+        value.modpow(&(modulus - 2u32), modulus)
+    }
+
+    /// Returns `a + b`.
+    pub fn add(&self, a: &Secp256k1Point, b: &Secp256k1Point) -> Secp256k1Point {
+        let p = &self.base_modulus;
+        let ((x1, y1), (x2, y2)) = match (a, b) {
+            (None, _) => return b.clone(),
+            (_, None) => return a.clone(),
+            (Some(a), Some(b)) => (a, b),
+        };
+        if x1 == x2 {
+            // If `a == b`, double the point, otherwise `a == -b`, and the sum is the point at infinity.
+            return if y1 == y2 { self.double(a) } else { None };
+        }
+        // Compute `lambda = (y2 - y1) / (x2 - x1)`.
+        let lambda = ((y2 + p - y1) * Self::inverse(&((x2 + p - x1) % p), p)) % p;
+        // Compute `x3 = lambda^2 - x1 - x2` and `y3 = lambda * (x1 - x3) - y1`.
+        let x3 = (&lambda * &lambda + p + p - x1 - x2) % p;
+        let y3 = (lambda * ((x1 + p - &x3) % p) + p - y1) % p;
+        Some((x3, y3))
+    }
+
+    /// Returns `2 * a`.
+    pub fn double(&self, a: &Secp256k1Point) -> Secp256k1Point {
+        let p = &self.base_modulus;
+        let (x, y) = a.as_ref()?;
+        if y.bits() == 0 {
+            return None;
+        }
+        // Compute `lambda = 3 * x^2 / (2 * y)`.
+        let lambda = (x * x * 3u32 * Self::inverse(&((y * 2u32) % p), p)) % p;
+        // Compute `x3 = lambda^2 - 2 * x` and `y3 = lambda * (x - x3) - y`.
+        let x3 = (&lambda * &lambda + p + p - x - x) % p;
+        let y3 = (lambda * ((x + p - &x3) % p) + p - y) % p;
+        Some((x3, y3))
+    }
+
+    /// Returns `scalar * a`.
+    pub fn mul(&self, a: &Secp256k1Point, scalar: &BigUint) -> Secp256k1Point {
+        let mut output = None;
+        for i in (0..scalar.bits()).rev() {
+            output = self.double(&output);
+            if scalar.bit(i) {
+                output = self.add(&output, a);
+            }
+        }
+        output
+    }
+
+    /// Returns a point on the curve, derived from the Keccak-256 hash of the given domain and a counter.
+    fn hash_to_curve(p: &BigUint, domain: &[u8]) -> (BigUint, BigUint) {
+        // Note: As `p = 3 mod 4`, the square root of a quadratic residue `a` is `a^((p + 1) / 4)`.
+        let sqrt_exponent: BigUint = (p + 1u32) >> 2;
+        for counter in 0u32.. {
+            let mut keccak = TinyKeccak::v256();
+            keccak.update(domain);
+            keccak.update(&counter.to_le_bytes());
+            let mut hash = [0u8; 32];
+            keccak.finalize(&mut hash);
+
+            // Set `x` to the hash, and check if `x^3 + b` is a quadratic residue.
+            let x = BigUint::from_bytes_be(&hash) % p;
+            let rhs = (&x * &x * &x + Self::B) % p;
+            let y = rhs.modpow(&sqrt_exponent, p);
+            if (&y * &y) % p == rhs {
+                return (x, y);
+            }
+        }
+        unreachable!("Failed to hash to the secp256k1 curve")
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Secp256k1 {
+    /// Returns `true` if `signature` is a valid ECDSA signature on the 32-byte `digest` under `public_key`.
+    ///
+    /// The signature is encoded as `r || s`, and the public key as `x || y`, in big-endian bytes,
+    /// as in Ethereum. Both `r` and `s` must be in `[1, n - 1]`, and the public key must be on the curve.
+    pub fn verify(&self, signature: &[u8; 64], public_key: &[u8; 64], digest: &[u8; 32]) -> bool {
+        let n = &self.scalar_modulus;
+
+        // Parse the signature, and ensure `r` and `s` are in `[1, n - 1]`.
+        let (r, s) = (BigUint::from_bytes_be(&signature[..32]), BigUint::from_bytes_be(&signature[32..]));
+        if r.bits() == 0 || &r >= n || s.bits() == 0 || &s >= n {
+            return false;
+        }
+
+        // Parse the public key, and ensure it is on the curve.
+        let (x, y) = (BigUint::from_bytes_be(&public_key[..32]), BigUint::from_bytes_be(&public_key[32..]));
+        if !self.is_on_curve(&x, &y) {
+            return false;
+        }
+
+        // Compute `u1 = e / s` and `u2 = r / s`.
+        let w = Self::inverse(&s, n);
+        let u1 = (BigUint::from_bytes_be(digest) * &w) % n;
+        let u2 = (&r * &w) % n;
+
+        // Compute `R = u1 * G + u2 * Q`, and check that `x(R) mod n == r`.
+        let generator = Some(self.generator.clone());
+        match self.add(&self.mul(&generator, &u1), &self.mul(&Some((x, y)), &u2)) {
+            Some((x, _)) => x % n == r,
+            None => false,
+        }
+    }
+
+    /// Returns the public key `x || y` in big-endian bytes for the given secret key,
+    /// or `None` if the secret key is not in `[1, n - 1]`.
+    pub fn public_key(&self, secret_key: &BigUint) -> Option<[u8; 64]> {
+        if secret_key.bits() == 0 || secret_key >= &self.scalar_modulus {
+            return None;
+        }
+        let (x, y) = self.mul(&Some(self.generator.clone()), secret_key)?;
+        Some(Self::to_bytes_be(&x, &y))
+    }
+
+    /// Returns the ECDSA signature `r || s` in big-endian bytes on the 32-byte `digest` under the given secret key,
+    /// or `None` if the nonce does not yield a valid signature.
+    ///
+    /// The nonce must be sampled uniformly at random (or derived as in RFC 6979) and never be reused,
+    /// as otherwise the secret key can be recovered from the signatures.
+    pub fn sign(&self, secret_key: &BigUint, digest: &[u8; 32], nonce: &BigUint) -> Option<[u8; 64]> {
+        let n = &self.scalar_modulus;
+        if nonce.bits() == 0 || nonce >= n {
+            return None;
+        }
+
+        // Compute `r = x(k * G) mod n` and `s = (e + r * d) / k`.
+        let (x, _) = self.mul(&Some(self.generator.clone()), nonce)?;
+        let r = x % n;
+        let s = ((BigUint::from_bytes_be(digest) + &r * secret_key) * Self::inverse(nonce, n)) % n;
+        if r.bits() == 0 || s.bits() == 0 {
+            return None;
+        }
+        Some(Self::to_bytes_be(&r, &s))
+    }
+
+    /// Returns the concatenation of `a` and `b` as 32-byte big-endian integers.
+    fn to_bytes_be(a: &BigUint, b: &BigUint) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        for (i, value) in [a, b].into_iter().enumerate() {
+            let value = value.to_bytes_be();
+            bytes[32 * (i + 1) - value.len()..32 * (i + 1)].copy_from_slice(&value);
+        }
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 10;
+
+    /// Samples a random integer in `[1, n - 1]`.
+    fn sample_scalar(curve: &Secp256k1, rng: &mut TestRng) -> BigUint {
+        BigUint::from_bytes_be(&<[u8; 32]>::rand(rng)) % (curve.scalar_modulus() - 1u32) + 1u32
+    }
+
+    #[test]
+    fn test_curve() {
+        let curve = Secp256k1::new();
+        let (x, y) = curve.generator();
+        let (offset_x, offset_y) = curve.offset();
+
+        // Ensure the generator and offset are on the curve.
+        assert!(curve.is_on_curve(x, y));
+        assert!(curve.is_on_curve(offset_x, offset_y));
+        // Ensure the generator has order `n`.
+        let generator = Some(curve.generator().clone());
+        assert_eq!(curve.mul(&generator, curve.scalar_modulus()), None);
+        assert_eq!(curve.mul(&generator, &(curve.scalar_modulus() + 1u32)), generator);
+        // Ensure addition is consistent with doubling.
+        assert_eq!(curve.add(&generator, &generator), curve.double(&generator));
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let mut rng = TestRng::default();
+        let curve = Secp256k1::new();
+
+        for _ in 0..ITERATIONS {
+            let secret_key = sample_scalar(&curve, &mut rng);
+            let public_key = curve.public_key(&secret_key).unwrap();
+            let digest = <[u8; 32]>::rand(&mut rng);
+            let signature = curve.sign(&secret_key, &digest, &sample_scalar(&curve, &mut rng)).unwrap();
+
+            // Ensure the signature is valid.
+            assert!(curve.verify(&signature, &public_key, &digest));
+
+            // Ensure the signature is invalid for a different digest.
+            let mut other_digest = digest;
+            other_digest[0] ^= 1;
+            assert!(!curve.verify(&signature, &public_key, &other_digest));
+
+            // Ensure the signature is invalid for a different public key.
+            let other_public_key = curve.public_key(&sample_scalar(&curve, &mut rng)).unwrap();
+            assert!(!curve.verify(&signature, &other_public_key, &digest));
+
+            // Ensure an invalid public key is rejected.
+            let mut invalid_public_key = public_key;
+            invalid_public_key[63] ^= 1;
+            assert!(!curve.verify(&signature, &invalid_public_key, &digest));
+
+            // Ensure a signature with `s = 0` is rejected.
+            let mut invalid_signature = signature;
+            invalid_signature[32..].fill(0);
+            assert!(!curve.verify(&invalid_signature, &public_key, &digest));
+        }
+    }
+}
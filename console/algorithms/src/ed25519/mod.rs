@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use sha2::{Digest, Sha512};
+
+use num_bigint::BigUint;
+
+/// An affine point on the Ed25519 curve, where `(0, 1)` is the identity.
+pub type Ed25519Point = (BigUint, BigUint);
+
+/// The base field modulus `p = 2^255 - 19` of Ed25519.
+const BASE_MODULUS: &str = "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed";
+/// The prime order `l` of the subgroup generated by the base point of Ed25519.
+const SCALAR_MODULUS: &str = "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed";
+
+/// The twisted Edwards curve `-x^2 + y^2 = 1 + d * x^2 * y^2` that is birationally equivalent to Curve25519,
+/// which is used for Ed25519 signatures (RFC 8032) in Solana, Cosmos, and hardware tokens.
+///
+/// The arithmetic is implemented with arbitrary-precision integers,
+/// and is intended for signature verification and for computing circuit witnesses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519 {
+    /// The base field modulus `p`.
+    base_modulus: BigUint,
+    /// The order `l` of the base point.
+    scalar_modulus: BigUint,
+    /// The curve coefficient `d = -121665 / 121666`.
+    d: BigUint,
+    /// The base point `B`.
+    base: Ed25519Point,
+}
+
+impl Default for Ed25519 {
+    /// Initializes the Ed25519 curve.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ed25519 {
+    /// Initializes the Ed25519 curve.
+    pub fn new() -> Self {
+        // Note: The unwraps are guaranteed to succeed, as the constants are valid hex strings.
+        let parse = |hex: &str| BigUint::parse_bytes(hex.as_bytes(), 16).unwrap();
+        let base_modulus = parse(BASE_MODULUS);
+        let scalar_modulus = parse(SCALAR_MODULUS);
+        let d = (&base_modulus - 121665u32) * Self::inverse(&BigUint::from(121666u32), &base_modulus) % &base_modulus;
+        let mut curve = Self { base_modulus, scalar_modulus, d, base: (BigUint::default(), BigUint::from(1u32)) };
+        // The base point is the point with `y = 4 / 5` and an even `x`.
+        let y = Self::inverse(&BigUint::from(5u32), &curve.base_modulus) * 4u32 % &curve.base_modulus;
+        // Note: The unwrap is guaranteed to succeed, as `y = 4 / 5` is a valid encoding.
+        curve.base = curve.decompress(&Self::to_bytes_le(&y)).unwrap();
+        curve
+    }
+
+    /// Returns the base field modulus `p`.
+    pub const fn base_modulus(&self) -> &BigUint {
+        &self.base_modulus
+    }
+
+    /// Returns the order `l` of the base point.
+    pub const fn scalar_modulus(&self) -> &BigUint {
+        &self.scalar_modulus
+    }
+
+    /// Returns the curve coefficient `d`.
+    pub const fn d(&self) -> &BigUint {
+        &self.d
+    }
+
+    /// Returns the base point `B`.
+    pub const fn base(&self) -> &Ed25519Point {
+        &self.base
+    }
+
+    /// Returns `true` if `(x, y)` is a point on the curve, with `x` and `y` reduced modulo `p`.
+    pub fn is_on_curve(&self, (x, y): &Ed25519Point) -> bool {
+        let p = &self.base_modulus;
+        let (x2, y2) = (x * x % p, y * y % p);
+        x < p && y < p && (&y2 + p - &x2) % p == (&self.d * x2 * y2 + 1u32) % p
+    }
+
+    /// Returns the inverse of `value` modulo the prime `modulus`.
+    pub fn inverse(value: &BigUint, modulus: &BigUint) -> BigUint {
+        // Note: By Fermat's little theorem, `value^(modulus - 2)` is the inverse of `value`.
+        value.modpow(&(modulus - 2u32), modulus)
+    }
+
+    /// Returns `a + b`.
+    pub fn add(&self, (x1, y1): &Ed25519Point, (x2, y2): &Ed25519Point) -> Ed25519Point {
+        let p = &self.base_modulus;
+        // Note: The formulas are complete, as `d` is not a square, so the denominators are never zero.
+        let k = &self.d * x1 * x2 % p * y1 * y2 % p;
+        // Compute `x3 = (x1 * y2 + y1 * x2) / (1 + k)` and `y3 = (y1 * y2 + x1 * x2) / (1 - k)`.
+        let x3 = (x1 * y2 + y1 * x2) * Self::inverse(&((&k + 1u32) % p), p) % p;
+        let y3 = (y1 * y2 + x1 * x2) * Self::inverse(&((p + 1u32 - k) % p), p) % p;
+        (x3, y3)
+    }
+
+    /// Returns `scalar * a`.
+    pub fn mul(&self, a: &Ed25519Point, scalar: &BigUint) -> Ed25519Point {
+        let mut output = (BigUint::default(), BigUint::from(1u32));
+        for i in (0..scalar.bits()).rev() {
+            output = self.add(&output, &output);
+            if scalar.bit(i) {
+                output = self.add(&output, a);
+            }
+        }
+        output
+    }
+
+    /// Returns the point with the given 32-byte encoding, or `None` if the encoding is invalid.
+    ///
+    /// The encoding is the little-endian `y`-coordinate, with the most significant bit set to the parity of `x`.
+    pub fn decompress(&self, bytes: &[u8; 32]) -> Option<Ed25519Point> {
+        let p = &self.base_modulus;
+        let sign = bytes[31] >> 7 == 1;
+        let mut bytes = *bytes;
+        bytes[31] &= 0x7f;
+
+        // Ensure `y` is reduced.
+        let y = BigUint::from_bytes_le(&bytes);
+        if &y >= p {
+            return None;
+        }
+
+        // Compute `x = sqrt((y^2 - 1) / (d * y^2 + 1))`.
+        let y2 = &y * &y % p;
+        let u = (&y2 + p - 1u32) * Self::inverse(&((&self.d * y2 + 1u32) % p), p) % p;
+        let x = Self::sqrt(&u, p)?;
+        // Ensure the parity is valid, and select the root with the given parity.
+        if x.bits() == 0 && sign {
+            return None;
+        }
+        match x.bit(0) == sign {
+            true => Some((x, y)),
+            false => Some((p - x, y)),
+        }
+    }
+
+    /// Returns the 32-byte encoding of the given point.
+    pub fn compress(&self, (x, y): &Ed25519Point) -> [u8; 32] {
+        let mut bytes = Self::to_bytes_le(y);
+        bytes[31] |= u8::from(x.bit(0)) << 7;
+        bytes
+    }
+
+    /// Returns a square root of `value` modulo `p`, or `None` if `value` is not a square.
+    pub fn sqrt(value: &BigUint, p: &BigUint) -> Option<BigUint> {
+        // Note: As `p = 5 mod 8`, a square root of a square `a` is either `a^((p + 3) / 8)`,
+        // or `a^((p + 3) / 8) * sqrt(-1)`, where `sqrt(-1) = 2^((p - 1) / 4)`.
+        let x = value.modpow(&((p + 3u32) >> 3), p);
+        if &x * &x % p == *value {
+            return Some(x);
+        }
+        let x = x * BigUint::from(2u32).modpow(&((p - 1u32) >> 2), p) % p;
+        match &x * &x % p == *value {
+            true => Some(x),
+            false => None,
+        }
+    }
+
+    /// Returns the SHA-512 hash of the concatenation of the given inputs, as an integer modulo `l`.
+    fn hash_to_scalar(&self, inputs: &[&[u8]]) -> BigUint {
+        let mut hasher = Sha512::new();
+        inputs.iter().for_each(|input| hasher.update(input));
+        BigUint::from_bytes_le(&hasher.finalize()) % &self.scalar_modulus
+    }
+
+    /// Returns the given integer, which must be less than `2^256`, as 32 little-endian bytes.
+    fn to_bytes_le(value: &BigUint) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        let value = value.to_bytes_le();
+        bytes[..value.len()].copy_from_slice(&value);
+        bytes
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Ed25519 {
+    /// Returns `true` if `signature` is a valid Ed25519 signature on `message` under `public_key`.
+    ///
+    /// The signature is encoded as `R || S`, where `R` is a point encoding and `S` is a little-endian integer,
+    /// as in RFC 8032. This checks the cofactorless equation `S * B == R + SHA-512(R || A || M) * A`.
+    pub fn verify(&self, signature: &[u8; 64], public_key: &[u8; 32], message: &[u8]) -> bool {
+        // Ensure `S` is reduced.
+        let s = BigUint::from_bytes_le(&signature[32..]);
+        if s >= self.scalar_modulus {
+            return false;
+        }
+
+        // Decompress `R` and the public key.
+        let mut r_bytes = [0u8; 32];
+        r_bytes.copy_from_slice(&signature[..32]);
+        let (r, a) = match (self.decompress(&r_bytes), self.decompress(public_key)) {
+            (Some(r), Some(a)) => (r, a),
+            _ => return false,
+        };
+
+        // Compute `h = SHA-512(R || A || M) mod l`.
+        let h = self.hash_to_scalar(&[&r_bytes, public_key, message]);
+
+        // Check that `S * B == R + h * A`.
+        self.mul(&self.base, &s) == self.add(&r, &self.mul(&a, &h))
+    }
+
+    /// Returns the public key for the given 32-byte secret key, as in RFC 8032.
+    pub fn public_key(&self, secret_key: &[u8; 32]) -> [u8; 32] {
+        let (a, _) = self.expand(secret_key);
+        self.compress(&self.mul(&self.base, &a))
+    }
+
+    /// Returns the Ed25519 signature `R || S` on `message` under the given 32-byte secret key, as in RFC 8032.
+    pub fn sign(&self, secret_key: &[u8; 32], message: &[u8]) -> [u8; 64] {
+        let (a, prefix) = self.expand(secret_key);
+        let public_key = self.compress(&self.mul(&self.base, &a));
+
+        // Compute the deterministic nonce `r = SHA-512(prefix || M) mod l`, and `R = r * B`.
+        let r = self.hash_to_scalar(&[&prefix, message]);
+        let r_bytes = self.compress(&self.mul(&self.base, &r));
+
+        // Compute `S = r + SHA-512(R || A || M) * a mod l`.
+        let h = self.hash_to_scalar(&[&r_bytes, &public_key, message]);
+        let s = (r + h * a) % &self.scalar_modulus;
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&r_bytes);
+        signature[32..].copy_from_slice(&Self::to_bytes_le(&s));
+        signature
+    }
+
+    /// Returns the clamped secret scalar and the nonce prefix, derived from the given secret key.
+    fn expand(&self, secret_key: &[u8; 32]) -> (BigUint, [u8; 32]) {
+        let hash = Sha512::digest(secret_key);
+        let mut scalar = [0u8; 32];
+        scalar.copy_from_slice(&hash[..32]);
+        // Clear the lowest 3 bits and the highest bit, and set the second highest bit.
+        scalar[0] &= 0xf8;
+        scalar[31] &= 0x7f;
+        scalar[31] |= 0x40;
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&hash[32..]);
+        (BigUint::from_bytes_le(&scalar), prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_curve() {
+        let curve = Ed25519::new();
+        let identity = (BigUint::default(), BigUint::from(1u32));
+
+        // Ensure the base point is on the curve, and has order `l`.
+        assert!(curve.is_on_curve(curve.base()));
+        assert_eq!(identity, curve.mul(curve.base(), curve.scalar_modulus()));
+        // Ensure the encoding of the base point is `y = 4 / 5`.
+        let mut expected = [0x66u8; 32];
+        expected[0] = 0x58;
+        assert_eq!(expected, curve.compress(curve.base()));
+        assert_eq!(Some(curve.base().clone()), curve.decompress(&expected));
+    }
+
+    #[test]
+    fn test_rfc8032_vector() {
+        let curve = Ed25519::new();
+        let decode = |string: &str| hex::decode(string).unwrap();
+
+        // The first test vector of RFC 8032, Section 7.1.
+        let secret_key: [u8; 32] =
+            decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60").try_into().unwrap();
+        let public_key: [u8; 32] =
+            decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a").try_into().unwrap();
+        let signature: [u8; 64] = decode(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        )
+        .try_into()
+        .unwrap();
+
+        assert_eq!(public_key, curve.public_key(&secret_key));
+        assert_eq!(signature, curve.sign(&secret_key, &[]));
+        assert!(curve.verify(&signature, &public_key, &[]));
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let mut rng = TestRng::default();
+        let curve = Ed25519::new();
+
+        for i in 0..ITERATIONS {
+            let secret_key = <[u8; 32]>::rand(&mut rng);
+            let public_key = curve.public_key(&secret_key);
+            let message = (0..i * 20).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+            let signature = curve.sign(&secret_key, &message);
+
+            // Ensure the signature is valid.
+            assert!(curve.verify(&signature, &public_key, &message));
+
+            // Ensure the signature is invalid for a different message.
+            let mut other_message = message.clone();
+            other_message.push(0);
+            assert!(!curve.verify(&signature, &public_key, &other_message));
+
+            // Ensure the signature is invalid for a different public key.
+            let other_public_key = curve.public_key(&<[u8; 32]>::rand(&mut rng));
+            assert!(!curve.verify(&signature, &other_public_key, &message));
+
+            // Ensure a signature with an unreduced `S` is rejected.
+            let mut invalid_signature = signature;
+            invalid_signature[63] |= 0xf0;
+            assert!(!curve.verify(&invalid_signature, &public_key, &message));
+        }
+    }
+}
@@ -31,12 +31,33 @@ impl<const TYPE: u8, const VARIANT: usize> Hash for Keccak<TYPE, VARIANT> {
             (1, 256) => bits_from_bytes_le(&sha3_256_native(&bytes_from_bits_le(input))).collect(),
             (1, 384) => bits_from_bytes_le(&sha3_384_native(&bytes_from_bits_le(input))).collect(),
             (1, 512) => bits_from_bytes_le(&sha3_512_native(&bytes_from_bits_le(input))).collect(),
+            (2, 128) => bits_from_bytes_le(&shake_128_native(&bytes_from_bits_le(input), 32)).collect(),
+            (2, 256) => bits_from_bytes_le(&shake_256_native(&bytes_from_bits_le(input), 64)).collect(),
             _ => unreachable!("Invalid Keccak type and variant"),
         };
         Ok(result)
     }
 }
 
+impl<const VARIANT: usize> HashMany for Keccak<{ KeccakType::Shake as u8 }, VARIANT> {
+    type Input = bool;
+    type Output = bool;
+
+    /// Returns the first `num_outputs` bits of the SHAKE output for the given input.
+    #[inline]
+    fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
+        // Squeeze enough bytes to cover the requested number of bits.
+        let num_bytes = (num_outputs as usize + 7) / 8;
+        let output = match VARIANT {
+            128 => shake_128_native(&bytes_from_bits_le(input), num_bytes),
+            256 => shake_256_native(&bytes_from_bits_le(input), num_bytes),
+            _ => unreachable!("Invalid SHAKE variant"),
+        };
+        // Truncate the output to the requested number of bits.
+        bits_from_bytes_le(&output).take(num_outputs as usize).collect()
+    }
+}
+
 /// Computes the Keccak-224 hash of the given preimage as bytes.
 fn keccak_224_native(preimage: &[u8]) -> [u8; 28] {
     let mut keccak = TinyKeccak::v224();
@@ -117,6 +138,26 @@ fn sha3_512_native(preimage: &[u8]) -> [u8; 64] {
     hash
 }
 
+/// Computes `num_bytes` bytes of SHAKE-128 output for the given preimage.
+fn shake_128_native(preimage: &[u8], num_bytes: usize) -> Vec<u8> {
+    let mut shake = TinyShake::v128();
+    shake.update(preimage);
+
+    let mut output = vec![0u8; num_bytes];
+    shake.finalize(&mut output);
+    output
+}
+
+/// Computes `num_bytes` bytes of SHAKE-256 output for the given preimage.
+fn shake_256_native(preimage: &[u8], num_bytes: usize) -> Vec<u8> {
+    let mut shake = TinyShake::v256();
+    shake.update(preimage);
+
+    let mut output = vec![0u8; num_bytes];
+    shake.finalize(&mut output);
+    output
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,4 +227,51 @@ mod tests {
     fn test_sha3_512_equivalence() {
         check_equivalence!(Sha3_512::default(), sha3_512_native);
     }
+
+    #[test]
+    fn test_shake_128_equivalence() {
+        check_equivalence!(Shake128::default(), |preimage: &[u8]| shake_128_native(preimage, 32));
+    }
+
+    #[test]
+    fn test_shake_256_equivalence() {
+        check_equivalence!(Shake256::default(), |preimage: &[u8]| shake_256_native(preimage, 64));
+    }
+
+    #[test]
+    fn test_shake_known_answers() {
+        // The empty preimage, as specified in the NIST SHA-3 test vectors.
+        let expected_128 = "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26";
+        let expected_256 = "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f";
+
+        let candidate_128 = bytes_from_bits_le(&Shake128::default().hash_many(&[], 256));
+        let candidate_256 = bytes_from_bits_le(&Shake256::default().hash_many(&[], 256));
+        assert_eq!(expected_128, hex::encode(candidate_128));
+        assert_eq!(expected_256, hex::encode(candidate_256));
+    }
+
+    #[test]
+    fn test_shake_hash_many() {
+        let rng = &mut TestRng::default();
+
+        for num_inputs in [1, 8, 64, 1024, 1344, 1345, 2048] {
+            // Prepare the preimage.
+            let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+
+            for num_outputs in [0u16, 1, 7, 8, 255, 256, 1344, 1345, 4096] {
+                let shake_128 = Shake128::default().hash_many(&input, num_outputs);
+                let shake_256 = Shake256::default().hash_many(&input, num_outputs);
+                assert_eq!(shake_128.len(), num_outputs as usize);
+                assert_eq!(shake_256.len(), num_outputs as usize);
+
+                // Ensure the output is a prefix of the longest output.
+                assert_eq!(shake_128[..], Shake128::default().hash_many(&input, 4096)[..num_outputs as usize]);
+                assert_eq!(shake_256[..], Shake256::default().hash_many(&input, 4096)[..num_outputs as usize]);
+            }
+
+            // Ensure the fixed-length hash is the corresponding prefix.
+            assert_eq!(Shake128::default().hash(&input).unwrap(), Shake128::default().hash_many(&input, 256));
+            assert_eq!(Shake256::default().hash(&input).unwrap(), Shake256::default().hash_many(&input, 512));
+        }
+    }
 }
@@ -17,10 +17,10 @@ mod hash;
 #[cfg(test)]
 use snarkvm_utilities::Uniform;
 
-use crate::Hash;
+use crate::{Hash, HashMany};
 use snarkvm_console_types::environment::prelude::*;
 
-use tiny_keccak::{Hasher, Keccak as TinyKeccak, Sha3 as TinySha3};
+use tiny_keccak::{Hasher, Keccak as TinyKeccak, Sha3 as TinySha3, Shake as TinyShake};
 
 /// The Keccak-224 hash function.
 pub type Keccak224 = Keccak<{ KeccakType::Keccak as u8 }, 224>;
@@ -40,10 +40,16 @@ pub type Sha3_384 = Keccak<{ KeccakType::Sha3 as u8 }, 384>;
 /// The SHA3-512 hash function.
 pub type Sha3_512 = Keccak<{ KeccakType::Sha3 as u8 }, 512>;
 
+/// The SHAKE-128 extendable-output function, which outputs 256 bits when used as a `Hash`.
+pub type Shake128 = Keccak<{ KeccakType::Shake as u8 }, 128>;
+/// The SHAKE-256 extendable-output function, which outputs 512 bits when used as a `Hash`.
+pub type Shake256 = Keccak<{ KeccakType::Shake as u8 }, 256>;
+
 /// A helper to specify the hash type.
 enum KeccakType {
     Keccak,
     Sha3,
+    Shake,
 }
 
 /// The sponge construction `Sponge[f, pad, r]` is a function that takes a variable-length input
@@ -59,6 +65,8 @@ enum KeccakType {
 /// where `M` is the input data, and `0x01 || 0x00…0x00 || 0x80` is the padding.
 /// In SHA-3, `pad` is a SHAKE, defined as `pad(M) = M || 0x06 || 0x00…0x00 || 0x80`,
 /// where `M` is the input data, and `0x06 || 0x00…0x00 || 0x80` is the padding.
+/// In SHAKE, `pad` is defined as `pad(M) = M || 0x1F || 0x00…0x00 || 0x80`, and the output
+/// may be squeezed to any length (see `HashMany`).
 ///
 /// The bitrate `r` is the number of bits that are absorbed into the sponge state in each iteration
 /// of the absorbing phase.
@@ -20,11 +20,17 @@
 pub use snarkvm_console_types::prelude::*;
 
 pub mod bhp;
-pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
+pub use bhp::{CustomBHP, BHP, BHP1024, BHP256, BHP512, BHP768};
 
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ecdsa;
+pub use ecdsa::{expand_message_xmd, HashToCurveMethod, Secp256k1, Secp256k1Point};
+
+mod ed25519;
+pub use ed25519::{Ed25519, Ed25519Point};
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
@@ -32,7 +38,10 @@ mod keccak;
 pub use keccak::*;
 
 mod pedersen;
-pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
+pub use pedersen::{CustomPedersen, Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod vrf;
+pub use vrf::{VRFProof, ECVRF};
@@ -20,12 +20,13 @@ impl<E: Environment, const NUM_BITS: u8> HashUncompressed for Pedersen<E, NUM_BI
 
     /// Returns the Pedersen hash of the given input as a group element.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        let num_bits = self.num_bits();
         let mut input = Cow::Borrowed(input);
-        match input.len() <= NUM_BITS as usize {
+        match input.len() <= num_bits {
             // Pad the input if it is under the required parameter size.
-            true => input.to_mut().resize(NUM_BITS as usize, false),
+            true => input.to_mut().resize(num_bits, false),
             // Ensure the input size is within the parameter size,
-            false => bail!("Invalid input size for Pedersen: expected <= {NUM_BITS}, found {}", input.len()),
+            false => bail!("Invalid input size for Pedersen: expected <= {num_bits}, found {}", input.len()),
         }
 
         // Compute sum of h_i^{m_i} for all i.
@@ -26,6 +26,8 @@ use std::{borrow::Cow, sync::Arc};
 pub type Pedersen64<E> = Pedersen<E, 64>;
 /// Pedersen128 is an *additively-homomorphic* collision-resistant hash function that takes up to a 128-bit input.
 pub type Pedersen128<E> = Pedersen<E, 128>;
+/// CustomPedersen is an *additively-homomorphic* collision-resistant hash function whose input size is chosen at runtime.
+pub type CustomPedersen<E> = Pedersen<E, 0>;
 
 /// Pedersen is a collision-resistant hash function that takes a variable-length input.
 /// The Pedersen hash function does *not* behave like a random oracle, see Poseidon for one.
@@ -40,17 +42,22 @@ pub struct Pedersen<E: Environment, const NUM_BITS: u8> {
 impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
     /// Initializes a new instance of Pedersen with the given setup message.
     pub fn setup(message: &str) -> Self {
+        Self::setup_with_num_bits(message, NUM_BITS as usize)
+    }
+
+    /// Initializes a new instance of Pedersen with the given setup message and maximum number of input bits.
+    fn setup_with_num_bits(message: &str, num_bits: usize) -> Self {
         // Construct an indexed message to attempt to sample a base.
         let (generator, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.Pedersen.Base.{message}"));
         // Construct the window with the base.
-        let mut base_window = vec![Group::<E>::zero(); NUM_BITS as usize];
+        let mut base_window = vec![Group::<E>::zero(); num_bits];
         {
             let mut base_power = Group::<E>::new(generator);
-            for base in base_window.iter_mut().take(NUM_BITS as usize) {
+            for base in base_window.iter_mut().take(num_bits) {
                 *base = base_power;
                 base_power = base_power.double();
             }
-            assert_eq!(base_window.len(), NUM_BITS as usize);
+            assert_eq!(base_window.len(), num_bits);
         }
 
         // Compute the random base.
@@ -78,4 +85,60 @@ impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
     pub fn random_base_window(&self) -> &Arc<Vec<Group<E>>> {
         &self.random_base_window
     }
+
+    /// Returns the maximum number of input bits.
+    pub fn num_bits(&self) -> usize {
+        self.base_window.len()
+    }
+}
+
+impl<E: Environment> CustomPedersen<E> {
+    /// Initializes a new instance of Pedersen with the given setup message and maximum number of input bits.
+    pub fn setup_custom(message: &str, num_bits: usize) -> Result<Self> {
+        // Ensure the number of bits is nonzero, and that the inputs cannot overflow the scalar field.
+        ensure!(num_bits > 0, "The number of Pedersen input bits must be nonzero");
+        ensure!(
+            num_bits < Scalar::<E>::size_in_bits(),
+            "The number of Pedersen input bits must be less than {}, found {num_bits}",
+            Scalar::<E>::size_in_bits()
+        );
+        Ok(Self::setup_with_num_bits(message, num_bits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_setup_custom() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure a custom Pedersen with the input size of a preset matches the preset.
+        let pedersen64 = Pedersen64::<CurrentEnvironment>::setup("PedersenTest");
+        let custom = CustomPedersen::<CurrentEnvironment>::setup_custom("PedersenTest", 64)?;
+        assert_eq!(custom.num_bits(), 64);
+        for _ in 0..ITERATIONS {
+            let input = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let randomizer = Uniform::rand(&mut rng);
+            assert_eq!(pedersen64.hash(&input)?, custom.hash(&input)?);
+            assert_eq!(pedersen64.commit(&input, &randomizer)?, custom.commit(&input, &randomizer)?);
+        }
+
+        // Ensure a custom Pedersen supports inputs beyond the presets, and rejects inputs beyond its size.
+        let custom = CustomPedersen::<CurrentEnvironment>::setup_custom("PedersenTest", 200)?;
+        let input = (0..200).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        custom.hash(&input)?;
+        assert!(custom.hash(&[input, vec![true]].concat()).is_err());
+
+        // Ensure invalid parameters are rejected.
+        assert!(CustomPedersen::<CurrentEnvironment>::setup_custom("PedersenTest", 0).is_err());
+        assert!(CustomPedersen::<CurrentEnvironment>::setup_custom("PedersenTest", 1024).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The domain separator for authenticated encryption.
+const AEAD_DOMAIN: &str = "AleoPoseidonAEAD0";
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Encrypts the given plaintext under the given key and nonce, authenticating the associated data,
+    /// and returns the ciphertext and the authentication tag.
+    ///
+    /// The duplex sponge is initialized with `[ DOMAIN || AEAD_DOMAIN || KEY || NONCE || LENGTH(AD) || LENGTH(PLAINTEXT) ]`,
+    /// followed by the associated data. Each block of `RATE` plaintext elements is then masked with
+    /// squeezed elements, and the resulting ciphertext block is absorbed. Finally, the tag is squeezed.
+    ///
+    /// Note: A nonce must never be reused with the same key.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn encrypt(
+        &self,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        associated_data: &[Field<E>],
+        plaintext: &[Field<E>],
+    ) -> (Vec<Field<E>>, Field<E>) {
+        // Initialize the sponge.
+        let mut sponge = self.initialize_aead(key, nonce, associated_data, plaintext.len());
+
+        // Encrypt the plaintext, one block at a time.
+        let mut ciphertext = Vec::with_capacity(plaintext.len());
+        for block in plaintext.chunks(RATE) {
+            // Mask the plaintext block with the squeezed elements.
+            let mask = sponge.squeeze(block.len() as u16);
+            let ciphertext_block = block.iter().zip_eq(&mask).map(|(p, m)| *p + m).collect::<Vec<_>>();
+            // Absorb the ciphertext block.
+            sponge.absorb(&ciphertext_block);
+            ciphertext.extend(ciphertext_block);
+        }
+
+        // Squeeze the authentication tag.
+        (ciphertext, sponge.squeeze(1)[0])
+    }
+
+    /// Decrypts the given ciphertext under the given key and nonce, and returns the plaintext,
+    /// if the tag authenticates both the ciphertext and the associated data.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn decrypt(
+        &self,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        associated_data: &[Field<E>],
+        ciphertext: &[Field<E>],
+        tag: &Field<E>,
+    ) -> Result<Vec<Field<E>>> {
+        // Initialize the sponge.
+        let mut sponge = self.initialize_aead(key, nonce, associated_data, ciphertext.len());
+
+        // Decrypt the ciphertext, one block at a time.
+        let mut plaintext = Vec::with_capacity(ciphertext.len());
+        for block in ciphertext.chunks(RATE) {
+            // Unmask the ciphertext block with the squeezed elements.
+            let mask = sponge.squeeze(block.len() as u16);
+            plaintext.extend(block.iter().zip_eq(&mask).map(|(c, m)| *c - m));
+            // Absorb the ciphertext block.
+            sponge.absorb(block);
+        }
+
+        // Ensure the authentication tag is valid.
+        ensure!(sponge.squeeze(1)[0] == *tag, "Failed to decrypt: the authentication tag is invalid");
+        Ok(plaintext)
+    }
+
+    /// Returns a sponge that has absorbed the key, nonce, lengths, and associated data.
+    fn initialize_aead(
+        &self,
+        key: &Field<E>,
+        nonce: &Field<E>,
+        associated_data: &[Field<E>],
+        message_length: usize,
+    ) -> PoseidonSponge<E, RATE, CAPACITY> {
+        // Construct the preimage: [ DOMAIN || AEAD_DOMAIN || KEY || NONCE || LENGTH(AD) || LENGTH(MESSAGE) || AD ].
+        let mut preimage = Vec::with_capacity(6 + associated_data.len());
+        preimage.push(self.domain);
+        preimage.push(Field::<E>::new_domain_separator(AEAD_DOMAIN));
+        preimage.push(*key);
+        preimage.push(*nonce);
+        preimage.push(Field::<E>::from_u128(associated_data.len() as u128));
+        preimage.push(Field::<E>::from_u128(message_length as u128));
+        preimage.extend_from_slice(associated_data);
+
+        let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
+        sponge.absorb(&preimage);
+        sponge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_aead<const RATE: usize>(rng: &mut TestRng) -> Result<()> {
+        let poseidon = Poseidon::<CurrentEnvironment, RATE>::setup("PoseidonAEADTest")?;
+
+        for num_elements in 0..=(3 * RATE) {
+            for _ in 0..ITERATIONS {
+                let key = Uniform::rand(rng);
+                let nonce = Uniform::rand(rng);
+                let associated_data = (0..rng.gen_range(0..=RATE)).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+                let plaintext = (0..num_elements).map(|_| Uniform::rand(rng)).collect::<Vec<Field<_>>>();
+
+                // Ensure the ciphertext decrypts to the plaintext.
+                let (ciphertext, tag) = poseidon.encrypt(&key, &nonce, &associated_data, &plaintext);
+                assert_eq!(ciphertext.len(), plaintext.len());
+                assert_eq!(plaintext, poseidon.decrypt(&key, &nonce, &associated_data, &ciphertext, &tag)?);
+
+                // Ensure decryption fails with a different key, nonce, or tag.
+                let other = Uniform::rand(rng);
+                assert!(poseidon.decrypt(&other, &nonce, &associated_data, &ciphertext, &tag).is_err());
+                assert!(poseidon.decrypt(&key, &other, &associated_data, &ciphertext, &tag).is_err());
+                assert!(poseidon.decrypt(&key, &nonce, &associated_data, &ciphertext, &other).is_err());
+
+                // Ensure decryption fails with modified associated data.
+                let mut modified_associated_data = associated_data.clone();
+                modified_associated_data.push(Uniform::rand(rng));
+                assert!(poseidon.decrypt(&key, &nonce, &modified_associated_data, &ciphertext, &tag).is_err());
+
+                // Ensure decryption fails with a modified or truncated ciphertext.
+                if !ciphertext.is_empty() {
+                    let mut modified_ciphertext = ciphertext.clone();
+                    let index = rng.gen_range(0..ciphertext.len());
+                    modified_ciphertext[index] += Field::one();
+                    assert!(poseidon.decrypt(&key, &nonce, &associated_data, &modified_ciphertext, &tag).is_err());
+                    let truncated_ciphertext = &ciphertext[..ciphertext.len() - 1];
+                    assert!(poseidon.decrypt(&key, &nonce, &associated_data, truncated_ciphertext, &tag).is_err());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_aead_rate_2() -> Result<()> {
+        check_aead::<2>(&mut TestRng::default())
+    }
+
+    #[test]
+    fn test_aead_rate_4() -> Result<()> {
+        check_aead::<4>(&mut TestRng::default())
+    }
+
+    #[test]
+    fn test_aead_rate_8() -> Result<()> {
+        check_aead::<8>(&mut TestRng::default())
+    }
+}
@@ -14,6 +14,7 @@
 
 mod helpers;
 
+mod aead;
 mod hash;
 mod hash_many;
 mod hash_to_group;
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBytes for VRFProof<E> {
+    /// Reads a VRF proof from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let gamma = Group::read_le(&mut reader)?;
+        let challenge = Scalar::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { gamma, challenge, response })
+    }
+}
+
+impl<E: Environment> ToBytes for VRFProof<E> {
+    /// Writes a VRF proof to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.gamma.write_le(&mut writer)?;
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let vrf = ECVRF::<CurrentEnvironment>::setup("VRFTest")?;
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new proof.
+            let (_, proof) = vrf.prove(&Scalar::rand(&mut rng), &[Field::rand(&mut rng)], &mut rng)?;
+
+            // Check the byte representation.
+            let proof_bytes = proof.to_bytes_le()?;
+            assert_eq!(proof, VRFProof::read_le(&proof_bytes[..])?);
+            assert!(VRFProof::<CurrentEnvironment>::read_le(&proof_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
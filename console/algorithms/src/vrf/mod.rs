@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod prove;
+mod verify;
+
+use crate::Poseidon4;
+use snarkvm_console_types::prelude::*;
+
+/// An elliptic curve verifiable random function (ECVRF), in the style of RFC 9381,
+/// over the prime-order subgroup of the console group, with Poseidon as the hash function.
+///
+/// For a secret key `sk` with public key `pk := G^sk`, the output on an `input` is `Hash(H^sk)`,
+/// where `H := HashToGroup(input)`. The output is unique for every public key and input,
+/// and the accompanying proof shows that it was computed with the secret key of `pk`.
+/// For an account, the secret key is the view key, and the public key is the address.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ECVRF<E: Environment> {
+    /// The hash function for the input, the challenge, and the output.
+    hasher: Poseidon4<E>,
+}
+
+impl<E: Environment> ECVRF<E> {
+    /// Initializes a new instance of the VRF with the given domain separator.
+    pub fn setup(domain: &str) -> Result<Self> {
+        Ok(Self { hasher: Poseidon4::setup(domain)? })
+    }
+
+    /// Returns the hash function of the VRF.
+    pub const fn hasher(&self) -> &Poseidon4<E> {
+        &self.hasher
+    }
+
+    /// Returns the challenge `Hash(pk, H, gamma, G^k, H^k)` as a scalar.
+    fn hash_challenge(
+        &self,
+        public_key: &Group<E>,
+        h: &Group<E>,
+        gamma: &Group<E>,
+        g_k: &Group<E>,
+        h_k: &Group<E>,
+    ) -> Result<Scalar<E>> {
+        self.hasher.hash_to_scalar(&[public_key, h, gamma, g_k, h_k].map(|point| point.to_x_coordinate()))
+    }
+
+    /// Returns the output `Hash(gamma)` of the VRF.
+    fn hash_output(&self, gamma: &Group<E>) -> Result<Field<E>> {
+        // Note: The output is domain-separated from the challenge, as Poseidon absorbs the length of its input.
+        self.hasher.hash(&[gamma.to_x_coordinate()])
+    }
+}
+
+/// A proof that a VRF output was computed with the secret key of a public key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VRFProof<E: Environment> {
+    /// The group element `gamma := H^sk`, from which the output is derived.
+    gamma: Group<E>,
+    /// The verifier challenge to check against.
+    challenge: Scalar<E>,
+    /// The prover response to the challenge.
+    response: Scalar<E>,
+}
+
+impl<E: Environment> From<(Group<E>, Scalar<E>, Scalar<E>)> for VRFProof<E> {
+    /// Derives the VRF proof from a tuple `(gamma, challenge, response)`.
+    fn from((gamma, challenge, response): (Group<E>, Scalar<E>, Scalar<E>)) -> Self {
+        Self { gamma, challenge, response }
+    }
+}
+
+impl<E: Environment> VRFProof<E> {
+    /// Returns the group element `gamma`.
+    pub const fn gamma(&self) -> Group<E> {
+        self.gamma
+    }
+
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<E> {
+        self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> Scalar<E> {
+        self.response
+    }
+}
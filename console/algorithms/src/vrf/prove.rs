@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ECVRF<E> {
+    /// Returns the VRF output and proof for the given secret key and input.
+    pub fn prove<R: Rng + CryptoRng>(
+        &self,
+        secret_key: &Scalar<E>,
+        input: &[Field<E>],
+        rng: &mut R,
+    ) -> Result<(Field<E>, VRFProof<E>)> {
+        // Ensure the secret key is nonzero.
+        ensure!(!secret_key.is_zero(), "The VRF secret key cannot be zero");
+
+        // Compute the public key `pk := G^sk`.
+        let public_key = Group::generator() * secret_key;
+        // Hash the input to a group element `H`.
+        let h = self.hasher.hash_to_group(input)?;
+        // Compute `gamma := H^sk`.
+        let gamma = h * secret_key;
+
+        // Sample a random nonce `k`, and compute `G^k` and `H^k`.
+        let nonce = Scalar::rand(rng);
+        let (g_k, h_k) = (Group::generator() * nonce, h * nonce);
+
+        // Compute the verifier challenge.
+        let challenge = self.hash_challenge(&public_key, &h, &gamma, &g_k, &h_k)?;
+        // Compute the prover response as `k - challenge * sk`.
+        let response = nonce - challenge * secret_key;
+
+        Ok((self.hash_output(&gamma)?, VRFProof { gamma, challenge, response }))
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ECVRF<E> {
+    /// Returns the VRF output for the given public key and input, if the proof is valid.
+    pub fn verify(&self, public_key: &Group<E>, input: &[Field<E>], proof: &VRFProof<E>) -> Result<Field<E>> {
+        // Ensure the public key is not the identity.
+        ensure!(!public_key.is_zero(), "The VRF public key cannot be zero");
+
+        // Hash the input to a group element `H`.
+        let h = self.hasher.hash_to_group(input)?;
+
+        // Compute `G^k` := (response * G) + (challenge * pk).
+        let g_k = Group::generator() * proof.response + *public_key * proof.challenge;
+        // Compute `H^k` := (response * H) + (challenge * gamma).
+        let h_k = h * proof.response + proof.gamma * proof.challenge;
+
+        // Ensure the verifier challenge matches the candidate challenge.
+        let candidate_challenge = self.hash_challenge(public_key, &h, &proof.gamma, &g_k, &h_k)?;
+        ensure!(proof.challenge == candidate_challenge, "The VRF proof is invalid for the given public key and input");
+
+        self.hash_output(&proof.gamma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_prove_and_verify() -> Result<()> {
+        let vrf = ECVRF::<CurrentEnvironment>::setup("VRFTest")?;
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a key pair and an input.
+            let secret_key = Scalar::rand(&mut rng);
+            let public_key = Group::generator() * secret_key;
+            let input = (0..=i % 8).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure a valid proof is accepted, and returns the output.
+            let (output, proof) = vrf.prove(&secret_key, &input, &mut rng)?;
+            assert_eq!(output, vrf.verify(&public_key, &input, &proof)?);
+
+            // Ensure the output is unique, even though the proofs are randomized.
+            let (candidate_output, candidate_proof) = vrf.prove(&secret_key, &input, &mut rng)?;
+            assert_eq!(output, candidate_output);
+            assert_ne!(proof, candidate_proof);
+
+            // Ensure the proof is rejected for a different public key.
+            let other_key = Group::generator() * Scalar::rand(&mut rng);
+            assert!(vrf.verify(&other_key, &input, &proof).is_err());
+
+            // Ensure the proof is rejected for a different input.
+            let mut other_input = input.clone();
+            other_input.push(Field::rand(&mut rng));
+            assert!(vrf.verify(&public_key, &other_input, &proof).is_err());
+
+            // Ensure the proof is rejected for a different gamma.
+            let forged = VRFProof::from((Group::rand(&mut rng), proof.challenge(), proof.response()));
+            assert!(vrf.verify(&public_key, &input, &forged).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_domain_separation() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let secret_key = Scalar::rand(&mut rng);
+        let public_key = Group::generator() * secret_key;
+        let input = [Field::rand(&mut rng)];
+
+        // Ensure the output and proof are bound to the domain.
+        let (output, proof) = ECVRF::<CurrentEnvironment>::setup("VRFTest0")?.prove(&secret_key, &input, &mut rng)?;
+        let vrf = ECVRF::<CurrentEnvironment>::setup("VRFTest1")?;
+        assert_ne!(output, vrf.prove(&secret_key, &input, &mut rng)?.0);
+        assert!(vrf.verify(&public_key, &input, &proof).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod witness;
+pub use witness::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::{LeafHash, MerklePath, PathHash};
+use snarkvm_console_types::prelude::*;
+
+use aleo_std::prelude::*;
+
+/// An append-only Merkle tree of depth `DEPTH`, which stores only its frontier (one hash per level),
+/// instead of every hash in the tree.
+///
+/// The roots of this tree and the Merkle paths of its witnesses are identical to those of a [`MerkleTree`]
+/// with the same leaves. An [`IncrementalWitness`] is updated in constant time for each appended leaf,
+/// which allows a wallet to keep its record inclusion witnesses current without the full Merkle tree.
+///
+/// [`MerkleTree`]: crate::merkle_tree::MerkleTree
+#[derive(Clone)]
+pub struct IncrementalMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The computed root of the full Merkle tree.
+    root: PH::Hash,
+    /// The most recent left child at each height, from the leaf level to the level below the root.
+    frontier: Vec<PH::Hash>,
+    /// The hash of an empty subtree at each height, from the leaf level to the root level.
+    empty_hashes: Vec<PH::Hash>,
+    /// The number of hashed leaves in the tree.
+    number_of_leaves: u64,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    IncrementalMerkleTree<E, LH, PH, DEPTH>
+{
+    #[inline]
+    /// Initializes a new, empty incremental Merkle tree.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the empty hash for each height, starting from the empty leaf hash.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for height in 0..DEPTH as usize {
+            let empty_hash = empty_hashes[height];
+            empty_hashes.push(path_hasher.hash_children(&empty_hash, &empty_hash)?);
+        }
+
+        // Compute the root hash of the empty Merkle tree, as in `MerkleTree`.
+        let mut root_hash = empty_hashes[0];
+        for _ in 0..DEPTH {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = path_hasher.hash_children(&root_hash, &empty_hashes[0])?;
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: root_hash,
+            frontier: empty_hashes[..DEPTH as usize].to_vec(),
+            empty_hashes,
+            number_of_leaves: 0,
+        })
+    }
+
+    #[inline]
+    /// Appends the given leaves to the Merkle tree.
+    pub fn append(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
+        self.append_with_witnesses(new_leaves, &mut [])
+    }
+
+    #[inline]
+    /// Appends the given leaves to the Merkle tree, and updates the given witnesses to include them.
+    ///
+    /// Each witness must be up to date with the current Merkle tree.
+    /// If an error occurs, the Merkle tree and the witnesses are left unchanged.
+    pub fn append_with_witnesses(
+        &mut self,
+        new_leaves: &[LH::Leaf],
+        witnesses: &mut [IncrementalWitness<E, DEPTH>],
+    ) -> Result<()> {
+        let timer = timer!("IncrementalMerkleTree::append_with_witnesses");
+
+        // Ensure each witness is up to date with the Merkle tree.
+        for witness in witnesses.iter() {
+            ensure!(
+                witness.number_of_leaves() == self.number_of_leaves,
+                "The witness for leaf {} is not up to date with the Merkle tree",
+                witness.leaf_index()
+            );
+        }
+        // Ensure the new leaves fit in the Merkle tree.
+        let number_of_leaves = self.number_of_leaves.checked_add(u64::try_from(new_leaves.len())?);
+        let number_of_leaves = match number_of_leaves {
+            Some(number_of_leaves) if (number_of_leaves as u128) <= (1u128 << DEPTH) => number_of_leaves,
+            _ => bail!("Merkle tree cannot exceed {} leaves", 1u128 << DEPTH),
+        };
+
+        // Compute the leaf hashes.
+        let leaf_hashes = self.leaf_hasher.hash_leaves(new_leaves)?;
+        lap!(timer, "Hashed {} new leaves", new_leaves.len());
+
+        // Prepare the updated frontier and witnesses.
+        let mut frontier = self.frontier.clone();
+        let mut updated_witnesses = witnesses.to_vec();
+        let mut latest_path = Vec::new();
+
+        for (leaf_index, leaf_hash) in (self.number_of_leaves..number_of_leaves).zip_eq(leaf_hashes) {
+            // Compute the hashes on the path from the new leaf to the root, padding the right with empty hashes.
+            latest_path = Vec::with_capacity(DEPTH as usize + 1);
+            let mut current_hash = leaf_hash;
+            for height in 0..DEPTH {
+                latest_path.push(current_hash);
+                current_hash = match (leaf_index >> height) & 1 == 0 {
+                    // If the current hash is a left child, store it in the frontier.
+                    true => {
+                        frontier[height as usize] = current_hash;
+                        self.path_hasher.hash_children(&current_hash, &self.empty_hashes[height as usize])?
+                    }
+                    // If the current hash is a right child, its left sibling is complete and in the frontier.
+                    false => self.path_hasher.hash_children(&frontier[height as usize], &current_hash)?,
+                };
+            }
+            latest_path.push(current_hash);
+
+            // Update each witness with the path of the new leaf.
+            updated_witnesses.iter_mut().for_each(|witness| witness.update(leaf_index, &latest_path));
+        }
+        lap!(timer, "Hashed {} paths", new_leaves.len());
+
+        // Compute the root of the updated Merkle tree.
+        let root = match latest_path.is_empty() {
+            true => self.root,
+            false => {
+                // Note: The subtree of all leaves contains the latest leaf, so it is on the path of the latest leaf.
+                let depth = subtree_depth(number_of_leaves) as usize;
+                self.compute_root_from(latest_path[depth], depth)?
+            }
+        };
+
+        // Apply the updates.
+        self.root = root;
+        self.frontier = frontier;
+        self.number_of_leaves = number_of_leaves;
+        witnesses.clone_from_slice(&updated_witnesses);
+
+        finish!(timer);
+        Ok(())
+    }
+
+    #[inline]
+    /// Returns a witness for the most recently appended leaf.
+    pub fn witness(&self) -> Result<IncrementalWitness<E, DEPTH>> {
+        // Ensure the Merkle tree is not empty.
+        ensure!(self.number_of_leaves > 0, "Cannot witness a leaf in an empty Merkle tree");
+        // Compute the index of the most recently appended leaf.
+        let leaf_index = self.number_of_leaves - 1;
+        // Compute the siblings of the leaf, where each left sibling is complete and in the frontier,
+        // and each right sibling is empty.
+        let siblings = (0..DEPTH)
+            .map(|height| match (leaf_index >> height) & 1 == 0 {
+                true => self.empty_hashes[height as usize],
+                false => self.frontier[height as usize],
+            })
+            .collect();
+        Ok(IncrementalWitness::new(leaf_index, siblings, self.number_of_leaves, self.empty_hashes[0]))
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the empty hash.
+    pub fn empty_hash(&self) -> &PH::Hash {
+        &self.empty_hashes[0]
+    }
+
+    /// Returns the number of leaves in the Merkle tree.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.number_of_leaves
+    }
+
+    /// Returns the root for the given hash of the subtree of all leaves, at the given subtree depth.
+    /// As in `MerkleTree`, the levels above the subtree are padded with the empty hash.
+    fn compute_root_from(&self, subtree_hash: PH::Hash, depth: usize) -> Result<PH::Hash> {
+        let mut root_hash = subtree_hash;
+        for _ in depth..DEPTH as usize {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hashes[0])?;
+        }
+        Ok(root_hash)
+    }
+}
+
+/// Returns the depth of the smallest complete subtree that holds the given number of leaves.
+/// This matches the depth of the (unpadded) tree in `MerkleTree`.
+fn subtree_depth(number_of_leaves: u64) -> u32 {
+    match number_of_leaves.checked_next_power_of_two() {
+        Some(max_leaves) => max_leaves.trailing_zeros(),
+        None => u64::BITS,
+    }
+}
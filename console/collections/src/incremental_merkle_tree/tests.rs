@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::merkle_tree::MerkleTree;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 33;
+
+macro_rules! run_tests {
+    ($rng:expr, [$($i:expr),*]) => {
+        $( assert!(run_test::<$i>($rng).is_ok()); )*
+    };
+}
+
+/// Runs the following test:
+/// 1. Append the leaves to an incremental Merkle tree, one at a time, creating a witness for each leaf.
+/// 2. After each append, check that the root matches the root of a `MerkleTree` with the same leaves.
+/// 3. After each append, check that every witness yields the same Merkle path as the `MerkleTree`.
+/// 4. Check that appending the leaves in one batch produces the same Merkle root.
+fn check_incremental_merkle_tree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the empty incremental Merkle tree.
+    let mut tree = IncrementalMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    assert_eq!(merkle_tree.root(), tree.root());
+    assert_eq!(merkle_tree.empty_hash(), tree.empty_hash());
+    assert!(tree.witness().is_err());
+
+    let mut witnesses = Vec::with_capacity(leaves.len());
+    for (i, leaf) in leaves.iter().enumerate() {
+        // Append the leaf, updating the existing witnesses.
+        tree.append_with_witnesses(std::slice::from_ref(leaf), &mut witnesses)?;
+        // Create a witness for the new leaf.
+        witnesses.push(tree.witness()?);
+
+        // Check that the Merkle root matches.
+        let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..=i])?;
+        assert_eq!(merkle_tree.root(), tree.root());
+        assert_eq!(i + 1, usize::try_from(tree.number_of_leaves())?);
+
+        // Check that every witness is up to date.
+        for (leaf_index, (leaf, witness)) in leaves.iter().zip(&witnesses).enumerate() {
+            let path = witness.path()?;
+            assert_eq!(merkle_tree.prove(leaf_index, leaf)?, path);
+            assert!(path.verify(leaf_hasher, path_hasher, tree.root(), leaf));
+        }
+    }
+
+    // Check that appending the leaves in one batch produces the same Merkle root.
+    let mut batched = IncrementalMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+    batched.append(leaves)?;
+    assert_eq!(tree.root(), batched.root());
+    Ok(())
+}
+
+#[test]
+fn test_incremental_merkle_tree_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), ITERATIONS as u128);
+
+        // Check the incremental Merkle tree.
+        check_incremental_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &(0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>(),
+        )
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Ensure DEPTH > 64 fails.
+    assert!(run_test::<65>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 6, 7, 8, 16, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_incremental_merkle_tree_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), ITERATIONS as u128);
+
+        // Check the incremental Merkle tree.
+        check_incremental_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &(0..num_leaves).map(|_| vec![Field::<CurrentEnvironment>::rand(rng)]).collect::<Vec<_>>(),
+        )
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Ensure DEPTH > 64 fails.
+    assert!(run_test::<65>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 6, 7, 8, 16, 32, 64]);
+    Ok(())
+}
+
+#[test]
+fn test_append_failures() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut tree = IncrementalMerkleTree::<CurrentEnvironment, LH, PH, 2>::new(&leaf_hasher, &path_hasher)?;
+    let mut leaves = (0..6).map(|_| vec![Field::rand(&mut rng)]);
+
+    // Append a leaf, and create a witness for it.
+    tree.append(&[leaves.next().unwrap()])?;
+    let mut witnesses = vec![tree.witness()?];
+    let root = *tree.root();
+
+    // Ensure a witness that is not up to date is rejected, and the Merkle tree is left unchanged.
+    tree.append(&[leaves.next().unwrap()])?;
+    let stale = witnesses.clone();
+    assert!(tree.append_with_witnesses(&[leaves.next().unwrap()], &mut witnesses).is_err());
+    assert_eq!(stale, witnesses);
+    assert_eq!(2, tree.number_of_leaves());
+    assert_ne!(root, *tree.root());
+
+    // Ensure the Merkle tree cannot exceed its capacity.
+    assert!(tree.append(&leaves.by_ref().take(3).collect::<Vec<_>>()).is_err());
+    assert_eq!(2, tree.number_of_leaves());
+    Ok(())
+}
+
+#[test]
+fn test_witness_bytes() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut tree = IncrementalMerkleTree::<CurrentEnvironment, LH, PH, 32>::new(&leaf_hasher, &path_hasher)?;
+    for _ in 0..ITERATIONS {
+        tree.append(&[vec![Field::rand(&mut rng)]])?;
+
+        // Check the byte representation.
+        let expected = tree.witness()?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, IncrementalWitness::read_le(&expected_bytes[..])?);
+    }
+    Ok(())
+}
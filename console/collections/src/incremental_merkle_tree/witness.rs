@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle path for a leaf in an [`IncrementalMerkleTree`], which is kept up to date as new leaves are appended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncrementalWitness<E: Environment, const DEPTH: u8> {
+    /// The leaf index for the witness.
+    leaf_index: u64,
+    /// The sibling hashes from the leaf to the root, where each right sibling includes the leaves appended so far.
+    siblings: Vec<Field<E>>,
+    /// The number of leaves in the Merkle tree that the witness is up to date with.
+    number_of_leaves: u64,
+    /// The canonical empty hash.
+    empty_hash: Field<E>,
+}
+
+impl<E: Environment, const DEPTH: u8> IncrementalWitness<E, DEPTH> {
+    /// Initializes a new witness for the given leaf index.
+    pub(super) fn new(leaf_index: u64, siblings: Vec<Field<E>>, number_of_leaves: u64, empty_hash: Field<E>) -> Self {
+        Self { leaf_index, siblings, number_of_leaves, empty_hash }
+    }
+
+    /// Returns the leaf index for the witness.
+    pub const fn leaf_index(&self) -> u64 {
+        self.leaf_index
+    }
+
+    /// Returns the number of leaves in the Merkle tree that the witness is up to date with.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.number_of_leaves
+    }
+
+    /// Returns the Merkle path for the leaf, in the Merkle tree that the witness is up to date with.
+    pub fn path(&self) -> Result<MerklePath<E, DEPTH>> {
+        // Compute the depth of the subtree of all leaves.
+        let depth = subtree_depth(self.number_of_leaves) as usize;
+        // Use the siblings within the subtree, and pad the path with the empty hash, as in `MerkleTree`.
+        let mut path = self.siblings[..depth.min(DEPTH as usize)].to_vec();
+        path.resize(DEPTH as usize, self.empty_hash);
+        // Return the Merkle path.
+        MerklePath::try_from((U64::new(self.leaf_index), path))
+    }
+
+    /// Updates the witness with the hashes on the path of the newly appended leaf, from the leaf level to the root level.
+    pub(super) fn update(&mut self, new_leaf_index: u64, new_leaf_path: &[Field<E>]) {
+        // The new leaf is in the right sibling at the height of the highest bit in which the leaf indices differ.
+        let height = (u64::BITS - 1 - (self.leaf_index ^ new_leaf_index).leading_zeros()) as usize;
+        // Update the right sibling with the new hash at that height.
+        self.siblings[height] = new_leaf_path[height];
+        // Increment the number of leaves.
+        self.number_of_leaves += 1;
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for IncrementalWitness<E, DEPTH> {
+    /// Reads in an incremental witness from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the leaf index.
+        let leaf_index = u64::read_le(&mut reader)?;
+        // Read the number of leaves.
+        let number_of_leaves = u64::read_le(&mut reader)?;
+        // Read the empty hash.
+        let empty_hash = Field::read_le(&mut reader)?;
+        // Read the siblings.
+        let siblings = (0..DEPTH).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Ensure the Merkle tree depth is within bounds.
+        if DEPTH == 0 || DEPTH > 64 {
+            return Err(error("Merkle tree depth must be between 1 and 64"));
+        }
+        // Ensure the leaf index is within the Merkle tree.
+        if leaf_index >= number_of_leaves || (number_of_leaves as u128) > (1u128 << DEPTH) {
+            return Err(error("Found an out of bounds leaf index in the incremental witness"));
+        }
+        // Return the witness.
+        Ok(Self::new(leaf_index, siblings, number_of_leaves, empty_hash))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for IncrementalWitness<E, DEPTH> {
+    /// Writes the incremental witness to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the leaf index.
+        self.leaf_index.write_le(&mut writer)?;
+        // Write the number of leaves.
+        self.number_of_leaves.write_le(&mut writer)?;
+        // Write the empty hash.
+        self.empty_hash.write_le(&mut writer)?;
+        // Write the siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
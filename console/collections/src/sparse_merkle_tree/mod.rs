@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod path;
+pub use path::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::{LeafHash, PathHash};
+use snarkvm_console_types::prelude::*;
+
+use aleo_std::prelude::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A sparse Merkle tree of depth `DEPTH`, where each leaf is stored at the position given by its key.
+///
+/// A key is a field element whose value is less than `2^DEPTH`, and the bits of the key (in little-endian order)
+/// select the path from the leaf to the root. As every position in the tree is determined by its key,
+/// the tree can prove that a key is **not** present, by proving that its position holds the empty hash.
+#[derive(Clone)]
+pub struct SparseMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The computed root of the full Merkle tree.
+    root: PH::Hash,
+    /// The leaves of the Merkle tree, keyed by their position.
+    leaves: BTreeMap<Field<E>, LH::Leaf>,
+    /// The non-empty hashes of the Merkle tree, keyed by `(height, key bits from the height up to the root)`.
+    nodes: BTreeMap<(u8, Vec<bool>), PH::Hash>,
+    /// The hash of an empty subtree at each height, from the leaf level (height 0) to the root level.
+    empty_hashes: Vec<PH::Hash>,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    SparseMerkleTree<E, LH, PH, DEPTH>
+{
+    #[inline]
+    /// Initializes a new, empty sparse Merkle tree.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is at most the number of bits in a field element.
+        ensure!(
+            DEPTH as usize <= Field::<E>::size_in_bits(),
+            "Merkle tree depth must be less than or equal to {}",
+            Field::<E>::size_in_bits()
+        );
+
+        // Compute the empty hash for each height, starting from the empty leaf hash.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for height in 0..DEPTH as usize {
+            let empty_hash = empty_hashes[height];
+            empty_hashes.push(path_hasher.hash_children(&empty_hash, &empty_hash)?);
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hashes[DEPTH as usize],
+            leaves: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+            empty_hashes,
+        })
+    }
+
+    #[inline]
+    /// Initializes a new sparse Merkle tree with the given leaves.
+    pub fn new_with_leaves(leaf_hasher: &LH, path_hasher: &PH, leaves: &BTreeMap<Field<E>, LH::Leaf>) -> Result<Self> {
+        // Initialize an empty Merkle tree.
+        let mut tree = Self::new(leaf_hasher, path_hasher)?;
+        // Insert the leaves, if any.
+        if !leaves.is_empty() {
+            tree.update_many(&leaves.iter().map(|(key, leaf)| (*key, Some(leaf.clone()))).collect())?;
+        }
+        Ok(tree)
+    }
+
+    #[inline]
+    /// Inserts the given leaf at the given key, replacing the existing leaf if there is one.
+    pub fn insert(&mut self, key: Field<E>, leaf: LH::Leaf) -> Result<()> {
+        self.update_many(&[(key, Some(leaf))].into_iter().collect())
+    }
+
+    #[inline]
+    /// Removes the leaf at the given key, returning it if it exists.
+    pub fn remove(&mut self, key: &Field<E>) -> Result<Option<LH::Leaf>> {
+        // Retrieve the leaf, if it exists.
+        let leaf = self.leaves.get(key).cloned();
+        // Remove the leaf from the Merkle tree.
+        if leaf.is_some() {
+            self.update_many(&[(*key, None)].into_iter().collect())?;
+        }
+        Ok(leaf)
+    }
+
+    #[inline]
+    /// Updates the Merkle tree at the given keys, where `Some(leaf)` inserts or replaces a leaf,
+    /// and `None` removes the leaf. Each affected hash is recomputed once, regardless of the number of updates.
+    ///
+    /// If an error occurs, the Merkle tree is left unchanged.
+    pub fn update_many(&mut self, updates: &BTreeMap<Field<E>, Option<LH::Leaf>>) -> Result<()> {
+        let timer = timer!("SparseMerkleTree::update_many");
+
+        // Check that there are updates to perform.
+        ensure!(!updates.is_empty(), "There must be at least one leaf to update in the Merkle tree");
+
+        // Compute the position of each update.
+        let positions = updates.keys().map(key_to_bits::<E, DEPTH>).collect::<Result<Vec<_>>>()?;
+
+        // Compute the leaf hash of each inserted leaf.
+        let inserted = updates.values().filter_map(|leaf| leaf.clone()).collect::<Vec<_>>();
+        let mut inserted_hashes = self.leaf_hasher.hash_leaves(&inserted)?.into_iter();
+        lap!(timer, "Hashed {} new leaves", inserted.len());
+
+        // Initialize the updated hashes, starting with the leaf hashes.
+        let mut changes = BTreeMap::new();
+        for (position, leaf) in positions.into_iter().zip_eq(updates.values()) {
+            let leaf_hash = match leaf {
+                // Note: This unwrap is safe since there is one leaf hash for each inserted leaf.
+                Some(_) => inserted_hashes.next().unwrap(),
+                None => self.empty_hashes[0],
+            };
+            changes.insert((0u8, position), leaf_hash);
+        }
+
+        // Compute the updated hashes for each level, iterating from the leaf level to the root level.
+        let mut dirty = changes.keys().map(|(_, position)| position.clone()).collect::<BTreeSet<_>>();
+        for height in 0..DEPTH {
+            // Compute the positions of the parents of the updated hashes.
+            dirty = dirty.into_iter().map(|position| position[1..].to_vec()).collect();
+
+            // Construct the children for each updated parent.
+            let tuples = dirty
+                .iter()
+                .map(|position| {
+                    let child = |bit: bool| {
+                        let mut child = Vec::with_capacity(position.len() + 1);
+                        child.push(bit);
+                        child.extend_from_slice(position);
+                        self.get_node(&changes, height, child)
+                    };
+                    (child(false), child(true))
+                })
+                .collect::<Vec<_>>();
+
+            // Compute and store the hashes for each updated parent.
+            for (position, hash) in dirty.iter().zip_eq(self.path_hasher.hash_all_children(&tuples)?) {
+                changes.insert((height + 1, position.clone()), hash);
+            }
+        }
+        lap!(timer, "Hashed {} levels", DEPTH);
+
+        // Apply the updated hashes, removing the hashes of empty subtrees.
+        for ((height, position), hash) in changes {
+            match hash == self.empty_hashes[height as usize] {
+                true => self.nodes.remove(&(height, position)),
+                false => self.nodes.insert((height, position), hash),
+            };
+        }
+        // Apply the updated leaves.
+        for (key, leaf) in updates {
+            match leaf {
+                Some(leaf) => self.leaves.insert(*key, leaf.clone()),
+                None => self.leaves.remove(key),
+            };
+        }
+        // Update the root.
+        self.root = self.get_node(&BTreeMap::new(), DEPTH, vec![]);
+
+        finish!(timer);
+        Ok(())
+    }
+
+    #[inline]
+    /// Returns a Merkle path for the given key.
+    /// If the key is present, the path is a proof of membership for its leaf,
+    /// otherwise the path is a proof of non-membership for the key.
+    pub fn prove(&self, key: &Field<E>) -> Result<SparseMerklePath<E, DEPTH>> {
+        // Compute the position of the key.
+        let position = key_to_bits::<E, DEPTH>(key)?;
+
+        // Iterate from the leaf level to the root level, storing the sibling hashes along the path.
+        let siblings = (0..DEPTH)
+            .map(|height| {
+                let mut sibling = position[height as usize..].to_vec();
+                sibling[0] = !sibling[0];
+                self.get_node(&BTreeMap::new(), height, sibling)
+            })
+            .collect();
+
+        // Return the Merkle path.
+        SparseMerklePath::try_from(siblings)
+    }
+
+    /// Returns the Merkle root.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the leaf at the given key, if it exists.
+    pub fn get(&self, key: &Field<E>) -> Option<&LH::Leaf> {
+        self.leaves.get(key)
+    }
+
+    /// Returns `true` if the given key has a leaf in the Merkle tree.
+    pub fn contains_key(&self, key: &Field<E>) -> bool {
+        self.leaves.contains_key(key)
+    }
+
+    /// Returns the leaves of the Merkle tree, in order of their keys.
+    pub fn leaves(&self) -> impl '_ + Iterator<Item = (&Field<E>, &LH::Leaf)> {
+        self.leaves.iter()
+    }
+
+    /// Returns the number of leaves in the Merkle tree.
+    pub fn number_of_leaves(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns the hash at the given height and position, reading from the given changes first.
+    fn get_node(&self, changes: &BTreeMap<(u8, Vec<bool>), PH::Hash>, height: u8, position: Vec<bool>) -> PH::Hash {
+        let index = (height, position);
+        match changes.get(&index).or_else(|| self.nodes.get(&index)) {
+            Some(hash) => *hash,
+            None => self.empty_hashes[height as usize],
+        }
+    }
+}
+
+/// Returns the position of the given key, as `DEPTH` bits in little-endian order.
+fn key_to_bits<E: Environment, const DEPTH: u8>(key: &Field<E>) -> Result<Vec<bool>> {
+    let mut bits = key.to_bits_le();
+    // Ensure the key is within the tree depth.
+    ensure!(bits.iter().skip(DEPTH as usize).all(|bit| !bit), "Found an out of bounds sparse Merkle tree key");
+    // Truncate the bits to the tree depth.
+    bits.truncate(DEPTH as usize);
+    Ok(bits)
+}
@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseMerklePath<E: Environment, const DEPTH: u8> {
+    /// The `siblings` contains a list of sibling hashes from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<Vec<Field<E>>> for SparseMerklePath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a sparse Merkle path.
+    fn try_from(siblings: Vec<Field<E>>) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is at most the number of bits in a field element.
+        ensure!(
+            DEPTH as usize <= Field::<E>::size_in_bits(),
+            "Merkle tree depth must be less than or equal to {}",
+            Field::<E>::size_in_bits()
+        );
+        // Ensure the Merkle path is the correct length.
+        ensure!(siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
+        // Return the Merkle path.
+        Ok(Self { siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> SparseMerklePath<E, DEPTH> {
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle path proves that the given leaf is stored at the given key, for the given root.
+    pub fn verify_membership<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+        leaf: &LH::Leaf,
+    ) -> bool {
+        // Compute the leaf hash.
+        match leaf_hasher.hash_leaf(leaf) {
+            Ok(leaf_hash) => self.verify_leaf_hash(path_hasher, root, key, leaf_hash),
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaf during verification: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the Merkle path proves that no leaf is stored at the given key, for the given root.
+    pub fn verify_non_membership<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+    ) -> bool {
+        // Compute the empty leaf hash.
+        match path_hasher.hash_empty() {
+            Ok(empty_hash) => self.verify_leaf_hash(path_hasher, root, key, empty_hash),
+            Err(error) => {
+                eprintln!("Failed to hash the empty Merkle leaf during verification: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root, key, and leaf hash.
+    fn verify_leaf_hash<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        key: &Field<E>,
+        leaf_hash: PH::Hash,
+    ) -> bool {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            eprintln!("Found an incorrect Merkle path length");
+            return false;
+        }
+
+        // Compute the position of the key.
+        let position = match key_to_bits::<E, DEPTH>(key) {
+            Ok(position) => position,
+            Err(error) => {
+                eprintln!("{error}");
+                return false;
+            }
+        };
+
+        // Initialize a tracker for the current hash, starting from the leaf hash.
+        let mut current_hash = leaf_hash;
+
+        // Check levels between leaf level and root.
+        // If the position bit is `false`, then the ordering is (current_hash, sibling_hash).
+        // If the position bit is `true`, then the ordering is (sibling_hash, current_hash).
+        for (bit, sibling_hash) in position.into_iter().zip_eq(&self.siblings) {
+            // Construct the ordering of the left & right child hash for this level.
+            let (left, right) = match bit {
+                false => (current_hash, *sibling_hash),
+                true => (*sibling_hash, current_hash),
+            };
+            // Update the current hash for the next level.
+            match path_hasher.hash_children(&left, &right) {
+                Ok(hash) => current_hash = hash,
+                Err(error) => {
+                    eprintln!("Failed to hash the Merkle path during verification: {error}");
+                    return false;
+                }
+            }
+        }
+
+        // Ensure the final hash matches the given root.
+        current_hash == *root
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for SparseMerklePath<E, DEPTH> {
+    /// Reads in a sparse Merkle path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the Merkle path siblings.
+        let siblings =
+            (0..DEPTH).map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle path.
+        Self::try_from(siblings).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for SparseMerklePath<E, DEPTH> {
+    /// Writes the sparse Merkle path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the Merkle path siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> Serialize for SparseMerklePath<E, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize(self, serializer)
+    }
+}
+
+impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for SparseMerklePath<E, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Compute the size for: Field::SIZE_IN_BYTES * DEPTH.
+        let size = DEPTH as usize * (Field::<E>::size_in_bits() + 7) / 8;
+        FromBytesDeserializer::<Self>::deserialize(deserializer, "sparse Merkle path", size)
+    }
+}
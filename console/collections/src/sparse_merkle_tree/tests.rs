@@ -0,0 +1,305 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+macro_rules! run_tests {
+    ($rng:expr, [$($i:expr),*]) => {
+        $( assert!(run_test::<$i>($rng).is_ok()); )*
+    };
+}
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Insert the leaves into a sparse Merkle tree, one at a time.
+/// 2. Check that the Merkle proof for every leaf is a valid proof of membership.
+/// 3. Check that the Merkle proof for every absent key is a valid proof of non-membership.
+/// 4. Check that the same leaves inserted in one batch produce the same Merkle root.
+/// 5. Remove every leaf, and check that the Merkle root is the empty root.
+fn check_sparse_merkle_tree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &BTreeMap<Field<E>, LH::Leaf>,
+    absent_keys: &[Field<E>],
+) -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Construct the empty sparse Merkle tree.
+    let mut merkle_tree = SparseMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+    let empty_root = *merkle_tree.root();
+
+    // Insert each leaf in the sparse Merkle tree.
+    for (key, leaf) in leaves {
+        merkle_tree.insert(*key, leaf.clone())?;
+    }
+    assert_eq!(leaves.len(), merkle_tree.number_of_leaves());
+
+    // Check each leaf in the sparse Merkle tree.
+    for (key, leaf) in leaves {
+        assert_eq!(Some(leaf), merkle_tree.get(key));
+        // Compute a Merkle proof for the leaf.
+        let proof = merkle_tree.prove(key)?;
+        // Verify the proof of membership succeeds.
+        assert!(proof.verify_membership(leaf_hasher, path_hasher, merkle_tree.root(), key, leaf));
+        // Verify the proof of membership **fails** on an invalid root.
+        assert!(!proof.verify_membership(leaf_hasher, path_hasher, &PH::Hash::zero(), key, leaf));
+        assert!(!proof.verify_membership(leaf_hasher, path_hasher, &PH::Hash::rand(&mut rng), key, leaf));
+        // Verify the proof of non-membership **fails**.
+        assert!(!proof.verify_non_membership(path_hasher, merkle_tree.root(), key));
+    }
+
+    // Check each absent key in the sparse Merkle tree.
+    for key in absent_keys {
+        assert!(!merkle_tree.contains_key(key));
+        // Compute a Merkle proof for the key.
+        let proof = merkle_tree.prove(key)?;
+        // Verify the proof of non-membership succeeds.
+        assert!(proof.verify_non_membership(path_hasher, merkle_tree.root(), key));
+        // Verify the proof of non-membership **fails** on an invalid root.
+        assert!(!proof.verify_non_membership(path_hasher, &PH::Hash::rand(&mut rng), key));
+        // Verify the proof does not prove membership of any of the leaves.
+        for leaf in leaves.values() {
+            assert!(!proof.verify_membership(leaf_hasher, path_hasher, merkle_tree.root(), key, leaf));
+        }
+    }
+
+    // Check that inserting the leaves in one batch produces the same Merkle root.
+    let batched = SparseMerkleTree::<E, LH, PH, DEPTH>::new_with_leaves(leaf_hasher, path_hasher, leaves)?;
+    assert_eq!(merkle_tree.root(), batched.root());
+
+    // Remove each leaf from the sparse Merkle tree.
+    for (key, leaf) in leaves {
+        assert_eq!(Some(leaf.clone()), merkle_tree.remove(key)?);
+        assert_eq!(None, merkle_tree.remove(key)?);
+    }
+    assert_eq!(0, merkle_tree.number_of_leaves());
+    assert_eq!(empty_root, *merkle_tree.root());
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct a sparse Merkle tree with the given leaves.
+/// 2. Apply a batch of insertions, replacements, and removals.
+/// 3. Check that the Merkle root matches a sparse Merkle tree constructed with only the resulting leaves.
+fn check_update_many<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &BTreeMap<Field<E>, LH::Leaf>,
+    updates: &BTreeMap<Field<E>, Option<LH::Leaf>>,
+) -> Result<()> {
+    // Construct the sparse Merkle tree and apply the updates.
+    let mut merkle_tree = SparseMerkleTree::<E, LH, PH, DEPTH>::new_with_leaves(leaf_hasher, path_hasher, leaves)?;
+    merkle_tree.update_many(updates)?;
+
+    // Compute the resulting leaves.
+    let mut expected_leaves = leaves.clone();
+    for (key, leaf) in updates {
+        match leaf {
+            Some(leaf) => expected_leaves.insert(*key, leaf.clone()),
+            None => expected_leaves.remove(key),
+        };
+    }
+
+    // Construct the expected sparse Merkle tree, and check that the Merkle roots match.
+    let expected = SparseMerkleTree::<E, LH, PH, DEPTH>::new_with_leaves(leaf_hasher, path_hasher, &expected_leaves)?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected_leaves.len(), merkle_tree.number_of_leaves());
+
+    // Check that every resulting leaf has a valid proof of membership.
+    for (key, leaf) in &expected_leaves {
+        let proof = merkle_tree.prove(key)?;
+        assert!(proof.verify_membership(leaf_hasher, path_hasher, merkle_tree.root(), key, leaf));
+    }
+    Ok(())
+}
+
+/// Samples `num_keys` distinct keys that are less than `2^DEPTH`.
+fn sample_keys<E: Environment, const DEPTH: u8>(num_keys: usize, rng: &mut TestRng) -> Vec<Field<E>> {
+    let mut keys = BTreeSet::new();
+    while keys.len() < num_keys {
+        let mut bits = Field::<E>::rand(rng).to_bits_le();
+        bits.truncate(DEPTH as usize);
+        keys.insert(Field::from_bits_le(&bits).unwrap());
+    }
+    keys.into_iter().collect()
+}
+
+#[test]
+fn test_sparse_merkle_tree_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves and absent keys.
+            let num_leaves = core::cmp::min(1usize << DEPTH.min(16), i);
+            let num_absent = core::cmp::min((1usize << DEPTH.min(16)) - num_leaves, 3);
+
+            // Sample the keys, and split them into leaves and absent keys.
+            let keys = sample_keys::<CurrentEnvironment, DEPTH>(num_leaves + num_absent, rng);
+            let (present, absent) = keys.split_at(num_leaves);
+
+            // Check the sparse Merkle tree.
+            check_sparse_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &present.iter().map(|key| (*key, Field::<CurrentEnvironment>::rand(rng).to_bits_le())).collect(),
+                absent,
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Ensure DEPTH > 253 fails.
+    assert!(run_test::<254>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 8, 16, 32, 64, 253]);
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Determine the number of leaves and absent keys.
+            let num_leaves = core::cmp::min(1usize << DEPTH.min(16), i);
+            let num_absent = core::cmp::min((1usize << DEPTH.min(16)) - num_leaves, 3);
+
+            // Sample the keys, and split them into leaves and absent keys.
+            let keys = sample_keys::<CurrentEnvironment, DEPTH>(num_leaves + num_absent, rng);
+            let (present, absent) = keys.split_at(num_leaves);
+
+            // Check the sparse Merkle tree.
+            check_sparse_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &present.iter().map(|key| (*key, vec![Field::<CurrentEnvironment>::rand(rng)])).collect(),
+                absent,
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Ensure DEPTH > 253 fails.
+    assert!(run_test::<254>(&mut rng).is_err());
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 8, 16, 32, 64, 253]);
+    Ok(())
+}
+
+#[test]
+fn test_update_many() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for i in 0..ITERATIONS {
+            // Sample the keys, half of which are initially present.
+            let keys = sample_keys::<CurrentEnvironment, DEPTH>(2 * i + 1, rng);
+            let leaves = keys[..i].iter().map(|key| (*key, vec![Field::rand(rng)])).collect();
+
+            // Remove every other key, and insert or replace the rest.
+            let updates =
+                keys.iter().enumerate().map(|(j, key)| (*key, (j % 2 == 0).then(|| vec![Field::rand(rng)]))).collect();
+
+            // Check the sparse Merkle tree.
+            check_update_many::<CurrentEnvironment, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves, &updates)?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [8, 32, 253]);
+    Ok(())
+}
+
+#[test]
+fn test_out_of_bounds_key() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut merkle_tree = SparseMerkleTree::<CurrentEnvironment, LH, PH, 8>::new(&leaf_hasher, &path_hasher)?;
+    let key = Field::from_u16(256);
+
+    // Ensure a key of `2^DEPTH` is rejected.
+    assert!(merkle_tree.insert(key, vec![Field::one()]).is_err());
+    assert!(merkle_tree.prove(&key).is_err());
+    // Ensure the failed update left the sparse Merkle tree unchanged.
+    assert_eq!(0, merkle_tree.number_of_leaves());
+
+    // Ensure a proof cannot be verified for an out of bounds key.
+    let proof = merkle_tree.prove(&Field::zero())?;
+    assert!(proof.verify_non_membership(&path_hasher, merkle_tree.root(), &Field::zero()));
+    assert!(!proof.verify_non_membership(&path_hasher, merkle_tree.root(), &key));
+    Ok(())
+}
+
+#[test]
+fn test_bytes() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let mut rng = TestRng::default();
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let keys = sample_keys::<CurrentEnvironment, 32>(ITERATIONS, &mut rng);
+    let leaves = keys.iter().map(|key| (*key, vec![Field::rand(&mut rng)])).collect();
+    let merkle_tree =
+        SparseMerkleTree::<CurrentEnvironment, LH, PH, 32>::new_with_leaves(&leaf_hasher, &path_hasher, &leaves)?;
+
+    for key in &keys {
+        // Check the byte representation.
+        let expected = merkle_tree.prove(key)?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, SparseMerklePath::read_le(&expected_bytes[..])?);
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ParserResult;
+
+use anyhow::{anyhow, ensure, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{anychar, char},
+    combinator::{not, recognize, success, value, verify},
+    multi::{many0, many1},
+    sequence::{pair, preceded, terminated},
+};
+
+/// The digits of a numeric literal, written in decimal, hexadecimal (`0x`), or binary (`0b`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Digits {
+    /// The radix of the digits.
+    radix: u32,
+    /// The digits, without the prefix and underscores.
+    digits: String,
+}
+
+impl Digits {
+    /// Parses the digits of a numeric literal, stopping before the given type name.
+    /// Each digit may be followed by underscores, i.e. `0xff_ff`.
+    ///
+    /// If the type name begins with a digit in the radix (i.e. `field` in hexadecimal), the digits must be
+    /// separated from the type name by an underscore (i.e. `0xff_field`), as they would otherwise run into it.
+    pub fn parse<'a>(string: &'a str, type_name: &str) -> ParserResult<'a, Self> {
+        // Parses one or more digits in the given radix.
+        // Note: The type name is checked before each digit, as hexadecimal digits may overlap with it.
+        let digits = |radix: u32| {
+            verify(
+                recognize(many1(terminated(
+                    preceded(not(tag(type_name)), verify(anychar, move |c: &char| c.is_digit(radix))),
+                    many0(char('_')),
+                ))),
+                // Ensure the digits do not run into a type name that begins with a digit.
+                move |digits: &str| !type_name.starts_with(|c: char| c.is_digit(radix)) || digits.ends_with('_'),
+            )
+        };
+        // Parse the prefix and the digits.
+        let (string, (radix, digits)) = alt((
+            pair(value(16, tag("0x")), digits(16)),
+            pair(value(2, tag("0b")), digits(2)),
+            pair(success(10), digits(10)),
+        ))(string)?;
+
+        Ok((string, Self { radix, digits: digits.replace('_', "") }))
+    }
+
+    /// Returns the radix of the digits.
+    pub const fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Returns the digits, without the prefix and underscores.
+    pub fn as_str(&self) -> &str {
+        &self.digits
+    }
+
+    /// Returns the value of the digits as a `u128`.
+    pub fn to_u128(&self) -> Result<u128> {
+        u128::from_str_radix(&self.digits, self.radix)
+            .map_err(|error| anyhow!("Invalid digits '{}': {error}", self.digits))
+    }
+
+    /// Returns the value of the digits as a list of **little-endian** bits.
+    /// This is only supported for hexadecimal and binary digits.
+    pub fn to_bits_le(&self) -> Result<Vec<bool>> {
+        ensure!(self.radix.is_power_of_two(), "Digits in radix {} cannot be converted to bits", self.radix);
+        // Each digit corresponds to a fixed number of bits.
+        let bits_per_digit = self.radix.trailing_zeros();
+
+        let mut bits_le = Vec::with_capacity(self.digits.len() * bits_per_digit as usize);
+        for character in self.digits.chars().rev() {
+            let digit = character.to_digit(self.radix).ok_or_else(|| anyhow!("Invalid digit '{character}'"))?;
+            bits_le.extend((0..bits_per_digit).map(|i| (digit >> i) & 1 == 1));
+        }
+        Ok(bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        // Ensure decimal, hexadecimal, and binary digits are parsed.
+        let (remainder, digits) = Digits::parse("1_000u32", "u32").unwrap();
+        assert_eq!((remainder, digits.radix(), digits.as_str()), ("u32", 10, "1000"));
+        let (remainder, digits) = Digits::parse("0xFF_ffu32", "u32").unwrap();
+        assert_eq!((remainder, digits.radix(), digits.as_str()), ("u32", 16, "FFff"));
+        let (remainder, digits) = Digits::parse("0b1010u8", "u8").unwrap();
+        assert_eq!((remainder, digits.radix(), digits.as_str()), ("u8", 2, "1010"));
+
+        // Ensure hexadecimal digits are separated from an overlapping type name.
+        let (remainder, digits) = Digits::parse("0xa_field", "field").unwrap();
+        assert_eq!((remainder, digits.radix(), digits.as_str()), ("field", 16, "a"));
+        let (remainder, digits) = Digits::parse("0xff_field", "field").unwrap();
+        assert_eq!((remainder, digits.radix(), digits.as_str()), ("field", 16, "ff"));
+        // Ensure hexadecimal digits that run into an overlapping type name are not parsed as hexadecimal.
+        let (remainder, digits) = Digits::parse("0xffield", "field").unwrap();
+        assert_eq!((remainder, digits.radix(), digits.as_str()), ("xffield", 10, "0"));
+
+        // Ensure a prefix without digits is not parsed as hexadecimal or binary.
+        let (remainder, digits) = Digits::parse("0xu8", "u8").unwrap();
+        assert_eq!((remainder, digits.radix(), digits.as_str()), ("xu8", 10, "0"));
+        assert!(Digits::parse("u8", "u8").is_err());
+        assert!(Digits::parse("", "u8").is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let digits = |string: &str| Digits::parse(string, "u8").unwrap().1;
+
+        assert_eq!(digits("255").to_u128().unwrap(), 255);
+        assert_eq!(digits("0xff").to_u128().unwrap(), 255);
+        assert_eq!(digits("0b1111_1111").to_u128().unwrap(), 255);
+        assert!(digits("0x1_0000_0000_0000_0000_0000_0000_0000_0000").to_u128().is_err());
+
+        assert_eq!(digits("0x1a").to_bits_le().unwrap(), [false, true, false, true, true, false, false, false]);
+        assert_eq!(digits("0b0110").to_bits_le().unwrap(), [false, true, true, false]);
+        assert!(digits("26").to_bits_le().is_err());
+    }
+}
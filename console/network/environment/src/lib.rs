@@ -41,6 +41,7 @@ pub mod prelude {
         error,
         has_duplicates,
         io::{Read, Result as IoResult, Write},
+        ByteCounter,
         DeserializeExt,
         FromBits as _,
         FromBytes,
@@ -0,0 +1,528 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Re-exports for the `define_network!` macro.
+#[doc(hidden)]
+pub mod __private {
+    pub use once_cell::sync::OnceCell;
+    pub use snarkvm_algorithms::srs::{UniversalProver, UniversalVerifier};
+    pub use snarkvm_console_algorithms::{
+        Keccak256,
+        Keccak384,
+        Keccak512,
+        Pedersen128,
+        Pedersen64,
+        Poseidon2,
+        Poseidon4,
+        Poseidon8,
+        Sha3_256,
+        Sha3_384,
+        Sha3_512,
+        Shake128,
+        Shake256,
+        BHP1024,
+        BHP256,
+        BHP512,
+        BHP768,
+    };
+    pub use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
+    pub use snarkvm_console_types::{Field, Group, Scalar};
+    pub use std::sync::Arc;
+}
+
+/// Defines a new network, with the given network ID, name, edition, and genesis block bytes,
+/// and any of the constants of `Network` that have a default value, i.e. `BLOCK_TIME` or `MAX_FEE`.
+///
+/// The network uses the same curves, hash functions, and domain separators as `Testnet3`, so it shares
+/// the `credits.aleo` and inclusion circuit keys, and the universal SRS, with `Testnet3`.
+///
+/// ```ignore
+/// snarkvm_console_network::define_network! {
+///     /// A development network, with a block time of 5 seconds.
+///     pub struct Devnet {
+///         id: 7,
+///         name: "Aleo Devnet",
+///         edition: 0,
+///         genesis_bytes: include_bytes!("./resources/genesis.bin"),
+///         constants: {
+///             const BLOCK_TIME: u16 = 5;
+///             const MAX_FEE: u64 = 1_000_000_000;
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_network {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis struct $name:ident {
+            id: $id:expr,
+            name: $network_name:expr,
+            edition: $edition:expr,
+            genesis_bytes: $genesis_bytes:expr,
+            constants: { $(const $constant:ident: $constant_type:ty = $constant_value:expr;)* } $(,)?
+        }
+    ) => {
+        $(#[$attribute])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        $visibility struct $name;
+
+        const _: () = {
+            use $crate::{
+                __private::*,
+                environment::prelude::*,
+                BHPMerkleTree,
+                Console,
+                FiatShamirParameters,
+                Network,
+                PoseidonMerkleTree,
+                Testnet3,
+                VarunaProvingKey,
+                VarunaVerifyingKey,
+            };
+
+            // The group bases and hash functions are initialized on first use, like the statics of `Testnet3`.
+            fn generator_g() -> &'static Vec<Group<$name>> {
+                static INSTANCE: OnceCell<Vec<Group<$name>>> = OnceCell::new();
+                INSTANCE.get_or_init(|| Testnet3::g_powers().iter().map(|base| Group::new(base.to_affine())).collect())
+            }
+
+            fn bhp_256() -> &'static BHP256<$name> {
+                static INSTANCE: OnceCell<BHP256<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| BHP256::setup("AleoBHP256").expect("Failed to setup BHP256"))
+            }
+
+            fn bhp_512() -> &'static BHP512<$name> {
+                static INSTANCE: OnceCell<BHP512<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| BHP512::setup("AleoBHP512").expect("Failed to setup BHP512"))
+            }
+
+            fn bhp_768() -> &'static BHP768<$name> {
+                static INSTANCE: OnceCell<BHP768<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| BHP768::setup("AleoBHP768").expect("Failed to setup BHP768"))
+            }
+
+            fn bhp_1024() -> &'static BHP1024<$name> {
+                static INSTANCE: OnceCell<BHP1024<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| BHP1024::setup("AleoBHP1024").expect("Failed to setup BHP1024"))
+            }
+
+            fn pedersen_64() -> &'static Pedersen64<$name> {
+                static INSTANCE: OnceCell<Pedersen64<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| Pedersen64::setup("AleoPedersen64"))
+            }
+
+            fn pedersen_128() -> &'static Pedersen128<$name> {
+                static INSTANCE: OnceCell<Pedersen128<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| Pedersen128::setup("AleoPedersen128"))
+            }
+
+            fn poseidon_2() -> &'static Poseidon2<$name> {
+                static INSTANCE: OnceCell<Poseidon2<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| Poseidon2::setup("AleoPoseidon2").expect("Failed to setup Poseidon2"))
+            }
+
+            fn poseidon_4() -> &'static Poseidon4<$name> {
+                static INSTANCE: OnceCell<Poseidon4<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| Poseidon4::setup("AleoPoseidon4").expect("Failed to setup Poseidon4"))
+            }
+
+            fn poseidon_8() -> &'static Poseidon8<$name> {
+                static INSTANCE: OnceCell<Poseidon8<$name>> = OnceCell::new();
+                INSTANCE.get_or_init(|| Poseidon8::setup("AleoPoseidon8").expect("Failed to setup Poseidon8"))
+            }
+
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_unit_struct(stringify!($name))
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    struct NetworkVisitor;
+
+                    impl<'de> Visitor<'de> for NetworkVisitor {
+                        type Value = $name;
+
+                        fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                            formatter.write_str(concat!("unit struct ", stringify!($name)))
+                        }
+
+                        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                            Ok($name)
+                        }
+                    }
+
+                    deserializer.deserialize_unit_struct(stringify!($name), NetworkVisitor)
+                }
+            }
+
+            impl Environment for $name {
+                type Affine = <Console as Environment>::Affine;
+                type BigInteger = <Console as Environment>::BigInteger;
+                type Field = <Console as Environment>::Field;
+                type PairingCurve = <Console as Environment>::PairingCurve;
+                type Projective = <Console as Environment>::Projective;
+                type Scalar = <Console as Environment>::Scalar;
+
+                const EDWARDS_A: Self::Field = Console::EDWARDS_A;
+                const EDWARDS_D: Self::Field = Console::EDWARDS_D;
+                const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
+                const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+            }
+
+            impl Network for $name {
+                type BlockHash = <Testnet3 as Network>::BlockHash;
+                type RatificationID = <Testnet3 as Network>::RatificationID;
+                type StateRoot = <Testnet3 as Network>::StateRoot;
+                type TransactionID = <Testnet3 as Network>::TransactionID;
+                type TransitionID = <Testnet3 as Network>::TransitionID;
+
+                const EDITION: u16 = $edition;
+                const ID: u16 = $id;
+                const INCLUSION_FUNCTION_NAME: &'static str = Testnet3::INCLUSION_FUNCTION_NAME;
+                const NAME: &'static str = $network_name;
+
+                $(const $constant: $constant_type = $constant_value;)*
+
+                fn genesis_bytes() -> &'static [u8] {
+                    $genesis_bytes
+                }
+
+                fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
+                    Testnet3::get_credits_proving_key(function_name)
+                }
+
+                fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
+                    Testnet3::get_credits_verifying_key(function_name)
+                }
+
+                fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
+                    Testnet3::inclusion_proving_key()
+                }
+
+                fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
+                    Testnet3::inclusion_verifying_key()
+                }
+
+                fn g_powers() -> &'static Vec<Group<Self>> {
+                    generator_g()
+                }
+
+                fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+                    Group::new($crate::GENERATOR_G_TABLE.mul(scalar).to_affine())
+                }
+
+                fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
+                    Testnet3::varuna_universal_prover()
+                }
+
+                fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
+                    Testnet3::varuna_universal_verifier()
+                }
+
+                fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
+                    Testnet3::varuna_fs_parameters()
+                }
+
+                fn encryption_domain() -> Field<Self> {
+                    static INSTANCE: OnceCell<Field<$name>> = OnceCell::new();
+                    *INSTANCE.get_or_init(|| Field::new_domain_separator("AleoSymmetricEncryption0"))
+                }
+
+                fn graph_key_domain() -> Field<Self> {
+                    static INSTANCE: OnceCell<Field<$name>> = OnceCell::new();
+                    *INSTANCE.get_or_init(|| Field::new_domain_separator("AleoGraphKey0"))
+                }
+
+                fn serial_number_domain() -> Field<Self> {
+                    static INSTANCE: OnceCell<Field<$name>> = OnceCell::new();
+                    *INSTANCE.get_or_init(|| Field::new_domain_separator("AleoSerialNumber0"))
+                }
+
+                fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    bhp_256().commit(input, randomizer)
+                }
+
+                fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    bhp_512().commit(input, randomizer)
+                }
+
+                fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    bhp_768().commit(input, randomizer)
+                }
+
+                fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    bhp_1024().commit(input, randomizer)
+                }
+
+                fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    pedersen_64().commit(input, randomizer)
+                }
+
+                fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    pedersen_128().commit(input, randomizer)
+                }
+
+                fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    bhp_256().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    bhp_512().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    bhp_768().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    bhp_1024().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    pedersen_64().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    pedersen_128().commit_uncompressed(input, randomizer)
+                }
+
+                fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+                    bhp_256().hash(input)
+                }
+
+                fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+                    bhp_512().hash(input)
+                }
+
+                fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+                    bhp_768().hash(input)
+                }
+
+                fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+                    bhp_1024().hash(input)
+                }
+
+                fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+                    Keccak256::default().hash(input)
+                }
+
+                fn hash_keccak384(input: &[bool]) -> Result<Vec<bool>> {
+                    Keccak384::default().hash(input)
+                }
+
+                fn hash_keccak512(input: &[bool]) -> Result<Vec<bool>> {
+                    Keccak512::default().hash(input)
+                }
+
+                fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+                    pedersen_64().hash(input)
+                }
+
+                fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+                    pedersen_128().hash(input)
+                }
+
+                fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+                    poseidon_2().hash(input)
+                }
+
+                fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+                    poseidon_4().hash(input)
+                }
+
+                fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+                    poseidon_8().hash(input)
+                }
+
+                fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
+                    Sha3_256::default().hash(input)
+                }
+
+                fn hash_sha3_384(input: &[bool]) -> Result<Vec<bool>> {
+                    Sha3_384::default().hash(input)
+                }
+
+                fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>> {
+                    Sha3_512::default().hash(input)
+                }
+
+                fn hash_shake128(input: &[bool], num_outputs: u16) -> Vec<bool> {
+                    Shake128::default().hash_many(input, num_outputs)
+                }
+
+                fn hash_shake256(input: &[bool], num_outputs: u16) -> Vec<bool> {
+                    Shake256::default().hash_many(input, num_outputs)
+                }
+
+                fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                    poseidon_2().hash_many(input, num_outputs)
+                }
+
+                fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                    poseidon_4().hash_many(input, num_outputs)
+                }
+
+                fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                    poseidon_8().hash_many(input, num_outputs)
+                }
+
+                fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
+                    bhp_256().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
+                    bhp_512().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
+                    bhp_768().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
+                    bhp_1024().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
+                    pedersen_64().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
+                    pedersen_128().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+                    poseidon_2().hash_to_group(input)
+                }
+
+                fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+                    poseidon_4().hash_to_group(input)
+                }
+
+                fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+                    poseidon_8().hash_to_group(input)
+                }
+
+                fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                    poseidon_2().hash_to_scalar(input)
+                }
+
+                fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                    poseidon_4().hash_to_scalar(input)
+                }
+
+                fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                    poseidon_8().hash_to_scalar(input)
+                }
+
+                fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+                    MerkleTree::new(bhp_1024(), bhp_512(), leaves)
+                }
+
+                fn merkle_tree_psd<const DEPTH: u8>(
+                    leaves: &[Vec<Field<Self>>],
+                ) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
+                    MerkleTree::new(poseidon_4(), poseidon_2(), leaves)
+                }
+
+                fn verify_merkle_path_bhp<const DEPTH: u8>(
+                    path: &MerklePath<Self, DEPTH>,
+                    root: &Field<Self>,
+                    leaf: &Vec<bool>,
+                ) -> bool {
+                    path.verify(bhp_1024(), bhp_512(), root, leaf)
+                }
+
+                fn verify_merkle_path_psd<const DEPTH: u8>(
+                    path: &MerklePath<Self, DEPTH>,
+                    root: &Field<Self>,
+                    leaf: &Vec<Field<Self>>,
+                ) -> bool {
+                    path.verify(poseidon_4(), poseidon_2(), root, leaf)
+                }
+            }
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, Testnet3};
+    use snarkvm_console_types::{Field, Scalar};
+
+    crate::define_network! {
+        /// A network for testing `define_network!`.
+        pub struct CustomNetwork {
+            id: 42,
+            name: "Custom Network",
+            edition: 1,
+            genesis_bytes: &[],
+            constants: {
+                const BLOCK_TIME: u16 = 5;
+                const MAX_FEE: u64 = 1_000_000;
+            }
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(CustomNetwork::ID, 42);
+        assert_eq!(CustomNetwork::NAME, "Custom Network");
+        assert_eq!(CustomNetwork::EDITION, 1);
+        assert!(CustomNetwork::genesis_bytes().is_empty());
+
+        // Ensure the overridden constants are used, including in the constants derived from them.
+        assert_eq!(CustomNetwork::BLOCK_TIME, 5);
+        assert_eq!(CustomNetwork::MAX_FEE, 1_000_000);
+        assert_eq!(CustomNetwork::NUM_BLOCKS_PER_EPOCH, 3600 / 5);
+        assert_eq!(CustomNetwork::ANCHOR_HEIGHT, Testnet3::ANCHOR_TIME as u32 / 5);
+        // Ensure the remaining constants match the defaults.
+        assert_eq!(CustomNetwork::STARTING_SUPPLY, Testnet3::STARTING_SUPPLY);
+        assert_eq!(CustomNetwork::MAX_DATA_SIZE_IN_FIELDS, Testnet3::MAX_DATA_SIZE_IN_FIELDS);
+    }
+
+    #[test]
+    fn test_hashes_match_testnet3() {
+        let rng = &mut TestRng::default();
+
+        let bits: Vec<bool> = (0..200).map(|_| rng.gen()).collect();
+        let fields: Vec<Field<Testnet3>> = (0..5).map(|_| Uniform::rand(rng)).collect();
+        let custom_fields: Vec<Field<CustomNetwork>> = fields.iter().map(|field| Field::new(**field)).collect();
+        let scalar = Scalar::<Testnet3>::rand(rng);
+        let custom_scalar = Scalar::<CustomNetwork>::new(*scalar);
+
+        assert_eq!(*CustomNetwork::hash_bhp256(&bits).unwrap(), *Testnet3::hash_bhp256(&bits).unwrap());
+        assert_eq!(*CustomNetwork::hash_ped64(&bits[..64]).unwrap(), *Testnet3::hash_ped64(&bits[..64]).unwrap());
+        assert_eq!(*CustomNetwork::hash_psd8(&custom_fields).unwrap(), *Testnet3::hash_psd8(&fields).unwrap());
+        assert_eq!(*CustomNetwork::encryption_domain(), *Testnet3::encryption_domain());
+        assert_eq!(*CustomNetwork::g_scalar_multiply(&custom_scalar), *Testnet3::g_scalar_multiply(&scalar));
+        assert_eq!(CustomNetwork::hash_sha3_256(&bits).unwrap(), Testnet3::hash_sha3_256(&bits).unwrap());
+    }
+
+    #[test]
+    fn test_merkle_tree() {
+        let rng = &mut TestRng::default();
+
+        let leaves: Vec<Vec<bool>> = (0..4).map(|_| (0..64).map(|_| rng.gen()).collect()).collect();
+        let tree = CustomNetwork::merkle_tree_bhp::<4>(&leaves).unwrap();
+        let expected = Testnet3::merkle_tree_bhp::<4>(&leaves).unwrap();
+        assert_eq!(**tree.root(), **expected.root());
+
+        let path = tree.prove(1, &leaves[1]).unwrap();
+        assert!(CustomNetwork::verify_merkle_path_bhp(&path, tree.root(), &leaves[1]));
+        assert!(!CustomNetwork::verify_merkle_path_bhp(&path, tree.root(), &leaves[2]));
+    }
+}
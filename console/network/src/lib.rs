@@ -22,6 +22,9 @@ extern crate lazy_static;
 pub use snarkvm_console_network_environment as environment;
 pub use snarkvm_console_network_environment::*;
 
+mod custom;
+pub use custom::*;
+
 mod helpers;
 pub use helpers::*;
 
@@ -35,6 +38,7 @@ pub mod prelude {
 use crate::environment::prelude::*;
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
+    msm::FixedBaseTable,
     snark::varuna::{CircuitProvingKey, CircuitVerifyingKey, VarunaHidingMode},
     srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
@@ -59,8 +63,8 @@ pub type FiatShamir<N> = PoseidonSponge<Fq<N>, 2, 1>;
 pub type FiatShamirParameters<N> = <FiatShamir<N> as AlgebraicSponge<Fq<N>, 2>>::Parameters;
 
 /// Helper types for the Varuna proving and verifying key.
-pub(crate) type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, VarunaHidingMode>;
-pub(crate) type VarunaVerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve>;
+pub type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, VarunaHidingMode>;
+pub type VarunaVerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve>;
 
 pub trait Network:
     'static
@@ -133,6 +137,9 @@ pub trait Network:
     /// The maximum number of elements in an array.
     const MAX_ARRAY_ELEMENTS: usize = Self::MAX_DATA_ENTRIES;
 
+    /// The maximum number of entries in a map.
+    const MAX_MAP_ENTRIES: usize = Self::MAX_DATA_ENTRIES;
+
     /// The minimum number of entries in a record.
     const MIN_RECORD_ENTRIES: usize = 1; // This accounts for 'record.owner'.
     /// The maximum number of entries in a record.
@@ -287,6 +294,12 @@ pub trait Network:
     /// Returns the SHA-3 hash with a 512-bit output.
     fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>>;
 
+    /// Returns the SHAKE-128 output with the given number of bits.
+    fn hash_shake128(input: &[bool], num_outputs: u16) -> Vec<bool>;
+
+    /// Returns the SHAKE-256 output with the given number of bits.
+    fn hash_shake256(input: &[bool], num_outputs: u16) -> Vec<bool>;
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
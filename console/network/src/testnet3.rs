@@ -26,6 +26,8 @@ use snarkvm_console_algorithms::{
     Sha3_256,
     Sha3_384,
     Sha3_512,
+    Shake128,
+    Shake256,
     BHP1024,
     BHP256,
     BHP512,
@@ -35,6 +37,9 @@ use snarkvm_console_algorithms::{
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
     pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+    /// The window table for scalar multiplications on the generator `G`.
+    pub static ref GENERATOR_G_TABLE: FixedBaseTable<<Testnet3 as Environment>::Affine> =
+        FixedBaseTable::new(GENERATOR_G[0].to_affine(), 8).expect("Failed to setup the generator table");
 
     /// The Varuna sponge parameters.
     pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
@@ -190,14 +195,7 @@ impl Network for Testnet3 {
 
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
-        GENERATOR_G
-            .iter()
-            .zip_eq(&scalar.to_bits_le())
-            .filter_map(|(base, bit)| match bit {
-                true => Some(base),
-                false => None,
-            })
-            .sum()
+        Group::new(GENERATOR_G_TABLE.mul(scalar).to_affine())
     }
 
     /// Returns the Varuna universal prover.
@@ -214,12 +212,8 @@ impl Network for Testnet3 {
     /// Returns the Varuna universal verifier.
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalVerifier<<Console as Environment>::PairingCurve>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
-            snarkvm_algorithms::polycommit::kzg10::UniversalParams::load()
-                .expect("Failed to load universal SRS (KZG10).")
-                .to_universal_verifier()
-                .expect("Failed to convert universal SRS (KZG10) to the verifier.")
-        })
+        // Note: The verifier only loads the SRS elements it needs, instead of the powers of beta G.
+        INSTANCE.get_or_init(|| UniversalVerifier::load().expect("Failed to load the universal verifier (KZG10)."))
     }
 
     /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
@@ -377,6 +371,16 @@ impl Network for Testnet3 {
         Sha3_512::default().hash(input)
     }
 
+    /// Returns the SHAKE-128 output with the given number of bits.
+    fn hash_shake128(input: &[bool], num_outputs: u16) -> Vec<bool> {
+        Shake128::default().hash_many(input, num_outputs)
+    }
+
+    /// Returns the SHAKE-256 output with the given number of bits.
+    fn hash_shake256(input: &[bool], num_outputs: u16) -> Vec<bool> {
+        Shake256::default().hash_many(input, num_outputs)
+    }
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
         POSEIDON_2.hash_many(input, num_outputs)
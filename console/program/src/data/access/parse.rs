@@ -23,6 +23,33 @@ impl<N: Network> Parser for Access<N> {
     }
 }
 
+impl<N: Network> Access<N> {
+    /// Parses a dotted path, such as `token.owner[2].amount`, into a sequence of accesses.
+    ///
+    /// The leading member may omit its `.`, and an index may omit its `u32` suffix.
+    pub fn parse_path(path: &str) -> Result<Vec<Self>> {
+        // Parses an index without the `u32` suffix, i.e. `[2]`.
+        let parse_bare_index = map_res(
+            pair(tag("["), pair(recognize(many1(one_of("0123456789"))), tag("]"))),
+            |(_, (index, _)): (&str, (&str, &str))| index.parse::<u32>().map(|index| Self::Index(U32::new(index))),
+        );
+        // Parses the optional leading member, followed by the remaining accesses.
+        let parse_path = pair(opt(map(Identifier::parse, Self::Member)), many0(alt((Self::parse, parse_bare_index))));
+
+        match complete(parse_path)(path) {
+            Ok((remainder, (first, rest))) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse path. Found invalid character in: \"{remainder}\"");
+                // Ensure the path is not empty.
+                ensure!(first.is_some() || !rest.is_empty(), "Failed to parse path. The path is empty");
+                // Return the accesses.
+                Ok(first.into_iter().chain(rest).collect())
+            }
+            Err(error) => bail!("Failed to parse path. {error}"),
+        }
+    }
+}
+
 impl<N: Network> FromStr for Access<N> {
     type Err = Error;
 
@@ -74,6 +101,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_path() -> Result<()> {
+        let member = |name: &str| Access::<CurrentNetwork>::Member(Identifier::from_str(name).unwrap());
+        let index = |index: u32| Access::<CurrentNetwork>::Index(U32::new(index));
+
+        let expected = vec![member("token"), member("owner"), index(2), member("amount")];
+        assert_eq!(Access::parse_path("token.owner[2].amount")?, expected);
+        assert_eq!(Access::parse_path(".token.owner[2u32].amount")?, expected);
+        assert_eq!(Access::parse_path("[0][1u32]")?, vec![index(0), index(1)]);
+
+        // Ensure malformed paths fail.
+        assert!(Access::<CurrentNetwork>::parse_path("").is_err());
+        assert!(Access::<CurrentNetwork>::parse_path("token.").is_err());
+        assert!(Access::<CurrentNetwork>::parse_path("token[2").is_err());
+        assert!(Access::<CurrentNetwork>::parse_path("token[-1]").is_err());
+        assert!(Access::<CurrentNetwork>::parse_path("token owner").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() -> Result<()> {
         // Must be non-empty.
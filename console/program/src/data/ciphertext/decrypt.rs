@@ -23,6 +23,15 @@ impl<N: Network> Ciphertext<N> {
         self.decrypt_symmetric(plaintext_view_key)
     }
 
+    /// Decrypts `self` into plaintext using the decryption shares of a Shamir-shared view key.
+    /// Note: This method does not verify the decryption shares. Use `DecryptionShare::verify` to identify invalid shares.
+    pub fn decrypt_with_shares(&self, shares: &[DecryptionShare<N>]) -> Result<Plaintext<N>> {
+        // Compute the plaintext view key.
+        let plaintext_view_key = DecryptionShare::combine(shares)?;
+        // Decrypt the plaintext.
+        self.decrypt_symmetric(plaintext_view_key)
+    }
+
     /// Decrypts `self` into plaintext using the given plaintext view key.
     pub fn decrypt_symmetric(&self, plaintext_view_key: Field<N>) -> Result<Plaintext<N>> {
         // Determine the number of randomizers needed to encrypt the plaintext.
@@ -98,6 +107,12 @@ mod tests {
         // Decrypt the plaintext.
         let nonce = N::g_scalar_multiply(&randomizer);
         assert_eq!(plaintext, ciphertext.decrypt(view_key, nonce)?);
+
+        // Decrypt the plaintext with 2 of 3 decryption shares.
+        let shares = view_key.split(2, 3, rng)?;
+        let decryption_shares =
+            shares[1..].iter().map(|share| share.decrypt(nonce, rng)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(plaintext, ciphertext.decrypt_with_shares(&decryption_shares)?);
         Ok(())
     }
 
@@ -25,7 +25,7 @@ mod to_bits;
 mod to_fields;
 
 use crate::Plaintext;
-use snarkvm_console_account::ViewKey;
+use snarkvm_console_account::{DecryptionShare, ViewKey};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group};
 
@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Future<N> {
+    /// Returns an arbitrary future, within the maximum depth and number of arguments.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        Self::arbitrary_with_depth(u, 0)
+    }
+}
+
+impl<N: Network> Future<N> {
+    /// Returns an arbitrary future, nested at the given depth.
+    fn arbitrary_with_depth(u: &mut Unstructured, depth: usize) -> ::arbitrary::Result<Self> {
+        let program_id = ProgramID::arbitrary(u)?;
+        let function_name = Identifier::arbitrary(u)?;
+        let num_arguments = u.int_in_range(0..=N::MAX_INPUTS)?;
+        let mut arguments = Vec::with_capacity(num_arguments);
+        for _ in 0..num_arguments {
+            // Only nest another future while within the maximum depth, and while there is input remaining.
+            let argument = match depth < N::MAX_DATA_DEPTH && !u.is_empty() && u.ratio(1, 4)? {
+                true => Argument::Future(Self::arbitrary_with_depth(u, depth + 1)?),
+                false => Argument::Plaintext(Plaintext::arbitrary_with_depth(u, depth + 1)?),
+            };
+            arguments.push(argument);
+        }
+        Ok(Self::new(program_id, function_name, arguments))
+    }
+}
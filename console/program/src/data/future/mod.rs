@@ -15,6 +15,8 @@
 mod argument;
 pub use argument::Argument;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod equal;
 mod find;
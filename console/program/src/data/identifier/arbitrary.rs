@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+/// The characters that may start an identifier.
+const LEADING_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+/// The characters that may follow the first character of an identifier.
+const TRAILING_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_";
+
+impl<'a, N: Network> Arbitrary<'a> for Identifier<N> {
+    /// Returns an arbitrary identifier, of up to the maximum number of bytes.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        Self::arbitrary_from_chars(u, LEADING_CHARS, TRAILING_CHARS)
+    }
+}
+
+impl<N: Network> Identifier<N> {
+    /// Returns an arbitrary identifier, whose characters are drawn from the given character sets.
+    pub(crate) fn arbitrary_from_chars(
+        u: &mut Unstructured,
+        leading_chars: &[u8],
+        trailing_chars: &[u8],
+    ) -> ::arbitrary::Result<Self> {
+        // Determine the number of bytes in the identifier.
+        let max_bytes = Field::<N>::size_in_data_bits() / 8; // Note: This intentionally rounds down.
+        let num_bytes = u.int_in_range(1..=max_bytes)?;
+        // Sample the identifier string.
+        let mut identifier = String::with_capacity(num_bytes);
+        identifier.push(char::from(*u.choose(leading_chars)?));
+        for _ in 1..num_bytes {
+            identifier.push(char::from(*u.choose(trailing_chars)?));
+        }
+        Self::from_str(&identifier).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+}
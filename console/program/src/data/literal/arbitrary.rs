@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+use num_traits::FromPrimitive;
+
+impl<'a, N: Network> Arbitrary<'a> for Literal<N> {
+    /// Returns an arbitrary literal of any literal type.
+    ///
+    /// Booleans, integers, and strings are read directly from the input, so that their edge cases are reachable.
+    /// The remaining literals are sampled from an RNG seeded by the input, so that they are always valid.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        let literal_type = LiteralType::from_u8(u.int_in_range(0..=LiteralType::String as u8)?)
+            .ok_or(::arbitrary::Error::IncorrectFormat)?;
+        let literal = match literal_type {
+            LiteralType::Boolean => Literal::Boolean(Boolean::new(u.arbitrary()?)),
+            LiteralType::I8 => Literal::I8(I8::new(u.arbitrary()?)),
+            LiteralType::I16 => Literal::I16(I16::new(u.arbitrary()?)),
+            LiteralType::I32 => Literal::I32(I32::new(u.arbitrary()?)),
+            LiteralType::I64 => Literal::I64(I64::new(u.arbitrary()?)),
+            LiteralType::I128 => Literal::I128(I128::new(u.arbitrary()?)),
+            LiteralType::U8 => Literal::U8(U8::new(u.arbitrary()?)),
+            LiteralType::U16 => Literal::U16(U16::new(u.arbitrary()?)),
+            LiteralType::U32 => Literal::U32(U32::new(u.arbitrary()?)),
+            LiteralType::U64 => Literal::U64(U64::new(u.arbitrary()?)),
+            LiteralType::U128 => Literal::U128(U128::new(u.arbitrary()?)),
+            LiteralType::String => Literal::String(StringType::new(&arbitrary_string::<N>(u)?)),
+            _ => Literal::sample(literal_type, &mut TestRng::from_seed(u.arbitrary()?)),
+        };
+        Ok(literal)
+    }
+}
+
+/// Returns an arbitrary string that the string parser accepts, of up to the maximum number of bytes.
+fn arbitrary_string<N: Network>(u: &mut Unstructured) -> ::arbitrary::Result<String> {
+    let mut string = String::new();
+    for character in u.arbitrary::<&str>()?.chars() {
+        // Skip the characters that the parser rejects, or that require an escape sequence.
+        if !string_parser::is_char_supported(character) || matches!(character, '"' | '\\') {
+            continue;
+        }
+        // Stop once the string would exceed the maximum number of bytes.
+        if string.len() + character.len_utf8() > N::MAX_STRING_BYTES as usize {
+            break;
+        }
+        string.push(character);
+    }
+    Ok(string)
+}
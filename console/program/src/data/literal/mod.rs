@@ -15,6 +15,8 @@
 pub use cast::Cast;
 pub use cast_lossy::CastLossy;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod cast;
 mod cast_lossy;
@@ -112,4 +112,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_radix() -> Result<()> {
+        // Ensure hexadecimal and binary literals are parsed into their types.
+        assert_eq!(Literal::<CurrentNetwork>::from_str("0xffu8")?, Literal::from_str("255u8")?);
+        assert_eq!(Literal::<CurrentNetwork>::from_str("-0x7fi16")?, Literal::from_str("-127i16")?);
+        assert_eq!(Literal::<CurrentNetwork>::from_str("0b1010u64")?, Literal::from_str("10u64")?);
+        assert_eq!(Literal::<CurrentNetwork>::from_str("0xa_field")?, Literal::from_str("10field")?);
+        assert!(Literal::<CurrentNetwork>::from_str("0xafield").is_err());
+        assert_eq!(Literal::<CurrentNetwork>::from_str("0x0group")?, Literal::from_str("0group")?);
+
+        // Ensure hexadecimal and binary literals are displayed in decimal.
+        assert_eq!(Literal::<CurrentNetwork>::from_str("0xdead_beefu32")?.to_string(), "3735928559u32");
+
+        // Ensure out-of-range literals fail.
+        assert!(Literal::<CurrentNetwork>::from_str("0x100u8").is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Plaintext<N> {
+    /// Returns an arbitrary plaintext, within the maximum depth and number of entries.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        Self::arbitrary_with_depth(u, 0)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns an arbitrary plaintext, nested at the given depth.
+    pub(crate) fn arbitrary_with_depth(u: &mut Unstructured, depth: usize) -> ::arbitrary::Result<Self> {
+        // Only nest further while within the maximum depth, and while there is input remaining.
+        let max_variant = match depth < N::MAX_DATA_DEPTH && !u.is_empty() {
+            true => 3,
+            false => 0,
+        };
+        match u.int_in_range(0..=max_variant)? {
+            0 => Ok(Self::from(Literal::arbitrary(u)?)),
+            1 => {
+                let num_members = u.int_in_range(1..=N::MAX_STRUCT_ENTRIES)?;
+                let mut members = IndexMap::with_capacity(num_members);
+                for _ in 0..num_members {
+                    // Note: Duplicate member names are skipped, so the struct may have fewer members.
+                    let name = Identifier::arbitrary(u)?;
+                    let member = Self::arbitrary_with_depth(u, depth + 1)?;
+                    members.entry(name).or_insert(member);
+                }
+                Ok(Self::Struct(members, Default::default()))
+            }
+            2 => {
+                let num_elements = u.int_in_range(1..=N::MAX_ARRAY_ELEMENTS)?;
+                let elements = (0..num_elements)
+                    .map(|_| Self::arbitrary_with_depth(u, depth + 1))
+                    .collect::<::arbitrary::Result<_>>()?;
+                Ok(Self::Array(elements, Default::default()))
+            }
+            _ => {
+                let num_entries = u.int_in_range(0..=N::MAX_MAP_ENTRIES)?;
+                let mut entries = Vec::<(Literal<N>, Plaintext<N>)>::with_capacity(num_entries);
+                for _ in 0..num_entries {
+                    // Note: Duplicate keys are skipped, so the map may have fewer entries.
+                    let key = Literal::arbitrary(u)?;
+                    let value = Self::arbitrary_with_depth(u, depth + 1)?;
+                    if !entries.iter().any(|(candidate, _)| *candidate == key) {
+                        entries.push((key, value));
+                    }
+                }
+                Self::new_map(entries).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+            }
+        }
+    }
+}
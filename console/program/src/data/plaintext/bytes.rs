@@ -63,7 +63,33 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Return the array.
                 Self::Array(elements, Default::default())
             }
-            3.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
+            3 => {
+                // Read the number of entries in the map.
+                let num_entries = u32::read_le(&mut reader)?;
+                if num_entries as usize > N::MAX_MAP_ENTRIES {
+                    return Err(error("Failed to deserialize plaintext: Map exceeds maximum length"));
+                }
+                // Read the entries.
+                let mut entries = Vec::with_capacity(num_entries as usize);
+                for _ in 0..num_entries {
+                    // Read the key.
+                    let key = Literal::read_le(&mut reader)?;
+                    // Read the plaintext value (in 2 steps to prevent infinite recursion).
+                    let num_bytes = u16::read_le(&mut reader)?;
+                    // Read the plaintext bytes.
+                    let bytes = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                    // Recover the plaintext value.
+                    let plaintext = Plaintext::read_le(&mut bytes.as_slice())?;
+                    // Add the entry.
+                    entries.push((key, plaintext));
+                }
+                // Ensure the map is canonical.
+                Self::check_map_entries(&entries)
+                    .map_err(|e| error(format!("Failed to deserialize plaintext: {e}")))?;
+                // Return the map.
+                Self::Map(entries, Default::default())
+            }
+            4.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
         };
         Ok(plaintext)
     }
@@ -114,10 +140,36 @@ impl<N: Network> ToBytes for Plaintext<N> {
                 }
                 Ok(())
             }
+            Self::Map(entries, ..) => {
+                3u8.write_le(&mut writer)?;
+
+                // Write the number of entries in the map.
+                u32::try_from(entries.len()).map_err(error)?.write_le(&mut writer)?;
+
+                // Write each entry.
+                for (key, value) in entries {
+                    // Write the key.
+                    key.write_le(&mut writer)?;
+                    // Write the value (performed in 2 steps to prevent infinite recursion).
+                    let bytes = value.to_bytes_le().map_err(error)?;
+                    // Write the number of bytes.
+                    u16::try_from(bytes.len()).map_err(error)?.write_le(&mut writer)?;
+                    // Write the bytes.
+                    bytes.write_le(&mut writer)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
+impl<N: Network> Plaintext<N> {
+    /// Returns the number of bytes in the serialized plaintext, without allocating the full byte buffer.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(ByteCounter::count(self)?)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +183,8 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
+        // Check the size in bytes.
+        assert_eq!(expected.size_in_bytes()?, expected_bytes.len() as u64);
         Ok(())
     }
 
@@ -230,6 +284,23 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
 
+        // Check the map manually.
+        let expected = Plaintext::<CurrentNetwork>::from_str("{ 1u8 => { x: 1field }, 2u8 => { x: 2field } }")?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
+
+        // Ensure a map with keys that are not in canonical order is rejected.
+        let unordered = Plaintext::<CurrentNetwork>::Map(
+            vec![
+                (Literal::from_str("2u8")?, Plaintext::from_str("2field")?),
+                (Literal::from_str("1u8")?, Plaintext::from_str("1field")?),
+            ],
+            Default::default(),
+        );
+        assert!(Plaintext::<CurrentNetwork>::read_le(&unordered.to_bytes_le()?[..]).is_err());
+
         Ok(())
     }
 }
@@ -48,7 +48,19 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(false),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(false),
+            (Self::Map(a, _), Self::Map(b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each entry for equality.
+                    // Note: This relies on the entries being in their canonical order.
+                    Boolean::new(a.iter().zip_eq(b.iter()).all(|((key_a, plaintext_a), (key_b, plaintext_b))| {
+                        *key_a.is_equal(key_b) && *plaintext_a.is_equal(plaintext_b)
+                    }))
+                }
+                false => Boolean::new(false),
+            },
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Map(..), _) => {
+                Boolean::new(false)
+            }
         }
     }
 
@@ -76,7 +88,19 @@ impl<N: Network> Equal<Self> for Plaintext<N> {
                 }
                 false => Boolean::new(true),
             },
-            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) => Boolean::new(true),
+            (Self::Map(a, _), Self::Map(b, _)) => match a.len() == b.len() {
+                true => {
+                    // Recursively check each entry for inequality.
+                    // Note: This relies on the entries being in their canonical order.
+                    Boolean::new(a.iter().zip_eq(b.iter()).any(|((key_a, plaintext_a), (key_b, plaintext_b))| {
+                        *(key_a.is_not_equal(key_b) | plaintext_a.is_not_equal(plaintext_b))
+                    }))
+                }
+                false => Boolean::new(true),
+            },
+            (Self::Literal(..), _) | (Self::Struct(..), _) | (Self::Array(..), _) | (Self::Map(..), _) => {
+                Boolean::new(true)
+            }
         }
     }
 }
@@ -0,0 +1,433 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{LiteralType, PlaintextType, StructType};
+
+/// The size of an Ethereum ABI word, in bytes.
+const WORD_SIZE: usize = 32;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the Ethereum ABI encoding of the plaintext, as produced by `abi.encode(value)` in Solidity.
+    ///
+    /// Structs are encoded as tuples, and arrays as fixed-size arrays.
+    /// Integers are encoded as `uintN` and `intN`, and booleans and strings as `bool` and `string`.
+    /// Fields, scalars, and groups are encoded as `uint256`, with groups (and addresses) encoded by their x-coordinate,
+    /// as in their Aleo syntax.
+    /// Signatures and maps are not supported.
+    pub fn to_eth_abi(&self) -> Result<Vec<u8>> {
+        encode_sequence([self])
+    }
+
+    /// Initializes a plaintext from its Ethereum ABI encoding, as consumed by `abi.decode(data, (T))` in Solidity.
+    pub fn from_eth_abi(
+        plaintext_type: &PlaintextType<N>,
+        structs: &IndexMap<Identifier<N>, StructType<N>>,
+        bytes: &[u8],
+    ) -> Result<Self> {
+        let mut plaintexts = decode_sequence(&[plaintext_type], structs, bytes, 0)?;
+        match plaintexts.pop() {
+            Some(plaintext) => Ok(plaintext),
+            None => bail!("Failed to decode the Ethereum ABI plaintext"),
+        }
+    }
+}
+
+/// Returns `true` if the given plaintext has a dynamic Ethereum ABI encoding.
+fn is_dynamic<N: Network>(plaintext: &Plaintext<N>) -> bool {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => matches!(literal, Literal::String(..)),
+        Plaintext::Struct(members, ..) => members.values().any(is_dynamic),
+        Plaintext::Array(elements, ..) => elements.iter().any(is_dynamic),
+        Plaintext::Map(..) => true,
+    }
+}
+
+/// Returns the Ethereum ABI encoding of the given plaintext.
+fn encode<N: Network>(plaintext: &Plaintext<N>) -> Result<Vec<u8>> {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => encode_literal(literal),
+        Plaintext::Struct(members, ..) => encode_sequence(members.values()),
+        Plaintext::Array(elements, ..) => encode_sequence(elements.iter()),
+        Plaintext::Map(..) => bail!("Cannot encode a map in the Ethereum ABI"),
+    }
+}
+
+/// Returns the Ethereum ABI encoding of the given tuple or fixed-size array elements.
+fn encode_sequence<'a, N: Network + 'a>(plaintexts: impl IntoIterator<Item = &'a Plaintext<N>>) -> Result<Vec<u8>> {
+    let encodings = plaintexts
+        .into_iter()
+        .map(|plaintext| Ok((is_dynamic(plaintext), encode(plaintext)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Compute the size of the heads, where each dynamic element is replaced by an offset.
+    let heads_size =
+        encodings.iter().map(|(dynamic, encoding)| if *dynamic { WORD_SIZE } else { encoding.len() }).sum::<usize>();
+
+    let mut heads = Vec::with_capacity(heads_size);
+    let mut tails = Vec::new();
+    for (dynamic, encoding) in encodings {
+        match dynamic {
+            // Write the offset of the element, relative to the start of the sequence.
+            true => {
+                heads.extend_from_slice(&uint_word((heads_size + tails.len()) as u128));
+                tails.extend_from_slice(&encoding);
+            }
+            false => heads.extend_from_slice(&encoding),
+        }
+    }
+    heads.extend_from_slice(&tails);
+    Ok(heads)
+}
+
+/// Returns the Ethereum ABI encoding of the given literal.
+fn encode_literal<N: Network>(literal: &Literal<N>) -> Result<Vec<u8>> {
+    let word = match literal {
+        Literal::Address(address) => field_word(&address.to_group().to_x_coordinate())?,
+        Literal::Boolean(boolean) => uint_word(**boolean as u128),
+        Literal::Field(field) => field_word(field)?,
+        Literal::Group(group) => field_word(&group.to_x_coordinate())?,
+        Literal::I8(integer) => int_word(**integer as i128),
+        Literal::I16(integer) => int_word(**integer as i128),
+        Literal::I32(integer) => int_word(**integer as i128),
+        Literal::I64(integer) => int_word(**integer as i128),
+        Literal::I128(integer) => int_word(**integer),
+        Literal::U8(integer) => uint_word(**integer as u128),
+        Literal::U16(integer) => uint_word(**integer as u128),
+        Literal::U32(integer) => uint_word(**integer as u128),
+        Literal::U64(integer) => uint_word(**integer as u128),
+        Literal::U128(integer) => uint_word(**integer),
+        Literal::Scalar(scalar) => {
+            let mut word = scalar.to_bytes_le()?;
+            word.reverse();
+            to_word(&word)?
+        }
+        Literal::Signature(..) => bail!("Cannot encode a signature in the Ethereum ABI"),
+        Literal::String(string) => {
+            // Write the length, followed by the bytes padded to a multiple of the word size.
+            let bytes = string.as_bytes();
+            let mut encoding = uint_word(bytes.len() as u128).to_vec();
+            encoding.extend_from_slice(bytes);
+            encoding.resize(WORD_SIZE + (bytes.len() + WORD_SIZE - 1) / WORD_SIZE * WORD_SIZE, 0);
+            return Ok(encoding);
+        }
+    };
+    Ok(word.to_vec())
+}
+
+/// Returns `true` if the given plaintext type has a dynamic Ethereum ABI encoding.
+fn is_dynamic_type<N: Network>(
+    plaintext_type: &PlaintextType<N>,
+    structs: &IndexMap<Identifier<N>, StructType<N>>,
+) -> Result<bool> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => Ok(*literal_type == LiteralType::String),
+        PlaintextType::Struct(struct_name) => {
+            for member_type in get_struct(struct_name, structs)?.members().values() {
+                if is_dynamic_type(member_type, structs)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        PlaintextType::Array(array_type) => is_dynamic_type(array_type.next_element_type(), structs),
+        PlaintextType::Map(..) => bail!("Cannot decode a map from the Ethereum ABI"),
+    }
+}
+
+/// Returns the size of the Ethereum ABI encoding of the given static plaintext type.
+fn static_size<N: Network>(
+    plaintext_type: &PlaintextType<N>,
+    structs: &IndexMap<Identifier<N>, StructType<N>>,
+) -> Result<usize> {
+    match plaintext_type {
+        PlaintextType::Literal(..) => Ok(WORD_SIZE),
+        PlaintextType::Struct(struct_name) => get_struct(struct_name, structs)?
+            .members()
+            .values()
+            .map(|member_type| static_size(member_type, structs))
+            .sum(),
+        PlaintextType::Array(array_type) => {
+            Ok(static_size(array_type.next_element_type(), structs)? * **array_type.length() as usize)
+        }
+        PlaintextType::Map(..) => bail!("Cannot decode a map from the Ethereum ABI"),
+    }
+}
+
+/// Returns the plaintext of the given type, decoded from the Ethereum ABI encoding at the given offset.
+fn decode<N: Network>(
+    plaintext_type: &PlaintextType<N>,
+    structs: &IndexMap<Identifier<N>, StructType<N>>,
+    bytes: &[u8],
+    offset: usize,
+) -> Result<Plaintext<N>> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => Ok(Plaintext::from(decode_literal(*literal_type, bytes, offset)?)),
+        PlaintextType::Struct(struct_name) => {
+            let struct_ = get_struct(struct_name, structs)?;
+            let member_types = struct_.members().values().collect::<Vec<_>>();
+            let members = decode_sequence(&member_types, structs, bytes, offset)?;
+            Ok(Plaintext::Struct(struct_.members().keys().copied().zip_eq(members).collect(), Default::default()))
+        }
+        PlaintextType::Array(array_type) => {
+            let element_types = vec![array_type.next_element_type(); **array_type.length() as usize];
+            Ok(Plaintext::Array(decode_sequence(&element_types, structs, bytes, offset)?, Default::default()))
+        }
+        PlaintextType::Map(..) => bail!("Cannot decode a map from the Ethereum ABI"),
+    }
+}
+
+/// Returns the tuple or fixed-size array elements of the given types, decoded from the Ethereum ABI encoding at the given offset.
+fn decode_sequence<N: Network>(
+    plaintext_types: &[&PlaintextType<N>],
+    structs: &IndexMap<Identifier<N>, StructType<N>>,
+    bytes: &[u8],
+    offset: usize,
+) -> Result<Vec<Plaintext<N>>> {
+    let mut head = offset;
+    let mut plaintexts = Vec::with_capacity(plaintext_types.len());
+    for plaintext_type in plaintext_types {
+        match is_dynamic_type(plaintext_type, structs)? {
+            // Decode the element at its offset, relative to the start of the sequence.
+            true => {
+                let element_offset = usize::try_from(read_uint(bytes, head)?)?;
+                let Some(element_offset) = offset.checked_add(element_offset) else {
+                    bail!("Invalid offset in the Ethereum ABI encoding")
+                };
+                plaintexts.push(decode(plaintext_type, structs, bytes, element_offset)?);
+                head += WORD_SIZE;
+            }
+            false => {
+                plaintexts.push(decode(plaintext_type, structs, bytes, head)?);
+                head += static_size(plaintext_type, structs)?;
+            }
+        }
+    }
+    Ok(plaintexts)
+}
+
+/// Returns the literal of the given type, decoded from the Ethereum ABI encoding at the given offset.
+fn decode_literal<N: Network>(literal_type: LiteralType, bytes: &[u8], offset: usize) -> Result<Literal<N>> {
+    let literal = match literal_type {
+        LiteralType::Address => Literal::Address(Address::new(Group::from_x_coordinate(read_field(bytes, offset)?)?)),
+        LiteralType::Boolean => match read_uint(bytes, offset)? {
+            0 => Literal::Boolean(Boolean::new(false)),
+            1 => Literal::Boolean(Boolean::new(true)),
+            _ => bail!("Invalid boolean in the Ethereum ABI encoding"),
+        },
+        LiteralType::Field => Literal::Field(read_field(bytes, offset)?),
+        LiteralType::Group => Literal::Group(Group::from_x_coordinate(read_field(bytes, offset)?)?),
+        LiteralType::I8 => Literal::I8(I8::new(i8::try_from(read_int(bytes, offset)?)?)),
+        LiteralType::I16 => Literal::I16(I16::new(i16::try_from(read_int(bytes, offset)?)?)),
+        LiteralType::I32 => Literal::I32(I32::new(i32::try_from(read_int(bytes, offset)?)?)),
+        LiteralType::I64 => Literal::I64(I64::new(i64::try_from(read_int(bytes, offset)?)?)),
+        LiteralType::I128 => Literal::I128(I128::new(read_int(bytes, offset)?)),
+        LiteralType::U8 => Literal::U8(U8::new(u8::try_from(read_uint(bytes, offset)?)?)),
+        LiteralType::U16 => Literal::U16(U16::new(u16::try_from(read_uint(bytes, offset)?)?)),
+        LiteralType::U32 => Literal::U32(U32::new(u32::try_from(read_uint(bytes, offset)?)?)),
+        LiteralType::U64 => Literal::U64(U64::new(u64::try_from(read_uint(bytes, offset)?)?)),
+        LiteralType::U128 => Literal::U128(U128::new(read_uint(bytes, offset)?)),
+        LiteralType::Scalar => {
+            let mut word = read_word(bytes, offset)?.to_vec();
+            word.reverse();
+            Literal::Scalar(Scalar::from_bytes_le(&word)?)
+        }
+        LiteralType::Signature => bail!("Cannot decode a signature from the Ethereum ABI"),
+        LiteralType::String => {
+            // Read the length, followed by the bytes.
+            let length = usize::try_from(read_uint(bytes, offset)?)?;
+            ensure!(length <= N::MAX_STRING_BYTES as usize, "String exceeds the maximum length");
+            let start = offset.saturating_add(WORD_SIZE);
+            let Some(string) = bytes.get(start..start.saturating_add(length)) else {
+                bail!("Unexpected end of the Ethereum ABI encoding")
+            };
+            Literal::String(StringType::new(core::str::from_utf8(string)?))
+        }
+    };
+    Ok(literal)
+}
+
+/// Returns the struct with the given name.
+fn get_struct<'a, N: Network>(
+    struct_name: &Identifier<N>,
+    structs: &'a IndexMap<Identifier<N>, StructType<N>>,
+) -> Result<&'a StructType<N>> {
+    structs.get(struct_name).ok_or_else(|| anyhow!("Struct '{struct_name}' is not defined"))
+}
+
+/// Returns the given unsigned integer as a big-endian word.
+fn uint_word(value: u128) -> [u8; WORD_SIZE] {
+    let mut word = [0u8; WORD_SIZE];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Returns the given signed integer as a sign-extended big-endian word.
+fn int_word(value: i128) -> [u8; WORD_SIZE] {
+    let mut word = [if value < 0 { 0xff } else { 0 }; WORD_SIZE];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Returns the given field element as a big-endian word.
+fn field_word<N: Network>(field: &Field<N>) -> Result<[u8; WORD_SIZE]> {
+    let mut bytes = field.to_bytes_le()?;
+    bytes.reverse();
+    to_word(&bytes)
+}
+
+/// Returns the given big-endian bytes as a word.
+fn to_word(bytes: &[u8]) -> Result<[u8; WORD_SIZE]> {
+    ensure!(bytes.len() <= WORD_SIZE, "Expected at most {WORD_SIZE} bytes, found {} bytes", bytes.len());
+    let mut word = [0u8; WORD_SIZE];
+    word[WORD_SIZE - bytes.len()..].copy_from_slice(bytes);
+    Ok(word)
+}
+
+/// Returns the word at the given offset.
+fn read_word(bytes: &[u8], offset: usize) -> Result<&[u8]> {
+    match bytes.get(offset..offset.saturating_add(WORD_SIZE)) {
+        Some(word) if word.len() == WORD_SIZE => Ok(word),
+        _ => bail!("Unexpected end of the Ethereum ABI encoding"),
+    }
+}
+
+/// Returns the unsigned integer at the given offset.
+fn read_uint(bytes: &[u8], offset: usize) -> Result<u128> {
+    let word = read_word(bytes, offset)?;
+    // Ensure the integer fits in 128 bits.
+    ensure!(word[..16].iter().all(|byte| *byte == 0), "Unsigned integer exceeds 128 bits in the Ethereum ABI encoding");
+    Ok(u128::from_be_bytes(word[16..].try_into()?))
+}
+
+/// Returns the signed integer at the given offset.
+fn read_int(bytes: &[u8], offset: usize) -> Result<i128> {
+    let word = read_word(bytes, offset)?;
+    let value = i128::from_be_bytes(word[16..].try_into()?);
+    // Ensure the integer is correctly sign-extended.
+    let sign = if value < 0 { 0xff } else { 0 };
+    ensure!(
+        word[..16].iter().all(|byte| *byte == sign),
+        "Signed integer exceeds 128 bits in the Ethereum ABI encoding"
+    );
+    Ok(value)
+}
+
+/// Returns the field element at the given offset.
+fn read_field<N: Network>(bytes: &[u8], offset: usize) -> Result<Field<N>> {
+    let mut word = read_word(bytes, offset)?.to_vec();
+    word.reverse();
+    Field::from_bytes_le(&word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the given words as bytes.
+    fn words(words: &[[u8; WORD_SIZE]]) -> Vec<u8> {
+        words.concat()
+    }
+
+    /// Returns the structs used in the tests.
+    fn sample_structs() -> Result<IndexMap<Identifier<CurrentNetwork>, StructType<CurrentNetwork>>> {
+        [
+            "struct point: x as i8; y as u64;",
+            "struct message: id as u32; text as string;",
+            "struct bundle: points as [point; 2u32]; messages as [message; 2u32]; flag as boolean; origin as field; owner as address; base as group; weight as scalar;",
+        ]
+        .into_iter()
+        .map(|string| StructType::<CurrentNetwork>::from_str(string).map(|struct_| (*struct_.name(), struct_)))
+        .collect()
+    }
+
+    #[test]
+    fn test_static_struct() -> Result<()> {
+        let structs = sample_structs()?;
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ x: -1i8, y: 5u64 }")?;
+
+        // Ensure a static struct is encoded in place.
+        let expected = words(&[int_word(-1), uint_word(5)]);
+        assert_eq!(plaintext.to_eth_abi()?, expected);
+        assert_eq!(Plaintext::from_eth_abi(&PlaintextType::from_str("point")?, &structs, &expected)?, plaintext);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dynamic_struct() -> Result<()> {
+        let structs = sample_structs()?;
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(r#"{ id: 1u32, text: "hi" }"#)?;
+
+        // Ensure a dynamic struct is encoded after its offset, with the string after its own offset.
+        let mut text = [0u8; WORD_SIZE];
+        text[..2].copy_from_slice(b"hi");
+        let expected = words(&[uint_word(32), uint_word(1), uint_word(64), uint_word(2), text]);
+        assert_eq!(plaintext.to_eth_abi()?, expected);
+        assert_eq!(Plaintext::from_eth_abi(&PlaintextType::from_str("message")?, &structs, &expected)?, plaintext);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let structs = sample_structs()?;
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            r#"{
+                points: [{ x: -128i8, y: 18446744073709551615u64 }, { x: 127i8, y: 0u64 }],
+                messages: [{ id: 1u32, text: "" }, { id: 2u32, text: "a string that spans more than one ABI word" }],
+                flag: true,
+                origin: 7field,
+                owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah,
+                base: 0group,
+                weight: 9scalar
+            }"#,
+        )?;
+        let bytes = plaintext.to_eth_abi()?;
+        assert_eq!(Plaintext::from_eth_abi(&PlaintextType::from_str("bundle")?, &structs, &bytes)?, plaintext);
+
+        // Ensure a truncated encoding fails.
+        assert!(
+            Plaintext::from_eth_abi(&PlaintextType::from_str("bundle")?, &structs, &bytes[..bytes.len() - 1]).is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid() -> Result<()> {
+        let structs = sample_structs()?;
+        let decode = |plaintext_type: &str, bytes: &[u8]| {
+            Plaintext::<CurrentNetwork>::from_eth_abi(
+                &PlaintextType::from_str(plaintext_type).unwrap(),
+                &structs,
+                bytes,
+            )
+        };
+
+        // Ensure out-of-range values fail.
+        assert!(decode("u8", &uint_word(256)).is_err());
+        assert!(decode("i8", &int_word(-129)).is_err());
+        assert!(decode("u128", &[0xff; WORD_SIZE]).is_err());
+        assert!(decode("boolean", &uint_word(2)).is_err());
+        assert!(decode("field", &[0xff; WORD_SIZE]).is_err());
+        // Ensure in-range values succeed.
+        assert_eq!(decode("i128", &[0xff; WORD_SIZE])?, Plaintext::from_str("-1i128")?);
+
+        // Ensure unsupported types fail.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ 1u8 => 2u8 }")?.to_eth_abi().is_err());
+        assert!(decode("[u8 => u8; 1u32]", &uint_word(0)).is_err());
+        Ok(())
+    }
+}
@@ -23,6 +23,8 @@ impl<N: Network> Plaintext<N> {
         match self {
             // Halts if the value is not a struct.
             Self::Literal(..) => bail!("'{self}' is not a struct"),
+            // Halts if the value is a map, as its entries are not accessible by path.
+            Self::Map(..) => bail!("'{self}' is a map, which does not support member access"),
             // Retrieve the value of the member (from the value).
             Self::Struct(..) | Self::Array(..) => {
                 // Initialize the plaintext starting from the top-level.
@@ -90,6 +90,30 @@ impl<N: Network> FromBits for Plaintext<N> {
             // Cache the plaintext bits, and return the array.
             Ok(Self::Array(elements, OnceCell::with_value(bits_le.to_vec())))
         }
+        // Map
+        else if variant == [true, true] {
+            let num_entries = u32::from_bits_le(next_bits(32)?)?;
+            if num_entries as usize > N::MAX_MAP_ENTRIES {
+                bail!("Map exceeds maximum of entries.");
+            }
+
+            let mut entries = Vec::with_capacity(num_entries as usize);
+            for _ in 0..num_entries {
+                let key_variant = u8::from_bits_le(next_bits(8)?)?;
+                let key_size = u16::from_bits_le(next_bits(16)?)?;
+                let key = Literal::from_bits_le(key_variant, next_bits(key_size as usize)?)?;
+
+                let value_size = u16::from_bits_le(next_bits(16)?)?;
+                let value = Plaintext::from_bits_le(next_bits(value_size as usize)?)?;
+
+                entries.push((key, value));
+            }
+            // Ensure the map is canonical.
+            Self::check_map_entries(&entries)?;
+
+            // Cache the plaintext bits, and return the map.
+            Ok(Self::Map(entries, OnceCell::with_value(bits_le.to_vec())))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant - {variant:?}");
@@ -171,6 +195,30 @@ impl<N: Network> FromBits for Plaintext<N> {
             // Cache the plaintext bits, and return the array.
             Ok(Self::Array(elements, OnceCell::with_value(bits_be.to_vec())))
         }
+        // Map
+        else if variant == [true, true] {
+            let num_entries = u32::from_bits_be(next_bits(32)?)?;
+            if num_entries as usize > N::MAX_MAP_ENTRIES {
+                bail!("Map exceeds maximum of entries.");
+            }
+
+            let mut entries = Vec::with_capacity(num_entries as usize);
+            for _ in 0..num_entries {
+                let key_variant = u8::from_bits_be(next_bits(8)?)?;
+                let key_size = u16::from_bits_be(next_bits(16)?)?;
+                let key = Literal::from_bits_be(key_variant, next_bits(key_size as usize)?)?;
+
+                let value_size = u16::from_bits_be(next_bits(16)?)?;
+                let value = Plaintext::from_bits_be(next_bits(value_size as usize)?)?;
+
+                entries.push((key, value));
+            }
+            // Ensure the map is canonical.
+            Self::check_map_entries(&entries)?;
+
+            // Cache the plaintext bits, and return the map.
+            Ok(Self::Map(entries, OnceCell::with_value(bits_be.to_vec())))
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant - {variant:?}");
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{LiteralType, PlaintextType, StructType};
+
+use serde_json::Value as JsonValue;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the plaintext as a plain JSON value, without the Aleo literal type suffixes.
+    ///
+    /// Booleans and integers of up to 32 bits are JSON booleans and numbers.
+    /// All other literals, including 64-bit and 128-bit integers, are JSON strings, so that they remain lossless.
+    /// Structs and maps are JSON objects, and arrays are JSON arrays.
+    pub fn to_json_value(&self) -> JsonValue {
+        match self {
+            Self::Literal(literal, ..) => literal_to_json(literal),
+            Self::Struct(members, ..) => JsonValue::Object(
+                members.iter().map(|(name, member)| (name.to_string(), member.to_json_value())).collect(),
+            ),
+            Self::Array(elements, ..) => {
+                JsonValue::Array(elements.iter().map(|element| element.to_json_value()).collect())
+            }
+            Self::Map(entries, ..) => JsonValue::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| {
+                        // Note: JSON object keys must be strings.
+                        let key = match literal_to_json(key) {
+                            JsonValue::String(key) => key,
+                            key => key.to_string(),
+                        };
+                        (key, value.to_json_value())
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Initializes a plaintext from a plain JSON value, using the given plaintext type and struct definitions.
+    ///
+    /// 64-bit and 128-bit integers may be given as JSON strings or JSON numbers.
+    pub fn from_json_value(
+        plaintext_type: &PlaintextType<N>,
+        structs: &IndexMap<Identifier<N>, StructType<N>>,
+        json: &JsonValue,
+    ) -> Result<Self> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Ok(Self::from(literal_from_json::<N>(*literal_type, json)?)),
+            PlaintextType::Struct(struct_name) => {
+                // Retrieve the struct definition.
+                let Some(struct_) = structs.get(struct_name) else { bail!("Struct '{struct_name}' is not defined") };
+                let JsonValue::Object(object) = json else { bail!("Expected a JSON object for '{struct_name}'") };
+                // Ensure the JSON object does not contain unknown members.
+                ensure!(
+                    object.len() == struct_.members().len(),
+                    "Expected {} members for '{struct_name}', found {} members",
+                    struct_.members().len(),
+                    object.len()
+                );
+                // Construct the members, in the order of the struct definition.
+                let members = struct_
+                    .members()
+                    .iter()
+                    .map(|(member_name, member_type)| {
+                        let Some(member) = object.get(&member_name.to_string()) else {
+                            bail!("Missing member '{member_name}' for '{struct_name}'")
+                        };
+                        Ok((*member_name, Self::from_json_value(member_type, structs, member)?))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                Ok(Self::Struct(members, Default::default()))
+            }
+            PlaintextType::Array(array_type) => {
+                let JsonValue::Array(array) = json else { bail!("Expected a JSON array for '{array_type}'") };
+                // Ensure the array length matches.
+                ensure!(
+                    array.len() == **array_type.length() as usize,
+                    "Expected {} elements for '{array_type}', found {} elements",
+                    array_type.length(),
+                    array.len()
+                );
+                let elements = array
+                    .iter()
+                    .map(|element| Self::from_json_value(array_type.next_element_type(), structs, element))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Self::Array(elements, Default::default()))
+            }
+            PlaintextType::Map(map_type) => {
+                let JsonValue::Object(object) = json else { bail!("Expected a JSON object for '{map_type}'") };
+                // Ensure the number of entries does not exceed the maximum.
+                ensure!(
+                    object.len() <= **map_type.max_entries() as usize,
+                    "Expected at most {} entries for '{map_type}', found {} entries",
+                    map_type.max_entries(),
+                    object.len()
+                );
+                let entries = object
+                    .iter()
+                    .map(|(key, value)| {
+                        let key = literal_from_text::<N>(*map_type.key_type(), key)?;
+                        Ok((key, Self::from_json_value(map_type.value_type(), structs, value)?))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Self::new_map(entries)
+            }
+        }
+    }
+}
+
+/// Returns the given literal as a plain JSON value.
+fn literal_to_json<N: Network>(literal: &Literal<N>) -> JsonValue {
+    match literal {
+        Literal::Boolean(boolean) => JsonValue::from(**boolean),
+        Literal::I8(integer) => JsonValue::from(**integer),
+        Literal::I16(integer) => JsonValue::from(**integer),
+        Literal::I32(integer) => JsonValue::from(**integer),
+        Literal::U8(integer) => JsonValue::from(**integer),
+        Literal::U16(integer) => JsonValue::from(**integer),
+        Literal::U32(integer) => JsonValue::from(**integer),
+        Literal::String(string) => JsonValue::from(&**string),
+        // Note: The remaining literals are printed without their type suffix.
+        _ => {
+            let string = literal.to_string();
+            let type_name = literal.to_type().type_name();
+            JsonValue::from(string.strip_suffix(type_name).unwrap_or(&string))
+        }
+    }
+}
+
+/// Returns the literal of the given type from a plain JSON value.
+fn literal_from_json<N: Network>(literal_type: LiteralType, json: &JsonValue) -> Result<Literal<N>> {
+    match (literal_type, json) {
+        (LiteralType::Boolean, JsonValue::Bool(boolean)) => Ok(Literal::Boolean(Boolean::new(*boolean))),
+        (
+            LiteralType::I8
+            | LiteralType::I16
+            | LiteralType::I32
+            | LiteralType::U8
+            | LiteralType::U16
+            | LiteralType::U32,
+            JsonValue::Number(number),
+        ) => literal_from_text(literal_type, &number.to_string()),
+        // Note: 64-bit and 128-bit integers are accepted as JSON strings, as JSON numbers may lose precision.
+        (LiteralType::I64 | LiteralType::I128 | LiteralType::U64 | LiteralType::U128, JsonValue::Number(number)) => {
+            literal_from_text(literal_type, &number.to_string())
+        }
+        (
+            LiteralType::I64
+            | LiteralType::I128
+            | LiteralType::U64
+            | LiteralType::U128
+            | LiteralType::Address
+            | LiteralType::Field
+            | LiteralType::Group
+            | LiteralType::Scalar
+            | LiteralType::Signature
+            | LiteralType::String,
+            JsonValue::String(string),
+        ) => literal_from_text(literal_type, string),
+        _ => bail!("Invalid JSON value '{json}' for a literal of type '{literal_type}'"),
+    }
+}
+
+/// Returns the literal of the given type from its text, without the type suffix.
+fn literal_from_text<N: Network>(literal_type: LiteralType, text: &str) -> Result<Literal<N>> {
+    let literal = match literal_type {
+        LiteralType::Address | LiteralType::Boolean | LiteralType::Signature => Literal::from_str(text)?,
+        LiteralType::String => {
+            // Ensure the string is within the maximum length.
+            ensure!(text.len() <= N::MAX_STRING_BYTES as usize, "String exceeds the maximum length");
+            Literal::String(StringType::new(text))
+        }
+        _ => Literal::from_str(&format!("{text}{}", literal_type.type_name()))?,
+    };
+    // Ensure the literal type matches.
+    ensure!(literal.to_type() == literal_type, "Expected a literal of type '{literal_type}', found '{literal}'");
+    Ok(literal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use serde_json::json;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json_value() -> Result<()> {
+        let structs = [
+            StructType::<CurrentNetwork>::from_str("struct point: x as i32; y as u128;")?,
+            StructType::<CurrentNetwork>::from_str(
+                "struct shape: points as [point; 2u32]; visible as boolean; origin as field; label as [u8 => string; 2u32];",
+            )?,
+        ]
+        .into_iter()
+        .map(|struct_| (*struct_.name(), struct_))
+        .collect::<IndexMap<_, _>>();
+
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            r#"{ points: [{ x: -1i32, y: 340282366920938463463374607431768211455u128 }, { x: 2i32, y: 0u128 }], visible: true, origin: 7field, label: { 1u8 => "one", 3u8 => "three" } }"#,
+        )?;
+        let expected = json!({
+            "points": [
+                { "x": -1, "y": "340282366920938463463374607431768211455" },
+                { "x": 2, "y": "0" },
+            ],
+            "visible": true,
+            "origin": "7",
+            "label": { "1": "one", "3": "three" },
+        });
+
+        // Ensure the plaintext converts to JSON.
+        let plaintext_type = PlaintextType::from_str("shape")?;
+        assert_eq!(plaintext.to_json_value(), expected);
+        // Ensure the JSON converts back to the plaintext.
+        assert_eq!(Plaintext::from_json_value(&plaintext_type, &structs, &expected)?, plaintext);
+
+        // Ensure 64-bit and 128-bit integers may also be given as JSON numbers.
+        let candidate = Plaintext::from_json_value(&PlaintextType::from_str("u64")?, &structs, &json!(5))?;
+        assert_eq!(candidate, Plaintext::from_str("5u64")?);
+        // Ensure the literals are type-checked.
+        for (plaintext_type, json) in [
+            ("u8", json!(256)),
+            ("u8", json!("5")),
+            ("i32", json!(1.5)),
+            ("u64", json!("5u64")),
+            ("boolean", json!("true")),
+            ("field", json!(5)),
+            ("address", json!("1")),
+            ("[u8; 2u32]", json!([1])),
+        ] {
+            let plaintext_type = PlaintextType::from_str(plaintext_type)?;
+            assert!(Plaintext::from_json_value(&plaintext_type, &structs, &json).is_err());
+        }
+        // Ensure missing or unknown struct members fail.
+        let point = PlaintextType::from_str("point")?;
+        assert!(Plaintext::from_json_value(&point, &structs, &json!({ "x": 1 })).is_err());
+        assert!(Plaintext::from_json_value(&point, &structs, &json!({ "x": 1, "z": "1" })).is_err());
+        assert!(Plaintext::from_json_value(&point, &structs, &json!({ "x": 1, "y": "1", "z": 1 })).is_err());
+        Ok(())
+    }
+}
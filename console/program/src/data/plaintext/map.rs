@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::cmp::Ordering;
+
+impl<N: Network> Plaintext<N> {
+    /// Initializes a new map from the given entries, which are sorted into their canonical order.
+    ///
+    /// The canonical order sorts the keys by their literal variant, and then by their big-endian bits,
+    /// so that equal maps have the same encoding.
+    pub fn new_map(mut entries: Vec<(Literal<N>, Plaintext<N>)>) -> Result<Self> {
+        // Ensure the number of entries is within the maximum limit.
+        ensure!(entries.len() <= N::MAX_MAP_ENTRIES, "A map can contain at most {} entries", N::MAX_MAP_ENTRIES);
+        // Sort the entries by their keys.
+        entries.sort_by(|(a, _), (b, _)| Self::cmp_map_keys(a, b));
+        // Ensure the map is canonical, which also ensures the keys are unique.
+        Self::check_map_entries(&entries)?;
+        // Return the map.
+        Ok(Self::Map(entries, Default::default()))
+    }
+
+    /// Ensures the given map entries are in their canonical order, with unique keys, and within the maximum size.
+    pub fn check_map_entries(entries: &[(Literal<N>, Plaintext<N>)]) -> Result<()> {
+        // Ensure the number of entries is within the maximum limit.
+        ensure!(entries.len() <= N::MAX_MAP_ENTRIES, "A map can contain at most {} entries", N::MAX_MAP_ENTRIES);
+        // Ensure the keys are strictly increasing.
+        for window in entries.windows(2) {
+            match Self::cmp_map_keys(&window[0].0, &window[1].0) {
+                Ordering::Less => (),
+                Ordering::Equal => bail!("Duplicate key '{}' in map", window[0].0),
+                Ordering::Greater => bail!("Map keys are not in canonical order"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the canonical ordering of the given map keys.
+    fn cmp_map_keys(a: &Literal<N>, b: &Literal<N>) -> Ordering {
+        a.variant().cmp(&b.variant()).then_with(|| a.to_bits_be().cmp(&b.to_bits_be()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn entry(key: &str, value: &str) -> (Literal<CurrentNetwork>, Plaintext<CurrentNetwork>) {
+        (Literal::from_str(key).unwrap(), Plaintext::from_str(value).unwrap())
+    }
+
+    #[test]
+    fn test_new_map() -> Result<()> {
+        // Ensure the entries are sorted into canonical order.
+        let expected = Plaintext::new_map(vec![entry("1u8", "10u64"), entry("2u8", "20u64"), entry("3u8", "30u64")])?;
+        let candidate = Plaintext::new_map(vec![entry("3u8", "30u64"), entry("1u8", "10u64"), entry("2u8", "20u64")])?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_bits_le(), candidate.to_bits_le());
+        assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+
+        // Ensure an empty map is valid.
+        let empty = Plaintext::<CurrentNetwork>::new_map(vec![])?;
+        assert_ne!(empty, expected);
+
+        // Ensure duplicate keys are rejected.
+        assert!(Plaintext::new_map(vec![entry("1u8", "10u64"), entry("1u8", "20u64")]).is_err());
+
+        // Ensure the maximum number of entries is enforced.
+        let entries = (0..=CurrentNetwork::MAX_MAP_ENTRIES).map(|i| entry(&format!("{i}u8"), "true")).collect();
+        assert!(Plaintext::new_map(entries).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_map_entries() {
+        // Ensure entries out of canonical order are rejected.
+        assert!(Plaintext::check_map_entries(&[entry("1u8", "10u64"), entry("2u8", "20u64")]).is_ok());
+        assert!(Plaintext::check_map_entries(&[entry("2u8", "20u64"), entry("1u8", "10u64")]).is_err());
+        assert!(Plaintext::check_map_entries(&[entry("1u8", "10u64"), entry("1u8", "10u64")]).is_err());
+    }
+}
@@ -12,14 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod to_hash;
+pub use to_hash::PlaintextHasher;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod encrypt;
 mod equal;
+mod eth_abi;
 mod find;
 mod from_bits;
 mod from_fields;
+mod json;
+mod map;
 mod num_randomizers;
 mod parse;
+mod path;
 mod serialize;
 mod size_in_fields;
 mod to_bits;
@@ -40,6 +49,8 @@ pub enum Plaintext<N: Network> {
     Struct(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
     /// An array.
     Array(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// A map, with its entries sorted by key in canonical order.
+    Map(Vec<(Literal<N>, Plaintext<N>)>, OnceCell<Vec<bool>>),
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -226,6 +237,15 @@ mod tests {
             OnceCell::new(),
         ));
 
+        // Test a map of structs.
+        run_test(Plaintext::<CurrentNetwork>::new_map(vec![
+            (Literal::from_str("1u8")?, Plaintext::<CurrentNetwork>::from_str("{ x: 0field, y: 1field }")?),
+            (Literal::from_str("2u8")?, Plaintext::<CurrentNetwork>::from_str("{ x: 2field, y: 3field }")?),
+        ])?);
+
+        // Test an empty map.
+        run_test(Plaintext::<CurrentNetwork>::new_map(vec![])?);
+
         Ok(())
     }
 }
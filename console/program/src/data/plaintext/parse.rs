@@ -18,8 +18,15 @@ impl<N: Network> Parser for Plaintext<N> {
     /// Parses a string into a plaintext value.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        Self::parse_internal(string, 0)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Parses a string into a plaintext value, at the given nesting depth.
+    fn parse_internal(string: &str, depth: usize) -> ParserResult<Self> {
         /// Parses a sanitized pair: `identifier: plaintext`.
-        fn parse_pair<N: Network>(string: &str) -> ParserResult<(Identifier<N>, Plaintext<N>)> {
+        fn parse_pair<N: Network>(string: &str, depth: usize) -> ParserResult<(Identifier<N>, Plaintext<N>)> {
             // Parse the whitespace and comments from the string.
             let (string, _) = Sanitizer::parse(string)?;
             // Parse the identifier from the string.
@@ -29,7 +36,7 @@ impl<N: Network> Parser for Plaintext<N> {
             // Parse the ":" from the string.
             let (string, _) = tag(":")(string)?;
             // Parse the plaintext from the string.
-            let (string, plaintext) = Plaintext::parse(string)?;
+            let (string, plaintext) = Plaintext::parse_internal(string, depth + 1)?;
             // Parse the whitespace from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
             // Return the identifier and plaintext.
@@ -37,23 +44,24 @@ impl<N: Network> Parser for Plaintext<N> {
         }
 
         /// Parses a plaintext as a struct: `{ identifier_0: plaintext_0, ..., identifier_n: plaintext_n }`.
-        fn parse_struct<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+        fn parse_struct<N: Network>(string: &str, depth: usize) -> ParserResult<Plaintext<N>> {
             // Parse the whitespace and comments from the string.
             let (string, _) = Sanitizer::parse(string)?;
             // Parse the "{" from the string.
             let (string, _) = tag("{")(string)?;
             // Parse the members.
-            let (string, members) = map_res(separated_list1(tag(","), parse_pair), |members: Vec<_>| {
-                // Ensure the members has no duplicate names.
-                if has_duplicates(members.iter().map(|(name, ..)| name)) {
-                    return Err(error("Duplicate member in struct"));
-                }
-                // Ensure the number of structs is within the maximum limit.
-                match members.len() <= N::MAX_STRUCT_ENTRIES {
-                    true => Ok(members),
-                    false => Err(error(format!("Found a plaintext that exceeds size ({})", members.len()))),
-                }
-            })(string)?;
+            let (string, members) =
+                map_res(separated_list1(tag(","), |string| parse_pair(string, depth)), |members: Vec<_>| {
+                    // Ensure the members has no duplicate names.
+                    if has_duplicates(members.iter().map(|(name, ..)| name)) {
+                        return Err(error("Duplicate member in struct"));
+                    }
+                    // Ensure the number of structs is within the maximum limit.
+                    match members.len() <= N::MAX_STRUCT_ENTRIES {
+                        true => Ok(members),
+                        false => Err(error(format!("Found a plaintext that exceeds size ({})", members.len()))),
+                    }
+                })(string)?;
             // Parse the whitespace and comments from the string.
             let (string, _) = Sanitizer::parse(string)?;
             // Parse the '}' from the string.
@@ -63,13 +71,22 @@ impl<N: Network> Parser for Plaintext<N> {
         }
 
         /// Parses a plaintext as an array: `[plaintext_0, ..., plaintext_n]`.
-        fn parse_array<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+        fn parse_array<N: Network>(string: &str, depth: usize) -> ParserResult<Plaintext<N>> {
             // Parse the whitespace and comments from the string.
             let (string, _) = Sanitizer::parse(string)?;
             // Parse the "[" from the string.
             let (string, _) = tag("[")(string)?;
             // Parse the members.
-            let (string, members) = separated_list1(tag(","), Plaintext::parse)(string)?;
+            let (string, members) = map_res(
+                separated_list1(tag(","), |string| Plaintext::parse_internal(string, depth + 1)),
+                |members: Vec<_>| {
+                    // Ensure the number of elements is within the maximum limit.
+                    match members.len() <= N::MAX_ARRAY_ELEMENTS {
+                        true => Ok(members),
+                        false => Err(error(format!("Found an array that exceeds size ({})", members.len()))),
+                    }
+                },
+            )(string)?;
             // Parse the whitespace and comments from the string.
             let (string, _) = Sanitizer::parse(string)?;
             // Parse the ']' from the string.
@@ -78,6 +95,48 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Array(members, Default::default())))
         }
 
+        /// Parses a sanitized entry: `literal => plaintext`.
+        fn parse_entry<N: Network>(string: &str, depth: usize) -> ParserResult<(Literal<N>, Plaintext<N>)> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the key from the string.
+            let (string, key) = Literal::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "=>" from the string.
+            let (string, _) = tag("=>")(string)?;
+            // Parse the plaintext from the string.
+            let (string, plaintext) = Plaintext::parse_internal(string, depth + 1)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Return the key and plaintext.
+            Ok((string, (key, plaintext)))
+        }
+
+        /// Parses a plaintext as a map: `{ literal_0 => plaintext_0, ..., literal_n => plaintext_n }`.
+        fn parse_map<N: Network>(string: &str, depth: usize) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "{" from the string.
+            let (string, _) = tag("{")(string)?;
+            // Parse the entries, and sort them into canonical order.
+            let (string, map) =
+                map_res(separated_list0(tag(","), |string| parse_entry(string, depth)), Plaintext::new_map)(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the '}' from the string.
+            let (string, _) = tag("}")(string)?;
+            // Output the plaintext.
+            Ok((string, map))
+        }
+
+        // Ensure the plaintext does not exceed the maximum depth.
+        if depth > N::MAX_DATA_DEPTH {
+            return map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Found a plaintext that exceeds the maximum depth of {}", N::MAX_DATA_DEPTH)))
+            })(string);
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -85,9 +144,11 @@ impl<N: Network> Parser for Plaintext<N> {
             // Parse a plaintext literal.
             map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
             // Parse a plaintext struct.
-            parse_struct,
+            |string| parse_struct(string, depth),
             // Parse a plaintext array.
-            parse_array,
+            |string| parse_array(string, depth),
+            // Parse a plaintext map.
+            |string| parse_map(string, depth),
         ))(string)
     }
 }
@@ -125,7 +186,7 @@ impl<N: Network> Display for Plaintext<N> {
 
 impl<N: Network> Plaintext<N> {
     /// Prints the plaintext with the given indentation depth.
-    fn fmt_internal(&self, f: &mut Formatter, depth: usize) -> fmt::Result {
+    pub(in crate::data) fn fmt_internal(&self, f: &mut Formatter, depth: usize) -> fmt::Result {
         /// The number of spaces to indent.
         const INDENT: usize = 2;
 
@@ -149,7 +210,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) | Self::Array(..) => {
+                        Self::Struct(..) | Self::Array(..) | Self::Map(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -182,7 +243,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) | Self::Array(..) => {
+                        Self::Struct(..) | Self::Array(..) | Self::Map(..) => {
                             // Print a newline.
                             write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -198,6 +259,41 @@ impl<N: Network> Plaintext<N> {
                     }
                 })
             }
+            // Prints the empty map, i.e. {}
+            Self::Map(entries, ..) if entries.is_empty() => write!(f, "{{}}"),
+            // Prints the map, i.e. { 1u8 => 10i64, 2u8 => 198i64 }
+            Self::Map(entries, ..) => {
+                // Print the opening brace.
+                write!(f, "{{")?;
+                // Print the entries.
+                entries.iter().enumerate().try_for_each(|(i, (key, plaintext))| {
+                    match plaintext {
+                        Self::Literal(literal, ..) => match i == entries.len() - 1 {
+                            true => {
+                                // Print the last entry without a comma.
+                                write!(f, "\n{:indent$}{key} => {literal}", "", indent = (depth + 1) * INDENT)?;
+                                // Print the closing brace.
+                                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+                            }
+                            // Print the entry with a comma.
+                            false => write!(f, "\n{:indent$}{key} => {literal},", "", indent = (depth + 1) * INDENT),
+                        },
+                        Self::Struct(..) | Self::Array(..) | Self::Map(..) => {
+                            // Print the key.
+                            write!(f, "\n{:indent$}{key} => ", "", indent = (depth + 1) * INDENT)?;
+                            // Print the value.
+                            plaintext.fmt_internal(f, depth + 1)?;
+                            // Print the closing brace.
+                            match i == entries.len() - 1 {
+                                // Print the last entry without a comma.
+                                true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
+                                // Print the entry with a comma.
+                                false => write!(f, ","),
+                            }
+                        }
+                    }
+                })
+            }
         }
     }
 }
@@ -264,11 +360,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_map() -> Result<()> {
+        // Sanity check.
+        let expected = r"{
+  1u8 => 10u64,
+  2u8 => {
+    foo: 5u8
+  },
+  3u8 => [
+    true
+  ]
+}";
+        let (remainder, candidate) =
+            Plaintext::<CurrentNetwork>::parse("{ 3u8 => [true], 1u8 => 10u64, 2u8 => { foo: 5u8 } }")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+        assert_eq!(candidate, Plaintext::from_str(expected)?);
+
+        // Ensure an empty map is parsed.
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("{ }")?;
+        assert!(matches!(candidate, Plaintext::Map(ref entries, ..) if entries.is_empty()));
+        assert_eq!("{}", candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure duplicate keys are rejected.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ 1u8 => 10u64, 1u8 => 20u64 }").is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
         assert!(Plaintext::<CurrentNetwork>::parse("").is_err());
-        assert!(Plaintext::<CurrentNetwork>::parse("{}").is_err());
 
         // Invalid characters.
         assert!(Plaintext::<CurrentNetwork>::parse("_").is_err());
@@ -295,6 +420,23 @@ mod tests {
         assert!(plaintext.is_err());
     }
 
+    #[test]
+    fn test_parse_limits() -> Result<()> {
+        // Ensure an array may contain up to the maximum number of elements.
+        let elements = |n: usize| format!("[{}]", vec!["0u8"; n].join(", "));
+        assert!(Plaintext::<CurrentNetwork>::from_str(&elements(CurrentNetwork::MAX_ARRAY_ELEMENTS)).is_ok());
+        assert!(Plaintext::<CurrentNetwork>::from_str(&elements(CurrentNetwork::MAX_ARRAY_ELEMENTS + 1)).is_err());
+
+        // Ensure a plaintext may be nested up to the maximum depth.
+        let nested = |depth: usize| format!("{}0u8{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(Plaintext::<CurrentNetwork>::from_str(&nested(CurrentNetwork::MAX_DATA_DEPTH)).is_ok());
+        assert!(Plaintext::<CurrentNetwork>::from_str(&nested(CurrentNetwork::MAX_DATA_DEPTH + 1)).is_err());
+        let nested = |depth: usize| format!("{}0u8{}", "{ a: ".repeat(depth), " }".repeat(depth));
+        assert!(Plaintext::<CurrentNetwork>::from_str(&nested(CurrentNetwork::MAX_DATA_DEPTH)).is_ok());
+        assert!(Plaintext::<CurrentNetwork>::from_str(&nested(CurrentNetwork::MAX_DATA_DEPTH + 1)).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_nested_structs1() {
         let expected = r"{
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the plaintext member at the given dotted path, i.e. `token.owner[2].amount`.
+    pub fn get_path(&self, path: &str) -> Result<Plaintext<N>> {
+        self.find(&Access::parse_path(path)?)
+    }
+
+    /// Replaces the plaintext member at the given dotted path, i.e. `token.owner[2].amount`.
+    pub fn set_path(&mut self, path: &str, plaintext: Plaintext<N>) -> Result<()> {
+        self.set(&Access::parse_path(path)?, plaintext)
+    }
+
+    /// Replaces the plaintext member at the given path.
+    ///
+    /// Note: This method does not check that `plaintext` has the same type as the member it replaces.
+    pub fn set<A: Into<Access<N>> + Copy + Debug>(&mut self, path: &[A], plaintext: Plaintext<N>) -> Result<()> {
+        // Ensure the path is not empty.
+        let Some((first, rest)) = path.split_first() else { bail!("Attempted to set a member with an empty path.") };
+
+        let access = (*first).into();
+        match self {
+            Self::Struct(members, bits_le) => {
+                let Access::Member(identifier) = access else { bail!("Invalid access `{access}` for a struct") };
+                match members.get_mut(&identifier) {
+                    Some(member) => {
+                        match rest.is_empty() {
+                            true => *member = plaintext,
+                            false => member.set(rest, plaintext)?,
+                        }
+                        // Clear the cached bits, as the struct has changed.
+                        *bits_le = OnceCell::new();
+                    }
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}'"),
+                }
+            }
+            Self::Array(elements, bits_le) => {
+                let Access::Index(index) = access else { bail!("Invalid access `{access}` for an array") };
+                match elements.get_mut(*index as usize) {
+                    Some(element) => {
+                        match rest.is_empty() {
+                            true => *element = plaintext,
+                            false => element.set(rest, plaintext)?,
+                        }
+                        // Clear the cached bits, as the array has changed.
+                        *bits_le = OnceCell::new();
+                    }
+                    // Halts if the index is out of bounds.
+                    None => bail!("Index '{index}' is out of bounds"),
+                }
+            }
+            Self::Literal(..) => bail!("Invalid access `{access}` for a literal"),
+            Self::Map(..) => bail!("Invalid access `{access}` for a map, which does not support member access"),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_get_and_set_path() -> Result<()> {
+        let mut plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ token: { owner: [0u64, 1u64, { amount: 5u64 }], flag: true }, count: 3u8 }",
+        )?;
+
+        // Ensure nested members can be retrieved.
+        assert_eq!(plaintext.get_path("count")?, Plaintext::from_str("3u8")?);
+        assert_eq!(plaintext.get_path("token.owner[1]")?, Plaintext::from_str("1u64")?);
+        assert_eq!(plaintext.get_path("token.owner[2].amount")?, Plaintext::from_str("5u64")?);
+
+        // Ensure the bits are cached before the update.
+        let bits_before = plaintext.to_bits_le();
+
+        // Ensure nested members can be updated.
+        plaintext.set_path("token.owner[2].amount", Plaintext::from_str("6u64")?)?;
+        assert_eq!(plaintext.get_path("token.owner[2].amount")?, Plaintext::from_str("6u64")?);
+        plaintext.set_path("token.flag", Plaintext::from_str("false")?)?;
+
+        // Ensure the cached bits are refreshed.
+        let expected = Plaintext::<CurrentNetwork>::from_str(
+            "{ token: { owner: [0u64, 1u64, { amount: 6u64 }], flag: false }, count: 3u8 }",
+        )?;
+        assert_eq!(plaintext, expected);
+        assert_ne!(plaintext.to_bits_le(), bits_before);
+        assert_eq!(plaintext.to_bits_le(), expected.to_bits_le());
+
+        // Ensure invalid paths fail.
+        assert!(plaintext.get_path("token.missing").is_err());
+        assert!(plaintext.get_path("token.owner[3]").is_err());
+        assert!(plaintext.set_path("count.value", Plaintext::from_str("1u8")?).is_err());
+        assert!(plaintext.set_path("token[0]", Plaintext::from_str("1u8")?).is_err());
+        assert!(plaintext.set_path("token.owner.amount", Plaintext::from_str("1u8")?).is_err());
+        assert!(plaintext.set_path("", Plaintext::from_str("1u8")?).is_err());
+        Ok(())
+    }
+}
@@ -85,6 +85,35 @@ impl<N: Network> ToBits for Plaintext<N> {
                 // Extend the vector with the bits.
                 vec.extend_from_slice(bits)
             }
+            Self::Map(entries, bits_le) => {
+                // Compute the bits.
+                let bits = bits_le.get_or_init(|| {
+                    let mut bits_le = vec![true, true]; // Variant bits.
+
+                    // Write the number of entries in the map.
+                    u32::try_from(entries.len())
+                        .or_halt_with::<N>("Plaintext map length exceeds u32::MAX")
+                        .write_bits_le(&mut bits_le);
+
+                    // Write each entry of the map.
+                    for (key, value) in entries {
+                        // Write the key.
+                        key.variant().write_bits_le(&mut bits_le);
+                        key.size_in_bits().write_bits_le(&mut bits_le);
+                        key.write_bits_le(&mut bits_le);
+
+                        // Write the value.
+                        let value_bits = value.to_bits_le();
+                        u16::try_from(value_bits.len())
+                            .or_halt_with::<N>("Plaintext map value exceeds u16::MAX bits")
+                            .write_bits_le(&mut bits_le);
+                        bits_le.extend(value_bits);
+                    }
+                    bits_le
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 
@@ -159,6 +188,35 @@ impl<N: Network> ToBits for Plaintext<N> {
                 // Extend the vector with the bits.
                 vec.extend_from_slice(bits)
             }
+            Self::Map(entries, bits_be) => {
+                // Compute the bits.
+                let bits = bits_be.get_or_init(|| {
+                    let mut bits_be = vec![true, true]; // Variant bits.
+
+                    // Write the number of entries in the map.
+                    u32::try_from(entries.len())
+                        .or_halt_with::<N>("Plaintext map length exceeds u32::MAX")
+                        .write_bits_be(&mut bits_be);
+
+                    // Write each entry of the map.
+                    for (key, value) in entries {
+                        // Write the key.
+                        key.variant().write_bits_be(&mut bits_be);
+                        key.size_in_bits().write_bits_be(&mut bits_be);
+                        key.write_bits_be(&mut bits_be);
+
+                        // Write the value.
+                        let value_bits = value.to_bits_be();
+                        u16::try_from(value_bits.len())
+                            .or_halt_with::<N>("Plaintext map value exceeds u16::MAX bits")
+                            .write_bits_be(&mut bits_be);
+                        bits_be.extend(value_bits);
+                    }
+                    bits_be
+                });
+                // Extend the vector with the bits.
+                vec.extend_from_slice(bits)
+            }
         }
     }
 }
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon2, Poseidon4, Poseidon8};
+
+/// The domain separator for the canonical hash of a plaintext.
+const PLAINTEXT_HASH_DOMAIN: &str = "AleoPlaintextHash0";
+
+/// A Poseidon hash function, as instantiated by the network, that may be used to hash a plaintext.
+pub trait PlaintextHasher<N: Network> {
+    /// Returns the hash of the given field elements.
+    fn hash_fields(input: &[Field<N>]) -> Result<Field<N>>;
+}
+
+impl<N: Network> PlaintextHasher<N> for Poseidon2<N> {
+    /// Returns the hash of the given field elements, using Poseidon with a rate of 2.
+    fn hash_fields(input: &[Field<N>]) -> Result<Field<N>> {
+        N::hash_psd2(input)
+    }
+}
+
+impl<N: Network> PlaintextHasher<N> for Poseidon4<N> {
+    /// Returns the hash of the given field elements, using Poseidon with a rate of 4.
+    fn hash_fields(input: &[Field<N>]) -> Result<Field<N>> {
+        N::hash_psd4(input)
+    }
+}
+
+impl<N: Network> PlaintextHasher<N> for Poseidon8<N> {
+    /// Returns the hash of the given field elements, using Poseidon with a rate of 8.
+    fn hash_fields(input: &[Field<N>]) -> Result<Field<N>> {
+        N::hash_psd8(input)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the canonical hash of the plaintext under the Poseidon hash function `H`,
+    /// e.g. `plaintext.to_hash::<Poseidon2<N>>()`.
+    ///
+    /// The hash is computed over a domain separator and the field encoding of the plaintext,
+    /// which includes the variant, member names, and length of every nested value.
+    /// As such, plaintexts with equal literals but different structures have distinct hashes.
+    pub fn to_hash<H: PlaintextHasher<N>>(&self) -> Result<Field<N>> {
+        // Construct the preimage as `[ PLAINTEXT_HASH_DOMAIN || FIELDS(plaintext) ]`.
+        let mut preimage = vec![Field::new_domain_separator(PLAINTEXT_HASH_DOMAIN)];
+        preimage.extend(self.to_fields()?);
+        // Hash the preimage.
+        H::hash_fields(&preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_hash() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: [2u8, 3u8] }")?;
+        let expected = plaintext.to_hash::<Poseidon2<CurrentNetwork>>()?;
+
+        // Ensure the hash is deterministic, and depends on the hash function.
+        assert_eq!(
+            expected,
+            Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: [2u8, 3u8] }")?.to_hash::<Poseidon2<_>>()?
+        );
+        assert_ne!(expected, plaintext.to_hash::<Poseidon4<CurrentNetwork>>()?);
+        assert_ne!(expected, plaintext.to_hash::<Poseidon8<CurrentNetwork>>()?);
+
+        // Ensure the hash is domain-separated from the hash of the field encoding.
+        assert_ne!(expected, CurrentNetwork::hash_psd2(&plaintext.to_fields()?)?);
+
+        // Ensure plaintexts with the same literals but different structures have distinct hashes.
+        for candidate in [
+            "{ a: 1u8, c: [2u8, 3u8] }",
+            "{ b: [2u8, 3u8], a: 1u8 }",
+            "{ a: 1u8, b: { c: 2u8, d: 3u8 } }",
+            "[1u8, 2u8, 3u8]",
+        ] {
+            let candidate = Plaintext::<CurrentNetwork>::from_str(candidate)?;
+            assert_ne!(expected, candidate.to_hash::<Poseidon2<CurrentNetwork>>()?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Record<N, Plaintext<N>> {
+    /// Returns an arbitrary record plaintext, within the maximum number of entries.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        // Sample the owner and nonce from an RNG seeded by the input, so that they are valid.
+        let rng = &mut TestRng::from_seed(u.arbitrary()?);
+        let owner = match u.arbitrary::<bool>()? {
+            true => Owner::Public(Address::rand(rng)),
+            false => Owner::Private(Plaintext::from(Literal::Address(Address::rand(rng)))),
+        };
+        let nonce = Group::rand(rng);
+
+        // Sample the entries.
+        let reserved = Identifier::from_str("owner").map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+        let num_entries = u.int_in_range(0..=N::MAX_DATA_ENTRIES)?;
+        let mut data = IndexMap::with_capacity(num_entries);
+        for _ in 0..num_entries {
+            // Note: Duplicate or reserved entry names are skipped, so the record may have fewer entries.
+            let name = Identifier::arbitrary(u)?;
+            let plaintext = Plaintext::arbitrary_with_depth(u, 1)?;
+            let entry = match u.int_in_range(0..=2)? {
+                0 => Entry::Constant(plaintext),
+                1 => Entry::Public(plaintext),
+                _ => Entry::Private(plaintext),
+            };
+            if name != reserved {
+                data.entry(name).or_insert(entry);
+            }
+        }
+        Self::from_plaintext(owner, data, nonce).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+}
@@ -78,6 +78,13 @@ impl<N: Network, Private: Visibility> ToBytes for Record<N, Private> {
     }
 }
 
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// Returns the number of bytes in the serialized record, without allocating the full byte buffer.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(ByteCounter::count(self)?)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +102,8 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Record::read_le(&expected_bytes[..])?);
+        // Check the size in bytes.
+        assert_eq!(expected.size_in_bytes()?, expected_bytes.len() as u64);
         Ok(())
     }
 }
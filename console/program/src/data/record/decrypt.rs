@@ -28,6 +28,27 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         }
     }
 
+    /// Decrypts `self` into plaintext using the decryption shares of a Shamir-shared view key,
+    /// and checks that the owner matches the given address.
+    /// Note: This method does not verify the decryption shares. Use `DecryptionShare::verify` to identify invalid shares.
+    pub fn decrypt_with_shares(
+        &self,
+        address: &Address<N>,
+        shares: &[DecryptionShare<N>],
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Compute the record view key.
+        let record_view_key = DecryptionShare::combine(shares)?;
+        // Decrypt the record.
+        let record = self.decrypt_symmetric_unchecked(&record_view_key)?;
+        // Ensure the record owner matches the address.
+        match *address == **record.owner() {
+            true => Ok(record),
+            false => bail!(
+                "Illegal operation: Record::decrypt_with_shares() address does not correspond to the record owner."
+            ),
+        }
+    }
+
     /// Decrypts `self` into plaintext using the given record view key.
     /// Note: This method does not check that the record view key corresponds to the record owner.
     /// Use `Self::decrypt` for the checked variant.
@@ -133,6 +154,16 @@ mod tests {
         // Ensure that decrypting with the incorrect view key fails.
         assert!(ciphertext.decrypt(&incorrect_view_key).is_err());
 
+        // Split the view key into shares, and decrypt the record with any 2 of 3 decryption shares.
+        let address = view_key.to_address();
+        let shares = view_key.split(2, 3, rng)?;
+        let decryption_shares =
+            shares.iter().map(|share| share.decrypt(record.nonce, rng)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(record, ciphertext.decrypt_with_shares(&address, &decryption_shares[1..])?);
+        // Ensure that decrypting with too few shares, or for the incorrect address, fails.
+        assert!(ciphertext.decrypt_with_shares(&address, &decryption_shares[..1]).is_err());
+        assert!(ciphertext.decrypt_with_shares(&incorrect_view_key.to_address(), &decryption_shares).is_err());
+
         Ok(())
     }
 
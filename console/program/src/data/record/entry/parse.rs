@@ -46,6 +46,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_struct,
                 // Parse an array.
                 parse_array,
+                // Parse a map.
+                parse_map,
             ))(string)?;
             // Parse the whitespace from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
@@ -111,7 +113,7 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             let (string, (elements, mode)) = map_res(
                 separated_list1(
                     pair(Sanitizer::parse_whitespaces, pair(tag(","), Sanitizer::parse_whitespaces)),
-                    alt((parse_literal, parse_struct, parse_array)),
+                    alt((parse_literal, parse_struct, parse_array, parse_map)),
                 ),
                 |members: Vec<(Plaintext<N>, Mode)>| {
                     // Ensure the members all have the same visibility.
@@ -136,6 +138,24 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             Ok((string, (Plaintext::Array(elements, Default::default()), mode)))
         }
 
+        /// Parses an entry as a map: `{ literal_0 => plaintext_0, ..., literal_n => plaintext_n }.visibility`.
+        /// Observe the `visibility` is the same for all entries of the plaintext value.
+        fn parse_map<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            // Parse the map from the string.
+            let (string, plaintext) = map_res(Plaintext::parse, |plaintext| match plaintext {
+                Plaintext::Map(..) => Ok(plaintext),
+                _ => Err(error("Expected a map")),
+            })(string)?;
+            // Parse the visibility from the string.
+            let (string, mode) = alt((
+                map(tag(".constant"), |_| Mode::Constant),
+                map(tag(".public"), |_| Mode::Public),
+                map(tag(".private"), |_| Mode::Private),
+            ))(string)?;
+            // Output the plaintext and visibility.
+            Ok((string, (plaintext, mode)))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the entry (order matters).
@@ -146,6 +166,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_struct,
             // Parse an array.
             parse_array,
+            // Parse a map.
+            parse_map,
         ))(string)?;
 
         // Return the entry.
@@ -223,7 +245,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Map(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -261,7 +283,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) | Plaintext::Map(..) => {
                             // Print a new line.
                             write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -281,6 +303,13 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                     }
                 })
             }
+            // Prints the map, i.e. { 1u8 => 10i64, 2u8 => 198i64 }.private
+            Plaintext::Map(..) => {
+                // Print the map.
+                plaintext.fmt_internal(f, depth)?;
+                // Print the visibility.
+                write!(f, ".{visibility}")
+            }
         }
     }
 }
@@ -388,6 +417,34 @@ mod tests {
         assert_eq!(expected, candidate.to_string());
         assert_eq!("", remainder);
 
+        // Test a map.
+        let expected = r"{
+  1u8 => 10u64,
+  2u8 => 20u64
+}.private";
+        let (remainder, candidate) =
+            Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("{ 2u8 => 20u64, 1u8 => 10u64 }.private")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Test an empty map.
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("{}.public")?;
+        assert_eq!("{}.public", candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Test a struct with a map.
+        let expected = r"{
+  foo: {
+    1u8 => 10u64
+  }.public,
+  bar: 5u8.public
+}";
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(
+            "{ foo: { 1u8 => 10u64 }.public, bar: 5u8.public }",
+        )?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
         Ok(())
     }
 }
@@ -15,7 +15,8 @@
 use super::*;
 
 impl<N: Network> Record<N, Ciphertext<N>> {
-    /// Decrypts `self` into plaintext using the given view key.
+    /// Returns `true` if the given view key owns `self`.
+    /// This decrypts only the owner, which costs one scalar multiplication and one hash, instead of the full record.
     pub fn is_owner(&self, view_key: &ViewKey<N>) -> bool {
         // Compute the address.
         let address = view_key.to_address();
@@ -23,7 +24,8 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         self.is_owner_with_address_x_coordinate(view_key, &address.to_x_coordinate())
     }
 
-    /// Decrypts `self` into plaintext using the x-coordinate of the address corresponding to the given view key.
+    /// Returns `true` if the given view key owns `self`, using the x-coordinate of the address of the view key.
+    /// When scanning many records, precompute the x-coordinate once to avoid deriving the address for every record.
     pub fn is_owner_with_address_x_coordinate(&self, view_key: &ViewKey<N>, address_x_coordinate: &Field<N>) -> bool {
         // In debug mode, check that the address corresponds to the given view key.
         debug_assert_eq!(
@@ -18,6 +18,8 @@ pub use entry::Entry;
 mod helpers;
 pub use helpers::Owner;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod decrypt;
 mod encrypt;
@@ -29,13 +31,14 @@ mod parse_ciphertext;
 mod parse_plaintext;
 mod serial_number;
 mod serialize;
+mod set;
 mod tag;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
 
 use crate::{Access, Ciphertext, Identifier, Literal, Plaintext, ProgramID};
-use snarkvm_console_account::{Address, PrivateKey, ViewKey};
+use snarkvm_console_account::{Address, DecryptionShare, PrivateKey, ViewKey};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group, Scalar};
 
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Replaces the entry member at the given path, preserving the visibility of the entry.
+    ///
+    /// Note: This method does not check that `plaintext` has the same type as the member it replaces.
+    pub fn set<A: Into<Access<N>> + Copy + Debug>(&mut self, path: &[A], plaintext: Plaintext<N>) -> Result<()> {
+        // Ensure the path is not empty.
+        let Some((first, rest)) = path.split_first() else {
+            bail!("Attempted to set a record entry with an empty path.")
+        };
+        let first = match (*first).into() {
+            Access::Member(identifier) => identifier,
+            Access::Index(_) => bail!("Attempted to index into a record"),
+        };
+
+        // If the path is requesting the `owner`, replace the owner address.
+        if first == Identifier::from_str("owner")? {
+            ensure!(rest.is_empty(), "Attempted to access a member of the record owner");
+            let Plaintext::Literal(Literal::Address(address), ..) = plaintext else {
+                bail!("The record owner must be an address, found '{plaintext}'")
+            };
+            self.owner = match self.owner {
+                Owner::Public(..) => Owner::Public(address),
+                Owner::Private(..) => Owner::Private(Plaintext::from(Literal::Address(address))),
+            };
+            return Ok(());
+        }
+
+        // Retrieve the top-level entry.
+        let Some(entry) = self.data.get_mut(&first) else { bail!("Record entry `{first}` not found.") };
+        let (Entry::Constant(member) | Entry::Public(member) | Entry::Private(member)) = entry;
+        match rest.is_empty() {
+            // If the remaining path is empty, replace the top-level entry.
+            true => *member = plaintext,
+            // Otherwise, recursively call `set` on the top-level entry.
+            false => member.set(rest, plaintext)?,
+        }
+        Ok(())
+    }
+}
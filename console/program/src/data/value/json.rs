@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Identifier, StructType, ValueType};
+
+use indexmap::IndexMap;
+use serde_json::Value as JsonValue;
+
+impl<N: Network> Value<N> {
+    /// Returns the value as a plain JSON value, without the Aleo literal type suffixes.
+    ///
+    /// Note: Only plaintext values are supported, as records and futures carry data that JSON cannot express.
+    pub fn to_json_value(&self) -> Result<JsonValue> {
+        match self {
+            Self::Plaintext(plaintext) => Ok(plaintext.to_json_value()),
+            Self::Record(..) => bail!("Cannot convert a record into a JSON value"),
+            Self::Future(..) => bail!("Cannot convert a future into a JSON value"),
+        }
+    }
+
+    /// Initializes a value from a plain JSON value, using the given value type and struct definitions.
+    pub fn from_json_value(
+        value_type: &ValueType<N>,
+        structs: &IndexMap<Identifier<N>, StructType<N>>,
+        json: &JsonValue,
+    ) -> Result<Self> {
+        match value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => {
+                Ok(Self::Plaintext(Plaintext::from_json_value(plaintext_type, structs, json)?))
+            }
+            ValueType::Record(..) | ValueType::ExternalRecord(..) => bail!("Cannot convert a JSON value into a record"),
+            ValueType::Future(..) => bail!("Cannot convert a JSON value into a future"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use serde_json::json;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json_value() -> Result<()> {
+        let structs = IndexMap::new();
+
+        // Ensure a plaintext value round-trips through JSON.
+        let value_type = ValueType::<CurrentNetwork>::from_str("[u64; 2u32].private")?;
+        let value = Value::<CurrentNetwork>::from_str("[1u64, 18446744073709551615u64]")?;
+        let json = value.to_json_value()?;
+        assert_eq!(json, json!(["1", "18446744073709551615"]));
+        assert_eq!(Value::from_json_value(&value_type, &structs, &json)?, value);
+
+        // Ensure records are rejected.
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, _nonce: 0group.public }",
+        )?;
+        assert!(record.to_json_value().is_err());
+        let record_type = ValueType::<CurrentNetwork>::from_str("token.record")?;
+        assert!(Value::from_json_value(&record_type, &structs, &json!({})).is_err());
+        Ok(())
+    }
+}
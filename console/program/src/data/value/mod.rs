@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod equal;
 mod find;
+mod json;
 mod parse;
+mod path;
 mod serialize;
 mod to_bits;
 mod to_fields;
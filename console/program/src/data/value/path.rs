@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the value at the given dotted path, i.e. `token.owner[2].amount`.
+    pub fn get_path(&self, path: &str) -> Result<Self> {
+        self.find(&Access::parse_path(path)?)
+    }
+
+    /// Replaces the plaintext member at the given dotted path, i.e. `token.owner[2].amount`.
+    ///
+    /// Note: This method does not check that `plaintext` has the same type as the member it replaces.
+    pub fn set_path(&mut self, path: &str, plaintext: Plaintext<N>) -> Result<()> {
+        let path = Access::parse_path(path)?;
+        match self {
+            Self::Plaintext(value) => value.set(&path, plaintext),
+            Self::Record(record) => record.set(&path, plaintext),
+            Self::Future(..) => bail!("Attempted to set an argument of a future"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_get_and_set_path_on_record() -> Result<()> {
+        let mut value = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1g8qul5a44vk22u9uuvaewdcjw4v6xg8wx0llru39nnjn7eu08yrscxe4e2.private, token: { amounts: [1u64.public, 2u64.public] }, _nonce: 0group.public }",
+        )?;
+
+        // Ensure the record entries can be retrieved.
+        assert_eq!(value.get_path("token.amounts[1]")?, Value::from_str("2u64")?);
+        assert_eq!(
+            value.get_path("owner")?,
+            Value::from_str("aleo1g8qul5a44vk22u9uuvaewdcjw4v6xg8wx0llru39nnjn7eu08yrscxe4e2")?
+        );
+
+        // Ensure the record entries can be updated, preserving their visibility.
+        value.set_path("token.amounts[1]", Plaintext::from_str("3u64")?)?;
+        assert_eq!(value.get_path("token.amounts[1]")?, Value::from_str("3u64")?);
+        let owner = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+        value.set_path("owner", Plaintext::from_str(owner)?)?;
+        assert_eq!(
+            value,
+            Value::from_str(&format!(
+                "{{ owner: {owner}.private, token: {{ amounts: [1u64.public, 3u64.public] }}, _nonce: 0group.public }}"
+            ))?
+        );
+
+        // Ensure invalid updates fail.
+        assert!(value.set_path("owner", Plaintext::from_str("1u64")?).is_err());
+        assert!(value.set_path("owner.inner", Plaintext::from_str(owner)?).is_err());
+        assert!(value.set_path("missing", Plaintext::from_str("1u64")?).is_err());
+        assert!(value.set_path("token[0]", Plaintext::from_str("1u64")?).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for MapType<N> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the key type.
+        let key_type = LiteralType::read_le(&mut reader)?;
+        // Read the value type.
+        let value_type = PlaintextType::read_le(&mut reader)?;
+        // Read the maximum number of entries.
+        let max_entries = U32::read_le(&mut reader)?;
+        // Construct the map type.
+        MapType::new(key_type, value_type, max_entries).map_err(|e| error(format!("{e}")))
+    }
+}
+
+impl<N: Network> ToBytes for MapType<N> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the value type is not a map.
+        if let PlaintextType::Map(_) = *self.value_type {
+            return Err(error("A map value cannot be a map"));
+        }
+        // Write the key type.
+        self.key_type.write_le(&mut writer)?;
+        // Write the value type.
+        self.value_type.write_le(&mut writer)?;
+        // Write the maximum number of entries.
+        self.max_entries.write_le(&mut writer)
+    }
+}
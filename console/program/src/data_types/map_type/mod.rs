@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{LiteralType, PlaintextType, U32};
+use snarkvm_console_network::prelude::*;
+
+use core::fmt::{Debug, Display};
+
+/// A `MapType` defines the key type, value type, and maximum number of entries of a map.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MapType<N: Network> {
+    /// The key type.
+    key_type: LiteralType,
+    /// The value type.
+    value_type: Box<PlaintextType<N>>,
+    /// The maximum number of entries in the map.
+    max_entries: U32<N>,
+}
+
+impl<N: Network> MapType<N> {
+    /// Initializes a new map type.
+    pub fn new(key_type: LiteralType, value_type: PlaintextType<N>, max_entries: U32<N>) -> Result<Self> {
+        // Ensure the value type is not a map.
+        ensure!(!matches!(value_type, PlaintextType::Map(..)), "A map value cannot be a map");
+        // Ensure the maximum number of entries is valid.
+        ensure!(*max_entries > 0, "A map must allow at least one entry");
+        ensure!(*max_entries as usize <= N::MAX_MAP_ENTRIES, "A map can contain {} entries", N::MAX_MAP_ENTRIES);
+        // Construct the map type.
+        Ok(Self { key_type, value_type: Box::new(value_type), max_entries })
+    }
+}
+
+impl<N: Network> MapType<N> {
+    /// Returns the key type.
+    pub const fn key_type(&self) -> &LiteralType {
+        &self.key_type
+    }
+
+    /// Returns the value type.
+    pub const fn value_type(&self) -> &PlaintextType<N> {
+        &self.value_type
+    }
+
+    /// Returns the maximum number of entries in the map.
+    pub const fn max_entries(&self) -> &U32<N> {
+        &self.max_entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArrayType, Identifier};
+    use snarkvm_console_network::Testnet3;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_map_type() -> Result<()> {
+        // Test a map of literals.
+        let map = MapType::<CurrentNetwork>::from_str("[address => u64; 8u32]")?;
+        assert_eq!(
+            map,
+            MapType::<CurrentNetwork>::new(LiteralType::Address, PlaintextType::from_str("u64")?, U32::new(8))?
+        );
+        assert_eq!(map.to_bytes_le()?, MapType::<CurrentNetwork>::from_bytes_le(&map.to_bytes_le()?)?.to_bytes_le()?);
+        assert_eq!(map.key_type(), &LiteralType::Address);
+        assert_eq!(map.value_type(), &PlaintextType::Literal(LiteralType::U64));
+        assert_eq!(map.max_entries(), &U32::new(8));
+
+        // Test a map of structs.
+        let map = MapType::<CurrentNetwork>::from_str("[u8 => foo; 1u32]")?;
+        assert_eq!(map.to_bytes_le()?, MapType::<CurrentNetwork>::from_bytes_le(&map.to_bytes_le()?)?.to_bytes_le()?);
+        assert_eq!(map.value_type(), &PlaintextType::Struct(Identifier::from_str("foo")?));
+        assert_eq!(map.to_string(), "[u8 => foo; 1u32]");
+
+        // Test a map of arrays.
+        let map = MapType::<CurrentNetwork>::from_str("[field=>[boolean; 4u32];32u32]")?;
+        assert_eq!(map.to_bytes_le()?, MapType::<CurrentNetwork>::from_bytes_le(&map.to_bytes_le()?)?.to_bytes_le()?);
+        assert_eq!(map.value_type(), &PlaintextType::Array(ArrayType::from_str("[boolean; 4u32]")?));
+        assert_eq!(map.to_string(), "[field => [boolean; 4u32]; 32u32]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_type_fails() {
+        // The map must allow at least one entry.
+        assert!(MapType::<CurrentNetwork>::from_str("[u8 => u8; 0u32]").is_err());
+        // The map must not exceed the maximum number of entries.
+        assert!(MapType::<CurrentNetwork>::from_str("[u8 => u8; 33u32]").is_err());
+        // The key must be a literal.
+        assert!(MapType::<CurrentNetwork>::from_str("[foo => u8; 1u32]").is_err());
+        assert!(MapType::<CurrentNetwork>::from_str("[[u8; 1u32] => u8; 1u32]").is_err());
+        // The value must not be a map.
+        assert!(MapType::<CurrentNetwork>::from_str("[u8 => [u8 => u8; 1u32]; 1u32]").is_err());
+        // The length must be a u32.
+        assert!(MapType::<CurrentNetwork>::from_str("[u8 => u8; 1u8]").is_err());
+    }
+}
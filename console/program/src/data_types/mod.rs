@@ -21,6 +21,9 @@ pub use finalize_type::FinalizeType;
 mod literal_type;
 pub use literal_type::LiteralType;
 
+mod map_type;
+pub use map_type::MapType;
+
 mod plaintext_type;
 pub use plaintext_type::PlaintextType;
 
@@ -16,10 +16,10 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{ArrayType, Identifier, LiteralType};
+use crate::{ArrayType, Identifier, LiteralType, MapType};
 use snarkvm_console_network::prelude::*;
 
-/// A `PlaintextType` defines the type parameter for a literal, struct, or array.
+/// A `PlaintextType` defines the type parameter for a literal, struct, array, or map.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum PlaintextType<N: Network> {
     /// A literal type contains its type name.
@@ -31,6 +31,9 @@ pub enum PlaintextType<N: Network> {
     /// An array type contains its element type and length.
     /// The format of the type is `[<element_type>; <length>]`.
     Array(ArrayType<N>),
+    /// A map type contains its key type, value type, and maximum number of entries.
+    /// The format of the type is `[<key_type> => <value_type>; <max_entries>]`.
+    Map(MapType<N>),
 }
 
 impl<N: Network> From<LiteralType> for PlaintextType<N> {
@@ -53,3 +56,10 @@ impl<N: Network> From<ArrayType<N>> for PlaintextType<N> {
         PlaintextType::Array(array)
     }
 }
+
+impl<N: Network> From<MapType<N>> for PlaintextType<N> {
+    /// Initializes a plaintext type from a map type.
+    fn from(map: MapType<N>) -> Self {
+        PlaintextType::Map(map)
+    }
+}
@@ -67,6 +67,9 @@ mod tests {
         "passport",
         "object",
         "array",
+        // Map
+        "[address => u64; 8u32]",
+        "[u8 => item; 1u32]",
     ];
 
     fn check_serde_json<
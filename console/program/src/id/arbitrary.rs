@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+/// The characters that may start a program name.
+const LEADING_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+/// The characters that may follow the first character of a program name.
+const TRAILING_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789_";
+
+impl<'a, N: Network> Arbitrary<'a> for ProgramID<N> {
+    /// Returns an arbitrary program ID on the `aleo` network, which may be namespaced.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        let name = Identifier::arbitrary_from_chars(u, LEADING_CHARS, TRAILING_CHARS)?;
+        let network = Identifier::from_str("aleo").map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+        let program_id = match u.arbitrary::<bool>()? {
+            true => {
+                let namespace = Identifier::arbitrary_from_chars(u, LEADING_CHARS, TRAILING_CHARS)?;
+                Self::try_from((name, namespace, network))
+            }
+            false => Self::try_from((name, network)),
+        };
+        program_id.map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+}
@@ -18,8 +18,13 @@ impl<N: Network> FromBytes for ProgramID<N> {
     /// Reads the program ID from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let name = FromBytes::read_le(&mut reader)?;
-        let network = FromBytes::read_le(&mut reader)?;
-        Self::try_from((name, network)).map_err(|e| error(format!("{e}")))
+        let network_or_namespace: Identifier<N> = FromBytes::read_le(&mut reader)?;
+        // Note: A namespace is never the network-level domain, so a namespaced program ID is followed by its network.
+        match network_or_namespace.to_string() == "aleo" {
+            true => Self::try_from((name, network_or_namespace)),
+            false => Self::try_from((name, network_or_namespace, FromBytes::read_le(&mut reader)?)),
+        }
+        .map_err(|e| error(format!("{e}")))
     }
 }
 
@@ -27,6 +32,9 @@ impl<N: Network> ToBytes for ProgramID<N> {
     /// Writes the program ID to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.name.write_le(&mut writer)?;
+        if let Some(namespace) = &self.namespace {
+            namespace.write_le(&mut writer)?;
+        }
         self.network.write_le(&mut writer)
     }
 }
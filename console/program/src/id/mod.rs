@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod parse;
 mod serialize;
@@ -28,11 +30,14 @@ fn is_lowercase_alphanumeric(s: &str) -> bool {
     s.chars().all(|c| matches!(c, '0'..='9' | 'a'..='z' | '_'))
 }
 
-/// A program ID is of the form `{name}.{network}`.
+/// A program ID is of the form `{name}.{network}`, or `{name}.{namespace}.{network}` for a namespaced program.
+/// A namespaced program may only be deployed by the owner of its parent program, `{namespace}.{network}`.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct ProgramID<N: Network> {
     /// The program name.
     name: Identifier<N>,
+    /// The namespace, which is the name of the parent program, if the program is namespaced.
+    namespace: Option<Identifier<N>>,
     /// The network-level domain (NLD).
     network: Identifier<N>,
 }
@@ -52,7 +57,7 @@ impl<N: Network> TryFrom<(Identifier<N>, Identifier<N>)> for ProgramID<N> {
         // Ensure the name is lowercase alphabets and numbers.
         ensure!(is_lowercase_alphanumeric(&name.to_string()), "Program name is invalid: {name}");
         // Construct the program ID.
-        let id = Self { name, network };
+        let id = Self { name, namespace: None, network };
         // Ensure the program network-level domain is `aleo`.
         ensure!(id.is_aleo(), "Program network is invalid: {network}");
         // Return the program ID.
@@ -60,6 +65,21 @@ impl<N: Network> TryFrom<(Identifier<N>, Identifier<N>)> for ProgramID<N> {
     }
 }
 
+impl<N: Network> TryFrom<(Identifier<N>, Identifier<N>, Identifier<N>)> for ProgramID<N> {
+    type Error = Error;
+
+    /// Initializes a namespaced program ID from a name, namespace, and network-level domain identifier.
+    fn try_from((name, namespace, network): (Identifier<N>, Identifier<N>, Identifier<N>)) -> Result<Self> {
+        // Ensure the namespace is not the network-level domain.
+        // Note: This keeps the byte and bit encodings of namespaced and flat program IDs distinct.
+        ensure!(namespace != network, "Program namespace is invalid: {namespace}");
+        // Ensure the parent program ID is valid.
+        Self::try_from((namespace, network))?;
+        // Construct the program ID.
+        Ok(Self { namespace: Some(namespace), ..Self::try_from((name, network))? })
+    }
+}
+
 impl<N: Network> TryFrom<String> for ProgramID<N> {
     type Error = Error;
 
@@ -83,16 +103,27 @@ impl<N: Network> TryFrom<&str> for ProgramID<N> {
 
     /// Initializes a program ID from a name and network-level domain identifier.
     fn try_from(program_id: &str) -> Result<Self> {
-        // Split the program ID into a name and network-level domain.
-        let mut split = program_id.split('.');
-        // Parse the name and network.
-        if let (Some(name), Some(network), None) = (split.next(), split.next(), split.next()) {
-            // Ensure the name is lowercase alphabets and numbers.
-            ensure!(is_lowercase_alphanumeric(name), "Program name is invalid: {name}");
-            // Construct the program ID.
-            Self::try_from((Identifier::from_str(name)?, Identifier::from_str(network)?))
-        } else {
-            bail!("Invalid program ID '{program_id}'")
+        // Split the program ID into a name, an optional namespace, and network-level domain.
+        let split = program_id.split('.').collect::<Vec<_>>();
+        // Parse the name, namespace, and network.
+        match split[..] {
+            [name, network] => {
+                // Ensure the name is lowercase alphabets and numbers.
+                ensure!(is_lowercase_alphanumeric(name), "Program name is invalid: {name}");
+                // Construct the program ID.
+                Self::try_from((Identifier::from_str(name)?, Identifier::from_str(network)?))
+            }
+            [name, namespace, network] => {
+                // Ensure the name is lowercase alphabets and numbers.
+                ensure!(is_lowercase_alphanumeric(name), "Program name is invalid: {name}");
+                // Construct the program ID.
+                Self::try_from((
+                    Identifier::from_str(name)?,
+                    Identifier::from_str(namespace)?,
+                    Identifier::from_str(network)?,
+                ))
+            }
+            _ => bail!("Invalid program ID '{program_id}'"),
         }
     }
 }
@@ -104,12 +135,24 @@ impl<N: Network> ProgramID<N> {
         &self.name
     }
 
+    /// Returns the namespace, if the program is namespaced.
+    #[inline]
+    pub const fn namespace(&self) -> Option<&Identifier<N>> {
+        self.namespace.as_ref()
+    }
+
     /// Returns the network-level domain (NLD).
     #[inline]
     pub const fn network(&self) -> &Identifier<N> {
         &self.network
     }
 
+    /// Returns the parent program ID, `{namespace}.{network}`, if the program is namespaced.
+    #[inline]
+    pub fn parent(&self) -> Option<Self> {
+        self.namespace.map(|namespace| Self { name: namespace, namespace: None, network: self.network })
+    }
+
     /// Returns `true` if the network-level domain is `aleo`.
     #[inline]
     pub fn is_aleo(&self) -> bool {
@@ -118,17 +161,18 @@ impl<N: Network> ProgramID<N> {
 }
 
 impl<N: Network> Ord for ProgramID<N> {
-    /// Ordering is determined by the network first, then the program name second.
+    /// Ordering is determined by the network first, the namespace second, then the program name third.
     fn cmp(&self, other: &Self) -> Ordering {
-        match self.network == other.network {
-            true => self.name.to_string().cmp(&other.name.to_string()),
-            false => self.network.to_string().cmp(&other.network.to_string()),
+        match (self.network == other.network, self.namespace == other.namespace) {
+            (true, true) => self.name.to_string().cmp(&other.name.to_string()),
+            (true, false) => self.namespace.map(|n| n.to_string()).cmp(&other.namespace.map(|n| n.to_string())),
+            (false, _) => self.network.to_string().cmp(&other.network.to_string()),
         }
     }
 }
 
 impl<N: Network> PartialOrd for ProgramID<N> {
-    /// Ordering is determined by the network first, then the program name second.
+    /// Ordering is determined by the network first, the namespace second, then the program name third.
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
@@ -183,6 +227,38 @@ mod tests {
         assert_eq!(import4.partial_cmp(&import3), Some(Ordering::Greater));
         assert_eq!(import4.partial_cmp(&import4), Some(Ordering::Equal));
 
+        // Ensure namespaced program IDs are ordered after the flat program IDs in the same network.
+        let import5 = ProgramID::<CurrentNetwork>::from_str("bar.foo.aleo")?;
+        let import6 = ProgramID::<CurrentNetwork>::from_str("baz.foo.aleo")?;
+        assert_eq!(import4.partial_cmp(&import5), Some(Ordering::Less));
+        assert_eq!(import5.partial_cmp(&import6), Some(Ordering::Less));
+        assert_eq!(import6.partial_cmp(&import5), Some(Ordering::Greater));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_namespace() -> Result<()> {
+        // Ensure a flat program ID has no namespace or parent.
+        let id = ProgramID::<CurrentNetwork>::from_str("foo.aleo")?;
+        assert_eq!(id.namespace(), None);
+        assert_eq!(id.parent(), None);
+
+        // Ensure a namespaced program ID has a namespace and parent.
+        let id = ProgramID::<CurrentNetwork>::from_str("router.myapp.aleo")?;
+        assert_eq!(id.name(), &Identifier::from_str("router")?);
+        assert_eq!(id.namespace(), Some(&Identifier::from_str("myapp")?));
+        assert_eq!(id.network(), &Identifier::from_str("aleo")?);
+        assert_eq!(id.parent(), Some(ProgramID::from_str("myapp.aleo")?));
+        assert_eq!(id, ProgramID::try_from("router.myapp.aleo")?);
+        assert_ne!(id.to_address()?, ProgramID::<CurrentNetwork>::from_str("router.aleo")?.to_address()?);
+
+        // Ensure invalid namespaces are rejected.
+        assert!(ProgramID::<CurrentNetwork>::try_from("router.aleo.aleo").is_err());
+        assert!(ProgramID::<CurrentNetwork>::try_from("router.MyApp.aleo").is_err());
+        assert!(ProgramID::<CurrentNetwork>::try_from("router.myapp.foo").is_err());
+        assert!(ProgramID::<CurrentNetwork>::try_from("a.router.myapp.aleo").is_err());
+
         Ok(())
     }
 }
@@ -15,14 +15,21 @@
 use super::*;
 
 impl<N: Network> Parser for ProgramID<N> {
-    /// Parses a string into a program ID of the form `{name}.{network}`.
+    /// Parses a string into a program ID of the form `{name}.{network}` or `{name}.{namespace}.{network}`.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the name, ".", namespace, ".", and network-level domain (NLD) from the string.
+        let parse_namespaced = map_res(
+            pair(Identifier::parse, pair(pair(tag("."), Identifier::parse), pair(tag("."), Identifier::parse))),
+            |(name, ((_, namespace), (_, network)))| Self::try_from((name, namespace, network)),
+        );
         // Parse the name, ".", and network-level domain (NLD) from the string.
-        map_res(pair(Identifier::parse, pair(tag("."), Identifier::parse)), |(name, (_, network))| {
+        let parse_flat = map_res(pair(Identifier::parse, pair(tag("."), Identifier::parse)), |(name, (_, network))| {
             // Return the program ID.
             Self::try_from((name, network))
-        })(string)
+        });
+        // Note: The namespaced form is attempted first, as the flat form is its prefix.
+        alt((parse_namespaced, parse_flat))(string)
     }
 }
 
@@ -54,7 +61,10 @@ impl<N: Network> Debug for ProgramID<N> {
 impl<N: Network> Display for ProgramID<N> {
     /// Prints the program ID as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{name}.{network}", name = self.name, network = self.network)
+        match &self.namespace {
+            Some(namespace) => write!(f, "{}.{namespace}.{}", self.name, self.network),
+            None => write!(f, "{name}.{network}", name = self.name, network = self.network),
+        }
     }
 }
 
@@ -73,6 +83,16 @@ mod tests {
 
         assert!(ProgramID::<CurrentNetwork>::parse("foo").is_err());
 
+        let id = ProgramID::<CurrentNetwork>::parse("bar.foo.aleo").unwrap().1;
+        assert_eq!(id.name(), &Identifier::<CurrentNetwork>::from_str("bar")?);
+        assert_eq!(id.namespace(), Some(&Identifier::<CurrentNetwork>::from_str("foo")?));
+        assert_eq!(id.network(), &Identifier::<CurrentNetwork>::from_str("aleo")?);
+
+        // Ensure a trailing identifier that does not form a valid namespaced program ID is left unparsed.
+        let (remainder, id) = ProgramID::<CurrentNetwork>::parse("bar.aleo.foo").unwrap();
+        assert_eq!(id, ProgramID::from_str("bar.aleo")?);
+        assert_eq!(remainder, ".foo");
+
         Ok(())
     }
 
@@ -81,6 +101,9 @@ mod tests {
         let id = ProgramID::<CurrentNetwork>::from_str("bar.aleo")?;
         assert_eq!("bar.aleo", id.to_string());
 
+        let id = ProgramID::<CurrentNetwork>::from_str("bar.foo.aleo")?;
+        assert_eq!("bar.foo.aleo", id.to_string());
+
         assert!(ProgramID::<CurrentNetwork>::from_str("foo").is_err());
         assert!(ProgramID::<CurrentNetwork>::from_str("Bar.aleo").is_err());
         assert!(ProgramID::<CurrentNetwork>::from_str("foO.aleo").is_err());
@@ -18,7 +18,7 @@ impl<N: Network> ProgramID<N> {
     /// Returns the program address for this program ID.
     pub fn to_address(&self) -> Result<Address<N>> {
         // Compute the program address as `HashToGroup(program_id)`.
-        let group = N::hash_to_group_psd4(&[self.name().to_field()?, self.network().to_field()?])?;
+        let group = N::hash_to_group_psd4(&self.to_fields()?)?;
         // Return the program address.
         Ok(Address::new(group))
     }
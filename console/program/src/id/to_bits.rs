@@ -30,12 +30,18 @@ impl<N: Network> ToBits for &ProgramID<N> {
     /// Returns the little-endian bits of the program ID.
     fn write_bits_le(&self, vec: &mut Vec<bool>) {
         self.name().write_bits_le(vec);
+        if let Some(namespace) = self.namespace() {
+            namespace.write_bits_le(vec);
+        }
         self.network().write_bits_le(vec);
     }
 
     /// Returns the big-endian bits of the program ID.
     fn write_bits_be(&self, vec: &mut Vec<bool>) {
         self.name().write_bits_be(vec);
+        if let Some(namespace) = self.namespace() {
+            namespace.write_bits_be(vec);
+        }
         self.network().write_bits_be(vec);
     }
 }
@@ -19,6 +19,9 @@ impl<N: Network> ToFields for ProgramID<N> {
 
     /// Returns this program ID as a list of field elements.
     fn to_fields(&self) -> Result<Vec<Self::Field>> {
-        Ok(vec![self.name().to_field()?, self.network().to_field()?])
+        match self.namespace() {
+            Some(namespace) => Ok(vec![self.name().to_field()?, namespace.to_field()?, self.network().to_field()?]),
+            None => Ok(vec![self.name().to_field()?, self.network().to_field()?]),
+        }
     }
 }
@@ -47,3 +47,12 @@ pub use response::*;
 
 pub mod state_path;
 pub use state_path::*;
+
+mod typed_data;
+pub use typed_data::*;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
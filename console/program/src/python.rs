@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The value API for Python, with `pyo3` types for the current network.
+
+use crate::{Boolean, Identifier, Literal, Plaintext};
+use snarkvm_console_account::python::{to_py_error, Address, CurrentNetwork};
+use snarkvm_console_network::prelude::*;
+
+use indexmap::IndexMap;
+use pyo3::prelude::*;
+
+/// A program value.
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct Value(crate::Value<CurrentNetwork>);
+
+#[pymethods]
+impl Value {
+    /// Returns the value for the given string.
+    #[staticmethod]
+    pub fn from_string(value: &str) -> PyResult<Self> {
+        crate::Value::from_str(value).map(Self).map_err(to_py_error)
+    }
+
+    /// Returns a boolean value.
+    #[staticmethod]
+    pub fn from_bool(value: bool) -> Self {
+        Self(Literal::Boolean(Boolean::new(value)).into())
+    }
+
+    /// Returns an integer value of the given type, e.g. `u64` or `i8`.
+    #[staticmethod]
+    pub fn from_integer(value: i128, integer_type: &str) -> PyResult<Self> {
+        match Literal::from_str(&format!("{value}{integer_type}")).map_err(to_py_error)? {
+            literal @ (Literal::I8(..)
+            | Literal::I16(..)
+            | Literal::I32(..)
+            | Literal::I64(..)
+            | Literal::I128(..)
+            | Literal::U8(..)
+            | Literal::U16(..)
+            | Literal::U32(..)
+            | Literal::U64(..)
+            | Literal::U128(..)) => Ok(Self(literal.into())),
+            _ => Err(to_py_error(format!("'{integer_type}' is not an integer type"))),
+        }
+    }
+
+    /// Returns an address value.
+    #[staticmethod]
+    pub fn from_address(address: &Address) -> Self {
+        Self(Literal::Address(**address).into())
+    }
+
+    /// Returns an array value with the given elements, which must be plaintext values.
+    #[staticmethod]
+    pub fn from_array(elements: Vec<Value>) -> PyResult<Self> {
+        let elements = elements.iter().map(Value::to_plaintext).collect::<PyResult<Vec<_>>>()?;
+        Ok(Self(crate::Value::Plaintext(Plaintext::Array(elements, Default::default()))))
+    }
+
+    /// Returns a struct value with the given `(name, value)` members, which must be plaintext values.
+    #[staticmethod]
+    pub fn from_struct(members: Vec<(String, Value)>) -> PyResult<Self> {
+        let members = members
+            .iter()
+            .map(|(name, value)| Ok((Identifier::from_str(name).map_err(to_py_error)?, value.to_plaintext()?)))
+            .collect::<PyResult<IndexMap<_, _>>>()?;
+        Ok(Self(crate::Value::Plaintext(Plaintext::Struct(members, Default::default()))))
+    }
+
+    /// Returns the kind of the value, i.e. `plaintext`, `record`, or `future`.
+    #[getter]
+    pub fn kind(&self) -> &'static str {
+        match self.0 {
+            crate::Value::Plaintext(..) => "plaintext",
+            crate::Value::Record(..) => "record",
+            crate::Value::Future(..) => "future",
+        }
+    }
+
+    /// Returns the value as a string.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the values are equal.
+    pub fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Value {
+    /// Returns the value as a plaintext, or an error if it is not a plaintext value.
+    fn to_plaintext(&self) -> PyResult<Plaintext<CurrentNetwork>> {
+        match &self.0 {
+            crate::Value::Plaintext(plaintext) => Ok(plaintext.clone()),
+            _ => Err(to_py_error(format!("Expected a plaintext value, found a {}", self.kind()))),
+        }
+    }
+}
+
+impl Deref for Value {
+    type Target = crate::Value<CurrentNetwork>;
+
+    /// Returns the value.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Adds the value classes to the given Python module.
+pub fn register(module: &PyModule) -> PyResult<()> {
+    module.add_class::<Value>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value() {
+        assert_eq!(Value::from_bool(true).__str__(), "true");
+        assert_eq!(Value::from_integer(-5, "i8").unwrap().__str__(), "-5i8");
+        assert!(Value::from_integer(256, "u8").is_err());
+        assert!(Value::from_integer(1, "field").is_err());
+
+        let array = Value::from_array(vec![Value::from_bool(true), Value::from_bool(false)]).unwrap();
+        assert!(array.__eq__(&Value::from_string("[true, false]").unwrap()));
+        assert_eq!(array.kind(), "plaintext");
+
+        let members = vec![("a".to_string(), Value::from_integer(1, "u64").unwrap()), ("b".to_string(), array)];
+        let value = Value::from_struct(members).unwrap();
+        assert!(value.__eq__(&Value::from_string("{ a: 1u64, b: [true, false] }").unwrap()));
+    }
+}
@@ -22,7 +22,7 @@ mod string;
 mod verify;
 
 use crate::{Identifier, Plaintext, ProgramID, Record, Value, ValueType};
-use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
+use snarkvm_console_account::{Address, ComputeKey, Signature, Signer, SignerCommitment};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -155,6 +155,7 @@ impl<N: Network> Request<N> {
 #[cfg(test)]
 mod test_helpers {
     use super::*;
+    use snarkvm_console_account::PrivateKey;
     use snarkvm_console_network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -15,17 +15,111 @@
 use super::*;
 
 impl<N: Network> Request<N> {
-    /// Returns the request for a given private key, program ID, function name, inputs, input types, and RNG, where:
+    /// Returns the request for a given signer, program ID, function name, inputs, input types, and RNG, where:
     ///     challenge := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
     ///     response := r - challenge * sk_sig
-    pub fn sign<R: Rng + CryptoRng>(
-        private_key: &PrivateKey<N>,
+    pub fn sign<S: Signer<N> + ?Sized, R: Rng + CryptoRng>(
+        signer: &S,
         program_id: ProgramID<N>,
         function_name: Identifier<N>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         input_types: &[ValueType<N>],
         rng: &mut R,
     ) -> Result<Self> {
+        // Retrieve the compute key and `sk_tag` of the signer.
+        let compute_key = signer.compute_key()?;
+        let sk_tag = signer.graph_key()?.sk_tag();
+        // Prepare the inputs, and the record generators `H`.
+        let (inputs, records) = Self::prepare_inputs(&compute_key, program_id, function_name, inputs, input_types)?;
+        // Sample a random nonce.
+        let nonce = Field::<N>::rand(rng);
+        // Commit to the transition secret key `r`.
+        let commitment = signer.commit(nonce, &records.iter().map(|(_, h)| *h).collect::<Vec<_>>())?;
+        // Construct the message and the input IDs.
+        let (message, input_ids) = Self::prepare_message(
+            &compute_key,
+            sk_tag,
+            program_id,
+            function_name,
+            &inputs,
+            input_types,
+            &records,
+            &commitment,
+        )?;
+        // Compute the response.
+        let response = signer.respond(&commitment, &message)?;
+        // Construct the request.
+        Self::from_response(
+            compute_key,
+            sk_tag,
+            program_id,
+            function_name,
+            inputs,
+            input_ids,
+            &commitment,
+            &message,
+            response,
+        )
+    }
+
+    /// Returns the request for a given signer, program ID, function name, inputs, input types, and RNG, where:
+    ///     challenge := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
+    ///     response := r - challenge * sk_sig
+    #[cfg(feature = "async")]
+    pub async fn sign_async<S: Signer<N> + ?Sized, R: Rng + CryptoRng>(
+        signer: &S,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Retrieve the compute key and `sk_tag` of the signer.
+        let compute_key = signer.compute_key_async().await?;
+        let sk_tag = signer.graph_key_async().await?.sk_tag();
+        // Prepare the inputs, and the record generators `H`.
+        let (inputs, records) = Self::prepare_inputs(&compute_key, program_id, function_name, inputs, input_types)?;
+        // Sample a random nonce.
+        let nonce = Field::<N>::rand(rng);
+        // Commit to the transition secret key `r`.
+        let commitment = signer.commit_async(nonce, &records.iter().map(|(_, h)| *h).collect::<Vec<_>>()).await?;
+        // Construct the message and the input IDs.
+        let (message, input_ids) = Self::prepare_message(
+            &compute_key,
+            sk_tag,
+            program_id,
+            function_name,
+            &inputs,
+            input_types,
+            &records,
+            &commitment,
+        )?;
+        // Compute the response.
+        let response = signer.respond_async(&commitment, &message).await?;
+        // Construct the request.
+        Self::from_response(
+            compute_key,
+            sk_tag,
+            program_id,
+            function_name,
+            inputs,
+            input_ids,
+            &commitment,
+            &message,
+            response,
+        )
+    }
+}
+
+impl<N: Network> Request<N> {
+    /// Returns the prepared inputs, and the `(commitment, H)` pair for each record input.
+    fn prepare_inputs(
+        compute_key: &ComputeKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+    ) -> Result<(Vec<Value<N>>, Vec<(Field<N>, Group<N>)>)> {
         // Ensure the number of inputs matches the number of input types.
         if input_types.len() != inputs.len() {
             bail!(
@@ -35,59 +129,91 @@ impl<N: Network> Request<N> {
             )
         }
 
-        // Retrieve `sk_sig`.
-        let sk_sig = private_key.sk_sig();
+        // Derive the signer from the compute key.
+        let signer = Address::try_from(compute_key)?;
 
-        // Derive the compute key.
-        let compute_key = ComputeKey::try_from(private_key)?;
-        // Retrieve `pk_sig`.
-        let pk_sig = compute_key.pk_sig();
-        // Retrieve `pr_sig`.
-        let pr_sig = compute_key.pr_sig();
+        // Initialize a vector to store the prepared inputs.
+        let mut prepared_inputs = Vec::with_capacity(inputs.len());
+        // Initialize a vector to store the record commitments and generators.
+        let mut records = Vec::new();
 
-        // Derive the view key.
-        let view_key = ViewKey::try_from((private_key, &compute_key))?;
-        // Derive `sk_tag` from the graph key.
-        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+        // Prepare the inputs.
+        for (index, (input, input_type)) in inputs.zip_eq(input_types).enumerate() {
+            // Prepare the input.
+            let input = input.try_into().map_err(|_| {
+                anyhow!("Failed to parse input #{index} ('{input_type}') for '{program_id}/{function_name}'")
+            })?;
 
-        // Sample a random nonce.
-        let nonce = Field::<N>::rand(rng);
-        // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
-        let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
-        // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
-        let g_r = N::g_scalar_multiply(&r);
+            // A record input requires the generator `H` for its serial number.
+            if let ValueType::Record(record_name) = input_type {
+                // Retrieve the record.
+                let record = match &input {
+                    Value::Record(record) => record,
+                    // Ensure the input is a record.
+                    Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
+                    Value::Future(..) => bail!("Expected a record input, found a future input"),
+                };
+                // Ensure the record belongs to the signer.
+                ensure!(**record.owner() == signer, "Input record for '{program_id}' must belong to the signer");
+
+                // Compute the record commitment.
+                let commitment = record.to_commitment(&program_id, record_name)?;
+                // Compute the generator `H` as `HashToGroup(commitment)`.
+                let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+                // Store the record commitment and generator.
+                records.push((commitment, h));
+            }
+
+            // Store the prepared input.
+            prepared_inputs.push(input);
+        }
+
+        Ok((prepared_inputs, records))
+    }
 
+    /// Returns the request message and the input IDs, for the given signer commitment.
+    fn prepare_message(
+        compute_key: &ComputeKey<N>,
+        sk_tag: Field<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+        records: &[(Field<N>, Group<N>)],
+        commitment: &SignerCommitment<N>,
+    ) -> Result<(Vec<Field<N>>, Vec<InputID<N>>)> {
         // Derive the signer from the compute key.
         let signer = Address::try_from(compute_key)?;
-        // Compute the transition view key `tvk` as `r * signer`.
-        let tvk = (*signer * r).to_x_coordinate();
+        // Retrieve the transition view key `tvk`, as `r * signer`.
+        let tvk = commitment.tvk();
         // Compute the transition commitment `tcm` as `Hash(tvk)`.
         let tcm = N::hash_psd2(&[tvk])?;
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id = N::hash_bhp1024(
-            &(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le(),
-        )?;
+        let function_id = N::hash_bhp1024(&(U16::<N>::new(N::ID), &program_id, function_name).to_bits_le())?;
 
         // Construct the hash input as `(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
         let mut message = Vec::with_capacity(9 + 2 * inputs.len());
-        message.extend([g_r, pk_sig, pr_sig, *signer].map(|point| point.to_x_coordinate()));
+        message.extend(
+            [commitment.tpk(), compute_key.pk_sig(), compute_key.pr_sig(), *signer]
+                .map(|point| point.to_x_coordinate()),
+        );
         message.extend([tvk, tcm, function_id]);
 
-        // Initialize a vector to store the prepared inputs.
-        let mut prepared_inputs = Vec::with_capacity(inputs.len());
         // Initialize a vector to store the input IDs.
         let mut input_ids = Vec::with_capacity(inputs.len());
+        // Ensure the signer committed to each record input.
+        ensure!(
+            records.len() == commitment.records().len(),
+            "Expected {} record commitments from the signer, found {}",
+            records.len(),
+            commitment.records().len()
+        );
+        // Initialize an iterator over the record commitments, generators, and signer values.
+        let mut records = records.iter().zip(commitment.records());
 
-        // Prepare the inputs.
-        for (index, (input, input_type)) in inputs.zip_eq(input_types).enumerate() {
-            // Prepare the input.
-            let input = input.try_into().map_err(|_| {
-                anyhow!("Failed to parse input #{index} ('{input_type}') for '{program_id}/{function_name}'")
-            })?;
-            // Store the prepared input.
-            prepared_inputs.push(input.clone());
-
+        // Construct the input IDs.
+        for (index, (input, input_type)) in inputs.iter().zip_eq(input_types).enumerate() {
             match input_type {
                 // A constant input is hashed (using `tcm`) to a field element.
                 ValueType::Constant(..) => {
@@ -141,7 +267,7 @@ impl<N: Network> Request<N> {
                     // Compute the input view key as `Hash(function ID || tvk || index)`.
                     let input_view_key = N::hash_psd4(&[function_id, tvk, index])?;
                     // Compute the ciphertext.
-                    let ciphertext = match &input {
+                    let ciphertext = match input {
                         Value::Plaintext(plaintext) => plaintext.encrypt_symmetric(input_view_key)?,
                         // Ensure the input is a plaintext.
                         Value::Record(..) => bail!("Expected a plaintext input, found a record input"),
@@ -156,38 +282,23 @@ impl<N: Network> Request<N> {
                     input_ids.push(InputID::Private(input_hash));
                 }
                 // A record input is computed to its serial number.
-                ValueType::Record(record_name) => {
-                    // Retrieve the record.
-                    let record = match &input {
-                        Value::Record(record) => record,
-                        // Ensure the input is a record.
-                        Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
-                        Value::Future(..) => bail!("Expected a record input, found a future input"),
+                ValueType::Record(..) => {
+                    // Retrieve the record commitment, the generator `H`, `r * H`, and `gamma`.
+                    let Some(((commitment, h), (h_r, gamma))) = records.next() else {
+                        bail!("Missing the signer commitment for input #{index} of '{program_id}/{function_name}'")
                     };
-                    // Ensure the record belongs to the signer.
-                    ensure!(**record.owner() == signer, "Input record for '{program_id}' must belong to the signer");
-
-                    // Compute the record commitment.
-                    let commitment = record.to_commitment(&program_id, record_name)?;
-
-                    // Compute the generator `H` as `HashToGroup(commitment)`.
-                    let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
-                    // Compute `h_r` as `r * H`.
-                    let h_r = h * r;
-                    // Compute `gamma` as `sk_sig * H`.
-                    let gamma = h * sk_sig;
 
                     // Compute the `serial_number` from `gamma`.
-                    let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
+                    let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(gamma, *commitment)?;
                     // Compute the tag.
-                    let tag = Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?;
+                    let tag = Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?;
 
                     // Add (`H`, `r * H`, `gamma`, `tag`) to the preimage.
                     message.extend([h, h_r, gamma].iter().map(|point| point.to_x_coordinate()));
                     message.push(tag);
 
                     // Add the input ID.
-                    input_ids.push(InputID::Record(commitment, gamma, serial_number, tag));
+                    input_ids.push(InputID::Record(*commitment, *gamma, serial_number, tag));
                 }
                 // An external record input is hashed (using `tvk`) to a field element.
                 ValueType::ExternalRecord(..) => {
@@ -215,22 +326,40 @@ impl<N: Network> Request<N> {
             }
         }
 
+        Ok((message, input_ids))
+    }
+
+    /// Returns the request for the given signer response.
+    fn from_response(
+        compute_key: ComputeKey<N>,
+        sk_tag: Field<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<Value<N>>,
+        input_ids: Vec<InputID<N>>,
+        commitment: &SignerCommitment<N>,
+        message: &[Field<N>],
+        response: Scalar<N>,
+    ) -> Result<Self> {
         // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
-        let challenge = N::hash_to_scalar_psd8(&message)?;
-        // Compute `response` as `r - challenge * sk_sig`.
-        let response = r - challenge * sk_sig;
+        let challenge = N::hash_to_scalar_psd8(message)?;
+        // Ensure the response is valid, as `r * G == response * G + challenge * pk_sig`.
+        ensure!(
+            commitment.tpk() == N::g_scalar_multiply(&response) + compute_key.pk_sig() * challenge,
+            "The signer returned an invalid response for '{program_id}/{function_name}'"
+        );
 
         Ok(Self {
-            signer,
+            signer: Address::try_from(compute_key)?,
             network_id: U16::new(N::ID),
             program_id,
             function_name,
             input_ids,
-            inputs: prepared_inputs,
+            inputs,
             signature: Signature::from((challenge, response, compute_key)),
             sk_tag,
-            tvk,
-            tcm,
+            tvk: commitment.tvk(),
+            tcm: N::hash_psd2(&[commitment.tvk()])?,
         })
     }
 }
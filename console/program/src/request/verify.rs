@@ -44,16 +44,14 @@ impl<N: Network> Request<N> {
         let response = self.signature.response();
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id = match N::hash_bhp1024(
-            &(U16::<N>::new(N::ID), self.program_id.name(), self.program_id.network(), &self.function_name)
-                .to_bits_le(),
-        ) {
-            Ok(function_id) => function_id,
-            Err(error) => {
-                eprintln!("Failed to construct the function ID: {error}");
-                return false;
-            }
-        };
+        let function_id =
+            match N::hash_bhp1024(&(U16::<N>::new(N::ID), &self.program_id, &self.function_name).to_bits_le()) {
+                Ok(function_id) => function_id,
+                Err(error) => {
+                    eprintln!("Failed to construct the function ID: {error}");
+                    return false;
+                }
+            };
 
         // Construct the signature message as `[tvk, tcm, function ID, input IDs]`.
         let mut message = Vec::with_capacity(3 + self.input_ids.len());
@@ -257,4 +255,45 @@ mod tests {
             assert!(request.verify(&input_types));
         }
     }
+
+    #[test]
+    fn test_sign_with_signer() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key, and use it as an opaque signer.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let signer: &dyn Signer<CurrentNetwork> = &private_key;
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Construct the inputs and input types.
+        let inputs = [
+            Value::from_str("{ token_amount: 9876543210u128 }").unwrap(),
+            Value::from_str(&format!(
+                "{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+            ))
+            .unwrap(),
+        ];
+        let input_types =
+            [ValueType::from_str("amount.private").unwrap(), ValueType::from_str("token.record").unwrap()];
+
+        // Ensure the signer produces the same request as the private key.
+        let expected = Request::sign(
+            &private_key,
+            program_id,
+            function_name,
+            inputs.clone().into_iter(),
+            &input_types,
+            &mut TestRng::fixed(1),
+        )
+        .unwrap();
+        let candidate =
+            Request::sign(signer, program_id, function_name, inputs.into_iter(), &input_types, &mut TestRng::fixed(1))
+                .unwrap();
+        assert!(candidate.verify(&input_types));
+        assert!(expected == candidate);
+    }
 }
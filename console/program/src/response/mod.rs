@@ -61,8 +61,7 @@ impl<N: Network> Response<N> {
         output_operands: &[Option<Register<N>>],
     ) -> Result<Self> {
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id =
-            N::hash_bhp1024(&(*network_id, program_id.name(), program_id.network(), function_name).to_bits_le())?;
+        let function_id = N::hash_bhp1024(&(*network_id, program_id, function_name).to_bits_le())?;
 
         // Compute the output IDs.
         let output_ids = outputs
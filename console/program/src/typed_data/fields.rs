@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A single literal in typed data, with its dotted path, for display in a wallet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedField<N: Network> {
+    /// The dotted path of the literal, i.e. `message.memo.nonce` or `message.amounts[1]`.
+    pub path: String,
+    /// The literal.
+    pub value: Literal<N>,
+}
+
+impl<N: Network> Display for TypedField<N> {
+    /// Prints the field as `path: value`, i.e. `message.amounts[1]: 250u64`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.value)
+    }
+}
+
+impl<N: Network> TypedData<N> {
+    /// Returns every literal in the domain and message, in order, with its dotted path.
+    pub fn fields(&self) -> Vec<TypedField<N>> {
+        let mut fields = Vec::new();
+        Self::collect_fields("domain".to_string(), &self.domain, &mut fields);
+        Self::collect_fields("message".to_string(), &self.message, &mut fields);
+        fields
+    }
+
+    /// Appends the literals in the given plaintext to `fields`, under the given path.
+    fn collect_fields(path: String, plaintext: &Plaintext<N>, fields: &mut Vec<TypedField<N>>) {
+        match plaintext {
+            Plaintext::Literal(literal, ..) => fields.push(TypedField { path, value: literal.clone() }),
+            Plaintext::Struct(members, ..) => {
+                for (identifier, member) in members {
+                    Self::collect_fields(format!("{path}.{identifier}"), member, fields);
+                }
+            }
+            Plaintext::Array(elements, ..) => {
+                for (index, element) in elements.iter().enumerate() {
+                    Self::collect_fields(format!("{path}[{index}]"), element, fields);
+                }
+            }
+            Plaintext::Map(entries, ..) => {
+                for (key, value) in entries {
+                    Self::collect_fields(format!("{path}[{key}]"), value, fields);
+                }
+            }
+        }
+    }
+}
+
+impl<N: Network> Display for TypedData<N> {
+    /// Prints the typed data as one `path: value` line per literal.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (index, field) in self.fields().iter().enumerate() {
+            match index {
+                0 => write!(f, "{field}")?,
+                _ => write!(f, "\n{field}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typed_data::test_helpers::sample_typed_data;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_fields() {
+        let rng = &mut TestRng::default();
+
+        let address = Address::<CurrentNetwork>::new(Uniform::rand(rng));
+        let typed_data = sample_typed_data(&address);
+
+        let expected = [
+            "domain.name: 1field".to_string(),
+            "domain.version: 1u8".to_string(),
+            "domain.chain: 3u16".to_string(),
+            format!("message.owner: {address}"),
+            "message.amounts[0]: 100u64".to_string(),
+            "message.amounts[1]: 250u64".to_string(),
+            "message.memo.nonce: 7u32".to_string(),
+            "message.memo.expires: 1000u32".to_string(),
+        ];
+        let fields = typed_data.fields();
+        assert_eq!(expected.len(), fields.len());
+        for (expected, field) in expected.iter().zip_eq(&fields) {
+            assert_eq!(expected, &field.to_string());
+        }
+
+        // Ensure each path resolves to its literal.
+        for field in &fields {
+            let (root, path) = field.path.split_once('.').unwrap();
+            let plaintext = match root {
+                "domain" => typed_data.domain(),
+                _ => typed_data.message(),
+            };
+            assert_eq!(Plaintext::from(&field.value), plaintext.get_path(path).unwrap());
+        }
+
+        assert_eq!(expected.join("\n"), typed_data.to_string());
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod fields;
+pub use fields::TypedField;
+
+mod sign;
+
+use crate::{Literal, Plaintext};
+use snarkvm_console_account::{Address, PrivateKey, Signature};
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+/// Typed structured data for off-chain approvals, consisting of a domain struct and a message struct.
+///
+/// The domain identifies the application requesting the approval (i.e. its name, version, and program),
+/// and the message holds the data being approved. The signature is over the plaintext
+/// `[ HashPSD8(domain), HashPSD8(message) ]` (as a `[field; 2u32]` array), which a program may reconstruct
+/// and check with the following instructions:
+///
+/// ```text
+/// hash.psd8 r2 into r4 as field;
+/// hash.psd8 r3 into r5 as field;
+/// cast r4 r5 into r6 as [field; 2u32];
+/// sign.verify r0 r1 r6 into r7;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedData<N: Network> {
+    /// The domain struct.
+    domain: Plaintext<N>,
+    /// The message struct.
+    message: Plaintext<N>,
+}
+
+impl<N: Network> TypedData<N> {
+    /// Initializes new typed data from the given domain and message structs.
+    pub fn new(domain: Plaintext<N>, message: Plaintext<N>) -> Result<Self> {
+        // Ensure the domain and message are structs.
+        ensure!(matches!(domain, Plaintext::Struct(..)), "The typed data domain must be a struct");
+        ensure!(matches!(message, Plaintext::Struct(..)), "The typed data message must be a struct");
+        Ok(Self { domain, message })
+    }
+
+    /// Returns the domain struct.
+    pub const fn domain(&self) -> &Plaintext<N> {
+        &self.domain
+    }
+
+    /// Returns the message struct.
+    pub const fn message(&self) -> &Plaintext<N> {
+        &self.message
+    }
+
+    /// Returns the hash of the domain, as computed by `hash.psd8` in a program.
+    pub fn domain_hash(&self) -> Result<Field<N>> {
+        N::hash_psd8(&self.domain.to_fields()?)
+    }
+
+    /// Returns the hash of the message, as computed by `hash.psd8` in a program.
+    pub fn message_hash(&self) -> Result<Field<N>> {
+        N::hash_psd8(&self.message.to_fields()?)
+    }
+
+    /// Returns the plaintext that is signed, i.e. `[ HashPSD8(domain), HashPSD8(message) ]`.
+    pub fn to_signing_plaintext(&self) -> Result<Plaintext<N>> {
+        let elements = vec![
+            Plaintext::from(Literal::Field(self.domain_hash()?)),
+            Plaintext::from(Literal::Field(self.message_hash()?)),
+        ];
+        Ok(Plaintext::Array(elements, Default::default()))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns sample typed data, approving a transfer from the given address.
+    pub(crate) fn sample_typed_data(address: &Address<CurrentNetwork>) -> TypedData<CurrentNetwork> {
+        let domain = Plaintext::from_str("{ name: 1field, version: 1u8, chain: 3u16 }").unwrap();
+        let message = Plaintext::from_str(&format!(
+            "{{ owner: {address}, amounts: [ 100u64, 250u64 ], memo: {{ nonce: 7u32, expires: 1000u32 }} }}"
+        ))
+        .unwrap();
+        TypedData::new(domain, message).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new() {
+        let structure = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8 }").unwrap();
+        let literal = Plaintext::<CurrentNetwork>::from_str("1u8").unwrap();
+        let array = Plaintext::<CurrentNetwork>::from_str("[ 1u8, 2u8 ]").unwrap();
+
+        assert!(TypedData::new(structure.clone(), structure.clone()).is_ok());
+        assert!(TypedData::new(literal.clone(), structure.clone()).is_err());
+        assert!(TypedData::new(structure.clone(), literal).is_err());
+        assert!(TypedData::new(array, structure).is_err());
+    }
+
+    #[test]
+    fn test_signing_plaintext() {
+        let typed_data = TypedData::<CurrentNetwork>::new(
+            Plaintext::from_str("{ name: 1field, version: 1u8 }").unwrap(),
+            Plaintext::from_str("{ amount: 100u64 }").unwrap(),
+        )
+        .unwrap();
+
+        // Ensure the signing plaintext matches the program encoding.
+        let domain_hash = typed_data.domain_hash().unwrap();
+        let message_hash = typed_data.message_hash().unwrap();
+        let expected = Plaintext::from_str(&format!("[ {domain_hash}, {message_hash} ]")).unwrap();
+        assert_eq!(expected, typed_data.to_signing_plaintext().unwrap());
+
+        // Ensure the domain separates otherwise-equal messages.
+        let other = TypedData::<CurrentNetwork>::new(
+            Plaintext::from_str("{ name: 2field, version: 1u8 }").unwrap(),
+            typed_data.message().clone(),
+        )
+        .unwrap();
+        assert_eq!(typed_data.message_hash().unwrap(), other.message_hash().unwrap());
+        assert_ne!(typed_data.to_signing_plaintext().unwrap(), other.to_signing_plaintext().unwrap());
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> TypedData<N> {
+    /// Returns a signature for the typed data, under the given private key.
+    pub fn sign<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Signature<N>> {
+        private_key.sign(&self.to_signing_plaintext()?.to_fields()?, rng)
+    }
+
+    /// Returns `true` if the signature is valid for the typed data, under the given address.
+    pub fn verify(&self, signature: &Signature<N>, address: &Address<N>) -> bool {
+        match self.to_signing_plaintext().and_then(|plaintext| plaintext.to_fields()) {
+            Ok(message) => signature.verify(address, &message),
+            Err(error) => {
+                eprintln!("Failed to encode the typed data for verification: {error}");
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typed_data::test_helpers::sample_typed_data;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let typed_data = sample_typed_data(&address);
+
+        // Ensure the signature is valid.
+        let signature = typed_data.sign(&private_key, rng).unwrap();
+        assert!(typed_data.verify(&signature, &address));
+
+        // Ensure the signature matches the `sign.verify` check on the signing plaintext.
+        let message = typed_data.to_signing_plaintext().unwrap().to_fields().unwrap();
+        assert!(signature.verify(&address, &message));
+
+        // Ensure the signature is invalid for another address.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(!typed_data.verify(&signature, &other_address));
+
+        // Ensure the signature is invalid for a modified message.
+        let mut message = typed_data.message().clone();
+        message.set_path("memo.nonce", Plaintext::from_str("8u32").unwrap()).unwrap();
+        let modified = TypedData::new(typed_data.domain().clone(), message).unwrap();
+        assert!(!modified.verify(&signature, &address));
+
+        // Ensure the signature is invalid for a modified domain.
+        let domain = Plaintext::from_str("{ name: 1field, version: 2u8, chain: 3u16 }").unwrap();
+        let modified = TypedData::new(domain, typed_data.message().clone()).unwrap();
+        assert!(!modified.verify(&signature, &address));
+    }
+}
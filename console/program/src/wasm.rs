@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The record API for browser wallets, with `wasm-bindgen` types for the current network.
+
+use crate::{Ciphertext, Plaintext, Record};
+use snarkvm_console_account::wasm::{to_js_error, CurrentNetwork, ViewKey};
+use snarkvm_console_network::prelude::*;
+
+use wasm_bindgen::prelude::*;
+
+/// An encrypted record.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct RecordCiphertext(Record<CurrentNetwork, Ciphertext<CurrentNetwork>>);
+
+#[wasm_bindgen]
+impl RecordCiphertext {
+    /// Returns the record ciphertext for the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordCiphertext, JsError> {
+        Record::from_str(record).map(Self).map_err(to_js_error)
+    }
+
+    /// Returns the record ciphertext as a string.
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the given view key corresponds to the owner of the record.
+    #[wasm_bindgen(js_name = isOwner)]
+    pub fn is_owner(&self, view_key: &ViewKey) -> bool {
+        self.0.is_owner(view_key)
+    }
+
+    /// Decrypts the record with the given view key.
+    pub fn decrypt(&self, view_key: &ViewKey) -> Result<RecordPlaintext, JsError> {
+        self.0.decrypt(view_key).map(RecordPlaintext).map_err(to_js_error)
+    }
+}
+
+impl Deref for RecordCiphertext {
+    type Target = Record<CurrentNetwork, Ciphertext<CurrentNetwork>>;
+
+    /// Returns the record ciphertext.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A decrypted record.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct RecordPlaintext(Record<CurrentNetwork, Plaintext<CurrentNetwork>>);
+
+#[wasm_bindgen]
+impl RecordPlaintext {
+    /// Returns the record plaintext for the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordPlaintext, JsError> {
+        Record::from_str(record).map(Self).map_err(to_js_error)
+    }
+
+    /// Returns the record plaintext as a string.
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the owner of the record, as an address string.
+    pub fn owner(&self) -> String {
+        (**self.0.owner()).to_string()
+    }
+}
+
+impl Deref for RecordPlaintext {
+    type Target = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+    /// Returns the record plaintext.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scalar;
+    use snarkvm_console_account::wasm::PrivateKey;
+
+    #[test]
+    fn test_decrypt() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::new().unwrap();
+        let view_key = private_key.to_view_key().unwrap();
+        let address = private_key.to_address().unwrap();
+
+        // Encrypt a record for the address.
+        let randomizer = Scalar::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let plaintext = RecordPlaintext::from_string(&format!(
+            "{{ owner: {}.private, token_amount: 100u64.private, _nonce: {nonce}.public }}",
+            address.to_string()
+        ))
+        .unwrap();
+        let ciphertext = RecordCiphertext::from_string(&plaintext.encrypt(randomizer).unwrap().to_string()).unwrap();
+
+        // Ensure the owner can decrypt the record.
+        assert!(ciphertext.is_owner(&view_key));
+        let decrypted = ciphertext.decrypt(&view_key).unwrap();
+        assert_eq!(plaintext.to_string(), decrypted.to_string());
+        assert_eq!(address.to_string(), decrypted.owner());
+
+        // Ensure another account is not the owner.
+        let other_view_key = PrivateKey::new().unwrap().to_view_key().unwrap();
+        assert!(!ciphertext.is_owner(&other_view_key));
+    }
+}
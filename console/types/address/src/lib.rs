@@ -29,6 +29,9 @@ mod to_bits;
 mod to_field;
 mod to_fields;
 mod to_group;
+mod validate;
+
+pub use validate::AddressError;
 
 pub use snarkvm_console_network_environment::prelude::*;
 pub use snarkvm_console_types_boolean::Boolean;
@@ -14,7 +14,7 @@
 
 use super::*;
 
-static ADDRESS_PREFIX: &str = "aleo";
+pub(crate) static ADDRESS_PREFIX: &str = "aleo";
 
 impl<E: Environment> Parser for Address<E> {
     /// Parses a string into an address.
@@ -37,22 +37,9 @@ impl<E: Environment> FromStr for Address<E> {
     type Err = Error;
 
     /// Reads in an account address string.
+    /// Note: The error is an `AddressError`, which describes why the string is invalid. Use `Address::validate` to match on it.
     fn from_str(address: &str) -> Result<Self, Self::Err> {
-        // Ensure the address string length is 63 characters.
-        if address.len() != 63 {
-            bail!("Invalid account address length: found {}, expected 63", address.len())
-        }
-        // Decode the address string from bech32m.
-        let (hrp, data, variant) = bech32::decode(address)?;
-        if hrp != ADDRESS_PREFIX {
-            bail!("Failed to decode address: '{hrp}' is an invalid prefix")
-        } else if data.is_empty() {
-            bail!("Failed to decode address: data field is empty")
-        } else if variant != bech32::Variant::Bech32m {
-            bail!("Found an address that is not bech32m encoded: {address}");
-        }
-        // Decode the address data from u5 to u8, and into an account address.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        Ok(Self::validate(address)?)
     }
 }
 
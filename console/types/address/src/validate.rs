@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::parse::ADDRESS_PREFIX;
+
+use thiserror::Error;
+
+/// The number of characters in an address string.
+const ADDRESS_LENGTH: usize = 63;
+/// The bech32 character set.
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+/// The prefixes of the strings that are commonly mistaken for an address.
+const OTHER_PREFIXES: [(&str, &str); 5] = [
+    ("APrivateKey1", "private key"),
+    ("AViewKey1", "view key"),
+    ("AComputeKey1", "compute key"),
+    ("AGraphKey1", "graph key"),
+    ("sign1", "signature"),
+];
+
+/// The reason an address string is invalid.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum AddressError {
+    #[error("Invalid account address: found a {0}, expected an address starting with 'aleo1'")]
+    WrongType(&'static str),
+
+    #[error("Invalid account address prefix: found '{0}', expected 'aleo1'")]
+    InvalidPrefix(String),
+
+    #[error("Invalid account address length: found {0}, expected 63")]
+    InvalidLength(usize),
+
+    #[error("Invalid account address: found mixed-case characters{}", suggest(.0))]
+    MixedCase(Option<String>),
+
+    #[error("Invalid account address character '{character}' at position {position}{}", suggest(.suggestion))]
+    InvalidCharacter { character: char, position: usize, suggestion: Option<String> },
+
+    #[error("Invalid account address checksum{}", suggest(.0))]
+    InvalidChecksum(Option<String>),
+
+    #[error("Invalid account address encoding: found bech32, expected bech32m")]
+    InvalidVariant,
+
+    #[error("Invalid account address: the data does not encode an address")]
+    InvalidData,
+}
+
+impl AddressError {
+    /// Returns the likely intended address, if a correction was found.
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            Self::MixedCase(suggestion)
+            | Self::InvalidCharacter { suggestion, .. }
+            | Self::InvalidChecksum(suggestion) => suggestion.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the suffix of an error message for the given suggestion.
+fn suggest(suggestion: &Option<String>) -> String {
+    match suggestion {
+        Some(suggestion) => format!(", did you mean '{suggestion}'?"),
+        None => String::new(),
+    }
+}
+
+impl<E: Environment> Address<E> {
+    /// Returns the address for the given string, or the reason it is invalid, with a suggested correction if one is found.
+    pub fn validate(address: &str) -> Result<Self, AddressError> {
+        // Ensure the string is not another type of account string.
+        if let Some((_, name)) = OTHER_PREFIXES.iter().find(|(prefix, _)| address.starts_with(prefix)) {
+            return Err(AddressError::WrongType(name));
+        }
+        // Ensure the string is not mixed-case.
+        if address.chars().any(|c| c.is_ascii_uppercase()) && address.chars().any(|c| c.is_ascii_lowercase()) {
+            return Err(AddressError::MixedCase(
+                Self::validate(&address.to_ascii_lowercase()).ok().map(|_| address.to_ascii_lowercase()),
+            ));
+        }
+        // Ensure the prefix is correct.
+        let prefix = match address.find('1') {
+            Some(index) => &address[..index],
+            None => address,
+        };
+        if !prefix.eq_ignore_ascii_case(ADDRESS_PREFIX) {
+            return Err(AddressError::InvalidPrefix(prefix.chars().take(ADDRESS_PREFIX.len() + 8).collect()));
+        }
+        // Ensure the address string length is 63 characters.
+        if address.len() != ADDRESS_LENGTH {
+            return Err(AddressError::InvalidLength(address.len()));
+        }
+        // Ensure the data contains only bech32 characters.
+        let data_start = ADDRESS_PREFIX.len() + 1;
+        if let Some((position, character)) =
+            address.char_indices().skip(data_start).find(|(_, c)| !BECH32_CHARSET.contains(c.to_ascii_lowercase()))
+        {
+            // Replace the character with a lookalike from the character set.
+            let suggestion = match character.to_ascii_lowercase() {
+                'o' => Some('0'),
+                'b' => Some('6'),
+                'i' | '1' => Some('l'),
+                _ => None,
+            }
+            .map(|replacement| format!("{}{replacement}{}", &address[..position], &address[position + 1..]))
+            .filter(|candidate| Self::validate(candidate).is_ok());
+            return Err(AddressError::InvalidCharacter { character, position, suggestion });
+        }
+        // Decode the address.
+        match Self::decode(address) {
+            Err(AddressError::InvalidChecksum(_)) => {
+                Err(AddressError::InvalidChecksum(Self::correct_checksum(address)))
+            }
+            result => result,
+        }
+    }
+
+    /// Decodes the given address string, which has a valid prefix, length, and characters.
+    fn decode(address: &str) -> Result<Self, AddressError> {
+        // Decode the address string from bech32m.
+        let (_, data, variant) = match bech32::decode(address) {
+            Ok(decoded) => decoded,
+            Err(bech32::Error::InvalidChecksum) => return Err(AddressError::InvalidChecksum(None)),
+            Err(_) => return Err(AddressError::InvalidData),
+        };
+        if variant != bech32::Variant::Bech32m {
+            return Err(AddressError::InvalidVariant);
+        }
+        // Decode the address data from u5 to u8, and into an account address.
+        let bytes = Vec::from_base32(&data).map_err(|_| AddressError::InvalidData)?;
+        Self::read_le(&bytes[..]).map_err(|_| AddressError::InvalidData)
+    }
+
+    /// Returns the unique address that differs from the given string by one character, or by two swapped adjacent characters.
+    fn correct_checksum(address: &str) -> Option<String> {
+        let address = address.to_ascii_lowercase();
+        let data_start = ADDRESS_PREFIX.len() + 1;
+        let bytes = address.as_bytes();
+
+        let mut candidates = Vec::new();
+        for position in data_start..bytes.len() {
+            // Substitute the character at the position.
+            for replacement in BECH32_CHARSET.bytes().filter(|c| *c != bytes[position]) {
+                let mut candidate = bytes.to_vec();
+                candidate[position] = replacement;
+                candidates.push(candidate);
+            }
+            // Swap the character with the next character.
+            if position + 1 < bytes.len() && bytes[position] != bytes[position + 1] {
+                let mut candidate = bytes.to_vec();
+                candidate.swap(position, position + 1);
+                candidates.push(candidate);
+            }
+        }
+
+        let mut corrections = candidates
+            .into_iter()
+            .filter_map(|candidate| String::from_utf8(candidate).ok())
+            .filter(|candidate| Self::decode(candidate).is_ok());
+        // Only suggest a correction if it is unique.
+        match (corrections.next(), corrections.next()) {
+            (Some(correction), None) => Some(correction),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Returns the given string, with the character at the given position replaced.
+    fn replace(string: &str, position: usize, character: char) -> String {
+        format!("{}{character}{}", &string[..position], &string[position + 1..])
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected = Address::<CurrentEnvironment>::rand(&mut rng);
+            let string = expected.to_string();
+            assert_eq!(expected, Address::validate(&string).unwrap());
+            assert_eq!(expected, Address::validate(&string.to_ascii_uppercase()).unwrap());
+
+            // Ensure a mixed-case address is corrected.
+            let mixed = format!("ALEO{}", &string[4..]);
+            assert_eq!(
+                Address::<CurrentEnvironment>::validate(&mixed),
+                Err(AddressError::MixedCase(Some(string.clone())))
+            );
+
+            // Ensure a wrong prefix and length are reported.
+            let candidate = format!("aleq{}", &string[4..]);
+            assert_eq!(
+                Address::<CurrentEnvironment>::validate(&candidate),
+                Err(AddressError::InvalidPrefix("aleq".into()))
+            );
+            assert_eq!(Address::<CurrentEnvironment>::validate(&string[..62]), Err(AddressError::InvalidLength(62)));
+            assert_eq!(
+                Address::<CurrentEnvironment>::validate(&format!("{string}q")),
+                Err(AddressError::InvalidLength(64))
+            );
+
+            // Ensure a lookalike character is corrected.
+            if let Some(position) = string[5..].find('0').map(|offset| 5 + offset) {
+                let candidate = replace(&string, position, 'o');
+                let error = Address::<CurrentEnvironment>::validate(&candidate).unwrap_err();
+                assert_eq!(error, AddressError::InvalidCharacter {
+                    character: 'o',
+                    position,
+                    suggestion: Some(string.clone())
+                });
+            }
+
+            // Ensure a single substituted character is corrected.
+            let position = rng.gen_range(5..string.len());
+            let original = char::from(string.as_bytes()[position]);
+            let typo = BECH32_CHARSET.chars().find(|c| *c != original).unwrap();
+            let error = Address::<CurrentEnvironment>::validate(&replace(&string, position, typo)).unwrap_err();
+            assert_eq!(error, AddressError::InvalidChecksum(Some(string.clone())));
+
+            // Ensure two swapped adjacent characters are corrected.
+            let position = rng.gen_range(5..string.len() - 1);
+            let mut bytes = string.clone().into_bytes();
+            bytes.swap(position, position + 1);
+            if bytes != string.as_bytes() {
+                let error = Address::<CurrentEnvironment>::validate(&String::from_utf8(bytes).unwrap()).unwrap_err();
+                assert_eq!(error.suggestion(), Some(string.as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_other_types() {
+        for (string, name) in [
+            ("APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p", "private key"),
+            ("AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD", "view key"),
+        ] {
+            assert_eq!(Address::<CurrentEnvironment>::validate(string), Err(AddressError::WrongType(name)));
+        }
+        assert_eq!(Address::<CurrentEnvironment>::validate(""), Err(AddressError::InvalidPrefix("".into())));
+    }
+}
@@ -20,11 +20,16 @@ impl<E: Environment> Parser for Field<E> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the optional negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
-        // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        // Parse the decimal, hexadecimal, or binary digits from the string.
+        let (string, digits) = Digits::parse(string, Self::type_name())?;
         // Parse the value from the string.
-        let (string, value): (&str, E::Field) =
-            map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        let (string, value): (&str, E::Field) = map_res(tag(Self::type_name()), |_| -> Result<E::Field> {
+            match digits.radix() {
+                10 => Ok(digits.as_str().parse()?),
+                // Note: Hexadecimal and binary values must be less than the modulus.
+                _ => Ok(*Self::from_bits_le(&digits.to_bits_le()?)?),
+            }
+        })(string)?;
         // Negate the value if the negative sign was present.
         let value = match negation {
             true => -value,
@@ -94,6 +99,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_radix() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure hexadecimal and binary values match their decimal values.
+        assert_eq!(Field::<CurrentEnvironment>::from_str("0xff_field")?, Field::from_str("255field")?);
+        assert_eq!(Field::<CurrentEnvironment>::from_str("0xFF_FF_field")?, Field::from_str("65535field")?);
+        assert_eq!(Field::<CurrentEnvironment>::from_str("0b101field")?, Field::from_str("5field")?);
+        assert_eq!(Field::<CurrentEnvironment>::from_str("-0x1_field")?, Field::from_str("-1field")?);
+
+        // Ensure hexadecimal digits that run into the type name fail.
+        assert!(Field::<CurrentEnvironment>::from_str("0xffield").is_err());
+        assert!(Field::<CurrentEnvironment>::from_str("0x1field").is_err());
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value, and write it in binary.
+            let field = Field::<CurrentEnvironment>::new(Uniform::rand(rng));
+            let binary = field.to_bits_le().iter().rev().map(|bit| if *bit { '1' } else { '0' }).collect::<String>();
+            assert_eq!(field, Field::from_str(&format!("0b{binary}field"))?);
+        }
+
+        // Ensure values greater than or equal to the modulus fail.
+        let size_in_digits = (Field::<CurrentEnvironment>::size_in_bits() + 3) / 4;
+        assert!(Field::<CurrentEnvironment>::from_str(&format!("0x{}_field", "f".repeat(size_in_digits))).is_err());
+        // Ensure a prefix without digits fails.
+        assert!(Field::<CurrentEnvironment>::from_str("0xfield").is_err());
+        assert!(Field::<CurrentEnvironment>::from_str("0bfield").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a field from the given element,
@@ -20,15 +20,19 @@ impl<E: Environment> Parser for Group<E> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the optional negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
-        // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        // Parse the decimal, hexadecimal, or binary digits from the string.
+        let (string, digits) = Digits::parse(string, Self::type_name())?;
         // Parse the group from the string.
         let (string, group): (&str, Self) = map_res(tag(Self::type_name()), |_| {
-            let x_coordinate = primitive.replace('_', "").parse()?;
+            let x_coordinate = match digits.radix() {
+                10 => Field::new(digits.as_str().parse()?),
+                // Note: Hexadecimal and binary values must be less than the modulus.
+                _ => Field::from_bits_le(&digits.to_bits_le()?)?,
+            };
             // Recover and negate the group element if the negative sign was present.
             match negation {
-                true => Ok(-Group::from_x_coordinate(Field::new(x_coordinate))?),
-                false => Group::from_x_coordinate(Field::new(x_coordinate)),
+                true => Ok(-Group::from_x_coordinate(x_coordinate)?),
+                false => Group::from_x_coordinate(x_coordinate),
             }
         })(string)?;
 
@@ -95,6 +99,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_radix() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random value, and write its x-coordinate in binary.
+            let group = Group::<CurrentEnvironment>::new(Uniform::rand(rng));
+            let x_coordinate = group.to_x_coordinate();
+            let binary = x_coordinate.to_bits_le().iter().rev().map(|bit| if *bit { '1' } else { '0' }).collect::<String>();
+            assert_eq!(group, Group::from_str(&format!("0b{binary}group"))?);
+            assert_eq!(-group, Group::from_str(&format!("-0b{binary}group"))?);
+        }
+
+        // Ensure hexadecimal values match their decimal values.
+        assert_eq!(Group::<CurrentEnvironment>::from_str("0x0group")?, Group::from_str("0group")?);
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a group from the given element,
@@ -277,6 +277,42 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrAssign<Integer<E, M>> for
     }
 }
 
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateLeft<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates the bits of `self` to the left by `n` bits, wrapping the truncated bits to the end.
+    #[inline]
+    fn rotate_left(&self, n: &Integer<E, M>) -> Self::Output {
+        // Note: Casting `n` to a `u32` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+        let n = n.integer.to_u32().unwrap() % I::BITS as u32;
+        // Rotating to the left moves each bit to a more significant position in the little-endian bits.
+        let mut bits_le = self.integer.to_bits_le();
+        bits_le.rotate_right(n as usize);
+        match I::from_bits_le(&bits_le) {
+            Ok(rotated) => Integer::new(rotated),
+            Err(error) => E::halt(format!("Failed to rotate {self} left by {n} bits: {error}")),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateRight<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates the bits of `self` to the right by `n` bits, wrapping the truncated bits to the beginning.
+    #[inline]
+    fn rotate_right(&self, n: &Integer<E, M>) -> Self::Output {
+        // Note: Casting `n` to a `u32` is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+        let n = n.integer.to_u32().unwrap() % I::BITS as u32;
+        // Rotating to the right moves each bit to a less significant position in the little-endian bits.
+        let mut bits_le = self.integer.to_bits_le();
+        bits_le.rotate_left(n as usize);
+        match I::from_bits_le(&bits_le) {
+            Ok(rotated) => Integer::new(rotated),
+            Err(error) => E::halt(format!("Failed to rotate {self} right by {n} bits: {error}")),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
     type Boolean = Boolean<E>;
     type Output = Self;
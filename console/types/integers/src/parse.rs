@@ -20,12 +20,13 @@ impl<E: Environment, I: IntegerType> Parser for Integer<E, I> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the negative sign '-' from the string.
         let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.unwrap_or_default().to_string())(string)?;
-        // Parse the digits from the string.
-        let (string, primitive) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
-        // Combine the sign and primitive.
-        let primitive = negation + primitive;
+        // Parse the decimal, hexadecimal, or binary digits from the string.
+        let (string, digits) = Digits::parse(string, Self::type_name())?;
         // Parse the value from the string.
-        let (string, value) = map_res(tag(Self::type_name()), |_| primitive.replace('_', "").parse())(string)?;
+        let (string, value) = map_res(tag(Self::type_name()), |_| -> Result<I> {
+            // Combine the sign and the magnitude, which is then parsed in decimal to enforce the bounds of the type.
+            Ok(format!("{negation}{}", digits.to_u128()?).parse()?)
+        })(string)?;
 
         Ok((string, Integer::new(value)))
     }
@@ -90,6 +91,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_radix() -> Result<()> {
+        // Ensure hexadecimal and binary values match their decimal values.
+        assert_eq!(Integer::<CurrentEnvironment, u8>::from_str("0xffu8")?, Integer::from_str("255u8")?);
+        assert_eq!(Integer::<CurrentEnvironment, u8>::from_str("0b1111_0000u8")?, Integer::from_str("240u8")?);
+        assert_eq!(Integer::<CurrentEnvironment, u32>::from_str("0xDEAD_BEEFu32")?, Integer::from_str("3735928559u32")?);
+        assert_eq!(Integer::<CurrentEnvironment, i8>::from_str("-0x80i8")?, Integer::from_str("-128i8")?);
+        assert_eq!(
+            Integer::<CurrentEnvironment, u128>::from_str(&format!("0x{}u128", "f".repeat(32)))?,
+            Integer::new(u128::MAX)
+        );
+
+        // Ensure values outside the bounds of the type fail.
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0x100u8").is_err());
+        assert!(Integer::<CurrentEnvironment, i8>::from_str("0x80i8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("-0x1u8").is_err());
+        assert!(Integer::<CurrentEnvironment, u128>::from_str(&format!("0x1{}u128", "0".repeat(32))).is_err());
+        // Ensure a prefix without digits or invalid digits fail.
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0xu8").is_err());
+        assert!(Integer::<CurrentEnvironment, u8>::from_str("0b2u8").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         /// Attempts to construct a integer from the given element,
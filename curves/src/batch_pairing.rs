@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, Zero};
+
+use rand::Rng;
+use std::collections::HashMap;
+
+/// A batch of pairing equations of the form `prod_i e(g1_i, g2_i) == 1`,
+/// which are checked together with one Miller loop and one final exponentiation.
+///
+/// Each equation is scaled by a random 128-bit coefficient before it is combined,
+/// so that the batch only passes if every equation holds, except with probability at most 2^-128.
+/// Pairs that share a G2 element are merged in G1, so each distinct G2 element costs a single pairing.
+#[derive(Clone, Debug)]
+pub struct BatchPairingCheck<E: PairingEngine> {
+    /// The accumulated G1 elements, one for each distinct G2 element.
+    pairs: Vec<(E::G1Projective, E::G2Affine)>,
+    /// The index of each distinct G2 element in `pairs`.
+    indices: HashMap<E::G2Affine, usize>,
+    /// The number of equations in the batch.
+    num_equations: usize,
+}
+
+impl<E: PairingEngine> Default for BatchPairingCheck<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: PairingEngine> BatchPairingCheck<E> {
+    /// Initializes an empty batch of pairing equations.
+    pub fn new() -> Self {
+        Self { pairs: Vec::new(), indices: HashMap::new(), num_equations: 0 }
+    }
+
+    /// Returns the number of equations in the batch.
+    pub fn num_equations(&self) -> usize {
+        self.num_equations
+    }
+
+    /// Returns the number of pairings that are computed when the batch is verified.
+    pub fn num_pairings(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns `true` if the batch has no equations.
+    pub fn is_empty(&self) -> bool {
+        self.num_equations == 0
+    }
+
+    /// Adds the equation `prod_i e(g1_i, g2_i) == 1` to the batch.
+    pub fn add_equation<R: Rng + ?Sized>(
+        &mut self,
+        pairs: impl IntoIterator<Item = (E::G1Affine, E::G2Affine)>,
+        rng: &mut R,
+    ) {
+        // Note: The first equation does not need to be randomized, as only the ratios of the coefficients matter.
+        let coefficient = match self.num_equations {
+            0 => E::Fr::one(),
+            _ => E::Fr::from(rng.gen::<u128>()),
+        };
+        for (g1, g2) in pairs {
+            // Skip the pairs that do not contribute to the product.
+            if g1.is_zero() || g2.is_zero() {
+                continue;
+            }
+            let g1 = match coefficient.is_one() {
+                true => g1.to_projective(),
+                false => g1 * coefficient,
+            };
+            match self.indices.get(&g2) {
+                Some(&index) => self.pairs[index].0 += g1,
+                None => {
+                    self.indices.insert(g2, self.pairs.len());
+                    self.pairs.push((g1, g2));
+                }
+            }
+        }
+        self.num_equations += 1;
+    }
+
+    /// Returns `true` if every equation in the batch holds.
+    pub fn verify(self) -> bool {
+        // Convert the accumulated G1 elements to affine coordinates, with one inversion.
+        let (g1s, g2s): (Vec<_>, Vec<_>) = self.pairs.into_iter().unzip();
+        let g1s = E::G1Projective::batch_normalization_into_affine(g1s);
+
+        // Compute the product of the pairings, and check that it is the identity.
+        E::multi_pairing(g1s.into_iter().zip(g2s)).is_one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_377::{Bls12_377, Fq12, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 5;
+
+    /// Samples the equation `e(a * s, b) * e(-a, b * s) == 1`, which holds by bilinearity.
+    fn sample_equation(rng: &mut TestRng) -> Vec<(G1Affine, G2Affine)> {
+        let a = G1Projective::rand(rng);
+        let b = G2Projective::rand(rng);
+        let s = Fr::rand(rng);
+        vec![((a * s).to_affine(), b.to_affine()), ((-a).to_affine(), (b * s).to_affine())]
+    }
+
+    #[test]
+    fn test_multi_pairing() {
+        let mut rng = TestRng::default();
+
+        let pairs = (0..ITERATIONS).map(|_| (G1Affine::rand(&mut rng), G2Affine::rand(&mut rng))).collect::<Vec<_>>();
+        let expected = pairs.iter().map(|(a, b)| Bls12_377::pairing(*a, *b)).product::<Fq12>();
+        assert_eq!(Bls12_377::multi_pairing(pairs), expected);
+    }
+
+    #[test]
+    fn test_batch_pairing_check() {
+        let mut rng = TestRng::default();
+
+        for num_equations in 0..ITERATIONS {
+            // Check that a batch of valid equations passes.
+            let mut batch = BatchPairingCheck::<Bls12_377>::new();
+            for _ in 0..num_equations {
+                batch.add_equation(sample_equation(&mut rng), &mut rng);
+            }
+            assert_eq!(batch.num_equations(), num_equations);
+            assert!(batch.clone().verify());
+
+            // Check that the batch fails if any equation does not hold.
+            let (a, b) = (G1Affine::rand(&mut rng), G2Affine::rand(&mut rng));
+            batch.add_equation([(a, b)], &mut rng);
+            assert!(!batch.verify());
+        }
+    }
+
+    #[test]
+    fn test_batch_pairing_check_merges_g2() {
+        let mut rng = TestRng::default();
+
+        // Sample equations of the form `e(a_i, h) * e(-a_i, h) == 1`, which share the same G2 element.
+        let h = G2Affine::rand(&mut rng);
+        let mut batch = BatchPairingCheck::<Bls12_377>::new();
+        for _ in 0..ITERATIONS {
+            let a = G1Affine::rand(&mut rng);
+            batch.add_equation([(a, h), (-a, h)], &mut rng);
+        }
+        assert_eq!(batch.num_equations(), ITERATIONS);
+        assert_eq!(batch.num_pairings(), 1);
+        assert!(batch.verify());
+
+        // Check that the batch fails if two equations each do not hold, even if their errors would cancel out.
+        let (a, b) = (G1Affine::rand(&mut rng), G1Affine::rand(&mut rng));
+        let mut batch = BatchPairingCheck::<Bls12_377>::new();
+        batch.add_equation(sample_equation(&mut rng), &mut rng);
+        batch.add_equation([(a, h), (-b, h)], &mut rng);
+        batch.add_equation([(b, h), (-a, h)], &mut rng);
+        assert!(!batch.verify());
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// BLS12-381 scalar field.
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[
+        BigInteger([13381757501831005802, 6564924994866501612, 789602057691799140, 6625830629041353339]),
+        BigInteger([2247254910183794051, 10179359060998904646, 16374224147123116639, 2505802552228490801]),
+        BigInteger([10279234601271501832, 12244463288630609852, 11266272650963883056, 5652581035981853584]),
+        BigInteger([2669118413884432918, 5934166748088376158, 16444912192167141296, 167597312378346012]),
+        BigInteger([3203747135021890743, 70994565004507607, 17235788135750828559, 7876924154499784133]),
+        BigInteger([16696510867198663912, 12221097625107267195, 3543242541900803268, 4707463238453853309]),
+        BigInteger([9945946604290812967, 8377052352530705012, 18440725395715115930, 4925158585391604575]),
+        BigInteger([2714820497449887946, 11679381547634373161, 4840180967673222302, 6983776311278389609]),
+        BigInteger([7660530968858739962, 11874253193584273370, 11761487690460443391, 255081491333449653]),
+        BigInteger([13606120907746107200, 2958353736330050301, 4014770707312291613, 5767795444543284552]),
+        BigInteger([987386510384600923, 11332811039286575596, 6151389280757851111, 7013439565078853168]),
+        BigInteger([2620985839949755891, 6772206329088222003, 12226284371775004815, 4919209079377057285]),
+        BigInteger([1469178399808718286, 7599259365871963436, 8933719661150226646, 1424763332181769228]),
+        BigInteger([4287460369908740981, 12311208848362562774, 6848587066192510757, 289989561920731332]),
+        BigInteger([6261996195661067430, 8755663888868851183, 4490492374759947232, 5991359774874516781]),
+        BigInteger([9659294510740816672, 5498993734223199433, 15649895282809273878, 4625935495086727891]),
+        BigInteger([4982921323682023417, 14754743735024931754, 17502517684368924452, 6330752984026055240]),
+        BigInteger([7979896386485297008, 1527833538803911420, 7397147933221900311, 6587613531531591297]),
+        BigInteger([11404955049196627414, 2297758466819477275, 6099599778808296364, 1155909078486489671]),
+        BigInteger([11762601369654853577, 4754331843865155954, 458560669857420187, 8204223352538390613]),
+        BigInteger([17500748647561005625, 327882457407811959, 1622839330127799675, 304905280203330990]),
+        BigInteger([17628337692763101669, 9438022943359071367, 12650783271993012248, 66914617722419450]),
+        BigInteger([11864420382399758890, 18195565927427728881, 16759393787988053888, 8029136087195778842]),
+        BigInteger([2303317588682311819, 14808230075684248308, 16476861943053935190, 6270675576097159939]),
+        BigInteger([13022296683467543916, 7661133950517522179, 9115645151217961758, 2446776454592824750]),
+        BigInteger([15777868834799428406, 11622678732444946282, 16130905425703808603, 8025976722118475544]),
+        BigInteger([13616601703093039458, 1572254930254677924, 13253353514880961111, 3647086199338495155]),
+        BigInteger([3514927915072804143, 16320912647252728779, 745894539287512369, 1826404433089498275]),
+        BigInteger([1931873697864362853, 6564196806406635599, 17178027137773862389, 2857626588001866400]),
+        BigInteger([7984681569392717816, 7980935456522893911, 4608817634557210972, 6104631936886375195]),
+        BigInteger([17559630006194917297, 517633858171484670, 14887226632843983398, 5561070350782530420]),
+    ];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = 10238227357739495823651030575849232062558860180284477541189508159991286009131
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xb9b58d8c5f0e466a,
+        0x5b1b4c801819d7ec,
+        0xaf53ae352a31e64,
+        0x5bf3adda19e9b27b,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 7
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0xefffffff1,
+        0x17e363d300189c0f,
+        0xff9c57876f8457b0,
+        0x351332208fc5a8c4,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 0xfffffffeffffffff;
+    /// MODULUS = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xffffffff00000001,
+        0x53bda402fffe5bfe,
+        0x3339d80809a1d805,
+        0x73eda753299d7d48,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x7fffffff80000000,
+        0xa9ded2017fff2dff,
+        0x199cec0404d0ec02,
+        0x39f6d3a994cebea4,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x1fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xc999e990f3f29c6d,
+        0x2b6cedcb87925c23,
+        0x5d314967254398f,
+        0x748d9d99f59ff11,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    /// T = (MODULUS - 1) // 2^S =
+    /// 12208678567578594777604504606729831043093128246378069236549469339647
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xfffe5bfeffffffff,
+        0x9a1d80553bda402,
+        0x299d7d483339d808,
+        0x73eda753,
+    ]);
+    /// (T - 1) // 2 =
+    /// 6104339283789297388802252303364915521546564123189034618274734669823
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x7fff2dff7fffffff,
+        0x4d0ec02a9ded201,
+        0x94cebea4199cec04,
+        0x39f6d3a9,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FrParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{FftField, Field, PrimeField};
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        let two = Fr::from(2u8);
+
+        // Compute the expected powers of root of unity.
+        let root_of_unity = Fr::two_adic_root_of_unity();
+        let powers = (0..FrParameters::TWO_ADICITY - 1)
+            .map(|i| root_of_unity.pow(two.pow(Fr::from(i as u64).to_bigint()).to_bigint()))
+            .collect::<Vec<_>>();
+
+        // Ensure the correct number of powers of root of unity are present.
+        assert_eq!(FrParameters::POWERS_OF_ROOTS_OF_UNITY.len() as u64, (FrParameters::TWO_ADICITY - 1) as u64);
+        assert_eq!(FrParameters::POWERS_OF_ROOTS_OF_UNITY.len(), powers.len());
+
+        // Ensure the expected and candidate powers match.
+        for (expected, candidate) in powers.iter().zip(FrParameters::POWERS_OF_ROOTS_OF_UNITY) {
+            assert_eq!(&expected.0, candidate);
+        }
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Fr::multiplicative_generator().pow(FrParameters::T);
+        assert_eq!(expected, Fr::two_adic_root_of_unity());
+    }
+}
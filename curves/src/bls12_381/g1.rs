@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{field, PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger256, BigInteger384},
+    BitIteratorBE,
+};
+
+use crate::{
+    bls12_381::{Fq, Fr},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+    AffineCurve,
+    ProjectiveCurve,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bls12_381G1Parameters;
+
+impl ModelParameters for Bls12_381G1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Bls12_381G1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+    /// B1 = x^2 - 1
+    const B1: Fr = field!(
+        Fr,
+        BigInteger256([10581498742487126482, 18202632089594667123, 13975037914852467110, 107924994359545323])
+    );
+    /// B2 = x^2
+    const B2: Fr = field!(
+        Fr,
+        BigInteger256([10581498751077061072, 6134313272518502517, 6592600117572923804, 1847635349140198235])
+    );
+    /// COFACTOR = (x - 1)^2 / 3 = 76329603384216526031706109802092473003
+    const COFACTOR: &'static [u64] = &[0x8c00aaab0000aaab, 0x396c8c005555e156];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    ///              = 52435875175126190458656871551744051925719901746859129887267498875565241663483
+    const COFACTOR_INV: Fr =
+        field!(Fr, BigInteger256([288839107172787499, 1152722415086798946, 2612889808468387987, 5124657601728438008]));
+    /// PHI = a cube root of unity, where (PHI * x, y) = (x^2 - 1) * (x, y) in G1
+    const PHI: Fq = field!(
+        Fq,
+        BigInteger384([
+            0xcd03c9e48671f071,
+            0x5dab22461fcda5d2,
+            0x587042afd3851b95,
+            0x8eb60ebe01bacb9e,
+            0x3f97d6e83d050d2,
+            0x18f0206554638741,
+        ])
+    );
+    /// R128 = 2^128 - 1
+    const R128: Fr = field!(
+        Fr,
+        BigInteger256([14927939739479666991, 4175742571572041739, 16226352327327285525, 1556554173745411419])
+    );
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fq = field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]));
+    /// WEIERSTRASS_B = 4
+    const WEIERSTRASS_B: Fq = field!(
+        Fq,
+        BigInteger384([
+            0xaa270000000cfff3,
+            0x53cc0032fc34000a,
+            0x478fe97a6b0a807f,
+            0xb1d37ebee6ba24d7,
+            0x8ec9733bbf78ab2f,
+            0x9d645513d83de7e,
+        ])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &super::G1Affine) -> bool {
+        p.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic())).is_zero()
+    }
+
+    fn glv_endomorphism(
+        mut p: crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> crate::templates::short_weierstrass_jacobian::Affine<Self> {
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(
+        p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
+        by: Self::ScalarField,
+    ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
+        // Note: The default GLV decomposition parameters are specific to the BLS12-377 scalar field,
+        // so this uses double-and-add instead.
+        let mut acc = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(by.to_bigint()) {
+            acc.double_in_place();
+            if bit {
+                acc += p;
+            }
+        }
+        acc
+    }
+}
+
+///
+/// G1_GENERATOR_X =
+/// 3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
+///
+pub const G1_GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        6679831729115696150,
+        8653662730902241269,
+        1535610680227111361,
+        17342916647841752903,
+        17135755455211762752,
+        1297449291367578485,
+    ])
+);
+
+///
+/// G1_GENERATOR_Y =
+/// 1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569
+///
+pub const G1_GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        13451288730302620273,
+        10097742279870053774,
+        15949884091978425806,
+        5885175747529691540,
+        1016841820992199104,
+        845620083434234474,
+    ])
+);
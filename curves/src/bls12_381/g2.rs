@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{field, PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger256, BigInteger384},
+    BitIteratorBE,
+};
+
+use crate::{
+    bls12_381::{g1::Bls12_381G1Parameters, Fq, Fq2, Fr},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+    AffineCurve,
+    ProjectiveCurve,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bls12_381G2Parameters;
+
+impl ModelParameters for Bls12_381G2Parameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Bls12_381G2Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G2_GENERATOR_X, G2_GENERATOR_Y);
+    /// B1 = x^2 - 1
+    const B1: Fr = field!(
+        Fr,
+        BigInteger256([10581498742487126482, 18202632089594667123, 13975037914852467110, 107924994359545323])
+    );
+    /// B2 = x^2
+    const B2: Fr = field!(
+        Fr,
+        BigInteger256([10581498751077061072, 6134313272518502517, 6592600117572923804, 1847635349140198235])
+    );
+    /// COFACTOR = (x^8 - 4 x^7 + 5 x^6 - 4 x^4 + 6 x^3 - 4 x^2 - 4 x + 13) / 9 =
+    /// 305502333931268344200999753193121504214466019254188142667664032982267604182971884026507427359259977847832272839041616661285803823378372096355777062779109
+    const COFACTOR: &'static [u64] = &[
+        0xcf1c38e31c7238e5,
+        0x1616ec6e786f0c70,
+        0x21537e293a6691ae,
+        0xa628f1cb4d9e82ef,
+        0xa68a205b2e5a7ddf,
+        0xcd91de4547085aba,
+        0x091d50792876a202,
+        0x05d543a95414e7f1,
+    ];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    ///              = 26652489039290660355457965112010883481355318854675681319708643586776743290055
+    const COFACTOR_INV: Fr =
+        field!(Fr, BigInteger256([6746407649509787816, 1304054119431494378, 2461312685643913071, 5956596749362435284]));
+    /// PHI = a cube root of unity, where (PHI * x, y) = (x^2 - 1) * (x, y) in G2
+    const PHI: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x30f1361b798a64e8,
+                0xf3b8ddab7ece5a2a,
+                0x16a8ca3ac61577f7,
+                0xc26a2ff874fd029b,
+                0x3636b76660701c6e,
+                0x51ba4ab241b6160,
+            ])
+        ),
+        field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    );
+    /// R128 = 2^128 - 1
+    const R128: Fr = field!(
+        Fr,
+        BigInteger256([14927939739479666991, 4175742571572041739, 16226352327327285525, 1556554173745411419])
+    );
+    /// WEIERSTRASS_A = [0, 0]
+    const WEIERSTRASS_A: Fq2 = field!(Fq2, Bls12_381G1Parameters::WEIERSTRASS_A, Bls12_381G1Parameters::WEIERSTRASS_A,);
+    // As per https://eprint.iacr.org/2012/072.pdf,
+    // this curve has b' = b * i, where b is the COEFF_B of G1, and x^6 - i is
+    // the irreducible poly used to extend from Fp2 to Fp12.
+    // In our case, i = u + 1.
+    /// WEIERSTRASS_B = [4, 4]
+    const WEIERSTRASS_B: Fq2 = field!(Fq2, Bls12_381G1Parameters::WEIERSTRASS_B, Bls12_381G1Parameters::WEIERSTRASS_B,);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(
+        p: &crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> bool {
+        p.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic())).is_zero()
+    }
+
+    fn glv_endomorphism(
+        mut p: crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> crate::templates::short_weierstrass_jacobian::Affine<Self> {
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(
+        p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
+        by: Self::ScalarField,
+    ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
+        // Note: The default GLV decomposition parameters are specific to the BLS12-377 scalar field,
+        // so this uses double-and-add instead.
+        let mut acc = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(by.to_bigint()) {
+            acc.double_in_place();
+            if bit {
+                acc += p;
+            }
+        }
+        acc
+    }
+}
+
+pub const G2_GENERATOR_X: Fq2 = field!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
+pub const G2_GENERATOR_Y: Fq2 = field!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
+
+///
+/// G2_GENERATOR_X_C0 =
+/// 352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160
+///
+pub const G2_GENERATOR_X_C0: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        17722385409647053328,
+        12967546844987299354,
+        11648722842835150208,
+        10994581490347323113,
+        8027586497049998955,
+        396758299565931735,
+    ])
+);
+
+///
+/// G2_GENERATOR_X_C1 =
+/// 3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758
+///
+pub const G2_GENERATOR_X_C1: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        11937283898719073798,
+        12295044263989567683,
+        4301357764460312582,
+        1953074377943790439,
+        14030662337566180679,
+        1266120665323335155,
+    ])
+);
+
+///
+/// G2_GENERATOR_Y_C0 =
+/// 1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905
+///
+pub const G2_GENERATOR_Y_C0: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        5508758831087832138,
+        6448303779119275098,
+        16710190169160573786,
+        13542242618704742751,
+        563980702369916322,
+        37152010398653157,
+    ])
+);
+
+///
+/// G2_GENERATOR_Y_C1 =
+/// 927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582
+///
+pub const G2_GENERATOR_Y_C1: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        12520284671833321565,
+        1777275927576994268,
+        9704602344324656032,
+        8739618045342622522,
+        16651875250601773805,
+        804950956836789234,
+    ])
+);
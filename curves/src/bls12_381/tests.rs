@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    bls12_381::{
+        g1::Bls12_381G1Parameters,
+        g2::Bls12_381G2Parameters,
+        Bls12_381,
+        Fq,
+        Fq12,
+        Fq2,
+        Fq6,
+        Fr,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+    },
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        PairingEngine,
+        ProjectiveCurve,
+        ShortWeierstrassParameters,
+    },
+};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::{
+    rand::{TestRng, Uniform},
+    BitIteratorBE,
+};
+
+use rand::Rng;
+use std::ops::Mul;
+
+pub(crate) const ITERATIONS: usize = 10;
+
+#[test]
+fn test_bls12_381_fr() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fr>(&mut rng);
+        sqrt_field_test(b, &mut rng);
+        field_serialization_test::<Fr>(&mut rng);
+    }
+}
+
+#[test]
+fn test_bls12_381_fq() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(a, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_bls12_381_fq2() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = rng.gen();
+        let b: Fq2 = rng.gen();
+        field_test(a, b, &mut rng);
+        sqrt_field_test(a, &mut rng);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq2>(&mut rng);
+}
+
+#[test]
+fn test_bls12_381_fq6() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g: Fq6 = rng.gen();
+        let h: Fq6 = rng.gen();
+        field_test(g, h, &mut rng);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq6>(&mut rng);
+}
+
+#[test]
+fn test_bls12_381_fq12() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g: Fq12 = rng.gen();
+        let h: Fq12 = rng.gen();
+        field_test(g, h, &mut rng);
+    }
+    frobenius_test::<Fq12, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq12>(&mut rng);
+}
+
+#[test]
+fn test_fq_sqrt() {
+    let mut rng = TestRng::default();
+
+    // Note: The base field has a two-adicity of 1, so its square roots take a separate path.
+    for _ in 0..1000 {
+        let a = Fq::rand(&mut rng);
+        let b = a.square();
+        let c = b.sqrt().unwrap();
+        assert!(a == c || a == -c);
+        // Ensure `-1` is a quadratic non-residue.
+        assert!(b.is_zero() || (-b).sqrt().is_none());
+    }
+}
+
+#[test]
+fn test_g1_projective_mul() {
+    let mut rng = TestRng::default();
+
+    let point = G1Projective::rand(&mut rng);
+    let scalar = Fr::rand(&mut rng);
+    let affine = point.to_affine();
+    assert_eq!(point.mul(scalar), affine.mul(scalar));
+    assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
+    // Ensure the endomorphism acts as multiplication by `x^2 - 1`.
+    assert_eq!(Bls12_381G1Parameters::glv_endomorphism(affine).to_projective(), affine.mul(Bls12_381G1Parameters::B1));
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<G1Projective>(&mut rng);
+    sw_tests::<Bls12_381G1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G1Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<G2Projective>(&mut rng);
+    sw_tests::<Bls12_381G2Parameters>(&mut rng);
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: G2Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = Bls12_381::pairing(sa, b);
+    let ans2 = Bls12_381::pairing(a, sb);
+    let ans3 = Bls12_381::pairing(a, b).pow(s.to_bigint());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq12::one());
+    assert_ne!(ans2, Fq12::one());
+    assert_ne!(ans3, Fq12::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
+}
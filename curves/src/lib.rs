@@ -21,8 +21,14 @@
 #[macro_use]
 extern crate thiserror;
 
+pub mod batch_pairing;
+pub use batch_pairing::*;
+
 pub mod bls12_377;
 
+#[cfg(feature = "bls12_381")]
+pub mod bls12_381;
+
 pub mod edwards_bls12;
 
 pub mod errors;
@@ -73,6 +73,17 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Hash
         Self::final_exponentiation(&Self::miller_loop(i)).unwrap()
     }
 
+    /// Computes the product of the pairings of the given (G1, G2) pairs, with one final exponentiation.
+    #[must_use]
+    fn multi_pairing<G1, G2>(pairs: impl IntoIterator<Item = (G1, G2)>) -> Self::Fqk
+    where
+        G1: Into<Self::G1Affine>,
+        G2: Into<Self::G2Affine>,
+    {
+        let prepared = pairs.into_iter().map(|(p, q)| (p.into().prepare(), q.into().prepare())).collect::<Vec<_>>();
+        Self::product_of_pairings(prepared.iter().map(|(p, q)| (p, q)))
+    }
+
     /// Performs multiple pairing operations
     #[must_use]
     fn pairing<G1, G2>(p: G1, q: G2) -> Self::Fqk
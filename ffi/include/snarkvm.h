@@ -0,0 +1,69 @@
+/*
+ * Copyright (C) 2019-2023 Aleo Systems Inc.
+ * This file is part of the snarkVM library.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at:
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#ifndef SNARKVM_H
+#define SNARKVM_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The status code returned by every function. Codes are never renumbered or reused. */
+typedef int32_t snarkvm_status_t;
+
+#define SNARKVM_OK                    0
+#define SNARKVM_NULL_POINTER          1
+#define SNARKVM_INVALID_UTF8          2
+#define SNARKVM_INVALID_PRIVATE_KEY   3
+#define SNARKVM_INVALID_VIEW_KEY      4
+#define SNARKVM_INVALID_RECORD        5
+#define SNARKVM_RECORD_NOT_OWNED      6
+#define SNARKVM_INVALID_PROGRAM       7
+#define SNARKVM_INVALID_TRANSACTION   8
+#define SNARKVM_VERIFICATION_FAILED   9
+#define SNARKVM_UNSUPPORTED           10
+#define SNARKVM_INTERNAL              11
+#define SNARKVM_PANIC                 12
+
+/* Returns the message of the last error on the calling thread, or NULL. Valid until the next call on the thread. */
+const char *snarkvm_last_error_message(void);
+
+/* Releases a string returned by snarkVM. */
+void snarkvm_string_free(char *string);
+
+/* Accounts. Every output string must be released with `snarkvm_string_free`. */
+snarkvm_status_t snarkvm_private_key_new(char **private_key);
+snarkvm_status_t snarkvm_private_key_to_view_key(const char *private_key, char **view_key);
+snarkvm_status_t snarkvm_private_key_to_address(const char *private_key, char **address);
+snarkvm_status_t snarkvm_view_key_to_address(const char *view_key, char **address);
+
+/* Records. */
+snarkvm_status_t snarkvm_record_decrypt(const char *view_key, const char *ciphertext, char **plaintext);
+
+/* Programs. */
+snarkvm_status_t snarkvm_program_parse(const char *program, char **program_id);
+snarkvm_status_t snarkvm_program_load(const char *program);
+
+/* Transactions. */
+snarkvm_status_t snarkvm_transaction_verify(const char *transaction);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SNARKVM_H */
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{read_str, run, write_string, CurrentNetwork, SnarkvmStatus, WithStatus};
+use console::account::{Address, PrivateKey, ViewKey};
+
+use std::{ffi::c_char, str::FromStr};
+
+/// Samples a new private key, and writes it to `private_key`.
+///
+/// # Safety
+/// `private_key` must be valid for writes. The output must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_new(private_key: *mut *mut c_char) -> SnarkvmStatus {
+    run(|| {
+        let new_private_key =
+            PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng()).with_status(SnarkvmStatus::Internal)?;
+        write_string(private_key, new_private_key.to_string())
+    })
+}
+
+/// Derives the view key of the given private key, and writes it to `view_key`.
+///
+/// # Safety
+/// `private_key` must be a NUL-terminated string, and `view_key` must be valid for writes.
+/// The output must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_to_view_key(
+    private_key: *const c_char,
+    view_key: *mut *mut c_char,
+) -> SnarkvmStatus {
+    run(|| {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(read_str(private_key, "private key")?)
+            .with_status(SnarkvmStatus::InvalidPrivateKey)?;
+        let derived = ViewKey::try_from(&private_key).with_status(SnarkvmStatus::InvalidPrivateKey)?;
+        write_string(view_key, derived.to_string())
+    })
+}
+
+/// Derives the address of the given private key, and writes it to `address`.
+///
+/// # Safety
+/// `private_key` must be a NUL-terminated string, and `address` must be valid for writes.
+/// The output must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_to_address(
+    private_key: *const c_char,
+    address: *mut *mut c_char,
+) -> SnarkvmStatus {
+    run(|| {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(read_str(private_key, "private key")?)
+            .with_status(SnarkvmStatus::InvalidPrivateKey)?;
+        let derived = Address::try_from(&private_key).with_status(SnarkvmStatus::InvalidPrivateKey)?;
+        write_string(address, derived.to_string())
+    })
+}
+
+/// Derives the address of the given view key, and writes it to `address`.
+///
+/// # Safety
+/// `view_key` must be a NUL-terminated string, and `address` must be valid for writes.
+/// The output must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_view_key_to_address(
+    view_key: *const c_char,
+    address: *mut *mut c_char,
+) -> SnarkvmStatus {
+    run(|| {
+        let view_key = ViewKey::<CurrentNetwork>::from_str(read_str(view_key, "view key")?)
+            .with_status(SnarkvmStatus::InvalidViewKey)?;
+        let derived = Address::try_from(&view_key).with_status(SnarkvmStatus::InvalidViewKey)?;
+        write_string(address, derived.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{snarkvm_last_error_message, snarkvm_string_free};
+
+    use std::ffi::{CStr, CString};
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    /// Calls the given C ABI function with the given input, and returns its status and output.
+    fn call(
+        function: unsafe extern "C" fn(*const c_char, *mut *mut c_char) -> SnarkvmStatus,
+        input: &str,
+    ) -> (SnarkvmStatus, Option<String>) {
+        let input = CString::new(input).unwrap();
+        let mut output = std::ptr::null_mut();
+        let status = unsafe { function(input.as_ptr(), &mut output) };
+        if output.is_null() {
+            return (status, None);
+        }
+        let string = unsafe { CStr::from_ptr(output) }.to_str().unwrap().to_string();
+        unsafe { snarkvm_string_free(output) };
+        (status, Some(string))
+    }
+
+    #[test]
+    fn test_account() {
+        assert_eq!(
+            call(snarkvm_private_key_to_view_key, ALEO_PRIVATE_KEY),
+            (SnarkvmStatus::Ok, Some(ALEO_VIEW_KEY.to_string()))
+        );
+        assert_eq!(
+            call(snarkvm_private_key_to_address, ALEO_PRIVATE_KEY),
+            (SnarkvmStatus::Ok, Some(ALEO_ADDRESS.to_string()))
+        );
+        assert_eq!(
+            call(snarkvm_view_key_to_address, ALEO_VIEW_KEY),
+            (SnarkvmStatus::Ok, Some(ALEO_ADDRESS.to_string()))
+        );
+        assert!(snarkvm_last_error_message().is_null());
+
+        // Ensure a new private key is valid.
+        let mut private_key = std::ptr::null_mut();
+        assert_eq!(unsafe { snarkvm_private_key_new(&mut private_key) }, SnarkvmStatus::Ok);
+        let string = unsafe { CStr::from_ptr(private_key) }.to_str().unwrap();
+        assert!(PrivateKey::<CurrentNetwork>::from_str(string).is_ok());
+        unsafe { snarkvm_string_free(private_key) };
+    }
+
+    #[test]
+    fn test_account_errors() {
+        assert_eq!(call(snarkvm_private_key_to_address, ALEO_VIEW_KEY), (SnarkvmStatus::InvalidPrivateKey, None));
+        assert!(!snarkvm_last_error_message().is_null());
+        assert_eq!(call(snarkvm_view_key_to_address, ALEO_PRIVATE_KEY), (SnarkvmStatus::InvalidViewKey, None));
+
+        // Ensure null pointers are rejected.
+        let mut output = std::ptr::null_mut();
+        let status = unsafe { snarkvm_private_key_to_address(std::ptr::null(), &mut output) };
+        assert_eq!(status, SnarkvmStatus::NullPointer);
+        assert!(output.is_null());
+        let input = CString::new(ALEO_PRIVATE_KEY).unwrap();
+        let status = unsafe { snarkvm_private_key_to_address(input.as_ptr(), std::ptr::null_mut()) };
+        assert_eq!(status, SnarkvmStatus::NullPointer);
+    }
+}
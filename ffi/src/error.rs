@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CString},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+/// The status code returned by every C ABI function.
+///
+/// The codes are stable: a code is never renumbered or reused, and new codes are only appended.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnarkvmStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The private key is malformed.
+    InvalidPrivateKey = 3,
+    /// The view key is malformed.
+    InvalidViewKey = 4,
+    /// The record is malformed.
+    InvalidRecord = 5,
+    /// The record is not owned by the view key.
+    RecordNotOwned = 6,
+    /// The program is malformed, or could not be loaded.
+    InvalidProgram = 7,
+    /// The transaction is malformed.
+    InvalidTransaction = 8,
+    /// The transaction failed verification.
+    VerificationFailed = 9,
+    /// The operation is not supported through the C ABI.
+    Unsupported = 10,
+    /// An internal error occurred.
+    Internal = 11,
+    /// The call panicked.
+    Panic = 12,
+}
+
+/// An error raised by a C ABI function, with the status code returned to the caller.
+#[derive(Clone, Debug)]
+pub(crate) struct FfiError {
+    /// The status code.
+    status: SnarkvmStatus,
+    /// The error message.
+    message: String,
+}
+
+impl FfiError {
+    /// Initializes a new error with the given status code and message.
+    pub(crate) fn new(status: SnarkvmStatus, message: impl Display) -> Self {
+        Self { status, message: message.to_string() }
+    }
+}
+
+/// A trait to attach a status code to an error.
+pub(crate) trait WithStatus<T> {
+    /// Returns the result, or an error with the given status code.
+    fn with_status(self, status: SnarkvmStatus) -> Result<T, FfiError>;
+}
+
+impl<T, E: Display> WithStatus<T> for Result<T, E> {
+    fn with_status(self, status: SnarkvmStatus) -> Result<T, FfiError> {
+        self.map_err(|error| FfiError::new(status, error))
+    }
+}
+
+thread_local! {
+    /// The message of the last error raised on this thread.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Records the given message as the last error on this thread.
+fn set_last_error(message: String) {
+    // Note: Interior null bytes are stripped, as they cannot be represented in a C string.
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+/// Runs the given C ABI function body, and returns its status code.
+/// If the body fails or panics, the error message is recorded as the last error on this thread.
+pub(crate) fn run(body: impl FnOnce() -> Result<(), FfiError>) -> SnarkvmStatus {
+    match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => {
+            LAST_ERROR.with(|last_error| *last_error.borrow_mut() = None);
+            SnarkvmStatus::Ok
+        }
+        Ok(Err(error)) => {
+            set_last_error(error.message);
+            error.status
+        }
+        Err(payload) => {
+            let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+                (Some(message), _) => message.to_string(),
+                (_, Some(message)) => message.clone(),
+                _ => "snarkVM panicked".to_string(),
+            };
+            set_last_error(message);
+            SnarkvmStatus::Panic
+        }
+    }
+}
+
+/// Returns the message of the last error raised on the calling thread, or null if the last call succeeded.
+///
+/// The message is owned by snarkVM, and remains valid until the next call on the same thread.
+#[no_mangle]
+pub extern "C" fn snarkvm_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| last_error.borrow().as_ref().map_or(ptr::null(), |message| message.as_ptr()))
+}
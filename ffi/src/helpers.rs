@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FfiError, SnarkvmStatus};
+
+use std::ffi::{c_char, CStr, CString};
+
+/// Returns the string behind the given C string pointer.
+///
+/// # Safety
+/// The pointer must be null, or point to a NUL-terminated string that outlives the call.
+pub(crate) unsafe fn read_str<'a>(pointer: *const c_char, name: &str) -> Result<&'a str, FfiError> {
+    if pointer.is_null() {
+        return Err(FfiError::new(SnarkvmStatus::NullPointer, format!("The {name} pointer is null")));
+    }
+    CStr::from_ptr(pointer)
+        .to_str()
+        .map_err(|_| FfiError::new(SnarkvmStatus::InvalidUtf8, format!("The {name} is not valid UTF-8")))
+}
+
+/// Writes the given string to the given output pointer, as a C string owned by the caller.
+/// The caller must release it with `snarkvm_string_free`.
+///
+/// # Safety
+/// The pointer must be null, or be valid for writes.
+pub(crate) unsafe fn write_string(output: *mut *mut c_char, string: String) -> Result<(), FfiError> {
+    if output.is_null() {
+        return Err(FfiError::new(SnarkvmStatus::NullPointer, "The output pointer is null"));
+    }
+    let string = CString::new(string).map_err(|error| FfiError::new(SnarkvmStatus::Internal, error))?;
+    *output = string.into_raw();
+    Ok(())
+}
+
+/// Releases a string returned by snarkVM.
+///
+/// # Safety
+/// The string must be null, or have been returned by snarkVM and not already released.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
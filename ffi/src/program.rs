@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{read_str, run, write_string, CurrentNetwork, SnarkvmStatus, WithStatus};
+use synthesizer_program::Program;
+
+use std::{ffi::c_char, str::FromStr};
+
+/// Parses the given program source, and writes the program ID to `program_id`.
+///
+/// # Safety
+/// `program` must be a NUL-terminated string, and `program_id` must be valid for writes.
+/// The output must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_program_parse(program: *const c_char, program_id: *mut *mut c_char) -> SnarkvmStatus {
+    run(|| {
+        let program = Program::<CurrentNetwork>::from_str(read_str(program, "program")?)
+            .with_status(SnarkvmStatus::InvalidProgram)?;
+        write_string(program_id, program.id().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snarkvm_string_free;
+
+    use std::ffi::{CStr, CString};
+
+    #[test]
+    fn test_program_parse() {
+        let program = CString::new(
+            r"
+program token.aleo;
+
+function transfer:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+        let mut program_id = std::ptr::null_mut();
+        assert_eq!(unsafe { snarkvm_program_parse(program.as_ptr(), &mut program_id) }, SnarkvmStatus::Ok);
+        assert_eq!(unsafe { CStr::from_ptr(program_id) }.to_str().unwrap(), "token.aleo");
+        unsafe { snarkvm_string_free(program_id) };
+
+        // Ensure a malformed program is rejected.
+        let program = CString::new("program token.aleo; function").unwrap();
+        let mut program_id = std::ptr::null_mut();
+        assert_eq!(unsafe { snarkvm_program_parse(program.as_ptr(), &mut program_id) }, SnarkvmStatus::InvalidProgram);
+        assert!(program_id.is_null());
+    }
+}
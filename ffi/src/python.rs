@@ -0,0 +1,26 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::prelude::*;
+
+/// The `snarkvm` Python module, with the account, value, program, and transaction classes.
+#[pymodule]
+#[pyo3(name = "snarkvm")]
+fn python_module(_py: Python<'_>, module: &PyModule) -> PyResult<()> {
+    console::account::python::register(module)?;
+    console::program::python::register(module)?;
+    synthesizer_program::python::register(module)?;
+    ledger_block::python::register(module)?;
+    Ok(())
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{read_str, run, write_string, CurrentNetwork, FfiError, SnarkvmStatus, WithStatus};
+use console::{
+    account::ViewKey,
+    program::{Ciphertext, Record},
+};
+
+use std::{ffi::c_char, str::FromStr};
+
+/// Decrypts the given record ciphertext with the given view key, and writes the record plaintext to `plaintext`.
+///
+/// Returns `RecordNotOwned` if the record does not belong to the view key.
+///
+/// # Safety
+/// `view_key` and `ciphertext` must be NUL-terminated strings, and `plaintext` must be valid for writes.
+/// The output must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_record_decrypt(
+    view_key: *const c_char,
+    ciphertext: *const c_char,
+    plaintext: *mut *mut c_char,
+) -> SnarkvmStatus {
+    run(|| {
+        let view_key = ViewKey::<CurrentNetwork>::from_str(read_str(view_key, "view key")?)
+            .with_status(SnarkvmStatus::InvalidViewKey)?;
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(read_str(ciphertext, "record")?)
+            .with_status(SnarkvmStatus::InvalidRecord)?;
+        if !record.is_owner(&view_key) {
+            return Err(FfiError::new(SnarkvmStatus::RecordNotOwned, "The record is not owned by the view key"));
+        }
+        let decrypted = record.decrypt(&view_key).with_status(SnarkvmStatus::InvalidRecord)?;
+        write_string(plaintext, decrypted.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snarkvm_string_free;
+    use console::{
+        account::{Address, PrivateKey},
+        network::{prelude::*, Network},
+        program::Plaintext,
+        types::Scalar,
+    };
+
+    use std::ffi::{CStr, CString};
+
+    /// Decrypts the given record ciphertext through the C ABI.
+    fn decrypt(view_key: &ViewKey<CurrentNetwork>, ciphertext: &str) -> (SnarkvmStatus, Option<String>) {
+        let view_key = CString::new(view_key.to_string()).unwrap();
+        let ciphertext = CString::new(ciphertext).unwrap();
+        let mut output = std::ptr::null_mut();
+        let status = unsafe { snarkvm_record_decrypt(view_key.as_ptr(), ciphertext.as_ptr(), &mut output) };
+        if output.is_null() {
+            return (status, None);
+        }
+        let string = unsafe { CStr::from_ptr(output) }.to_str().unwrap().to_string();
+        unsafe { snarkvm_string_free(output) };
+        (status, Some(string))
+    }
+
+    #[test]
+    fn test_record_decrypt() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Prepare the record.
+        let randomizer = Scalar::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, microcredits: 1500000u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap().to_string();
+
+        // Ensure the owner can decrypt the record.
+        assert_eq!(decrypt(&view_key, &ciphertext), (SnarkvmStatus::Ok, Some(record.to_string())));
+
+        // Ensure another account cannot decrypt the record.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert_eq!(decrypt(&other_view_key, &ciphertext), (SnarkvmStatus::RecordNotOwned, None));
+
+        // Ensure a malformed record is rejected.
+        assert_eq!(decrypt(&view_key, "record1"), (SnarkvmStatus::InvalidRecord, None));
+    }
+}
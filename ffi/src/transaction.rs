@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{read_str, run, CurrentAleo, CurrentNetwork, FfiError, SnarkvmStatus, WithStatus};
+use console::network::prelude::*;
+use ledger_block::Transaction;
+use synthesizer_process::Process;
+use synthesizer_program::Program;
+
+use once_cell::sync::OnceCell;
+use parking_lot::RwLock;
+use std::ffi::c_char;
+
+/// The process used to verify transactions, which contains 'credits.aleo' and every program loaded by the caller.
+static PROCESS: OnceCell<RwLock<Process<CurrentNetwork>>> = OnceCell::new();
+
+/// Returns the process used to verify transactions, loading it on first use.
+fn process() -> Result<&'static RwLock<Process<CurrentNetwork>>, FfiError> {
+    PROCESS.get_or_try_init(|| Process::load().map(RwLock::new)).with_status(SnarkvmStatus::Internal)
+}
+
+/// Loads the given program into the process used to verify transactions.
+///
+/// A transaction that deploys or executes a program can only be verified once the program, and its imports, are loaded.
+/// Loading a program that is already loaded is a no-op.
+///
+/// # Safety
+/// `program` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_program_load(program: *const c_char) -> SnarkvmStatus {
+    run(|| {
+        let program = Program::<CurrentNetwork>::from_str(read_str(program, "program")?)
+            .with_status(SnarkvmStatus::InvalidProgram)?;
+        let mut process = process()?.write();
+        if !process.contains_program(program.id()) {
+            process.add_program(&program).with_status(SnarkvmStatus::InvalidProgram)?;
+        }
+        Ok(())
+    })
+}
+
+/// Verifies the given transaction, in JSON format.
+///
+/// This checks the transaction ID, the deployment or execution proofs, and the fee proof. It does not check the
+/// transaction against the ledger, i.e. that its state root exists, its inputs are unspent, or its fee is sufficient.
+///
+/// Returns `Ok` if the transaction is valid, `VerificationFailed` if it is invalid, and `Unsupported` for fee
+/// transactions, which can only be verified with the ID of the rejected transaction.
+///
+/// # Safety
+/// `transaction` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_transaction_verify(transaction: *const c_char) -> SnarkvmStatus {
+    run(|| {
+        let transaction = Transaction::<CurrentNetwork>::from_str(read_str(transaction, "transaction")?)
+            .with_status(SnarkvmStatus::InvalidTransaction)?;
+        let process = process()?.read();
+        verify_transaction(&process, &transaction, &mut rand::thread_rng())
+    })
+}
+
+/// Verifies the given transaction against the given process.
+fn verify_transaction<R: Rng + CryptoRng>(
+    process: &Process<CurrentNetwork>,
+    transaction: &Transaction<CurrentNetwork>,
+    rng: &mut R,
+) -> Result<(), FfiError> {
+    // Ensure the transaction ID is correct.
+    let root = transaction.to_root().with_status(SnarkvmStatus::VerificationFailed)?;
+    if *transaction.id() != root {
+        return Err(FfiError::new(SnarkvmStatus::VerificationFailed, "Incorrect transaction ID"));
+    }
+
+    match transaction {
+        Transaction::Deploy(_, owner, deployment, fee) => {
+            let deployment_id = deployment.to_deployment_id().with_status(SnarkvmStatus::VerificationFailed)?;
+            if !owner.verify(deployment_id) {
+                return Err(FfiError::new(SnarkvmStatus::VerificationFailed, "Invalid deployment owner signature"));
+            }
+            process
+                .verify_deployment::<CurrentAleo, _>(deployment, rng)
+                .with_status(SnarkvmStatus::VerificationFailed)?;
+            process.verify_fee(fee, deployment_id).with_status(SnarkvmStatus::VerificationFailed)
+        }
+        Transaction::Execute(_, execution, fee) => {
+            process.verify_execution(execution).with_status(SnarkvmStatus::VerificationFailed)?;
+            match fee {
+                Some(fee) => {
+                    let execution_id = execution.to_execution_id().with_status(SnarkvmStatus::VerificationFailed)?;
+                    process.verify_fee(fee, execution_id).with_status(SnarkvmStatus::VerificationFailed)
+                }
+                None => Ok(()),
+            }
+        }
+        Transaction::Fee(..) => Err(FfiError::new(
+            SnarkvmStatus::Unsupported,
+            "Fee transactions require the ID of the rejected transaction",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::CString;
+
+    /// Verifies the given transaction through the C ABI.
+    fn verify(transaction: &str) -> SnarkvmStatus {
+        let transaction = CString::new(transaction).unwrap();
+        unsafe { snarkvm_transaction_verify(transaction.as_ptr()) }
+    }
+
+    #[test]
+    fn test_transaction_verify() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a valid execution is accepted.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        assert_eq!(verify(&transaction.to_string()), SnarkvmStatus::Ok);
+
+        // Ensure a fee transaction is reported as unsupported.
+        let transaction = ledger_test_helpers::sample_fee_private_transaction(rng);
+        assert_eq!(verify(&transaction.to_string()), SnarkvmStatus::Unsupported);
+
+        // Ensure a malformed transaction is rejected.
+        assert_eq!(verify("{}"), SnarkvmStatus::InvalidTransaction);
+    }
+}
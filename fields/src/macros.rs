@@ -92,6 +92,10 @@ macro_rules! sqrt_impl {
                 let n = $P::TWO_ADICITY as u64;
                 // `T` is equivalent to `m` in the paper.
                 let v = $self.pow($P::T_MINUS_ONE_DIV_TWO);
+                // If the two-adicity is 1, then `self * v = self^((MODULUS + 1) / 4)` is the square root.
+                if n == 1 {
+                    return Some(*$self * v);
+                }
                 let x = *$self * v.square();
 
                 let k = ((n - 1) as f64).sqrt().floor() as u64;
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Block<N> {
+    /// Returns an arbitrary beacon block, with a valid header and signature.
+    /// Note: The block is well-formed, but its transactions are **not** proven, and it does not extend a ledger.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        // Sample the transactions, ensuring there is at least one.
+        let mut transactions = Vec::new();
+        loop {
+            let index = u32::try_from(transactions.len()).map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+            let transaction = Transaction::arbitrary(u)?;
+            // Note: Fee transactions are confirmed as rejected executions.
+            let confirmed = match transaction.is_execute() {
+                true => ConfirmedTransaction::accepted_execute(index, transaction, vec![]),
+                false => {
+                    let rejected = Rejected::new_execution(Execution::arbitrary(u)?);
+                    ConfirmedTransaction::rejected_execute(index, transaction, rejected, vec![])
+                }
+            };
+            transactions.push(confirmed.map_err(|_| ::arbitrary::Error::IncorrectFormat)?);
+            if transactions.len() >= Transactions::<N>::MAX_TRANSACTIONS || u.is_empty() || !u.arbitrary::<bool>()? {
+                break;
+            }
+        }
+        let transactions = transactions.into_iter().collect::<Transactions<N>>();
+
+        // Sample the ratifications.
+        let ratifications =
+            Ratifications::try_from(vec![Ratify::BlockReward(u.arbitrary()?), Ratify::PuzzleReward(u.arbitrary()?)])
+                .map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+
+        // Sample the metadata, within the bounds of a valid (non-genesis) block.
+        let height = u.int_in_range(1..=u32::MAX)?;
+        let round = u.int_in_range(height as u64..=u64::MAX)?;
+        let proof_target = u.int_in_range(N::GENESIS_PROOF_TARGET..=u64::MAX - 1)?;
+        let coinbase_target = u.int_in_range(N::GENESIS_COINBASE_TARGET.max(proof_target + 1)..=u64::MAX)?;
+        let metadata = Metadata::new(
+            N::ID,
+            round,
+            height,
+            u.arbitrary()?,
+            u.arbitrary()?,
+            coinbase_target,
+            proof_target,
+            u.int_in_range(N::GENESIS_COINBASE_TARGET..=u64::MAX)?,
+            u.int_in_range(N::GENESIS_TIMESTAMP..=i64::MAX)?,
+            u.int_in_range(N::GENESIS_TIMESTAMP + 1..=i64::MAX)?,
+        )
+        .map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+
+        // Sample the aborted transaction IDs.
+        let rng = &mut TestRng::from_seed(u.arbitrary()?);
+        let mut aborted_transaction_ids = Vec::new();
+        while aborted_transaction_ids.len() < Transactions::<N>::MAX_TRANSACTIONS && u.arbitrary::<bool>()? {
+            aborted_transaction_ids.push(N::TransactionID::rand(rng));
+        }
+
+        // Construct the header.
+        let transactions_root = transactions.to_transactions_root().map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+        let ratifications_root =
+            ratifications.to_ratifications_root().map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+        let header = Header::from(
+            N::StateRoot::rand(rng),
+            transactions_root,
+            Field::rand(rng),
+            ratifications_root,
+            Field::zero(),
+            Field::zero(),
+            metadata,
+        )
+        .map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+
+        // Sign the block with a sampled beacon.
+        let private_key = PrivateKey::new(rng).map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+        let previous_hash = N::BlockHash::rand(rng);
+        Self::new_beacon(
+            &private_key,
+            previous_hash,
+            header,
+            ratifications,
+            None,
+            transactions,
+            aborted_transaction_ids,
+            rng,
+        )
+        .map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+}
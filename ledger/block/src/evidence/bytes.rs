@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Evidence<N> {
+    /// Reads the evidence from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid evidence version"));
+        }
+
+        let variant = Variant::read_le(&mut reader)?;
+        let evidence = match variant {
+            0 => {
+                // Read the first batch header.
+                let first: BatchHeader<N> = FromBytes::read_le(&mut reader)?;
+                // Read the second batch header.
+                let second: BatchHeader<N> = FromBytes::read_le(&mut reader)?;
+                // Return the evidence.
+                Self::new_equivocation(first, second).map_err(|e| error(e.to_string()))?
+            }
+            1.. => return Err(error(format!("Failed to decode evidence variant {variant}"))),
+        };
+        Ok(evidence)
+    }
+}
+
+impl<N: Network> ToBytes for Evidence<N> {
+    /// Writes the evidence to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        match self {
+            Self::Equivocation(first, second) => {
+                (0 as Variant).write_le(&mut writer)?;
+                first.write_le(&mut writer)?;
+                second.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..5 {
+            let expected = crate::evidence::test_helpers::sample_equivocation(rng);
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Evidence::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{network::prelude::*, types::Address};
+use ledger_committee::Committee;
+use ledger_narwhal_batch_header::BatchHeader;
+
+type Variant = u8;
+
+/// Evidence of validator misbehavior, which may be submitted in a block as `Ratify::Evidence`.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Evidence<N: Network> {
+    /// An equivocation, as two distinct batch headers signed by the same author in the same round.
+    Equivocation(BatchHeader<N>, BatchHeader<N>),
+}
+
+impl<N: Network> Evidence<N> {
+    /// Initializes a new equivocation from the given conflicting batch headers.
+    /// The batch headers are stored in canonical order (i.e. by ascending batch ID).
+    pub fn new_equivocation(first: BatchHeader<N>, second: BatchHeader<N>) -> Result<Self> {
+        // Ensure the batch headers are from the same author.
+        ensure!(first.author() == second.author(), "Equivocation requires batch headers from the same author");
+        // Ensure the batch headers are for the same round.
+        ensure!(first.round() == second.round(), "Equivocation requires batch headers from the same round");
+        // Ensure the batch headers are distinct.
+        ensure!(first.batch_id() != second.batch_id(), "Equivocation requires distinct batch headers");
+        // Order the batch headers canonically.
+        match first.batch_id() < second.batch_id() {
+            true => Ok(Self::Equivocation(first, second)),
+            false => Ok(Self::Equivocation(second, first)),
+        }
+    }
+
+    /// Returns the address of the validator that misbehaved.
+    pub fn author(&self) -> Address<N> {
+        match self {
+            Self::Equivocation(first, _) => first.author(),
+        }
+    }
+
+    /// Returns the round in which the misbehavior occurred.
+    pub fn round(&self) -> u64 {
+        match self {
+            Self::Equivocation(first, _) => first.round(),
+        }
+    }
+
+    /// Checks that the evidence is well-formed, and that the author was a member of the given `committee`,
+    /// which must be the committee for the round of the evidence.
+    ///
+    /// Note: The signature of each batch header is checked when the batch header is constructed or deserialized.
+    pub fn verify(&self, committee: &Committee<N>) -> Result<()> {
+        match self {
+            Self::Equivocation(first, second) => {
+                // Ensure the batch headers are from the same author.
+                ensure!(first.author() == second.author(), "Equivocation has batch headers from different authors");
+                // Ensure the batch headers are for the same round.
+                ensure!(first.round() == second.round(), "Equivocation has batch headers from different rounds");
+                // Ensure the batch headers are distinct and in canonical order.
+                ensure!(first.batch_id() < second.batch_id(), "Equivocation has batch headers in non-canonical order");
+            }
+        }
+        // Ensure the committee is for the round of the evidence.
+        ensure!(
+            committee.starting_round() <= self.round(),
+            "Evidence from round {} cannot be checked against a committee starting at round {}",
+            self.round(),
+            committee.starting_round()
+        );
+        // Ensure the author was a committee member in the round of the evidence.
+        ensure!(
+            committee.is_committee_member(self.author()),
+            "Evidence author '{}' was not a committee member in round {}",
+            self.author(),
+            self.round()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3, types::Field};
+
+    use indexmap::IndexSet;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns an equivocation for the given private key and round.
+    pub(crate) fn sample_equivocation_for_round(
+        private_key: &PrivateKey<CurrentNetwork>,
+        round: u64,
+        rng: &mut TestRng,
+    ) -> Evidence<CurrentNetwork> {
+        // Sample the previous certificate IDs.
+        let previous_certificate_ids = match round {
+            0 | 1 => IndexSet::new(),
+            _ => (0..3).map(|_| Field::<CurrentNetwork>::rand(rng)).collect::<IndexSet<_>>(),
+        };
+        // Sample two batch headers with differing timestamps.
+        let mut sample = |timestamp| {
+            BatchHeader::new(
+                private_key,
+                round,
+                timestamp,
+                IndexSet::new(),
+                previous_certificate_ids.clone(),
+                IndexSet::new(),
+                rng,
+            )
+            .unwrap()
+        };
+        let (first, second) = (sample(0), sample(1));
+        // Return the equivocation.
+        Evidence::new_equivocation(first, second).unwrap()
+    }
+
+    /// Returns a sample equivocation, sampled at random.
+    pub(crate) fn sample_equivocation(rng: &mut TestRng) -> Evidence<CurrentNetwork> {
+        sample_equivocation_for_round(&PrivateKey::new(rng).unwrap(), rng.gen_range(1..100), rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new_equivocation() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let evidence = test_helpers::sample_equivocation_for_round(&private_key, 5, rng);
+        assert_eq!(evidence.author(), Address::try_from(&private_key).unwrap());
+        assert_eq!(evidence.round(), 5);
+
+        // Ensure the batch headers are ordered canonically.
+        let Evidence::Equivocation(first, second) = evidence.clone();
+        assert_eq!(evidence, Evidence::new_equivocation(second.clone(), first.clone()).unwrap());
+
+        // Ensure a batch header does not equivocate with itself.
+        assert!(Evidence::new_equivocation(first.clone(), first.clone()).is_err());
+        // Ensure batch headers from different rounds do not equivocate.
+        let Evidence::Equivocation(other, _) = test_helpers::sample_equivocation_for_round(&private_key, 6, rng);
+        assert!(Evidence::new_equivocation(first.clone(), other).is_err());
+        // Ensure batch headers from different authors do not equivocate.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let Evidence::Equivocation(other, _) = test_helpers::sample_equivocation_for_round(&other_private_key, 5, rng);
+        assert!(Evidence::new_equivocation(first, other).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for Evidence<N> {
+    /// Serializes the evidence into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => match self {
+                Self::Equivocation(first, second) => {
+                    let mut input = serializer.serialize_struct("Evidence", 3)?;
+                    input.serialize_field("type", "equivocation")?;
+                    input.serialize_field("first", &first)?;
+                    input.serialize_field("second", &second)?;
+                    input.end()
+                }
+            },
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Evidence<N> {
+    /// Deserializes the evidence from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the input from a string into a value.
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+
+                // Recover the evidence.
+                let evidence = match object.get("type").and_then(|t| t.as_str()) {
+                    Some("equivocation") => {
+                        // Retrieve the first batch header.
+                        let first: BatchHeader<N> = DeserializeExt::take_from_value::<D>(&mut object, "first")?;
+                        // Retrieve the second batch header.
+                        let second: BatchHeader<N> = DeserializeExt::take_from_value::<D>(&mut object, "second")?;
+                        // Construct the evidence.
+                        Evidence::new_equivocation(first, second).map_err(de::Error::custom)?
+                    }
+                    _ => return Err(de::Error::custom("Invalid evidence type")),
+                };
+                // Return the evidence.
+                Ok(evidence)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "evidence"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..5 {
+            let expected = crate::evidence::test_helpers::sample_equivocation(rng);
+
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+            assert_eq!(expected_string, &candidate_string);
+
+            // Deserialize
+            assert_eq!(expected, Evidence::from_str(expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(expected_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..5 {
+            let expected = crate::evidence::test_helpers::sample_equivocation(rng);
+
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, Evidence::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
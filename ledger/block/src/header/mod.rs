@@ -22,7 +22,7 @@ mod serialize;
 mod string;
 mod verify;
 
-use crate::{Ratifications, Transactions};
+use crate::{Ratifications, TargetState, Transactions};
 use console::{
     network::prelude::*,
     program::{HeaderLeaf, HeaderPath, HeaderTree, HEADER_DEPTH},
@@ -180,6 +180,18 @@ impl<N: Network> Header<N> {
     pub const fn timestamp(&self) -> i64 {
         self.metadata.timestamp()
     }
+
+    /// Returns the target state for this block.
+    pub const fn to_target_state(&self) -> TargetState {
+        TargetState {
+            cumulative_proof_target: self.cumulative_proof_target(),
+            coinbase_target: self.coinbase_target(),
+            proof_target: self.proof_target(),
+            last_coinbase_target: self.last_coinbase_target(),
+            last_coinbase_timestamp: self.last_coinbase_timestamp(),
+            timestamp: self.timestamp(),
+        }
+    }
 }
 
 #[cfg(test)]
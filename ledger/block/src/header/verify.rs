@@ -96,3 +96,60 @@ impl<N: Network> Header<N> {
         )
     }
 }
+
+impl<N: Network> Header<N> {
+    /// Ensures the block header is consistent with the given previous block header.
+    ///
+    /// This is a partial check that only requires the headers, and does not require the transactions,
+    /// solutions, or ratifications of the block. It enables light clients and sync pipelines to validate
+    /// a chain of headers before the block bodies are downloaded.
+    ///
+    /// Note: The roots of the header are not checked, as they depend on the block body and ledger state.
+    pub fn verify_against(&self, previous_header: &Header<N>, current_timestamp: i64) -> Result<()> {
+        // Determine the expected height.
+        let expected_height = previous_header.height().saturating_add(1);
+
+        // Ensure the block header is well-formed.
+        ensure!(self.is_valid(), "Header is malformed in block {expected_height}");
+        // Ensure the height is correct.
+        ensure!(
+            self.height() == expected_height,
+            "Height is incorrect in block {expected_height} (found '{}', expected '{expected_height}')",
+            self.height()
+        );
+        // Ensure the round is after the previous round.
+        ensure!(
+            self.round() > previous_header.round(),
+            "Round is not after the previous round in block {expected_height} (found '{}', expected after '{}')",
+            self.round(),
+            previous_header.round()
+        );
+
+        // Ensure the cumulative weight is non-decreasing.
+        ensure!(
+            self.cumulative_weight() >= previous_header.cumulative_weight(),
+            "Cumulative weight is decreasing in block {expected_height} (found '{}', expected at least '{}')",
+            self.cumulative_weight(),
+            previous_header.cumulative_weight()
+        );
+        // Compute the combined proof target of the block solutions.
+        let combined_proof_target = self.cumulative_weight() - previous_header.cumulative_weight();
+
+        // Compute the expected targets.
+        let expected = previous_header.to_target_state().next::<N>(self.timestamp(), combined_proof_target)?;
+
+        // Ensure the block metadata is correct.
+        self.metadata.verify(
+            self.round(),
+            expected_height,
+            self.cumulative_weight(),
+            expected.cumulative_proof_target,
+            expected.coinbase_target,
+            expected.proof_target,
+            expected.last_coinbase_target,
+            expected.last_coinbase_timestamp,
+            self.timestamp(),
+            current_timestamp,
+        )
+    }
+}
@@ -12,96 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use console::prelude::{ensure, Result};
-
-/// A safety bound (sanity-check) for the coinbase reward.
-pub const MAX_COINBASE_REWARD: u64 = 190_258_739; // Coinbase reward at block 1.
-
-/// Calculate the block reward, given the total supply, block time, coinbase reward, and transaction fees.
-///     R_staking = floor((0.05 * S) / H_Y1) + CR / 2 + TX_F.
-///     S = Total supply.
-///     H_Y1 = Expected block height at year 1.
-///     CR = Coinbase reward.
-///     TX_F = Transaction fees.
-pub const fn block_reward(total_supply: u64, block_time: u16, coinbase_reward: u64, transaction_fees: u64) -> u64 {
-    // Compute the expected block height at year 1.
-    let block_height_at_year_1 = block_height_at_year(block_time, 1);
-    // Compute the annual reward: (0.05 * S).
-    let annual_reward = total_supply / 20;
-    // Compute the block reward: (0.05 * S) / H_Y1.
-    let block_reward = annual_reward / block_height_at_year_1 as u64;
-    // Return the sum of the block reward, coinbase reward, and transaction fees.
-    block_reward + (coinbase_reward / 2) + transaction_fees
-}
-
-/// Calculate the puzzle reward, given the coinbase reward.
-pub const fn puzzle_reward(coinbase_reward: u64) -> u64 {
-    // Return the coinbase reward divided by 2.
-    coinbase_reward / 2
-}
-
-/// Calculates the coinbase reward for a given block.
-///     R_coinbase = max(0, H_Y10 - H) * R_anchor * min(P, C_R) / C
-///     R_anchor = Anchor reward.
-///     H_Y10 = Anchor block height at year 10.
-///     H = Current block height.
-///     P = Combined proof target.
-///     C_R = Remaining coinbase target.
-///     C = Coinbase target.
-pub fn coinbase_reward(
-    block_height: u32,
-    starting_supply: u64,
-    anchor_height: u32,
-    block_time: u16,
-    combined_proof_target: u128,
-    cumulative_proof_target: u64,
-    coinbase_target: u64,
-) -> Result<u64> {
-    // Compute the remaining coinbase target.
-    let remaining_coinbase_target = coinbase_target.saturating_sub(cumulative_proof_target);
-    // Compute the remaining proof target.
-    let remaining_proof_target = combined_proof_target.min(remaining_coinbase_target as u128);
-
-    /* Until the anchor block height at year 10, the coinbase reward is determined by this equation: */
-    /*   anchor_block_reward * remaining_proof_target / coinbase_target */
-
-    // Compute the anchor block reward.
-    let anchor_block_reward = anchor_block_reward_at_height(block_height, starting_supply, anchor_height, block_time);
-
-    // Calculate the coinbase reward.
-    let reward = anchor_block_reward.saturating_mul(remaining_proof_target).saturating_div(coinbase_target as u128);
-
-    // Ensure the coinbase reward is less than the maximum coinbase reward.
-    ensure!(reward <= MAX_COINBASE_REWARD as u128, "Coinbase reward ({reward}) exceeds maximum {MAX_COINBASE_REWARD}");
-
-    // Return the coinbase reward.
-    // Note: This '.expect' is guaranteed to be safe, as we ensure the reward is within a safe bound.
-    Ok(u64::try_from(reward).expect("Coinbase reward exceeds u64::MAX"))
-}
-
-/// Calculates the anchor block reward for the given block height.
-///     R_anchor = floor((2 * S * H_A * H_R) / (H_Y10 * (H_Y10 + 1))).
-///     S = Starting supply.
-///     H_A = Anchor block height.
-///     H_R = Remaining number of blocks until year 10.
-///     H_Y10 = Expected block height at year 10.
-const fn anchor_block_reward_at_height(
-    block_height: u32,
-    starting_supply: u64,
-    anchor_height: u32,
-    block_time: u16,
-) -> u128 {
-    // Calculate the block height at year 10.
-    let block_height_at_year_10 = block_height_at_year(block_time, 10) as u128;
-    // Compute the remaining blocks until year 10, as a u64.
-    let num_remaining_blocks_to_year_10 = block_height_at_year_10.saturating_sub(block_height as u128);
-    // Compute the numerator.
-    let numerator = 2 * starting_supply as u128 * anchor_height as u128 * num_remaining_blocks_to_year_10;
-    // Compute the denominator.
-    let denominator = block_height_at_year_10 * (block_height_at_year_10 + 1);
-    // Return the anchor block reward.
-    numerator / denominator
-}
+use console::{
+    network::Network,
+    prelude::{ensure, Result},
+};
 
 /// Returns the block height after a given number of years for a specific block time.
 pub const fn block_height_at_year(block_time: u16, num_years: u32) -> u32 {
@@ -136,6 +50,94 @@ pub fn proof_target(coinbase_target: u64, genesis_proof_target: u64) -> u64 {
     coinbase_target.checked_shr(7).map(|target| target.saturating_add(1)).unwrap_or(genesis_proof_target)
 }
 
+/// The coinbase and proof targets of a block, with the state needed to compute the targets of the next block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TargetState {
+    /// The cumulative proof target since the last coinbase.
+    pub cumulative_proof_target: u128,
+    /// The coinbase target.
+    pub coinbase_target: u64,
+    /// The proof target.
+    pub proof_target: u64,
+    /// The coinbase target of the last coinbase.
+    pub last_coinbase_target: u64,
+    /// The Unix timestamp (UTC) of the last coinbase.
+    pub last_coinbase_timestamp: i64,
+    /// The Unix timestamp (UTC) of the block.
+    pub timestamp: i64,
+}
+
+impl TargetState {
+    /// Returns the target state of the genesis block.
+    pub const fn genesis<N: Network>() -> Self {
+        Self {
+            cumulative_proof_target: 0,
+            coinbase_target: N::GENESIS_COINBASE_TARGET,
+            proof_target: N::GENESIS_PROOF_TARGET,
+            last_coinbase_target: N::GENESIS_COINBASE_TARGET,
+            last_coinbase_timestamp: N::GENESIS_TIMESTAMP,
+            timestamp: N::GENESIS_TIMESTAMP,
+        }
+    }
+
+    /// Returns the target state of the next block, given its timestamp and the combined proof target of its solutions.
+    pub fn next<N: Network>(&self, timestamp: i64, combined_proof_target: u128) -> Result<Self> {
+        // Compute the coinbase target.
+        let coinbase_target = coinbase_target(
+            self.last_coinbase_target,
+            self.last_coinbase_timestamp,
+            timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        // Compute the proof target.
+        let proof_target = proof_target(coinbase_target, N::GENESIS_PROOF_TARGET);
+
+        // Compute the actual cumulative proof target (which can exceed the coinbase target).
+        let cumulative_proof_target = self.cumulative_proof_target.saturating_add(combined_proof_target);
+        // Determine if the coinbase target is reached.
+        // Note: The coinbase target can only be reached if the block contains solutions.
+        let is_coinbase_target_reached =
+            combined_proof_target > 0 && cumulative_proof_target >= self.coinbase_target as u128;
+
+        // Determine the cumulative proof target, last coinbase target, and last coinbase timestamp.
+        let (cumulative_proof_target, last_coinbase_target, last_coinbase_timestamp) = match is_coinbase_target_reached
+        {
+            true => (0u128, coinbase_target, timestamp),
+            false => (cumulative_proof_target, self.last_coinbase_target, self.last_coinbase_timestamp),
+        };
+
+        Ok(Self {
+            cumulative_proof_target,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        })
+    }
+}
+
+/// Simulates the targets of the blocks after genesis, where the network produces blocks every `N::BLOCK_TIME` seconds,
+/// and each rate in `solution_rates` is the combined proof target of the solutions found per second,
+/// held for `blocks` blocks.
+///
+/// Note: The simulation does not cap the number of solutions per block, or drop solutions below the proof target.
+pub fn simulate_targets<N: Network>(solution_rates: &[u128], blocks: u32) -> Result<Vec<TargetState>> {
+    let mut state = TargetState::genesis::<N>();
+    let mut states = Vec::with_capacity(solution_rates.len().saturating_mul(blocks as usize));
+    for solution_rate in solution_rates {
+        // Compute the combined proof target of each block.
+        let combined_proof_target = solution_rate.saturating_mul(N::BLOCK_TIME as u128);
+        for _ in 0..blocks {
+            state = state.next::<N>(state.timestamp.saturating_add(N::BLOCK_TIME as i64), combined_proof_target)?;
+            states.push(state);
+        }
+    }
+    Ok(states)
+}
+
 /// Retarget algorithm using fixed point arithmetic from https://www.reference.cash/protocol/forks/2020-11-15-asert.
 ///     T_{i+1} = T_i * 2^(INV * (D - A) / TAU).
 ///     T_i = Current target.
@@ -230,311 +232,6 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
-    const ITERATIONS: u32 = 1000;
-
-    const EXPECTED_ANCHOR_BLOCK_REWARD_AT_BLOCK_1: u128 = MAX_COINBASE_REWARD as u128;
-    const EXPECTED_STAKING_REWARD: u64 = 23_782_343;
-    const EXPECTED_COINBASE_REWARD_AT_BLOCK_1: u64 = MAX_COINBASE_REWARD;
-
-    #[test]
-    fn test_anchor_block_reward() {
-        let reward = anchor_block_reward_at_height(
-            1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-        );
-        assert_eq!(reward, EXPECTED_ANCHOR_BLOCK_REWARD_AT_BLOCK_1);
-
-        // Calculate the block height at year 10.
-        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
-
-        // Ensure that the reward is decreasing for blocks before year 10.
-        let mut previous_reward = reward;
-        let anchor_height = CurrentNetwork::ANCHOR_HEIGHT as usize;
-        for height in (2..block_height_at_year_10).step_by(anchor_height).skip(1) {
-            let reward = anchor_block_reward_at_height(
-                height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
-            );
-            assert!(reward < previous_reward, "Failed on block height {height}");
-            previous_reward = reward;
-        }
-
-        // Ensure that the reward is zero for blocks after year 10.
-        for height in block_height_at_year_10..(block_height_at_year_10 + ITERATIONS) {
-            let reward = anchor_block_reward_at_height(
-                height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
-            );
-            assert_eq!(reward, 0);
-        }
-    }
-
-    #[test]
-    fn test_block_reward() {
-        let reward = block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, 0, 0);
-        assert_eq!(reward, EXPECTED_STAKING_REWARD);
-
-        // Increasing the anchor time will increase the reward.
-        let larger_reward = block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME + 1, 0, 0);
-        assert!(reward < larger_reward);
-
-        // Decreasing the anchor time will decrease the reward.
-        let smaller_reward = block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME - 1, 0, 0);
-        assert!(reward > smaller_reward);
-    }
-
-    #[test]
-    fn test_coinbase_reward() {
-        let coinbase_target: u64 = 10000;
-        let combined_proof_target: u128 = coinbase_target as u128;
-
-        let reward = coinbase_reward(
-            1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-            combined_proof_target,
-            0,
-            coinbase_target,
-        )
-        .unwrap();
-        assert_eq!(reward, EXPECTED_COINBASE_REWARD_AT_BLOCK_1);
-
-        // Halving the combined proof target halves the reward.
-        let smaller_reward = coinbase_reward(
-            1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-            combined_proof_target / 2,
-            0,
-            coinbase_target,
-        )
-        .unwrap();
-        assert_eq!(smaller_reward, reward / 2);
-
-        // Halving the remaining coinbase target halves the reward.
-        let smaller_reward = coinbase_reward(
-            1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-            combined_proof_target,
-            coinbase_target / 2,
-            coinbase_target,
-        )
-        .unwrap();
-        assert_eq!(smaller_reward, reward / 2);
-
-        // Dramatically increasing the combined proof target greater than the remaining coinbase target will not increase the reward.
-        let equivalent_reward = coinbase_reward(
-            1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-            u128::MAX,
-            0,
-            coinbase_target,
-        )
-        .unwrap();
-        assert_eq!(reward, equivalent_reward);
-
-        // Decreasing the combined proof target to 0 will result in a reward of 0.
-        let zero_reward = coinbase_reward(
-            1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-            0,
-            0,
-            coinbase_target,
-        )
-        .unwrap();
-        assert_eq!(zero_reward, 0);
-
-        // Increasing the cumulative proof target beyond the coinbase target will result in a reward of 0.
-        let zero_reward = coinbase_reward(
-            1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-            1,
-            coinbase_target + 1,
-            coinbase_target,
-        )
-        .unwrap();
-        assert_eq!(zero_reward, 0);
-    }
-
-    #[test]
-    fn test_coinbase_reward_remaining_target() {
-        let mut rng = TestRng::default();
-
-        fn compute_coinbase_reward(
-            combined_proof_target: u64,
-            cumulative_proof_target: u64,
-            coinbase_target: u64,
-        ) -> u64 {
-            coinbase_reward(
-                1,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
-                combined_proof_target as u128,
-                cumulative_proof_target,
-                coinbase_target,
-            )
-            .unwrap()
-        }
-
-        // Sample the starting conditions.
-        let coinbase_target: u64 = rng.gen_range(1_000_000..1_000_000_000_000_000);
-        let cumulative_proof_target = coinbase_target / 2;
-        let combined_proof_target = coinbase_target / 4;
-        let reward = compute_coinbase_reward(combined_proof_target, cumulative_proof_target, coinbase_target);
-
-        for _ in 0..ITERATIONS {
-            // Check that as long as the sum of the combined proof target and cumulative proof target is less than the coinbase target,
-            // the reward remains the same.
-            // Intuition: Staying below the coinbase target preserves the reward for the combined proof target.
-            let equivalent_reward = compute_coinbase_reward(
-                combined_proof_target,
-                rng.gen_range(0..(coinbase_target - combined_proof_target)),
-                coinbase_target,
-            );
-            assert_eq!(reward, equivalent_reward);
-
-            // Check that increasing the cumulative proof target to devalue the combined proof target will decrease the reward.
-            // Intuition: Overflowing the coinbase target crowds out the combined proof target, leading to less reward for the combined proof target.
-            let lower_reward = compute_coinbase_reward(
-                combined_proof_target,
-                rng.gen_range((coinbase_target - combined_proof_target + 1)..coinbase_target),
-                coinbase_target,
-            );
-            assert!(lower_reward < reward);
-
-            // Check that increasing the combined proof target increases the reward.
-            // Intuition: If a prover contributes more proof target, they should be rewarded more.
-            let larger_reward = compute_coinbase_reward(
-                rng.gen_range(combined_proof_target + 1..u64::MAX),
-                cumulative_proof_target,
-                coinbase_target,
-            );
-            assert!(reward < larger_reward);
-        }
-    }
-
-    #[test]
-    fn test_coinbase_reward_up_to_year_10() {
-        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
-
-        let mut block_height = 1;
-
-        let mut previous_reward = coinbase_reward(
-            block_height,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-            1,
-            0,
-            1,
-        )
-        .unwrap();
-
-        block_height += 1;
-
-        let mut total_reward = previous_reward;
-
-        let coinbase_target = CurrentNetwork::ANCHOR_HEIGHT as u64;
-        let mut cumulative_proof_target = 0;
-
-        let mut hit_500m = false;
-        let mut hit_1b = false;
-
-        while block_height < block_height_at_year_10 {
-            let reward = coinbase_reward(
-                block_height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
-                1,
-                cumulative_proof_target,
-                coinbase_target,
-            )
-            .unwrap();
-            assert!(reward <= previous_reward);
-
-            total_reward += reward;
-            previous_reward = reward;
-            block_height += 1;
-
-            // Update the cumulative proof target.
-            cumulative_proof_target = match cumulative_proof_target + 1 {
-                cumulative_proof_target if cumulative_proof_target == coinbase_target => 0,
-                cumulative_proof_target => cumulative_proof_target,
-            };
-
-            if !hit_500m && total_reward > 500_000_000_000_000 {
-                println!("500M credits block height is {block_height}");
-                assert_eq!(block_height, 5_786_964, "Update me if my parameters have changed");
-                hit_500m = true;
-            } else if !hit_1b && total_reward > 1_000_000_000_000_000 {
-                println!("1B credits block height is {block_height}");
-                assert_eq!(block_height, 13_328_683, "Update me if my parameters have changed");
-                hit_1b = true;
-            }
-        }
-
-        assert_eq!(total_reward, 1_499_999_984_232_003, "Update me if my parameters have changed");
-    }
-
-    #[test]
-    fn test_coinbase_reward_after_year_10() {
-        let mut rng = TestRng::default();
-
-        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
-
-        // Check that the block at year 10 has a reward of 0.
-        let reward = coinbase_reward(
-            block_height_at_year_10,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
-            1,
-            0,
-            1,
-        )
-        .unwrap();
-        assert_eq!(reward, 0);
-
-        // Check that the subsequent blocks have a reward of 0.
-        for _ in 0..ITERATIONS {
-            let block_height: u32 = rng.gen_range(block_height_at_year_10..block_height_at_year_10 * 10);
-            let coinbase_target = rng.gen_range(1_000_000..1_000_000_000_000_000);
-            let cumulative_proof_target = rng.gen_range(0..coinbase_target);
-            let combined_proof_target = rng.gen_range(0..coinbase_target as u128);
-
-            let reward = coinbase_reward(
-                block_height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
-                combined_proof_target,
-                cumulative_proof_target,
-                coinbase_target,
-            )
-            .unwrap();
-
-            assert_eq!(reward, 0);
-        }
-    }
-
     #[test]
     fn test_targets() {
         let mut rng = TestRng::default();
@@ -703,4 +400,35 @@ mod tests {
 
         assert_eq!(EXPECTED_NUM_BLOCKS_TO_DOUBLE, num_blocks);
     }
+
+    #[test]
+    fn test_simulate_targets() {
+        let genesis = TargetState::genesis::<CurrentNetwork>();
+        let high_rate = CurrentNetwork::GENESIS_COINBASE_TARGET as u128;
+        let low_rate = high_rate / 100;
+
+        let states = simulate_targets::<CurrentNetwork>(&[high_rate, low_rate, 0], 100).unwrap();
+        assert_eq!(300, states.len());
+
+        // Ensure the simulation is deterministic.
+        assert_eq!(states, simulate_targets::<CurrentNetwork>(&[high_rate, low_rate, 0], 100).unwrap());
+
+        // Ensure the targets increase when the coinbase target is reached faster than the anchor time.
+        assert!(states[99].coinbase_target > genesis.coinbase_target);
+        assert!(states[99].proof_target > genesis.proof_target);
+        // Ensure the targets decrease when the coinbase target is reached slower than the anchor time.
+        assert!(states[199].coinbase_target < states[99].coinbase_target);
+        // Ensure the targets decrease, but not below the genesis target, when no solutions are found.
+        assert!(states[299].coinbase_target < states[199].coinbase_target);
+        assert!(states[299].coinbase_target >= genesis.coinbase_target);
+        assert_eq!(states[199].last_coinbase_timestamp, states[299].last_coinbase_timestamp);
+
+        // Ensure each state matches the next state of the previous one.
+        let mut previous = genesis;
+        for (index, state) in states.iter().enumerate() {
+            let combined_proof_target = [high_rate, low_rate, 0][index / 100] * CurrentNetwork::BLOCK_TIME as u128;
+            assert_eq!(*state, previous.next::<CurrentNetwork>(state.timestamp, combined_proof_target).unwrap());
+            previous = *state;
+        }
+    }
 }
@@ -17,9 +17,15 @@
 // #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::single_element_loop))]
 
+#[macro_use]
+extern crate tracing;
+
 pub mod header;
 pub use header::*;
 
+pub mod evidence;
+pub use evidence::*;
+
 mod helpers;
 pub use helpers::*;
 
@@ -29,6 +35,9 @@ pub use ratifications::*;
 pub mod ratify;
 pub use ratify::*;
 
+pub mod rewards;
+pub use rewards::*;
+
 pub mod transaction;
 pub use transaction::*;
 
@@ -38,12 +47,17 @@ pub use transactions::*;
 pub mod transition;
 pub use transition::*;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod genesis;
 mod serialize;
 mod string;
 mod verify;
 
+#[cfg(feature = "python")]
+pub mod python;
+
 use console::{
     account::PrivateKey,
     network::prelude::*,
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The transaction API for Python, with `pyo3` types for the current network.
+//!
+//! Inputs, outputs, and transactions are returned as strings in their JSON encoding.
+
+use console::{
+    account::python::{to_py_error, CurrentNetwork},
+    network::prelude::*,
+};
+use synthesizer_program::python::Program;
+
+use pyo3::prelude::*;
+
+/// A transaction.
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct Transaction(crate::Transaction<CurrentNetwork>);
+
+#[pymethods]
+impl Transaction {
+    /// Returns the transaction for the given JSON string.
+    #[staticmethod]
+    pub fn from_string(transaction: &str) -> PyResult<Self> {
+        crate::Transaction::from_str(transaction).map(Self).map_err(to_py_error)
+    }
+
+    /// Returns the transaction ID.
+    #[getter]
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the kind of the transaction, i.e. `deploy`, `execute`, or `fee`.
+    #[getter]
+    pub fn kind(&self) -> &'static str {
+        match self.0 {
+            crate::Transaction::Deploy(..) => "deploy",
+            crate::Transaction::Execute(..) => "execute",
+            crate::Transaction::Fee(..) => "fee",
+        }
+    }
+
+    /// Returns the total fee, in microcredits.
+    pub fn fee_amount(&self) -> PyResult<u64> {
+        self.0.fee_amount().map(|amount| *amount).map_err(to_py_error)
+    }
+
+    /// Returns the base fee, in microcredits.
+    pub fn base_fee_amount(&self) -> PyResult<u64> {
+        self.0.base_fee_amount().map(|amount| *amount).map_err(to_py_error)
+    }
+
+    /// Returns the priority fee, in microcredits.
+    pub fn priority_fee_amount(&self) -> PyResult<u64> {
+        self.0.priority_fee_amount().map(|amount| *amount).map_err(to_py_error)
+    }
+
+    /// Returns the deployed program, if this is a deploy transaction.
+    pub fn deployed_program(&self) -> Option<Program> {
+        self.0.deployment().map(|deployment| Program::from(deployment.program().clone()))
+    }
+
+    /// Returns the transitions, including the fee transition.
+    pub fn transitions(&self) -> Vec<Transition> {
+        self.0.transitions().cloned().map(Transition).collect()
+    }
+
+    /// Returns the transaction as a JSON string.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl Deref for Transaction {
+    type Target = crate::Transaction<CurrentNetwork>;
+
+    /// Returns the transaction.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A transition.
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct Transition(crate::Transition<CurrentNetwork>);
+
+#[pymethods]
+impl Transition {
+    /// Returns the transition ID.
+    #[getter]
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the program ID.
+    #[getter]
+    pub fn program_id(&self) -> String {
+        self.0.program_id().to_string()
+    }
+
+    /// Returns the function name.
+    #[getter]
+    pub fn function_name(&self) -> String {
+        self.0.function_name().to_string()
+    }
+
+    /// Returns the inputs, as JSON strings.
+    pub fn inputs(&self) -> Vec<String> {
+        self.0.inputs().iter().map(ToString::to_string).collect()
+    }
+
+    /// Returns the outputs, as JSON strings.
+    pub fn outputs(&self) -> Vec<String> {
+        self.0.outputs().iter().map(ToString::to_string).collect()
+    }
+
+    /// Returns the transition as a JSON string.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl Deref for Transition {
+    type Target = crate::Transition<CurrentNetwork>;
+
+    /// Returns the transition.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Adds the transaction classes to the given Python module.
+pub fn register(module: &PyModule) -> PyResult<()> {
+    module.add_class::<Transaction>()?;
+    module.add_class::<Transition>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction() {
+        let rng = &mut TestRng::default();
+
+        let expected = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction = Transaction::from_string(&expected.to_string()).unwrap();
+        assert_eq!(transaction.id(), expected.id().to_string());
+        assert_eq!(transaction.kind(), "execute");
+        assert_eq!(transaction.fee_amount().unwrap(), *expected.fee_amount().unwrap());
+        assert!(transaction.deployed_program().is_none());
+
+        let transitions = transaction.transitions();
+        assert_eq!(transitions.len(), expected.transitions().count());
+        assert_eq!(transitions.last().unwrap().function_name(), "fee_private");
+
+        let expected = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let transaction = Transaction::from_string(&expected.to_string()).unwrap();
+        assert_eq!(transaction.kind(), "deploy");
+        assert_eq!(
+            transaction.deployed_program().unwrap().id(),
+            expected.deployment().unwrap().program_id().to_string()
+        );
+    }
+}
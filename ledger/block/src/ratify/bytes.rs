@@ -56,7 +56,30 @@ impl<N: Network> FromBytes for Ratify<N> {
                 // Return the ratify object.
                 Self::PuzzleReward(amount)
             }
-            3.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
+            3 => {
+                // Read the kind.
+                let kind: u16 = FromBytes::read_le(&mut reader)?;
+                // Read the version.
+                let version: u8 = FromBytes::read_le(&mut reader)?;
+                // Read the number of bytes in the payload.
+                let num_bytes: u32 = FromBytes::read_le(&mut reader)?;
+                // Ensure the payload is within the allowed size.
+                if num_bytes as usize > Self::MAX_CUSTOM_PAYLOAD_SIZE {
+                    return Err(error(format!("Custom ratification payload is too large ({num_bytes} bytes)")));
+                }
+                // Read the payload.
+                let mut payload = vec![0u8; num_bytes as usize];
+                reader.read_exact(&mut payload)?;
+                // Return the ratify object.
+                Self::Custom(kind, version, payload)
+            }
+            4 => {
+                // Read the evidence.
+                let evidence: Evidence<N> = FromBytes::read_le(&mut reader)?;
+                // Return the ratify object.
+                Self::Evidence(Box::new(evidence))
+            }
+            5.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
         };
         Ok(ratify)
     }
@@ -87,6 +110,21 @@ impl<N: Network> ToBytes for Ratify<N> {
                 (2 as Variant).write_le(&mut writer)?;
                 amount.write_le(&mut writer)
             }
+            Self::Custom(kind, version, payload) => {
+                // Ensure the payload is within the allowed size.
+                if payload.len() > Self::MAX_CUSTOM_PAYLOAD_SIZE {
+                    return Err(error(format!("Custom ratification payload is too large ({} bytes)", payload.len())));
+                }
+                (3 as Variant).write_le(&mut writer)?;
+                kind.write_le(&mut writer)?;
+                version.write_le(&mut writer)?;
+                u32::try_from(payload.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                payload.write_le(&mut writer)
+            }
+            Self::Evidence(evidence) => {
+                (4 as Variant).write_le(&mut writer)?;
+                evidence.write_le(&mut writer)
+            }
         }
     }
 }
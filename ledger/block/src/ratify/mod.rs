@@ -16,6 +16,7 @@ mod bytes;
 mod serialize;
 mod string;
 
+use crate::Evidence;
 use console::{network::prelude::*, types::Address};
 use ledger_committee::Committee;
 
@@ -33,9 +34,40 @@ pub enum Ratify<N: Network> {
     BlockReward(u64),
     /// The puzzle reward.
     PuzzleReward(u64),
+    /// A custom ratification, as a (kind, version, payload) tuple.
+    /// The payload is opaque to the block, and is applied by the handler registered for the kind in the VM.
+    Custom(u16, u8, Vec<u8>),
+    /// The evidence of validator misbehavior.
+    /// The validator is removed from the committee, and its stakers are unbonded.
+    Evidence(Box<Evidence<N>>),
 }
 
 impl<N: Network> Ratify<N> {
+    /// The maximum number of bytes allowed in the payload of a custom ratification.
+    pub const MAX_CUSTOM_PAYLOAD_SIZE: usize = 1 << 16;
+
+    /// Initializes a new custom ratification, with the given kind, version, and payload.
+    pub fn new_custom(kind: u16, version: u8, payload: Vec<u8>) -> Result<Self> {
+        // Ensure the payload is within the allowed size.
+        ensure!(
+            payload.len() <= Self::MAX_CUSTOM_PAYLOAD_SIZE,
+            "Custom ratification payload is too large (found '{}', expected at most '{}')",
+            payload.len(),
+            Self::MAX_CUSTOM_PAYLOAD_SIZE
+        );
+        Ok(Self::Custom(kind, version, payload))
+    }
+
+    /// Returns `true` if the ratification is a custom ratification.
+    pub const fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(..))
+    }
+
+    /// Returns `true` if the ratification is evidence of validator misbehavior.
+    pub const fn is_evidence(&self) -> bool {
+        matches!(self, Self::Evidence(..))
+    }
+
     /// Returns the ratification ID.
     pub fn to_id(&self) -> Result<N::RatificationID> {
         Ok(N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())?.into())
@@ -60,6 +92,8 @@ pub(crate) mod test_helpers {
             Ratify::Genesis(committee, public_balances),
             Ratify::BlockReward(rng.gen()),
             Ratify::PuzzleReward(rng.gen()),
+            Ratify::Custom(rng.gen(), rng.gen(), (0..rng.gen_range(0..64)).map(|_| rng.gen()).collect()),
+            Ratify::Evidence(Box::new(crate::evidence::test_helpers::sample_equivocation(rng))),
         ]
     }
 }
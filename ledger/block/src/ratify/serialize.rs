@@ -38,6 +38,20 @@ impl<N: Network> Serialize for Ratify<N> {
                     input.serialize_field("amount", &amount)?;
                     input.end()
                 }
+                Self::Custom(kind, version, payload) => {
+                    let mut input = serializer.serialize_struct("Ratify", 4)?;
+                    input.serialize_field("type", "custom")?;
+                    input.serialize_field("kind", &kind)?;
+                    input.serialize_field("version", &version)?;
+                    input.serialize_field("payload", &payload)?;
+                    input.end()
+                }
+                Self::Evidence(evidence) => {
+                    let mut input = serializer.serialize_struct("Ratify", 2)?;
+                    input.serialize_field("type", "evidence")?;
+                    input.serialize_field("evidence", &evidence)?;
+                    input.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -75,6 +89,22 @@ impl<'de, N: Network> Deserialize<'de> for Ratify<N> {
                         // Construct the ratify object.
                         Ratify::PuzzleReward(amount)
                     }
+                    Some("custom") => {
+                        // Retrieve the kind.
+                        let kind: u16 = DeserializeExt::take_from_value::<D>(&mut object, "kind")?;
+                        // Retrieve the version.
+                        let version: u8 = DeserializeExt::take_from_value::<D>(&mut object, "version")?;
+                        // Retrieve the payload.
+                        let payload: Vec<u8> = DeserializeExt::take_from_value::<D>(&mut object, "payload")?;
+                        // Construct the ratify object.
+                        Ratify::new_custom(kind, version, payload).map_err(de::Error::custom)?
+                    }
+                    Some("evidence") => {
+                        // Retrieve the evidence.
+                        let evidence: Evidence<N> = DeserializeExt::take_from_value::<D>(&mut object, "evidence")?;
+                        // Construct the ratify object.
+                        Ratify::Evidence(Box::new(evidence))
+                    }
                     _ => return Err(de::Error::custom("Invalid ratify object type")),
                 };
                 // Return the ratify object.
@@ -0,0 +1,704 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod simulate;
+pub use simulate::*;
+
+use crate::block_height_at_year;
+use console::{account::Address, network::prelude::*};
+use ledger_committee::{Committee, MIN_DELEGATOR_STAKE};
+
+use indexmap::IndexMap;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A safety bound (sanity-check) for the coinbase reward.
+pub const MAX_COINBASE_REWARD: u64 = 190_258_739; // Coinbase reward at block 1.
+
+/// Calculate the block reward, given the total supply, block time, coinbase reward, and transaction fees.
+///     R_staking = floor((0.05 * S) / H_Y1) + CR / 2 + TX_F.
+///     S = Total supply.
+///     H_Y1 = Expected block height at year 1.
+///     CR = Coinbase reward.
+///     TX_F = Transaction fees.
+pub const fn block_reward(total_supply: u64, block_time: u16, coinbase_reward: u64, transaction_fees: u64) -> u64 {
+    // Compute the expected block height at year 1.
+    let block_height_at_year_1 = block_height_at_year(block_time, 1);
+    // Compute the annual reward: (0.05 * S).
+    let annual_reward = total_supply / 20;
+    // Compute the block reward: (0.05 * S) / H_Y1.
+    let block_reward = annual_reward / block_height_at_year_1 as u64;
+    // Return the sum of the block reward, coinbase reward, and transaction fees.
+    block_reward + (coinbase_reward / 2) + transaction_fees
+}
+
+/// Calculate the puzzle reward, given the coinbase reward.
+pub const fn puzzle_reward(coinbase_reward: u64) -> u64 {
+    // Return the coinbase reward divided by 2.
+    coinbase_reward / 2
+}
+
+/// Calculates the coinbase reward for a given block.
+///     R_coinbase = max(0, H_Y10 - H) * R_anchor * min(P, C_R) / C
+///     R_anchor = Anchor reward.
+///     H_Y10 = Anchor block height at year 10.
+///     H = Current block height.
+///     P = Combined proof target.
+///     C_R = Remaining coinbase target.
+///     C = Coinbase target.
+pub fn coinbase_reward(
+    block_height: u32,
+    starting_supply: u64,
+    anchor_height: u32,
+    block_time: u16,
+    combined_proof_target: u128,
+    cumulative_proof_target: u64,
+    coinbase_target: u64,
+) -> Result<u64> {
+    // Compute the remaining coinbase target.
+    let remaining_coinbase_target = coinbase_target.saturating_sub(cumulative_proof_target);
+    // Compute the remaining proof target.
+    let remaining_proof_target = combined_proof_target.min(remaining_coinbase_target as u128);
+
+    /* Until the anchor block height at year 10, the coinbase reward is determined by this equation: */
+    /*   anchor_block_reward * remaining_proof_target / coinbase_target */
+
+    // Compute the anchor block reward.
+    let anchor_block_reward = anchor_block_reward_at_height(block_height, starting_supply, anchor_height, block_time);
+
+    // Calculate the coinbase reward.
+    let reward = anchor_block_reward.saturating_mul(remaining_proof_target).saturating_div(coinbase_target as u128);
+
+    // Ensure the coinbase reward is less than the maximum coinbase reward.
+    ensure!(reward <= MAX_COINBASE_REWARD as u128, "Coinbase reward ({reward}) exceeds maximum {MAX_COINBASE_REWARD}");
+
+    // Return the coinbase reward.
+    // Note: This '.expect' is guaranteed to be safe, as we ensure the reward is within a safe bound.
+    Ok(u64::try_from(reward).expect("Coinbase reward exceeds u64::MAX"))
+}
+
+/// Calculates the anchor block reward for the given block height.
+///     R_anchor = floor((2 * S * H_A * H_R) / (H_Y10 * (H_Y10 + 1))).
+///     S = Starting supply.
+///     H_A = Anchor block height.
+///     H_R = Remaining number of blocks until year 10.
+///     H_Y10 = Expected block height at year 10.
+const fn anchor_block_reward_at_height(
+    block_height: u32,
+    starting_supply: u64,
+    anchor_height: u32,
+    block_time: u16,
+) -> u128 {
+    // Calculate the block height at year 10.
+    let block_height_at_year_10 = block_height_at_year(block_time, 10) as u128;
+    // Compute the remaining blocks until year 10, as a u64.
+    let num_remaining_blocks_to_year_10 = block_height_at_year_10.saturating_sub(block_height as u128);
+    // Compute the numerator.
+    let numerator = 2 * starting_supply as u128 * anchor_height as u128 * num_remaining_blocks_to_year_10;
+    // Compute the denominator.
+    let denominator = block_height_at_year_10 * (block_height_at_year_10 + 1);
+    // Return the anchor block reward.
+    numerator / denominator
+}
+
+/// Returns the updated stakers reflecting the staking rewards for the given committee and block reward.
+/// The staking reward is defined as: `block_reward * stake / total_stake`.
+///
+/// This method ensures that stakers who are bonded to validators with more than **25%**
+/// of the total stake will not receive a staking reward. In addition, this method
+/// ensures stakers who have less than 10 credit are not eligible for a staking reward.
+///
+/// The choice of 25% is to ensure at least 4 validators are operational at any given time,
+/// since our security model adheres to 3f+1, where f=1. As such, we tolerate Byzantine behavior
+/// up to 33% of the total stake.
+pub fn staking_rewards<N: Network>(
+    stakers: &IndexMap<Address<N>, (Address<N>, u64)>,
+    committee: &Committee<N>,
+    block_reward: u64,
+) -> IndexMap<Address<N>, (Address<N>, u64)> {
+    // If the list of stakers is empty, there is no stake, or the block reward is 0, return the stakers.
+    if stakers.is_empty() || committee.total_stake() == 0 || block_reward == 0 {
+        return stakers.clone();
+    }
+
+    // Compute the updated stakers.
+    cfg_iter!(stakers)
+        .map(|(staker, (validator, stake))| {
+            // If the validator has more than 25% of the total stake, skip the staker.
+            if committee.get_stake(*validator) > committee.total_stake().saturating_div(4) {
+                trace!("Validator {validator} has more than 25% of the total stake - skipping {staker}");
+                return (*staker, (*validator, *stake));
+            }
+            // If the staker has less than the minimum required stake, skip the staker.
+            if *stake < MIN_DELEGATOR_STAKE {
+                trace!("Staker has less than {MIN_DELEGATOR_STAKE} microcredits - skipping {staker}");
+                return (*staker, (*validator, *stake));
+            }
+
+            // Compute the numerator.
+            let numerator = (block_reward as u128).saturating_mul(*stake as u128);
+            // Compute the denominator.
+            // Note: We guarantee this denominator cannot be 0 (as we return early if the total stake is 0).
+            let denominator = committee.total_stake() as u128;
+            // Compute the quotient.
+            let quotient = numerator.saturating_div(denominator);
+            // Ensure the staking reward is within a safe bound.
+            if quotient > MAX_COINBASE_REWARD as u128 {
+                error!("Staking reward ({quotient}) is too large - skipping {staker}");
+                return (*staker, (*validator, *stake));
+            }
+            // Cast the staking reward as a u64.
+            // Note: This '.expect' is guaranteed to be safe, as we ensure the quotient is within a safe bound.
+            let staking_reward = u64::try_from(quotient).expect("Staking reward is too large");
+            // Return the staker and the updated stake.
+            (*staker, (*validator, stake.saturating_add(staking_reward)))
+        })
+        .collect()
+}
+
+/// Returns the proving rewards for a given coinbase reward and list of prover solutions.
+/// The prover reward is defined as: `puzzle_reward * (proof_target / combined_proof_target)`.
+pub fn proving_rewards<N: Network>(
+    proof_targets: Vec<(Address<N>, u64)>,
+    puzzle_reward: u64,
+) -> IndexMap<Address<N>, u64> {
+    // Compute the combined proof target. Using '.sum' here is safe because we sum u64s into a u128.
+    let combined_proof_target = proof_targets.iter().map(|(_, t)| *t as u128).sum::<u128>();
+
+    // If the list of solutions is empty, the combined proof target is 0, or the puzzle reward is 0, return an empty map.
+    if proof_targets.is_empty() || combined_proof_target == 0 || puzzle_reward == 0 {
+        return Default::default();
+    }
+
+    // Initialize a vector to store the proving rewards.
+    let mut rewards = IndexMap::<_, u64>::with_capacity(proof_targets.len());
+
+    // Calculate the rewards for the individual provers.
+    for (address, proof_target) in proof_targets {
+        // Compute the numerator.
+        let numerator = (puzzle_reward as u128).saturating_mul(proof_target as u128);
+        // Compute the denominator.
+        // Note: We guarantee this denominator cannot be 0 (to prevent a div by 0).
+        let denominator = combined_proof_target.max(1);
+        // Compute the quotient.
+        let quotient = numerator.saturating_div(denominator);
+        // Ensure the proving reward is within a safe bound.
+        if quotient > MAX_COINBASE_REWARD as u128 {
+            error!("Prover reward ({quotient}) is too large - skipping solution from {address}");
+            continue;
+        }
+        // Cast the proving reward as a u64.
+        // Note: This '.expect' is guaranteed to be safe, as we ensure the quotient is within a safe bound.
+        let prover_reward = u64::try_from(quotient).expect("Prover reward is too large");
+        // If there is a proving reward, append it to the vector.
+        if prover_reward > 0 {
+            // Add the proving reward to the prover.
+            let entry = rewards.entry(address).or_default();
+            *entry = entry.saturating_add(prover_reward);
+        }
+    }
+
+    // Return the proving rewards.
+    rewards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    use indexmap::indexmap;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 1000;
+
+    const EXPECTED_ANCHOR_BLOCK_REWARD_AT_BLOCK_1: u128 = MAX_COINBASE_REWARD as u128;
+    const EXPECTED_STAKING_REWARD: u64 = 23_782_343;
+    const EXPECTED_COINBASE_REWARD_AT_BLOCK_1: u64 = MAX_COINBASE_REWARD;
+
+    #[test]
+    fn test_anchor_block_reward() {
+        let reward = anchor_block_reward_at_height(
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+        );
+        assert_eq!(reward, EXPECTED_ANCHOR_BLOCK_REWARD_AT_BLOCK_1);
+
+        // Calculate the block height at year 10.
+        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
+
+        // Ensure that the reward is decreasing for blocks before year 10.
+        let mut previous_reward = reward;
+        let anchor_height = CurrentNetwork::ANCHOR_HEIGHT as usize;
+        for height in (2..block_height_at_year_10).step_by(anchor_height).skip(1) {
+            let reward = anchor_block_reward_at_height(
+                height,
+                CurrentNetwork::STARTING_SUPPLY,
+                CurrentNetwork::ANCHOR_HEIGHT,
+                CurrentNetwork::BLOCK_TIME,
+            );
+            assert!(reward < previous_reward, "Failed on block height {height}");
+            previous_reward = reward;
+        }
+
+        // Ensure that the reward is zero for blocks after year 10.
+        for height in block_height_at_year_10..(block_height_at_year_10 + ITERATIONS) {
+            let reward = anchor_block_reward_at_height(
+                height,
+                CurrentNetwork::STARTING_SUPPLY,
+                CurrentNetwork::ANCHOR_HEIGHT,
+                CurrentNetwork::BLOCK_TIME,
+            );
+            assert_eq!(reward, 0);
+        }
+    }
+
+    #[test]
+    fn test_block_reward() {
+        let reward = block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, 0, 0);
+        assert_eq!(reward, EXPECTED_STAKING_REWARD);
+
+        // Increasing the anchor time will increase the reward.
+        let larger_reward = block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME + 1, 0, 0);
+        assert!(reward < larger_reward);
+
+        // Decreasing the anchor time will decrease the reward.
+        let smaller_reward = block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME - 1, 0, 0);
+        assert!(reward > smaller_reward);
+    }
+
+    #[test]
+    fn test_coinbase_reward() {
+        let coinbase_target: u64 = 10000;
+        let combined_proof_target: u128 = coinbase_target as u128;
+
+        let reward = coinbase_reward(
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            combined_proof_target,
+            0,
+            coinbase_target,
+        )
+        .unwrap();
+        assert_eq!(reward, EXPECTED_COINBASE_REWARD_AT_BLOCK_1);
+
+        // Halving the combined proof target halves the reward.
+        let smaller_reward = coinbase_reward(
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            combined_proof_target / 2,
+            0,
+            coinbase_target,
+        )
+        .unwrap();
+        assert_eq!(smaller_reward, reward / 2);
+
+        // Halving the remaining coinbase target halves the reward.
+        let smaller_reward = coinbase_reward(
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            combined_proof_target,
+            coinbase_target / 2,
+            coinbase_target,
+        )
+        .unwrap();
+        assert_eq!(smaller_reward, reward / 2);
+
+        // Dramatically increasing the combined proof target greater than the remaining coinbase target will not increase the reward.
+        let equivalent_reward = coinbase_reward(
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            u128::MAX,
+            0,
+            coinbase_target,
+        )
+        .unwrap();
+        assert_eq!(reward, equivalent_reward);
+
+        // Decreasing the combined proof target to 0 will result in a reward of 0.
+        let zero_reward = coinbase_reward(
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            0,
+            0,
+            coinbase_target,
+        )
+        .unwrap();
+        assert_eq!(zero_reward, 0);
+
+        // Increasing the cumulative proof target beyond the coinbase target will result in a reward of 0.
+        let zero_reward = coinbase_reward(
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            1,
+            coinbase_target + 1,
+            coinbase_target,
+        )
+        .unwrap();
+        assert_eq!(zero_reward, 0);
+    }
+
+    #[test]
+    fn test_coinbase_reward_remaining_target() {
+        let mut rng = TestRng::default();
+
+        fn compute_coinbase_reward(
+            combined_proof_target: u64,
+            cumulative_proof_target: u64,
+            coinbase_target: u64,
+        ) -> u64 {
+            coinbase_reward(
+                1,
+                CurrentNetwork::STARTING_SUPPLY,
+                CurrentNetwork::ANCHOR_HEIGHT,
+                CurrentNetwork::BLOCK_TIME,
+                combined_proof_target as u128,
+                cumulative_proof_target,
+                coinbase_target,
+            )
+            .unwrap()
+        }
+
+        // Sample the starting conditions.
+        let coinbase_target: u64 = rng.gen_range(1_000_000..1_000_000_000_000_000);
+        let cumulative_proof_target = coinbase_target / 2;
+        let combined_proof_target = coinbase_target / 4;
+        let reward = compute_coinbase_reward(combined_proof_target, cumulative_proof_target, coinbase_target);
+
+        for _ in 0..ITERATIONS {
+            // Check that as long as the sum of the combined proof target and cumulative proof target is less than the coinbase target,
+            // the reward remains the same.
+            // Intuition: Staying below the coinbase target preserves the reward for the combined proof target.
+            let equivalent_reward = compute_coinbase_reward(
+                combined_proof_target,
+                rng.gen_range(0..(coinbase_target - combined_proof_target)),
+                coinbase_target,
+            );
+            assert_eq!(reward, equivalent_reward);
+
+            // Check that increasing the cumulative proof target to devalue the combined proof target will decrease the reward.
+            // Intuition: Overflowing the coinbase target crowds out the combined proof target, leading to less reward for the combined proof target.
+            let lower_reward = compute_coinbase_reward(
+                combined_proof_target,
+                rng.gen_range((coinbase_target - combined_proof_target + 1)..coinbase_target),
+                coinbase_target,
+            );
+            assert!(lower_reward < reward);
+
+            // Check that increasing the combined proof target increases the reward.
+            // Intuition: If a prover contributes more proof target, they should be rewarded more.
+            let larger_reward = compute_coinbase_reward(
+                rng.gen_range(combined_proof_target + 1..u64::MAX),
+                cumulative_proof_target,
+                coinbase_target,
+            );
+            assert!(reward < larger_reward);
+        }
+    }
+
+    #[test]
+    fn test_coinbase_reward_up_to_year_10() {
+        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
+
+        let mut block_height = 1;
+
+        let mut previous_reward = coinbase_reward(
+            block_height,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            1,
+            0,
+            1,
+        )
+        .unwrap();
+
+        block_height += 1;
+
+        let mut total_reward = previous_reward;
+
+        let coinbase_target = CurrentNetwork::ANCHOR_HEIGHT as u64;
+        let mut cumulative_proof_target = 0;
+
+        let mut hit_500m = false;
+        let mut hit_1b = false;
+
+        while block_height < block_height_at_year_10 {
+            let reward = coinbase_reward(
+                block_height,
+                CurrentNetwork::STARTING_SUPPLY,
+                CurrentNetwork::ANCHOR_HEIGHT,
+                CurrentNetwork::BLOCK_TIME,
+                1,
+                cumulative_proof_target,
+                coinbase_target,
+            )
+            .unwrap();
+            assert!(reward <= previous_reward);
+
+            total_reward += reward;
+            previous_reward = reward;
+            block_height += 1;
+
+            // Update the cumulative proof target.
+            cumulative_proof_target = match cumulative_proof_target + 1 {
+                cumulative_proof_target if cumulative_proof_target == coinbase_target => 0,
+                cumulative_proof_target => cumulative_proof_target,
+            };
+
+            if !hit_500m && total_reward > 500_000_000_000_000 {
+                println!("500M credits block height is {block_height}");
+                assert_eq!(block_height, 5_786_964, "Update me if my parameters have changed");
+                hit_500m = true;
+            } else if !hit_1b && total_reward > 1_000_000_000_000_000 {
+                println!("1B credits block height is {block_height}");
+                assert_eq!(block_height, 13_328_683, "Update me if my parameters have changed");
+                hit_1b = true;
+            }
+        }
+
+        assert_eq!(total_reward, 1_499_999_984_232_003, "Update me if my parameters have changed");
+    }
+
+    #[test]
+    fn test_coinbase_reward_after_year_10() {
+        let mut rng = TestRng::default();
+
+        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
+
+        // Check that the block at year 10 has a reward of 0.
+        let reward = coinbase_reward(
+            block_height_at_year_10,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            1,
+            0,
+            1,
+        )
+        .unwrap();
+        assert_eq!(reward, 0);
+
+        // Check that the subsequent blocks have a reward of 0.
+        for _ in 0..ITERATIONS {
+            let block_height: u32 = rng.gen_range(block_height_at_year_10..block_height_at_year_10 * 10);
+            let coinbase_target = rng.gen_range(1_000_000..1_000_000_000_000_000);
+            let cumulative_proof_target = rng.gen_range(0..coinbase_target);
+            let combined_proof_target = rng.gen_range(0..coinbase_target as u128);
+
+            let reward = coinbase_reward(
+                block_height,
+                CurrentNetwork::STARTING_SUPPLY,
+                CurrentNetwork::ANCHOR_HEIGHT,
+                CurrentNetwork::BLOCK_TIME,
+                combined_proof_target,
+                cumulative_proof_target,
+                coinbase_target,
+            )
+            .unwrap();
+
+            assert_eq!(reward, 0);
+        }
+    }
+
+    #[test]
+    fn test_staking_rewards() {
+        let rng = &mut TestRng::default();
+        // Sample a random committee.
+        let committee = ledger_committee::test_helpers::sample_committee(rng);
+        // Sample a random block reward.
+        let block_reward = rng.gen_range(0..MAX_COINBASE_REWARD);
+        // Retrieve an address.
+        let address = *committee.members().iter().next().unwrap().0;
+
+        for _ in 0..ITERATIONS {
+            // Sample a random stake.
+            let stake = rng.gen_range(MIN_DELEGATOR_STAKE..committee.total_stake());
+            // Construct the stakers.
+            let stakers = indexmap! {address => (address, stake)};
+            let next_stakers = staking_rewards::<CurrentNetwork>(&stakers, &committee, block_reward);
+            assert_eq!(next_stakers.len(), 1);
+            let (candidate_address, (candidate_validator, candidate_stake)) = next_stakers.into_iter().next().unwrap();
+            assert_eq!(candidate_address, address);
+            assert_eq!(candidate_validator, address);
+            let reward = block_reward as u128 * stake as u128 / committee.total_stake() as u128;
+            assert_eq!(candidate_stake, stake + u64::try_from(reward).unwrap(), "stake: {stake}, reward: {reward}");
+        }
+    }
+
+    #[test]
+    fn test_staking_rewards_large() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random block reward.
+        let block_reward = rng.gen_range(0..MAX_COINBASE_REWARD);
+        // Sample a committee.
+        let committee = ledger_committee::test_helpers::sample_committee_for_round_and_size(1, 100, rng);
+        // Convert the committee into stakers, where each validator is bonded to itself.
+        let stakers = committee
+            .members()
+            .iter()
+            .map(|(validator, (microcredits, _))| (*validator, (*validator, *microcredits)))
+            .collect::<IndexMap<_, _>>();
+
+        // Start a timer.
+        let timer = std::time::Instant::now();
+        // Compute the staking rewards.
+        let next_stakers = staking_rewards::<CurrentNetwork>(&stakers, &committee, block_reward);
+        println!("staking_rewards: {}ms", timer.elapsed().as_millis());
+        assert_eq!(next_stakers.len(), stakers.len());
+        for ((staker, (validator, stake)), (next_staker, (next_validator, next_stake))) in
+            stakers.into_iter().zip(next_stakers.into_iter())
+        {
+            assert_eq!(staker, next_staker);
+            assert_eq!(validator, next_validator);
+            let reward = block_reward as u128 * stake as u128 / committee.total_stake() as u128;
+            assert_eq!(stake + u64::try_from(reward).unwrap(), next_stake, "stake: {stake}, reward: {reward}");
+        }
+    }
+
+    #[test]
+    fn test_staking_rewards_when_staker_is_under_min_yields_no_reward() {
+        let rng = &mut TestRng::default();
+        // Sample a random committee.
+        let committee = ledger_committee::test_helpers::sample_committee(rng);
+        // Sample a random block reward.
+        let block_reward = rng.gen_range(0..MAX_COINBASE_REWARD);
+        // Retrieve an address.
+        let address = *committee.members().iter().next().unwrap().0;
+
+        for _ in 0..ITERATIONS {
+            // Sample a random stake.
+            let stake = rng.gen_range(0..MIN_DELEGATOR_STAKE);
+            // Construct the stakers.
+            let stakers = indexmap! {address => (address, stake)};
+            let next_stakers = staking_rewards::<CurrentNetwork>(&stakers, &committee, block_reward);
+            assert_eq!(next_stakers.len(), 1);
+            let (candidate_address, (candidate_validator, candidate_stake)) = next_stakers.into_iter().next().unwrap();
+            assert_eq!(candidate_address, address);
+            assert_eq!(candidate_validator, address);
+            assert_eq!(candidate_stake, stake);
+        }
+    }
+
+    #[test]
+    fn test_staking_rewards_cannot_exceed_coinbase_reward() {
+        let rng = &mut TestRng::default();
+        // Sample a random committee.
+        let committee = ledger_committee::test_helpers::sample_committee(rng);
+        // Retrieve an address.
+        let address = *committee.members().iter().next().unwrap().0;
+
+        // Construct the stakers.
+        let stakers = indexmap![address => (address, MIN_DELEGATOR_STAKE)];
+        // Check that a maxed out coinbase reward, returns empty.
+        let next_stakers = staking_rewards::<CurrentNetwork>(&stakers, &committee, u64::MAX);
+        assert_eq!(stakers, next_stakers);
+
+        // Ensure a staking reward that is too large, renders no rewards.
+        for _ in 0..ITERATIONS {
+            // Sample a random overly-large block reward.
+            let block_reward = rng.gen_range(MAX_COINBASE_REWARD..u64::MAX);
+            // Sample a random stake.
+            let stake = rng.gen_range(MIN_DELEGATOR_STAKE..u64::MAX);
+            // Construct the stakers.
+            let stakers = indexmap![address => (address, stake)];
+            // Check that an overly large block reward fails.
+            let next_stakers = staking_rewards::<CurrentNetwork>(&stakers, &committee, block_reward);
+            assert_eq!(stakers, next_stakers);
+        }
+    }
+
+    #[test]
+    fn test_staking_rewards_is_empty() {
+        let rng = &mut TestRng::default();
+        // Sample a random committee.
+        let committee = ledger_committee::test_helpers::sample_committee(rng);
+
+        // Compute the staking rewards (empty).
+        let rewards = staking_rewards::<CurrentNetwork>(&indexmap![], &committee, rng.gen());
+        assert!(rewards.is_empty());
+    }
+
+    #[test]
+    fn test_proving_rewards() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random address.
+            let address = Address::rand(rng);
+            // Sample a random puzzle reward.
+            let puzzle_reward = rng.gen_range(0..MAX_COINBASE_REWARD);
+
+            let rewards = proving_rewards::<CurrentNetwork>(vec![(address, u64::MAX)], puzzle_reward);
+            assert_eq!(rewards.len(), 1);
+            let (candidate_address, candidate_amount) = rewards.into_iter().next().unwrap();
+            assert_eq!(candidate_address, address);
+            assert!(candidate_amount <= puzzle_reward);
+        }
+    }
+
+    #[test]
+    fn test_proving_rewards_cannot_exceed_coinbase_reward() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a proving reward that is too large, renders no rewards.
+        for _ in 0..ITERATIONS {
+            // Sample a random address.
+            let address = Address::rand(rng);
+            // Sample a random overly-large puzzle reward.
+            let puzzle_reward = rng.gen_range(MAX_COINBASE_REWARD..u64::MAX);
+            // Sample a random proof target.
+            let proof_target = rng.gen_range(0..u64::MAX);
+            // Check that a maxed out proof target fails.
+            let rewards = proving_rewards::<CurrentNetwork>(vec![(address, proof_target)], puzzle_reward);
+            assert!(rewards.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_proving_rewards_is_empty() {
+        let rng = &mut TestRng::default();
+        // Sample a random address.
+        let address = Address::rand(rng);
+
+        // Compute the proving rewards (empty).
+        let rewards = proving_rewards::<CurrentNetwork>(vec![], rng.gen());
+        assert!(rewards.is_empty());
+
+        // Check that a maxed out coinbase reward, returns empty.
+        let rewards = proving_rewards::<CurrentNetwork>(vec![(address, 2)], u64::MAX);
+        assert!(rewards.is_empty());
+
+        // Ensure a 0 coinbase reward case is empty.
+        let rewards = proving_rewards::<CurrentNetwork>(vec![(address, 2)], 0);
+        assert!(rewards.is_empty());
+    }
+}
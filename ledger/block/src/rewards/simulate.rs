@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The inputs for a block in a rewards simulation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulatedBlock {
+    /// The combined proof target of the solutions in the block.
+    pub combined_proof_target: u128,
+    /// The transaction fees in the block, in microcredits.
+    pub transaction_fees: u64,
+}
+
+/// The outcome of a rewards simulation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedRewards<N: Network> {
+    /// The total supply at the end of the simulation, in microcredits.
+    pub total_supply: u64,
+    /// The stakers at the end of the simulation, as a map of `staker => (validator, microcredits)`.
+    pub stakers: IndexMap<Address<N>, (Address<N>, u64)>,
+    /// The block reward of each simulated block, in microcredits.
+    pub block_rewards: Vec<u64>,
+    /// The puzzle reward of each simulated block, in microcredits.
+    pub puzzle_rewards: Vec<u64>,
+}
+
+/// Simulates the rewards over the given `blocks`, starting from the block after genesis, and returns
+/// the total supply, stakers, and per-block rewards at the end of the simulation.
+///
+/// The `supply` is used as the starting supply in the reward formulas, and the `stake_distribution`
+/// is the initial map of `staker => (validator, microcredits)`. The coinbase target is held at the genesis
+/// coinbase target, as block timestamps are not simulated. The puzzle reward is assumed to be paid out
+/// in full to provers, while transaction fees are assumed to be burned by their payers.
+pub fn simulate_rewards<N: Network>(
+    supply: u64,
+    stake_distribution: IndexMap<Address<N>, (Address<N>, u64)>,
+    blocks: impl IntoIterator<Item = SimulatedBlock>,
+) -> Result<SimulatedRewards<N>> {
+    // Initialize the simulated state.
+    let mut total_supply = supply;
+    let mut stakers = stake_distribution;
+    let mut block_rewards = Vec::new();
+    let mut puzzle_rewards = Vec::new();
+
+    // Initialize the coinbase target and cumulative proof target.
+    let coinbase_target = N::GENESIS_COINBASE_TARGET;
+    let mut cumulative_proof_target = 0u64;
+
+    for (height, block) in (1u32..).zip(blocks) {
+        // Compute the coinbase reward.
+        let coinbase_reward = coinbase_reward(
+            height,
+            supply,
+            N::ANCHOR_HEIGHT,
+            N::BLOCK_TIME,
+            block.combined_proof_target,
+            cumulative_proof_target,
+            coinbase_target,
+        )?;
+        // Update the cumulative proof target, resetting it if the coinbase target is reached.
+        let next_cumulative_proof_target =
+            (cumulative_proof_target as u128).saturating_add(block.combined_proof_target);
+        cumulative_proof_target = match next_cumulative_proof_target >= coinbase_target as u128 {
+            true => 0,
+            false => u64::try_from(next_cumulative_proof_target)?,
+        };
+
+        // Compute the block reward and puzzle reward.
+        let block_reward = block_reward(supply, N::BLOCK_TIME, coinbase_reward, block.transaction_fees);
+        let puzzle_reward = puzzle_reward(coinbase_reward);
+
+        // Construct the committee from the stakers.
+        let mut members = IndexMap::<_, (u64, bool)>::new();
+        for (validator, microcredits) in stakers.values() {
+            let entry = members.entry(*validator).or_insert((0, true));
+            entry.0 = entry.0.saturating_add(*microcredits);
+        }
+        let committee = Committee::new(height as u64, members)?;
+
+        // Compute the updated stakers.
+        let next_stakers = staking_rewards(&stakers, &committee, block_reward);
+        // Compute the staking rewards that were paid out.
+        let paid_staking_rewards = next_stakers.iter().fold(0u64, |sum, (staker, (_, next_microcredits))| {
+            let microcredits = stakers.get(staker).map_or(0, |(_, microcredits)| *microcredits);
+            sum.saturating_add(next_microcredits.saturating_sub(microcredits))
+        });
+
+        // Update the total supply.
+        total_supply = total_supply
+            .saturating_add(paid_staking_rewards)
+            .saturating_add(puzzle_reward)
+            .saturating_sub(block.transaction_fees);
+
+        // Update the simulated state.
+        stakers = next_stakers;
+        block_rewards.push(block_reward);
+        puzzle_rewards.push(puzzle_reward);
+    }
+
+    Ok(SimulatedRewards { total_supply, stakers, block_rewards, puzzle_rewards })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_simulate_rewards() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee, where each validator is bonded to itself.
+        let committee = ledger_committee::test_helpers::sample_committee_for_round_and_size(1, 10, rng);
+        let stakers = committee
+            .members()
+            .iter()
+            .map(|(validator, (microcredits, _))| (*validator, (*validator, *microcredits)))
+            .collect::<IndexMap<_, _>>();
+        let total_stake = committee.total_stake();
+
+        // Simulate blocks without solutions or transaction fees.
+        let num_blocks = 10;
+        let supply = CurrentNetwork::STARTING_SUPPLY;
+        let outcome =
+            simulate_rewards::<CurrentNetwork>(supply, stakers.clone(), vec![SimulatedBlock::default(); num_blocks])
+                .unwrap();
+
+        // Ensure the rewards match the formulas.
+        let expected_block_reward = block_reward(supply, CurrentNetwork::BLOCK_TIME, 0, 0);
+        assert_eq!(outcome.block_rewards, vec![expected_block_reward; num_blocks]);
+        assert_eq!(outcome.puzzle_rewards, vec![0; num_blocks]);
+
+        // Ensure the staking rewards are reflected in the stakers and total supply.
+        let final_stake = outcome.stakers.values().map(|(_, microcredits)| *microcredits).sum::<u64>();
+        assert!(final_stake > total_stake);
+        assert!(final_stake - total_stake <= expected_block_reward * num_blocks as u64);
+        assert_eq!(outcome.total_supply, supply + (final_stake - total_stake));
+
+        // Ensure the simulation matches a manual computation for the first block.
+        let outcome = simulate_rewards::<CurrentNetwork>(supply, stakers.clone(), [SimulatedBlock::default()]).unwrap();
+        assert_eq!(outcome.stakers, staking_rewards(&stakers, &committee, expected_block_reward));
+    }
+
+    #[test]
+    fn test_simulate_rewards_with_solutions() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee, where each validator is bonded to itself.
+        let committee = ledger_committee::test_helpers::sample_committee_for_round_and_size(1, 10, rng);
+        let stakers = committee
+            .members()
+            .iter()
+            .map(|(validator, (microcredits, _))| (*validator, (*validator, *microcredits)))
+            .collect::<IndexMap<_, _>>();
+
+        // Simulate a block that reaches the coinbase target, with transaction fees.
+        let block = SimulatedBlock {
+            combined_proof_target: CurrentNetwork::GENESIS_COINBASE_TARGET as u128,
+            transaction_fees: 1_000_000,
+        };
+        let outcome =
+            simulate_rewards::<CurrentNetwork>(CurrentNetwork::STARTING_SUPPLY, stakers, [block, block]).unwrap();
+
+        // Ensure the coinbase reward is split between the block reward and puzzle reward.
+        let coinbase_reward = coinbase_reward(
+            1,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            block.combined_proof_target,
+            0,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap();
+        assert!(coinbase_reward > 0);
+        assert_eq!(outcome.puzzle_rewards[0], puzzle_reward(coinbase_reward));
+        assert_eq!(
+            outcome.block_rewards[0],
+            block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, coinbase_reward, 1_000_000)
+        );
+        // Ensure the cumulative proof target resets after the coinbase target is reached.
+        assert!(outcome.puzzle_rewards[1] > 0);
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Transaction<N> {
+    /// Returns an arbitrary execute or fee transaction.
+    /// Note: Deploy transactions are not sampled, as they require synthesizing the program's circuits.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        let transaction = match u.arbitrary::<bool>()? {
+            true => Self::from_execution(Execution::arbitrary(u)?, u.arbitrary()?),
+            false => Self::from_fee(Fee::arbitrary(u)?),
+        };
+        transaction.map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+}
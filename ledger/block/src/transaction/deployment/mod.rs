@@ -99,9 +99,9 @@ impl<N: Network> Deployment<N> {
         Ok(())
     }
 
-    /// Returns the size in bytes.
+    /// Returns the size in bytes, without allocating the full byte buffer.
     pub fn size_in_bytes(&self) -> Result<u64> {
-        Ok(u64::try_from(self.to_bytes_le()?.len())?)
+        Ok(u64::try_from(ByteCounter::count(self)?)?)
     }
 
     /// Returns the edition.
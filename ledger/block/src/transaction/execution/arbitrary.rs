@@ -0,0 +1,30 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Execution<N> {
+    /// Returns an arbitrary execution, within the maximum number of transitions.
+    /// Note: The execution is well-formed, but it does **not** have a proof.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        // Note: One transition is held back for the fee.
+        let num_transitions = u.int_in_range(1..=Transaction::<N>::MAX_TRANSITIONS - 1)?;
+        let transitions =
+            (0..num_transitions).map(|_| Transition::arbitrary(u)).collect::<::arbitrary::Result<Vec<_>>>()?;
+        let global_state_root = N::StateRoot::rand(&mut TestRng::from_seed(u.arbitrary()?));
+        Self::from(transitions.into_iter(), global_state_root, None).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod serialize;
 mod string;
@@ -52,9 +54,9 @@ impl<N: Network> Execution<N> {
         Ok(execution)
     }
 
-    /// Returns the size in bytes.
+    /// Returns the size in bytes, without allocating the full byte buffer.
     pub fn size_in_bytes(&self) -> Result<u64> {
-        Ok(u64::try_from(self.to_bytes_le()?.len())?)
+        Ok(u64::try_from(ByteCounter::count(self)?)?)
     }
 
     /// Returns the global state root.
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::{
+    program::{Future, Identifier, ProgramID, Record},
+    types::Group,
+};
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Fee<N> {
+    /// Returns an arbitrary `fee_private` or `fee_public` fee, with the input and output layout of `credits.aleo`.
+    /// Note: The fee is well-formed, but its input and output IDs are sampled, and it does **not** have a proof.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        let rng = &mut TestRng::from_seed(u.arbitrary()?);
+        let is_fee_public = u.arbitrary::<bool>()?;
+
+        // Sample the base fee, priority fee, and deployment or execution ID.
+        let base_fee = U64::<N>::new(u.arbitrary()?);
+        let priority_fee = U64::<N>::new(u.arbitrary()?);
+        let public_inputs = [
+            Plaintext::from(Literal::U64(base_fee)),
+            Plaintext::from(Literal::U64(priority_fee)),
+            Plaintext::from(Literal::Field(Field::rand(rng))),
+        ];
+        let public_inputs = public_inputs
+            .into_iter()
+            .map(|plaintext| Input::Public(Field::rand(rng), Some(plaintext)))
+            .collect::<Vec<_>>();
+
+        // Construct the inputs and output for the fee function.
+        let program_id = ProgramID::from_str("credits.aleo").map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+        let (function_name, inputs, output) = match is_fee_public {
+            true => {
+                // Construct the `fee_public` finalize arguments from the payer and the total amount.
+                let payer = Plaintext::from(Literal::Address(Address::rand(rng)));
+                let amount = Plaintext::from(Literal::U64(U64::new(base_fee.saturating_add(*priority_fee))));
+                let function_name =
+                    Identifier::from_str("fee_public").map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+                let future = Future::new(program_id, function_name, vec![
+                    Argument::Plaintext(payer),
+                    Argument::Plaintext(amount),
+                ]);
+                (function_name, public_inputs, Output::Future(Field::rand(rng), Some(future)))
+            }
+            false => {
+                // Construct the `fee_private` inputs, starting with the spent record.
+                let function_name =
+                    Identifier::from_str("fee_private").map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+                let inputs =
+                    std::iter::once(Input::Record(Field::rand(rng), Field::rand(rng))).chain(public_inputs).collect();
+                let record = Record::<N, Plaintext<N>>::arbitrary(u)?
+                    .encrypt_symmetric_unchecked(&Field::rand(rng))
+                    .map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+                (function_name, inputs, Output::Record(Field::rand(rng), Field::rand(rng), Some(record)))
+            }
+        };
+
+        // Construct the fee transition.
+        let transition =
+            Transition::new(program_id, function_name, inputs, vec![output], Group::rand(rng), Field::rand(rng))
+                .map_err(|_| ::arbitrary::Error::IncorrectFormat)?;
+        Self::from(transition, N::StateRoot::rand(rng), None).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+}
@@ -21,6 +21,8 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod merkle;
 mod serialize;
@@ -79,6 +81,36 @@ impl<N: Network> Transaction<N> {
     }
 }
 
+impl<N: Network> Transaction<N> {
+    /// Returns a new transaction with the given fee, reusing the deployment or execution of this transaction.
+    ///
+    /// This is used to re-issue an unconfirmed transaction with a higher priority fee (i.e. replace-by-fee).
+    /// The given fee must be bound to the same deployment or execution ID as this transaction.
+    pub fn replace_fee(&self, fee: Fee<N>) -> Result<Self> {
+        match self {
+            Self::Deploy(_, owner, deployment, _) => {
+                // Ensure the fee is for the same deployment.
+                ensure!(
+                    fee.deployment_or_execution_id()? == deployment.to_deployment_id()?,
+                    "Attempted to replace the fee of a deployment transaction with a fee for a different deployment"
+                );
+                // Construct the deployment transaction.
+                Self::from_deployment(*owner, *deployment.clone(), fee)
+            }
+            Self::Execute(_, execution, _) => {
+                // Ensure the fee is for the same execution.
+                ensure!(
+                    fee.deployment_or_execution_id()? == execution.to_execution_id()?,
+                    "Attempted to replace the fee of an execution transaction with a fee for a different execution"
+                );
+                // Construct the execution transaction.
+                Self::from_execution(execution.clone(), Some(fee))
+            }
+            Self::Fee(..) => bail!("Cannot replace the fee of a fee transaction"),
+        }
+    }
+}
+
 impl<N: Network> Transaction<N> {
     /// Returns `true` if the transaction is a deploy transaction.
     #[inline]
@@ -112,6 +144,32 @@ impl<N: Network> Transaction<N> {
     }
 }
 
+impl<N: Network> Transaction<N> {
+    /// Returns `true` if this transaction conflicts with the given transaction.
+    ///
+    /// Two transactions conflict if they cannot both be accepted into the ledger, which is the case if they
+    /// share a transition ID, spend the same serial number, or deploy the same program.
+    /// Mempool implementations may use this to detect when a transaction replaces another (i.e. replace-by-fee).
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        // Check if the transactions are the same.
+        if self.id() == other.id() {
+            return true;
+        }
+        // Check if the transactions deploy the same program.
+        if let (Some(deployment), Some(other_deployment)) = (self.deployment(), other.deployment()) {
+            if deployment.program_id() == other_deployment.program_id() {
+                return true;
+            }
+        }
+        // Check if the transactions share a transition ID.
+        if self.transition_ids().any(|transition_id| other.contains_transition(transition_id)) {
+            return true;
+        }
+        // Check if the transactions share a serial number.
+        self.serial_numbers().any(|serial_number| other.contains_serial_number(serial_number))
+    }
+}
+
 impl<N: Network> Transaction<N> {
     /// Returns `Some(owner)` if the transaction is a deployment. Otherwise, returns `None`.
     #[inline]
@@ -182,6 +240,11 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns the size in bytes, without allocating the full byte buffer.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(ByteCounter::count(self)?)?)
+    }
+
     /// Returns the transaction total fee.
     pub fn fee_amount(&self) -> Result<U64<N>> {
         match self {
@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Transition<N> {
+    /// Returns an arbitrary transition, within the maximum number of inputs and outputs.
+    /// Note: The transition ID is computed from its inputs and outputs, but the transition is **not** provable.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        let program_id = ProgramID::arbitrary(u)?;
+        let function_name = Identifier::arbitrary(u)?;
+
+        // Sample the inputs and outputs.
+        let num_inputs = u.int_in_range(0..=N::MAX_INPUTS)?;
+        let inputs = (0..num_inputs).map(|_| Input::arbitrary(u)).collect::<::arbitrary::Result<Vec<_>>>()?;
+        let num_outputs = u.int_in_range(0..=N::MAX_OUTPUTS)?;
+        let outputs = (0..num_outputs).map(|_| Output::arbitrary(u)).collect::<::arbitrary::Result<Vec<_>>>()?;
+
+        // Sample the transition public key and commitment.
+        let rng = &mut TestRng::from_seed(u.arbitrary()?);
+        let (tpk, tcm) = (Group::rand(rng), Field::rand(rng));
+
+        Self::new(program_id, function_name, inputs, outputs, tpk, tcm).map_err(|_| ::arbitrary::Error::IncorrectFormat)
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+impl<'a, N: Network> Arbitrary<'a> for Input<N> {
+    /// Returns an arbitrary transition input.
+    /// Note: The input is well-formed, but its ID is sampled and is **not** derived from its value.
+    fn arbitrary(u: &mut Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        let rng = &mut TestRng::from_seed(u.arbitrary()?);
+        match u.int_in_range(0..=4)? {
+            0 => Ok(Self::Constant(Field::rand(rng), u.arbitrary()?)),
+            1 => Ok(Self::Public(Field::rand(rng), u.arbitrary()?)),
+            2 => {
+                let ciphertext = match u.arbitrary::<bool>()? {
+                    true => Some(
+                        Plaintext::arbitrary(u)?
+                            .encrypt_symmetric(Field::rand(rng))
+                            .map_err(|_| ::arbitrary::Error::IncorrectFormat)?,
+                    ),
+                    false => None,
+                };
+                Ok(Self::Private(Field::rand(rng), ciphertext))
+            }
+            3 => Ok(Self::Record(Field::rand(rng), Field::rand(rng))),
+            _ => Ok(Self::ExternalRecord(Field::rand(rng))),
+        }
+    }
+}
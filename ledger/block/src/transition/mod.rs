@@ -18,6 +18,8 @@ pub use input::Input;
 pub mod output;
 pub use output::Output;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod merkle;
 mod serialize;
@@ -94,8 +96,7 @@ impl<N: Network> Transition<N> {
         let num_inputs = request.inputs().len();
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id =
-            N::hash_bhp1024(&(network_id, program_id.name(), program_id.network(), function_name).to_bits_le())?;
+        let function_id = N::hash_bhp1024(&(network_id, &program_id, function_name).to_bits_le())?;
 
         let inputs = request
             .input_ids()
@@ -295,6 +296,11 @@ impl<N: Network> Transition<N> {
     pub const fn tcm(&self) -> &Field<N> {
         &self.tcm
     }
+
+    /// Returns the size in bytes, without allocating the full byte buffer.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        Ok(u64::try_from(ByteCounter::count(self)?)?)
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod bytes;
 mod serialize;
 mod string;
@@ -96,6 +96,53 @@ impl<N: Network> Block<N> {
     }
 }
 
+impl<N: Network> Block<N> {
+    /// Ensures the block header is correct, with respect to the previous block hash, previous block header,
+    /// and current committee.
+    ///
+    /// This is a partial check that only uses the block hash, header, and authority, and does not check the
+    /// transactions, solutions, or ratifications of the block. It is intended for light clients and sync
+    /// pipelines that validate headers before the block bodies are available. A block that passes this check
+    /// must still pass `Block::verify` before it is added to the ledger.
+    pub fn verify_header_against(
+        &self,
+        previous_hash: N::BlockHash,
+        previous_header: &Header<N>,
+        current_committee: &Committee<N>,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        // Ensure the block hash is correct.
+        self.verify_hash(previous_header.height(), previous_hash)?;
+
+        // Ensure the block authority is correct.
+        let (expected_round, expected_height, expected_timestamp) =
+            self.verify_authority_for_header(previous_header.round(), previous_header.height(), current_committee)?;
+
+        // Ensure the round is correct.
+        ensure!(
+            self.round() == expected_round,
+            "Round is incorrect in block {expected_height} (found '{}', expected '{expected_round}')",
+            self.round()
+        );
+        // Ensure the timestamp is correct.
+        ensure!(
+            self.timestamp() == expected_timestamp,
+            "Timestamp is incorrect in block {expected_height} (found '{}', expected '{expected_timestamp}')",
+            self.timestamp()
+        );
+        // Ensure the subdag root is correct.
+        let expected_subdag_root = self.compute_subdag_root()?;
+        ensure!(
+            self.header.subdag_root() == expected_subdag_root,
+            "Subdag root is incorrect in block {expected_height} (found '{}', expected '{expected_subdag_root}')",
+            self.header.subdag_root()
+        );
+
+        // Ensure the block header is consistent with the previous block header.
+        self.header.verify_against(previous_header, current_timestamp)
+    }
+}
+
 impl<N: Network> Block<N> {
     /// Ensures the block hash is correct.
     fn verify_hash(&self, previous_height: u32, previous_hash: N::BlockHash) -> Result<(), Error> {
@@ -136,6 +183,30 @@ impl<N: Network> Block<N> {
         previous_round: u64,
         previous_height: u32,
         current_committee: &Committee<N>,
+    ) -> Result<(u64, u32, i64)> {
+        // Ensure the block authority is correct, with respect to the block header.
+        let expected = self.verify_authority_for_header(previous_round, previous_height, current_committee)?;
+
+        // Ensure the transmission IDs from the subdag correspond to the block.
+        if let Authority::Quorum(subdag) = &self.authority {
+            Self::check_subdag_transmissions(
+                subdag,
+                &self.solutions,
+                &self.transactions,
+                &self.aborted_transaction_ids,
+            )?;
+        }
+
+        // Return success.
+        Ok(expected)
+    }
+
+    /// Ensures the block authority is correct, without checking the transmissions in the block.
+    fn verify_authority_for_header(
+        &self,
+        previous_round: u64,
+        previous_height: u32,
+        current_committee: &Committee<N>,
     ) -> Result<(u64, u32, i64)> {
         #[cfg(not(any(test, feature = "test")))]
         ensure!(self.authority.is_quorum(), "The next block must be a quorum block");
@@ -194,13 +265,6 @@ impl<N: Network> Block<N> {
                     "Quorum block {expected_height} is authored by an unexpected leader (found: {}, expected: {expected_leader})",
                     subdag.leader_address()
                 );
-                // Ensure the transmission IDs from the subdag correspond to the block.
-                Self::check_subdag_transmissions(
-                    subdag,
-                    &self.solutions,
-                    &self.transactions,
-                    &self.aborted_transaction_ids,
-                )?;
             }
         }
 
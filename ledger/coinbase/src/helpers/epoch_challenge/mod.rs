@@ -48,6 +48,21 @@ impl<N: Network> EpochChallenge<N> {
         Ok(EpochChallenge { epoch_number, epoch_block_hash, epoch_polynomial, epoch_polynomial_evaluations })
     }
 
+    /// Initializes the epoch challenge for the epoch after the given block, if the next block starts a new epoch.
+    ///
+    /// The epoch challenge of an epoch is known as soon as the last block of the previous epoch is known,
+    /// so it may be precomputed before the first block of the epoch arrives.
+    pub fn for_next_block(latest_height: u32, latest_hash: N::BlockHash, degree: u32) -> Result<Option<Self>> {
+        // Compute the next block height.
+        let next_height = latest_height.checked_add(1).ok_or_else(|| anyhow!("The block height overflows"))?;
+        // Ensure the next block starts a new epoch.
+        if next_height % N::NUM_BLOCKS_PER_EPOCH != 0 {
+            return Ok(None);
+        }
+        // Construct the epoch challenge, where the epoch block hash is the hash of the latest block.
+        Self::new(next_height / N::NUM_BLOCKS_PER_EPOCH, latest_hash, degree).map(Some)
+    }
+
     /// Returns the epoch number for the solution.
     pub const fn epoch_number(&self) -> u32 {
         self.epoch_number
@@ -82,3 +97,33 @@ impl<N: Network> EpochChallenge<N> {
         degree.checked_add(1).ok_or_else(|| anyhow!("Epoch polynomial degree ({degree} + 1) overflows"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_for_next_block() {
+        let mut rng = TestRng::default();
+
+        let degree = (1 << 8) - 1;
+        let latest_hash: <CurrentNetwork as Network>::BlockHash = rng.gen();
+
+        // Ensure the epoch challenge is only known from the last block of an epoch.
+        for latest_height in [0, 1, CurrentNetwork::NUM_BLOCKS_PER_EPOCH - 2, CurrentNetwork::NUM_BLOCKS_PER_EPOCH] {
+            assert!(
+                EpochChallenge::<CurrentNetwork>::for_next_block(latest_height, latest_hash, degree).unwrap().is_none()
+            );
+        }
+        for epoch_number in [1, 2, 100] {
+            let latest_height = epoch_number * CurrentNetwork::NUM_BLOCKS_PER_EPOCH - 1;
+            let candidate =
+                EpochChallenge::<CurrentNetwork>::for_next_block(latest_height, latest_hash, degree).unwrap().unwrap();
+            assert_eq!(candidate, EpochChallenge::new(epoch_number, latest_hash, degree).unwrap());
+        }
+        assert!(EpochChallenge::<CurrentNetwork>::for_next_block(u32::MAX, latest_hash, degree).is_err());
+    }
+}
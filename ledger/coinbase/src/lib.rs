@@ -22,6 +22,15 @@ pub use helpers::*;
 mod hash;
 use hash::*;
 
+mod pool;
+pub use pool::*;
+
+mod solver;
+pub use solver::*;
+
+mod test_vectors;
+pub use test_vectors::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -112,6 +121,26 @@ impl<N: Network> CoinbasePuzzle<N> {
         nonce: u64,
         minimum_proof_target: Option<u64>,
     ) -> Result<ProverSolution<N>> {
+        match self.try_prove(epoch_challenge, address, nonce, minimum_proof_target)? {
+            Some(prover_solution) => Ok(prover_solution),
+            None => bail!(
+                "Prover solution was below the necessary proof target ({})",
+                minimum_proof_target.unwrap_or_default()
+            ),
+        }
+    }
+
+    /// Returns a prover solution to the coinbase puzzle, or `None` if the solution is below the minimum proof target.
+    ///
+    /// Note: The proof target is checked before the opening proof is computed, so a candidate below the
+    /// minimum proof target is cheaper to reject than to prove.
+    pub fn try_prove(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<Option<ProverSolution<N>>> {
         // Retrieve the coinbase proving key.
         let pk = match self {
             Self::Prover(coinbase_proving_key) => coinbase_proving_key,
@@ -133,11 +162,9 @@ impl<N: Network> CoinbasePuzzle<N> {
 
         // Check that the minimum target is met.
         if let Some(minimum_target) = minimum_proof_target {
-            let proof_target = partial_solution.to_target()?;
-            ensure!(
-                proof_target >= minimum_target,
-                "Prover solution was below the necessary proof target ({proof_target} < {minimum_target})"
-            );
+            if partial_solution.to_target()? < minimum_target {
+                return Ok(None);
+            }
         }
 
         let point = hash_commitment(&commitment)?;
@@ -154,7 +181,7 @@ impl<N: Network> CoinbasePuzzle<N> {
 
         debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
 
-        Ok(ProverSolution::new(partial_solution, proof))
+        Ok(Some(ProverSolution::new(partial_solution, proof)))
     }
 
     /// Returns `true` if the solutions are valid.
@@ -184,12 +211,28 @@ impl<N: Network> CoinbasePuzzle<N> {
         }
         lap!(timer, "Perform initial checks");
 
-        // Verify each prover solution.
-        if !cfg_iter!(solutions).all(|(_, solution)| {
-            solution.verify(self.coinbase_verifying_key(), epoch_challenge, proof_target).unwrap_or(false)
-        }) {
-            bail!("The solutions contain an invalid prover solution");
-        }
+        // Ensure each prover solution is non-hiding and meets the proof target.
+        // Note: These checks are inexpensive, and are performed on all solutions first,
+        // so that an invalid set of solutions is rejected before any pairings are computed.
+        cfg_iter!(solutions).try_for_each(|(commitment, solution)| {
+            ensure!(!solution.proof().is_hiding(), "The prover solution '{commitment}' has a hiding proof");
+            let target = solution.to_target()?;
+            ensure!(
+                target >= proof_target,
+                "The prover solution '{commitment}' does not meet the proof target ({target} < {proof_target})"
+            );
+            Ok(())
+        })?;
+        lap!(timer, "Check the proof targets");
+
+        // Verify each prover solution in parallel, aborting on the first invalid solution.
+        cfg_iter!(solutions).try_for_each(|(commitment, solution)| {
+            match solution.verify(self.coinbase_verifying_key(), epoch_challenge, proof_target) {
+                Ok(true) => Ok(()),
+                Ok(false) => bail!("The solutions contain an invalid prover solution '{commitment}'"),
+                Err(error) => bail!("The solutions contain an invalid prover solution '{commitment}' - {error}"),
+            }
+        })?;
         finish!(timer, "Verify each solution");
 
         Ok(())
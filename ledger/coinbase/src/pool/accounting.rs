@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The maximum pool fee, in basis points.
+const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+/// The accepted shares of a mining pool, weighted by the share target at which they were accepted.
+#[derive(Clone, Debug)]
+pub struct PoolShares<N: Network> {
+    /// The puzzle commitments of the accepted shares.
+    commitments: IndexSet<PuzzleCommitment<N>>,
+    /// The total weight of the accepted shares, for each worker.
+    weights: IndexMap<Address<N>, u128>,
+}
+
+impl<N: Network> PoolShares<N> {
+    /// Initializes an empty set of pool shares.
+    pub fn new() -> Self {
+        Self { commitments: Default::default(), weights: Default::default() }
+    }
+
+    /// Records a share that was verified at the given share target.
+    ///
+    /// Note: The caller is responsible for verifying the share with `Share::verify`.
+    pub fn insert(&mut self, share: &Share<N>, share_target: u64) -> Result<()> {
+        // Ensure the share has not been submitted before.
+        ensure!(
+            self.commitments.insert(share.commitment()),
+            "The share '{}' was already submitted",
+            share.commitment()
+        );
+        // Add the share target to the weight of the worker.
+        let weight = self.weights.entry(share.worker()).or_default();
+        *weight = weight.saturating_add(share_target as u128);
+        Ok(())
+    }
+
+    /// Returns the number of accepted shares.
+    pub fn len(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Returns `true` if there are no accepted shares.
+    pub fn is_empty(&self) -> bool {
+        self.commitments.is_empty()
+    }
+
+    /// Returns the total weight of the accepted shares of the given worker.
+    pub fn weight(&self, worker: &Address<N>) -> u128 {
+        self.weights.get(worker).copied().unwrap_or_default()
+    }
+
+    /// Returns the total weight of the accepted shares.
+    pub fn total_weight(&self) -> u128 {
+        self.weights.values().fold(0u128, |total, weight| total.saturating_add(*weight))
+    }
+
+    /// Returns the payout of each worker for the given reward, in proportion to their weight,
+    /// after the pool fee (in basis points) is deducted, along with the amount retained by the pool.
+    ///
+    /// Note: The amount retained by the pool is the fee, plus any remainder from rounding the payouts down.
+    pub fn payouts(&self, reward: u64, fee_basis_points: u16) -> Result<(IndexMap<Address<N>, u64>, u64)> {
+        ensure!(fee_basis_points <= MAX_FEE_BASIS_POINTS, "The pool fee exceeds {MAX_FEE_BASIS_POINTS} basis points");
+
+        // Compute the reward to distribute to the workers.
+        let fee = (reward as u128 * fee_basis_points as u128) / MAX_FEE_BASIS_POINTS as u128;
+        let distributable = reward as u128 - fee;
+
+        // Scale the weights down to 64 bits, so that `distributable * weight` does not overflow.
+        let total_weight = self.total_weight();
+        let shift = (u128::BITS - total_weight.leading_zeros()).saturating_sub(64);
+        let total_weight = total_weight >> shift;
+
+        // Compute the payout of each worker.
+        let mut payouts = IndexMap::with_capacity(self.weights.len());
+        let mut paid = 0u64;
+        if total_weight > 0 {
+            for (worker, weight) in &self.weights {
+                // Note: The payout is at most `distributable`, as `weight <= total_weight`.
+                let payout = u64::try_from(distributable * (weight >> shift) / total_weight)?;
+                paid = paid.saturating_add(payout);
+                payouts.insert(*worker, payout);
+            }
+        }
+        Ok((payouts, reward.saturating_sub(paid)))
+    }
+
+    /// Clears the accepted shares, i.e. after the pool pays out.
+    pub fn clear(&mut self) {
+        self.commitments.clear();
+        self.weights.clear();
+    }
+}
+
+impl<N: Network> Default for PoolShares<N> {
+    /// Initializes an empty set of pool shares.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::test_helpers::sample_puzzle;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_payouts() {
+        let mut rng = TestRng::default();
+
+        let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+        let pool_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+        let alice = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+        let bob = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+        let mut sample_share =
+            |worker| Share::new(worker, puzzle.prove(&epoch_challenge, pool_address, rng.next_u64(), None).unwrap());
+
+        // Accept one share from Alice and three shares from Bob, at the same share target.
+        let mut shares = PoolShares::new();
+        let alice_share = sample_share(alice);
+        shares.insert(&alice_share, 10).unwrap();
+        for _ in 0..3 {
+            shares.insert(&sample_share(bob), 10).unwrap();
+        }
+        assert_eq!(shares.len(), 4);
+        assert_eq!(shares.weight(&alice), 10);
+        assert_eq!(shares.weight(&bob), 30);
+        assert_eq!(shares.total_weight(), 40);
+
+        // Ensure a duplicate share is rejected.
+        assert!(shares.insert(&alice_share, 10).is_err());
+        assert_eq!(shares.total_weight(), 40);
+
+        // Ensure the reward is split in proportion to the weights, after the fee.
+        let (payouts, retained) = shares.payouts(1_000_003, 100).unwrap();
+        assert_eq!(payouts[&alice], 247_500);
+        assert_eq!(payouts[&bob], 742_502);
+        assert_eq!(retained, 10_001);
+        assert_eq!(payouts.values().sum::<u64>() + retained, 1_000_003);
+
+        // Ensure an invalid fee is rejected.
+        assert!(shares.payouts(1_000_003, MAX_FEE_BASIS_POINTS + 1).is_err());
+
+        // Ensure the pool retains the reward when there are no shares.
+        shares.clear();
+        assert!(shares.is_empty());
+        let (payouts, retained) = shares.payouts(1_000_003, 100).unwrap();
+        assert!(payouts.is_empty());
+        assert_eq!(retained, 1_000_003);
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod accounting;
+pub use accounting::PoolShares;
+
+use crate::{CoinbaseVerifyingKey, EpochChallenge, ProverSolution, PuzzleCommitment};
+use console::{account::Address, prelude::*};
+
+/// Returns the share target for a pool that expects `shares_per_solution` shares for each solution
+/// that meets the proof target.
+pub fn share_target(proof_target: u64, shares_per_solution: u64) -> Result<u64> {
+    ensure!(shares_per_solution > 0, "The number of shares per solution must be nonzero");
+    Ok((proof_target / shares_per_solution).max(1))
+}
+
+/// A share submitted to a mining pool, i.e. a prover solution for the pool address
+/// that meets the share target of the pool, but not necessarily the proof target of the network.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Share<N: Network> {
+    /// The address of the worker, to which the pool pays out.
+    worker: Address<N>,
+    /// The prover solution.
+    solution: ProverSolution<N>,
+}
+
+impl<N: Network> Share<N> {
+    /// Initializes a new share for the given worker.
+    pub const fn new(worker: Address<N>, solution: ProverSolution<N>) -> Self {
+        Self { worker, solution }
+    }
+
+    /// Returns the address of the worker.
+    pub const fn worker(&self) -> Address<N> {
+        self.worker
+    }
+
+    /// Returns the prover solution.
+    pub const fn solution(&self) -> &ProverSolution<N> {
+        &self.solution
+    }
+
+    /// Returns the puzzle commitment of the share.
+    pub const fn commitment(&self) -> PuzzleCommitment<N> {
+        self.solution.commitment()
+    }
+
+    /// Ensures the share is a valid prover solution for the pool address, which meets the share target.
+    pub fn verify(
+        &self,
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        pool_address: &Address<N>,
+        share_target: u64,
+    ) -> Result<()> {
+        // Ensure the solution is for the pool address.
+        ensure!(
+            self.solution.address() == *pool_address,
+            "The share '{}' is for address '{}', not the pool address '{pool_address}'",
+            self.commitment(),
+            self.solution.address()
+        );
+        // Ensure the solution is valid and meets the share target.
+        ensure!(
+            self.solution.verify(verifying_key, epoch_challenge, share_target)?,
+            "The share '{}' is not a valid prover solution",
+            self.commitment()
+        );
+        Ok(())
+    }
+
+    /// Returns `true` if the share also meets the given proof target, and may be submitted to the network.
+    pub fn is_solution(&self, proof_target: u64) -> Result<bool> {
+        Ok(self.solution.to_target()? >= proof_target)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::{CoinbasePuzzle, PuzzleConfig};
+    use console::network::Testnet3;
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a coinbase puzzle and epoch challenge, with a small degree.
+    pub(crate) fn sample_puzzle(rng: &mut TestRng) -> (CoinbasePuzzle<CurrentNetwork>, EpochChallenge<CurrentNetwork>) {
+        let degree = (1 << 8) - 1;
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(PuzzleConfig { degree }).unwrap();
+        let puzzle = CoinbasePuzzle::<CurrentNetwork>::trim(&srs, PuzzleConfig { degree }).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+        (puzzle, epoch_challenge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::test_helpers::sample_puzzle;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_share_target() {
+        assert_eq!(share_target(1 << 25, 1 << 5).unwrap(), 1 << 20);
+        assert_eq!(share_target(3, 1 << 5).unwrap(), 1);
+        assert!(share_target(1 << 25, 0).is_err());
+    }
+
+    #[test]
+    fn test_verify_share() {
+        let mut rng = TestRng::default();
+
+        let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+        let pool_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+        let worker = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+        let verifying_key = puzzle.coinbase_verifying_key();
+
+        for _ in 0..10 {
+            let solution = puzzle.prove(&epoch_challenge, pool_address, rng.next_u64(), None).unwrap();
+            let share = Share::new(worker, solution);
+            let target = solution.to_target().unwrap();
+
+            // Ensure the share is valid up to its own target.
+            assert!(share.verify(verifying_key, &epoch_challenge, &pool_address, target).is_ok());
+            assert!(share.verify(verifying_key, &epoch_challenge, &pool_address, target + 1).is_err());
+            assert!(share.is_solution(target).unwrap());
+            assert!(!share.is_solution(target + 1).unwrap());
+
+            // Ensure the share is invalid for another pool address.
+            assert!(share.verify(verifying_key, &epoch_challenge, &worker, target).is_err());
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
+
+/// A solver that proves candidates on the CPU, using the coinbase puzzle proving key.
+pub struct CpuSolver<N: Network> {
+    /// The coinbase puzzle.
+    puzzle: CoinbasePuzzle<N>,
+    /// The number of candidates proven.
+    num_candidates: AtomicU64,
+    /// The time the solver was initialized.
+    start: Instant,
+}
+
+impl<N: Network> CpuSolver<N> {
+    /// Initializes a new CPU solver for the given coinbase puzzle.
+    pub fn new(puzzle: CoinbasePuzzle<N>) -> Result<Self> {
+        // Ensure the coinbase puzzle contains the proving key.
+        puzzle.coinbase_proving_key()?;
+        Ok(Self { puzzle, num_candidates: AtomicU64::new(0), start: Instant::now() })
+    }
+
+    /// Returns the coinbase puzzle.
+    pub const fn puzzle(&self) -> &CoinbasePuzzle<N> {
+        &self.puzzle
+    }
+}
+
+impl<N: Network> PuzzleSolver<N> for CpuSolver<N> {
+    /// Returns a prover solution for the given nonce, or `None` if the solution is below the minimum proof target.
+    fn prove(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<Option<ProverSolution<N>>> {
+        let solution = self.puzzle.try_prove(epoch_challenge, address, nonce, minimum_proof_target);
+        self.num_candidates.fetch_add(1, Ordering::Relaxed);
+        solution
+    }
+
+    /// Returns the number of candidates proven per second, since the solver was initialized.
+    fn hash_rate(&self) -> f64 {
+        let elapsed = self.start.elapsed().as_secs_f64();
+        match elapsed > 0.0 {
+            true => self.num_candidates.load(Ordering::Relaxed) as f64 / elapsed,
+            false => 0.0,
+        }
+    }
+}
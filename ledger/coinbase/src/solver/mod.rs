@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod cpu;
+pub use cpu::CpuSolver;
+
+use crate::{CoinbasePuzzle, EpochChallenge, ProverSolution};
+use console::{account::Address, prelude::*};
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// A token to cooperatively cancel a puzzle solver, which may be cloned and shared across threads.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Initializes a new cancellation token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels every solver holding this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the token is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The progress of a puzzle solver, as reported after each candidate.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SolverProgress {
+    /// The number of candidates tried.
+    pub iterations: u64,
+    /// The nonce of the latest candidate.
+    pub nonce: u64,
+    /// The minimum proof target that the candidates must meet.
+    pub minimum_proof_target: u64,
+    /// The time elapsed since the solver started.
+    pub elapsed: Duration,
+    /// The number of candidates tried per second, since the solver started.
+    pub hash_rate: f64,
+}
+
+/// A backend that solves the coinbase puzzle, i.e. on a CPU, GPU, or FPGA.
+pub trait PuzzleSolver<N: Network>: Send + Sync {
+    /// Returns a prover solution for the given nonce, or `None` if the solution is below the minimum proof target.
+    fn prove(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<Option<ProverSolution<N>>>;
+
+    /// Returns the number of candidates proven per second, since the solver was initialized.
+    fn hash_rate(&self) -> f64;
+
+    /// Returns the first prover solution that meets the minimum proof target, trying each nonce from `start_nonce`,
+    /// or `None` if the cancellation token is cancelled first.
+    fn solve(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        start_nonce: u64,
+        minimum_proof_target: u64,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Option<ProverSolution<N>>> {
+        self.solve_with_progress(
+            epoch_challenge,
+            address,
+            start_nonce,
+            minimum_proof_target,
+            cancellation_token,
+            &mut |_| {},
+        )
+    }
+
+    /// Returns the first prover solution that meets the minimum proof target, trying each nonce from `start_nonce`,
+    /// or `None` if the cancellation token is cancelled first.
+    ///
+    /// The `on_progress` callback is invoked after each candidate, before the cancellation token is checked,
+    /// so it may report the progress, throttle the solver (i.e. by sleeping), or cancel the token to stop the solver.
+    fn solve_with_progress(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        start_nonce: u64,
+        minimum_proof_target: u64,
+        cancellation_token: &CancellationToken,
+        on_progress: &mut dyn FnMut(&SolverProgress),
+    ) -> Result<Option<ProverSolution<N>>> {
+        let start = Instant::now();
+        let mut iterations = 0u64;
+        let mut nonce = start_nonce;
+        while !cancellation_token.is_cancelled() {
+            let solution = self.prove(epoch_challenge, address, nonce, Some(minimum_proof_target))?;
+            iterations = iterations.saturating_add(1);
+
+            // Report the progress.
+            let elapsed = start.elapsed();
+            let hash_rate = match elapsed.as_secs_f64() > 0.0 {
+                true => iterations as f64 / elapsed.as_secs_f64(),
+                false => 0.0,
+            };
+            on_progress(&SolverProgress { iterations, nonce, minimum_proof_target, elapsed, hash_rate });
+
+            if solution.is_some() {
+                return Ok(solution);
+            }
+            nonce = nonce.wrapping_add(1);
+        }
+        Ok(None)
+    }
+}
+
+impl<N: Network> CoinbasePuzzle<N> {
+    /// Returns a CPU solver for the coinbase puzzle.
+    pub fn to_cpu_solver(&self) -> Result<CpuSolver<N>> {
+        CpuSolver::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PuzzleConfig;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_solve() {
+        let mut rng = TestRng::default();
+
+        let degree = (1 << 8) - 1;
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(PuzzleConfig { degree }).unwrap();
+        let puzzle = CoinbasePuzzle::<CurrentNetwork>::trim(&srs, PuzzleConfig { degree }).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+
+        let solver: Box<dyn PuzzleSolver<CurrentNetwork>> = Box::new(puzzle.to_cpu_solver().unwrap());
+
+        // Ensure the solver finds a solution that meets the minimum proof target.
+        let minimum_proof_target = 4;
+        let solution = solver
+            .solve(&epoch_challenge, address, rng.next_u64(), minimum_proof_target, &CancellationToken::new())
+            .unwrap()
+            .unwrap();
+        assert!(solution.to_target().unwrap() >= minimum_proof_target);
+        assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, minimum_proof_target).unwrap());
+        assert!(solver.hash_rate() > 0.0);
+
+        // Ensure a cancelled solver stops without a solution.
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+        let solution = solver.solve(&epoch_challenge, address, rng.next_u64(), u64::MAX, &cancellation_token).unwrap();
+        assert!(solution.is_none());
+    }
+
+    #[test]
+    fn test_solve_with_progress() {
+        let mut rng = TestRng::default();
+
+        let degree = (1 << 8) - 1;
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(PuzzleConfig { degree }).unwrap();
+        let puzzle = CoinbasePuzzle::<CurrentNetwork>::trim(&srs, PuzzleConfig { degree }).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+        let solver = puzzle.to_cpu_solver().unwrap();
+
+        // Ensure the solver reports each candidate, and stops once the token is cancelled by the callback.
+        let start_nonce = rng.next_u64();
+        let cancellation_token = CancellationToken::new();
+        let mut reports = Vec::new();
+        let solution = solver
+            .solve_with_progress(
+                &epoch_challenge,
+                address,
+                start_nonce,
+                u64::MAX,
+                &cancellation_token,
+                &mut |progress| {
+                    reports.push(*progress);
+                    if progress.iterations == 5 {
+                        cancellation_token.cancel();
+                    }
+                },
+            )
+            .unwrap();
+        assert!(solution.is_none());
+        assert_eq!(reports.len(), 5);
+        for (index, progress) in reports.iter().enumerate() {
+            assert_eq!(progress.iterations, index as u64 + 1);
+            assert_eq!(progress.nonce, start_nonce.wrapping_add(index as u64));
+            assert_eq!(progress.minimum_proof_target, u64::MAX);
+        }
+        assert!(reports.windows(2).all(|pair| pair[0].elapsed <= pair[1].elapsed));
+    }
+}
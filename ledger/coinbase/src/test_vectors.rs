@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CoinbasePuzzle, EpochChallenge, PartialSolution, ProverSolution};
+use console::{
+    account::{Address, PrivateKey},
+    prelude::*,
+};
+
+/// A test vector for the coinbase puzzle, with the expected outcome of verifying the solution.
+#[derive(Clone, Debug)]
+pub struct PuzzleTestVector<N: Network> {
+    /// A description of the test vector.
+    pub description: &'static str,
+    /// The prover solution.
+    pub solution: ProverSolution<N>,
+    /// The expected target of the solution.
+    pub expected_target: u64,
+    /// The proof target to verify the solution against.
+    pub proof_target: u64,
+    /// `true` if the solution is expected to be valid for the epoch challenge and proof target.
+    pub is_valid: bool,
+}
+
+/// A deterministic set of test vectors for the coinbase puzzle, under a single epoch challenge.
+#[derive(Clone, Debug)]
+pub struct PuzzleTestVectors<N: Network> {
+    /// The epoch challenge.
+    pub epoch_challenge: EpochChallenge<N>,
+    /// The test vectors.
+    pub vectors: Vec<PuzzleTestVector<N>>,
+}
+
+impl<N: Network> PuzzleTestVector<N> {
+    /// Returns `true` if verifying the solution with the given puzzle matches the expected outcome.
+    pub fn check(&self, puzzle: &CoinbasePuzzle<N>, epoch_challenge: &EpochChallenge<N>) -> bool {
+        let is_valid = matches!(
+            self.solution.verify(puzzle.coinbase_verifying_key(), epoch_challenge, self.proof_target),
+            Ok(true)
+        );
+        is_valid == self.is_valid && self.solution.to_target().ok() == Some(self.expected_target)
+    }
+}
+
+impl<N: Network> CoinbasePuzzle<N> {
+    /// Returns a deterministic set of test vectors for the given seed and epoch number, with `num_valid` valid solutions,
+    /// followed by one invalid solution of each kind (below the proof target, from another epoch, with a modified nonce,
+    /// with a modified address, and with a mismatched proof).
+    ///
+    /// The epoch block hash and prover addresses are sampled from the seed, and the degree is the given degree,
+    /// which must be supported by the puzzle.
+    pub fn test_vectors(
+        &self,
+        seed: u64,
+        epoch_number: u32,
+        degree: u32,
+        num_valid: usize,
+    ) -> Result<PuzzleTestVectors<N>> {
+        ensure!(num_valid > 0, "The number of valid test vectors must be nonzero");
+
+        // Initialize a deterministic RNG from the seed.
+        let rng = &mut TestRng::fixed(seed);
+        // Sample the epoch challenge.
+        let epoch_challenge = EpochChallenge::new(epoch_number, rng.gen(), degree)?;
+        // Sample the prover addresses.
+        let address = Address::try_from(PrivateKey::<N>::new(rng)?)?;
+        let other_address = Address::try_from(PrivateKey::<N>::new(rng)?)?;
+
+        // Returns a test vector for the given solution.
+        let vector = |description, solution: ProverSolution<N>, proof_target, is_valid| -> Result<_> {
+            Ok(PuzzleTestVector {
+                description,
+                expected_target: solution.to_target()?,
+                solution,
+                proof_target,
+                is_valid,
+            })
+        };
+
+        // Construct the valid solutions, each at exactly its own target.
+        let mut vectors = Vec::with_capacity(num_valid + 5);
+        for _ in 0..num_valid {
+            let solution = self.prove(&epoch_challenge, address, rng.gen(), None)?;
+            vectors.push(vector("valid", solution, solution.to_target()?, true)?);
+        }
+        let solution = self.prove(&epoch_challenge, address, rng.gen(), None)?;
+        let target = solution.to_target()?;
+
+        // Construct a solution below the proof target.
+        if let Some(proof_target) = target.checked_add(1) {
+            vectors.push(vector("below the proof target", solution, proof_target, false)?);
+        }
+
+        // Construct a solution for another epoch.
+        let other_epoch_challenge = EpochChallenge::new(epoch_number.wrapping_add(1), rng.gen(), degree)?;
+        let other_solution = self.prove(&other_epoch_challenge, address, rng.gen(), None)?;
+        vectors.push(vector("from another epoch", other_solution, 0, false)?);
+
+        // Construct a solution with a modified nonce.
+        let partial_solution = PartialSolution::new(address, solution.nonce().wrapping_add(1), solution.commitment());
+        vectors.push(vector("modified nonce", ProverSolution::new(partial_solution, *solution.proof()), 0, false)?);
+
+        // Construct a solution with a modified address.
+        let partial_solution = PartialSolution::new(other_address, solution.nonce(), solution.commitment());
+        vectors.push(vector("modified address", ProverSolution::new(partial_solution, *solution.proof()), 0, false)?);
+
+        // Construct a solution with the proof of another solution.
+        let partial_solution = PartialSolution::new(address, solution.nonce(), solution.commitment());
+        let mismatched_solution = ProverSolution::new(partial_solution, *vectors[0].solution.proof());
+        vectors.push(vector("mismatched proof", mismatched_solution, 0, false)?);
+
+        Ok(PuzzleTestVectors { epoch_challenge, vectors })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PuzzleConfig;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_test_vectors() {
+        let degree = (1 << 8) - 1;
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(PuzzleConfig { degree }).unwrap();
+        let puzzle = CoinbasePuzzle::<CurrentNetwork>::trim(&srs, PuzzleConfig { degree }).unwrap();
+
+        let test_vectors = puzzle.test_vectors(7, 3, degree, 3).unwrap();
+        assert_eq!(test_vectors.epoch_challenge.epoch_number(), 3);
+        assert_eq!(test_vectors.vectors.len(), 8);
+        assert_eq!(test_vectors.vectors.iter().filter(|vector| vector.is_valid).count(), 3);
+
+        // Ensure each test vector verifies as expected.
+        for vector in &test_vectors.vectors {
+            assert!(
+                vector.check(&puzzle, &test_vectors.epoch_challenge),
+                "Test vector '{}' failed",
+                vector.description
+            );
+        }
+
+        // Ensure the test vectors are deterministic.
+        let candidate = puzzle.test_vectors(7, 3, degree, 3).unwrap();
+        assert_eq!(test_vectors.epoch_challenge, candidate.epoch_challenge);
+        for (expected, candidate) in test_vectors.vectors.iter().zip_eq(&candidate.vectors) {
+            assert_eq!(expected.solution, candidate.solution);
+            assert_eq!(expected.expected_target, candidate.expected_target);
+        }
+
+        // Ensure another seed produces other test vectors.
+        let candidate = puzzle.test_vectors(8, 3, degree, 3).unwrap();
+        assert_ne!(test_vectors.epoch_challenge, candidate.epoch_challenge);
+    }
+}
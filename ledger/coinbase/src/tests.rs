@@ -83,6 +83,37 @@ fn test_prover_solution_minimum_target() {
     }
 }
 
+#[test]
+fn test_check_solutions_aborts_on_invalid_solution() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Generate the solutions.
+    let solutions = (0..8)
+        .map(|_| {
+            let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+    // Determine the minimum target of the solutions.
+    let minimum_target = solutions.iter().map(|solution| solution.to_target().unwrap()).min().unwrap();
+    let full_solution = CoinbaseSolution::new(solutions).unwrap();
+
+    // Ensure the solutions are valid for the minimum target.
+    assert!(puzzle.check_solutions(&full_solution, &epoch_challenge, minimum_target).is_ok());
+    // Ensure the solutions are invalid if any solution does not meet the proof target.
+    let error = puzzle.check_solutions(&full_solution, &epoch_challenge, minimum_target.saturating_add(1)).unwrap_err();
+    assert!(error.to_string().contains("does not meet the proof target"));
+}
+
 #[test]
 fn test_edge_case_for_degree() {
     let mut rng = rand::thread_rng();
@@ -47,6 +47,8 @@ pub struct Committee<N: Network> {
 }
 
 impl<N: Network> Committee<N> {
+    /// The minimum number of members that may be in a committee.
+    pub const MIN_COMMITTEE_SIZE: u16 = 3;
     /// The maximum number of members that may be in a committee.
     pub const MAX_COMMITTEE_SIZE: u16 = 200;
 
@@ -58,8 +60,12 @@ impl<N: Network> Committee<N> {
 
     /// Initializes a new `Committee` instance.
     pub fn new(starting_round: u64, members: IndexMap<Address<N>, (u64, bool)>) -> Result<Self> {
-        // Ensure there are at least 3 members.
-        ensure!(members.len() >= 3, "Committee must have at least 3 members");
+        // Ensure there are at least the minimum number of members.
+        ensure!(
+            members.len() >= Self::MIN_COMMITTEE_SIZE as usize,
+            "Committee must have at least {} members",
+            Self::MIN_COMMITTEE_SIZE
+        );
         // Ensure there are no more than the maximum number of members.
         ensure!(
             members.len() <= Self::MAX_COMMITTEE_SIZE as usize,
@@ -14,8 +14,19 @@
 
 use super::*;
 
+use std::collections::HashSet;
+
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns a candidate for the next block in the ledger, using a committed subdag and its transmissions.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "ledger.prepare_advance_to_next_quorum_block",
+            level = "info",
+            skip_all,
+            fields(round = subdag.anchor_round())
+        )
+    )]
     pub fn prepare_advance_to_next_quorum_block(
         &self,
         subdag: Subdag<N>,
@@ -45,6 +56,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Returns a candidate for the next block in the ledger.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(name = "ledger.prepare_advance_to_next_beacon_block", level = "info", skip_all)
+    )]
     pub fn prepare_advance_to_next_beacon_block<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -53,8 +68,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         candidate_transactions: Vec<Transaction<N>>,
         rng: &mut R,
     ) -> Result<Block<N>> {
-        // Currently, we do not support ratifications from the memory pool.
-        ensure!(candidate_ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
+        // Currently, we only support evidence ratifications from the memory pool.
+        ensure!(
+            candidate_ratifications.iter().all(Ratify::is_evidence),
+            "Only evidence ratifications are currently supported from the memory pool"
+        );
 
         // Retrieve the latest block as the previous block (for the next block).
         let previous_block = self.latest_block();
@@ -82,6 +100,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Adds the given block as the next block in the ledger.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "ledger.advance_to_next_block",
+            level = "info",
+            skip_all,
+            fields(block_height = block.height(), block_hash = %block.hash())
+        )
+    )]
     pub fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the current block.
         let mut current_block = self.current_block.write();
@@ -99,8 +126,18 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         // If the block is the start of a new epoch, or the epoch challenge has not been set, update the current epoch challenge.
         if block.height() % N::NUM_BLOCKS_PER_EPOCH == 0 || self.current_epoch_challenge.read().is_none() {
+            // Retrieve the precomputed epoch challenge, if it is for this epoch.
+            let epoch_challenge = match self.next_epoch_challenge.write().take() {
+                Some(challenge)
+                    if challenge.epoch_number() == block.height() / N::NUM_BLOCKS_PER_EPOCH
+                        && challenge.epoch_block_hash() == block.previous_hash() =>
+                {
+                    Some(challenge)
+                }
+                _ => self.get_epoch_challenge(block.height()).ok(),
+            };
             // Update the current epoch challenge.
-            self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
+            self.current_epoch_challenge.write().clone_from(&epoch_challenge);
         }
 
         Ok(())
@@ -119,6 +156,28 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         candidate_transactions: Vec<Transaction<N>>,
     ) -> Result<(Header<N>, Ratifications<N>, Option<CoinbaseSolution<N>>, Transactions<N>, Vec<N::TransactionID>)>
     {
+        // Select the valid candidate evidence, for distinct validators.
+        // Note: Evidence beyond the ejection limit is deferred, so the committee keeps its minimum size.
+        let max_ejections = self.max_evidence_ejections()?;
+        let mut evidence_authors = HashSet::new();
+        let candidate_ratifications = candidate_ratifications
+            .into_iter()
+            .filter(|ratify| match ratify {
+                Ratify::Evidence(evidence) => match self.check_evidence(evidence) {
+                    Ok(()) if evidence_authors.len() >= max_ejections => {
+                        warn!("Deferring evidence against '{}' - the ejection limit is reached", evidence.author());
+                        false
+                    }
+                    Ok(()) => evidence_authors.insert(evidence.author()),
+                    Err(e) => {
+                        warn!("Discarding invalid evidence against '{}' - {e}", evidence.author());
+                        false
+                    }
+                },
+                _ => true,
+            })
+            .collect::<Vec<_>>();
+
         // Construct the solutions.
         let (solutions, solutions_root, combined_proof_target) = match candidate_solutions.is_empty() {
             true => (None, Field::<N>::zero(), 0u128),
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Checks the given evidence is valid against the historical committee, that it has not already been applied,
+    /// and that its author is still a member of the latest committee.
+    pub fn check_evidence(&self, evidence: &Evidence<N>) -> Result<()> {
+        let round = evidence.round();
+
+        // Ensure the evidence has not already been applied.
+        if self.contains_evidence(evidence)? {
+            bail!(LedgerError::EvidenceExists { author: evidence.author().to_string(), round })
+        }
+
+        // Ensure the evidence is not from a future round.
+        let latest_round = self.latest_round();
+        if round > latest_round {
+            bail!(LedgerError::FutureEvidence { round, latest_round })
+        }
+
+        // Retrieve the committee for the round of the evidence.
+        let Some(committee) = self.get_committee_for_round(round)? else {
+            bail!(LedgerError::MissingCommittee { round })
+        };
+        // Ensure the evidence is valid against the committee.
+        evidence.verify(&committee)?;
+
+        // Ensure the author is a member of the latest committee.
+        let author = evidence.author();
+        if !self.latest_committee()?.is_committee_member(author) {
+            bail!(LedgerError::UnknownEvidenceAuthor { author: author.to_string() })
+        }
+        Ok(())
+    }
+
+    /// Returns the maximum number of validators that may be ejected by evidence in the next block,
+    /// such that the committee does not fall below its minimum size.
+    pub fn max_evidence_ejections(&self) -> Result<usize> {
+        let num_members = self.latest_committee()?.num_members();
+        Ok(num_members.saturating_sub(Committee::<N>::MIN_COMMITTEE_SIZE as usize))
+    }
+}
@@ -15,38 +15,67 @@
 use super::*;
 
 use rand::{rngs::StdRng, SeedableRng};
+use std::collections::HashSet;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "ledger.check_next_block",
+            level = "info",
+            skip_all,
+            fields(block_height = block.height(), block_hash = %block.hash())
+        )
+    )]
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
         let height = block.height();
 
         // Ensure the block hash does not already exist.
         if self.contains_block_hash(&block.hash())? {
-            bail!("Block hash '{}' already exists in the ledger", block.hash())
+            bail!(LedgerError::BlockHashExists { block_hash: block.hash().to_string() })
         }
 
         // Ensure the block height does not already exist.
         if self.contains_block_height(block.height())? {
-            bail!("Block height '{height}' already exists in the ledger")
+            bail!(LedgerError::BlockHeightExists { height })
         }
 
         // Ensure the solutions do not already exist.
         if let Some(solutions) = block.solutions() {
             for puzzle_commitment in solutions.puzzle_commitments() {
                 if self.contains_puzzle_commitment(puzzle_commitment)? {
-                    bail!("Puzzle commitment {puzzle_commitment} already exists in the ledger");
+                    bail!(LedgerError::PuzzleCommitmentExists { puzzle_commitment: puzzle_commitment.to_string() });
                 }
             }
         }
 
+        // Ensure the evidence is valid, and applies to distinct validators.
+        let mut evidence_authors = HashSet::new();
+        for ratify in block.ratifications().iter() {
+            if let Ratify::Evidence(evidence) = ratify {
+                self.check_evidence(evidence)?;
+                if !evidence_authors.insert(evidence.author()) {
+                    bail!(LedgerError::DuplicateEvidence { author: evidence.author().to_string() })
+                }
+            }
+        }
+        // Ensure the evidence does not shrink the committee below its minimum size.
+        let max_ejections = self.max_evidence_ejections()?;
+        if evidence_authors.len() > max_ejections {
+            bail!(LedgerError::TooManyEjections { num_ejections: evidence_authors.len(), max_ejections })
+        }
+
         // Ensure each transaction is well-formed and unique.
         // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
         let transactions = block.transactions().iter().collect::<Vec<_>>();
         let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
         cfg_iter!(transactions).zip(rngs).try_for_each(|(transaction, mut rng)| {
-            self.check_transaction_basic(*transaction, transaction.to_rejected_id()?, &mut rng)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
+            self.check_transaction_basic(*transaction, transaction.to_rejected_id()?, &mut rng).map_err(|error| {
+                // Keep the underlying error, so its code remains recoverable by downcasting.
+                let reason = error.to_string();
+                error.context(LedgerError::InvalidTransaction { transaction_id: transaction.id().to_string(), reason })
+            })
         })?;
 
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
@@ -16,6 +16,15 @@ use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given transaction is well-formed and unique.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "ledger.check_transaction_basic",
+            level = "debug",
+            skip_all,
+            fields(transaction_id = %transaction.id())
+        )
+    )]
     pub fn check_transaction_basic<R: CryptoRng + Rng>(
         &self,
         transaction: &Transaction<N>,
@@ -54,6 +54,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().contains_puzzle_commitment(puzzle_commitment)
     }
 
+    /// Returns `true` if the given evidence has already been applied.
+    pub fn contains_evidence(&self, evidence: &Evidence<N>) -> Result<bool> {
+        self.vm.block_store().contains_evidence(&evidence.author(), evidence.round())
+    }
+
     /* Transaction */
 
     /// Returns `true` if the given transaction ID exists.
@@ -25,6 +25,71 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.finalize_store().committee_store().get_committee_for_round(round)
     }
 
+    /// Returns the total stake of the committee at the start of each epoch in the given `epochs` range.
+    /// Epochs that have not yet started are omitted.
+    pub fn get_total_stake_history(&self, epochs: Range<u32>) -> Result<Vec<(u32, u64)>> {
+        // Retrieve the latest height.
+        let latest_height = self.latest_height();
+        // Initialize a vector for the total stake history.
+        let mut history = Vec::with_capacity(epochs.len());
+        for epoch in epochs {
+            // Compute the starting height of the epoch.
+            let height = match epoch.checked_mul(N::NUM_BLOCKS_PER_EPOCH) {
+                Some(height) if height <= latest_height => height,
+                _ => break,
+            };
+            // Retrieve the committee at the starting height of the epoch.
+            match self.get_committee(height)? {
+                Some(committee) => history.push((epoch, committee.total_stake())),
+                None => bail!("Missing committee for block {height} (epoch {epoch})"),
+            }
+        }
+        Ok(history)
+    }
+
+    /// Returns the stakers, as a map of `staker => (validator, microcredits)`, from the `credits.aleo/bonded` mapping.
+    pub fn get_stakers(&self) -> Result<IndexMap<Address<N>, (Address<N>, u64)>> {
+        // Retrieve the bonded mapping from storage.
+        let bonded_map = self
+            .vm
+            .finalize_store()
+            .get_mapping_confirmed(ProgramID::from_str("credits.aleo")?, Identifier::from_str("bonded")?)?;
+        // Convert the bonded map into stakers.
+        synthesizer::vm::bonded_map_into_stakers(bonded_map)
+    }
+
+    /// Returns the delegators bonded to the given `validator`, as a map of `delegator => microcredits`.
+    /// Note: The self-bond of the validator is not included.
+    pub fn get_delegators(&self, validator: &Address<N>) -> Result<IndexMap<Address<N>, u64>> {
+        Ok(self
+            .get_stakers()?
+            .into_iter()
+            .filter(|(staker, (staker_validator, _))| staker_validator == validator && staker != validator)
+            .map(|(staker, (_, microcredits))| (staker, microcredits))
+            .collect())
+    }
+
+    /// Returns the projected staking reward (in microcredits) for the given `staker`,
+    /// if the next block were to have the given `block_reward` under the latest committee.
+    /// If the address is not a staker, or is not eligible for a staking reward, this method returns `0`.
+    pub fn get_projected_staking_reward(&self, staker: &Address<N>, block_reward: u64) -> Result<u64> {
+        // Retrieve the bonded state of the staker.
+        let (validator, microcredits) = match self.get_stakers()?.get(staker) {
+            Some(state) => *state,
+            None => return Ok(0),
+        };
+        // Retrieve the latest committee.
+        let committee = self.latest_committee()?;
+        // Compute the next stake of the staker.
+        let stakers = IndexMap::from([(*staker, (validator, microcredits))]);
+        let next_stakers = staking_rewards(&stakers, &committee, block_reward);
+        // Return the difference in stake.
+        match next_stakers.get(staker) {
+            Some((_, next_microcredits)) => Ok(next_microcredits.saturating_sub(microcredits)),
+            None => bail!("Missing staker '{staker}' after computing the staking rewards"),
+        }
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.vm.block_store().get_state_root(block_height)
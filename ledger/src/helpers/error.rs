@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use synthesizer::{process::ProcessError, VMError};
+
+use thiserror::Error;
+
+/// An error raised while checking a block in the ledger, with a stable error code and machine-readable context.
+///
+/// The ledger APIs return `Result`; callers recover this type with `error.downcast_ref::<LedgerError>()`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum LedgerError {
+    #[error("Block hash '{block_hash}' already exists in the ledger")]
+    BlockHashExists { block_hash: String },
+
+    #[error("Block height '{height}' already exists in the ledger")]
+    BlockHeightExists { height: u32 },
+
+    #[error("Puzzle commitment {puzzle_commitment} already exists in the ledger")]
+    PuzzleCommitmentExists { puzzle_commitment: String },
+
+    #[error("Found duplicate evidence for a validator")]
+    DuplicateEvidence { author: String },
+
+    #[error("Evidence from round {round} is ahead of the latest round {latest_round}")]
+    FutureEvidence { round: u64, latest_round: u64 },
+
+    #[error("Missing the committee for round {round}")]
+    MissingCommittee { round: u64 },
+
+    #[error("Evidence author '{author}' is not a member of the latest committee")]
+    UnknownEvidenceAuthor { author: String },
+
+    #[error("Invalid transaction found in the transactions list: {reason}")]
+    InvalidTransaction { transaction_id: String, reason: String },
+
+    #[error("Evidence against '{author}' in round {round} has already been applied")]
+    EvidenceExists { author: String, round: u64 },
+
+    #[error("Found {num_ejections} evidence ejections, exceeding the maximum of {max_ejections}")]
+    TooManyEjections { num_ejections: usize, max_ejections: usize },
+}
+
+impl LedgerError {
+    /// Returns the stable error code.
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::BlockHashExists { .. } => 3000,
+            Self::BlockHeightExists { .. } => 3001,
+            Self::PuzzleCommitmentExists { .. } => 3002,
+            Self::DuplicateEvidence { .. } => 3003,
+            Self::FutureEvidence { .. } => 3004,
+            Self::MissingCommittee { .. } => 3005,
+            Self::UnknownEvidenceAuthor { .. } => 3006,
+            Self::InvalidTransaction { .. } => 3007,
+            Self::EvidenceExists { .. } => 3008,
+            Self::TooManyEjections { .. } => 3009,
+        }
+    }
+
+    /// Returns the transaction ID, if the error is specific to a transaction.
+    pub fn transaction_id(&self) -> Option<&str> {
+        match self {
+            Self::InvalidTransaction { transaction_id, .. } => Some(transaction_id),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the stable code of the outermost coded error in the given error, if one exists.
+///
+/// Ledger codes are in the `3xxx` range, VM codes in the `2xxx` range, and process codes in the `1xxx` range.
+pub fn error_code(error: &anyhow::Error) -> Option<u16> {
+    error.chain().find_map(|cause| {
+        if let Some(error) = cause.downcast_ref::<LedgerError>() {
+            Some(error.code())
+        } else if let Some(error) = cause.downcast_ref::<VMError>() {
+            Some(error.code())
+        } else {
+            cause.downcast_ref::<ProcessError>().map(ProcessError::code)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{Result, bail};
+
+    fn check_transaction() -> Result<()> {
+        bail!(VMError::MissingFee { transaction_id: "at1".to_string() })
+    }
+
+    #[test]
+    fn test_error_code() {
+        let error = check_transaction().unwrap_err();
+        assert_eq!(error_code(&error), Some(2013));
+
+        // Wrap the VM error as a ledger error.
+        let reason = error.to_string();
+        let error = error.context(LedgerError::InvalidTransaction { transaction_id: "at1".to_string(), reason });
+        assert_eq!(error_code(&error), Some(3007));
+        assert_eq!(
+            error.to_string(),
+            "Invalid transaction found in the transactions list: Transaction 'at1' is missing a fee (execution)"
+        );
+        assert_eq!(error.downcast_ref::<LedgerError>().and_then(LedgerError::transaction_id), Some("at1"));
+        assert_eq!(error.downcast_ref::<VMError>().map(VMError::code), Some(2013));
+
+        // Ensure uncoded errors have no code.
+        assert_eq!(error_code(&anyhow::anyhow!("Uncoded error")), None);
+    }
+}
@@ -15,5 +15,11 @@
 mod bft;
 pub use bft::*;
 
+mod error;
+pub use error::*;
+
+mod scan;
+pub use scan::*;
+
 mod supply;
 pub use supply::*;
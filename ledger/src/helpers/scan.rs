@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::RecordsFilter;
+use console::{
+    account::{GraphKey, ViewKey},
+    network::prelude::*,
+    program::{Ciphertext, Plaintext, Record},
+    types::Field,
+};
+use ledger_block::Block;
+
+use indexmap::IndexSet;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A record that belongs to an account, as `(commitment, record, is_spent)`.
+pub type ScannedRecord<N> = (Field<N>, Record<N, Plaintext<N>>, bool);
+
+/// A trait to find the records of an account in a sequence of blocks, without a ledger.
+pub trait ScanRecords<N: Network> {
+    /// Returns the records in the given blocks that belong to `self` and match the given filter, in block order.
+    ///
+    /// A record is spent if its tag (or, for the `Slow*` filters, its serial number) is an input in any of the
+    /// given blocks. To determine the spent status of every record, the blocks must include every block after
+    /// the records of the account were created.
+    fn scan(&self, blocks: &[Block<N>], filter: RecordsFilter<N>) -> Result<Vec<ScannedRecord<N>>>;
+}
+
+impl<N: Network> ScanRecords<N> for ViewKey<N> {
+    fn scan(&self, blocks: &[Block<N>], filter: RecordsFilter<N>) -> Result<Vec<ScannedRecord<N>>> {
+        // Derive the x-coordinate of the address corresponding to the view key.
+        let address_x_coordinate = self.to_address().to_x_coordinate();
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(self) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+
+        // Collect the tags, or the serial numbers, of the records that are spent in the given blocks.
+        let spent = match filter {
+            RecordsFilter::SlowSpent(_) | RecordsFilter::SlowUnspent(_) => {
+                blocks.iter().flat_map(Block::serial_numbers).copied().collect::<IndexSet<_>>()
+            }
+            _ => blocks.iter().flat_map(Block::tags).copied().collect::<IndexSet<_>>(),
+        };
+        // Collect the records in the given blocks.
+        let records = blocks.iter().flat_map(Block::records).collect::<Vec<_>>();
+
+        // Trial-decrypt the records in parallel.
+        let records = cfg_into_iter!(records)
+            .map(|(commitment, record)| {
+                scan_record(self, *commitment, record, &address_x_coordinate, sk_tag, &spent, filter)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(records.into_iter().flatten().collect())
+    }
+}
+
+impl<N: Network> ScanRecords<N> for [ViewKey<N>] {
+    /// Returns the records in the given blocks that belong to any of the view keys, such as the view keys
+    /// of the diversified accounts of a private key, in the order of the view keys.
+    fn scan(&self, blocks: &[Block<N>], filter: RecordsFilter<N>) -> Result<Vec<ScannedRecord<N>>> {
+        // Ensure the `Slow*` filters, which derive serial numbers with a single private key, are used with one view key.
+        if let RecordsFilter::SlowSpent(_) | RecordsFilter::SlowUnspent(_) = filter {
+            ensure!(self.len() <= 1, "The 'Slow*' records filters only support a single view key");
+        }
+        let mut records = Vec::new();
+        for view_key in self {
+            records.extend(view_key.scan(blocks, filter)?);
+        }
+        Ok(records)
+    }
+}
+
+/// Returns the given record, if it belongs to the given view key and matches the given filter.
+fn scan_record<N: Network>(
+    view_key: &ViewKey<N>,
+    commitment: Field<N>,
+    record: &Record<N, Ciphertext<N>>,
+    address_x_coordinate: &Field<N>,
+    sk_tag: Field<N>,
+    spent: &IndexSet<Field<N>>,
+    filter: RecordsFilter<N>,
+) -> Result<Option<ScannedRecord<N>>> {
+    // Skip the record if it does not belong to the account, before decrypting it.
+    if !record.is_owner_with_address_x_coordinate(view_key, address_x_coordinate) {
+        return Ok(None);
+    }
+    // Determine if the record is spent.
+    let is_spent = match filter {
+        RecordsFilter::All | RecordsFilter::Spent | RecordsFilter::Unspent => {
+            spent.contains(&Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?)
+        }
+        RecordsFilter::SlowSpent(private_key) | RecordsFilter::SlowUnspent(private_key) => {
+            spent.contains(&Record::<N, Plaintext<N>>::serial_number(private_key, commitment)?)
+        }
+    };
+    // Determine whether to return this record (or not), based on the filter.
+    let is_match = match filter {
+        RecordsFilter::All => true,
+        RecordsFilter::Spent | RecordsFilter::SlowSpent(_) => is_spent,
+        RecordsFilter::Unspent | RecordsFilter::SlowUnspent(_) => !is_spent,
+    };
+    match is_match {
+        true => Ok(Some((commitment, record.decrypt(view_key)?, is_spent))),
+        false => Ok(None),
+    }
+}
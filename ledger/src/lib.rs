@@ -35,6 +35,7 @@ mod helpers;
 pub use helpers::*;
 
 mod advance;
+mod check_evidence;
 mod check_next_block;
 mod check_transaction_basic;
 mod contains;
@@ -101,6 +102,8 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     coinbase_puzzle: CoinbasePuzzle<N>,
     /// The current epoch challenge.
     current_epoch_challenge: Arc<RwLock<Option<EpochChallenge<N>>>>,
+    /// The epoch challenge of the next epoch, if it has been precomputed.
+    next_epoch_challenge: Arc<RwLock<Option<EpochChallenge<N>>>>,
     /// The current committee.
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
@@ -165,6 +168,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             genesis_block: genesis_block.clone(),
             coinbase_puzzle: CoinbasePuzzle::<N>::load()?,
             current_epoch_challenge: Default::default(),
+            next_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
         };
@@ -231,6 +235,32 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the epoch challenge of the next epoch, if the latest block is the last block of the current epoch.
+    ///
+    /// The epoch challenge is computed once and cached, and is used when the first block of the next epoch is added.
+    /// As such, calling this method (i.e. from a background thread) after the last block of an epoch is added
+    /// precomputes the epoch challenge, so solvers do not stall at the epoch boundary.
+    pub fn next_epoch_challenge(&self) -> Result<Option<EpochChallenge<N>>> {
+        // Retrieve the latest block height and hash.
+        let (latest_height, latest_hash) = {
+            let block = self.current_block.read();
+            (block.height(), block.hash())
+        };
+        // Return the cached epoch challenge, if it follows the latest block.
+        if let Some(challenge) = self.next_epoch_challenge.read().as_ref() {
+            if challenge.epoch_block_hash() == latest_hash {
+                return Ok(Some(challenge.clone()));
+            }
+        }
+        // Compute the epoch challenge, if the next block starts a new epoch.
+        let challenge = EpochChallenge::for_next_block(latest_height, latest_hash, N::COINBASE_PUZZLE_DEGREE)?;
+        // Cache the epoch challenge.
+        if let Some(challenge) = &challenge {
+            *self.next_epoch_challenge.write() = Some(challenge.clone());
+        }
+        Ok(challenge)
+    }
+
     /// Returns the latest block.
     pub fn latest_block(&self) -> Block<N> {
         self.current_block.read().clone()
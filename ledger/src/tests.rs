@@ -14,6 +14,7 @@
 
 use crate::{
     test_helpers::{CurrentLedger, CurrentNetwork},
+    LedgerError,
     RecordsFilter,
 };
 use console::{
@@ -21,10 +22,14 @@ use console::{
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
 };
-use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_block::{ConfirmedTransaction, Evidence, Ratify, Rejected, Transaction};
+use ledger_committee::Committee;
+use ledger_narwhal::BatchHeader;
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use synthesizer::{program::Program, vm::VM};
 
+use indexmap::IndexSet;
+
 #[test]
 fn test_load() {
     let rng = &mut TestRng::default();
@@ -64,6 +69,17 @@ fn test_load_unchecked() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_next_epoch_challenge() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+    // Ensure the next epoch challenge is not known before the last block of the epoch.
+    assert!(ledger.next_epoch_challenge().unwrap().is_none());
+    assert_eq!(ledger.latest_epoch_challenge().unwrap(), ledger.get_epoch_challenge(0).unwrap());
+}
+
 #[test]
 fn test_state_path() {
     let rng = &mut TestRng::default();
@@ -80,6 +96,44 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_verify_header_against() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the genesis block.
+    let genesis = ledger.latest_block();
+
+    // Sample a transfer transaction.
+    let address = Address::try_from(&private_key).unwrap();
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+
+    // Construct the next block.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    // Retrieve the current timestamp.
+    let current_timestamp = time::OffsetDateTime::now_utc().unix_timestamp();
+
+    // Ensure the block header is valid, with respect to the genesis header.
+    let committee = ledger.latest_committee().unwrap();
+    block.verify_header_against(genesis.hash(), genesis.header(), &committee, current_timestamp).unwrap();
+    block.header().verify_against(genesis.header(), current_timestamp).unwrap();
+
+    // Ensure the block header is invalid, with respect to an incorrect previous block hash.
+    assert!(block.verify_header_against(block.hash(), genesis.header(), &committee, current_timestamp).is_err());
+    // Ensure the block header is invalid, with respect to an incorrect previous block header.
+    assert!(block.header().verify_against(block.header(), current_timestamp).is_err());
+
+    // Advance to the next block.
+    ledger.advance_to_next_block(&block).unwrap();
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -568,6 +622,161 @@ fn test_bond_and_unbond_validator() {
     assert!(!committee.is_committee_member(new_member_address));
 }
 
+#[test]
+fn test_staking_queries() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the genesis committee.
+    let committee = ledger.latest_committee().unwrap();
+
+    // Check that the genesis validators are the only stakers, and have no delegators.
+    let stakers = ledger.get_stakers().unwrap();
+    assert_eq!(stakers.len(), committee.members().len());
+    for (staker, (validator, microcredits)) in &stakers {
+        assert_eq!(staker, validator);
+        assert_eq!(*microcredits, committee.get_stake(*validator));
+        assert!(ledger.get_delegators(validator).unwrap().is_empty());
+    }
+
+    // Check the total stake history.
+    assert_eq!(ledger.get_total_stake_history(0..10).unwrap(), vec![(0, committee.total_stake())]);
+
+    // Sample a new account for the delegator.
+    let delegator_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let delegator_address = Address::try_from(&delegator_private_key).unwrap();
+
+    // Check that the delegator does not receive a projected staking reward.
+    assert_eq!(ledger.get_projected_staking_reward(&delegator_address, 1_000_000).unwrap(), 0);
+
+    // Fund the delegator.
+    let inputs = [Value::from_str(&format!("{delegator_address}")).unwrap(), Value::from_str("1000000000u64").unwrap()];
+    let transfer_transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transfer_block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer_transaction], rng)
+        .unwrap();
+    ledger.check_next_block(&transfer_block, rng).unwrap();
+    ledger.advance_to_next_block(&transfer_block).unwrap();
+
+    // Bond the delegator to the genesis validator.
+    let bond_amount = 10_000_000u64; // 10 credits.
+    let inputs =
+        [Value::from_str(&format!("{address}")).unwrap(), Value::from_str(&format!("{bond_amount}u64")).unwrap()];
+    let bond_public_transaction = ledger
+        .vm
+        .execute(&delegator_private_key, ("credits.aleo", "bond_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let bond_public_block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![bond_public_transaction], rng)
+        .unwrap();
+    ledger.check_next_block(&bond_public_block, rng).unwrap();
+    ledger.advance_to_next_block(&bond_public_block).unwrap();
+
+    // Check that the delegator is bonded to the genesis validator.
+    let delegators = ledger.get_delegators(&address).unwrap();
+    assert_eq!(delegators.len(), 1);
+    assert_eq!(delegators.get(&delegator_address), Some(&bond_amount));
+    assert_eq!(ledger.get_stakers().unwrap().get(&delegator_address), Some(&(address, bond_amount)));
+
+    // Check that the delegator does not receive a projected staking reward,
+    // as the genesis validator now holds more than 25% of the total stake.
+    let committee = ledger.latest_committee().unwrap();
+    assert!(committee.get_stake(address) > committee.total_stake() / 4);
+    assert_eq!(ledger.get_projected_staking_reward(&delegator_address, 1_000_000).unwrap(), 0);
+
+    // Check that the other validators receive a projected staking reward proportional to their stake.
+    for validator in committee.members().keys().filter(|validator| **validator != address) {
+        let expected =
+            (1_000_000u128 * committee.get_stake(*validator) as u128 / committee.total_stake() as u128) as u64;
+        assert_eq!(ledger.get_projected_staking_reward(validator, 1_000_000).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_evidence_ejects_validator() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the genesis committee.
+    let committee = ledger.latest_committee().unwrap();
+    assert!(committee.is_committee_member(address));
+    let stake = committee.get_stake(address);
+
+    // Sample an equivocation from the genesis validator, as two distinct batch headers for the same round.
+    let sample_evidence = |round: u64, rng: &mut TestRng| {
+        let mut sample = |timestamp| {
+            BatchHeader::new(&private_key, round, timestamp, IndexSet::new(), IndexSet::new(), IndexSet::new(), rng)
+                .unwrap()
+        };
+        let (first, second) = (sample(0), sample(1));
+        Evidence::new_equivocation(first, second).unwrap()
+    };
+    let evidence = sample_evidence(ledger.latest_round(), rng);
+    ledger.check_evidence(&evidence).unwrap();
+
+    // Ensure evidence from a future round is rejected.
+    assert!(ledger.check_evidence(&sample_evidence(ledger.latest_round() + 1, rng)).is_err());
+
+    // Construct a transaction for the next block.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+
+    // Construct the next block, with the evidence.
+    let ratifications = vec![Ratify::Evidence(Box::new(evidence.clone()))];
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, ratifications, vec![], vec![transaction], rng)
+        .unwrap();
+    assert_eq!(block.ratifications().iter().filter(|ratify| ratify.is_evidence()).count(), 1);
+
+    // Check that the next block is valid.
+    ledger.check_next_block(&block, rng).unwrap();
+    // Add the block to the ledger.
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Check that the validator is removed from the committee.
+    let committee = ledger.latest_committee().unwrap();
+    assert!(!committee.is_committee_member(address));
+    assert!(!ledger.get_stakers().unwrap().contains_key(&address));
+
+    // Check that the stake of the validator is unbonding.
+    let unbond_state = ledger
+        .vm
+        .finalize_store()
+        .get_value_confirmed(
+            ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("unbonding").unwrap(),
+            &Plaintext::from(Literal::Address(address)),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        synthesizer::vm::unbond_state_from_value(&unbond_state).unwrap(),
+        (stake, block.height() + synthesizer::vm::UNBONDING_PERIOD)
+    );
+
+    // Ensure the evidence cannot be applied again.
+    assert!(ledger.contains_evidence(&evidence).unwrap());
+    let error = ledger.check_evidence(&evidence).unwrap_err();
+    assert_eq!(error.downcast_ref::<LedgerError>().map(LedgerError::code), Some(3008));
+
+    // Ensure the ejection limit keeps the committee at its minimum size.
+    let num_members = ledger.latest_committee().unwrap().num_members();
+    assert_eq!(
+        ledger.max_evidence_ejections().unwrap(),
+        num_members - Committee::<CurrentNetwork>::MIN_COMMITTEE_SIZE as usize
+    );
+}
+
 #[test]
 fn test_aborted_transaction_indexing() {
     let rng = &mut TestRng::default();
@@ -828,3 +1037,65 @@ finalize foo2:
     assert!(ledger.vm.transaction_store().contains_transaction_id(&deployment_1_id).unwrap());
     assert!(ledger.vm.block_store().contains_rejected_or_aborted_transaction_id(&deployment_2_id).unwrap());
 }
+
+#[test]
+fn test_scan_records() {
+    use crate::ScanRecords;
+    use console::{
+        account::{GraphKey, ViewKey},
+        program::Record,
+    };
+
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address, .. } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Spend a record of the account, with a private transfer to itself.
+    let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+    let inputs =
+        [Value::Record(record), Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Scan the blocks of the ledger.
+    let sk_tag = GraphKey::try_from(&view_key).unwrap().sk_tag();
+    let blocks = ledger.get_blocks(0..ledger.latest_height() + 1).unwrap();
+    for filter in [
+        RecordsFilter::All,
+        RecordsFilter::Spent,
+        RecordsFilter::Unspent,
+        RecordsFilter::SlowSpent(private_key),
+        RecordsFilter::SlowUnspent(private_key),
+    ] {
+        // Ensure the scanned records match the records found by the ledger.
+        let expected = ledger.find_records(&view_key, filter).unwrap().collect::<indexmap::IndexMap<_, _>>();
+        let candidate = view_key.scan(&blocks, filter).unwrap();
+        assert_eq!(expected.len(), candidate.len());
+        for (commitment, record, is_spent) in candidate {
+            assert_eq!(expected.get(&commitment), Some(&record));
+            let tag = Record::<CurrentNetwork, Plaintext<_>>::tag(sk_tag, commitment).unwrap();
+            assert_eq!(is_spent, ledger.contains_tag(&tag).unwrap());
+        }
+    }
+    // Ensure the transfer spent exactly one record.
+    assert_eq!(view_key.scan(&blocks, RecordsFilter::Spent).unwrap().len(), 1);
+
+    // Ensure another account has no records.
+    let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(other_view_key.scan(&blocks, RecordsFilter::All).unwrap().is_empty());
+
+    // Ensure scanning with several view keys finds the records of each view key.
+    let view_keys = [other_view_key, view_key];
+    assert_eq!(
+        view_keys.scan(&blocks, RecordsFilter::All).unwrap(),
+        view_key.scan(&blocks, RecordsFilter::All).unwrap()
+    );
+    assert!(view_keys.scan(&blocks, RecordsFilter::SlowUnspent(private_key)).is_err());
+}
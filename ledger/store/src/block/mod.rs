@@ -25,7 +25,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
-    types::Field,
+    types::{Address, Field},
 };
 use ledger_authority::Authority;
 use ledger_block::{
@@ -34,6 +34,7 @@ use ledger_block::{
     Header,
     NumFinalizeSize,
     Ratifications,
+    Ratify,
     Rejected,
     Transaction,
     Transactions,
@@ -189,6 +190,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type SolutionsMap: for<'a> Map<'a, N::BlockHash, Option<CoinbaseSolution<N>>>;
     /// The mapping of `puzzle commitment` to `block height`.
     type PuzzleCommitmentsMap: for<'a> Map<'a, PuzzleCommitment<N>, u32>;
+    /// The mapping of (`evidence author`, `evidence round`) to `block height`.
+    type EvidenceMap: for<'a> Map<'a, (Address<N>, u64), u32>;
     /// The mapping of `block hash` to `[transaction ID]`.
     type TransactionsMap: for<'a> Map<'a, N::BlockHash, Vec<N::TransactionID>>;
     /// The mapping of `block hash` to `[aborted transaction ID]`.
@@ -229,6 +232,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn solutions_map(&self) -> &Self::SolutionsMap;
     /// Returns the puzzle commitments map.
     fn puzzle_commitments_map(&self) -> &Self::PuzzleCommitmentsMap;
+    /// Returns the evidence map.
+    fn evidence_map(&self) -> &Self::EvidenceMap;
     /// Returns the accepted transactions map.
     fn transactions_map(&self) -> &Self::TransactionsMap;
     /// Returns the aborted transaction IDs map.
@@ -264,6 +269,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().start_atomic();
         self.solutions_map().start_atomic();
         self.puzzle_commitments_map().start_atomic();
+        self.evidence_map().start_atomic();
         self.transactions_map().start_atomic();
         self.aborted_transaction_ids_map().start_atomic();
         self.rejected_or_aborted_transaction_id_map().start_atomic();
@@ -284,6 +290,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.ratifications_map().is_atomic_in_progress()
             || self.solutions_map().is_atomic_in_progress()
             || self.puzzle_commitments_map().is_atomic_in_progress()
+            || self.evidence_map().is_atomic_in_progress()
             || self.transactions_map().is_atomic_in_progress()
             || self.aborted_transaction_ids_map().is_atomic_in_progress()
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
@@ -304,6 +311,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().atomic_checkpoint();
         self.solutions_map().atomic_checkpoint();
         self.puzzle_commitments_map().atomic_checkpoint();
+        self.evidence_map().atomic_checkpoint();
         self.transactions_map().atomic_checkpoint();
         self.aborted_transaction_ids_map().atomic_checkpoint();
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
@@ -324,6 +332,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().clear_latest_checkpoint();
         self.solutions_map().clear_latest_checkpoint();
         self.puzzle_commitments_map().clear_latest_checkpoint();
+        self.evidence_map().clear_latest_checkpoint();
         self.transactions_map().clear_latest_checkpoint();
         self.aborted_transaction_ids_map().clear_latest_checkpoint();
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
@@ -344,6 +353,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().atomic_rewind();
         self.solutions_map().atomic_rewind();
         self.puzzle_commitments_map().atomic_rewind();
+        self.evidence_map().atomic_rewind();
         self.transactions_map().atomic_rewind();
         self.aborted_transaction_ids_map().atomic_rewind();
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
@@ -364,6 +374,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().abort_atomic();
         self.solutions_map().abort_atomic();
         self.puzzle_commitments_map().abort_atomic();
+        self.evidence_map().abort_atomic();
         self.transactions_map().abort_atomic();
         self.aborted_transaction_ids_map().abort_atomic();
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
@@ -384,6 +395,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().finish_atomic()?;
         self.solutions_map().finish_atomic()?;
         self.puzzle_commitments_map().finish_atomic()?;
+        self.evidence_map().finish_atomic()?;
         self.transactions_map().finish_atomic()?;
         self.aborted_transaction_ids_map().finish_atomic()?;
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
@@ -452,6 +464,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 }
             }
 
+            // Store the block evidence.
+            for ratify in block.ratifications().iter() {
+                if let Ratify::Evidence(evidence) = ratify {
+                    self.evidence_map().insert((evidence.author(), evidence.round()), block.height())?;
+                }
+            }
+
             // Store the transaction IDs.
             self.transactions_map().insert(block.hash(), block.transaction_ids().copied().collect())?;
 
@@ -507,6 +526,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             }
         };
 
+        // Retrieve the ratifications.
+        let ratifications = match self.ratifications_map().get_confirmed(block_hash)? {
+            Some(ratifications) => cow_to_cloned!(ratifications),
+            None => {
+                bail!("Failed to remove block: missing ratifications for block '{block_height}' ('{block_hash}')")
+            }
+        };
+
         // Retrieve the aborted transaction IDs.
         let aborted_transaction_ids = match self.get_block_aborted_transaction_ids(block_hash)? {
             Some(transaction_ids) => transaction_ids,
@@ -568,6 +595,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 }
             }
 
+            // Remove the block evidence.
+            for ratify in ratifications.iter() {
+                if let Ratify::Evidence(evidence) = ratify {
+                    self.evidence_map().remove(&(evidence.author(), evidence.round()))?;
+                }
+            }
+
             // Remove the transaction IDs.
             self.transactions_map().remove(block_hash)?;
 
@@ -1031,6 +1065,15 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 
     /// Stores the given block into storage.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "store.block.insert",
+            level = "debug",
+            skip_all,
+            fields(block_height = block.height())
+        )
+    )]
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the block tree.
         let mut tree = self.tree.write();
@@ -1049,6 +1092,10 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 
     /// Removes the last 'n' blocks from storage.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(name = "store.block.remove_last_n", level = "debug", skip_all, fields(n = n))
+    )]
     pub fn remove_last_n(&self, n: u32) -> Result<()> {
         // Ensure 'n' is non-zero.
         ensure!(n > 0, "Cannot remove zero blocks");
@@ -1312,6 +1359,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn contains_puzzle_commitment(&self, puzzle_commitment: &PuzzleCommitment<N>) -> Result<bool> {
         self.storage.puzzle_commitments_map().contains_key_confirmed(puzzle_commitment)
     }
+
+    /// Returns `true` if evidence against the given author in the given round exists.
+    pub fn contains_evidence(&self, author: &Address<N>, round: u64) -> Result<bool> {
+        self.storage.evidence_map().contains_key_confirmed(&(*author, round))
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
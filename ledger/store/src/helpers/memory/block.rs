@@ -19,7 +19,10 @@ use crate::{
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{
+    prelude::*,
+    types::{Address, Field},
+};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
@@ -47,6 +50,8 @@ pub struct BlockMemory<N: Network> {
     solutions_map: MemoryMap<N::BlockHash, Option<CoinbaseSolution<N>>>,
     /// The puzzle commitments map.
     puzzle_commitments_map: MemoryMap<PuzzleCommitment<N>, u32>,
+    /// The evidence map.
+    evidence_map: MemoryMap<(Address<N>, u64), u32>,
     /// The transactions map.
     transactions_map: MemoryMap<N::BlockHash, Vec<N::TransactionID>>,
     /// The aborted transaction IDs map.
@@ -73,6 +78,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RatificationsMap = MemoryMap<N::BlockHash, Ratifications<N>>;
     type SolutionsMap = MemoryMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
     type PuzzleCommitmentsMap = MemoryMap<PuzzleCommitment<N>, u32>;
+    type EvidenceMap = MemoryMap<(Address<N>, u64), u32>;
     type TransactionsMap = MemoryMap<N::BlockHash, Vec<N::TransactionID>>;
     type AbortedTransactionIDsMap = MemoryMap<N::BlockHash, Vec<N::TransactionID>>;
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
@@ -99,6 +105,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             ratifications_map: MemoryMap::default(),
             solutions_map: MemoryMap::default(),
             puzzle_commitments_map: MemoryMap::default(),
+            evidence_map: MemoryMap::default(),
             transactions_map: MemoryMap::default(),
             aborted_transaction_ids_map: MemoryMap::default(),
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
@@ -158,6 +165,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.puzzle_commitments_map
     }
 
+    /// Returns the evidence map.
+    fn evidence_map(&self) -> &Self::EvidenceMap {
+        &self.evidence_map
+    }
+
     /// Returns the transactions map.
     fn transactions_map(&self) -> &Self::TransactionsMap {
         &self.transactions_map
@@ -25,7 +25,10 @@ use crate::{
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{
+    prelude::*,
+    types::{Address, Field},
+};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
@@ -53,6 +56,8 @@ pub struct BlockDB<N: Network> {
     solutions_map: DataMap<N::BlockHash, Option<CoinbaseSolution<N>>>,
     /// The puzzle commitments map.
     puzzle_commitments_map: DataMap<PuzzleCommitment<N>, u32>,
+    /// The evidence map.
+    evidence_map: DataMap<(Address<N>, u64), u32>,
     /// The transactions map.
     transactions_map: DataMap<N::BlockHash, Vec<N::TransactionID>>,
     /// The aborted transaction IDs map.
@@ -79,6 +84,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RatificationsMap = DataMap<N::BlockHash, Ratifications<N>>;
     type SolutionsMap = DataMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
     type PuzzleCommitmentsMap = DataMap<PuzzleCommitment<N>, u32>;
+    type EvidenceMap = DataMap<(Address<N>, u64), u32>;
     type TransactionsMap = DataMap<N::BlockHash, Vec<N::TransactionID>>;
     type AbortedTransactionIDsMap = DataMap<N::BlockHash, Vec<N::TransactionID>>;
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
@@ -105,6 +111,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             ratifications_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Ratifications))?,
             solutions_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Solutions))?,
             puzzle_commitments_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::PuzzleCommitments))?,
+            evidence_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Evidence))?,
             transactions_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Transactions))?,
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::AbortedTransactionIDs))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
@@ -164,6 +171,11 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.puzzle_commitments_map
     }
 
+    /// Returns the evidence map.
+    fn evidence_map(&self) -> &Self::EvidenceMap {
+        &self.evidence_map
+    }
+
     /// Returns the transactions map.
     fn transactions_map(&self) -> &Self::TransactionsMap {
         &self.transactions_map
@@ -82,6 +82,7 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    Evidence = DataID::BlockEvidenceMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -285,6 +286,7 @@ enum DataID {
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
     BFTTransmissionsMap,
+    BlockEvidenceMap,
 
     // Testing
     #[cfg(test)]
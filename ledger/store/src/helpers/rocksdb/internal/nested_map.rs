@@ -324,6 +324,11 @@ impl<
         if previous_atomic_depth == 1 {
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            #[cfg(feature = "metrics")]
+            {
+                metrics::histogram(metrics::store::BATCH_OPERATIONS, batch.len() as f64);
+                metrics::histogram(metrics::store::BATCH_BYTES, batch.size_in_bytes() as f64);
+            }
             // Execute all the operations atomically.
             self.database.rocksdb.write(batch)?;
             // Ensure that the database atomic batch is empty.
@@ -720,6 +720,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_mapping_names_confirmed(program_id)
     }
 
+    /// Returns the speculative mapping names for the given `program ID`.
+    pub fn get_mapping_names_speculative(&self, program_id: &ProgramID<N>) -> Result<Option<IndexSet<Identifier<N>>>> {
+        self.storage.get_mapping_names_speculative(program_id)
+    }
+
     /// Returns the confirmed mapping entries for the given `program ID` and `mapping name`.
     pub fn get_mapping_confirmed(
         &self,
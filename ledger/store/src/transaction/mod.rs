@@ -30,7 +30,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, ProgramID},
+    program::{Identifier, ProgramID, ProgramOwner},
 };
 use ledger_block::{Deployment, Execution, Transaction};
 use synthesizer_program::Program;
@@ -384,6 +384,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program_id(transaction_id)
     }
 
+    /// Returns the owner for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.deployment_store().get_owner(program_id)
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.deployment_store().get_program(program_id)
@@ -14,17 +14,72 @@
 
 #![forbid(unsafe_code)]
 
+const COUNTER_NAMES: [&str; 5] = [
+    vm::TRANSACTIONS_VERIFIED,
+    vm::DEPLOYMENT_CACHE_HITS,
+    vm::DEPLOYMENT_CACHE_MISSES,
+    process::CIRCUIT_KEY_CACHE_HITS,
+    process::CIRCUIT_KEY_CACHE_MISSES,
+];
+
 const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
 
+const HISTOGRAM_NAMES: [&str; 5] = [
+    vm::CHECK_TRANSACTION_SECONDS,
+    snark::PROVE_SECONDS,
+    snark::VERIFY_SECONDS,
+    store::BATCH_OPERATIONS,
+    store::BATCH_BYTES,
+];
+
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod process {
+    pub const CIRCUIT_KEY_CACHE_HITS: &str = "snarkvm_synthesizer_process_circuit_key_cache_hits";
+    pub const CIRCUIT_KEY_CACHE_MISSES: &str = "snarkvm_synthesizer_process_circuit_key_cache_misses";
+}
+
+pub mod snark {
+    pub const PROVE_SECONDS: &str = "snarkvm_synthesizer_snark_prove_seconds";
+    pub const VERIFY_SECONDS: &str = "snarkvm_synthesizer_snark_verify_seconds";
+}
+
+pub mod store {
+    pub const BATCH_OPERATIONS: &str = "snarkvm_ledger_store_batch_operations";
+    pub const BATCH_BYTES: &str = "snarkvm_ledger_store_batch_bytes";
+}
+
+pub mod vm {
+    pub const TRANSACTIONS_VERIFIED: &str = "snarkvm_synthesizer_vm_transactions_verified";
+    pub const CHECK_TRANSACTION_SECONDS: &str = "snarkvm_synthesizer_vm_check_transaction_seconds";
+    pub const DEPLOYMENT_CACHE_HITS: &str = "snarkvm_synthesizer_vm_deployment_cache_hits";
+    pub const DEPLOYMENT_CACHE_MISSES: &str = "snarkvm_synthesizer_vm_deployment_cache_misses";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
+    for name in COUNTER_NAMES {
+        register_counter(name);
+    }
     for name in GAUGE_NAMES {
         register_gauge(name);
     }
+    for name in HISTOGRAM_NAMES {
+        register_histogram(name);
+    }
+}
+
+/// Installs a Prometheus exporter, which serves all snarkVM metrics over HTTP at the given address.
+///
+/// Note: If a Tokio runtime is not available, the exporter spawns one on a background thread.
+pub fn install_prometheus_exporter(
+    address: std::net::SocketAddr,
+) -> Result<(), metrics_exporter_prometheus::BuildError> {
+    metrics_exporter_prometheus::PrometheusBuilder::new().with_http_listener(address).install()?;
+    register_metrics();
+    Ok(())
 }
 
 /******** Counter ********/
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Deref;
+
+/// The bytes of a universal SRS parameter file.
+///
+/// Parameter files that are bundled in the binary are borrowed in place, and parameter files that are
+/// stored on disk are memory-mapped, so that their pages are loaded by the OS on first access (and shared
+/// across processes) instead of being copied onto the heap.
+pub enum ParameterBytes {
+    /// The bytes of a parameter file that is bundled in the binary.
+    Static(&'static [u8]),
+    /// The bytes of a memory-mapped parameter file.
+    #[cfg(not(target_family = "wasm"))]
+    Mapped(memmap2::Mmap),
+    /// The bytes of a parameter file that is held in memory.
+    Owned(Vec<u8>),
+}
+
+impl ParameterBytes {
+    /// Memory-maps the parameter file at the given path.
+    #[cfg(not(target_family = "wasm"))]
+    #[allow(unsafe_code)]
+    pub fn map(file_path: &std::path::Path) -> Result<Self, crate::errors::ParameterError> {
+        let file = std::fs::File::open(file_path)?;
+        // Safety: Parameter files are only ever written in full, upon download, before they are mapped.
+        // The caller must verify the size and checksum of the mapped bytes before using them.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(Self::Mapped(mmap))
+    }
+
+    /// Returns `true` if the bytes are memory-mapped from a file.
+    pub fn is_mapped(&self) -> bool {
+        cfg_if::cfg_if! {
+            if #[cfg(not(target_family = "wasm"))] {
+                matches!(self, Self::Mapped(_))
+            } else {
+                false
+            }
+        }
+    }
+}
+
+impl Deref for ParameterBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Static(bytes) => bytes,
+            #[cfg(not(target_family = "wasm"))]
+            Self::Mapped(mmap) => mmap,
+            Self::Owned(bytes) => bytes,
+        }
+    }
+}
+
+impl AsRef<[u8]> for ParameterBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
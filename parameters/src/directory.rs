@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parking_lot::RwLock;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that overrides the directory the parameter files are loaded from and stored in.
+pub const DIRECTORY_ENV_VAR: &str = "SNARKVM_PARAMETERS_DIR";
+
+lazy_static! {
+    /// The directory the parameter files are loaded from and stored in, if it is overridden.
+    static ref DIRECTORY: RwLock<Option<PathBuf>> = RwLock::new(None);
+}
+
+/// The configuration of where the parameter files are loaded from.
+///
+/// By default, parameter files that are not bundled in the binary are loaded from (and downloaded to)
+/// the `resources` folder of the Aleo directory in the home directory, i.e. `~/.aleo/resources`.
+/// For containerized and air-gapped deployments, this can be overridden with `AleoParameters::set_directory`,
+/// or with the `SNARKVM_PARAMETERS_DIR` environment variable, in which case the parameter files are
+/// loaded from (and downloaded to) the given directory directly.
+pub struct AleoParameters;
+
+impl AleoParameters {
+    /// Sets the directory the parameter files are loaded from and stored in.
+    /// This takes precedence over the `SNARKVM_PARAMETERS_DIR` environment variable.
+    pub fn set_directory<P: Into<PathBuf>>(path: P) {
+        *DIRECTORY.write() = Some(path.into());
+    }
+
+    /// Resets the directory the parameter files are loaded from and stored in to the default.
+    pub fn reset_directory() {
+        *DIRECTORY.write() = None;
+    }
+
+    /// Returns the directory the parameter files are loaded from and stored in, if it is overridden.
+    pub fn directory() -> Option<PathBuf> {
+        resolve_directory(DIRECTORY.read().as_deref(), std::env::var_os(DIRECTORY_ENV_VAR).map(PathBuf::from))
+    }
+
+    /// Returns the path of the given parameter file, in the given folder of the Aleo directory,
+    /// or in the overridden directory if it is set.
+    pub fn file_path(local_dir: &str, filename: &str) -> PathBuf {
+        match Self::directory() {
+            Some(directory) => directory.join(filename),
+            None => {
+                let mut file_path = aleo_std::aleo_dir();
+                file_path.push(local_dir);
+                file_path.push(filename);
+                file_path
+            }
+        }
+    }
+}
+
+/// Returns the overridden directory, from the configured directory or the environment variable, in that order.
+fn resolve_directory(configured: Option<&Path>, environment: Option<PathBuf>) -> Option<PathBuf> {
+    match configured {
+        Some(directory) => Some(directory.to_path_buf()),
+        None => environment.filter(|directory| !directory.as_os_str().is_empty()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_directory() {
+        let configured = PathBuf::from("/mnt/parameters");
+        let environment = PathBuf::from("/opt/parameters");
+
+        assert_eq!(resolve_directory(None, None), None);
+        assert_eq!(resolve_directory(None, Some(PathBuf::new())), None);
+        assert_eq!(resolve_directory(None, Some(environment.clone())), Some(environment.clone()));
+        assert_eq!(resolve_directory(Some(&configured), None), Some(configured.clone()));
+        assert_eq!(resolve_directory(Some(&configured), Some(environment)), Some(configured));
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(not(feature = "wasm"))]
+use crate::errors::ParameterError;
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// The environment variable that lists additional mirrors for the parameter files, as a comma-separated list of URLs.
+pub const MIRRORS_ENV_VAR: &str = "SNARKVM_PARAMETER_MIRRORS";
+
+/// The progress of a parameter file download.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DownloadProgress {
+    /// The name of the parameter file.
+    pub filename: String,
+    /// The URL the parameter file is being downloaded from.
+    pub url: String,
+    /// The number of bytes downloaded so far, including any bytes from a resumed download.
+    pub downloaded: u64,
+    /// The total number of bytes in the parameter file.
+    pub total: u64,
+}
+
+impl DownloadProgress {
+    /// Returns the download progress as a percentage.
+    pub fn percent(&self) -> f64 {
+        match self.total {
+            0 => 0.0,
+            total => (self.downloaded as f64 / total as f64) * 100.0,
+        }
+    }
+}
+
+/// A callback that is invoked with the progress of a parameter file download.
+pub type ProgressCallback = Arc<dyn Fn(&DownloadProgress) + Send + Sync>;
+
+lazy_static! {
+    /// The mirrors that are tried, in order, before the default URL of a parameter file.
+    static ref MIRRORS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+    /// The callback that is invoked with the progress of a parameter file download.
+    static ref PROGRESS_CALLBACK: RwLock<Option<ProgressCallback>> = RwLock::new(None);
+}
+
+/// Sets the mirrors that are tried, in order, before the default URL of a parameter file.
+/// The mirrors take precedence over the mirrors in the `SNARKVM_PARAMETER_MIRRORS` environment variable.
+pub fn set_mirrors<S: Into<String>>(mirrors: impl IntoIterator<Item = S>) {
+    *MIRRORS.write() = mirrors.into_iter().map(Into::into).collect();
+}
+
+/// Sets the callback that is invoked with the progress of a parameter file download.
+/// If no callback is set, the progress is printed to stdout, unless `no_std_out` is enabled.
+pub fn set_progress_callback(callback: Option<ProgressCallback>) {
+    *PROGRESS_CALLBACK.write() = callback;
+}
+
+/// Returns the URLs to download the parameter files from, in order of preference:
+/// the configured mirrors, the mirrors in the `SNARKVM_PARAMETER_MIRRORS` environment variable,
+/// and finally the given default URL.
+pub fn mirror_urls(default_url: &str) -> Vec<String> {
+    let mut urls = MIRRORS.read().clone();
+    if let Ok(mirrors) = std::env::var(MIRRORS_ENV_VAR) {
+        urls.extend(mirrors.split(',').map(str::trim).filter(|mirror| !mirror.is_empty()).map(String::from));
+    }
+    urls.push(default_url.to_string());
+
+    // Remove any trailing slashes and duplicate URLs, preserving the order.
+    let mut unique_urls = Vec::with_capacity(urls.len());
+    for url in urls {
+        let url = url.trim_end_matches('/').to_string();
+        if !unique_urls.contains(&url) {
+            unique_urls.push(url);
+        }
+    }
+    unique_urls
+}
+
+/// Reports the progress of a parameter file download.
+#[cfg(not(feature = "wasm"))]
+fn report_progress(progress: &DownloadProgress) {
+    if let Some(callback) = PROGRESS_CALLBACK.read().as_ref() {
+        callback(progress);
+        return;
+    }
+
+    #[cfg(not(feature = "no_std_out"))]
+    {
+        use colored::*;
+
+        let size_in_megabytes = progress.total / 1_048_576;
+        let output = format!(
+            "\r{:>15} - {:.2}% complete ({:#} MB total)",
+            "Installation",
+            progress.percent(),
+            size_in_megabytes
+        );
+        print!("{}", output.dimmed());
+    }
+}
+
+/// Returns the path of the partially-downloaded parameter file, for the given file path.
+#[cfg(not(feature = "wasm"))]
+fn partial_path(file_path: &std::path::Path) -> std::path::PathBuf {
+    let mut path = file_path.as_os_str().to_owned();
+    path.push(".partial");
+    path.into()
+}
+
+/// Downloads the given parameter file from the mirrors of the given default URL, and stores it in the given file path.
+///
+/// The download is written to a `.partial` file next to the file path, and is resumed from it (with an HTTP range
+/// request) if it is interrupted, including when failing over to the next mirror. Once the size and checksum of
+/// the download are verified, the partial file is moved to the file path. If the partial file cannot be created,
+/// the parameter file is downloaded in memory instead, and is not stored.
+#[cfg(not(feature = "wasm"))]
+pub fn download(
+    default_url: &str,
+    filename: &str,
+    file_path: &std::path::Path,
+    expected_size: usize,
+    expected_checksum: &str,
+) -> Result<Vec<u8>, ParameterError> {
+    download_from(&mirror_urls(default_url), filename, file_path, expected_size, expected_checksum)
+}
+
+/// Downloads the given parameter file from the given mirrors, in order, and stores it in the given file path.
+#[cfg(not(feature = "wasm"))]
+fn download_from(
+    mirrors: &[String],
+    filename: &str,
+    file_path: &std::path::Path,
+    expected_size: usize,
+    expected_checksum: &str,
+) -> Result<Vec<u8>, ParameterError> {
+    // Ensure the folders up to the file path all exist.
+    if let Some(directory_path) = file_path.parent() {
+        let _ = std::fs::create_dir_all(directory_path);
+    }
+    let partial_path = partial_path(file_path);
+
+    let mut last_error = ParameterError::Message(format!("No mirrors are configured for \"{filename}\""));
+    for mirror in mirrors {
+        let url = format!("{mirror}/{filename}");
+
+        #[cfg(not(feature = "no_std_out"))]
+        {
+            use colored::*;
+            let output = format!("{:>15} - Downloading \"{}\"", "Installation", url);
+            println!("{}", output.dimmed());
+        }
+
+        // Download the parameter file, resuming from the partial file if possible.
+        let result = match std::fs::OpenOptions::new().create(true).append(true).open(&partial_path) {
+            Ok(mut file) => fetch_resumable(&mut file, &url, filename, expected_size as u64)
+                .and_then(|()| std::fs::read(&partial_path).map_err(Into::into)),
+            Err(_) => fetch(&url, filename),
+        };
+
+        let error = match result {
+            // Ensure the size and checksum match.
+            Ok(buffer) => match verify(&buffer, expected_size, expected_checksum) {
+                Ok(()) => {
+                    if partial_path.exists() && std::fs::rename(&partial_path, file_path).is_err() {
+                        eprintln!(
+                            "\n❗ Error - Failed to store \"{filename}\" locally. Please download this file manually and ensure it is stored in {file_path:?}.\n"
+                        );
+                    }
+                    return Ok(buffer);
+                }
+                Err(error) => {
+                    // Note: The partial file is corrupt, so it must not be resumed from.
+                    let _ = std::fs::remove_file(&partial_path);
+                    error
+                }
+            },
+            Err(error) => error,
+        };
+
+        eprintln!("\n⚠️  Failed to download \"{url}\": {error}\n");
+        last_error = error;
+    }
+    Err(last_error)
+}
+
+/// Ensures the size and checksum of the given buffer match the expected values.
+#[cfg(not(feature = "wasm"))]
+fn verify(buffer: &[u8], expected_size: usize, expected_checksum: &str) -> Result<(), ParameterError> {
+    if expected_size != buffer.len() {
+        return Err(ParameterError::SizeMismatch(expected_size, buffer.len()));
+    }
+    let candidate_checksum = checksum!(buffer);
+    if expected_checksum != candidate_checksum {
+        return checksum_error!(expected_checksum.to_string(), candidate_checksum);
+    }
+    Ok(())
+}
+
+/// Downloads the given URL, appending it to the given (partially-downloaded) file.
+#[cfg(not(feature = "wasm"))]
+fn fetch_resumable(
+    file: &mut std::fs::File,
+    url: &str,
+    filename: &str,
+    expected_size: u64,
+) -> Result<(), ParameterError> {
+    use std::io::Write;
+
+    let mut offset = file.metadata()?.len();
+    // If the partial file is larger than expected, restart the download.
+    if offset > expected_size {
+        file.set_len(0)?;
+        offset = 0;
+    }
+    // If the partial file is complete, there is nothing left to download.
+    if offset == expected_size {
+        return Ok(());
+    }
+
+    let mut easy = curl::easy::Easy::new();
+    easy.follow_location(true)?;
+    easy.fail_on_error(true)?;
+    easy.url(url)?;
+    easy.progress(true)?;
+    if offset > 0 {
+        easy.resume_from(offset)?;
+    }
+
+    let result = {
+        let mut transfer = easy.transfer();
+        transfer.progress_function(|total_download, current_download, _, _| {
+            report_progress(&DownloadProgress {
+                filename: filename.to_string(),
+                url: url.to_string(),
+                downloaded: offset + current_download as u64,
+                total: offset + total_download as u64,
+            });
+            true
+        })?;
+        transfer.write_function(|data| {
+            // Note: Returning fewer bytes than were received aborts the transfer.
+            Ok(match file.write_all(data) {
+                Ok(()) => data.len(),
+                Err(_) => 0,
+            })
+        })?;
+        transfer.perform()
+    };
+
+    match result {
+        Ok(()) => Ok(()),
+        // If the server does not support range requests, restart the download.
+        Err(error) if error.is_range_error() && offset > 0 => {
+            file.set_len(0)?;
+            fetch_resumable(file, url, filename, expected_size)
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Downloads the given URL in memory.
+#[cfg(not(feature = "wasm"))]
+fn fetch(url: &str, filename: &str) -> Result<Vec<u8>, ParameterError> {
+    let mut buffer = vec![];
+
+    let mut easy = curl::easy::Easy::new();
+    easy.follow_location(true)?;
+    easy.fail_on_error(true)?;
+    easy.url(url)?;
+    easy.progress(true)?;
+
+    let mut transfer = easy.transfer();
+    transfer.progress_function(|total_download, current_download, _, _| {
+        report_progress(&DownloadProgress {
+            filename: filename.to_string(),
+            url: url.to_string(),
+            downloaded: current_download as u64,
+            total: total_download as u64,
+        });
+        true
+    })?;
+    transfer.write_function(|data| {
+        buffer.extend_from_slice(data);
+        Ok(data.len())
+    })?;
+    transfer.perform()?;
+    drop(transfer);
+
+    Ok(buffer)
+}
+
+#[cfg(all(test, not(feature = "wasm")))]
+mod tests {
+    use super::*;
+
+    /// Returns a new, empty directory for testing.
+    fn test_directory(name: &str) -> std::path::PathBuf {
+        let directory = std::env::temp_dir().join(format!("snarkvm-parameters-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_mirror_urls() {
+        set_mirrors(["https://mirror-a.example/", "https://mirror-b.example"]);
+        let urls = mirror_urls("https://default.example");
+        set_mirrors(Vec::<String>::new());
+
+        assert_eq!(&urls[..2], &["https://mirror-a.example", "https://mirror-b.example"]);
+        assert_eq!(urls.last().unwrap(), "https://default.example");
+        // Ensure the configured mirrors are not repeated.
+        assert_eq!(urls.iter().filter(|url| url.contains("mirror-a")).count(), 1);
+    }
+
+    #[test]
+    fn test_download_resumes_and_fails_over() {
+        let remote = test_directory("remote");
+        let local = test_directory("local");
+
+        // Store the "remote" parameter file.
+        let filename = "parameters.test";
+        let bytes: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(remote.join(filename), &bytes).unwrap();
+        let checksum = checksum!(&bytes);
+
+        // Simulate an interrupted download.
+        let file_path = local.join(filename);
+        std::fs::write(partial_path(&file_path), &bytes[..40_000]).unwrap();
+
+        // Ensure the download fails over to the second mirror, and resumes from the partial file.
+        let mirrors =
+            vec![format!("file://{}", local.join("missing").display()), format!("file://{}", remote.display())];
+        let buffer = download_from(&mirrors, filename, &file_path, bytes.len(), &checksum).unwrap();
+        assert_eq!(buffer, bytes);
+        assert_eq!(std::fs::read(&file_path).unwrap(), bytes);
+        assert!(!partial_path(&file_path).exists());
+
+        // Ensure a corrupt partial file is discarded, and the download fails.
+        std::fs::remove_file(&file_path).unwrap();
+        std::fs::write(partial_path(&file_path), vec![0u8; 40_000]).unwrap();
+        assert!(download_from(&mirrors, filename, &file_path, bytes.len(), &checksum).is_err());
+        assert!(!partial_path(&file_path).exists());
+        assert!(!file_path.exists());
+
+        // Ensure the download succeeds from scratch.
+        let buffer = download_from(&mirrors, filename, &file_path, bytes.len(), &checksum).unwrap();
+        assert_eq!(buffer, bytes);
+
+        std::fs::remove_dir_all(remote).unwrap();
+        std::fs::remove_dir_all(local).unwrap();
+    }
+}
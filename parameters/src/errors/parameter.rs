@@ -25,6 +25,9 @@ pub enum ParameterError {
     #[error("{}", _0)]
     Message(String),
 
+    #[error("'{}' is only needed for proving, and is not loaded with the verifier parameter profile", _0)]
+    ProverParametersDisabled(String),
+
     #[error("Remote fetch is disabled, enable compiler flag for feature")]
     RemoteFetchDisabled,
 
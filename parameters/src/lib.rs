@@ -13,7 +13,7 @@
 // limitations under the License.
 
 #![allow(clippy::module_inception)]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 
 #[cfg(feature = "wasm")]
 #[macro_use]
@@ -27,6 +27,18 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+pub mod bytes;
+pub use bytes::*;
+
+pub mod directory;
+pub use directory::*;
+
+pub mod download;
+pub use download::*;
+
+pub mod profile;
+pub use profile::*;
+
 pub mod errors;
 pub use errors::*;
 
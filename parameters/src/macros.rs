@@ -43,64 +43,6 @@ macro_rules! remove_file {
 
 macro_rules! impl_store_and_remote_fetch {
     () => {
-        #[cfg(not(feature = "wasm"))]
-        fn store_bytes(buffer: &[u8], file_path: &std::path::Path) -> Result<(), $crate::errors::ParameterError> {
-            use snarkvm_utilities::Write;
-
-            #[cfg(not(feature = "no_std_out"))]
-            {
-                use colored::*;
-                let output = format!("{:>15} - Storing file in {:?}", "Installation", file_path);
-                println!("{}", output.dimmed());
-            }
-
-            // Ensure the folders up to the file path all exist.
-            let mut directory_path = file_path.to_path_buf();
-            directory_path.pop();
-            let _ = std::fs::create_dir_all(directory_path)?;
-
-            // Attempt to write the parameter buffer to a file.
-            match std::fs::File::create(file_path) {
-                Ok(mut file) => file.write_all(&buffer)?,
-                Err(error) => eprintln!("{}", error),
-            }
-            Ok(())
-        }
-
-        #[cfg(not(feature = "wasm"))]
-        fn remote_fetch(buffer: &mut Vec<u8>, url: &str) -> Result<(), $crate::errors::ParameterError> {
-            let mut easy = curl::easy::Easy::new();
-            easy.follow_location(true)?;
-            easy.url(url)?;
-
-            #[cfg(not(feature = "no_std_out"))]
-            {
-                use colored::*;
-
-                let output = format!("{:>15} - Downloading \"{}\"", "Installation", url);
-                println!("{}", output.dimmed());
-
-                easy.progress(true)?;
-                easy.progress_function(|total_download, current_download, _, _| {
-                    let percent = (current_download / total_download) * 100.0;
-                    let size_in_megabytes = total_download as u64 / 1_048_576;
-                    let output = format!(
-                        "\r{:>15} - {:.2}% complete ({:#} MB total)",
-                        "Installation", percent, size_in_megabytes
-                    );
-                    print!("{}", output.dimmed());
-                    true
-                })?;
-            }
-
-            let mut transfer = easy.transfer();
-            transfer.write_function(|data| {
-                buffer.extend_from_slice(data);
-                Ok(data.len())
-            })?;
-            Ok(transfer.perform()?)
-        }
-
         #[cfg(feature = "wasm")]
         fn remote_fetch(url: &str) -> Result<Vec<u8>, $crate::errors::ParameterError> {
             // Use the browser's XmlHttpRequest object to download the parameter file synchronously.
@@ -169,9 +111,7 @@ macro_rules! impl_load_bytes_logic_local {
 macro_rules! impl_load_bytes_logic_remote {
     ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
         // Compose the correct file path for the parameter file.
-        let mut file_path = aleo_std::aleo_dir();
-        file_path.push($local_dir);
-        file_path.push($filename);
+        let file_path = $crate::AleoParameters::file_path($local_dir, $filename);
 
         let buffer = if file_path.exists() {
             // Attempts to load the parameter file locally with an absolute path.
@@ -188,41 +128,28 @@ macro_rules! impl_load_bytes_logic_remote {
                 );
             }
 
-            // Construct the URL.
-            let url = format!("{}/{}", $remote_url, $filename);
-
             // Load remote file
             cfg_if::cfg_if! {
                 if #[cfg(not(feature = "wasm"))] {
-                    let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
-
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
-                    }
-
-                    match Self::store_bytes(&buffer, &file_path) {
-                        Ok(()) => buffer,
-                        Err(_) => {
-                            eprintln!(
-                                "\n❗ Error - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
-                                $filename, file_path
-                            );
-                            buffer
-                        }
-                    }
+                    // Download the file from the mirrors, resuming any interrupted download, and store it.
+                    $crate::download($remote_url, $filename, &file_path, $expected_size, &$expected_checksum)?
                 } else if #[cfg(feature = "wasm")] {
-                    let buffer = Self::remote_fetch(&url)?;
-
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
+                    // Download the file from the first mirror that responds with the expected bytes.
+                    let mut result = Err($crate::errors::ParameterError::RemoteFetchDisabled);
+                    for mirror in $crate::mirror_urls($remote_url) {
+                        result = Self::remote_fetch(&format!("{}/{}", mirror, $filename)).and_then(|buffer| {
+                            // Ensure the checksum matches.
+                            let candidate_checksum = checksum!(&buffer);
+                            if $expected_checksum != candidate_checksum {
+                                return checksum_error!($expected_checksum.clone(), candidate_checksum)
+                            }
+                            Ok(buffer)
+                        });
+                        if result.is_ok() {
+                            break;
+                        }
                     }
-
-                    buffer
+                    result?
                 } else {
                     return Err($crate::errors::ParameterError::RemoteFetchDisabled);
                 }
@@ -255,6 +182,11 @@ macro_rules! impl_local {
             pub const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                Self::load_mapped().map(|bytes| bytes.to_vec())
+            }
+
+            /// Returns the bundled bytes of the parameter file, without copying them.
+            pub fn load_mapped() -> Result<$crate::ParameterBytes, $crate::errors::ParameterError> {
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String =
@@ -262,10 +194,20 @@ macro_rules! impl_local {
                 let expected_size: usize =
                     metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
 
-                let _filepath = concat!($local_dir, $fname, ".", "usrs");
-                let buffer = include_bytes!(concat!($local_dir, $fname, ".", "usrs"));
+                let buffer: &'static [u8] = include_bytes!(concat!($local_dir, $fname, ".", "usrs"));
 
-                impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
+                // Ensure the size matches.
+                if expected_size != buffer.len() {
+                    return Err($crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
+                }
+
+                // Ensure the checksum matches.
+                let candidate_checksum = checksum!(buffer);
+                if expected_checksum != candidate_checksum {
+                    return checksum_error!(expected_checksum, candidate_checksum);
+                }
+
+                Ok($crate::ParameterBytes::Static(buffer))
             }
         }
 
@@ -274,6 +216,7 @@ macro_rules! impl_local {
             #[test]
             fn [< test_ $fname _usrs >]() {
                 assert!($name::load_bytes().is_ok());
+                assert!($name::load_mapped().is_ok());
             }
         }
     };
@@ -320,6 +263,9 @@ macro_rules! impl_remote {
             impl_store_and_remote_fetch!();
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                // Ensure the parameter file may be loaded with the current parameter profile.
+                $crate::AleoParameters::ensure_allowed($fname, "usrs")?;
+
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String =
@@ -342,6 +288,65 @@ macro_rules! impl_remote {
                     expected_size
                 );
             }
+
+            /// Returns the memory-mapped bytes of the parameter file, downloading it first if it does not exist.
+            pub fn load_mapped() -> Result<$crate::ParameterBytes, $crate::errors::ParameterError> {
+                cfg_if::cfg_if! {
+                    if #[cfg(not(target_family = "wasm"))] {
+                        // Ensure the parameter file may be loaded with the current parameter profile.
+                        $crate::AleoParameters::ensure_allowed($fname, "usrs")?;
+
+                        let metadata: serde_json::Value =
+                            serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                        let expected_checksum: String =
+                            metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                        let expected_size: usize =
+                            metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                        // Construct the versioned filename.
+                        let filename = match expected_checksum.get(0..7) {
+                            Some(sum) => format!("{}.{}.{}", $fname, "usrs", sum),
+                            _ => format!("{}.{}", $fname, "usrs"),
+                        };
+
+                        // Compose the correct file path for the parameter file.
+                        let file_path = $crate::AleoParameters::file_path($local_dir, &filename);
+
+                        // Download and store the parameter file, if it does not exist.
+                        if !file_path.exists() {
+                            let buffer = Self::load_bytes()?;
+                            // If the parameter file could not be stored, use the downloaded bytes directly.
+                            if !file_path.exists() {
+                                return Ok($crate::ParameterBytes::Owned(buffer));
+                            }
+                        }
+
+                        let bytes = $crate::ParameterBytes::map(&file_path)?;
+
+                        // Ensure the size matches.
+                        if expected_size != bytes.len() {
+                            let candidate_size = bytes.len();
+                            // Note: The file must be unmapped before it can be removed.
+                            drop(bytes);
+                            remove_file!(file_path);
+                            return Err($crate::errors::ParameterError::SizeMismatch(
+                                expected_size,
+                                candidate_size,
+                            ));
+                        }
+
+                        // Ensure the checksum matches.
+                        let candidate_checksum = checksum!(&*bytes);
+                        if expected_checksum != candidate_checksum {
+                            return checksum_error!(expected_checksum, candidate_checksum);
+                        }
+
+                        Ok(bytes)
+                    } else {
+                        Ok($crate::ParameterBytes::Owned(Self::load_bytes()?))
+                    }
+                }
+            }
         }
         paste::item! {
             #[cfg(test)]
@@ -360,6 +365,9 @@ macro_rules! impl_remote {
             impl_store_and_remote_fetch!();
 
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
+                // Ensure the parameter file may be loaded with the current parameter profile.
+                $crate::AleoParameters::ensure_allowed($fname, $ftype)?;
+
                 let metadata: serde_json::Value =
                     serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
                 let expected_checksum: String =
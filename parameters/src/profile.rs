@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{errors::ParameterError, AleoParameters};
+
+use parking_lot::RwLock;
+use std::str::FromStr;
+
+/// The environment variable that selects the parameter profile, i.e. `full` or `verifier`.
+pub const PROFILE_ENV_VAR: &str = "SNARKVM_PARAMETERS_PROFILE";
+
+/// The remote parameter files that are needed to verify proofs.
+const VERIFIER_REMOTE_FILES: &[&str] = &["powers-of-beta-15"];
+
+lazy_static! {
+    /// The parameter profile, if it is set.
+    static ref PROFILE: RwLock<Option<ParameterProfile>> = RwLock::new(None);
+}
+
+/// The set of parameter files that may be loaded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParameterProfile {
+    /// All parameter files may be loaded, for proving and verifying.
+    Full,
+    /// Only the verifying keys and the SRS elements needed for verification may be loaded.
+    /// The proving keys and the powers of beta G that are only needed for proving are never downloaded.
+    Verifier,
+}
+
+impl ParameterProfile {
+    /// Returns `true` if the profile only allows the parameter files needed for verification.
+    pub const fn is_verifier(&self) -> bool {
+        matches!(self, Self::Verifier)
+    }
+}
+
+impl Default for ParameterProfile {
+    /// Returns the `Verifier` profile if the `verifier_only` feature is enabled, and the `Full` profile otherwise.
+    fn default() -> Self {
+        match cfg!(feature = "verifier_only") {
+            true => Self::Verifier,
+            false => Self::Full,
+        }
+    }
+}
+
+impl FromStr for ParameterProfile {
+    type Err = ParameterError;
+
+    fn from_str(profile: &str) -> Result<Self, Self::Err> {
+        match profile.trim().to_lowercase().as_str() {
+            "full" => Ok(Self::Full),
+            "verifier" => Ok(Self::Verifier),
+            _ => Err(ParameterError::Message(format!("Unknown parameter profile '{profile}'"))),
+        }
+    }
+}
+
+impl AleoParameters {
+    /// Sets the parameter profile.
+    /// This takes precedence over the `SNARKVM_PARAMETERS_PROFILE` environment variable and the `verifier_only` feature.
+    pub fn set_profile(profile: ParameterProfile) {
+        *PROFILE.write() = Some(profile);
+    }
+
+    /// Returns the parameter profile, from the configured profile, the `SNARKVM_PARAMETERS_PROFILE`
+    /// environment variable, or the default profile, in that order.
+    pub fn profile() -> ParameterProfile {
+        if let Some(profile) = *PROFILE.read() {
+            return profile;
+        }
+        match std::env::var(PROFILE_ENV_VAR) {
+            Ok(profile) => profile.parse().unwrap_or_else(|error| {
+                eprintln!("{error}, using the default parameter profile");
+                ParameterProfile::default()
+            }),
+            Err(_) => ParameterProfile::default(),
+        }
+    }
+
+    /// Ensures the given remote parameter file may be loaded with the current parameter profile.
+    pub fn ensure_allowed(fname: &str, ftype: &str) -> Result<(), ParameterError> {
+        if Self::profile().is_verifier() && !is_verifier_file(fname, ftype) {
+            return Err(ParameterError::ProverParametersDisabled(format!("{fname}.{ftype}")));
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if the given remote parameter file is needed to verify proofs.
+fn is_verifier_file(fname: &str, ftype: &str) -> bool {
+    match ftype {
+        "usrs" => VERIFIER_REMOTE_FILES.contains(&fname),
+        "prover" => false,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_from_str() {
+        assert_eq!(ParameterProfile::from_str("full").unwrap(), ParameterProfile::Full);
+        assert_eq!(ParameterProfile::from_str(" Verifier ").unwrap(), ParameterProfile::Verifier);
+        assert!(ParameterProfile::from_str("prover").is_err());
+    }
+
+    #[test]
+    fn test_is_verifier_file() {
+        assert!(is_verifier_file("powers-of-beta-15", "usrs"));
+        assert!(is_verifier_file("inclusion", "verifier"));
+        assert!(!is_verifier_file("powers-of-beta-16", "usrs"));
+        assert!(!is_verifier_file("shifted-powers-of-beta-15", "usrs"));
+        assert!(!is_verifier_file("inclusion", "prover"));
+    }
+}
@@ -188,4 +188,22 @@ mod tests {
         InclusionProver::load_bytes().expect("Failed to load inclusion prover");
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifier");
     }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[test]
+    fn test_load_mapped() {
+        // Ensure the bundled powers are borrowed in place.
+        let bytes = Degree15::load_mapped().expect("Failed to load degree 15");
+        assert!(!bytes.is_mapped());
+        assert_eq!(&*bytes, Degree15::load_bytes().unwrap().as_slice());
+
+        // Ensure the remote powers are memory-mapped from disk, once they are stored.
+        Degree17::load_bytes().expect("Failed to load degree 17");
+        let bytes = Degree17::load_mapped().expect("Failed to load degree 17");
+        assert!(bytes.is_mapped());
+        assert_eq!(&*bytes, Degree17::load_bytes().unwrap().as_slice());
+
+        let bytes = ShiftedDegree17::load_mapped().expect("Failed to load shifted degree 17");
+        assert_eq!(&*bytes, ShiftedDegree17::load_bytes().unwrap().as_slice());
+    }
 }
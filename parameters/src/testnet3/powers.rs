@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use crate::ParameterBytes;
 use snarkvm_curves::traits::{PairingCurve, PairingEngine};
 use snarkvm_utilities::{
     CanonicalDeserialize,
@@ -50,11 +51,11 @@ const NUM_POWERS_28: usize = 1 << 28;
 const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
 lazy_static::lazy_static! {
-    static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
-    static ref SHIFTED_POWERS_OF_BETA_G_15: Vec<u8> = ShiftedDegree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
-    static ref POWERS_OF_BETA_GAMMA_G: Vec<u8> = Gamma::load_bytes().expect("Failed to load powers of beta wrt gamma * G in universal SRS");
-    static ref NEG_POWERS_OF_BETA_H: Vec<u8> = NegBeta::load_bytes().expect("Failed to load negative powers of beta in universal SRS");
-    static ref BETA_H: Vec<u8> = BetaH::load_bytes().expect("Failed to load negative powers of beta in universal SRS");
+    static ref POWERS_OF_BETA_G_15: ParameterBytes = Degree15::load_mapped().expect("Failed to load powers of beta in universal SRS");
+    static ref SHIFTED_POWERS_OF_BETA_G_15: ParameterBytes = ShiftedDegree15::load_mapped().expect("Failed to load powers of beta in universal SRS");
+    static ref POWERS_OF_BETA_GAMMA_G: ParameterBytes = Gamma::load_mapped().expect("Failed to load powers of beta wrt gamma * G in universal SRS");
+    static ref NEG_POWERS_OF_BETA_H: ParameterBytes = NegBeta::load_mapped().expect("Failed to load negative powers of beta in universal SRS");
+    static ref BETA_H: ParameterBytes = BetaH::load_mapped().expect("Failed to load negative powers of beta in universal SRS");
 }
 
 /// A vector of powers of beta G.
@@ -145,6 +146,43 @@ impl<E: PairingEngine> PowersOfG<E> {
     pub fn beta_h(&self) -> E::G2Affine {
         self.beta_h
     }
+
+    /// Loads the elements of the powers that are needed to verify proofs, without the powers of beta G.
+    pub fn load_verifier() -> Result<VerifierPowers<E>> {
+        // Deserialize the first power of beta G, skipping the length of the vector.
+        let bytes = POWERS_OF_BETA_G_15.get(std::mem::size_of::<u64>()..).ok_or_else(|| anyhow!("Missing powers"))?;
+        let g = E::G1Affine::deserialize_uncompressed_unchecked(bytes)?;
+
+        // Reconstruct powers of beta_times_gamma_g.
+        let powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine> =
+            BTreeMap::deserialize_uncompressed_unchecked(&**POWERS_OF_BETA_GAMMA_G)?;
+        let gamma_g = *powers_of_beta_times_gamma_g.get(&0).ok_or_else(|| anyhow!("Missing gamma * G"))?;
+
+        // Reconstruct negative powers of beta_h.
+        let negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine> =
+            BTreeMap::deserialize_uncompressed_unchecked(&**NEG_POWERS_OF_BETA_H)?;
+
+        // Compute the prepared negative powers of beta_h.
+        let prepared_negative_powers_of_beta_h =
+            Arc::new(negative_powers_of_beta_h.iter().map(|(d, affine)| (*d, affine.prepare())).collect());
+
+        let beta_h = E::G2Affine::deserialize_uncompressed_unchecked(&**BETA_H)?;
+
+        Ok(VerifierPowers { g, gamma_g, beta_h, prepared_negative_powers_of_beta_h })
+    }
+}
+
+/// The elements of the powers that are needed to verify proofs.
+#[derive(Clone, Debug)]
+pub struct VerifierPowers<E: PairingEngine> {
+    /// The first power of beta G, i.e. `G`.
+    pub g: E::G1Affine,
+    /// The first power of beta times gamma G, i.e. `gamma * G`.
+    pub gamma_g: E::G1Affine,
+    /// beta * h
+    pub beta_h: E::G2Affine,
+    /// The prepared negative powers of beta H, used to enforce degree bounds.
+    pub prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>>,
 }
 
 impl<E: PairingEngine> CanonicalSerialize for PowersOfG<E> {
@@ -400,21 +438,21 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             #[cfg(debug_assertions)]
             println!("Loading {num_powers} powers");
 
-            // Download the universal SRS powers if they're not already on disk.
+            // Download the universal SRS powers if they're not already on disk, and map them into memory.
             let additional_bytes = match *num_powers {
-                NUM_POWERS_16 => Degree16::load_bytes()?,
-                NUM_POWERS_17 => Degree17::load_bytes()?,
-                NUM_POWERS_18 => Degree18::load_bytes()?,
-                NUM_POWERS_19 => Degree19::load_bytes()?,
-                NUM_POWERS_20 => Degree20::load_bytes()?,
-                NUM_POWERS_21 => Degree21::load_bytes()?,
-                NUM_POWERS_22 => Degree22::load_bytes()?,
-                NUM_POWERS_23 => Degree23::load_bytes()?,
-                NUM_POWERS_24 => Degree24::load_bytes()?,
-                NUM_POWERS_25 => Degree25::load_bytes()?,
-                NUM_POWERS_26 => Degree26::load_bytes()?,
-                NUM_POWERS_27 => Degree27::load_bytes()?,
-                NUM_POWERS_28 => Degree28::load_bytes()?,
+                NUM_POWERS_16 => Degree16::load_mapped()?,
+                NUM_POWERS_17 => Degree17::load_mapped()?,
+                NUM_POWERS_18 => Degree18::load_mapped()?,
+                NUM_POWERS_19 => Degree19::load_mapped()?,
+                NUM_POWERS_20 => Degree20::load_mapped()?,
+                NUM_POWERS_21 => Degree21::load_mapped()?,
+                NUM_POWERS_22 => Degree22::load_mapped()?,
+                NUM_POWERS_23 => Degree23::load_mapped()?,
+                NUM_POWERS_24 => Degree24::load_mapped()?,
+                NUM_POWERS_25 => Degree25::load_mapped()?,
+                NUM_POWERS_26 => Degree26::load_mapped()?,
+                NUM_POWERS_27 => Degree27::load_mapped()?,
+                NUM_POWERS_28 => Degree28::load_mapped()?,
                 _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
             };
 
@@ -478,20 +516,20 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             #[cfg(debug_assertions)]
             println!("Loading {num_powers} shifted powers");
 
-            // Download the universal SRS powers if they're not already on disk.
+            // Download the universal SRS powers if they're not already on disk, and map them into memory.
             let additional_bytes = match *num_powers {
-                NUM_POWERS_16 => ShiftedDegree16::load_bytes()?,
-                NUM_POWERS_17 => ShiftedDegree17::load_bytes()?,
-                NUM_POWERS_18 => ShiftedDegree18::load_bytes()?,
-                NUM_POWERS_19 => ShiftedDegree19::load_bytes()?,
-                NUM_POWERS_20 => ShiftedDegree20::load_bytes()?,
-                NUM_POWERS_21 => ShiftedDegree21::load_bytes()?,
-                NUM_POWERS_22 => ShiftedDegree22::load_bytes()?,
-                NUM_POWERS_23 => ShiftedDegree23::load_bytes()?,
-                NUM_POWERS_24 => ShiftedDegree24::load_bytes()?,
-                NUM_POWERS_25 => ShiftedDegree25::load_bytes()?,
-                NUM_POWERS_26 => ShiftedDegree26::load_bytes()?,
-                NUM_POWERS_27 => ShiftedDegree27::load_bytes()?,
+                NUM_POWERS_16 => ShiftedDegree16::load_mapped()?,
+                NUM_POWERS_17 => ShiftedDegree17::load_mapped()?,
+                NUM_POWERS_18 => ShiftedDegree18::load_mapped()?,
+                NUM_POWERS_19 => ShiftedDegree19::load_mapped()?,
+                NUM_POWERS_20 => ShiftedDegree20::load_mapped()?,
+                NUM_POWERS_21 => ShiftedDegree21::load_mapped()?,
+                NUM_POWERS_22 => ShiftedDegree22::load_mapped()?,
+                NUM_POWERS_23 => ShiftedDegree23::load_mapped()?,
+                NUM_POWERS_24 => ShiftedDegree24::load_mapped()?,
+                NUM_POWERS_25 => ShiftedDegree25::load_mapped()?,
+                NUM_POWERS_26 => ShiftedDegree26::load_mapped()?,
+                NUM_POWERS_27 => ShiftedDegree27::load_mapped()?,
                 _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
             };
 
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ledger_query::QueryTrait;
+use synthesizer_snark::Proof;
+
+impl<N: Network> Process<N> {
+    /// Aggregates the given execution traces into a single proof.
+    ///
+    /// Returns the executions (without proofs) and one proof for all of their transitions,
+    /// which is verified with `Process::verify_aggregated_executions`.
+    /// Note: Every trace must be prepared with the same global state root.
+    #[inline]
+    pub fn aggregate_executions<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        traces: &[Trace<N>],
+        rng: &mut R,
+    ) -> Result<(Vec<Execution<N>>, Proof<N>)> {
+        let timer = timer!("Process::aggregate_executions");
+
+        // Ensure every transition is for a program in the process.
+        for transition in traces.iter().flat_map(|trace| trace.transitions()) {
+            ensure!(
+                self.contains_program(transition.program_id()),
+                "Program '{}' does not exist in the process",
+                transition.program_id()
+            );
+        }
+
+        // Compute the aggregate proof.
+        let locator = format!("{} aggregated executions", traces.len());
+        let (global_state_root, proof) = Trace::prove_aggregate_executions::<A, R>(&locator, traces, rng)?;
+        lap!(timer, "Compute the aggregate proof");
+
+        // Construct the executions, without proofs.
+        let executions = traces
+            .iter()
+            .map(|trace| Execution::from(trace.transitions().iter().cloned(), global_state_root, None))
+            .collect::<Result<Vec<_>>>()?;
+
+        finish!(timer);
+        Ok((executions, proof))
+    }
+
+    /// Executes the given function `num_iterations` times, and proves all of the iterations with one proof.
+    ///
+    /// The inputs to each iteration are computed by `next_inputs` from the response of the previous iteration
+    /// (or `None` for the first iteration), so that state can be threaded through a loop of calls.
+    /// Returns the responses, the executions (without proofs), and one proof for all of their transitions,
+    /// which is verified with `Process::verify_aggregated_executions`.
+    #[inline]
+    pub fn execute_iterated<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        num_iterations: usize,
+        mut next_inputs: impl FnMut(Option<&Response<N>>) -> Result<Vec<Value<N>>>,
+        query: impl QueryTrait<N> + Clone,
+        rng: &mut R,
+    ) -> Result<(Vec<Response<N>>, Vec<Execution<N>>, Proof<N>)> {
+        let timer = timer!("Process::execute_iterated");
+
+        // Ensure there is at least one iteration.
+        ensure!(num_iterations > 0, "Iterated execution requires at least one iteration");
+        // Prepare the program ID and function name.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+
+        let mut responses: Vec<Response<N>> = Vec::with_capacity(num_iterations);
+        let mut traces = Vec::with_capacity(num_iterations);
+        for _ in 0..num_iterations {
+            // Compute the inputs from the previous response.
+            let inputs = next_inputs(responses.last())?;
+            // Authorize and execute the iteration.
+            let authorization =
+                self.authorize::<A, R>(private_key, program_id, function_name, inputs.into_iter(), rng)?;
+            let (response, mut trace) = self.execute::<A, R>(authorization, rng)?;
+            // Prepare the trace.
+            trace.prepare(query.clone())?;
+
+            responses.push(response);
+            traces.push(trace);
+        }
+        lap!(timer, "Execute the iterations");
+
+        // Prove all of the iterations with one proof.
+        let (executions, proof) = self.aggregate_executions::<A, R>(&traces, rng)?;
+        lap!(timer, "Aggregate the executions");
+
+        finish!(timer);
+        Ok((responses, executions, proof))
+    }
+
+    /// Verifies the given executions against their aggregate proof.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_aggregated_executions(&self, executions: &[Execution<N>], proof: &Proof<N>) -> Result<()> {
+        let timer = timer!("Process::verify_aggregated_executions");
+
+        // Initialize a map of verifying keys to public inputs, across all executions.
+        let mut verifier_inputs: IndexMap<Locator<N>, (VerifyingKey<N>, Vec<Vec<N::Field>>)> = IndexMap::new();
+        for execution in executions {
+            // Ensure the execution does not contain its own proof.
+            ensure!(execution.proof().is_none(), "An aggregated execution must not contain a proof");
+            // Construct the verifier inputs for the execution.
+            let (_, inputs) = self.to_execution_verifier_inputs(execution)?;
+            // Append the verifier inputs for each locator.
+            for (locator, (verifying_key, inputs)) in inputs {
+                verifier_inputs.entry(locator).or_insert_with(|| (verifying_key, vec![])).1.extend(inputs);
+            }
+        }
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the aggregate proof.
+        let locator = format!("{} aggregated executions", executions.len());
+        let verifier_inputs = verifier_inputs.into_values().collect();
+        Trace::verify_aggregate_executions_proof(&locator, verifier_inputs, executions, proof)?;
+        lap!(timer, "Verify the proof");
+
+        finish!(timer);
+        Ok(())
+    }
+}
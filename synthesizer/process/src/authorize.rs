@@ -17,6 +17,7 @@ use super::*;
 impl<N: Network> Process<N> {
     /// Authorizes a call to the program function for the given inputs.
     #[inline]
+    #[cfg_attr(feature = "tracing-spans", tracing::instrument(name = "process.authorize", level = "debug", skip_all))]
     pub fn authorize<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -29,9 +30,29 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
     }
 
+    /// Authorizes a call to the program function for the given inputs, using the given signer for every request.
+    /// This allows an external signer, such as a hardware wallet, to authorize a call without exporting its private key.
+    #[inline]
+    #[cfg_attr(feature = "tracing-spans", tracing::instrument(name = "process.authorize", level = "debug", skip_all))]
+    pub fn authorize_with_signer<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        signer: Arc<dyn Signer<N> + Send + Sync>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Authorize the call.
+        self.get_stack(program_id)?.authorize_with_signer::<A, R>(signer, function_name, inputs, rng)
+    }
+
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
     /// and the deployment or execution ID.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(name = "process.authorize_fee_private", level = "debug", skip_all)
+    )]
     pub fn authorize_fee_private<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -74,6 +95,10 @@ impl<N: Network> Process<N> {
 
     /// Authorizes the fee given the the fee amount (in microcredits) and the deployment or execution ID.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(name = "process.authorize_fee_public", level = "debug", skip_all)
+    )]
     pub fn authorize_fee_public<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::path::{Path, PathBuf};
+
+/// An on-disk cache of circuit keys, for each `(program ID, edition, function name)`.
+///
+/// Each entry records the digest of the constraint system that its keys were synthesized from,
+/// and is only reused for an identical constraint system. This skips the synthesis of circuit keys
+/// across restarts, while a program or circuit change simply replaces the stale entry.
+#[derive(Clone, Debug)]
+pub struct CircuitKeyCache {
+    /// The directory of the cache.
+    directory: PathBuf,
+}
+
+impl CircuitKeyCache {
+    /// Opens the cache in the given directory, creating the directory if it does not exist.
+    pub fn open<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        std::fs::create_dir_all(&directory)?;
+        Ok(Self { directory })
+    }
+
+    /// Returns the directory of the cache.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the digest of the constraint system of the given assignment.
+    pub fn digest<N: Network>(assignment: &circuit::Assignment<N::Field>) -> Result<[u8; 32]> {
+        use blake2::Digest;
+
+        let mut r1cs = Vec::new();
+        assignment.write_r1cs(&mut r1cs)?;
+        Ok(blake2::Blake2s256::digest(&r1cs).into())
+    }
+
+    /// Returns the circuit keys for the given program ID, edition, and function name,
+    /// if they exist and were synthesized from the constraint system with the given digest.
+    pub fn load<N: Network>(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: Edition,
+        function_name: &Identifier<N>,
+        digest: &[u8; 32],
+    ) -> Result<Option<(ProvingKey<N>, VerifyingKey<N>)>> {
+        // Read the entry, if it exists.
+        let path = self.path(program_id, edition, function_name);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = std::fs::read(&path)?;
+
+        // Ensure the entry is for the same constraint system.
+        match bytes.get(..digest.len()) {
+            Some(candidate) if candidate == digest => (),
+            _ => return Ok(None),
+        }
+
+        // Read the proving key and verifying key.
+        let mut reader = &bytes[digest.len()..];
+        let proving_key = ProvingKey::read_le(&mut reader)?;
+        let verifying_key = VerifyingKey::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "The circuit key cache entry for '{program_id}/{function_name}' has trailing bytes");
+        Ok(Some((proving_key, verifying_key)))
+    }
+
+    /// Stores the circuit keys for the given program ID, edition, and function name,
+    /// which were synthesized from the constraint system with the given digest.
+    pub fn store<N: Network>(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: Edition,
+        function_name: &Identifier<N>,
+        digest: &[u8; 32],
+        proving_key: &ProvingKey<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        use std::io::Write as _;
+
+        // Serialize the entry.
+        let mut bytes = digest.to_vec();
+        proving_key.write_le(&mut bytes)?;
+        verifying_key.write_le(&mut bytes)?;
+
+        // Write to a temporary file, and move it into place, so that an entry is never partially written.
+        let path = self.path(program_id, edition, function_name);
+        let temporary_path = path.with_extension("tmp");
+        let mut file = std::fs::File::create(&temporary_path)?;
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+        std::fs::rename(&temporary_path, path)?;
+        Ok(())
+    }
+
+    /// Returns the path of the entry for the given program ID, edition, and function name.
+    fn path<N: Network>(&self, program_id: &ProgramID<N>, edition: Edition, function_name: &Identifier<N>) -> PathBuf {
+        self.directory.join(format!("{program_id}.{edition}.{function_name}.key"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_circuit_key_cache() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the cache in a new temporary directory.
+        let directory = std::env::temp_dir().join(format!("circuit_key_cache_{}", u64::rand(rng)));
+        let cache = CircuitKeyCache::open(&directory).unwrap();
+
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Synthesize the circuit keys, which stores them in the cache.
+        let process = crate::test_helpers::sample_process(&program);
+        process.set_circuit_key_cache(Some(cache.clone()));
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        let verifying_key = process.get_verifying_key(program.id(), function_name).unwrap();
+        assert!(cache.path(program.id(), CurrentNetwork::EDITION, &function_name).exists());
+
+        // Ensure a new process loads the same circuit keys from the cache.
+        let process = crate::test_helpers::sample_process(&program);
+        process.set_circuit_key_cache(Some(cache.clone()));
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        assert_eq!(process.get_verifying_key(program.id(), function_name).unwrap(), verifying_key);
+
+        // Ensure an entry is not loaded for a different constraint system.
+        let result = cache.load(program.id(), CurrentNetwork::EDITION, &function_name, &[0u8; 32]).unwrap();
+        assert!(result.is_none());
+        // Ensure a missing entry is not loaded.
+        let result = cache.load(program.id(), CurrentNetwork::EDITION + 1, &function_name, &[0u8; 32]).unwrap();
+        assert!(result.is_none());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
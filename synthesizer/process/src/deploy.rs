@@ -17,6 +17,10 @@ use super::*;
 impl<N: Network> Process<N> {
     /// Deploys the given program ID, if it does not exist.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(name = "process.deploy", level = "debug", skip_all, fields(program_id = %program.id()))
+    )]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         program: &Program<N>,
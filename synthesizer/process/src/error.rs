@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use thiserror::Error;
+
+/// An error raised by the process, with a stable error code and machine-readable context.
+///
+/// The process APIs return `Result`; callers recover this type with `error.downcast_ref::<ProcessError>()`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ProcessError {
+    #[error("Program '{program_id}' does not exist")]
+    ProgramNotFound { program_id: String },
+
+    #[error("Expected {expected} inputs, found {found}")]
+    InputCountMismatch { program_id: String, function_name: String, expected: usize, found: usize },
+
+    #[error("Request is invalid")]
+    InvalidRequest { program_id: String, function_name: String },
+
+    #[error("Register '{register}' is not a member of the function: {reason}")]
+    UndefinedRegister { register: String, reason: String },
+
+    #[error("Register '{register}' is missing a type definition: {reason}")]
+    UntypedRegister { register: String, reason: String },
+
+    #[error("Failed to evaluate instruction ({instruction}): {reason}")]
+    EvaluateInstruction {
+        program_id: String,
+        function_name: String,
+        instruction: String,
+        register: Option<String>,
+        reason: String,
+    },
+
+    #[error("Failed to execute instruction ({instruction}): {reason}")]
+    ExecuteInstruction {
+        program_id: String,
+        function_name: String,
+        instruction: String,
+        register: Option<String>,
+        reason: String,
+    },
+
+    #[error(
+        "'{program_id}/{function_name}' is not satisfied on the given inputs ({num_constraints} constraints).{diagnosis}"
+    )]
+    UnsatisfiedCircuit { program_id: String, function_name: String, num_constraints: u64, diagnosis: String },
+}
+
+impl ProcessError {
+    /// Returns the stable error code.
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::ProgramNotFound { .. } => 1000,
+            Self::InputCountMismatch { .. } => 1001,
+            Self::InvalidRequest { .. } => 1002,
+            Self::UndefinedRegister { .. } => 1003,
+            Self::UntypedRegister { .. } => 1004,
+            Self::EvaluateInstruction { .. } => 1005,
+            Self::ExecuteInstruction { .. } => 1006,
+            Self::UnsatisfiedCircuit { .. } => 1007,
+        }
+    }
+
+    /// Returns the program ID, if the error is specific to a program.
+    pub fn program_id(&self) -> Option<&str> {
+        match self {
+            Self::ProgramNotFound { program_id }
+            | Self::InputCountMismatch { program_id, .. }
+            | Self::InvalidRequest { program_id, .. }
+            | Self::EvaluateInstruction { program_id, .. }
+            | Self::ExecuteInstruction { program_id, .. }
+            | Self::UnsatisfiedCircuit { program_id, .. } => Some(program_id),
+            Self::UndefinedRegister { .. } | Self::UntypedRegister { .. } => None,
+        }
+    }
+
+    /// Returns the function (or closure) name, if the error is specific to a function.
+    pub fn function_name(&self) -> Option<&str> {
+        match self {
+            Self::InputCountMismatch { function_name, .. }
+            | Self::InvalidRequest { function_name, .. }
+            | Self::EvaluateInstruction { function_name, .. }
+            | Self::ExecuteInstruction { function_name, .. }
+            | Self::UnsatisfiedCircuit { function_name, .. } => Some(function_name),
+            Self::ProgramNotFound { .. } | Self::UndefinedRegister { .. } | Self::UntypedRegister { .. } => None,
+        }
+    }
+
+    /// Returns the register, if the error is specific to a register.
+    pub fn register(&self) -> Option<&str> {
+        match self {
+            Self::UndefinedRegister { register, .. } | Self::UntypedRegister { register, .. } => Some(register),
+            Self::EvaluateInstruction { register, .. } | Self::ExecuteInstruction { register, .. } => {
+                register.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the register named by the given error or any of its causes.
+    pub(crate) fn register_of(error: &console::prelude::Error) -> Option<String> {
+        error
+            .chain()
+            .filter_map(|cause| cause.downcast_ref::<Self>())
+            .find_map(|error| error.register().map(ToString::to_string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::{Result, anyhow, bail};
+
+    fn load_register() -> Result<()> {
+        bail!(ProcessError::UndefinedRegister { register: "r3".to_string(), reason: "not found".to_string() })
+    }
+
+    #[test]
+    fn test_downcast_preserves_code_and_context() {
+        let error = load_register().unwrap_err();
+        assert_eq!(error.to_string(), "Register 'r3' is not a member of the function: not found");
+
+        // Wrap the register error as an instruction failure.
+        let error = anyhow!(ProcessError::EvaluateInstruction {
+            program_id: "token.aleo".to_string(),
+            function_name: "mint".to_string(),
+            instruction: "add r0 r3 into r4;".to_string(),
+            register: ProcessError::register_of(&error),
+            reason: error.to_string(),
+        });
+
+        let error = error.downcast_ref::<ProcessError>().unwrap();
+        assert_eq!(error.code(), 1005);
+        assert_eq!(error.program_id(), Some("token.aleo"));
+        assert_eq!(error.function_name(), Some("mint"));
+        assert_eq!(error.register(), Some("r3"));
+        assert_eq!(
+            error.to_string(),
+            "Failed to evaluate instruction (add r0 r3 into r4;): Register 'r3' is not a member of the function: not found"
+        );
+    }
+}
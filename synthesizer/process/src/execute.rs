@@ -17,6 +17,15 @@ use super::*;
 impl<N: Network> Process<N> {
     /// Executes the given authorization.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "process.execute",
+            level = "debug",
+            skip_all,
+            fields(num_requests = authorization.len())
+        )
+    )]
     pub fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
         &self,
         authorization: Authorization<N>,
@@ -22,6 +22,15 @@ impl<N: Network> Process<N> {
     /// This method assumes the given deployment **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "process.finalize_deployment",
+            level = "debug",
+            skip_all,
+            fields(program_id = %deployment.program_id())
+        )
+    )]
     pub fn finalize_deployment<P: FinalizeStorage<N>>(
         &self,
         state: FinalizeGlobalState,
@@ -74,6 +83,15 @@ impl<N: Network> Process<N> {
     /// This method assumes the given execution **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "process.finalize_execution",
+            level = "debug",
+            skip_all,
+            fields(num_transitions = execution.len())
+        )
+    )]
     pub fn finalize_execution<P: FinalizeStorage<N>>(
         &self,
         state: FinalizeGlobalState,
@@ -129,6 +147,15 @@ impl<N: Network> Process<N> {
     /// This method assumes the given fee **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "process.finalize_fee",
+            level = "debug",
+            skip_all,
+            fields(transition_id = %fee.transition_id())
+        )
+    )]
     pub fn finalize_fee<P: FinalizeStorage<N>>(
         &self,
         state: FinalizeGlobalState,
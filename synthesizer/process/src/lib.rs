@@ -18,6 +18,12 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod circuit_key_cache;
+pub use circuit_key_cache::*;
+
+mod error;
+pub use error::*;
+
 mod stack;
 pub use stack::*;
 
@@ -27,6 +33,10 @@ pub use trace::*;
 mod traits;
 pub use traits::*;
 
+mod verifying_key_registry;
+pub use verifying_key_registry::*;
+
+mod aggregate;
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -40,7 +50,7 @@ mod verify_fee;
 mod tests;
 
 use console::{
-    account::PrivateKey,
+    account::{PrivateKey, Signer},
     network::prelude::*,
     program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
     types::{Field, U16, U64},
@@ -76,6 +86,8 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The on-disk cache of circuit keys, which is shared with the stacks.
+    circuit_key_cache: Arc<RwLock<Option<CircuitKeyCache>>>,
 }
 
 impl<N: Network> Process<N> {
@@ -85,7 +97,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            circuit_key_cache: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -140,7 +156,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            circuit_key_cache: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -173,7 +193,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            circuit_key_cache: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -194,6 +218,19 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the on-disk cache of circuit keys, if it is set.
+    #[inline]
+    pub fn circuit_key_cache(&self) -> Option<CircuitKeyCache> {
+        self.circuit_key_cache.read().clone()
+    }
+
+    /// Sets the on-disk cache of circuit keys, which applies to all programs in the process.
+    /// When set, circuit keys are loaded from the cache, and newly-synthesized circuit keys are stored in it.
+    #[inline]
+    pub fn set_circuit_key_cache(&self, cache: Option<CircuitKeyCache>) {
+        *self.circuit_key_cache.write() = cache;
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -206,7 +243,10 @@ impl<N: Network> Process<N> {
         // Prepare the program ID.
         let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
         // Retrieve the stack.
-        let stack = self.stacks.get(&program_id).ok_or_else(|| anyhow!("Program '{program_id}' does not exist"))?;
+        let stack = self
+            .stacks
+            .get(&program_id)
+            .ok_or_else(|| ProcessError::ProgramNotFound { program_id: program_id.to_string() })?;
         // Ensure the program ID matches.
         ensure!(stack.program_id() == &program_id, "Expected program '{}', found '{program_id}'", stack.program_id());
         // Return the stack.
@@ -269,6 +309,15 @@ impl<N: Network> Process<N> {
 
     /// Synthesizes the proving and verifying key for the given program ID and function name.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "process.synthesize_key",
+            level = "debug",
+            skip_all,
+            fields(program_id = %program_id, function_name = %function_name)
+        )
+    )]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         program_id: &ProgramID<N>,
@@ -278,6 +327,21 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Returns the constraint and variable counts for the given program ID and function name.
+    /// The circuit is synthesized on sampled inputs, but the circuit key is not.
+    #[inline]
+    pub fn circuit_metrics<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        rng: &mut R,
+    ) -> Result<CircuitMetrics<N>> {
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Compute the circuit metrics.
+        self.get_stack(program_id)?.circuit_metrics::<A, R>(&function_name, rng)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
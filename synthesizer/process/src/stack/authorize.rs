@@ -23,6 +23,18 @@ impl<N: Network> Stack<N> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_signer::<A, R>(Arc::new(*private_key), function_name, inputs, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, using the given signer for every request.
+    #[inline]
+    pub fn authorize_with_signer<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        signer: Arc<dyn Signer<N> + Send + Sync>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("Stack::authorize");
 
@@ -35,14 +47,15 @@ impl<N: Network> Stack<N> {
         lap!(timer, "Retrieve the input types");
 
         // Compute the request.
-        let request = Request::sign(private_key, program_id, function_name, inputs, &input_types, rng)?;
+        let request = Request::sign(signer.as_ref(), program_id, function_name, inputs, &input_types, rng)?;
         lap!(timer, "Compute the request");
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
         // Construct the call stack.
-        let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
+        let call_stack = CallStack::Authorize(vec![request], signer, authorization.clone());
         // Construct the authorization from the function.
-        let _response = self.execute_function::<A, R>(call_stack, None, rng)?;
+        // Note: Only the values of the circuit are used, so the constraints are skipped.
+        let _response = A::evaluation_only(|| self.execute_function::<A, R>(call_stack, None, rng))?;
         finish!(timer, "Construct the authorization from the function");
 
         // Return the authorization.
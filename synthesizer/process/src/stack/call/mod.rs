@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
+use crate::{Authorization, CallStack, ProcessError, Registers, RegistersCall, StackEvaluate, StackExecute, Trace};
 use aleo_std::prelude::{finish, lap, timer};
-use console::{network::prelude::*, program::Request};
+use console::{
+    network::prelude::*,
+    program::{ProgramID, Request, Value},
+};
 use synthesizer_program::{
     Call,
     CallOperator,
@@ -28,6 +31,103 @@ use synthesizer_program::{
     StackProgram,
 };
 
+use parking_lot::RwLock;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    sync::{
+        atomic::{self, AtomicUsize},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+};
+
+/// The number of function calls that are being synthesized on separate threads.
+static NUM_CALL_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// A guard that releases a reserved call thread when it is dropped.
+struct CallThreadGuard;
+
+impl Drop for CallThreadGuard {
+    fn drop(&mut self) {
+        NUM_CALL_THREADS.fetch_sub(1, atomic::Ordering::SeqCst);
+    }
+}
+
+/// A function call whose circuit is synthesized with its own circuit environment.
+pub enum PendingCall<N: Network> {
+    /// The function call is being synthesized on a separate thread.
+    Spawned(JoinHandle<Result<Trace<N>>>),
+    /// The function call was synthesized on the current thread, as every call thread was in use.
+    Completed(Result<Trace<N>>),
+}
+
+impl<N: Network> PendingCall<N> {
+    /// Executes the function call for the given authorization, and ensures its outputs match the console outputs.
+    /// The call is executed on a separate thread, unless every call thread (one per available core) is in use.
+    fn spawn<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        stack: Arc<impl StackExecute<N> + Send + Sync + 'static>,
+        authorization: Authorization<N>,
+        console_caller: Option<ProgramID<N>>,
+        console_outputs: Vec<Value<N>>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Retrieve the function name.
+        let function_name = *authorization.peek_next()?.function_name();
+        // Initialize an RNG for the thread.
+        let mut rng = StdRng::from_rng(rng)?;
+
+        // Executes the request with its own trace.
+        let execute = move || -> Result<Trace<N>> {
+            // Initialize the trace.
+            let trace = Arc::new(RwLock::new(Trace::new()));
+            // Execute the request.
+            let call_stack = CallStack::execute(authorization, trace.clone())?;
+            let response = stack.execute_function::<A, _>(call_stack, console_caller, &mut rng)?;
+            // Ensure the values are equal.
+            if console_outputs != response.outputs() {
+                #[cfg(debug_assertions)]
+                eprintln!("\n{:#?} != {:#?}\n", console_outputs, response.outputs());
+                bail!("Function '{function_name}' outputs do not match in a 'call' instruction.")
+            }
+            // Extract the trace.
+            match Arc::try_unwrap(trace) {
+                Ok(trace) => Ok(trace.into_inner()),
+                Err(_) => bail!("Failed to extract the trace of function '{function_name}'"),
+            }
+        };
+
+        // Reserve a call thread, if one is available.
+        let max_threads = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        let is_reserved = NUM_CALL_THREADS
+            .fetch_update(atomic::Ordering::SeqCst, atomic::Ordering::SeqCst, |n| (n < max_threads).then_some(n + 1))
+            .is_ok();
+        match is_reserved {
+            true => {
+                // Note: The guard releases the call thread when the thread exits, or if it fails to spawn.
+                let guard = CallThreadGuard;
+                let handle = thread::Builder::new().spawn(move || {
+                    let _guard = guard;
+                    execute()
+                })?;
+                Ok(Self::Spawned(handle))
+            }
+            false => Ok(Self::Completed(execute())),
+        }
+    }
+
+    /// Waits for the function call to complete, and returns its trace.
+    /// If the thread panicked, the panic is resumed on the current thread.
+    pub(crate) fn join(self) -> Result<Trace<N>> {
+        match self {
+            Self::Spawned(handle) => match handle.join() {
+                Ok(result) => result,
+                Err(error) => std::panic::resume_unwind(error),
+            },
+            Self::Completed(result) => result,
+        }
+    }
+}
+
 pub trait CallTrait<N: Network> {
     /// Evaluates the instruction.
     fn evaluate<A: circuit::Aleo<Network = N>>(
@@ -39,7 +139,7 @@ pub trait CallTrait<N: Network> {
     /// Executes the instruction.
     fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
         &self,
-        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N> + Send + Sync + 'static),
         registers: &mut (
                  impl RegistersCall<N>
                  + RegistersSignerCircuit<N, A>
@@ -87,7 +187,12 @@ impl<N: Network> CallTrait<N> for Call<N> {
         let outputs = if let Ok(closure) = substack.program().get_closure(resource) {
             // Ensure the number of inputs matches the number of input statements.
             if closure.inputs().len() != inputs.len() {
-                bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
+                bail!(ProcessError::InputCountMismatch {
+                    program_id: substack.program_id().to_string(),
+                    function_name: closure.name().to_string(),
+                    expected: closure.inputs().len(),
+                    found: inputs.len(),
+                })
             }
             // Evaluate the closure, and load the outputs.
             substack.evaluate_closure::<A>(
@@ -103,7 +208,12 @@ impl<N: Network> CallTrait<N> for Call<N> {
         else if let Ok(function) = substack.program().get_function(resource) {
             // Ensure the number of inputs matches the number of input statements.
             if function.inputs().len() != inputs.len() {
-                bail!("Expected {} inputs, found {}", function.inputs().len(), inputs.len())
+                bail!(ProcessError::InputCountMismatch {
+                    program_id: substack.program_id().to_string(),
+                    function_name: function.name().to_string(),
+                    expected: function.inputs().len(),
+                    found: inputs.len(),
+                })
             }
             // Set the (console) caller.
             let console_caller = Some(*stack.program_id());
@@ -132,7 +242,7 @@ impl<N: Network> CallTrait<N> for Call<N> {
     #[inline]
     fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
-        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N> + Send + Sync + 'static),
         registers: &mut (
                  impl RegistersCall<N>
                  + RegistersSignerCircuit<N, A>
@@ -197,7 +307,12 @@ impl<N: Network> CallTrait<N> for Call<N> {
             let num_inputs = function.inputs().len();
             // Ensure the number of inputs matches the number of input statements.
             if num_inputs != inputs.len() {
-                bail!("Expected {} inputs, found {}", num_inputs, inputs.len())
+                bail!(ProcessError::InputCountMismatch {
+                    program_id: substack.program_id().to_string(),
+                    function_name: function.name().to_string(),
+                    expected: num_inputs,
+                    found: inputs.len(),
+                })
             }
 
             // Retrieve the number of public variables in the circuit.
@@ -215,8 +330,32 @@ impl<N: Network> CallTrait<N> for Call<N> {
 
                 match registers.call_stack() {
                     // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
-                    CallStack::Authorize(_, private_key, authorization)
-                    | CallStack::Synthesize(_, private_key, authorization) => {
+                    CallStack::Authorize(_, signer, authorization) => {
+                        // Compute the request.
+                        let request = Request::sign(
+                            signer.as_ref(),
+                            *substack.program_id(),
+                            *function.name(),
+                            inputs.iter(),
+                            &function.input_types(),
+                            rng,
+                        )?;
+
+                        // Retrieve the call stack.
+                        let mut call_stack = registers.call_stack();
+                        // Push the request onto the call stack.
+                        call_stack.push(request.clone())?;
+
+                        // Add the request to the authorization.
+                        authorization.push(request.clone());
+
+                        // Execute the request.
+                        let response = substack.execute_function::<A, R>(call_stack, console_caller, rng)?;
+
+                        // Return the request and response.
+                        (request, response)
+                    }
+                    CallStack::Synthesize(_, private_key, authorization) => {
                         // Compute the request.
                         let request = Request::sign(
                             &private_key,
@@ -276,18 +415,47 @@ impl<N: Network> CallTrait<N> for Call<N> {
                             Ok(())
                         })?;
 
-                        // Evaluate the function, and load the outputs.
+                        // Evaluate the function on a replica of the authorization, and load the outputs.
+                        // Note: The evaluation consumes the requests in the call tree from the replica.
+                        let replica = authorization.replicate();
                         let console_response =
-                            substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
-                        // Execute the request.
-                        let response =
-                            substack.execute_function::<A, R>(registers.call_stack(), console_caller, rng)?;
-                        // Ensure the values are equal.
-                        if console_response.outputs() != response.outputs() {
-                            #[cfg(debug_assertions)]
-                            eprintln!("\n{:#?} != {:#?}\n", console_response.outputs(), response.outputs());
-                            bail!("Function '{}' outputs do not match in a 'call' instruction.", function.name())
-                        }
+                            substack.evaluate_function::<A>(CallStack::Evaluate(replica.clone()), console_caller)?;
+
+                        // If parallel synthesis is enabled, execute the request on a separate thread.
+                        // Its transitions are appended to the trace when the caller completes, in call order.
+                        let response = if cfg!(not(feature = "serial")) {
+                            // Retrieve the number of requests in the call tree, which were consumed by the evaluation.
+                            let num_requests = authorization.len().saturating_sub(replica.len());
+                            ensure!(num_requests > 0, "Function '{}' did not consume a request", function.name());
+                            // Split off the requests in the call tree.
+                            let call_authorization = Authorization::new(authorization.next()?);
+                            for _ in 1..num_requests {
+                                call_authorization.push(authorization.next()?);
+                            }
+                            // Retrieve the shared substack.
+                            let substack = stack.get_external_stack(substack.program_id())?.clone();
+                            // Execute the request on a separate thread.
+                            let pending_call = PendingCall::spawn::<A, R>(
+                                substack,
+                                call_authorization,
+                                console_caller,
+                                console_response.outputs().to_vec(),
+                                rng,
+                            )?;
+                            registers.defer_call(pending_call);
+                            console_response
+                        } else {
+                            // Execute the request.
+                            let response =
+                                substack.execute_function::<A, R>(registers.call_stack(), console_caller, rng)?;
+                            // Ensure the values are equal.
+                            if console_response.outputs() != response.outputs() {
+                                #[cfg(debug_assertions)]
+                                eprintln!("\n{:#?} != {:#?}\n", console_response.outputs(), response.outputs());
+                                bail!("Function '{}' outputs do not match in a 'call' instruction.", function.name())
+                            }
+                            response
+                        };
                         // Return the request and response.
                         (request, response)
                     }
@@ -73,56 +73,49 @@ impl<N: Network> Stack<N> {
 
         let program_id = self.program.id();
 
-        // Construct the call stacks and assignments used to verify the certificates.
-        let mut call_stacks = Vec::with_capacity(deployment.verifying_keys().len());
-
-        // Iterate through the program functions and construct the callstacks and corresponding assignments.
-        for function in deployment.program().functions().values() {
-            // Initialize a burner private key.
-            let burner_private_key = PrivateKey::new(rng)?;
-            // Compute the burner address.
-            let burner_address = Address::try_from(&burner_private_key)?;
-            // Retrieve the input types.
-            let input_types = function.input_types();
-            // Sample the inputs.
-            let inputs = input_types
-                .iter()
-                .map(|input_type| match input_type {
-                    ValueType::ExternalRecord(locator) => {
-                        // Retrieve the external stack.
-                        let stack = self.get_external_stack(locator.program_id())?;
-                        // Sample the input.
-                        stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
-                    }
-                    _ => self.sample_value(&burner_address, input_type, rng),
-                })
-                .collect::<Result<Vec<_>>>()?;
-            lap!(timer, "Sample the inputs");
-
-            // Compute the request, with a burner private key.
-            let request = Request::sign(
-                &burner_private_key,
-                *program_id,
-                *function.name(),
-                inputs.into_iter(),
-                &input_types,
-                rng,
-            )?;
-            lap!(timer, "Compute the request for {}", function.name());
-            // Initialize the assignments.
-            let assignments = Assignments::<N>::default();
-            // Initialize the call stack.
-            let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
-            // Append the function name, callstack, and assignments.
-            call_stacks.push((function.name(), call_stack, assignments));
-        }
+        // Verify the certificates, in parallel across the functions.
+        let functions = deployment.program().functions().values().collect::<Vec<_>>();
+        let rngs = (0..functions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        cfg_iter!(functions).zip_eq(deployment.verifying_keys()).zip_eq(rngs).try_for_each(
+            |((function, (_, (verifying_key, certificate))), mut rng)| {
+                let function_name = function.name();
+
+                // Initialize a burner private key.
+                let burner_private_key = PrivateKey::new(&mut rng)?;
+                // Compute the burner address.
+                let burner_address = Address::try_from(&burner_private_key)?;
+                // Retrieve the input types.
+                let input_types = function.input_types();
+                // Sample the inputs.
+                let inputs = input_types
+                    .iter()
+                    .map(|input_type| match input_type {
+                        ValueType::ExternalRecord(locator) => {
+                            // Retrieve the external stack.
+                            let stack = self.get_external_stack(locator.program_id())?;
+                            // Sample the input.
+                            stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), &mut rng)
+                        }
+                        _ => self.sample_value(&burner_address, input_type, &mut rng),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                // Compute the request, with a burner private key.
+                let request = Request::sign(
+                    &burner_private_key,
+                    *program_id,
+                    *function_name,
+                    inputs.into_iter(),
+                    &input_types,
+                    &mut rng,
+                )?;
+                // Initialize the assignments.
+                let assignments = Assignments::<N>::default();
+                // Initialize the call stack.
+                let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
 
-        // Verify the certificates.
-        let rngs = (0..call_stacks.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-        cfg_iter!(call_stacks).zip_eq(deployment.verifying_keys()).zip_eq(rngs).try_for_each(
-            |(((function_name, call_stack, assignments), (_, (verifying_key, certificate))), mut rng)| {
                 // Synthesize the circuit.
-                if let Err(err) = self.execute_function::<A, _>(call_stack.clone(), None, &mut rng) {
+                if let Err(err) = self.execute_function::<A, _>(call_stack, None, &mut rng) {
                     bail!("Failed to synthesize the circuit for '{function_name}': {err}")
                 }
                 // Check the certificate.
@@ -138,6 +131,7 @@ impl<N: Network> Stack<N> {
                 Ok(())
             },
         )?;
+        lap!(timer, "Verify the certificates");
 
         finish!(timer);
 
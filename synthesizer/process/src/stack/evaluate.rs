@@ -33,7 +33,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Ensure the number of inputs matches the number of input statements.
         if closure.inputs().len() != inputs.len() {
-            bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
+            bail!(ProcessError::InputCountMismatch {
+                program_id: self.program_id().to_string(),
+                function_name: closure.name().to_string(),
+                expected: closure.inputs().len(),
+                found: inputs.len(),
+            })
         }
 
         // Initialize the registers.
@@ -57,7 +62,13 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         for instruction in closure.instructions() {
             // If the evaluation fails, bail and return the error.
             if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+                bail!(ProcessError::EvaluateInstruction {
+                    program_id: self.program_id().to_string(),
+                    function_name: closure.name().to_string(),
+                    instruction: instruction.to_string(),
+                    register: ProcessError::register_of(&error),
+                    reason: error.to_string(),
+                });
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -163,7 +174,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Initialize the registers");
 
         // Ensure the request is well-formed.
-        ensure!(request.verify(&function.input_types()), "Request is invalid");
+        if !request.verify(&function.input_types()) {
+            bail!(ProcessError::InvalidRequest {
+                program_id: self.program_id().to_string(),
+                function_name: function.name().to_string(),
+            })
+        }
         lap!(timer, "Verify the request");
 
         // Store the inputs.
@@ -185,7 +201,13 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             };
             // If the evaluation fails, bail and return the error.
             if let Err(error) = result {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+                bail!(ProcessError::EvaluateInstruction {
+                    program_id: self.program_id().to_string(),
+                    function_name: function.name().to_string(),
+                    instruction: instruction.to_string(),
+                    register: ProcessError::register_of(&error),
+                    reason: error.to_string(),
+                });
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -36,7 +36,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Ensure the number of inputs matches the number of input statements.
         if closure.inputs().len() != inputs.len() {
-            bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
+            bail!(ProcessError::InputCountMismatch {
+                program_id: self.program_id().to_string(),
+                function_name: closure.name().to_string(),
+                expected: closure.inputs().len(),
+                found: inputs.len(),
+            })
         }
         lap!(timer, "Check the number of inputs");
 
@@ -72,11 +77,19 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
                 if let Err(error) = instruction.evaluate(self, &mut registers) {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                    bail!(ProcessError::EvaluateInstruction {
+                        program_id: self.program_id().to_string(),
+                        function_name: closure.name().to_string(),
+                        instruction: instruction.to_string(),
+                        register: ProcessError::register_of(&error),
+                        reason: error.to_string(),
+                    });
                 }
             }
             // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            self.profile_instruction::<A, _>(closure.name(), instruction, || {
+                instruction.execute(self, &mut registers)
+            })?;
         }
         lap!(timer, "Execute the instructions");
 
@@ -172,7 +185,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         let num_inputs = function.inputs().len();
         // Ensure the number of inputs matches the number of input statements.
         if num_inputs != console_request.inputs().len() {
-            bail!("Expected {num_inputs} inputs, found {}", console_request.inputs().len())
+            bail!(ProcessError::InputCountMismatch {
+                program_id: self.program_id().to_string(),
+                function_name: function.name().to_string(),
+                expected: num_inputs,
+                found: console_request.inputs().len(),
+            })
         }
         // Retrieve the input types.
         let input_types = function.input_types();
@@ -188,7 +206,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Verify the input types");
 
         // Ensure the request is well-formed.
-        ensure!(console_request.verify(&input_types), "Request is invalid");
+        if !console_request.verify(&input_types) {
+            bail!(ProcessError::InvalidRequest {
+                program_id: self.program_id().to_string(),
+                function_name: function.name().to_string(),
+            })
+        }
         lap!(timer, "Verify the console request");
 
         // Initialize the registers.
@@ -252,9 +275,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
+        // Initialize the number of constraints before each instruction, to attribute an unsatisfied constraint.
+        let mut instruction_offsets = Vec::with_capacity(function.instructions().len() + 1);
 
         // Execute the instructions.
         for instruction in function.instructions() {
+            instruction_offsets.push(A::num_constraints());
+
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
@@ -266,20 +293,32 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 };
                 // If the evaluation fails, bail and return the error.
                 if let Err(error) = result {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                    bail!(ProcessError::EvaluateInstruction {
+                        program_id: self.program_id().to_string(),
+                        function_name: function.name().to_string(),
+                        instruction: instruction.to_string(),
+                        register: ProcessError::register_of(&error),
+                        reason: error.to_string(),
+                    });
                 }
             }
 
             // Execute the instruction.
-            let result = match instruction {
+            let result = self.profile_instruction::<A, _>(function.name(), instruction, || match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
-            };
+            });
             // If the execution fails, bail and return the error.
             if let Err(error) = result {
-                bail!("Failed to execute instruction ({instruction}): {error}");
+                bail!(ProcessError::ExecuteInstruction {
+                    program_id: self.program_id().to_string(),
+                    function_name: function.name().to_string(),
+                    instruction: instruction.to_string(),
+                    register: ProcessError::register_of(&error),
+                    reason: error.to_string(),
+                });
             }
 
             // If the instruction was a function call, then set the tracker to `true`.
@@ -290,6 +329,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
         }
+        instruction_offsets.push(A::num_constraints());
         lap!(timer, "Execute the instructions");
 
         // Load the outputs.
@@ -384,13 +424,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // If the circuit is in `Execute` or `PackageRun` mode, then ensure the circuit is satisfied.
         if matches!(registers.call_stack(), CallStack::Execute(..) | CallStack::PackageRun(..)) {
             // If the circuit is empty or not satisfied, then throw an error.
-            ensure!(
-                A::num_constraints() > 0 && A::is_satisfied(),
-                "'{}/{}' is not satisfied on the given inputs ({} constraints).",
-                self.program.id(),
-                function.name(),
-                A::num_constraints()
-            );
+            if A::num_constraints() == 0 || !A::is_satisfied() {
+                bail!(ProcessError::UnsatisfiedCircuit {
+                    program_id: self.program.id().to_string(),
+                    function_name: function.name().to_string(),
+                    num_constraints: A::num_constraints(),
+                    diagnosis: self.diagnose_unsatisfied(function.instructions(), &instruction_offsets, &registers),
+                });
+            }
         }
 
         // Eject the circuit assignment and reset the circuit.
@@ -434,6 +475,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         else if let CallStack::Execute(_, ref trace) = registers.call_stack() {
             registers.ensure_console_and_circuit_registers_match()?;
 
+            // Append the transitions of the function calls that were executed on separate threads, in call order.
+            for pending_call in registers.take_pending_calls() {
+                let call_trace = pending_call.join()?;
+                trace.write().append(call_trace)?;
+            }
+            lap!(timer, "Join the function calls");
+
             // Construct the transition.
             let transition = Transition::from(&console_request, &response, &output_types, &output_registers)?;
 
@@ -481,6 +529,66 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Executes the given logic for the instruction, within a profiling scope for the instruction if profiling is enabled.
+    fn profile_instruction<A: circuit::Aleo<Network = N>, T>(
+        &self,
+        name: &Identifier<N>,
+        instruction: &Instruction<N>,
+        logic: impl FnOnce() -> T,
+    ) -> T {
+        // If profiling is disabled, skip constructing the label.
+        if !A::is_profiling() {
+            return logic();
+        }
+        let label = format!("{}/{name}: {instruction}", self.program_id());
+        A::push_scope(label.clone());
+        let output = logic();
+        A::pop_scope(label);
+        output
+    }
+
+    /// Returns a description of the first unsatisfied constraint in the circuit, if one exists.
+    /// If the constraint was enforced by an instruction, the description includes the instruction,
+    /// and the values of its operands and destination registers.
+    fn diagnose_unsatisfied<A: circuit::Aleo<Network = N>>(
+        &self,
+        instructions: &[Instruction<N>],
+        instruction_offsets: &[u64],
+        registers: &Registers<N, A>,
+    ) -> String {
+        let Some(constraint) = A::first_unsatisfied_constraint() else {
+            return String::new();
+        };
+
+        // Find the instruction that enforced the constraint, from the number of constraints before each instruction.
+        let position = instruction_offsets.partition_point(|offset| *offset <= constraint.index);
+        let instruction = match position {
+            0 => return format!(" {constraint}, in the request verification."),
+            position => match instructions.get(position - 1) {
+                Some(instruction) => instruction,
+                None => return format!(" {constraint}, in the response construction."),
+            },
+        };
+
+        // Load the values of the operands and destinations of the instruction.
+        let load = |operand: &Operand<N>| match registers.load_circuit(self, operand) {
+            Ok(value) => format!("{operand} = {}", value.eject_value()),
+            Err(_) => format!("{operand} = <unavailable>"),
+        };
+        let operands = instruction.operands().iter().map(load).collect::<Vec<_>>();
+        let destinations = instruction
+            .destinations()
+            .into_iter()
+            .map(|register| load(&Operand::Register(register)))
+            .collect::<Vec<_>>();
+
+        format!(
+            " {constraint}, in instruction '{instruction}' (operands: [{}], destinations: [{}]).",
+            operands.join(", "),
+            destinations.join(", ")
+        )
+    }
+
     /// Prints the current state of the circuit.
     #[cfg(debug_assertions)]
     pub(crate) fn log_circuit<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S) {
@@ -497,5 +605,9 @@ impl<N: Network> Stack<N> {
             scope.into().bold(),
             width = 20
         );
+        // If the circuit is not satisfied, print the counterexample.
+        if let Some(counterexample) = A::counterexample() {
+            print!("{counterexample}");
+        }
     }
 }
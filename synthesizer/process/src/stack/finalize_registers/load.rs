@@ -67,7 +67,9 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
                 "Attempted to load a '{stack_value}' value from a register '{register}' of type '{finalize_type}' in a finalize scope",
             ),
             // Ensure the register is defined.
-            (Err(error), _) => bail!("Register '{register}' is not a member of the function: {error}"),
+            (Err(error), _) => {
+                bail!(ProcessError::UndefinedRegister { register: register.to_string(), reason: error.to_string() })
+            }
         };
 
         Ok(value)
@@ -15,7 +15,7 @@
 mod load;
 mod store;
 
-use crate::FinalizeTypes;
+use crate::{FinalizeTypes, ProcessError};
 use console::{
     network::prelude::*,
     program::{Identifier, Literal, Plaintext, Register, Value},
@@ -55,7 +55,10 @@ impl<N: Network> RegistersStore<N> for FinalizeRegisters<N> {
                         "Attempted to store a '{stack_value}' value in a register '{register}' of type '{finalize_type}' in a finalize scope",
                     ),
                     // Ensure the register is defined.
-                    (Err(error), _) => bail!("Register '{register}' is missing a type definition: {error}"),
+                    (Err(error), _) => bail!(ProcessError::UntypedRegister {
+                        register: register.to_string(),
+                        reason: error.to_string()
+                    }),
                 };
 
                 // Store the plaintext value.
@@ -149,6 +149,7 @@ impl<N: Network> FinalizeTypes<N> {
                 RegisterTypes::check_struct(stack, struct_name)?
             }
             FinalizeType::Plaintext(PlaintextType::Array(array_type)) => RegisterTypes::check_array(stack, array_type)?,
+            FinalizeType::Plaintext(PlaintextType::Map(map_type)) => RegisterTypes::check_map(stack, map_type)?,
             FinalizeType::Future(..) => (),
         };
 
@@ -652,6 +653,9 @@ impl<N: Network> FinalizeTypes<N> {
                             // Ensure the operand types match the element type.
                             self.matches_array(stack, instruction.operands(), array_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Map(..)) => {
+                            bail!("Illegal operation: Cannot cast to a map.")
+                        }
                         CastType::Record(..) => {
                             bail!("Illegal operation: Cannot cast to a record.")
                         }
@@ -679,7 +683,7 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
+            Opcode::ECDSA | Opcode::Ed25519 | Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
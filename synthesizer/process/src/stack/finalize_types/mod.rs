@@ -176,6 +176,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
                 | (FinalizeRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
+                | (FinalizeRefType::Plaintext(PlaintextType::Map(..)), _)
                 | (FinalizeRefType::Future(..), Access::Member(..)) => {
                     bail!("Invalid access `{access}`")
                 }
@@ -27,6 +27,7 @@ impl<N: Network> Stack<N> {
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            circuit_key_cache: process.circuit_key_cache.clone(),
         };
 
         // Add all of the imports into the stack.
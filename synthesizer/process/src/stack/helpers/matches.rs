@@ -201,6 +201,8 @@ impl<N: Network> Stack<N> {
                 Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected literal, found struct"),
                 // If `plaintext` is an array, this is a mismatch.
                 Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected literal, found array"),
+                // If `plaintext` is a map, this is a mismatch.
+                Plaintext::Map(..) => bail!("'{plaintext_type}' is invalid: expected literal, found map"),
             },
             PlaintextType::Struct(struct_name) => {
                 // Ensure the struct name is valid.
@@ -221,6 +223,7 @@ impl<N: Network> Stack<N> {
                     Plaintext::Literal(..) => bail!("'{struct_name}' is invalid: expected struct, found literal"),
                     Plaintext::Struct(members, ..) => members,
                     Plaintext::Array(..) => bail!("'{struct_name}' is invalid: expected struct, found array"),
+                    Plaintext::Map(..) => bail!("'{struct_name}' is invalid: expected struct, found map"),
                 };
 
                 let num_members = members.len();
@@ -281,6 +284,38 @@ impl<N: Network> Stack<N> {
                     }
                     Ok(())
                 }
+                // If `plaintext` is a map, this is a mismatch.
+                Plaintext::Map(..) => bail!("'{plaintext_type}' is invalid: expected array, found map"),
+            },
+            PlaintextType::Map(map_type) => match plaintext {
+                // If `plaintext` is a literal, this is a mismatch.
+                Plaintext::Literal(..) => bail!("'{plaintext_type}' is invalid: expected map, found literal"),
+                // If `plaintext` is a struct, this is a mismatch.
+                Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected map, found struct"),
+                // If `plaintext` is an array, this is a mismatch.
+                Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected map, found array"),
+                // If `plaintext` is a map, it must match the map type.
+                Plaintext::Map(entries, ..) => {
+                    // Ensure the number of entries does not exceed the maximum.
+                    let (num_entries, max_entries) = (entries.len(), map_type.max_entries());
+                    if num_entries > **max_entries as usize {
+                        bail!(
+                            "'{plaintext_type}' is invalid: expected at most {max_entries} entries, found {num_entries} entries"
+                        )
+                    }
+                    // Ensure the entries are in canonical order, with unique keys.
+                    Plaintext::check_map_entries(entries)?;
+                    // Ensure the map entries match.
+                    for (key, value) in entries.iter() {
+                        // Ensure the key type matches.
+                        if key.to_type() != *map_type.key_type() {
+                            bail!("'{plaintext_type}' is invalid: expected key {}, found {key}", map_type.key_type())
+                        }
+                        // Ensure the value matches (recursive call).
+                        self.matches_plaintext_internal(value, map_type.value_type(), depth + 1)?;
+                    }
+                    Ok(())
+                }
             },
         }
     }
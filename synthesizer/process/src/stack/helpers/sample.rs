@@ -178,6 +178,23 @@ impl<N: Network> Stack<N> {
 
                 Plaintext::Array(elements, Default::default())
             }
+            // Sample a map.
+            PlaintextType::Map(map_type) => {
+                // Sample up to the maximum number of entries, skipping any duplicate keys.
+                let mut entries: Vec<(Literal<N>, Plaintext<N>)> = Vec::new();
+                for _ in 0..**map_type.max_entries() {
+                    // Sample the key.
+                    let key = Literal::sample(*map_type.key_type(), rng);
+                    if entries.iter().any(|(existing, _)| *existing == key) {
+                        continue;
+                    }
+                    // Sample the value.
+                    let value = self.sample_plaintext_internal(map_type.value_type(), depth + 1, rng)?;
+                    entries.push((key, value));
+                }
+
+                Plaintext::new_map(entries)?
+            }
         };
         // Return the plaintext.
         Ok(plaintext)
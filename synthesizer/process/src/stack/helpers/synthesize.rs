@@ -27,32 +27,8 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
-        // Retrieve the program ID.
-        let program_id = self.program_id();
-        // Retrieve the function input types.
-        let input_types = self.get_function(function_name)?.input_types();
-
-        // Initialize a burner private key.
-        let burner_private_key = PrivateKey::new(rng)?;
-        // Compute the burner address.
-        let burner_address = Address::try_from(&burner_private_key)?;
-        // Sample the inputs.
-        let inputs = input_types
-            .iter()
-            .map(|input_type| match input_type {
-                ValueType::ExternalRecord(locator) => {
-                    // Retrieve the external stack.
-                    let stack = self.get_external_stack(locator.program_id())?;
-                    // Sample the input.
-                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
-                }
-                _ => self.sample_value(&burner_address, input_type, rng),
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        // Compute the request, with a burner private key.
-        let request =
-            Request::sign(&burner_private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+        // Sample a request, with a burner private key.
+        let (request, burner_private_key) = self.sample_burner_request(function_name, rng)?;
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
         // Initialize the call stack.
@@ -67,6 +43,41 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Returns the circuit metrics for the given function name, without synthesizing the circuit key.
+    #[inline]
+    pub fn circuit_metrics<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<CircuitMetrics<N>> {
+        // Sample a request, with a burner private key.
+        let (request, burner_private_key) = self.sample_burner_request(function_name, rng)?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
+        // Synthesize the circuit.
+        self.execute_function::<A, R>(call_stack, None, rng)?;
+
+        // Note: The assignment of the function is the last one, as the assignments of any external calls precede it.
+        let assignments = assignments.read();
+        let Some((assignment, metrics)) = assignments.last() else {
+            bail!("The assignment for function '{function_name}' is missing in '{}'", self.program_id())
+        };
+        Ok(CircuitMetrics {
+            program_id: metrics.program_id,
+            function_name: metrics.function_name,
+            num_instructions: metrics.num_instructions,
+            num_public: assignment.num_public(),
+            num_private: assignment.num_private(),
+            num_constraints: assignment.num_constraints(),
+            num_request_constraints: metrics.num_request_constraints,
+            num_function_constraints: metrics.num_function_constraints,
+            num_response_constraints: metrics.num_response_constraints,
+            num_nonzeros: assignment.num_nonzeros(),
+        })
+    }
+
     /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
     #[inline]
     pub fn synthesize_from_assignment(
@@ -79,11 +90,77 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
-        // Synthesize the proving and verifying key.
-        let (proving_key, verifying_key) = self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
+        // If the circuit key cache is set, load the proving and verifying key from it, if they were
+        // synthesized from the same constraint system. Otherwise, synthesize and store them.
+        let cache = self.circuit_key_cache.read().clone();
+        let (proving_key, verifying_key) = match cache {
+            Some(cache) => {
+                let digest = CircuitKeyCache::digest::<N>(assignment)?;
+                match cache.load(self.program_id(), N::EDITION, function_name, &digest) {
+                    Ok(Some(circuit_key)) => {
+                        #[cfg(feature = "metrics")]
+                        metrics::increment_counter(metrics::process::CIRCUIT_KEY_CACHE_HITS);
+                        circuit_key
+                    }
+                    // Note: An unreadable entry is replaced, instead of failing the synthesis.
+                    Ok(None) | Err(_) => {
+                        #[cfg(feature = "metrics")]
+                        metrics::increment_counter(metrics::process::CIRCUIT_KEY_CACHE_MISSES);
+                        let (proving_key, verifying_key) =
+                            self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?;
+                        cache.store(
+                            self.program_id(),
+                            N::EDITION,
+                            function_name,
+                            &digest,
+                            &proving_key,
+                            &verifying_key,
+                        )?;
+                        (proving_key, verifying_key)
+                    }
+                }
+            }
+            // Synthesize the proving and verifying key.
+            None => self.universal_srs.to_circuit_key(&function_name.to_string(), assignment)?,
+        };
         // Insert the proving key.
         self.insert_proving_key(function_name, proving_key)?;
         // Insert the verifying key.
         self.insert_verifying_key(function_name, verifying_key)
     }
+
+    /// Returns a request for the given function name with sampled inputs, signed by a burner private key.
+    fn sample_burner_request<R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(Request<N>, PrivateKey<N>)> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+        // Retrieve the function input types.
+        let input_types = self.get_function(function_name)?.input_types();
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the request, with a burner private key.
+        let request =
+            Request::sign(&burner_private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+        Ok((request, burner_private_key))
+    }
 }
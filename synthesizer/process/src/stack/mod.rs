@@ -36,9 +36,9 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{CallMetrics, CircuitKeyCache, CircuitMetrics, Process, ProcessError, Trace, traits::*};
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, Signer},
     network::prelude::*,
     program::{
         Entry,
@@ -77,7 +77,7 @@ pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>
 
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
-    Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
+    Authorize(Vec<Request<N>>, Arc<dyn Signer<N> + Send + Sync>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
     Evaluate(Authorization<N>),
@@ -101,8 +101,8 @@ impl<N: Network> CallStack<N> {
     /// Returns a new and independent replica of the call stack.
     pub fn replicate(&self) -> Self {
         match self {
-            CallStack::Authorize(requests, private_key, authorization) => {
-                CallStack::Authorize(requests.clone(), *private_key, authorization.replicate())
+            CallStack::Authorize(requests, signer, authorization) => {
+                CallStack::Authorize(requests.clone(), signer.clone(), authorization.replicate())
             }
             CallStack::Synthesize(requests, private_key, authorization) => {
                 CallStack::Synthesize(requests.clone(), *private_key, authorization.replicate())
@@ -180,6 +180,8 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The on-disk cache of circuit keys, which is shared with the process.
+    circuit_key_cache: Arc<RwLock<Option<CircuitKeyCache>>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -272,6 +272,7 @@ impl<N: Network> RegisterTypes<N> {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Map(map_type)) => Self::check_map(stack, map_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -324,6 +325,7 @@ impl<N: Network> RegisterTypes<N> {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Map(map_type)) => Self::check_map(stack, map_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -539,6 +541,9 @@ impl<N: Network> RegisterTypes<N> {
                             // Ensure the operand types match the element type.
                             self.matches_array(stack, instruction.operands(), array_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Map(..)) => {
+                            bail!("Illegal operation: Cannot cast to a map.")
+                        }
                         CastType::Record(record_name) => {
                             // Ensure the record type is defined in the program.
                             if !stack.program().contains_record(record_name) {
@@ -573,7 +578,7 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
+            Opcode::ECDSA | Opcode::Ed25519 | Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
@@ -649,6 +654,7 @@ impl<N: Network> RegisterTypes<N> {
                 PlaintextType::Literal(..) => (),
                 PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
                 PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
+                PlaintextType::Map(map_type) => Self::check_map(stack, map_type)?,
             }
         }
         Ok(())
@@ -669,6 +675,16 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensure the value type of the map is defined in the program.
+    pub(crate) fn check_map(stack: &(impl StackMatches<N> + StackProgram<N>), map_type: &MapType<N>) -> Result<()> {
+        match map_type.value_type() {
+            PlaintextType::Literal(..) => Ok(()),
+            PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name),
+            PlaintextType::Array(array_type) => Self::check_array(stack, array_type),
+            PlaintextType::Map(..) => bail!("Map '{map_type}' in '{}' cannot contain a map.", stack.program_id()),
+        }
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the `commit` instruction.
     #[inline]
     pub(crate) fn check_commit_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
@@ -768,6 +784,14 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashSha3_512(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.shake128" => ensure!(
+                matches!(instruction, Instruction::HashShake128(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash.shake256" => ensure!(
+                matches!(instruction, Instruction::HashShake256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "hash_many.psd2" => ensure!(
                 matches!(instruction, Instruction::HashManyPSD2(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
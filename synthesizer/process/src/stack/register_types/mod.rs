@@ -23,6 +23,7 @@ use console::{
         EntryType,
         Identifier,
         LiteralType,
+        MapType,
         PlaintextType,
         RecordType,
         Register,
@@ -249,6 +250,7 @@ impl<N: Network> RegisterTypes<N> {
                 }
                 (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
                 | (RegisterRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
+                | (RegisterRefType::Plaintext(PlaintextType::Map(..)), _)
                 | (RegisterRefType::Future(..), Access::Member(..)) => {
                     bail!("Invalid access `{access}`")
                 }
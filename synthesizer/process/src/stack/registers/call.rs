@@ -20,4 +20,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersCall<N> for Registers<N
     fn call_stack(&self) -> CallStack<N> {
         self.call_stack.clone()
     }
+
+    /// Defers the given function call, whose circuit is being synthesized on a separate thread.
+    #[inline]
+    fn defer_call(&mut self, pending_call: PendingCall<N>) {
+        self.pending_calls.push(pending_call);
+    }
 }
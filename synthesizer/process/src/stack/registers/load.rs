@@ -70,7 +70,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             // Ensure the stack value matches the register type.
             Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
             // Ensure the register is defined.
-            Err(error) => bail!("Register '{register}' is not a member of the function: {error}"),
+            Err(error) => {
+                bail!(ProcessError::UndefinedRegister { register: register.to_string(), reason: error.to_string() })
+            }
         };
 
         Ok(stack_value)
@@ -158,7 +160,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
                 stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?
             }
             // Ensure the register is defined.
-            Err(error) => bail!("Register '{register}' is not a member of the function: {error}"),
+            Err(error) => {
+                bail!(ProcessError::UndefinedRegister { register: register.to_string(), reason: error.to_string() })
+            }
         };
 
         Ok(circuit_value)
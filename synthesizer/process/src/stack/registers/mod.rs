@@ -17,7 +17,7 @@ mod caller;
 mod load;
 mod store;
 
-use crate::{CallStack, RegisterTypes, RegistersCall};
+use crate::{CallStack, PendingCall, ProcessError, RegisterTypes, RegistersCall};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
@@ -37,7 +37,6 @@ use synthesizer_program::{
 
 use indexmap::IndexMap;
 
-#[derive(Clone)]
 pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     /// The current call stack.
     call_stack: CallStack<N>,
@@ -59,6 +58,8 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     tvk: Option<Field<N>>,
     /// The transition view key, as a circuit.
     tvk_circuit: Option<circuit::Field<A>>,
+    /// The function calls whose circuits are being synthesized on separate threads, in call order.
+    pending_calls: Vec<PendingCall<N>>,
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
@@ -76,6 +77,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             caller_circuit: None,
             tvk: None,
             tvk_circuit: None,
+            pending_calls: Vec::new(),
         }
     }
 
@@ -98,4 +100,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         }
         Ok(())
     }
+
+    /// Returns the pending function calls, in call order, and clears them.
+    #[inline]
+    pub(crate) fn take_pending_calls(&mut self) -> Vec<PendingCall<N>> {
+        core::mem::take(&mut self.pending_calls)
+    }
 }
@@ -44,7 +44,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
                     // Ensure the stack value matches the register type.
                     Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
                     // Ensure the register is defined.
-                    Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
+                    Err(error) => bail!(ProcessError::UntypedRegister {
+                        register: register.to_string(),
+                        reason: error.to_string()
+                    }),
                 };
 
                 // Store the stack value.
@@ -93,7 +96,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStoreCircuit<N, A> for
                         stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?
                     }
                     // Ensure the register is defined.
-                    Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
+                    Err(error) => bail!(ProcessError::UntypedRegister {
+                        register: register.to_string(),
+                        reason: error.to_string()
+                    }),
                 };
 
                 // Store the stack value.
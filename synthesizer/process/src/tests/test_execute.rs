@@ -1191,6 +1191,166 @@ function transfer:
     // assert_eq!(215810, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_nested_external_calls() {
+    // Initialize the innermost program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program child.aleo;
+
+function magic:
+    input r0 as u64.private;
+    add r0 1u64 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize a program that calls the innermost program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import child.aleo;
+
+program parent.aleo;
+
+function double_magic:
+    input r0 as u64.private;
+    call child.aleo/magic r0 into r1;
+    call child.aleo/magic r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize a program with a nested call tree (root -> parent -> child), followed by another call.
+    let (string, program2) = Program::<CurrentNetwork>::parse(
+        r"
+import child.aleo;
+import parent.aleo;
+
+program root.aleo;
+
+function main:
+    input r0 as u64.private;
+    call parent.aleo/double_magic r0 into r1;
+    call child.aleo/magic r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+    process.add_program(&program2).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str("10u64").unwrap();
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program2.id(), "main", [r0].iter(), rng).unwrap();
+    assert_eq!(authorization.len(), 5);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(Value::from_str("13u64").unwrap(), candidate[0]);
+
+    // Ensure the transitions are in the same order as a sequential execution.
+    let expected = [
+        ("child.aleo", "magic"),
+        ("child.aleo", "magic"),
+        ("parent.aleo", "double_magic"),
+        ("child.aleo", "magic"),
+        ("root.aleo", "main"),
+    ];
+    assert_eq!(trace.transitions().len(), expected.len());
+    for (transition, (program_id, function_name)) in trace.transitions().iter().zip_eq(expected) {
+        assert_eq!(transition.program_id().to_string(), program_id);
+        assert_eq!(transition.function_name().to_string(), function_name);
+    }
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_authorize_with_signer() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+function produce_magic_number:
+    add 1234u64 0u64 into r0;
+    output r0 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program wallet.aleo;
+
+function check:
+    input r0 as u64.private;
+    call token.aleo/produce_magic_number into r1;
+    assert.eq r0 r1;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the caller.
+    let private_key = PrivateKey::<CurrentNetwork>::new(&mut TestRng::default()).unwrap();
+    let caller = Address::try_from(&private_key).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("1234u64").unwrap()];
+
+    // Authorize the function call with the private key, and with an opaque signer.
+    let expected = process
+        .authorize::<CurrentAleo, _>(&private_key, program1.id(), "check", inputs.iter(), &mut TestRng::fixed(1))
+        .unwrap();
+    let candidate = process
+        .authorize_with_signer::<CurrentAleo, _>(
+            Arc::new(private_key),
+            program1.id(),
+            "check",
+            inputs.iter(),
+            &mut TestRng::fixed(1),
+        )
+        .unwrap();
+
+    // Ensure the signer signed both the root request and the external call.
+    assert_eq!(candidate.len(), 2);
+    for (expected, candidate) in expected.to_vec_deque().into_iter().zip_eq(candidate.to_vec_deque()) {
+        assert_eq!(candidate.signer(), &caller);
+        assert!(expected == candidate);
+    }
+
+    // Ensure the authorization evaluates.
+    process.evaluate::<CurrentAleo>(candidate).unwrap();
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_add_set() {
     // Initialize a new program.
@@ -2362,8 +2522,11 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        circuit_key_cache: Default::default(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2483,3 +2646,270 @@ function {function_name}:
     assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
     assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
 }
+
+#[test]
+fn test_process_synthesize_inclusions_in_chunks() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function burn:
+    input r0 as token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program wallet.aleo;
+
+function mint_and_burn:
+    input r0 as address.private;
+    input r1 as u64.private;
+    call token.aleo/mint r0 r1 into r2;
+    call token.aleo/burn r2;
+    call token.aleo/mint r0 r1 into r3;
+    call token.aleo/burn r3;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "mint_and_burn", [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 5);
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+    // Ensure the inclusion circuits cannot be synthesized before the trace is prepared.
+    assert!(trace.num_pending_inclusions().is_err());
+    assert!(trace.synthesize_inclusions::<CurrentAleo>(1).is_err());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Ensure each burned record has a pending inclusion circuit.
+    assert_eq!(trace.num_pending_inclusions().unwrap(), 2);
+
+    // Synthesize the inclusion circuits, one chunk at a time.
+    assert_eq!(trace.synthesize_inclusions::<CurrentAleo>(1).unwrap(), 1);
+    assert_eq!(trace.num_pending_inclusions().unwrap(), 1);
+    assert_eq!(trace.synthesize_inclusions::<CurrentAleo>(1).unwrap(), 0);
+    // Ensure synthesizing beyond the last chunk is a no-op.
+    assert_eq!(trace.synthesize_inclusions::<CurrentAleo>(1).unwrap(), 0);
+    assert_eq!(trace.num_pending_inclusions().unwrap(), 0);
+
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("wallet", rng).unwrap();
+    assert_eq!(execution.len(), 5);
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_aggregate_executions() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as field.private;
+
+function bar:
+    input r0 as field.public;
+    mul r0 r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute 'foo' twice and 'bar' once.
+    let calls = [("foo", vec!["1field", "2field"]), ("bar", vec!["3field"]), ("foo", vec!["4field", "5field"])];
+    let traces = calls
+        .iter()
+        .map(|(function_name, inputs)| {
+            let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap());
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), *function_name, inputs, rng)
+                .unwrap();
+            let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            trace.prepare(Query::from(&block_store)).unwrap();
+            trace
+        })
+        .collect::<Vec<_>>();
+
+    // Aggregate the executions.
+    let (executions, proof) = process.aggregate_executions::<CurrentAleo, _>(&traces, rng).unwrap();
+    assert_eq!(executions.len(), calls.len());
+    assert!(executions.iter().all(|execution| execution.proof().is_none()));
+
+    // Verify the aggregated executions.
+    process.verify_aggregated_executions(&executions, &proof).unwrap();
+
+    // Ensure the proof does not verify for a subset of the executions.
+    assert!(process.verify_aggregated_executions(&executions[..2], &proof).is_err());
+    // Ensure the proof does not verify for a reordering of the executions.
+    let reordered = [executions[2].clone(), executions[1].clone(), executions[0].clone()];
+    assert!(process.verify_aggregated_executions(&reordered, &proof).is_err());
+}
+
+#[test]
+fn test_process_execute_iterated() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program counter.aleo;
+
+function increment:
+    input r0 as u64.public;
+    add r0 1u64 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Increment the counter three times, starting from zero.
+    let (responses, executions, proof) = process
+        .execute_iterated::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            "increment",
+            3,
+            |response| match response {
+                Some(response) => Ok(response.outputs().to_vec()),
+                None => Ok(vec![Value::from_str("0u64")?]),
+            },
+            Query::from(&block_store),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(executions.len(), 3);
+    assert_eq!(responses.last().unwrap().outputs(), [Value::from_str("3u64").unwrap()]);
+
+    // Verify the iterations.
+    process.verify_aggregated_executions(&executions, &proof).unwrap();
+
+    // Ensure iterated execution requires at least one iteration.
+    let result = process.execute_iterated::<CurrentAleo, _>(
+        &caller_private_key,
+        program.id(),
+        "increment",
+        0,
+        |_| Ok(vec![]),
+        Query::from(&block_store),
+        rng,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_process_circuit_metrics() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program metrics.aleo;
+
+function one:
+    input r0 as u64.public;
+    add r0 1u64 into r1;
+    output r1 as u64.private;
+
+function three:
+    input r0 as u64.public;
+    add r0 1u64 into r1;
+    mul.w r1 r1 into r2;
+    add.w r2 r0 into r3;
+    output r3 as u64.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Compute the metrics for each function.
+    let one = process.circuit_metrics::<CurrentAleo, _>(program.id(), "one", rng).unwrap();
+    let three = process.circuit_metrics::<CurrentAleo, _>(program.id(), "three", rng).unwrap();
+
+    for metrics in [&one, &three] {
+        // Ensure the constraint counts add up.
+        assert_eq!(
+            metrics.num_constraints,
+            metrics.num_request_constraints + metrics.num_function_constraints + metrics.num_response_constraints
+        );
+        assert!(metrics.num_public > 1);
+        assert!(metrics.num_private > 0);
+        assert!(metrics.num_nonzeros.0 > 0);
+    }
+    assert_eq!((one.num_instructions, three.num_instructions), (1, 3));
+    // Ensure the additional instructions are reflected in the function constraints.
+    assert_eq!(one.num_request_constraints, three.num_request_constraints);
+    assert!(one.num_function_constraints < three.num_function_constraints);
+
+    // Ensure the metrics do not synthesize the circuit keys.
+    let function_name = Identifier::from_str("one").unwrap();
+    assert!(!process.get_stack(program.id()).unwrap().contains_proving_key(&function_name));
+
+    // Ensure a missing function is rejected.
+    assert!(process.circuit_metrics::<CurrentAleo, _>(program.id(), "missing", rng).is_err());
+}
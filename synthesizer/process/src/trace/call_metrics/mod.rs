@@ -26,3 +26,20 @@ pub struct CallMetrics<N: Network> {
     pub num_function_constraints: u64,
     pub num_response_constraints: u64,
 }
+
+/// The size of the circuit of a single function, excluding the circuits of any external calls.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CircuitMetrics<N: Network> {
+    pub program_id: ProgramID<N>,
+    pub function_name: Identifier<N>,
+    pub num_instructions: usize,
+    /// The number of public variables, including the constant `1`.
+    pub num_public: u64,
+    pub num_private: u64,
+    pub num_constraints: u64,
+    pub num_request_constraints: u64,
+    pub num_function_constraints: u64,
+    pub num_response_constraints: u64,
+    /// The number of non-zero entries in the `A`, `B`, and `C` matrices.
+    pub num_nonzeros: (u64, u64, u64),
+}
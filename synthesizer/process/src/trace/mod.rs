@@ -27,13 +27,19 @@ use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
 use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
 
+use indexmap::IndexMap;
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 #[derive(Clone, Debug, Default)]
 pub struct Trace<N: Network> {
     /// The list of transitions.
     transitions: Vec<Transition<N>>,
+    /// The list of input IDs, for each transition.
+    input_ids: Vec<Vec<InputID<N>>>,
     /// A map of locators to (proving key, assignments) pairs.
     transition_tasks: HashMap<Locator<N>, (ProvingKey<N>, Vec<Assignment<N::Field>>)>,
     /// A tracker for all inclusion tasks.
@@ -43,6 +49,8 @@ pub struct Trace<N: Network> {
 
     /// A tracker for the inclusion assignments.
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
+    /// The inclusion circuit assignments that have been synthesized ahead of proving.
+    inclusion_circuits: Vec<Assignment<N::Field>>,
     /// A tracker for the global state root.
     global_state_root: OnceCell<N::StateRoot>,
 }
@@ -52,9 +60,11 @@ impl<N: Network> Trace<N> {
     pub fn new() -> Self {
         Self {
             transitions: Vec::new(),
+            input_ids: Vec::new(),
             transition_tasks: HashMap::new(),
             inclusion_tasks: Inclusion::new(),
             inclusion_assignments: OnceCell::new(),
+            inclusion_circuits: Vec::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
         }
@@ -93,11 +103,38 @@ impl<N: Network> Trace<N> {
         self.transition_tasks.entry(locator).or_insert((proving_key, vec![])).1.push(assignment);
         // Insert the transition into the list.
         self.transitions.push(transition.clone());
+        // Insert the input IDs into the list.
+        self.input_ids.push(input_ids.to_vec());
         // Insert the call metrics into the list.
         self.call_metrics.push(metrics);
 
         Ok(())
     }
+
+    /// Appends the transitions of the given trace, which was executed independently for a function call.
+    pub(crate) fn append(&mut self, trace: Trace<N>) -> Result<()> {
+        // Ensure the inclusion assignments and global state root have not been set.
+        ensure!(self.inclusion_assignments.get().is_none());
+        ensure!(self.global_state_root.get().is_none());
+
+        let Trace { transitions, input_ids, transition_tasks, call_metrics, .. } = trace;
+
+        // Insert the transitions into the inclusion tasks, in order.
+        // Note: The inclusion tasks of the given trace are recomputed, as they depend on the preceding transitions.
+        for (transition, input_ids) in transitions.iter().zip_eq(&input_ids) {
+            self.inclusion_tasks.insert_transition(input_ids, transition)?;
+        }
+        // Append the assignments (and proving key if the entry does not exist), for each locator.
+        for (locator, (proving_key, assignments)) in transition_tasks {
+            self.transition_tasks.entry(locator).or_insert((proving_key, vec![])).1.extend(assignments);
+        }
+        // Append the transitions, input IDs, and call metrics to the lists.
+        self.transitions.extend(transitions);
+        self.input_ids.extend(input_ids);
+        self.call_metrics.extend(call_metrics);
+
+        Ok(())
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -121,6 +158,15 @@ impl<N: Network> Trace<N> {
 
 impl<N: Network> Trace<N> {
     /// Returns the inclusion assignments and global state root for the current transition(s).
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "trace.prepare",
+            level = "debug",
+            skip_all,
+            fields(num_transitions = self.transitions.len())
+        )
+    )]
     pub fn prepare(&mut self, query: impl QueryTrait<N>) -> Result<()> {
         // Compute the inclusion assignments.
         let (inclusion_assignments, global_state_root) = self.inclusion_tasks.prepare(&self.transitions, query)?;
@@ -134,6 +180,15 @@ impl<N: Network> Trace<N> {
 
     /// Returns the inclusion assignments and global state root for the current transition(s).
     #[cfg(feature = "async")]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "trace.prepare",
+            level = "debug",
+            skip_all,
+            fields(num_transitions = self.transitions.len())
+        )
+    )]
     pub async fn prepare_async(&mut self, query: impl QueryTrait<N>) -> Result<()> {
         // Compute the inclusion assignments.
         let (inclusion_assignments, global_state_root) =
@@ -147,6 +202,15 @@ impl<N: Network> Trace<N> {
     }
 
     /// Returns a new execution with a proof, for the current inclusion assignments and global state root.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "trace.prove_execution",
+            level = "debug",
+            skip_all,
+            fields(locator = %locator, num_transitions = self.transitions.len())
+        )
+    )]
     pub fn prove_execution<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         locator: &str,
@@ -167,14 +231,23 @@ impl<N: Network> Trace<N> {
             self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
+        // Synthesize the inclusion circuits.
+        let batch_inclusions = self.inclusion_circuits::<A>()?;
         // Compute the proof.
-        let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+        let (global_state_root, proof) = Self::prove_batch(
+            locator,
+            proving_tasks,
+            inclusion_assignments,
+            batch_inclusions,
+            *global_state_root,
+            rng,
+        )?;
         // Return the execution.
         Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
     }
 
     /// Returns a new fee with a proof, for the current inclusion assignment and global state root.
+    #[cfg_attr(feature = "tracing-spans", tracing::instrument(name = "trace.prove_fee", level = "debug", skip_all))]
     pub fn prove_fee<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Fee<N>> {
         // Ensure this is a fee.
         let is_fee_public = self.is_fee_public();
@@ -195,11 +268,14 @@ impl<N: Network> Trace<N> {
         let fee_transition = &self.transitions[0];
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
+        // Synthesize the inclusion circuits.
+        let batch_inclusions = self.inclusion_circuits::<A>()?;
         // Compute the proof.
-        let (global_state_root, proof) = Self::prove_batch::<A, R>(
+        let (global_state_root, proof) = Self::prove_batch(
             "credits.aleo/fee (private or public)",
             proving_tasks,
             inclusion_assignments,
+            batch_inclusions,
             *global_state_root,
             rng,
         )?;
@@ -254,12 +330,178 @@ impl<N: Network> Trace<N> {
     }
 }
 
+impl<N: Network> Trace<N> {
+    /// Returns the global state root and a single proof for the transitions in all of the given traces.
+    /// Note: Every trace must be prepared with the same global state root.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "trace.prove_aggregate_executions",
+            level = "debug",
+            skip_all,
+            fields(locator = %locator, num_traces = traces.len())
+        )
+    )]
+    pub fn prove_aggregate_executions<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        locator: &str,
+        traces: &[Trace<N>],
+        rng: &mut R,
+    ) -> Result<(N::StateRoot, Proof<N>)> {
+        // Ensure there are traces to aggregate.
+        ensure!(!traces.is_empty(), "There are no traces to aggregate");
+
+        // Initialize a map of locators to (proving key, assignments) pairs, across all traces.
+        let mut proving_tasks: IndexMap<Locator<N>, (ProvingKey<N>, Vec<Assignment<N::Field>>)> = IndexMap::new();
+        // Initialize a vector for the inclusion assignments, across all traces.
+        let mut inclusion_assignments = Vec::new();
+        // Initialize a vector for the inclusion circuits, across all traces.
+        let mut batch_inclusions = Vec::new();
+        // Initialize a tracker for the global state root.
+        let mut global_state_root = None;
+
+        for trace in traces {
+            // Ensure the trace is for an execution.
+            ensure!(
+                trace.transitions.iter().all(|transition| !(transition.is_fee_private() || transition.is_fee_public())),
+                "The trace cannot aggregate a fee, call 'prove_fee' instead"
+            );
+            // Retrieve the global state root.
+            let root = *trace.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+            // Ensure the global state root is the same across traces.
+            if *global_state_root.get_or_insert(root) != root {
+                bail!("Aggregation expected the global state root to be the same across traces")
+            }
+            // Append the inclusion assignments.
+            inclusion_assignments.extend(
+                trace
+                    .inclusion_assignments
+                    .get()
+                    .ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?
+                    .iter()
+                    .cloned(),
+            );
+            // Append the inclusion circuits.
+            batch_inclusions.extend(trace.inclusion_circuits::<A>()?);
+            // Append the assignments for each locator, in the order they were inserted into the trace.
+            for (locator, (proving_key, assignments)) in trace.transition_tasks.iter() {
+                proving_tasks
+                    .entry(*locator)
+                    .or_insert_with(|| (proving_key.clone(), vec![]))
+                    .1
+                    .extend(assignments.iter().cloned());
+            }
+        }
+
+        // Retrieve the global state root.
+        let Some(global_state_root) = global_state_root else { bail!("Global state root has not been set") };
+        // Compute the proof.
+        let proving_tasks = proving_tasks.into_values().collect();
+        Self::prove_batch(locator, proving_tasks, &inclusion_assignments, batch_inclusions, global_state_root, rng)
+    }
+
+    /// Checks the aggregate proof for the given executions.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_aggregate_executions_proof(
+        locator: &str,
+        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        executions: &[Execution<N>],
+        proof: &Proof<N>,
+    ) -> Result<()> {
+        // Retrieve the global state root.
+        let Some(global_state_root) = executions.first().map(|execution| execution.global_state_root()) else {
+            bail!("There are no executions to verify")
+        };
+        // Ensure the global state root is not zero.
+        if global_state_root == N::StateRoot::default() {
+            bail!("Inclusion expected the global state root in the execution to *not* be zero")
+        }
+        // Ensure the global state root is the same across executions.
+        if executions.iter().any(|execution| execution.global_state_root() != global_state_root) {
+            bail!("Aggregation expected the global state root to be the same across executions")
+        }
+
+        // Construct the batch of inclusion verifier inputs.
+        // Note: The inclusion inputs are constructed per execution, as each execution has its own transaction tree.
+        let mut batch_inclusion_inputs = Vec::new();
+        for execution in executions {
+            batch_inclusion_inputs
+                .extend(Inclusion::prepare_verifier_inputs(global_state_root, execution.transitions())?);
+        }
+        // Insert the batch of inclusion verifier inputs to the verifier inputs.
+        if !batch_inclusion_inputs.is_empty() {
+            // Fetch the inclusion verifying key.
+            let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
+            // Insert the inclusion verifier inputs.
+            verifier_inputs.push((verifying_key, batch_inclusion_inputs));
+        }
+        // Verify the proof.
+        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
+            true => Ok(()),
+            false => bail!("Aggregated executions are invalid - Failed to verify proof"),
+        }
+    }
+}
+
+impl<N: Network> Trace<N> {
+    /// Returns the number of inclusion circuits that have not yet been synthesized.
+    pub fn num_pending_inclusions(&self) -> Result<usize> {
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        Ok(inclusion_assignments.len().saturating_sub(self.inclusion_circuits.len()))
+    }
+
+    /// Synthesizes up to `chunk_size` of the pending inclusion circuits, and returns the number that remain.
+    ///
+    /// This allows a single-threaded caller (e.g. a web worker) to split the synthesis work across calls,
+    /// in order to report progress or yield to its event loop between chunks. Any inclusion circuits that
+    /// remain pending are synthesized when the proof is computed.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "trace.synthesize_inclusions",
+            level = "debug",
+            skip_all,
+            fields(chunk_size = chunk_size)
+        )
+    )]
+    pub fn synthesize_inclusions<A: circuit::Aleo<Network = N>>(&mut self, chunk_size: usize) -> Result<usize> {
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Determine the range of inclusion assignments to synthesize.
+        let start = self.inclusion_circuits.len();
+        let end = start.saturating_add(chunk_size).min(inclusion_assignments.len());
+        // Synthesize the inclusion circuits.
+        for assignment in &inclusion_assignments[start..end] {
+            self.inclusion_circuits.push(assignment.to_circuit_assignment::<A>()?);
+        }
+        Ok(inclusion_assignments.len() - end)
+    }
+
+    /// Returns the inclusion circuits for the current inclusion assignments, synthesizing any that are pending.
+    fn inclusion_circuits<A: circuit::Aleo<Network = N>>(&self) -> Result<Vec<Assignment<N::Field>>> {
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Synthesize the pending inclusion circuits.
+        // Note: Each inclusion circuit is independent, and the circuit environment is thread-local,
+        // so the inclusion circuits are synthesized in parallel.
+        let pending = cfg_iter!(inclusion_assignments[self.inclusion_circuits.len()..])
+            .map(|assignment| assignment.to_circuit_assignment::<A>())
+            .collect::<Result<Vec<_>>>()?;
+        // Return the synthesized and pending inclusion circuits, in order.
+        Ok(self.inclusion_circuits.iter().cloned().chain(pending).collect())
+    }
+}
+
 impl<N: Network> Trace<N> {
     /// Returns the global state root and proof for the given assignments.
-    fn prove_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+    fn prove_batch<R: Rng + CryptoRng>(
         locator: &str,
         mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
         inclusion_assignments: &[InclusionAssignment<N>],
+        batch_inclusions: Vec<Assignment<N::Field>>,
         global_state_root: N::StateRoot,
         rng: &mut R,
     ) -> Result<(N::StateRoot, Proof<N>)> {
@@ -270,18 +512,20 @@ impl<N: Network> Trace<N> {
             bail!("Inclusion expected the global state root in the execution to *not* be zero")
         }
 
-        // Initialize a vector for the batch inclusion assignments.
-        let mut batch_inclusions = Vec::with_capacity(inclusion_assignments.len());
-
-        for assignment in inclusion_assignments.iter() {
-            // Ensure the global state root is the same across iterations.
-            if global_state_root != assignment.state_path.global_state_root() {
-                bail!("Inclusion expected the global state root to be the same across iterations")
-            }
-            // Add the assignment to the assignments.
-            batch_inclusions.push(assignment.to_circuit_assignment::<A>()?);
+        // Ensure the global state root is the same across iterations.
+        if inclusion_assignments.iter().any(|assignment| global_state_root != assignment.state_path.global_state_root())
+        {
+            bail!("Inclusion expected the global state root to be the same across iterations")
         }
 
+        // Ensure there is an inclusion circuit for each inclusion assignment.
+        ensure!(
+            batch_inclusions.len() == inclusion_assignments.len(),
+            "Expected {} inclusion circuits, found {}",
+            inclusion_assignments.len(),
+            batch_inclusions.len()
+        );
+
         if !batch_inclusions.is_empty() {
             // Fetch the inclusion proving key.
             let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{CallStack, Closure, FinalizeTypes, RegisterTypes};
+use crate::{CallStack, Closure, FinalizeTypes, PendingCall, RegisterTypes};
 use console::{
     account::Address,
     network::Network,
@@ -87,4 +87,7 @@ pub trait StackProgramTypes<N: Network> {
 pub trait RegistersCall<N: Network> {
     /// Returns the current call stack.
     fn call_stack(&self) -> CallStack<N>;
+
+    /// Defers the given function call, whose circuit is being synthesized on a separate thread.
+    fn defer_call(&mut self, pending_call: PendingCall<N>);
 }
@@ -17,6 +17,15 @@ use super::*;
 impl<N: Network> Process<N> {
     /// Verifies the given deployment is ordered.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "process.verify_deployment",
+            level = "debug",
+            skip_all,
+            fields(program_id = %deployment.program_id())
+        )
+    )]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         deployment: &Deployment<N>,
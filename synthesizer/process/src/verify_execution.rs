@@ -18,9 +18,43 @@ impl<N: Network> Process<N> {
     /// Verifies the given execution is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "process.verify_execution",
+            level = "debug",
+            skip_all,
+            fields(num_transitions = execution.len())
+        )
+    )]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Construct the locator of the main function, and the verifier inputs for each transition.
+        let (locator, verifier_inputs) = self.to_execution_verifier_inputs(execution)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Construct the list of verifier inputs.
+        let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
+        // Verify the execution proof.
+        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+        lap!(timer, "Verify the proof");
+
+        finish!(timer);
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the locator of the main function, and a map of locators to (verifying key, verifier inputs) pairs,
+    /// for the transitions in the given execution.
+    /// Note: This does *not* verify the execution proof.
+    pub(crate) fn to_execution_verifier_inputs(
+        &self,
+        execution: &Execution<N>,
+    ) -> Result<(String, HashMap<Locator<N>, (VerifyingKey<N>, Vec<Vec<N::Field>>)>)> {
+        let timer = timer!("Process::to_execution_verifier_inputs");
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -75,13 +109,7 @@ impl<N: Network> Process<N> {
 
             // Compute the function ID as `Hash(network_id, program_id, function_name)`.
             let function_id = N::hash_bhp1024(
-                &(
-                    U16::<N>::new(N::ID),
-                    transition.program_id().name(),
-                    transition.program_id().network(),
-                    transition.function_name(),
-                )
-                    .to_bits_le(),
+                &(U16::<N>::new(N::ID), transition.program_id(), transition.function_name()).to_bits_le(),
             )?;
 
             // Ensure each input is valid.
@@ -138,15 +166,8 @@ impl<N: Network> Process<N> {
         // Ensure the number of instances matches the number of transitions.
         ensure!(num_instances == execution.transitions().len(), "The number of verifier instances is incorrect");
 
-        // Construct the list of verifier inputs.
-        let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-
-        lap!(timer, "Verify the proof");
-
         finish!(timer);
-        Ok(())
+        Ok((locator, verifier_inputs))
     }
 }
 
@@ -18,6 +18,15 @@ impl<N: Network> Process<N> {
     /// Verifies the given fee is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "process.verify_fee",
+            level = "debug",
+            skip_all,
+            fields(transition_id = %fee.transition_id())
+        )
+    )]
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
@@ -77,10 +86,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::verify_fee_private");
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id = N::hash_bhp1024(
-            &(U16::<N>::new(N::ID), fee.program_id().name(), fee.program_id().network(), fee.function_name())
-                .to_bits_le(),
-        )?;
+        let function_id = N::hash_bhp1024(&(U16::<N>::new(N::ID), fee.program_id(), fee.function_name()).to_bits_le())?;
 
         // Ensure the fee contains 1 input record.
         ensure!(
@@ -147,10 +153,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::verify_fee_public");
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id = N::hash_bhp1024(
-            &(U16::<N>::new(N::ID), fee.program_id().name(), fee.program_id().network(), fee.function_name())
-                .to_bits_le(),
-        )?;
+        let function_id = N::hash_bhp1024(&(U16::<N>::new(N::ID), fee.program_id(), fee.function_name()).to_bits_le())?;
 
         // Ensure the fee contains all public inputs.
         ensure!(
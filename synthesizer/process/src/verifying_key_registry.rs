@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The edition of a program, which is `0` for the initial deployment.
+pub type Edition = u16;
+
+/// A registry of verifying key IDs, for each `(program ID, function name, edition)`.
+///
+/// Nodes and provers can exchange and compare registries to check that they agree on the circuits,
+/// without exchanging the verifying keys themselves.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerifyingKeyRegistry<N: Network> {
+    /// The mapping of `(program ID, function name, edition)` to verifying key IDs.
+    key_ids: IndexMap<(ProgramID<N>, Identifier<N>, Edition), Field<N>>,
+}
+
+impl<N: Network> VerifyingKeyRegistry<N> {
+    /// Initializes a new, empty registry.
+    pub fn new() -> Self {
+        Self { key_ids: IndexMap::new() }
+    }
+
+    /// Returns the number of registered verifying keys.
+    pub fn len(&self) -> usize {
+        self.key_ids.len()
+    }
+
+    /// Returns `true` if the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.key_ids.is_empty()
+    }
+
+    /// Returns the verifying key ID for the given program ID, function name, and edition.
+    pub fn get(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>, edition: Edition) -> Option<&Field<N>> {
+        self.key_ids.get(&(*program_id, *function_name, edition))
+    }
+
+    /// Returns an iterator over the `((program ID, function name, edition), verifying key ID)` entries.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (&(ProgramID<N>, Identifier<N>, Edition), &Field<N>)> {
+        self.key_ids.iter()
+    }
+
+    /// Registers the verifying key ID for the given program ID, function name, and edition.
+    /// Registering the same ID twice is a no-op, while registering a different ID for the same entry is an error.
+    pub fn insert(
+        &mut self,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        edition: Edition,
+        key_id: Field<N>,
+    ) -> Result<()> {
+        match self.key_ids.get(&(program_id, function_name, edition)) {
+            Some(existing) if *existing != key_id => {
+                bail!("A different verifying key is registered for '{program_id}/{function_name}' (edition {edition})")
+            }
+            Some(_) => Ok(()),
+            None => {
+                self.key_ids.insert((program_id, function_name, edition), key_id);
+                Ok(())
+            }
+        }
+    }
+
+    /// Registers the ID of the given verifying key, for the given program ID, function name, and edition.
+    pub fn insert_verifying_key(
+        &mut self,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        edition: Edition,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        self.insert(program_id, function_name, edition, verifying_key.to_id()?)
+    }
+
+    /// Ensures the given verifying key matches the registered ID for the given program ID, function name, and edition.
+    pub fn check(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        edition: Edition,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        let Some(expected) = self.get(program_id, function_name, edition) else {
+            bail!("No verifying key is registered for '{program_id}/{function_name}' (edition {edition})")
+        };
+        ensure!(
+            *expected == verifying_key.to_id()?,
+            "The verifying key for '{program_id}/{function_name}' (edition {edition}) does not match the registry"
+        );
+        Ok(())
+    }
+
+    /// Returns the entries on which the two registries disagree, as `(entry, self ID, other ID)`,
+    /// where an ID is `None` if the entry is missing from the corresponding registry.
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> Vec<(&'a (ProgramID<N>, Identifier<N>, Edition), Option<&'a Field<N>>, Option<&'a Field<N>>)> {
+        self.key_ids
+            .keys()
+            .chain(other.key_ids.keys().filter(|entry| !self.key_ids.contains_key(*entry)))
+            .filter_map(|entry| {
+                let (a, b) = (self.key_ids.get(entry), other.key_ids.get(entry));
+                (a != b).then_some((entry, a, b))
+            })
+            .collect()
+    }
+}
+
+impl<N: Network> FromBytes for VerifyingKeyRegistry<N> {
+    /// Reads the registry from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid verifying key registry version"));
+        }
+        // Read the number of entries.
+        let num_entries = u32::read_le(&mut reader)?;
+        // Read the entries.
+        let mut registry = Self::new();
+        for _ in 0..num_entries {
+            let program_id = FromBytes::read_le(&mut reader)?;
+            let function_name = FromBytes::read_le(&mut reader)?;
+            let edition = FromBytes::read_le(&mut reader)?;
+            let key_id = FromBytes::read_le(&mut reader)?;
+            registry.insert(program_id, function_name, edition, key_id).map_err(error)?;
+        }
+        Ok(registry)
+    }
+}
+
+impl<N: Network> ToBytes for VerifyingKeyRegistry<N> {
+    /// Writes the registry to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of entries.
+        u32::try_from(self.key_ids.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the entries.
+        for ((program_id, function_name, edition), key_id) in &self.key_ids {
+            program_id.write_le(&mut writer)?;
+            function_name.write_le(&mut writer)?;
+            edition.write_le(&mut writer)?;
+            key_id.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns a registry of the IDs of the verifying keys that are loaded in the process.
+    ///
+    /// Note: Programs cannot be upgraded, so every verifying key is registered with edition `0`.
+    pub fn verifying_key_registry(&self) -> Result<VerifyingKeyRegistry<N>> {
+        let mut registry = VerifyingKeyRegistry::new();
+        for (program_id, stack) in &self.stacks {
+            for function_name in stack.program().functions().keys() {
+                if stack.contains_verifying_key(function_name) {
+                    let verifying_key = stack.get_verifying_key(function_name)?;
+                    registry.insert_verifying_key(*program_id, *function_name, 0, &verifying_key)?;
+                }
+            }
+        }
+        Ok(registry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verifying_key_registry() {
+        let (function_name, _, verifying_key) = crate::test_helpers::sample_key();
+        let program_id = ProgramID::<CurrentNetwork>::from_str("testing.aleo").unwrap();
+        let key_id = verifying_key.to_id().unwrap();
+
+        // Ensure the verifying key ID is deterministic.
+        assert_eq!(key_id, verifying_key.to_id().unwrap());
+
+        let mut registry = VerifyingKeyRegistry::new();
+        assert!(registry.is_empty());
+        registry.insert_verifying_key(program_id, function_name, 0, &verifying_key).unwrap();
+        assert_eq!(registry.get(&program_id, &function_name, 0), Some(&key_id));
+        assert!(registry.check(&program_id, &function_name, 0, &verifying_key).is_ok());
+
+        // Ensure re-registering the same key succeeds, and a conflicting key is rejected.
+        registry.insert(program_id, function_name, 0, key_id).unwrap();
+        assert!(registry.insert(program_id, function_name, 0, Field::from_u8(1)).is_err());
+        assert_eq!(registry.len(), 1);
+
+        // Ensure a missing edition fails the check, and is reported in the diff.
+        assert!(registry.check(&program_id, &function_name, 1, &verifying_key).is_err());
+        let mut other = registry.clone();
+        other.insert(program_id, function_name, 1, Field::from_u8(1)).unwrap();
+        let diff = registry.diff(&other);
+        assert_eq!(diff, vec![(&(program_id, function_name, 1), None, Some(&Field::from_u8(1)))]);
+        assert!(other.diff(&other).is_empty());
+
+        // Ensure the registry round-trips through bytes.
+        let bytes = other.to_bytes_le().unwrap();
+        assert_eq!(other, VerifyingKeyRegistry::read_le(&bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn test_process_verifying_key_registry() {
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let registry = process.verifying_key_registry().unwrap();
+
+        // Ensure every function of `credits.aleo` is registered with the ID of its verifying key.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let program = process.get_program(program_id).unwrap();
+        assert_eq!(registry.len(), program.functions().len());
+        for function_name in program.functions().keys() {
+            let verifying_key = process.get_verifying_key(program_id, *function_name).unwrap();
+            assert!(registry.check(&program_id, function_name, 0, &verifying_key).is_ok());
+        }
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::{EntryType, LiteralType};
+
+use serde_json::{json, Value as JsonValue};
+
+/// The JSON Schema dialect of the generated schemas.
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns a JSON Schema for the struct or record with the given name.
+    ///
+    /// Literals are represented as strings in their Aleo syntax, i.e. `"5u64"`.
+    /// In a record, each literal is suffixed with the visibility of its entry, i.e. `"5u64.private"`.
+    pub fn json_schema(&self, name: &Identifier<N>) -> Result<JsonValue> {
+        let schema = match (self.structs.get(name), self.records.get(name)) {
+            (Some(struct_), _) => self.struct_schema(struct_.name(), None)?,
+            (None, Some(record)) => self.record_schema(record)?,
+            (None, None) => bail!("Struct or record '{name}' is not defined in '{}'.", self.id),
+        };
+
+        // Prepend the dialect and title to the schema.
+        let mut output = serde_json::Map::new();
+        output.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+        output.insert("title".to_string(), json!(format!("{}/{name}", self.id)));
+        if let JsonValue::Object(schema) = schema {
+            output.extend(schema);
+        }
+        Ok(JsonValue::Object(output))
+    }
+
+    /// Returns a JSON Schema for the given record.
+    fn record_schema(&self, record: &RecordType<N>) -> Result<JsonValue> {
+        let mut properties = serde_json::Map::new();
+        // Add the owner, which is always an address.
+        let owner_visibility = match record.owner().is_public() {
+            true => "public",
+            false => "private",
+        };
+        properties.insert("owner".to_string(), literal_schema(LiteralType::Address, Some(owner_visibility)));
+        // Add the entries.
+        for (entry_name, entry_type) in record.entries() {
+            let visibility = match entry_type {
+                EntryType::Constant(..) => "constant",
+                EntryType::Public(..) => "public",
+                EntryType::Private(..) => "private",
+            };
+            let entry_schema = self.plaintext_schema(entry_type.plaintext_type(), Some(visibility))?;
+            properties.insert(entry_name.to_string(), entry_schema);
+        }
+        // Add the nonce, which is always a public group element.
+        properties.insert("_nonce".to_string(), literal_schema(LiteralType::Group, Some("public")));
+
+        Ok(object_schema(properties))
+    }
+
+    /// Returns a JSON Schema for the struct with the given name.
+    fn struct_schema(&self, struct_name: &Identifier<N>, visibility: Option<&str>) -> Result<JsonValue> {
+        let struct_ = self.get_struct(struct_name)?;
+
+        let mut properties = serde_json::Map::new();
+        for (member_name, member_type) in struct_.members() {
+            properties.insert(member_name.to_string(), self.plaintext_schema(member_type, visibility)?);
+        }
+        Ok(object_schema(properties))
+    }
+
+    /// Returns a JSON Schema for the given plaintext type.
+    fn plaintext_schema(&self, plaintext_type: &PlaintextType<N>, visibility: Option<&str>) -> Result<JsonValue> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Ok(literal_schema(*literal_type, visibility)),
+            PlaintextType::Struct(struct_name) => self.struct_schema(struct_name, visibility),
+            PlaintextType::Array(array_type) => Ok(json!({
+                "type": "array",
+                "items": self.plaintext_schema(array_type.next_element_type(), visibility)?,
+                "minItems": **array_type.length(),
+                "maxItems": **array_type.length(),
+            })),
+            PlaintextType::Map(map_type) => {
+                // Note: The visibility of a map in a record applies to the whole map, which JSON cannot express.
+                ensure!(visibility.is_none(), "Map '{map_type}' in a record entry is not supported in a JSON Schema");
+                Ok(json!({
+                    "type": "object",
+                    "propertyNames": literal_schema(*map_type.key_type(), None),
+                    "additionalProperties": self.plaintext_schema(map_type.value_type(), None)?,
+                    "maxProperties": **map_type.max_entries(),
+                }))
+            }
+        }
+    }
+}
+
+/// Returns a JSON Schema for an object with exactly the given properties.
+fn object_schema(properties: serde_json::Map<String, JsonValue>) -> JsonValue {
+    let required = properties.keys().cloned().collect::<Vec<_>>();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Returns a JSON Schema for the given literal type, with an optional visibility suffix.
+fn literal_schema(literal_type: LiteralType, visibility: Option<&str>) -> JsonValue {
+    let pattern = match literal_type {
+        LiteralType::Address => "aleo1[a-z0-9]{58}".to_string(),
+        LiteralType::Boolean => "(true|false)".to_string(),
+        LiteralType::Signature => "sign1[a-z0-9]+".to_string(),
+        LiteralType::String => "\"(?:[^\"\\\\]|\\\\.)*\"".to_string(),
+        // Note: Numeric literals may contain underscores between digits, i.e. `1_000u64`.
+        _ => format!("-?[0-9][0-9_]*{}", literal_type.type_name()),
+    };
+    let suffix = visibility.map(|visibility| format!("\\.{visibility}")).unwrap_or_default();
+    json!({
+        "type": "string",
+        "pattern": format!("^{pattern}{suffix}$"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_json_schema() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program schema.aleo;
+
+struct point:
+    x as i64;
+    y as i64;
+
+struct shape:
+    points as [point; 3u32];
+    labels as [address => u8; 4u32];
+
+record token:
+    owner as address.private;
+    amount as u64.public;
+    origin as point.constant;",
+        )?;
+
+        // Ensure the struct schema nests its members.
+        let schema = program.json_schema(&Identifier::from_str("shape")?)?;
+        let point = json!({
+            "type": "object",
+            "properties": {
+                "x": { "type": "string", "pattern": "^-?[0-9][0-9_]*i64$" },
+                "y": { "type": "string", "pattern": "^-?[0-9][0-9_]*i64$" },
+            },
+            "required": ["x", "y"],
+            "additionalProperties": false,
+        });
+        let expected = json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "title": "schema.aleo/shape",
+            "type": "object",
+            "properties": {
+                "points": { "type": "array", "items": point, "minItems": 3, "maxItems": 3 },
+                "labels": {
+                    "type": "object",
+                    "propertyNames": { "type": "string", "pattern": "^aleo1[a-z0-9]{58}$" },
+                    "additionalProperties": { "type": "string", "pattern": "^-?[0-9][0-9_]*u8$" },
+                    "maxProperties": 4,
+                },
+            },
+            "required": ["points", "labels"],
+            "additionalProperties": false,
+        });
+        assert_eq!(schema, expected);
+
+        // Ensure the record schema suffixes each literal with its visibility.
+        let schema = program.json_schema(&Identifier::from_str("token")?)?;
+        assert_eq!(schema["title"], json!("schema.aleo/token"));
+        assert_eq!(schema["required"], json!(["owner", "amount", "origin", "_nonce"]));
+        assert_eq!(schema["properties"]["owner"]["pattern"], json!("^aleo1[a-z0-9]{58}\\.private$"));
+        assert_eq!(schema["properties"]["amount"]["pattern"], json!("^-?[0-9][0-9_]*u64\\.public$"));
+        assert_eq!(
+            schema["properties"]["origin"]["properties"]["x"]["pattern"],
+            json!("^-?[0-9][0-9_]*i64\\.constant$")
+        );
+        assert_eq!(schema["properties"]["_nonce"]["pattern"], json!("^-?[0-9][0-9_]*group\\.public$"));
+
+        // Ensure an undefined name fails.
+        assert!(program.json_schema(&Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+}
@@ -39,13 +39,20 @@ pub use logic::*;
 mod mapping;
 pub use mapping::*;
 
+mod registry;
+pub use registry::*;
+
 pub mod traits;
 pub use traits::*;
 
 mod bytes;
+mod json_schema;
 mod parse;
 mod serialize;
 
+#[cfg(feature = "python")]
+pub mod python;
+
 use console::{
     network::prelude::{
         alt,
@@ -391,9 +398,36 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         }
                     }
                 }
+                PlaintextType::Map(map_type) => {
+                    let struct_name = match map_type.value_type() {
+                        PlaintextType::Struct(struct_name) => Some(struct_name),
+                        PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                            PlaintextType::Struct(struct_name) => Some(struct_name),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    if let Some(struct_name) = struct_name {
+                        // Ensure the value struct name exists in the program.
+                        if !self.structs.contains_key(struct_name) {
+                            bail!("'{struct_name}' in map '{map_type}' is not defined.")
+                        }
+                    }
+                }
             }
         }
 
+        // Ensure the struct does not exceed the maximum depth.
+        let mut depth = 0;
+        for member_type in struct_.members().values() {
+            depth = depth.max(self.plaintext_type_depth(member_type)?.saturating_add(1));
+        }
+        ensure!(
+            depth <= N::MAX_DATA_DEPTH,
+            "Struct '{struct_name}' exceeds the maximum depth of {}.",
+            N::MAX_DATA_DEPTH
+        );
+
         // Add the struct name to the identifiers.
         if self.identifiers.insert(struct_name, ProgramDefinition::Struct).is_some() {
             bail!("'{}' already exists in the program.", struct_name)
@@ -445,9 +479,35 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         }
                     }
                 }
+                PlaintextType::Map(map_type) => {
+                    let struct_name = match map_type.value_type() {
+                        PlaintextType::Struct(struct_name) => Some(struct_name),
+                        PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                            PlaintextType::Struct(struct_name) => Some(struct_name),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    if let Some(struct_name) = struct_name {
+                        // Ensure the value struct name exists in the program.
+                        if !self.structs.contains_key(struct_name) {
+                            bail!("'{struct_name}' in map '{map_type}' is not defined.")
+                        }
+                    }
+                }
             }
         }
 
+        // Ensure the record does not exceed the maximum depth.
+        for (identifier, entry_type) in record.entries() {
+            let depth = self.plaintext_type_depth(entry_type.plaintext_type())?.saturating_add(1);
+            ensure!(
+                depth <= N::MAX_DATA_DEPTH,
+                "Entry '{identifier}' in record '{record_name}' exceeds the maximum depth of {}.",
+                N::MAX_DATA_DEPTH
+            );
+        }
+
         // Add the record name to the identifiers.
         if self.identifiers.insert(record_name, ProgramDefinition::Record).is_some() {
             bail!("'{record_name}' already exists in the program.")
@@ -632,6 +692,27 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         !self.identifiers.contains_key(name)
     }
 
+    /// Returns the nesting depth of the given plaintext type, where a literal has a depth of zero.
+    fn plaintext_type_depth(&self, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+        let depth = match plaintext_type {
+            PlaintextType::Literal(..) => 0,
+            PlaintextType::Struct(struct_name) => {
+                // Note: Structs are defined before they are referenced, so the recursion terminates.
+                let Some(struct_) = self.structs.get(struct_name) else { bail!("'{struct_name}' is not defined.") };
+                let mut depth = 0;
+                for member_type in struct_.members().values() {
+                    depth = depth.max(self.plaintext_type_depth(member_type)?);
+                }
+                depth.saturating_add(1)
+            }
+            PlaintextType::Array(array_type) => {
+                self.plaintext_type_depth(array_type.next_element_type())?.saturating_add(1)
+            }
+            PlaintextType::Map(map_type) => self.plaintext_type_depth(map_type.value_type())?.saturating_add(1),
+        };
+        Ok(depth)
+    }
+
     /// Returns `true` if the given name is a reserved opcode.
     pub fn is_reserved_opcode(name: &str) -> bool {
         Instruction::is_reserved_opcode(name)
@@ -706,6 +787,25 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_struct_depth() -> Result<()> {
+        // Returns a program with a chain of structs, where the last struct has the given depth.
+        let program_with_depth = |depth: usize| {
+            let mut program = "program unknown.aleo; struct s1: a as field;".to_string();
+            for i in 2..=depth {
+                program.push_str(&format!(" struct s{i}: a as s{}; b as [field; 2u32];", i - 1));
+            }
+            Program::<CurrentNetwork>::from_str(&program)
+        };
+
+        // Ensure a struct at the maximum depth is allowed.
+        assert!(program_with_depth(CurrentNetwork::MAX_DATA_DEPTH).is_ok());
+        // Ensure a struct exceeding the maximum depth is rejected.
+        assert!(program_with_depth(CurrentNetwork::MAX_DATA_DEPTH + 1).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
@@ -105,6 +105,10 @@ pub enum Instruction<N: Network> {
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Computes whether the secp256k1 ECDSA `signature` is valid for the given `public key` and `message`.
+    ECDSAVerify(ECDSAVerify<N>),
+    /// Computes whether the Ed25519 `signature` is valid for the given `public key` and `message`.
+    Ed25519Verify(Ed25519Verify<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -139,6 +143,10 @@ pub enum Instruction<N: Network> {
     HashSha3_384(HashSha3_384<N>),
     /// Performs a SHA-3 hash, outputting 512 bits.
     HashSha3_512(HashSha3_512<N>),
+    /// Performs a SHAKE-128 hash, outputting 256 bits or a `u8` array of up to `N::MAX_ARRAY_ELEMENTS` elements.
+    HashShake128(HashShake128<N>),
+    /// Performs a SHAKE-256 hash, outputting 512 bits or a `u8` array of up to `N::MAX_ARRAY_ELEMENTS` elements.
+    HashShake256(HashShake256<N>),
     /// Performs a Poseidon hash with an input rate of 2.
     HashManyPSD2(HashManyPSD2<N>),
     /// Performs a Poseidon hash with an input rate of 4.
@@ -179,6 +187,10 @@ pub enum Instruction<N: Network> {
     Rem(Rem<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the remainder in `destination`.
     RemWrapped(RemWrapped<N>),
+    /// Rotates the bits of `first` left by `second` bits, storing the outcome in `destination`.
+    RotateLeft(RotateLeft<N>),
+    /// Rotates the bits of `first` right by `second` bits, storing the outcome in `destination`.
+    RotateRight(RotateRight<N>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<N>),
     /// Shifts `first` left by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
@@ -300,6 +312,12 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            ECDSAVerify,
+            Ed25519Verify,
+            HashShake128,
+            HashShake256,
+            RotateLeft,
+            RotateRight,
         }}
     };
     // A variant **without** curly braces:
@@ -469,7 +487,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            74,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
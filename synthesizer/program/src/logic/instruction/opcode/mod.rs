@@ -29,6 +29,10 @@ pub enum Opcode {
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
     Commit(&'static str),
+    /// The opcode is for ECDSA signature verification (i.e. `ecdsa.verify`).
+    ECDSA,
+    /// The opcode is for Ed25519 signature verification (i.e. `ed25519.verify`).
+    Ed25519,
     /// The opcode is for a hash operation (i.e. `hash.psd4`).
     Hash(&'static str),
     /// The opcode is for an 'is' operation (i.e. `is.eq`).
@@ -51,6 +55,8 @@ impl Deref for Opcode {
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
+            Opcode::ECDSA => &"ecdsa.verify",
+            Opcode::Ed25519 => &"ed25519.verify",
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
@@ -76,6 +82,8 @@ impl Display for Opcode {
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
+            Self::ECDSA => write!(f, "{}", self.deref()),
+            Self::Ed25519 => write!(f, "{}", self.deref()),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
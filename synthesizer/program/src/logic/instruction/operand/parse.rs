@@ -92,6 +92,12 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("1field").unwrap().1;
         assert_eq!(Operand::Literal(Literal::from_str("1field")?), operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("0xffu8").unwrap().1;
+        assert_eq!(Operand::Literal(Literal::from_str("255u8")?), operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("0b1010field").unwrap().1;
+        assert_eq!(Operand::Literal(Literal::from_str("10field")?), operand);
+
         let operand = Operand::<CurrentNetwork>::parse("r0").unwrap().1;
         assert_eq!(Operand::Register(Register::from_str("r0")?), operand);
 
@@ -125,10 +125,13 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         let input_a = registers.load_circuit(stack, &self.operands[0])?;
         let input_b = registers.load_circuit(stack, &self.operands[1])?;
 
+        // Label the assertion with the instruction, so that an unsatisfied assertion can be reported.
+        let label = format!("{} {} {}", Self::opcode(), self.operands[0], self.operands[1]);
+
         // Assert the inputs.
         match VARIANT {
-            0 => A::assert(input_a.is_equal(&input_b)),
-            1 => A::assert(input_a.is_not_equal(&input_b)),
+            0 => A::assert_labeled(label, input_a.is_equal(&input_b)),
+            1 => A::assert_labeled(label, input_a.is_not_equal(&input_b)),
             _ => bail!("Invalid 'assert' variant: {VARIANT}"),
         }
         Ok(())
@@ -249,6 +249,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Map(..)) => bail!("Illegal operation: Cannot cast to a map."),
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -486,6 +487,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 // Store the array.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
             }
+            CastType::Plaintext(PlaintextType::Map(..)) => bail!("Illegal operation: Cannot cast to a map."),
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -625,6 +627,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Map(..)) => bail!("Illegal operation: Cannot cast to a map."),
             CastType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
             }
@@ -758,6 +761,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     }
                 }
             }
+            CastType::Plaintext(PlaintextType::Map(..)) => bail!("Illegal operation: Cannot cast to a map."),
             CastType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(record_name)?;
@@ -973,6 +977,8 @@ impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            // Note: Casting to a map is not supported.
+            CastType::Plaintext(PlaintextType::Map(_)) => 0,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         match !operands.is_empty() && (operands.len() <= max_operands) {
@@ -1021,6 +1027,8 @@ impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            // Note: Casting to a map is not supported.
+            CastType::Plaintext(PlaintextType::Map(_)) => 0,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if self.operands.is_empty() || self.operands.len() > max_operands {
@@ -1066,6 +1074,8 @@ impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            // Note: Casting to a map is not supported.
+            CastType::Plaintext(PlaintextType::Map(_)) => 0,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if num_operands.is_zero() || num_operands > max_operands {
@@ -1087,6 +1097,8 @@ impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            // Note: Casting to a map is not supported.
+            CastType::Plaintext(PlaintextType::Map(_)) => 0,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if self.operands.is_empty() || self.operands.len() > max_operands {
@@ -0,0 +1,367 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use circuit::prelude::ToBits as CircuitToBits;
+use console::{
+    algorithms::Secp256k1,
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Boolean, U32},
+};
+
+/// Computes whether the secp256k1 ECDSA `signature` is valid for the given `public key` and `message`,
+/// where the message is hashed with Keccak-256, as in Ethereum.
+///
+/// The signature `r || s` and the public key `x || y` are `[[u8; 32u32]; 2u32]` arrays of big-endian integers,
+/// and the message is a (possibly nested) array of `u8` values.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ECDSAVerify<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> ECDSAVerify<N> {
+    /// Initializes a new `ecdsa.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::ECDSA
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> ECDSAVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes(&registers.load(stack, &self.operands[0])?)?;
+        let public_key = to_bytes(&registers.load(stack, &self.operands[1])?)?;
+        let message = to_bytes(&registers.load(stack, &self.operands[2])?)?;
+
+        // Hash the message.
+        let message_bits = message.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        let digest = N::hash_keccak256(&message_bits)?
+            .chunks(8)
+            .map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | u8::from(*bit)))
+            .collect::<Vec<_>>();
+
+        // Verify the signature.
+        let output = Secp256k1::new().verify(
+            &<[u8; 64]>::try_from(signature.as_slice())?,
+            &<[u8; 64]>::try_from(public_key.as_slice())?,
+            &<[u8; 32]>::try_from(digest.as_slice())?,
+        );
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[0])?)?;
+        let public_key = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[1])?)?;
+        let message = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[2])?)?;
+
+        // Ensure the signature and public key are 64 bytes.
+        ensure!(signature.len() == 64, "Expected a 64-byte signature, found {} bytes", signature.len());
+        ensure!(public_key.len() == 64, "Expected a 64-byte public key, found {} bytes", public_key.len());
+
+        // Hash the message.
+        let message_bits = message.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        let digest = A::hash_keccak256(&message_bits);
+
+        // Verify the signature.
+        let output = circuit::algorithms::Secp256k1::verify(
+            &signature.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>(),
+            &public_key.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>(),
+            &digest,
+        );
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first and second operands are of type `[[u8; 32u32]; 2u32]`.
+        let bytes_type = RegisterType::Plaintext(PlaintextType::Array(ArrayType::new(
+            PlaintextType::Literal(LiteralType::U8),
+            vec![U32::new(2), U32::new(32)],
+        )?));
+        if input_types[0] != bytes_type {
+            bail!(
+                "Instruction '{}' expects the first input to be a '{bytes_type}'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+        if input_types[1] != bytes_type {
+            bail!(
+                "Instruction '{}' expects the second input to be a '{bytes_type}'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is an array of `u8` values.
+        match &input_types[2] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type))
+                if array_type.base_element_type() == &PlaintextType::Literal(LiteralType::U8) => {}
+            _ => bail!(
+                "Instruction '{}' expects the third input to be a 'u8' array. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            ),
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+/// Returns the bytes of the given value, which must be a (possibly nested) array of `u8` values.
+pub(crate) fn to_bytes<N: Network>(value: &Value<N>) -> Result<Vec<u8>> {
+    /// Appends the bytes of the given plaintext to `bytes`.
+    fn append_bytes<N: Network>(plaintext: &Plaintext<N>, bytes: &mut Vec<u8>) -> Result<()> {
+        match plaintext {
+            Plaintext::Literal(Literal::U8(byte), _) => bytes.push(**byte),
+            Plaintext::Array(elements, _) => {
+                elements.iter().try_for_each(|element| append_bytes(element, bytes))?;
+            }
+            _ => bail!("Expected a 'u8' array, found '{plaintext}'"),
+        }
+        Ok(())
+    }
+
+    let mut bytes = Vec::new();
+    match value {
+        Value::Plaintext(plaintext @ Plaintext::Array(..)) => append_bytes(plaintext, &mut bytes)?,
+        _ => bail!("Expected a 'u8' array, found '{value}'"),
+    }
+    Ok(bytes)
+}
+
+/// Returns the bytes of the given value, which must be a (possibly nested) array of `u8` values.
+pub(crate) fn to_bytes_circuit<A: circuit::Aleo>(value: &circuit::Value<A>) -> Result<Vec<circuit::U8<A>>> {
+    /// Appends the bytes of the given plaintext to `bytes`.
+    fn append_bytes<A: circuit::Aleo>(
+        plaintext: &circuit::Plaintext<A>,
+        bytes: &mut Vec<circuit::U8<A>>,
+    ) -> Result<()> {
+        match plaintext {
+            circuit::Plaintext::Literal(circuit::Literal::U8(byte), _) => bytes.push(byte.clone()),
+            circuit::Plaintext::Array(elements, _) => {
+                elements.iter().try_for_each(|element| append_bytes(element, bytes))?;
+            }
+            _ => bail!("Expected a 'u8' array"),
+        }
+        Ok(())
+    }
+
+    let mut bytes = Vec::new();
+    match value {
+        circuit::Value::Plaintext(plaintext @ circuit::Plaintext::Array(..)) => append_bytes(plaintext, &mut bytes)?,
+        _ => bail!("Expected a 'u8' array"),
+    }
+    Ok(bytes)
+}
+
+impl<N: Network> Parser for ECDSAVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for ECDSAVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ECDSAVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ECDSAVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for ECDSAVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for ECDSAVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) = ECDSAVerify::<CurrentNetwork>::parse("ecdsa.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(is.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+}
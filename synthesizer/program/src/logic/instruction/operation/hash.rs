@@ -20,6 +20,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U8,
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -57,6 +58,13 @@ pub type HashSha3_384<N> = HashInstruction<N, { Hasher::HashSha3_384 as u8 }>;
 /// SHA3-512 is a cryptographic hash function that outputs a 512-bit digest.
 pub type HashSha3_512<N> = HashInstruction<N, { Hasher::HashSha3_512 as u8 }>;
 
+/// SHAKE-128 is an extendable-output function that outputs a 256-bit digest, or a `u8` array of up to
+/// `N::MAX_ARRAY_ELEMENTS` elements.
+pub type HashShake128<N> = HashInstruction<N, { Hasher::HashShake128 as u8 }>;
+/// SHAKE-256 is an extendable-output function that outputs a 512-bit digest, or a `u8` array of up to
+/// `N::MAX_ARRAY_ELEMENTS` elements.
+pub type HashShake256<N> = HashInstruction<N, { Hasher::HashShake256 as u8 }>;
+
 /// Poseidon2 is a cryptographic hash function that processes inputs in 2-field chunks.
 pub type HashManyPSD2<N> = HashInstruction<N, { Hasher::HashManyPSD2 as u8 }>;
 /// Poseidon4 is a cryptographic hash function that processes inputs in 4-field chunks.
@@ -83,6 +91,8 @@ enum Hasher {
     HashManyPSD2,
     HashManyPSD4,
     HashManyPSD8,
+    HashShake128,
+    HashShake256,
 }
 
 /// Returns the expected number of operands given the variant.
@@ -104,14 +114,34 @@ fn check_number_of_operands(variant: u8, opcode: Opcode, num_operands: usize) ->
 }
 
 /// Returns 'true' if the destination type is valid.
-fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) -> bool {
-    !matches!(
-        destination_type,
-        PlaintextType::Literal(LiteralType::Boolean)
-            | PlaintextType::Literal(LiteralType::String)
-            | PlaintextType::Struct(..)
-            | PlaintextType::Array(..)
-    )
+fn is_valid_destination_type<N: Network>(variant: u8, destination_type: &PlaintextType<N>) -> bool {
+    const HASH_SHAKE_128: u8 = Hasher::HashShake128 as u8;
+    const HASH_SHAKE_256: u8 = Hasher::HashShake256 as u8;
+
+    match (variant, destination_type) {
+        // The extendable-output functions may also hash into a `u8` array.
+        (HASH_SHAKE_128 | HASH_SHAKE_256, PlaintextType::Array(..)) => num_output_bits(destination_type).is_some(),
+        _ => !matches!(
+            destination_type,
+            PlaintextType::Literal(LiteralType::Boolean)
+                | PlaintextType::Literal(LiteralType::String)
+                | PlaintextType::Struct(..)
+                | PlaintextType::Array(..)
+                | PlaintextType::Map(..)
+        ),
+    }
+}
+
+/// Returns the number of output bits, if the destination type is a `u8` array that fits in the output length.
+fn num_output_bits<N: Network>(destination_type: &PlaintextType<N>) -> Option<u16> {
+    match destination_type {
+        PlaintextType::Array(array_type)
+            if array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8) =>
+        {
+            u16::try_from(**array_type.length()).ok()?.checked_mul(8)
+        }
+        _ => None,
+    }
 }
 
 /// Hashes the operand into the declared type.
@@ -136,7 +166,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Sanity check the number of operands.
         check_number_of_operands(VARIANT, Self::opcode(), operands.len())?;
         // Sanity check the destination type.
-        if !is_valid_destination_type(&destination_type) {
+        if !is_valid_destination_type(VARIANT, &destination_type) {
             bail!("Invalid destination type for 'hash' instruction")
         }
         // Return the instruction.
@@ -165,7 +195,9 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             15 => Opcode::Hash("hash_many.psd2"),
             16 => Opcode::Hash("hash_many.psd4"),
             17 => Opcode::Hash("hash_many.psd8"),
-            18.. => panic!("Invalid 'hash' instruction opcode"),
+            18 => Opcode::Hash("hash.shake128"),
+            19 => Opcode::Hash("hash.shake256"),
+            20.. => panic!("Invalid 'hash' instruction opcode"),
         }
     }
 
@@ -206,10 +238,31 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
+
+        // If the destination is a `u8` array, squeeze the output of the extendable-output function into it.
+        if let Some(num_bits) = num_output_bits(&self.destination_type) {
+            let output = match VARIANT {
+                18 => N::hash_shake128(&input.to_bits_le(), num_bits),
+                19 => N::hash_shake256(&input.to_bits_le(), num_bits),
+                _ => bail!("Cannot hash into an array (yet)"),
+            };
+            // Pack the output bits into bytes.
+            let elements = output
+                .chunks(8)
+                .map(|bits| Ok(Plaintext::from(Literal::U8(U8::from_bits_le(bits)?))))
+                .collect::<Result<Vec<_>>>()?;
+            // Store the output.
+            let output = Value::Plaintext(Plaintext::Array(elements, Default::default()));
+            return registers.store(stack, &self.destination, output);
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => Literal::Group(N::hash_to_group_bhp256(&input.to_bits_le())?),
@@ -251,15 +304,23 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (18, PlaintextType::Literal(..)) => {
+                Literal::Group(N::hash_to_group_bhp256(&N::hash_shake128(&input.to_bits_le(), 256))?)
+            }
+            (19, PlaintextType::Literal(..)) => {
+                Literal::Group(N::hash_to_group_bhp512(&N::hash_shake256(&input.to_bits_le(), 512))?)
+            }
+            (20.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Map(..)) => bail!("Cannot hash into a map"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Map(..) => bail!("Cannot hash into a map"),
         };
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
@@ -272,15 +333,41 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         stack: &(impl StackMatches<N> + StackProgram<N>),
         registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
     ) -> Result<()> {
-        use circuit::traits::{ToBits, ToFields};
+        use circuit::traits::{FromBits, ToBits, ToFields};
 
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
+
+        // If the destination is a `u8` array, squeeze the output of the extendable-output function into it.
+        if let Some(num_bits) = num_output_bits(&self.destination_type) {
+            let output = match VARIANT {
+                18 => A::hash_shake128(&input.to_bits_le(), num_bits),
+                19 => A::hash_shake256(&input.to_bits_le(), num_bits),
+                _ => bail!("Cannot hash into an array (yet)"),
+            };
+            // Pack the output bits into bytes.
+            let elements = output
+                .chunks(8)
+                .map(|bits| {
+                    circuit::Plaintext::Literal(
+                        circuit::Literal::U8(circuit::U8::from_bits_le(bits)),
+                        Default::default(),
+                    )
+                })
+                .collect();
+            // Store the output.
+            let output = circuit::Value::Plaintext(circuit::Plaintext::Array(elements, Default::default()));
+            return registers.store_circuit(stack, &self.destination, output);
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => circuit::Literal::Group(A::hash_to_group_bhp256(&input.to_bits_le())),
@@ -322,15 +409,23 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (18, PlaintextType::Literal(..)) => {
+                circuit::Literal::Group(A::hash_to_group_bhp256(&A::hash_shake128(&input.to_bits_le(), 256)))
+            }
+            (19, PlaintextType::Literal(..)) => {
+                circuit::Literal::Group(A::hash_to_group_bhp512(&A::hash_shake256(&input.to_bits_le(), 512)))
+            }
+            (20.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Map(..)) => bail!("Cannot hash into a map"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Map(..) => bail!("Cannot hash into a map"),
         };
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
@@ -360,14 +455,17 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
+            0..=14 | 18 | 19 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
             15..=17 => bail!("'hash_many' is not yet implemented"),
-            18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
+            20.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
@@ -27,6 +27,12 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod ecdsa_verify;
+pub use ecdsa_verify::*;
+
+mod ed25519_verify;
+pub use ed25519_verify::*;
+
 mod hash;
 pub use hash::*;
 
@@ -525,6 +531,82 @@ crate::operation!(
     }
 );
 
+/// Rotates the bits of `first` left by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
+pub type RotateLeft<N> = BinaryLiteral<N, RotateLeftOperation<N>>;
+
+crate::operation!(
+    pub struct RotateLeftOperation<console::prelude::RotateLeft, circuit::traits::RotateLeft, rotate_left, "rotl"> {
+        (I8, U8) => I8,
+        (I8, U16) => I8,
+        (I8, U32) => I8,
+        (I16, U8) => I16,
+        (I16, U16) => I16,
+        (I16, U32) => I16,
+        (I32, U8) => I32,
+        (I32, U16) => I32,
+        (I32, U32) => I32,
+        (I64, U8) => I64,
+        (I64, U16) => I64,
+        (I64, U32) => I64,
+        (I128, U8) => I128,
+        (I128, U16) => I128,
+        (I128, U32) => I128,
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
+/// Rotates the bits of `first` right by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
+pub type RotateRight<N> = BinaryLiteral<N, RotateRightOperation<N>>;
+
+crate::operation!(
+    pub struct RotateRightOperation<console::prelude::RotateRight, circuit::traits::RotateRight, rotate_right, "rotr"> {
+        (I8, U8) => I8,
+        (I8, U16) => I8,
+        (I8, U32) => I8,
+        (I16, U8) => I16,
+        (I16, U16) => I16,
+        (I16, U32) => I16,
+        (I32, U8) => I32,
+        (I32, U16) => I32,
+        (I32, U32) => I32,
+        (I64, U8) => I64,
+        (I64, U16) => I64,
+        (I64, U32) => I64,
+        (I128, U8) => I128,
+        (I128, U16) => I128,
+        (I128, U32) => I128,
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
 /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
 pub type Shl<N> = BinaryLiteral<N, ShlOperation<N>>;
 
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The program API for Python, with `pyo3` types for the current network.
+
+use console::{
+    account::python::{to_py_error, CurrentNetwork},
+    network::prelude::*,
+};
+
+use pyo3::prelude::*;
+
+/// An Aleo program.
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct Program(crate::Program<CurrentNetwork>);
+
+#[pymethods]
+impl Program {
+    /// Returns the program for the given source code.
+    #[staticmethod]
+    pub fn from_string(program: &str) -> PyResult<Self> {
+        crate::Program::from_str(program).map(Self).map_err(to_py_error)
+    }
+
+    /// Returns the 'credits.aleo' program.
+    #[staticmethod]
+    pub fn credits() -> PyResult<Self> {
+        crate::Program::credits().map(Self).map_err(to_py_error)
+    }
+
+    /// Returns the program ID.
+    #[getter]
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the IDs of the imported programs.
+    pub fn imports(&self) -> Vec<String> {
+        self.0.imports().keys().map(ToString::to_string).collect()
+    }
+
+    /// Returns the names of the mappings.
+    pub fn mappings(&self) -> Vec<String> {
+        self.0.mappings().keys().map(ToString::to_string).collect()
+    }
+
+    /// Returns the names of the structs.
+    pub fn structs(&self) -> Vec<String> {
+        self.0.structs().keys().map(ToString::to_string).collect()
+    }
+
+    /// Returns the names of the records.
+    pub fn records(&self) -> Vec<String> {
+        self.0.records().keys().map(ToString::to_string).collect()
+    }
+
+    /// Returns the names of the functions.
+    pub fn functions(&self) -> Vec<String> {
+        self.0.functions().keys().map(ToString::to_string).collect()
+    }
+
+    /// Returns the source code of the program.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<crate::Program<CurrentNetwork>> for Program {
+    /// Returns the Python program for the given program.
+    fn from(program: crate::Program<CurrentNetwork>) -> Self {
+        Self(program)
+    }
+}
+
+impl Deref for Program {
+    type Target = crate::Program<CurrentNetwork>;
+
+    /// Returns the program.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Adds the program classes to the given Python module.
+pub fn register(module: &PyModule) -> PyResult<()> {
+    module.add_class::<Program>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program() {
+        let program = Program::credits().unwrap();
+        assert_eq!(program.id(), "credits.aleo");
+        assert_eq!(program.mappings(), ["committee", "bonded", "unbonding", "account"]);
+        assert!(program.functions().contains(&"transfer_public".to_string()));
+        assert_eq!(Program::from_string(&program.__str__()).unwrap().__str__(), program.__str__());
+
+        assert!(Program::from_string("program token.aleo; function").is_err());
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Program;
+use console::{
+    network::prelude::*,
+    program::{Entry, EntryType, Identifier, Plaintext, PlaintextType, ProgramID, Record, RecordType},
+};
+
+use indexmap::IndexMap;
+
+/// A registry of deployed programs, used to decode program data given only a program ID and type name.
+#[derive(Clone)]
+pub struct ProgramRegistry<N: Network> {
+    /// The programs, keyed by program ID.
+    programs: IndexMap<ProgramID<N>, Program<N>>,
+}
+
+impl<N: Network> Default for ProgramRegistry<N> {
+    /// Initializes a new, empty registry.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ProgramRegistry<N> {
+    /// Initializes a new, empty registry.
+    pub fn new() -> Self {
+        Self { programs: IndexMap::new() }
+    }
+
+    /// Adds the given program to the registry.
+    pub fn add_program(&mut self, program: &Program<N>) -> Result<()> {
+        let program_id = program.id();
+        match self.programs.get(program_id) {
+            // Note: A deployed program is immutable, so a program may only be added again if it is unchanged.
+            Some(existing) => ensure!(existing == program, "Program '{program_id}' already exists in the registry"),
+            None => {
+                self.programs.insert(*program_id, program.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the registry contains the given program ID.
+    pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
+        self.programs.contains_key(program_id)
+    }
+
+    /// Returns the program for the given program ID.
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<&Program<N>> {
+        self.programs.get(program_id).ok_or_else(|| anyhow!("Program '{program_id}' is not in the registry"))
+    }
+
+    /// Returns the program IDs in the registry.
+    pub fn program_ids(&self) -> impl '_ + ExactSizeIterator<Item = &ProgramID<N>> {
+        self.programs.keys()
+    }
+
+    /// Decodes the given plaintext bytes, ensuring they match the struct with the given name in the given program.
+    pub fn decode_plaintext(
+        &self,
+        program_id: &ProgramID<N>,
+        struct_name: &Identifier<N>,
+        bytes: &[u8],
+    ) -> Result<Plaintext<N>> {
+        // Retrieve the program.
+        let program = self.get_program(program_id)?;
+        // Ensure the struct exists in the program.
+        ensure!(program.contains_struct(struct_name), "Struct '{struct_name}' is not defined in '{program_id}'");
+        // Decode the plaintext.
+        let plaintext = decode_exact::<Plaintext<N>>(bytes)?;
+        // Ensure the plaintext matches the struct.
+        check_plaintext(program, &plaintext, &PlaintextType::Struct(*struct_name), 0)?;
+        Ok(plaintext)
+    }
+
+    /// Decodes the given record bytes, ensuring they match the record with the given name in the given program.
+    pub fn decode_record(
+        &self,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        bytes: &[u8],
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Retrieve the program.
+        let program = self.get_program(program_id)?;
+        // Retrieve the record type.
+        let Ok(record_type) = program.get_record(record_name) else {
+            bail!("Record '{record_name}' is not defined in '{program_id}'")
+        };
+        // Decode the record.
+        let record = decode_exact::<Record<N, Plaintext<N>>>(bytes)?;
+        // Ensure the record matches the record type.
+        check_record(program, &record, record_type)?;
+        Ok(record)
+    }
+}
+
+/// Decodes a value from the given bytes, ensuring all of the bytes are consumed.
+fn decode_exact<T: FromBytes>(bytes: &[u8]) -> Result<T> {
+    let mut reader = bytes;
+    let value = T::read_le(&mut reader)?;
+    ensure!(reader.is_empty(), "Found {} trailing bytes after the decoded value", reader.len());
+    Ok(value)
+}
+
+/// Checks that the given record matches the layout of the record type.
+fn check_record<N: Network>(
+    program: &Program<N>,
+    record: &Record<N, Plaintext<N>>,
+    record_type: &RecordType<N>,
+) -> Result<()> {
+    let record_name = record_type.name();
+    // Ensure the visibility of the record owner matches.
+    ensure!(
+        record.owner().is_public() == record_type.owner().is_public(),
+        "Visibility of record entry '{record_name}.owner' does not match"
+    );
+    // Ensure the record entries match, in the same order.
+    let (num_entries, expected_num_entries) = (record.data().len(), record_type.entries().len());
+    ensure!(
+        num_entries == expected_num_entries,
+        "'{record_name}' expected {expected_num_entries} entries, found {num_entries} entries"
+    );
+    for ((expected_name, entry_type), (entry_name, entry)) in record_type.entries().iter().zip_eq(record.data()) {
+        ensure!(expected_name == entry_name, "'{record_name}' expected entry '{expected_name}', found '{entry_name}'");
+        match (entry, entry_type) {
+            (Entry::Constant(plaintext), EntryType::Constant(plaintext_type))
+            | (Entry::Public(plaintext), EntryType::Public(plaintext_type))
+            | (Entry::Private(plaintext), EntryType::Private(plaintext_type)) => {
+                check_plaintext(program, plaintext, plaintext_type, 1)
+                    .map_err(|error| anyhow!("Invalid record entry '{record_name}.{entry_name}': {error}"))?
+            }
+            _ => bail!("Visibility of record entry '{record_name}.{entry_name}' does not match"),
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the given plaintext matches the layout of the plaintext type.
+fn check_plaintext<N: Network>(
+    program: &Program<N>,
+    plaintext: &Plaintext<N>,
+    plaintext_type: &PlaintextType<N>,
+    depth: usize,
+) -> Result<()> {
+    // Ensure the depth does not exceed the maximum.
+    ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+    match (plaintext, plaintext_type) {
+        (Plaintext::Literal(literal, ..), PlaintextType::Literal(literal_type)) => {
+            ensure!(literal.to_type() == *literal_type, "Expected {literal_type}, found {literal}");
+        }
+        (Plaintext::Struct(members, ..), PlaintextType::Struct(struct_name)) => {
+            let struct_ = program.get_struct(struct_name)?;
+            // Ensure the struct members match, in the same order.
+            let (num_members, expected_num_members) = (members.len(), struct_.members().len());
+            ensure!(
+                num_members == expected_num_members,
+                "'{struct_name}' expected {expected_num_members} members, found {num_members} members"
+            );
+            for ((expected_name, member_type), (member_name, member)) in struct_.members().iter().zip_eq(members) {
+                ensure!(
+                    expected_name == member_name,
+                    "'{struct_name}' expected member '{expected_name}', found '{member_name}'"
+                );
+                check_plaintext(program, member, member_type, depth + 1)?;
+            }
+        }
+        (Plaintext::Array(elements, ..), PlaintextType::Array(array_type)) => {
+            // Ensure the array length matches.
+            let (length, expected_length) = (elements.len(), **array_type.length() as usize);
+            ensure!(length == expected_length, "'{plaintext_type}' expected {expected_length} elements, found {length}");
+            for element in elements {
+                check_plaintext(program, element, array_type.next_element_type(), depth + 1)?;
+            }
+        }
+        (Plaintext::Map(entries, ..), PlaintextType::Map(map_type)) => {
+            // Ensure the number of entries does not exceed the maximum.
+            let (num_entries, max_entries) = (entries.len(), **map_type.max_entries() as usize);
+            ensure!(num_entries <= max_entries, "'{plaintext_type}' expected at most {max_entries} entries");
+            // Ensure the entries are in canonical order, with unique keys.
+            Plaintext::check_map_entries(entries)?;
+            for (key, value) in entries {
+                ensure!(key.to_type() == *map_type.key_type(), "Expected key {}, found {key}", map_type.key_type());
+                check_plaintext(program, value, map_type.value_type(), depth + 1)?;
+            }
+        }
+        _ => bail!("'{plaintext}' does not match '{plaintext_type}'"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_registry() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program registry.aleo;
+
+struct point:
+    x as u32;
+    y as [u32; 2u32];
+
+record token:
+    owner as address.private;
+    amount as u64.public;
+    origin as point.private;
+
+function noop:
+    input r0 as u8.private;",
+        )?;
+        let program_id = ProgramID::from_str("registry.aleo")?;
+        let (point, token) = (Identifier::from_str("point")?, Identifier::from_str("token")?);
+
+        // Add the program to the registry.
+        let mut registry = ProgramRegistry::<CurrentNetwork>::new();
+        assert!(registry.get_program(&program_id).is_err());
+        registry.add_program(&program)?;
+        assert!(registry.contains_program(&program_id));
+        // Ensure the program may be added again, but not changed.
+        registry.add_program(&program)?;
+        let changed = Program::from_str(&program.to_string().replace("as u8.private", "as u16.private"))?;
+        assert!(registry.add_program(&changed).is_err());
+
+        // Ensure a struct is decoded.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ x: 1u32, y: [2u32, 3u32] }")?;
+        assert_eq!(registry.decode_plaintext(&program_id, &point, &plaintext.to_bytes_le()?)?, plaintext);
+        // Ensure a struct with the wrong layout, trailing bytes, or an unknown name fails.
+        let invalid = Plaintext::<CurrentNetwork>::from_str("{ x: 1u32, y: [2u32, 3u32, 4u32] }")?;
+        assert!(registry.decode_plaintext(&program_id, &point, &invalid.to_bytes_le()?).is_err());
+        let trailing = [plaintext.to_bytes_le()?, vec![0u8]].concat();
+        assert!(registry.decode_plaintext(&program_id, &point, &trailing).is_err());
+        assert!(registry.decode_plaintext(&program_id, &token, &plaintext.to_bytes_le()?).is_err());
+
+        // Ensure a record is decoded.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 5u64.public, origin: { x: 1u32.private, y: [2u32.private, 3u32.private] }, _nonce: 0group.public }",
+        )?;
+        assert_eq!(registry.decode_record(&program_id, &token, &record.to_bytes_le()?)?, record);
+        // Ensure a record with a mismatched visibility fails.
+        let invalid = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 5u64.private, origin: { x: 1u32.private, y: [2u32.private, 3u32.private] }, _nonce: 0group.public }",
+        )?;
+        assert!(registry.decode_record(&program_id, &token, &invalid.to_bytes_le()?).is_err());
+        // Ensure an unknown program fails.
+        let unknown = ProgramID::from_str("unknown.aleo")?;
+        assert!(registry.decode_record(&unknown, &token, &record.to_bytes_le()?).is_err());
+        Ok(())
+    }
+}
@@ -160,6 +160,7 @@ pub trait RegistersLoad<N: Network> {
             Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
             Value::Plaintext(Plaintext::Struct(..))
             | Value::Plaintext(Plaintext::Array(..))
+            | Value::Plaintext(Plaintext::Map(..))
             | Value::Record(..)
             | Value::Future(..) => {
                 bail!("Operand must be a literal")
@@ -214,6 +215,7 @@ pub trait RegistersLoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
             circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal),
             circuit::Value::Plaintext(circuit::Plaintext::Struct(..))
             | circuit::Value::Plaintext(circuit::Plaintext::Array(..))
+            | circuit::Value::Plaintext(circuit::Plaintext::Map(..))
             | circuit::Value::Record(..)
             | circuit::Value::Future(..) => bail!("Operand must be a literal"),
         }
@@ -39,6 +39,8 @@ use snarkvm_synthesizer_program::{
     HashSha3_256,
     HashSha3_384,
     HashSha3_512,
+    HashShake128,
+    HashShake256,
     Opcode,
     Operand,
     Program,
@@ -183,6 +185,18 @@ fn check_hash<const VARIANT: u8>(
                     "The output type is inconsistent with the declared type"
                 );
             }
+            Value::Plaintext(Plaintext::Array(elements, _)) => {
+                let PlaintextType::Array(array_type) = &destination_type else {
+                    unreachable!("The output type is inconsistent with the declared type")
+                };
+                assert_eq!(elements.len(), **array_type.length() as usize, "The output length is inconsistent");
+                for element in elements {
+                    assert!(
+                        matches!(element, Plaintext::Literal(Literal::U8(..), _)),
+                        "The output type is inconsistent with the declared type"
+                    );
+                }
+            }
             _ => unreachable!("The output type is inconsistent with the declared type"),
         }
     }
@@ -245,6 +259,59 @@ test_hash!(hash_sha3_256, HashSha3_256, 5);
 test_hash!(hash_sha3_384, HashSha3_384, 5);
 test_hash!(hash_sha3_512, HashSha3_512, 5);
 
+test_hash!(hash_shake128, HashShake128, 5);
+test_hash!(hash_shake256, HashShake256, 5);
+
+#[test]
+fn test_hash_shake_into_array_is_consistent() {
+    // Prepare the rng.
+    let mut rng = TestRng::default();
+
+    // Prepare the test.
+    let modes = [circuit::Mode::Public, circuit::Mode::Private];
+    let destination_types = ["[u8; 1u32]", "[u8; 16u32]", "[u8; 24u32]", "[u8; 32u32]"]
+        .map(|string| PlaintextType::<CurrentNetwork>::from_str(string).unwrap());
+
+    macro_rules! check_hash {
+        ($operation:tt) => {
+            let literals = sample_literals!(CurrentNetwork, &mut rng);
+            for literal in literals.iter() {
+                for mode in modes.iter() {
+                    for destination_type in destination_types.iter() {
+                        check_hash(
+                            |operands, destination, destination_type| {
+                                $operation::<CurrentNetwork>::new(operands, destination, destination_type).unwrap()
+                            },
+                            $operation::<CurrentNetwork>::opcode(),
+                            literal,
+                            mode,
+                            destination_type.clone(),
+                        );
+                    }
+                }
+            }
+        };
+    }
+    check_hash!(HashShake128);
+    check_hash!(HashShake256);
+}
+
+#[test]
+fn test_hash_into_array_is_rejected() {
+    let destination_type = PlaintextType::<CurrentNetwork>::from_str("[u8; 32u32]").unwrap();
+    let operands = vec![Operand::Register(Register::Locator(0))];
+
+    // Ensure only the extendable-output functions may hash into a `u8` array.
+    assert!(
+        HashShake128::<CurrentNetwork>::new(operands.clone(), Register::Locator(1), destination_type.clone()).is_ok()
+    );
+    assert!(HashSha3_256::<CurrentNetwork>::new(operands.clone(), Register::Locator(1), destination_type).is_err());
+
+    // Ensure the extendable-output functions only hash into a `u8` array.
+    let destination_type = PlaintextType::<CurrentNetwork>::from_str("[u16; 32u32]").unwrap();
+    assert!(HashShake256::<CurrentNetwork>::new(operands, Register::Locator(1), destination_type).is_err());
+}
+
 // Note this test must be explicitly written, instead of using the macro, because HashPED64 fails on certain input types.
 #[test]
 fn test_hash_ped64_is_consistent() {
@@ -17,7 +17,10 @@
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(not(feature = "aleo-cli"), allow(unused_variables))]
 
-use console::network::{prelude::*, FiatShamir};
+use console::{
+    network::{prelude::*, FiatShamir},
+    types::Field,
+};
 use snarkvm_algorithms::{snark::varuna, traits::SNARK};
 
 use once_cell::sync::OnceCell;
@@ -55,4 +55,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_compressed_bytes() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Check the compressed byte representation.
+        let compressed_bytes = expected.to_compressed_bytes()?;
+        assert_eq!(expected, Proof::from_compressed_bytes(&compressed_bytes)?);
+        // Ensure the compressed byte representation is smaller.
+        assert!(compressed_bytes.len() < expected.to_bytes_le()?.len());
+
+        Ok(())
+    }
 }
@@ -29,6 +29,11 @@ impl<N: Network> Proof<N> {
     pub(super) const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
         Self { proof }
     }
+
+    /// Returns the proof from its compressed encoding, as produced by `to_compressed_bytes`.
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::new(varuna::Proof::from_compressed_bytes(bytes)?))
+    }
 }
 
 impl<N: Network> Deref for Proof<N> {
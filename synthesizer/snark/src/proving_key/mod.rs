@@ -39,7 +39,7 @@ impl<N: Network> ProvingKey<N> {
         assignment: &circuit::Assignment<N::Field>,
         rng: &mut R,
     ) -> Result<Proof<N>> {
-        #[cfg(feature = "aleo-cli")]
+        #[cfg(any(feature = "aleo-cli", feature = "metrics"))]
         let timer = std::time::Instant::now();
 
         // Retrieve the proving parameters.
@@ -49,6 +49,9 @@ impl<N: Network> ProvingKey<N> {
         // Compute the proof.
         let proof = Proof::new(Varuna::<N>::prove(universal_prover, fiat_shamir, self, assignment, rng)?);
 
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::snark::PROVE_SECONDS, timer.elapsed().as_secs_f64());
+
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Executed '{function_name}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
         Ok(proof)
@@ -61,7 +64,7 @@ impl<N: Network> ProvingKey<N> {
         assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
         rng: &mut R,
     ) -> Result<Proof<N>> {
-        #[cfg(feature = "aleo-cli")]
+        #[cfg(any(feature = "aleo-cli", feature = "metrics"))]
         let timer = std::time::Instant::now();
 
         // Prepare the instances.
@@ -77,6 +80,9 @@ impl<N: Network> ProvingKey<N> {
         // Compute the proof.
         let batch_proof = Proof::new(Varuna::<N>::prove_batch(universal_prover, fiat_shamir, &instances, rng)?);
 
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::snark::PROVE_SECONDS, timer.elapsed().as_secs_f64());
+
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Executed '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
 
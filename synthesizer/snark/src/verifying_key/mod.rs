@@ -32,9 +32,14 @@ impl<N: Network> VerifyingKey<N> {
         Self { verifying_key }
     }
 
+    /// Returns the verifying key ID, which is the BHP hash of the canonical byte encoding of the verifying key.
+    pub fn to_id(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
     /// Returns `true` if the proof is valid for the given public inputs.
     pub fn verify(&self, function_name: &str, inputs: &[N::Field], proof: &Proof<N>) -> bool {
-        #[cfg(feature = "aleo-cli")]
+        #[cfg(any(feature = "aleo-cli", feature = "metrics"))]
         let timer = std::time::Instant::now();
 
         // Retrieve the verification parameters.
@@ -44,6 +49,8 @@ impl<N: Network> VerifyingKey<N> {
         // Verify the proof.
         match Varuna::<N>::verify(universal_verifier, fiat_shamir, self, inputs, proof) {
             Ok(is_valid) => {
+                #[cfg(feature = "metrics")]
+                metrics::histogram(metrics::snark::VERIFY_SECONDS, timer.elapsed().as_secs_f64());
                 #[cfg(feature = "aleo-cli")]
                 println!(
                     "{}",
@@ -52,6 +59,8 @@ impl<N: Network> VerifyingKey<N> {
                 is_valid
             }
             Err(error) => {
+                #[cfg(feature = "metrics")]
+                metrics::histogram(metrics::snark::VERIFY_SECONDS, timer.elapsed().as_secs_f64());
                 #[cfg(feature = "aleo-cli")]
                 println!("{}", format!(" • Verifier failed: {error}").dimmed());
                 false
@@ -62,7 +71,7 @@ impl<N: Network> VerifyingKey<N> {
     /// Returns `true` if the batch proof is valid for the given public inputs.
     #[allow(clippy::type_complexity)]
     pub fn verify_batch(locator: &str, inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, proof: &Proof<N>) -> bool {
-        #[cfg(feature = "aleo-cli")]
+        #[cfg(any(feature = "aleo-cli", feature = "metrics"))]
         let timer = std::time::Instant::now();
 
         // Convert the instances.
@@ -76,11 +85,15 @@ impl<N: Network> VerifyingKey<N> {
         // Verify the batch proof.
         match Varuna::<N>::verify_batch(universal_verifier, fiat_shamir, &keys_to_inputs, proof) {
             Ok(is_valid) => {
+                #[cfg(feature = "metrics")]
+                metrics::histogram(metrics::snark::VERIFY_SECONDS, timer.elapsed().as_secs_f64());
                 #[cfg(feature = "aleo-cli")]
                 println!("{}", format!(" • Verified '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
                 is_valid
             }
             Err(error) => {
+                #[cfg(feature = "metrics")]
+                metrics::histogram(metrics::snark::VERIFY_SECONDS, timer.elapsed().as_secs_f64());
                 #[cfg(feature = "aleo-cli")]
                 println!("{}", format!(" • Verifier failed: {error}").dimmed());
                 false
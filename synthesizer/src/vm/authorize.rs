@@ -52,6 +52,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
     /// and the deployment or execution ID.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(name = "vm.authorize_fee_private", level = "debug", skip_all)
+    )]
     pub fn authorize_fee_private<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -86,6 +90,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Authorizes the fee given the the fee amount (in microcredits) and the deployment or execution ID.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(name = "vm.authorize_fee_public", level = "debug", skip_all)
+    )]
     pub fn authorize_fee_public<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -120,6 +128,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Authorizes a call to the program function for the given inputs.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "vm.authorize",
+            level = "debug",
+            skip_all,
+            fields(program_id = %program_id, function_name = %function_name)
+        )
+    )]
     fn authorize_raw<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -110,6 +110,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Executes a call to the program function for the given authorization.
     /// Returns the execution.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(name = "vm.execute", level = "debug", skip_all, fields(num_requests = authorization.len()))
+    )]
     fn execute_authorization_raw<R: Rng + CryptoRng>(
         &self,
         authorization: Authorization<N>,
@@ -160,6 +164,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Executes a call to the program function for the given fee authorization.
     /// Returns the fee.
     #[inline]
+    #[cfg_attr(feature = "tracing-spans", tracing::instrument(name = "vm.execute_fee", level = "debug", skip_all))]
     fn execute_fee_authorization_raw<R: Rng + CryptoRng>(
         &self,
         authorization: Authorization<N>,
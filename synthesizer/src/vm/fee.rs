@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a new transaction that re-issues the given unconfirmed transaction with a higher priority fee.
+    ///
+    /// The deployment or execution of the given transaction is reused as-is, as the fee is bound to
+    /// the deployment or execution ID (and not the transaction ID). Only the fee transition is recomputed.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the base fee,
+    /// and must be strictly greater than the priority fee of the given transaction.
+    pub fn bump_priority_fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        transaction: &Transaction<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the transaction is not already in the ledger.
+        if self.block_store().contains_transaction_id(&transaction.id())? {
+            bail!("Transaction '{}' is already confirmed, and cannot be replaced", transaction.id());
+        }
+        // Ensure the priority fee is increasing.
+        let current_priority_fee = *transaction.priority_fee_amount()?;
+        ensure!(
+            priority_fee_in_microcredits > current_priority_fee,
+            "The new priority fee ({priority_fee_in_microcredits}) must exceed the current priority fee ({current_priority_fee})"
+        );
+
+        // Compute the base fee and the deployment or execution ID.
+        let (base_fee_in_microcredits, deployment_or_execution_id) = match transaction {
+            Transaction::Deploy(_, _, deployment, _) => {
+                (deployment_cost(deployment)?.0, deployment.to_deployment_id()?)
+            }
+            Transaction::Execute(_, execution, _) => (execution_cost(self, execution)?.0, execution.to_execution_id()?),
+            Transaction::Fee(..) => bail!("Cannot bump the priority fee of a fee transaction"),
+        };
+
+        // Authorize the fee.
+        let authorization = match fee_record {
+            Some(record) => self.authorize_fee_private(
+                private_key,
+                record,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                deployment_or_execution_id,
+                rng,
+            )?,
+            None => self.authorize_fee_public(
+                private_key,
+                base_fee_in_microcredits,
+                priority_fee_in_microcredits,
+                deployment_or_execution_id,
+                rng,
+            )?,
+        };
+        // Compute the fee.
+        let fee = self.execute_fee_authorization(authorization, query, rng)?;
+
+        // Return the replacement transaction.
+        transaction.replace_fee(fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::Address, network::Testnet3, program::Value};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bump_priority_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Update the VM.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        vm.add_next_block(&genesis).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Execute.
+        let transaction =
+            vm.execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs, None, 1, None, rng).unwrap();
+
+        // Ensure a non-increasing priority fee is rejected.
+        assert!(vm.bump_priority_fee(&caller_private_key, &transaction, None, 1, None, rng).is_err());
+
+        // Bump the priority fee.
+        let replacement = vm.bump_priority_fee(&caller_private_key, &transaction, None, 10, None, rng).unwrap();
+        assert_ne!(transaction.id(), replacement.id());
+        assert_eq!(*replacement.priority_fee_amount().unwrap(), 10);
+        assert_eq!(transaction.execution(), replacement.execution());
+
+        // Ensure the replacement conflicts with the original transaction.
+        assert!(transaction.conflicts_with(&replacement));
+        assert!(replacement.conflicts_with(&transaction));
+
+        // Ensure the replacement is well-formed.
+        vm.check_transaction(&replacement, None, rng).unwrap();
+    }
+}
@@ -37,13 +37,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let timer = timer!("VM::speculate");
 
         // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
-        let (ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations) = self
+        let (ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations, _) = self
             .atomic_speculate(
                 state,
                 coinbase_reward,
                 candidate_ratifications,
                 candidate_solutions,
                 candidate_transactions,
+                false,
             )?;
 
         // Convert the aborted transactions into aborted transaction IDs.
@@ -64,6 +65,59 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ))
     }
 
+    /// Speculates on the given list of transactions in the VM, and additionally returns
+    /// the effects of each confirmed transaction on the finalize store.
+    ///
+    /// Returns the ratifications, confirmed transactions, aborted transaction IDs,
+    /// finalize operations from pre-ratify and post-ratify, and the transaction diffs.
+    /// Each transaction diff contains the finalize operations and the mapping entries
+    /// that were changed by the transaction, in the order the transactions were confirmed.
+    ///
+    /// Note: To compute the mapping diffs, every mapping of the programs invoked by a transaction
+    /// is read before and after it is finalized. This method is intended for tooling (e.g. explorers
+    /// and wallets), and should not be used in block production.
+    #[inline]
+    pub fn speculate_with_diffs<'a>(
+        &self,
+        state: FinalizeGlobalState,
+        coinbase_reward: Option<u64>,
+        candidate_ratifications: Vec<Ratify<N>>,
+        candidate_solutions: Option<&CoinbaseSolution<N>>,
+        candidate_transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
+    ) -> Result<(
+        Ratifications<N>,
+        Transactions<N>,
+        Vec<N::TransactionID>,
+        Vec<FinalizeOperation<N>>,
+        Vec<TransactionDiff<N>>,
+    )> {
+        let timer = timer!("VM::speculate_with_diffs");
+
+        // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
+        let (ratifications, confirmed, aborted, ratified_finalize_operations, diffs) = self.atomic_speculate(
+            state,
+            coinbase_reward,
+            candidate_ratifications,
+            candidate_solutions,
+            candidate_transactions,
+            true,
+        )?;
+
+        // Convert the aborted transactions into aborted transaction IDs.
+        let aborted_transaction_ids = aborted.into_iter().map(|(tx, _)| tx.id()).collect();
+
+        finish!(timer, "Finished dry-run of the transactions");
+
+        // Return the ratifications, confirmed transactions, aborted transaction IDs, finalize operations, and diffs.
+        Ok((
+            ratifications,
+            confirmed.into_iter().collect(),
+            aborted_transaction_ids,
+            ratified_finalize_operations,
+            diffs,
+        ))
+    }
+
     /// Checks the speculation on the given transactions in the VM.
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
@@ -84,8 +138,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             transactions.iter().map(|confirmed| confirmed.to_unconfirmed_transaction()).collect::<Result<Vec<_>>>()?;
 
         // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
-        let (speculate_ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations) =
-            self.atomic_speculate(state, None, candidate_ratifications, solutions, candidate_transactions.iter())?;
+        let (speculate_ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations, _) =
+            self.atomic_speculate(
+                state,
+                None,
+                candidate_ratifications,
+                solutions,
+                candidate_transactions.iter(),
+                false,
+            )?;
 
         // Ensure the ratifications after speculation match.
         if ratifications != &speculate_ratifications {
@@ -139,13 +200,23 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Performs atomic speculation over a list of transactions.
     ///
     /// Returns the ratifications, confirmed transactions, aborted transactions,
-    /// and finalize operations from pre-ratify and post-ratify.
+    /// finalize operations from pre-ratify and post-ratify, and (if `record_diffs` is set)
+    /// the diff of each confirmed transaction.
     ///
-    /// Note: This method is used by `VM::speculate` and `VM::check_speculate`.
+    /// Note: This method is used by `VM::speculate`, `VM::speculate_with_diffs`, and `VM::check_speculate`.
     ///   - If `coinbase_reward = None`, then the `ratifications` will not be modified.
     ///   - If `coinbase_reward = Some(coinbase_reward)`, then the method will append a
     ///     `Ratify::BlockReward(block_reward)` and `Ratify::PuzzleReward(puzzle_reward)`
     ///     to the front of the `ratifications` list.
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "vm.speculate",
+            level = "debug",
+            skip_all,
+            fields(block_height = state.block_height(), num_transactions = transactions.len())
+        )
+    )]
     fn atomic_speculate<'a>(
         &self,
         state: FinalizeGlobalState,
@@ -153,11 +224,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: Vec<Ratify<N>>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
+        record_diffs: bool,
     ) -> Result<(
         Ratifications<N>,
         Vec<ConfirmedTransaction<N>>,
         Vec<(Transaction<N>, String)>,
         Vec<FinalizeOperation<N>>,
+        Vec<TransactionDiff<N>>,
     )> {
         // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
         // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
@@ -166,6 +239,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         let timer = timer!("VM::atomic_speculate");
 
+        // Retrieve the handlers for custom ratifications.
+        let ratify_handlers = self.ratify_handlers.read();
+
         // Retrieve the number of transactions.
         let num_transactions = transactions.len();
 
@@ -182,13 +258,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                Ratify::Genesis(_, _) | Ratify::Evidence(..) => true,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Custom(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::Genesis(_, _) | Ratify::Evidence(..) => false,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Custom(..) => true,
             });
 
             // Initialize a list of finalize operations.
@@ -223,6 +299,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut counter = 0u32;
             // Initialize a list of spent input IDs.
             let mut input_ids: IndexSet<Field<N>> = IndexSet::new();
+            // Initialize a list of the transaction diffs.
+            let mut diffs = Vec::new();
 
             // Finalize the transactions.
             'outer: for transaction in transactions {
@@ -248,6 +326,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     }
                 }
 
+                // If diffs are requested, snapshot the mappings of the invoked programs.
+                let snapshot = match record_diffs {
+                    true => match snapshot_mappings(store, transaction.transitions().map(|t| *t.program_id())) {
+                        Ok(snapshot) => Some(snapshot),
+                        // Note: This will abort the entire atomic batch.
+                        Err(e) => return Err(format!("Failed to snapshot the mappings before speculation - {e}")),
+                    },
+                    false => None,
+                };
+
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
@@ -361,6 +449,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 match outcome {
                     // If the transaction succeeded, store it and continue to the next transaction.
                     Ok(confirmed_transaction) => {
+                        // If diffs are requested, compute the diff of the transaction.
+                        if let Some(before) = snapshot {
+                            let program_ids = transaction.transitions().map(|t| *t.program_id());
+                            let after = match snapshot_mappings(store, program_ids) {
+                                Ok(after) => after,
+                                // Note: This will abort the entire atomic batch.
+                                Err(e) => {
+                                    return Err(format!("Failed to snapshot the mappings after speculation - {e}"));
+                                }
+                            };
+                            diffs.push(TransactionDiff {
+                                transaction_id: transaction.id(),
+                                finalize_operations: confirmed_transaction.finalize_operations().clone(),
+                                mapping_diffs: diff_mappings(&before, &after),
+                            });
+                        }
                         // Add the input IDs to the set of spent input IDs.
                         input_ids.extend(confirmed_transaction.transaction().input_ids());
                         // Store the confirmed transaction.
@@ -418,7 +522,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let post_ratifications = reward_ratifications.iter().chain(post_ratifications);
 
             // Process the post-ratifications.
-            match Self::atomic_post_ratify(store, state, post_ratifications, solutions) {
+            match Self::atomic_post_ratify(store, state, post_ratifications, solutions, &ratify_handlers) {
                 // Store the finalize operations from the post-ratify.
                 Ok(operations) => ratified_finalize_operations.extend(operations),
                 // Note: This will abort the entire atomic batch.
@@ -437,8 +541,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             finish!(timer);
 
             // On return, 'atomic_finalize!' will abort the batch, and return the ratifications,
-            // confirmed & aborted transactions, and finalize operations from pre-ratify and post-ratify.
-            Ok((ratifications, confirmed, aborted, ratified_finalize_operations))
+            // confirmed & aborted transactions, finalize operations from pre-ratify and post-ratify, and diffs.
+            Ok((ratifications, confirmed, aborted, ratified_finalize_operations, diffs))
         })
     }
 
@@ -446,6 +550,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "vm.finalize",
+            level = "debug",
+            skip_all,
+            fields(block_height = state.block_height(), num_transactions = transactions.len())
+        )
+    )]
     fn atomic_finalize(
         &self,
         state: FinalizeGlobalState,
@@ -460,17 +573,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         let timer = timer!("VM::atomic_finalize");
 
+        // Retrieve the handlers for custom ratifications.
+        let ratify_handlers = self.ratify_handlers.read();
+
         // Perform the finalize operation on the preset finalize mode.
         atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                Ratify::Genesis(_, _) | Ratify::Evidence(..) => true,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Custom(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::Genesis(_, _) | Ratify::Evidence(..) => false,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Custom(..) => true,
             });
 
             // Initialize a list of finalize operations.
@@ -662,7 +778,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             /* Perform the ratifications after finalize. */
 
-            match Self::atomic_post_ratify(store, state, post_ratifications, solutions) {
+            match Self::atomic_post_ratify(store, state, post_ratifications, solutions, &ratify_handlers) {
                 // Store the finalize operations from the post-ratify.
                 Ok(operations) => ratified_finalize_operations.extend(operations),
                 // Note: This will abort the entire atomic batch.
@@ -695,6 +811,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let committee_mapping = Identifier::from_str("committee")?;
         // Construct the bonded mapping name.
         let bonded_mapping = Identifier::from_str("bonded")?;
+        // Construct the unbonding mapping name.
+        let unbonding_mapping = Identifier::from_str("unbonding")?;
         // Construct the account mapping name.
         let account_mapping = Identifier::from_str("account")?;
 
@@ -774,7 +892,68 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the genesis ratification flag.
                     is_genesis_ratified = true;
                 }
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => continue,
+                Ratify::Evidence(evidence) => {
+                    // Ensure this is not the genesis block.
+                    ensure!(state.block_height() > 0, "Ratify::Evidence(..) is not allowed in the genesis block");
+
+                    // Retrieve the validator that misbehaved.
+                    let validator = evidence.author();
+
+                    // Retrieve the committee mapping from storage.
+                    let current_committee_map = store.get_mapping_speculative(program_id, committee_mapping)?;
+                    // Convert the committee mapping into a committee.
+                    let current_committee = committee_map_into_committee(state.block_round(), current_committee_map)?;
+                    // Retrieve the bonded mapping from storage.
+                    let current_bonded_map = store.get_mapping_speculative(program_id, bonded_mapping)?;
+                    // Convert the bonded map into stakers.
+                    let current_stakers = bonded_map_into_stakers(current_bonded_map)?;
+
+                    // Ensure the committee matches the bonded mapping.
+                    ensure_stakers_matches(&current_committee, &current_stakers)?;
+                    // Ensure the validator is in the committee.
+                    ensure!(
+                        current_committee.is_committee_member(validator),
+                        "Ratify::Evidence(..) expected validator '{validator}' to be in the committee"
+                    );
+
+                    // Separate the stakers bonded to the validator from the remaining stakers.
+                    let (ejected_stakers, next_stakers): (IndexMap<_, _>, IndexMap<_, _>) = current_stakers
+                        .into_iter()
+                        .partition(|(_, (staker_validator, _))| *staker_validator == validator);
+                    // Compute the updated committee, without the validator.
+                    let next_committee = to_next_committee(&current_committee, state.block_round(), &next_stakers)?;
+                    // Construct the next committee map and next bonded map.
+                    let (next_committee_map, next_bonded_map) =
+                        to_next_commitee_map_and_bonded_map(&next_committee, &next_stakers);
+
+                    // Store the finalize operations for updating the committee and bonded mapping.
+                    // Note: The committee store is updated by the block reward in post-ratify.
+                    finalize_operations.extend(&[
+                        // Replace the committee mapping in storage.
+                        store.replace_mapping(program_id, committee_mapping, next_committee_map)?,
+                        // Replace the bonded mapping in storage.
+                        store.replace_mapping(program_id, bonded_mapping, next_bonded_map)?,
+                    ]);
+
+                    // Compute the unlock height for the ejected stakers.
+                    let unlock_height = state.block_height().saturating_add(UNBONDING_PERIOD);
+                    // Iterate over the ejected stakers, and unbond their microcredits.
+                    for (staker, (_, microcredits)) in ejected_stakers {
+                        // Construct the key.
+                        let key = Plaintext::from(Literal::Address(staker));
+                        // Retrieve the current unbonding microcredits.
+                        let unbonding = match store.get_value_speculative(program_id, unbonding_mapping, &key)? {
+                            Some(value) => unbond_state_from_value(&value)?.0,
+                            None => 0,
+                        };
+                        // Compute the next unbond state.
+                        let next_value = unbond_state_to_value(unbonding.saturating_add(microcredits), unlock_height)?;
+                        // Update the unbond state in finalize storage.
+                        let operation = store.update_key_value(program_id, unbonding_mapping, key, next_value)?;
+                        finalize_operations.push(operation);
+                    }
+                }
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Custom(..) => continue,
             }
         }
 
@@ -789,6 +968,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         state: FinalizeGlobalState,
         post_ratifications: impl Iterator<Item = &'a Ratify<N>>,
         solutions: Option<&CoinbaseSolution<N>>,
+        ratify_handlers: &RatifyHandlers<N, C::FinalizeStorage>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         // Construct the program ID.
         let program_id = ProgramID::from_str("credits.aleo")?;
@@ -810,7 +990,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Iterate over the ratifications.
         for ratify in post_ratifications {
             match ratify {
-                Ratify::Genesis(..) => continue,
+                Ratify::Genesis(..) | Ratify::Evidence(..) => continue,
                 Ratify::BlockReward(block_reward) => {
                     // Ensure the block reward has not been ratified yet.
                     ensure!(!is_block_reward_ratified, "Ratify::BlockReward(..) has already been ratified");
@@ -887,6 +1067,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the puzzle reward ratification flag.
                     is_puzzle_reward_ratified = true;
                 }
+                Ratify::Custom(kind, version, payload) => {
+                    // Retrieve the handler for the custom ratification.
+                    let Some(handler) = ratify_handlers.get(kind) else {
+                        bail!("No handler is registered for custom ratification kind '{kind}'")
+                    };
+                    // Apply the custom ratification, and store the finalize operations.
+                    finalize_operations.extend(handler.ratify(store, state, *version, payload)?);
+                }
             }
         }
 
@@ -1160,6 +1348,132 @@ finalize transfer_public:
         }
     }
 
+    #[test]
+    fn test_custom_ratification() {
+        let rng = &mut TestRng::default();
+
+        /// A custom ratification handler that credits the given address with the amount in the payload.
+        struct Airdrop(Address<CurrentNetwork>);
+
+        impl RatifyHandler<CurrentNetwork, ledger_store::helpers::memory::FinalizeMemory<CurrentNetwork>> for Airdrop {
+            fn ratify(
+                &self,
+                store: &FinalizeStore<CurrentNetwork, ledger_store::helpers::memory::FinalizeMemory<CurrentNetwork>>,
+                _state: FinalizeGlobalState,
+                version: u8,
+                payload: &[u8],
+            ) -> Result<Vec<FinalizeOperation<CurrentNetwork>>> {
+                ensure!(version == 1, "Unsupported airdrop version");
+                let amount = u64::from_bytes_le(payload)?;
+                let program_id = ProgramID::from_str("credits.aleo")?;
+                let mapping = Identifier::from_str("account")?;
+                let key = Plaintext::from(Literal::Address(self.0));
+                let value = Value::from(Literal::U64(U64::new(amount)));
+                Ok(vec![store.update_key_value(program_id, mapping, key, value)?])
+            }
+        }
+
+        // Initialize the VM.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        // Sample a recipient.
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Prepare the custom ratification.
+        let ratify = Ratify::new_custom(7, 1, 100u64.to_bytes_le().unwrap()).unwrap();
+        let transactions: Vec<Transaction<CurrentNetwork>> = vec![];
+
+        // Ensure speculation fails without a registered handler.
+        assert!(vm.speculate(sample_finalize_state(1), None, vec![ratify.clone()], None, transactions.iter()).is_err());
+
+        // Register the handler.
+        vm.register_ratify_handler(7, Airdrop(recipient)).unwrap();
+        assert!(vm.contains_ratify_handler(7));
+        // Ensure the handler cannot be registered twice.
+        assert!(vm.register_ratify_handler(7, Airdrop(recipient)).is_err());
+
+        // Ensure speculation applies the custom ratification.
+        let (ratifications, _, _, ratified_finalize_operations) =
+            vm.speculate(sample_finalize_state(1), None, vec![ratify.clone()], None, transactions.iter()).unwrap();
+        assert_eq!(ratifications.len(), 1);
+        assert_eq!(ratified_finalize_operations.len(), 1);
+
+        // Ensure an unsupported version is rejected by the handler.
+        let ratify = Ratify::new_custom(7, 2, 100u64.to_bytes_le().unwrap()).unwrap();
+        assert!(vm.speculate(sample_finalize_state(1), None, vec![ratify], None, transactions.iter()).is_err());
+    }
+
+    #[test]
+    fn test_speculate_with_diffs() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+        // Sample a recipient.
+        let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Construct a public transfer with a public fee.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&recipient_address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        let fee = *transaction.fee_amount().unwrap();
+
+        // Retrieve the balance of the caller.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let caller_key = Plaintext::from(Literal::Address(caller_address));
+        let recipient_key = Plaintext::from(Literal::Address(recipient_address));
+        let caller_balance = match vm.finalize_store().get_value_confirmed(program_id, mapping_name, &caller_key) {
+            Ok(Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _)))) => *balance,
+            _ => panic!("Expected a valid balance for the caller"),
+        };
+
+        // Speculate on the transaction, with and without diffs.
+        let (_, transactions, aborted_transaction_ids, _) =
+            vm.speculate(sample_finalize_state(1), None, vec![], None, [transaction.clone()].iter()).unwrap();
+        let (_, transactions_with_diffs, aborted_transaction_ids_with_diffs, _, diffs) = vm
+            .speculate_with_diffs(sample_finalize_state(1), None, vec![], None, [transaction.clone()].iter())
+            .unwrap();
+        assert_eq!(transactions, transactions_with_diffs);
+        assert_eq!(aborted_transaction_ids, aborted_transaction_ids_with_diffs);
+
+        // Ensure the diff matches the confirmed transaction.
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].transaction_id, transaction.id());
+        let confirmed = transactions.iter().next().unwrap();
+        assert_eq!(&diffs[0].finalize_operations, confirmed.finalize_operations());
+
+        // Ensure the mapping diffs capture the transfer and the fee.
+        let u64_value = |amount: u64| Value::from(Literal::U64(U64::new(amount)));
+        let mapping_diffs = &diffs[0].mapping_diffs;
+        assert_eq!(mapping_diffs.len(), 2);
+        assert!(mapping_diffs.contains(&MappingDiff {
+            program_id,
+            mapping_name,
+            key: caller_key.clone(),
+            old_value: Some(u64_value(caller_balance)),
+            new_value: Some(u64_value(caller_balance - 1 - fee)),
+        }));
+        assert!(mapping_diffs.contains(&MappingDiff {
+            program_id,
+            mapping_name,
+            key: recipient_key.clone(),
+            old_value: None,
+            new_value: Some(u64_value(1)),
+        }));
+
+        // Ensure speculation did not update the finalize store.
+        assert_eq!(vm.finalize_store().get_value_speculative(program_id, mapping_name, &recipient_key).unwrap(), None);
+    }
+
     #[test]
     fn test_finalize_duplicate_deployment() {
         let rng = &mut TestRng::default();
@@ -1196,8 +1510,9 @@ finalize transfer_public:
         assert!(vm.contains_program(&program_id));
 
         // Ensure the dry run of the redeployment will cause a reject transaction to be created.
-        let (_, candidate_transactions, aborted_transaction_ids, _) =
-            vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, [deployment_transaction].iter()).unwrap();
+        let (_, candidate_transactions, aborted_transaction_ids, _, _) = vm
+            .atomic_speculate(sample_finalize_state(1), None, vec![], None, [deployment_transaction].iter(), false)
+            .unwrap();
         assert_eq!(candidate_transactions.len(), 1);
         assert!(matches!(candidate_transactions[0], ConfirmedTransaction::RejectedDeploy(..)));
         assert!(aborted_transaction_ids.is_empty());
@@ -1298,8 +1613,8 @@ finalize transfer_public:
         // Transfer_20 -> Balance = 20 - 20 = 0
         {
             let transactions = [mint_10.clone(), transfer_10.clone(), transfer_20.clone()];
-            let (_, confirmed_transactions, aborted_transaction_ids, _) =
-                vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, transactions.iter()).unwrap();
+            let (_, confirmed_transactions, aborted_transaction_ids, _, _) =
+                vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, transactions.iter(), false).unwrap();
 
             // Assert that all the transactions are accepted.
             assert_eq!(confirmed_transactions.len(), 3);
@@ -1318,8 +1633,8 @@ finalize transfer_public:
         // Transfer_30 -> Balance = 30 - 30 = 0
         {
             let transactions = [transfer_20.clone(), mint_10.clone(), mint_20.clone(), transfer_30.clone()];
-            let (_, confirmed_transactions, aborted_transaction_ids, _) =
-                vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, transactions.iter()).unwrap();
+            let (_, confirmed_transactions, aborted_transaction_ids, _, _) =
+                vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, transactions.iter(), false).unwrap();
 
             // Assert that all the transactions are accepted.
             assert_eq!(confirmed_transactions.len(), 4);
@@ -1338,8 +1653,8 @@ finalize transfer_public:
         // Transfer_10 -> Balance = 0 - 10 = -10 (should be rejected)
         {
             let transactions = [transfer_20.clone(), transfer_10.clone()];
-            let (_, confirmed_transactions, aborted_transaction_ids, _) =
-                vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, transactions.iter()).unwrap();
+            let (_, confirmed_transactions, aborted_transaction_ids, _, _) =
+                vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, transactions.iter(), false).unwrap();
 
             // Assert that the accepted and rejected transactions are correct.
             assert_eq!(confirmed_transactions.len(), 2);
@@ -1362,8 +1677,8 @@ finalize transfer_public:
         // Transfer_10 -> Balance = 10 - 10 = 0
         {
             let transactions = [mint_20.clone(), transfer_30.clone(), transfer_20.clone(), transfer_10.clone()];
-            let (_, confirmed_transactions, aborted_transaction_ids, _) =
-                vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, transactions.iter()).unwrap();
+            let (_, confirmed_transactions, aborted_transaction_ids, _, _) =
+                vm.atomic_speculate(sample_finalize_state(1), None, vec![], None, transactions.iter(), false).unwrap();
 
             // Assert that the accepted and rejected transactions are correct.
             assert_eq!(confirmed_transactions.len(), 4);
@@ -19,7 +19,7 @@ use console::{
     network::Network,
     prelude::{cfg_into_iter, cfg_iter, cfg_reduce},
     program::{Identifier, Literal, Plaintext, Value},
-    types::{Boolean, U64},
+    types::{Boolean, U32, U64},
 };
 use ledger_committee::Committee;
 
@@ -30,6 +30,9 @@ use std::str::FromStr;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The number of blocks a staker must wait to claim unbonding microcredits, as defined in `credits.aleo`.
+pub const UNBONDING_PERIOD: u32 = 360;
+
 /// Returns the committee given the committee map from finalize storage.
 pub fn committee_map_into_committee<N: Network>(
     starting_round: u64,
@@ -229,6 +232,36 @@ pub fn to_next_commitee_map_and_bonded_map<N: Network>(
     (committee_map, bonded_map)
 }
 
+/// Returns the unbonding microcredits and unlock height, given the unbond state from finalize storage.
+pub fn unbond_state_from_value<N: Network>(value: &Value<N>) -> Result<(u64, u32)> {
+    match value {
+        Value::Plaintext(Plaintext::Struct(state, _)) => {
+            // Extract the microcredits from the value.
+            let microcredits = match state.get(&Identifier::from_str("microcredits")?) {
+                Some(Plaintext::Literal(Literal::U64(microcredits), _)) => **microcredits,
+                _ => bail!("Invalid unbond state (missing microcredits) - {value}"),
+            };
+            // Extract the unlock height from the value.
+            let height = match state.get(&Identifier::from_str("height")?) {
+                Some(Plaintext::Literal(Literal::U32(height), _)) => **height,
+                _ => bail!("Invalid unbond state (missing height) - {value}"),
+            };
+            Ok((microcredits, height))
+        }
+        _ => bail!("Invalid unbond value (missing struct) - {value}"),
+    }
+}
+
+/// Returns the unbond state for finalize storage, given the unbonding microcredits and unlock height.
+pub fn unbond_state_to_value<N: Network>(microcredits: u64, height: u32) -> Result<Value<N>> {
+    // Construct the unbond state.
+    let unbond_state = indexmap! {
+        Identifier::from_str("microcredits")? => Plaintext::from(Literal::U64(U64::new(microcredits))),
+        Identifier::from_str("height")? => Plaintext::from(Literal::U32(U32::new(height))),
+    };
+    Ok(Value::Plaintext(Plaintext::Struct(unbond_state, Default::default())))
+}
+
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
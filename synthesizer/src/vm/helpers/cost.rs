@@ -121,6 +121,8 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::Div(_)) => Ok(10_000),
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Double(_)) => Ok(2_000),
+        Command::Instruction(Instruction::ECDSAVerify(_)) => Ok(500_000),
+        Command::Instruction(Instruction::Ed25519Verify(_)) => Ok(500_000),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
         Command::Instruction(Instruction::HashBHP256(_)) => Ok(100_000),
@@ -150,6 +152,8 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::HashSha3_256(_)) => Ok(100_000),
         Command::Instruction(Instruction::HashSha3_384(_)) => Ok(100_000),
         Command::Instruction(Instruction::HashSha3_512(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashShake128(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashShake256(_)) => Ok(100_000),
         Command::Instruction(Instruction::HashManyPSD2(_)) => {
             bail!("`hash_many.psd2` is not supported in finalize.")
         }
@@ -176,6 +180,8 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::PowWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Rem(_)) => Ok(2_000),
         Command::Instruction(Instruction::RemWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::RotateLeft(_)) => Ok(2_000),
+        Command::Instruction(Instruction::RotateRight(_)) => Ok(2_000),
         Command::Instruction(Instruction::SignVerify(_)) => Ok(250_000),
         Command::Instruction(Instruction::Shl(_)) => Ok(2_000),
         Command::Instruction(Instruction::ShlWrapped(_)) => Ok(2_000),
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::VM;
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+    program::Identifier,
+};
+use ledger_block::Block;
+use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
+use ledger_store::{ConsensusStorage, ConsensusStore};
+use synthesizer_process::Process;
+use synthesizer_program::Program;
+use synthesizer_snark::{ProvingKey, VerifyingKey};
+
+use indexmap::IndexMap;
+use std::path::Path;
+
+/// The configuration of a development network.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DevnetConfig {
+    /// The number of validators in the genesis committee.
+    pub num_validators: u16,
+    /// The stake of each validator in the genesis committee, in microcredits.
+    pub validator_stake: u64,
+    /// If `true`, new 'credits.aleo' circuit keys are synthesized, instead of using the network's keys.
+    pub synthesize_credits_keys: bool,
+}
+
+impl Default for DevnetConfig {
+    /// Returns a configuration with 4 validators, each with the minimum stake, using the network's credits keys.
+    fn default() -> Self {
+        Self { num_validators: 4, validator_stake: MIN_VALIDATOR_STAKE, synthesize_credits_keys: false }
+    }
+}
+
+/// A complete bundle for a development network.
+#[derive(Clone)]
+pub struct DevnetBundle<N: Network> {
+    /// The private keys of the validators, the first of which created the genesis block.
+    pub validator_private_keys: Vec<PrivateKey<N>>,
+    /// The genesis committee.
+    pub committee: Committee<N>,
+    /// The genesis block.
+    pub genesis: Block<N>,
+    /// The 'credits.aleo' circuit keys, as a map of `function name => (proving key, verifying key)`.
+    pub credits_keys: IndexMap<Identifier<N>, (ProvingKey<N>, VerifyingKey<N>)>,
+    /// The subset of the universal SRS that was loaded to produce the circuit keys and the genesis block.
+    pub universal_srs: Vec<u8>,
+}
+
+impl<N: Network> DevnetBundle<N> {
+    /// Generates a new development network bundle, with the given configuration.
+    ///
+    /// All keys are sampled from the given RNG, so a seeded RNG produces a reproducible bundle.
+    /// The remaining supply, after the validator stakes, is split evenly between the validators' public balances.
+    pub fn generate<A: circuit::Aleo<Network = N>, C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        config: &DevnetConfig,
+        rng: &mut R,
+    ) -> Result<Self> {
+        ensure!(config.num_validators > 0, "A devnet requires at least one validator");
+        ensure!(config.validator_stake >= MIN_VALIDATOR_STAKE, "The validator stake is below the minimum");
+
+        // Sample the validator private keys.
+        let validator_private_keys =
+            (0..config.num_validators).map(|_| PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+        let validators = validator_private_keys.iter().map(Address::try_from).collect::<Result<Vec<_>>>()?;
+
+        // Construct the committee.
+        let members = validators.iter().map(|address| (*address, (config.validator_stake, true))).collect();
+        let committee = Committee::<N>::new_genesis(members)?;
+
+        // Split the remaining supply between the validators, assigning any remainder to the first validator.
+        let remaining_supply = N::STARTING_SUPPLY
+            .checked_sub(committee.total_stake())
+            .ok_or_else(|| anyhow!("The validator stakes exceed the starting supply"))?;
+        let balance = remaining_supply / validators.len() as u64;
+        let remainder = remaining_supply % validators.len() as u64;
+        let public_balances = validators
+            .iter()
+            .enumerate()
+            .map(|(index, address)| (*address, if index == 0 { balance + remainder } else { balance }))
+            .collect();
+
+        // Initialize the VM.
+        let vm = VM::from(ConsensusStore::<N, C>::open(None)?)?;
+        let credits = Program::<N>::credits()?;
+
+        // If requested, synthesize new 'credits.aleo' circuit keys, and use them for the genesis block.
+        if config.synthesize_credits_keys {
+            let process = Process::<N>::setup::<A, _>(rng)?;
+            for function_name in credits.functions().keys() {
+                let proving_key = process.get_proving_key(*credits.id(), *function_name)?;
+                let verifying_key = process.get_verifying_key(*credits.id(), *function_name)?;
+                vm.process().read().insert_proving_key(credits.id(), function_name, proving_key)?;
+                vm.process().read().insert_verifying_key(credits.id(), function_name, verifying_key)?;
+            }
+        }
+
+        // Construct the genesis block.
+        let genesis = vm.genesis_quorum(&validator_private_keys[0], committee.clone(), public_balances, rng)?;
+
+        // Collect the 'credits.aleo' circuit keys.
+        let process = vm.process();
+        let process = process.read();
+        let credits_keys = credits
+            .functions()
+            .keys()
+            .map(|function_name| {
+                let proving_key = process.get_proving_key(*credits.id(), *function_name)?;
+                let verifying_key = process.get_verifying_key(*credits.id(), *function_name)?;
+                Ok((*function_name, (proving_key, verifying_key)))
+            })
+            .collect::<Result<_>>()?;
+        // Collect the universal SRS.
+        let universal_srs = process.universal_srs().to_bytes_le()?;
+
+        Ok(Self { validator_private_keys, committee, genesis, credits_keys, universal_srs })
+    }
+
+    /// Writes the bundle to the given directory.
+    ///
+    /// The genesis block is written to `genesis.block`, the committee to `committee`, the validator private keys
+    /// to `validators.txt`, the universal SRS to `universal.srs`, and the 'credits.aleo' circuit keys to
+    /// `{function_name}.prover` and `{function_name}.verifier`, in the format of the bundled parameter files.
+    pub fn write_to(&self, directory: &Path) -> Result<()> {
+        std::fs::create_dir_all(directory)?;
+
+        std::fs::write(directory.join("genesis.block"), self.genesis.to_bytes_le()?)?;
+        std::fs::write(directory.join("committee"), self.committee.to_bytes_le()?)?;
+        std::fs::write(directory.join("universal.srs"), &self.universal_srs)?;
+
+        let private_keys = self.validator_private_keys.iter().map(|private_key| format!("{private_key}\n"));
+        std::fs::write(directory.join("validators.txt"), private_keys.collect::<String>())?;
+
+        for (function_name, (proving_key, verifying_key)) in &self.credits_keys {
+            std::fs::write(directory.join(format!("{function_name}.prover")), proving_key.to_bytes_le()?)?;
+            std::fs::write(directory.join(format!("{function_name}.verifier")), verifying_key.to_bytes_le()?)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use ledger_store::helpers::memory::ConsensusMemory;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_generate_devnet_bundle() {
+        let config = DevnetConfig { num_validators: 5, ..Default::default() };
+        let bundle = DevnetBundle::<CurrentNetwork>::generate::<CurrentAleo, ConsensusMemory<CurrentNetwork>, _>(
+            &config,
+            &mut TestRng::fixed(1234),
+        )
+        .unwrap();
+
+        // Ensure the committee contains every validator.
+        assert_eq!(bundle.validator_private_keys.len(), 5);
+        assert_eq!(bundle.committee.num_members(), 5);
+        assert_eq!(bundle.committee.total_stake(), 5 * MIN_VALIDATOR_STAKE);
+        for private_key in &bundle.validator_private_keys {
+            assert!(bundle.committee.is_committee_member(Address::try_from(private_key).unwrap()));
+        }
+
+        // Ensure the genesis block is valid, and was created by the first validator.
+        assert!(bundle.genesis.is_genesis());
+        assert_eq!(bundle.credits_keys.len(), Program::<CurrentNetwork>::credits().unwrap().functions().len());
+        assert!(!bundle.universal_srs.is_empty());
+
+        // Ensure the bundle is written to disk.
+        let directory = std::env::temp_dir().join(format!("snarkvm-devnet-{}", std::process::id()));
+        bundle.write_to(&directory).unwrap();
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(&std::fs::read(directory.join("genesis.block")).unwrap());
+        assert_eq!(genesis.unwrap(), bundle.genesis);
+        assert!(directory.join("transfer_public.prover").exists());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_devnet_config() {
+        let rng = &mut TestRng::default();
+
+        let config = DevnetConfig { num_validators: 0, ..Default::default() };
+        assert!(
+            DevnetBundle::<CurrentNetwork>::generate::<CurrentAleo, ConsensusMemory<CurrentNetwork>, _>(&config, rng)
+                .is_err()
+        );
+
+        let config = DevnetConfig { validator_stake: MIN_VALIDATOR_STAKE - 1, ..Default::default() };
+        assert!(
+            DevnetBundle::<CurrentNetwork>::generate::<CurrentAleo, ConsensusMemory<CurrentNetwork>, _>(&config, rng)
+                .is_err()
+        );
+    }
+}
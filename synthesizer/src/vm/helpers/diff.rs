@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+use ledger_store::{FinalizeStorage, FinalizeStore};
+use synthesizer_program::FinalizeOperation;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A snapshot of the mapping entries of a set of programs, as a map of `(program ID, mapping name) => (key => value)`.
+pub(crate) type MappingSnapshot<N> = IndexMap<(ProgramID<N>, Identifier<N>), IndexMap<Plaintext<N>, Value<N>>>;
+
+/// The change to a single mapping entry, caused by finalizing a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingDiff<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The mapping name.
+    pub mapping_name: Identifier<N>,
+    /// The key.
+    pub key: Plaintext<N>,
+    /// The value before the transaction, or `None` if the key did not exist.
+    pub old_value: Option<Value<N>>,
+    /// The value after the transaction, or `None` if the key was removed.
+    pub new_value: Option<Value<N>>,
+}
+
+/// The effects of finalizing a single transaction during speculation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionDiff<N: Network> {
+    /// The ID of the (unconfirmed) transaction.
+    pub transaction_id: N::TransactionID,
+    /// The finalize operations produced by the transaction.
+    pub finalize_operations: Vec<FinalizeOperation<N>>,
+    /// The mapping entries changed by the transaction.
+    pub mapping_diffs: Vec<MappingDiff<N>>,
+}
+
+/// Returns a speculative snapshot of every mapping in the given programs.
+pub(crate) fn snapshot_mappings<N: Network, P: FinalizeStorage<N>>(
+    store: &FinalizeStore<N, P>,
+    program_ids: impl IntoIterator<Item = ProgramID<N>>,
+) -> Result<MappingSnapshot<N>> {
+    let mut snapshot = MappingSnapshot::new();
+    for program_id in program_ids.into_iter().collect::<IndexSet<_>>() {
+        // Note: A program that is being deployed has no mappings until it is finalized.
+        let Some(mapping_names) = store.get_mapping_names_speculative(&program_id)? else {
+            continue;
+        };
+        for mapping_name in mapping_names {
+            let entries = store.get_mapping_speculative(program_id, mapping_name)?;
+            snapshot.insert((program_id, mapping_name), entries.into_iter().collect());
+        }
+    }
+    Ok(snapshot)
+}
+
+/// Returns the mapping entries that differ between the `before` and `after` snapshots.
+pub(crate) fn diff_mappings<N: Network>(
+    before: &MappingSnapshot<N>,
+    after: &MappingSnapshot<N>,
+) -> Vec<MappingDiff<N>> {
+    // Initialize an empty mapping, for mappings that are missing from a snapshot.
+    let empty = IndexMap::new();
+
+    let mut diffs = Vec::new();
+    for (program_id, mapping_name) in before.keys().chain(after.keys()).collect::<IndexSet<_>>() {
+        let old_entries = before.get(&(*program_id, *mapping_name)).unwrap_or(&empty);
+        let new_entries = after.get(&(*program_id, *mapping_name)).unwrap_or(&empty);
+
+        // Collect the inserted, updated, and removed keys, in order of first appearance.
+        for key in new_entries.keys().chain(old_entries.keys()).collect::<IndexSet<_>>() {
+            let old_value = old_entries.get(key);
+            let new_value = new_entries.get(key);
+            if old_value != new_value {
+                diffs.push(MappingDiff {
+                    program_id: *program_id,
+                    mapping_name: *mapping_name,
+                    key: key.clone(),
+                    old_value: old_value.cloned(),
+                    new_value: new_value.cloned(),
+                });
+            }
+        }
+    }
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_diff_mappings() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let (a, b, c) = (
+            Plaintext::from_str("1u8").unwrap(),
+            Plaintext::from_str("2u8").unwrap(),
+            Plaintext::from_str("3u8").unwrap(),
+        );
+        let value = |s: &str| Value::<CurrentNetwork>::from_str(s).unwrap();
+
+        let mut before = MappingSnapshot::new();
+        before.insert(
+            (program_id, mapping_name),
+            [(a.clone(), value("10u64")), (b.clone(), value("20u64"))].into_iter().collect(),
+        );
+        let mut after = MappingSnapshot::new();
+        after.insert(
+            (program_id, mapping_name),
+            [(a.clone(), value("10u64")), (c.clone(), value("30u64")), (b.clone(), value("25u64"))]
+                .into_iter()
+                .collect(),
+        );
+
+        // Ensure unchanged entries are omitted, and updates and inserts are reported.
+        let diffs = diff_mappings(&before, &after);
+        assert_eq!(diffs.len(), 2);
+        assert_eq!((&diffs[0].key, &diffs[0].old_value, &diffs[0].new_value), (&c, &None, &Some(value("30u64"))));
+        assert_eq!(
+            (&diffs[1].key, &diffs[1].old_value, &diffs[1].new_value),
+            (&b, &Some(value("20u64")), &Some(value("25u64")))
+        );
+
+        // Ensure removals are reported.
+        let diffs = diff_mappings(&after, &before);
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(
+            (&diffs[0].key, &diffs[0].old_value, &diffs[0].new_value),
+            (&b, &Some(value("25u64")), &Some(value("20u64")))
+        );
+        assert_eq!((&diffs[1].key, &diffs[1].old_value, &diffs[1].new_value), (&c, &Some(value("30u64")), &None));
+
+        // Ensure identical snapshots have no diffs.
+        assert!(diff_mappings(&before, &before).is_empty());
+    }
+}
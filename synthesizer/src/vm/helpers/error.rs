@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use thiserror::Error;
+
+/// An error raised while verifying a transaction in the VM, with a stable error code and machine-readable context.
+///
+/// The VM APIs return `Result`; callers recover this type with `error.downcast_ref::<VMError>()`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum VMError {
+    #[error("Transaction '{transaction_id}' already exists in the ledger")]
+    DuplicateTransaction { transaction_id: String },
+
+    #[error("Incorrect transaction ID ({transaction_id})")]
+    InvalidTransactionId { transaction_id: String },
+
+    #[error("Found a duplicate {element} in the transaction")]
+    DuplicateElement { element: &'static str },
+
+    #[error("The {element} '{value}' already exists in the ledger")]
+    ElementExists { element: &'static str, value: String },
+
+    #[error("Invalid owner signature for deployment transaction '{transaction_id}'")]
+    InvalidOwnerSignature { transaction_id: String },
+
+    #[error("Invalid deployment transaction '{transaction_id}' - expected edition {expected}")]
+    InvalidEdition { transaction_id: String, expected: u16 },
+
+    #[error("Program ID '{program_id}' is already deployed")]
+    ProgramExists { program_id: String },
+
+    #[error("Program ID '{program_id}' may only be deployed by the owner of '{parent}'")]
+    UnauthorizedDeployer { program_id: String, parent: String },
+
+    #[error("Transaction '{transaction_id}' contains a previously rejected execution")]
+    RejectedExecution { transaction_id: String },
+
+    #[error("Transaction '{transaction_id}' should not have a rejected ID ({kind})")]
+    UnexpectedRejectedId { transaction_id: String, kind: &'static str },
+
+    #[error("Transaction '{transaction_id}' is missing a rejected ID (fee)")]
+    MissingRejectedId { transaction_id: String },
+
+    #[error("Transaction '{transaction_id}' has an insufficient base fee ({kind}) - requires {required} microcredits")]
+    InsufficientBaseFee { transaction_id: String, kind: &'static str, required: u64 },
+
+    #[error("Transaction '{transaction_id}' has a non-zero base fee (execution)")]
+    NonZeroBaseFee { transaction_id: String },
+
+    #[error("Transaction '{transaction_id}' is missing a fee (execution)")]
+    MissingFee { transaction_id: String },
+
+    #[error("Deployment verification failed - {reason}")]
+    InvalidDeployment { program_id: String, reason: String },
+
+    #[error("Execution verification failed: {reason}")]
+    InvalidExecution { program_id: Option<String>, function_name: Option<String>, reason: String },
+
+    #[error("Fee verification failed: {reason}")]
+    InvalidFee { reason: String },
+}
+
+impl VMError {
+    /// Returns the stable error code.
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::DuplicateTransaction { .. } => 2000,
+            Self::InvalidTransactionId { .. } => 2001,
+            Self::DuplicateElement { .. } => 2002,
+            Self::ElementExists { .. } => 2003,
+            Self::InvalidOwnerSignature { .. } => 2004,
+            Self::InvalidEdition { .. } => 2005,
+            Self::ProgramExists { .. } => 2006,
+            Self::UnauthorizedDeployer { .. } => 2007,
+            Self::RejectedExecution { .. } => 2008,
+            Self::UnexpectedRejectedId { .. } => 2009,
+            Self::MissingRejectedId { .. } => 2010,
+            Self::InsufficientBaseFee { .. } => 2011,
+            Self::NonZeroBaseFee { .. } => 2012,
+            Self::MissingFee { .. } => 2013,
+            Self::InvalidDeployment { .. } => 2014,
+            Self::InvalidExecution { .. } => 2015,
+            Self::InvalidFee { .. } => 2016,
+        }
+    }
+
+    /// Returns the transaction ID, if the error is specific to a transaction.
+    pub fn transaction_id(&self) -> Option<&str> {
+        match self {
+            Self::DuplicateTransaction { transaction_id }
+            | Self::InvalidTransactionId { transaction_id }
+            | Self::InvalidOwnerSignature { transaction_id }
+            | Self::InvalidEdition { transaction_id, .. }
+            | Self::RejectedExecution { transaction_id }
+            | Self::UnexpectedRejectedId { transaction_id, .. }
+            | Self::MissingRejectedId { transaction_id }
+            | Self::InsufficientBaseFee { transaction_id, .. }
+            | Self::NonZeroBaseFee { transaction_id }
+            | Self::MissingFee { transaction_id } => Some(transaction_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the program ID, if the error is specific to a program.
+    pub fn program_id(&self) -> Option<&str> {
+        match self {
+            Self::ProgramExists { program_id }
+            | Self::UnauthorizedDeployer { program_id, .. }
+            | Self::InvalidDeployment { program_id, .. } => Some(program_id),
+            Self::InvalidExecution { program_id, .. } => program_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the function name, if the error is specific to a function.
+    pub fn function_name(&self) -> Option<&str> {
+        match self {
+            Self::InvalidExecution { function_name, .. } => function_name.as_deref(),
+            _ => None,
+        }
+    }
+}
@@ -18,7 +18,16 @@ pub use committee::*;
 mod cost;
 pub use cost::*;
 
+mod devnet;
+pub use devnet::*;
+
+mod diff;
+pub use diff::*;
+
+mod error;
+pub use error::*;
+
 mod macros;
 
-mod rewards;
-pub use rewards::*;
+mod ratify;
+pub use ratify::*;
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_store::{FinalizeStorage, FinalizeStore};
+use synthesizer_program::{FinalizeGlobalState, FinalizeOperation};
+
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+/// A handler for custom ratifications (i.e. `Ratify::Custom`) of a given kind.
+///
+/// Custom ratifications are applied after the transactions in a block are finalized,
+/// in the order in which they appear in the block. As the resulting finalize operations are
+/// committed to in the block header, every node in the network must register the same handlers.
+pub trait RatifyHandler<N: Network, F: FinalizeStorage<N>>: Send + Sync {
+    /// Applies the custom ratification with the given version and payload to the finalize store,
+    /// and returns the resulting finalize operations.
+    fn ratify(
+        &self,
+        store: &FinalizeStore<N, F>,
+        state: FinalizeGlobalState,
+        version: u8,
+        payload: &[u8],
+    ) -> Result<Vec<FinalizeOperation<N>>>;
+}
+
+/// A map of custom ratification kinds to their registered handlers.
+pub type RatifyHandlers<N, F> = IndexMap<u16, Arc<dyn RatifyHandler<N, F>>>;
@@ -18,6 +18,7 @@ pub use helpers::*;
 mod authorize;
 mod deploy;
 mod execute;
+mod fee;
 mod finalize;
 mod verify;
 
@@ -29,6 +30,8 @@ use console::{
     types::{Field, U64},
 };
 use ledger_block::{
+    proving_rewards,
+    staking_rewards,
     Block,
     ConfirmedTransaction,
     Deployment,
@@ -76,6 +79,10 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     atomic_lock: Arc<Mutex<()>>,
     /// The lock for ensuring there is no concurrency when advancing blocks.
     block_lock: Arc<Mutex<()>>,
+    /// The handlers for custom ratifications.
+    ratify_handlers: Arc<RwLock<RatifyHandlers<N, C::FinalizeStorage>>>,
+    /// The cache of deployment verification results, as a map of `(program ID, edition) => verified deployment ID`.
+    deployment_verification_cache: Arc<Mutex<IndexMap<(ProgramID<N>, u16), Field<N>>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -178,6 +185,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             store,
             atomic_lock: Arc::new(Mutex::new(())),
             block_lock: Arc::new(Mutex::new(())),
+            ratify_handlers: Default::default(),
+            deployment_verification_cache: Default::default(),
         })
     }
 
@@ -194,6 +203,31 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 }
 
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Registers the given handler for custom ratifications of the given kind.
+    ///
+    /// Note: Blocks containing a custom ratification without a registered handler are rejected.
+    /// As such, every node in the network must register the same handlers.
+    pub fn register_ratify_handler(
+        &self,
+        kind: u16,
+        handler: impl RatifyHandler<N, C::FinalizeStorage> + 'static,
+    ) -> Result<()> {
+        // Acquire the write lock on the handlers.
+        let mut handlers = self.ratify_handlers.write();
+        // Ensure the kind is not already registered.
+        ensure!(!handlers.contains_key(&kind), "A handler for custom ratification kind '{kind}' is already registered");
+        // Register the handler.
+        handlers.insert(kind, Arc::new(handler));
+        Ok(())
+    }
+
+    /// Returns `true` if a handler is registered for custom ratifications of the given kind.
+    pub fn contains_ratify_handler(&self, kind: u16) -> bool {
+        self.ratify_handlers.read().contains_key(&kind)
+    }
+}
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns the finalize store.
     #[inline]
@@ -318,6 +352,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Adds the given block into the VM.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "vm.add_next_block",
+            level = "debug",
+            skip_all,
+            fields(block_height = block.height(), block_hash = %block.hash())
+        )
+    )]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
         // Acquire the block lock, which is needed to ensure this function is not called concurrently.
         // Note: This lock must be held for the entire scope of this function.
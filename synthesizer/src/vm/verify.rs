@@ -20,20 +20,32 @@ macro_rules! ensure_is_unique {
     ($name:expr, $self:expr, $method:ident, $iter:expr) => {
         // Ensure there are no duplicate items in the transaction.
         if has_duplicates($iter) {
-            bail!("Found a duplicate {} in the transaction", $name);
+            bail!(VMError::DuplicateElement { element: $name });
         }
         // Ensure the ledger does not already contain a given item.
         for item in $iter {
             if $self.transition_store().$method(item)? {
-                bail!("The {} '{}' already exists in the ledger", $name, item)
+                bail!(VMError::ElementExists { element: $name, value: item.to_string() })
             }
         }
     };
 }
 
+/// The maximum number of deployment verification results to keep in the cache.
+const MAX_DEPLOYMENT_VERIFICATION_CACHE_SIZE: usize = 64;
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
+    #[cfg_attr(
+        feature = "tracing-spans",
+        tracing::instrument(
+            name = "vm.check_transaction",
+            level = "debug",
+            skip_all,
+            fields(transaction_id = %transaction.id())
+        )
+    )]
     pub fn check_transaction<R: CryptoRng + Rng>(
         &self,
         transaction: &Transaction<N>,
@@ -41,18 +53,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         /* Transaction */
 
         // Ensure the transaction ID is unique.
         if self.block_store().contains_transaction_id(&transaction.id())? {
-            bail!("Transaction '{}' already exists in the ledger", transaction.id())
+            bail!(VMError::DuplicateTransaction { transaction_id: transaction.id().to_string() })
         }
 
         // Compute the Merkle root of the transaction.
         match transaction.to_root() {
             // Ensure the transaction ID is correct.
-            Ok(root) if *transaction.id() != root => bail!("Incorrect transaction ID ({})", transaction.id()),
+            Ok(root) if *transaction.id() != root => {
+                bail!(VMError::InvalidTransactionId { transaction_id: transaction.id().to_string() })
+            }
             Ok(_) => (),
             Err(error) => {
                 bail!("Failed to compute the Merkle root of the transaction: {error}\n{transaction}");
@@ -103,14 +119,26 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Failed to compute the Merkle root for a deployment transaction '{id}'")
                 };
                 // Verify the signature corresponds to the transaction ID.
-                ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
+                if !owner.verify(deployment_id) {
+                    bail!(VMError::InvalidOwnerSignature { transaction_id: id.to_string() })
+                }
                 // Ensure the edition is correct.
                 if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
+                    bail!(VMError::InvalidEdition { transaction_id: id.to_string(), expected: N::EDITION })
                 }
                 // Ensure the program ID does not already exist..
                 if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
+                    bail!(VMError::ProgramExists { program_id: deployment.program_id().to_string() })
+                }
+                // If the program is namespaced, ensure the deployer owns the parent program.
+                if let Some(parent) = deployment.program_id().parent() {
+                    let parent_owner = self.transaction_store().get_owner(&parent)?;
+                    if parent_owner.map(|parent_owner| parent_owner.address()) != Some(owner.address()) {
+                        bail!(VMError::UnauthorizedDeployer {
+                            program_id: deployment.program_id().to_string(),
+                            parent: parent.to_string(),
+                        })
+                    }
                 }
                 // Verify the deployment.
                 self.check_deployment_internal(deployment, rng)?;
@@ -122,7 +150,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Ensure the execution was not previously rejected (replay attack prevention).
                 if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
-                    bail!("Transaction '{id}' contains a previously rejected execution")
+                    bail!(VMError::RejectedExecution { transaction_id: id.to_string() })
                 }
                 // Verify the execution.
                 self.check_execution_internal(execution)?;
@@ -130,6 +158,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Transaction::Fee(..) => { /* no-op */ }
         }
 
+        #[cfg(feature = "metrics")]
+        {
+            metrics::increment_counter(metrics::vm::TRANSACTIONS_VERIFIED);
+            metrics::histogram(metrics::vm::CHECK_TRANSACTION_SECONDS, start.elapsed().as_secs_f64());
+        }
+
         finish!(timer, "Verify the transaction");
         Ok(())
     }
@@ -140,7 +174,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
-                ensure!(rejected_id.is_none(), "Transaction '{id}' should not have a rejected ID (deployment)");
+                if rejected_id.is_some() {
+                    bail!(VMError::UnexpectedRejectedId { transaction_id: id.to_string(), kind: "deployment" })
+                }
                 // Compute the deployment ID.
                 let Ok(deployment_id) = deployment.to_deployment_id() else {
                     bail!("Failed to compute the Merkle root for deployment transaction '{id}'")
@@ -149,14 +185,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let (cost, _) = deployment_cost(deployment)?;
                 // Ensure the fee is sufficient to cover the cost.
                 if *fee.base_amount()? < cost {
-                    bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
+                    bail!(VMError::InsufficientBaseFee {
+                        transaction_id: id.to_string(),
+                        kind: "deployment",
+                        required: cost,
+                    })
                 }
                 // Verify the fee.
                 self.check_fee_internal(fee, deployment_id)?;
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
-                ensure!(rejected_id.is_none(), "Transaction '{id}' should not have a rejected ID (execution)");
+                if rejected_id.is_some() {
+                    bail!(VMError::UnexpectedRejectedId { transaction_id: id.to_string(), kind: "execution" })
+                }
                 // Compute the execution ID.
                 let Ok(execution_id) = execution.to_execution_id() else {
                     bail!("Failed to compute the Merkle root for execution transaction '{id}'")
@@ -171,19 +213,25 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         let (cost, _) = execution_cost(self, execution)?;
                         // Ensure the fee is sufficient to cover the cost.
                         if *fee.base_amount()? < cost {
-                            bail!(
-                                "Transaction '{id}' has an insufficient base fee (execution) - requires {cost} microcredits"
-                            )
+                            bail!(VMError::InsufficientBaseFee {
+                                transaction_id: id.to_string(),
+                                kind: "execution",
+                                required: cost,
+                            })
                         }
                     } else {
                         // Ensure the base fee amount is zero.
-                        ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
+                        if *fee.base_amount()? != 0 {
+                            bail!(VMError::NonZeroBaseFee { transaction_id: id.to_string() })
+                        }
                     }
                     // Verify the fee.
                     self.check_fee_internal(fee, execution_id)?;
                 } else {
                     // Ensure the fee can be safely skipped.
-                    ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
+                    if is_fee_required {
+                        bail!(VMError::MissingFee { transaction_id: id.to_string() })
+                    }
                 }
             }
             // Note: This transaction type does not need to check the fee amount, because:
@@ -193,7 +241,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Verify the fee.
                 match rejected_id {
                     Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
-                    None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
+                    None => bail!(VMError::MissingRejectedId { transaction_id: id.to_string() }),
                 }
             }
         }
@@ -204,10 +252,27 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the given deployment. On failure, returns an error.
     ///
+    /// Successful results are cached by `(program ID, edition)`, along with the deployment ID that was verified.
+    /// A deployment whose ID matches the cached entry is accepted without re-synthesizing its circuits,
+    /// while any other deployment for the same key is verified in full. The cache only records verification
+    /// results, and does not indicate whether a program is deployed.
+    ///
     /// Note: This is an internal check only. To ensure all components of the deployment are checked,
     /// use `VM::check_transaction` instead.
     #[inline]
     fn check_deployment_internal<R: CryptoRng + Rng>(&self, deployment: &Deployment<N>, rng: &mut R) -> Result<()> {
+        // Compute the cache key and deployment ID.
+        let cache_key = (*deployment.program_id(), deployment.edition());
+        let deployment_id = deployment.to_deployment_id()?;
+        // If this exact deployment was already verified, skip re-synthesizing the circuits.
+        if self.deployment_verification_cache.lock().get(&cache_key) == Some(&deployment_id) {
+            #[cfg(feature = "metrics")]
+            metrics::increment_counter(metrics::vm::DEPLOYMENT_CACHE_HITS);
+            return Ok(());
+        }
+        #[cfg(feature = "metrics")]
+        metrics::increment_counter(metrics::vm::DEPLOYMENT_CACHE_MISSES);
+
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the deployment.
@@ -219,8 +284,23 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Process the logic.
         let timer = timer!("VM::check_deployment");
-        let result = process!(self, logic).map_err(|error| anyhow!("Deployment verification failed - {error}"));
+        let result = process!(self, logic).map_err(|error| {
+            anyhow!(VMError::InvalidDeployment {
+                program_id: deployment.program_id().to_string(),
+                reason: error.to_string(),
+            })
+        });
         finish!(timer);
+
+        // If the deployment is valid, record the verification result in the cache.
+        if result.is_ok() {
+            let mut cache = self.deployment_verification_cache.lock();
+            // If the cache is full, evict the oldest entry.
+            if cache.len() >= MAX_DEPLOYMENT_VERIFICATION_CACHE_SIZE {
+                cache.shift_remove_index(0);
+            }
+            cache.insert(cache_key, deployment_id);
+        }
         result
     }
 
@@ -236,15 +316,25 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let verification = self.process.read().verify_execution(execution);
         lap!(timer, "Verify the execution");
 
+        // Returns an execution verification error, with the locator of the top-level transition.
+        let invalid_execution = |reason: String| {
+            let transition = execution.transitions().last();
+            VMError::InvalidExecution {
+                program_id: transition.map(|transition| transition.program_id().to_string()),
+                function_name: transition.map(|transition| transition.function_name().to_string()),
+                reason,
+            }
+        };
+
         // Ensure the global state root exists in the block store.
         let result = match verification {
             // Ensure the global state root exists in the block store.
             Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
                 Ok(true) => Ok(()),
-                Ok(false) => bail!("Execution verification failed: global state root not found"),
-                Err(error) => bail!("Execution verification failed: {error}"),
+                Ok(false) => bail!(invalid_execution("global state root not found".to_string())),
+                Err(error) => bail!(invalid_execution(error.to_string())),
             },
-            Err(error) => bail!("Execution verification failed: {error}"),
+            Err(error) => bail!(invalid_execution(error.to_string())),
         };
         finish!(timer, "Check the global state root");
         result
@@ -260,7 +350,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Ensure the fee does not exceed the limit.
         let fee_amount = fee.amount()?;
-        ensure!(*fee_amount <= N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
+        if *fee_amount > N::MAX_FEE {
+            bail!(VMError::InvalidFee { reason: "fee exceeds the maximum limit".to_string() })
+        }
 
         // Verify the fee.
         let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
@@ -272,7 +364,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         if fee.is_fee_public() {
             // Retrieve the payer.
             let Some(payer) = fee.payer() else {
-                bail!("Fee verification failed: fee is public, but the payer is missing");
+                bail!(VMError::InvalidFee { reason: "fee is public, but the payer is missing".to_string() });
             };
             // Retrieve the account balance of the payer.
             let Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) =
@@ -282,20 +374,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     &Plaintext::from(Literal::Address(payer)),
                 )?
             else {
-                bail!("Fee verification failed: fee is public, but the payer account balance is missing");
+                bail!(VMError::InvalidFee {
+                    reason: "fee is public, but the payer account balance is missing".to_string()
+                });
             };
             // Ensure the balance is sufficient.
-            ensure!(balance >= fee_amount, "Fee verification failed: insufficient balance");
+            if balance < fee_amount {
+                bail!(VMError::InvalidFee { reason: "insufficient balance".to_string() })
+            }
         }
 
         // Ensure the global state root exists in the block store.
         let result = match verification {
             Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
                 Ok(true) => Ok(()),
-                Ok(false) => bail!("Fee verification failed: global state root not found"),
-                Err(error) => bail!("Fee verification failed: {error}"),
+                Ok(false) => bail!(VMError::InvalidFee { reason: "global state root not found".to_string() }),
+                Err(error) => bail!(VMError::InvalidFee { reason: error.to_string() }),
             },
-            Err(error) => bail!("Fee verification failed: {error}"),
+            Err(error) => bail!(VMError::InvalidFee { reason: error.to_string() }),
         };
         finish!(timer, "Check the global state root");
         result
@@ -356,6 +452,43 @@ mod tests {
         vm.check_deployment_internal(&deployment_transaction, rng).unwrap();
     }
 
+    #[test]
+    fn test_verify_deployment_cache() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Deploy the program.
+        let deployment = vm.deploy_raw(&crate::vm::test_helpers::sample_program(), rng).unwrap();
+        let cache_key = (*deployment.program_id(), deployment.edition());
+        assert!(vm.deployment_verification_cache.lock().get(&cache_key).is_none());
+
+        // Ensure the deployment is valid, and is cached.
+        vm.check_deployment_internal(&deployment, rng).unwrap();
+        assert_eq!(
+            vm.deployment_verification_cache.lock().get(&cache_key),
+            Some(&deployment.to_deployment_id().unwrap())
+        );
+
+        // Ensure the cached deployment is still valid.
+        vm.check_deployment_internal(&deployment, rng).unwrap();
+        assert_eq!(vm.deployment_verification_cache.lock().len(), 1);
+
+        // Construct an invalid deployment for the same program, by swapping the certificates of the functions.
+        let mut verifying_keys = deployment.verifying_keys().clone();
+        let first_certificate = verifying_keys[0].1.1.clone();
+        verifying_keys[0].1.1 = verifying_keys[1].1.1.clone();
+        verifying_keys[1].1.1 = first_certificate;
+        let invalid_deployment =
+            Deployment::new(deployment.edition(), deployment.program().clone(), verifying_keys).unwrap();
+
+        // Ensure the invalid deployment is not accepted from the cache.
+        assert!(vm.check_deployment_internal(&invalid_deployment, rng).is_err());
+        assert_eq!(
+            vm.deployment_verification_cache.lock().get(&cache_key),
+            Some(&deployment.to_deployment_id().unwrap())
+        );
+    }
+
     #[test]
     fn test_verify_execution() {
         let rng = &mut TestRng::default();
@@ -432,6 +565,12 @@ mod tests {
         let valid_transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
         vm.check_transaction(&valid_transaction, None, rng).unwrap();
 
+        // Ensure an execution with a rejected ID fails with a coded error.
+        let error = vm.check_transaction(&valid_transaction, Some(Field::zero()), rng).unwrap_err();
+        let error = error.downcast_ref::<VMError>().unwrap();
+        assert_eq!(error.code(), 2009);
+        assert_eq!(error.transaction_id(), Some(valid_transaction.id().to_string().as_str()));
+
         // Fetch a valid execution transaction with a public fee.
         let valid_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
         vm.check_transaction(&valid_transaction, None, rng).unwrap();
@@ -80,6 +80,50 @@ pub trait FromBytes {
     }
 }
 
+/// A writer that counts the number of bytes written to it, without storing them.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ByteCounter {
+    /// The number of bytes written.
+    num_bytes: usize,
+}
+
+impl ByteCounter {
+    /// Returns the number of bytes in the little-endian encoding of the given value, without allocating them.
+    pub fn count<T: ToBytes>(value: &T) -> anyhow::Result<usize> {
+        let mut counter = Self::default();
+        value.write_le(&mut counter)?;
+        Ok(counter.num_bytes)
+    }
+
+    /// Returns the number of bytes written.
+    pub const fn num_bytes(&self) -> usize {
+        self.num_bytes
+    }
+}
+
+#[cfg(feature = "std")]
+impl Write for ByteCounter {
+    #[inline]
+    fn write(&mut self, data: &[u8]) -> IoResult<usize> {
+        self.num_bytes += data.len();
+        Ok(data.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Write for ByteCounter {
+    #[inline]
+    fn write_all(&mut self, data: &[u8]) -> IoResult<()> {
+        self.num_bytes += data.len();
+        Ok(())
+    }
+}
+
 pub struct ToBytesSerializer<T: ToBytes>(String, Option<usize>, PhantomData<T>);
 
 impl<T: ToBytes> ToBytesSerializer<T> {
@@ -573,4 +617,19 @@ mod test {
             assert_eq!(ipv6, ipv6_2);
         }
     }
+
+    #[test]
+    fn test_byte_counter() {
+        let mut rng = TestRng::default();
+
+        assert_eq!(ByteCounter::count(&()).unwrap(), 0);
+        for _ in 0..ITERATIONS {
+            let value: u128 = rng.gen();
+            let array: [u8; 13] = rng.gen();
+            let socket = SocketAddr::from((rng.gen::<[u8; 4]>(), rng.gen::<u16>()));
+            assert_eq!(ByteCounter::count(&value).unwrap(), value.to_bytes_le().unwrap().len());
+            assert_eq!(ByteCounter::count(&array).unwrap(), array.to_bytes_le().unwrap().len());
+            assert_eq!(ByteCounter::count(&socket).unwrap(), socket.to_bytes_le().unwrap().len());
+        }
+    }
 }
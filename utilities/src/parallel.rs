@@ -78,11 +78,17 @@ pub fn execute_with_max_available_threads<T>(f: impl FnOnce() -> T + Send) -> T
 
 #[cfg(not(any(feature = "serial", feature = "wasm")))]
 #[inline(always)]
-fn execute_with_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send, num_threads: usize) -> T {
+pub fn execute_with_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send, num_threads: usize) -> T {
     let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
     pool.install(f)
 }
 
+#[cfg(any(feature = "serial", feature = "wasm"))]
+#[inline(always)]
+pub fn execute_with_threads<T>(f: impl FnOnce() -> T + Send, _num_threads: usize) -> T {
+    f()
+}
+
 /// Creates parallel iterator over refs if `parallel` feature is enabled.
 #[macro_export]
 macro_rules! cfg_iter {
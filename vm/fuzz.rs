@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structured fuzz entrypoints for the parsers and deserializers.
+//!
+//! Each `parse_*` entrypoint feeds the raw input to the byte, string, and JSON deserializers, and
+//! each `roundtrip_*` entrypoint builds an arbitrary value from the input and round-trips it through
+//! the byte, string, and JSON encodings. An entrypoint only panics if a decoded value does not survive
+//! a round trip, so these can be called directly from a `cargo fuzz` target.
+
+use crate::{
+    console::{
+        network::{Testnet3, prelude::*},
+        program::{Plaintext, Record, Value},
+    },
+    ledger::{Block, Transaction, Transition},
+};
+
+use ::arbitrary::{Arbitrary, Unstructured};
+
+type CurrentNetwork = Testnet3;
+
+/// Parses the given input as a plaintext.
+pub fn parse_plaintext(data: &[u8]) {
+    parse::<Plaintext<CurrentNetwork>>(data)
+}
+
+/// Parses the given input as a value.
+pub fn parse_value(data: &[u8]) {
+    parse::<Value<CurrentNetwork>>(data)
+}
+
+/// Parses the given input as a record.
+pub fn parse_record(data: &[u8]) {
+    parse::<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>(data)
+}
+
+/// Parses the given input as a transition.
+pub fn parse_transition(data: &[u8]) {
+    parse::<Transition<CurrentNetwork>>(data)
+}
+
+/// Parses the given input as a transaction.
+pub fn parse_transaction(data: &[u8]) {
+    parse::<Transaction<CurrentNetwork>>(data)
+}
+
+/// Parses the given input as a block.
+pub fn parse_block(data: &[u8]) {
+    parse::<Block<CurrentNetwork>>(data)
+}
+
+/// Round-trips an arbitrary plaintext built from the given input.
+pub fn roundtrip_plaintext(data: &[u8]) {
+    roundtrip::<Plaintext<CurrentNetwork>>(data)
+}
+
+/// Round-trips an arbitrary value built from the given input.
+pub fn roundtrip_value(data: &[u8]) {
+    roundtrip::<Value<CurrentNetwork>>(data)
+}
+
+/// Round-trips an arbitrary record built from the given input.
+pub fn roundtrip_record(data: &[u8]) {
+    roundtrip::<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>(data)
+}
+
+/// Round-trips an arbitrary transition built from the given input.
+pub fn roundtrip_transition(data: &[u8]) {
+    roundtrip::<Transition<CurrentNetwork>>(data)
+}
+
+/// Round-trips an arbitrary transaction built from the given input.
+pub fn roundtrip_transaction(data: &[u8]) {
+    roundtrip::<Transaction<CurrentNetwork>>(data)
+}
+
+/// Round-trips an arbitrary block built from the given input.
+pub fn roundtrip_block(data: &[u8]) {
+    roundtrip::<Block<CurrentNetwork>>(data)
+}
+
+/// Deserializes the given input from bytes, a string, and JSON, and checks that every decoded object round-trips.
+fn parse<T>(data: &[u8])
+where
+    T: FromBytes + ToBytes + FromStr + Display + Serialize + DeserializeOwned + Debug + PartialEq,
+{
+    if let Ok(object) = T::from_bytes_le(data) {
+        check_roundtrip(&object);
+    }
+    if let Ok(string) = str::from_utf8(data) {
+        if let Ok(object) = T::from_str(string) {
+            check_roundtrip(&object);
+        }
+    }
+    if let Ok(object) = serde_json::from_slice::<T>(data) {
+        check_roundtrip(&object);
+    }
+}
+
+/// Builds an arbitrary object from the given input, and checks that it round-trips.
+fn roundtrip<'a, T>(data: &'a [u8])
+where
+    T: Arbitrary<'a> + FromBytes + ToBytes + FromStr + Display + Serialize + DeserializeOwned + Debug + PartialEq,
+{
+    if let Ok(object) = T::arbitrary(&mut Unstructured::new(data)) {
+        check_roundtrip(&object);
+    }
+}
+
+/// Checks that the given object round-trips through bytes, a string, and JSON.
+/// Note: Objects that exceed the byte encoding limits (e.g. a nested size that overflows its length prefix) are skipped.
+fn check_roundtrip<T>(object: &T)
+where
+    T: FromBytes + ToBytes + FromStr + Display + Serialize + DeserializeOwned + Debug + PartialEq,
+{
+    // Ensure the object round-trips through bytes.
+    let Ok(bytes) = object.to_bytes_le() else {
+        return;
+    };
+    let candidate = T::from_bytes_le(&bytes).expect("Failed to deserialize the object from bytes");
+    assert_eq!(object, &candidate, "The object did not round-trip through bytes");
+
+    // Ensure the object round-trips through a string.
+    let candidate = T::from_str(&object.to_string()).unwrap_or_else(|_| panic!("Failed to parse the object: {object}"));
+    assert_eq!(object, &candidate, "The object did not round-trip through a string");
+
+    // Ensure the object round-trips through JSON.
+    let json = serde_json::to_string(object).expect("Failed to serialize the object to JSON");
+    let candidate = serde_json::from_str::<T>(&json).expect("Failed to deserialize the object from JSON");
+    assert_eq!(object, &candidate, "The object did not round-trip through JSON");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_roundtrip() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let data = (0..1024).map(|_| rng.gen()).collect::<Vec<u8>>();
+            roundtrip_plaintext(&data);
+            roundtrip_value(&data);
+            roundtrip_record(&data);
+            roundtrip_transition(&data);
+            roundtrip_transaction(&data);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let data = (0..1024).map(|_| rng.gen()).collect::<Vec<u8>>();
+            parse_plaintext(&data);
+            parse_value(&data);
+            parse_record(&data);
+            parse_transition(&data);
+            parse_transaction(&data);
+            parse_block(&data);
+        }
+    }
+}
@@ -23,6 +23,8 @@ extern crate thiserror;
 #[cfg(feature = "cli")]
 pub mod cli;
 pub mod file;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
 pub mod package;
 
 #[cfg(feature = "algorithms")]